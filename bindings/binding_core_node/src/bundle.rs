@@ -14,7 +14,7 @@ use swc_core::{
     atoms::Atom,
     base::{
         config::SourceMapsConfig,
-        resolver::{environment_resolver, paths_resolver},
+        resolver::{environment_resolver, import_map_resolver, paths_resolver},
         Compiler, PrintArgs, TransformOutput,
     },
     bundler::{BundleKind, Bundler, Load, ModuleRecord, Resolve},
@@ -245,6 +245,16 @@ pub(crate) fn bundle(
         ))
     };
 
+    let import_map = static_items
+        .config
+        .options
+        .as_ref()
+        .and_then(|options| options.config.jsc.import_map.as_deref());
+    let resolver: Box<dyn Resolve> = match import_map {
+        Some(path) => Box::new(import_map_resolver(resolver, path).convert_err()?),
+        None => resolver,
+    };
+
     Ok(AsyncTask::with_optional_signal(
         BundleTask {
             swc: c,
@@ -56,6 +56,12 @@ impl FromStr for Version {
     }
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
 impl cmp::PartialOrd for Version {
     fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -167,20 +173,33 @@ pub fn should_enable(target: &Versions, feature: &Versions, default: bool) -> bo
         return default;
     }
 
-    target.iter().zip(feature.iter()).any(
-        |((target_name, maybe_target_version), (_, maybe_feature_version))| {
-            maybe_target_version.is_some_and(|target_version| {
-                let feature_or_fallback_version =
-                    maybe_feature_version.or_else(|| match target_name {
-                        // Fall back to Chrome versions if Android browser data
-                        // is missing from the feature data. It appears the
-                        // Android browser has aligned its versioning with Chrome.
-                        "android" => feature.chrome,
-                        _ => None,
-                    });
-
-                feature_or_fallback_version.map_or(true, |v| v > target_version)
-            })
+    unsupported_targets(target, feature).next().is_some()
+}
+
+/// Returns the targets which do not support `feature`, with their versions.
+///
+/// These are the reason why [should_enable] returns `true`, unless no version
+/// is known at all.
+pub fn unsupported_targets<'a>(
+    target: &'a Versions,
+    feature: &'a Versions,
+) -> impl 'a + Iterator<Item = (&'static str, Version)> {
+    target.iter().zip(feature.iter()).filter_map(
+        move |((target_name, maybe_target_version), (_, maybe_feature_version))| {
+            let target_version = (*maybe_target_version)?;
+            let feature_or_fallback_version = maybe_feature_version.or_else(|| match target_name {
+                // Fall back to Chrome versions if Android browser data
+                // is missing from the feature data. It appears the
+                // Android browser has aligned its versioning with Chrome.
+                "android" => feature.chrome,
+                _ => None,
+            });
+
+            if feature_or_fallback_version.map_or(true, |v| v > target_version) {
+                Some((target_name, target_version))
+            } else {
+                None
+            }
         },
     )
 }
@@ -204,4 +223,26 @@ mod tests {
             false
         ));
     }
+
+    #[test]
+    fn unsupported_targets_lists_old_browsers() {
+        let targets = BrowserData {
+            chrome: Some("50.0.0".parse().unwrap()),
+            firefox: Some("60.0.0".parse().unwrap()),
+            ie: Some("11.0.0".parse().unwrap()),
+            ..Default::default()
+        };
+        let feature = BrowserData {
+            chrome: Some("51.0.0".parse().unwrap()),
+            firefox: Some("53.0.0".parse().unwrap()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            unsupported_targets(&targets, &feature)
+                .map(|(name, version)| format!("{name} {version}"))
+                .collect::<Vec<_>>(),
+            vec!["chrome 50.0.0", "ie 11.0.0"]
+        );
+    }
 }
@@ -7,8 +7,11 @@ use swc_atoms::Atom;
 use swc_common::{
     comments::Comments, errors::Handler, sync::Lrc, util::take::Take, FileName, Mark, SourceMap,
 };
-use swc_ecma_ast::{EsVersion, Module, Pass, Script};
-use swc_ecma_minifier::option::{terser::TerserTopLevelOptions, MinifyOptions};
+use swc_ecma_ast::{EsVersion, Module, Pass, Program, Script};
+use swc_ecma_minifier::{
+    observer::observe_phases,
+    option::{terser::TerserTopLevelOptions, MinifyOptions},
+};
 use swc_ecma_parser::Syntax;
 use swc_ecma_preset_env::Caniuse;
 use swc_ecma_transforms::{
@@ -23,7 +26,10 @@ use swc_ecma_transforms::{
 use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
 use swc_visit::Optional;
 
-use crate::config::{GlobalPassOption, JsMinifyOptions, ModuleConfig};
+use crate::{
+    config::{GlobalPassOption, JsMinifyOptions, ModuleConfig},
+    explain::{explained, TransformExplainer},
+};
 
 /// Builder is used to create a high performance `Compiler`.
 pub struct PassBuilder<'a, 'b, P: Pass> {
@@ -45,6 +51,7 @@ pub struct PassBuilder<'a, 'b, P: Pass> {
     inject_helpers: bool,
     minify: Option<JsMinifyOptions>,
     regenerator: regenerator::Config,
+    explainer: Option<TransformExplainer>,
 }
 
 impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
@@ -72,6 +79,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             inject_helpers: true,
             minify: None,
             regenerator: Default::default(),
+            explainer: None,
         }
     }
 
@@ -95,6 +103,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             inject_helpers: self.inject_helpers,
             minify: self.minify,
             regenerator: self.regenerator,
+            explainer: self.explainer,
         }
     }
 
@@ -145,11 +154,24 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
         self
     }
 
+    /// Same as [Self::preset_env], but takes a resolved config so that the
+    /// caller can keep its [swc_ecma_preset_env::EnvReporter].
+    pub fn preset_env_config(mut self, env: Option<swc_ecma_preset_env::EnvConfig>) -> Self {
+        self.env = env;
+        self
+    }
+
     pub fn regenerator(mut self, config: regenerator::Config) -> Self {
         self.regenerator = config;
         self
     }
 
+    /// Records checkpoints for the transform explain mode between the passes.
+    pub fn explain(mut self, explainer: Option<TransformExplainer>) -> Self {
+        self.explainer = explainer;
+        self
+    }
+
     /// # Arguments
     /// ## module
     ///  - Use `None` if you want swc to emit import statements.
@@ -214,48 +236,70 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             .map(|v| v.mangle.is_obj() || v.mangle.is_true())
             .unwrap_or(false);
 
+        let explainer = self.explainer;
+
         (
-            self.pass,
+            explained(&explainer, "transforms", self.pass),
             Optional::new(
                 paren_remover(comments.map(|v| v as &dyn Comments)),
                 self.fixer,
             ),
-            compat_pass,
+            explained(&explainer, "compat", compat_pass),
             // module / helper
             Optional::new(
                 modules::import_analysis::import_analyzer(import_interop, ignore_dynamic),
                 need_analyzer,
             ),
             Optional::new(
-                helpers::inject_helpers(self.unresolved_mark),
+                explained(
+                    &explainer,
+                    "inject_helpers",
+                    helpers::inject_helpers(self.unresolved_mark),
+                ),
                 self.inject_helpers,
             ),
-            ModuleConfig::build(
-                self.cm.clone(),
-                comments,
-                module,
-                self.unresolved_mark,
-                resolver,
-                |f| {
-                    feature_config
-                        .as_ref()
-                        .map_or_else(|| self.target.caniuse(f), |env| env.caniuse(f))
-                },
+            explained(
+                &explainer,
+                "modules",
+                ModuleConfig::build(
+                    self.cm.clone(),
+                    comments,
+                    module,
+                    self.unresolved_mark,
+                    resolver,
+                    |f| {
+                        feature_config
+                            .as_ref()
+                            .map_or_else(|| self.target.caniuse(f), |env| env.caniuse(f))
+                    },
+                ),
             ),
             visit_mut_pass(MinifierPass {
                 options: self.minify,
                 cm: self.cm.clone(),
                 comments,
                 top_level_mark: self.top_level_mark,
+                explainer: explainer.clone(),
             }),
             Optional::new(
-                hygiene_with_config(swc_ecma_transforms_base::hygiene::Config {
-                    top_level_mark: self.top_level_mark,
-                    ..self.hygiene.clone().unwrap_or_default()
-                }),
+                explained(
+                    &explainer,
+                    "hygiene",
+                    hygiene_with_config(swc_ecma_transforms_base::hygiene::Config {
+                        top_level_mark: self.top_level_mark,
+                        ..self.hygiene.clone().unwrap_or_default()
+                    }),
+                ),
                 self.hygiene.is_some() && !is_mangler_enabled,
             ),
-            Optional::new(fixer(comments.map(|v| v as &dyn Comments)), self.fixer),
+            Optional::new(
+                explained(
+                    &explainer,
+                    "fixer",
+                    fixer(comments.map(|v| v as &dyn Comments)),
+                ),
+                self.fixer,
+            ),
         )
     }
 }
@@ -265,6 +309,37 @@ struct MinifierPass<'a> {
     cm: Lrc<SourceMap>,
     comments: Option<&'a dyn Comments>,
     top_level_mark: Mark,
+    explainer: Option<TransformExplainer>,
+}
+
+impl MinifierPass<'_> {
+    fn optimize(
+        &self,
+        program: Program,
+        opts: &MinifyOptions,
+        unresolved_mark: Mark,
+        top_level_mark: Mark,
+    ) -> Program {
+        let optimize = || {
+            swc_ecma_minifier::optimize(
+                program,
+                self.cm.clone(),
+                self.comments.as_ref().map(|v| v as &dyn Comments),
+                None,
+                opts,
+                &swc_ecma_minifier::option::ExtraOptions {
+                    unresolved_mark,
+                    top_level_mark,
+                    mangle_name_cache: None,
+                },
+            )
+        };
+
+        match &self.explainer {
+            Some(explainer) => observe_phases(Box::new(explainer.clone()), optimize),
+            None => optimize(),
+        }
+    }
 }
 
 impl VisitMut for MinifierPass<'_> {
@@ -317,19 +392,8 @@ impl VisitMut for MinifierPass<'_> {
             m.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
 
             m.map_with_mut(|m| {
-                swc_ecma_minifier::optimize(
-                    m.into(),
-                    self.cm.clone(),
-                    self.comments.as_ref().map(|v| v as &dyn Comments),
-                    None,
-                    &opts,
-                    &swc_ecma_minifier::option::ExtraOptions {
-                        unresolved_mark,
-                        top_level_mark,
-                        mangle_name_cache: None,
-                    },
-                )
-                .expect_module()
+                self.optimize(m.into(), &opts, unresolved_mark, top_level_mark)
+                    .expect_module()
             })
         }
     }
@@ -380,19 +444,8 @@ impl VisitMut for MinifierPass<'_> {
             m.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
 
             m.map_with_mut(|m| {
-                swc_ecma_minifier::optimize(
-                    m.into(),
-                    self.cm.clone(),
-                    self.comments.as_ref().map(|v| v as &dyn Comments),
-                    None,
-                    &opts,
-                    &swc_ecma_minifier::option::ExtraOptions {
-                        unresolved_mark,
-                        top_level_mark,
-                        mangle_name_cache: None,
-                    },
-                )
-                .expect_script()
+                self.optimize(m.into(), &opts, unresolved_mark, top_level_mark)
+                    .expect_script()
             })
         }
     }
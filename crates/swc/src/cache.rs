@@ -0,0 +1,450 @@
+//! Persistent, content-addressed cache for the outputs of
+//! [Compiler::process_js_file](crate::Compiler::process_js_file).
+//!
+//! Each entry is keyed by a [CacheKey], which is a hash of
+//!
+//!  - the source text of the input file,
+//!  - the resolved [Options] and the [Config] read from `.swcrc`,
+//!  - the contents of every configured wasm plugin,
+//!  - the version of `swc`.
+//!
+//! Entries are stored as one file per key under the cache root. The
+//! modification time of an entry file is used as its last access time, so the
+//! least recently used entries are evicted first when the total size of the
+//! cache exceeds the configured limit, even across processes.
+//!
+//! Runs which emit diagnostics, like lint warnings, are not cached, because a
+//! cache hit can't report them again.
+//!
+//! Note that external input source maps (`inputSourceMap: true`) are not part
+//! of the key. Call [TransformCache::invalidate] or [TransformCache::clear] if
+//! those change without the source file changing.
+
+use std::{
+    cell::Cell,
+    fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
+
+use anyhow::{Context, Error};
+use dashmap::DashMap;
+use filetime::FileTime;
+use parking_lot::Mutex;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use swc_common::{
+    errors::{Diagnostic, TRACK_DIAGNOSTICS},
+    FileName, SourceFile,
+};
+use swc_ecma_loader::{
+    resolve::Resolve,
+    resolvers::{lru::CachingResolver, node::NodeModulesResolver},
+};
+
+use crate::{
+    config::{Config, Options, PluginConfig},
+    TransformOutput,
+};
+
+/// Bumped when the layout of stored entries changes.
+const CACHE_FORMAT_VERSION: &str = "v1";
+
+const ENTRY_EXTENSION: &str = "json";
+
+/// A hash identifying a single transform.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    /// Computes the key for transforming `fm` with `opts`, where `config` is
+    /// the config read from `.swcrc` (if any).
+    pub fn new(
+        fm: &SourceFile,
+        opts: &Options,
+        config: Option<&Config>,
+        plugin_hashes: &[String],
+    ) -> Result<Self, Error> {
+        let mut hasher = Sha256::new();
+
+        let mut field = |name: &str, value: &[u8]| {
+            hasher.update(name.as_bytes());
+            hasher.update((value.len() as u64).to_le_bytes());
+            hasher.update(value);
+        };
+
+        field("format", CACHE_FORMAT_VERSION.as_bytes());
+        field("swc", env!("CARGO_PKG_VERSION").as_bytes());
+        field("src", fm.src.as_bytes());
+        field("filename", fm.name.to_string().as_bytes());
+
+        // Marks are allocated per process, so they are not serialized. They only
+        // affect the output if the caller also applies custom passes, which are
+        // not cached anyway.
+        field(
+            "options",
+            &canonical_json(opts).context("failed to serialize options")?,
+        );
+        field(
+            "swcrc",
+            &canonical_json(&config).context("failed to serialize .swcrc")?,
+        );
+
+        for hash in plugin_hashes {
+            field("plugin", hash.as_bytes());
+        }
+
+        Ok(CacheKey(hex::encode(hasher.finalize())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Runs `op`, returning whether it emitted any diagnostics on the current
+/// thread.
+pub(crate) fn track_diagnostics<R>(op: impl FnOnce() -> R) -> (R, bool) {
+    let emitted = Rc::new(Cell::new(false));
+
+    let prev = TRACK_DIAGNOSTICS.with(|track| {
+        let prev = Rc::new(track.replace(Box::new(|_| {})));
+        let (emitted, prev_hook) = (emitted.clone(), prev.clone());
+        *track.borrow_mut() = Box::new(move |diagnostic: &Diagnostic| {
+            emitted.set(true);
+            prev_hook(diagnostic);
+        });
+        prev
+    });
+
+    let ret = op();
+
+    TRACK_DIAGNOSTICS.with(|track| {
+        // Drops the hook above, so `prev` is the only reference left.
+        drop(track.replace(Box::new(|_| {})));
+        *track.borrow_mut() = match Rc::try_unwrap(prev) {
+            Ok(prev) => prev,
+            Err(prev) => Box::new(move |diagnostic| prev(diagnostic)),
+        };
+    });
+
+    (ret, emitted.get())
+}
+
+/// Serializes `value` as JSON with sorted object keys, so the key doesn't
+/// depend on the order of fields or the iteration order of maps.
+fn canonical_json<T: Serialize>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    serde_json::to_vec(&serde_json::to_value(value)?)
+}
+
+/// What we actually store on disk.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    code: String,
+    #[serde(default)]
+    map: Option<String>,
+    #[serde(default)]
+    output: Option<String>,
+    #[serde(default)]
+    diagnostics: Vec<String>,
+}
+
+impl From<CacheEntry> for TransformOutput {
+    fn from(e: CacheEntry) -> Self {
+        TransformOutput {
+            code: e.code,
+            map: e.map,
+            output: e.output,
+            diagnostics: e.diagnostics,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct EntryMeta {
+    size: u64,
+    last_access: SystemTime,
+}
+
+#[derive(Default)]
+struct Index {
+    entries: FxHashMap<String, EntryMeta>,
+    total_size: u64,
+}
+
+impl Index {
+    fn insert(&mut self, key: String, meta: EntryMeta) {
+        if let Some(prev) = self.entries.insert(key, meta) {
+            self.total_size -= prev.size;
+        }
+        self.total_size += meta.size;
+    }
+
+    fn remove(&mut self, key: &str) -> Option<EntryMeta> {
+        let meta = self.entries.remove(key)?;
+        self.total_size -= meta.size;
+        Some(meta)
+    }
+
+    /// Returns keys to evict, from the least recently used one, so that the
+    /// total size fits in `max_size`.
+    fn overflow(&self, max_size: u64) -> Vec<String> {
+        if self.total_size <= max_size {
+            return Vec::new();
+        }
+
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(_, meta)| meta.last_access);
+
+        let mut size = self.total_size;
+        let mut evicted = Vec::new();
+        for (key, meta) in entries {
+            if size <= max_size {
+                break;
+            }
+            size -= meta.size;
+            evicted.push(key.clone());
+        }
+        evicted
+    }
+}
+
+/// A persistent transform cache stored in a directory.
+///
+/// A cache is safe to share between threads. Multiple processes may use the
+/// same directory, as entries are written atomically, but each process only
+/// evicts the entries it knows about.
+pub struct TransformCache {
+    root: PathBuf,
+    max_size: u64,
+    index: Mutex<Index>,
+    /// Hashes of plugin binaries, keyed by the configured plugin name.
+    ///
+    /// Like `PLUGIN_MODULE_CACHE`, plugins are not revalidated in a single
+    /// process lifecycle.
+    plugin_hashes: DashMap<String, String, FxBuildHasher>,
+}
+
+impl TransformCache {
+    /// Opens (or creates) a cache at `root`, which keeps at most `max_size`
+    /// bytes of entries.
+    pub fn open(root: impl Into<PathBuf>, max_size: u64) -> Result<Self, Error> {
+        let root = root.into().join(CACHE_FORMAT_VERSION);
+        fs::create_dir_all(&root)
+            .with_context(|| format!("failed to create cache directory at {}", root.display()))?;
+
+        let mut index = Index::default();
+
+        for shard in fs::read_dir(&root)? {
+            let shard = shard?;
+            if !shard.file_type()?.is_dir() {
+                continue;
+            }
+
+            for entry in fs::read_dir(shard.path())? {
+                let entry = entry?;
+                let path = entry.path();
+                if path.extension().and_then(|s| s.to_str()) != Some(ENTRY_EXTENSION) {
+                    continue;
+                }
+                let (Some(key), Ok(meta)) =
+                    (path.file_stem().and_then(|s| s.to_str()), entry.metadata())
+                else {
+                    continue;
+                };
+
+                index.insert(
+                    key.to_string(),
+                    EntryMeta {
+                        size: meta.len(),
+                        last_access: meta.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                    },
+                );
+            }
+        }
+
+        let cache = TransformCache {
+            root,
+            max_size,
+            index: Mutex::new(index),
+            plugin_hashes: Default::default(),
+        };
+        cache.evict();
+
+        Ok(cache)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Total size of the stored entries, in bytes.
+    pub fn size(&self) -> u64 {
+        self.index.lock().total_size
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.root
+            .join(&key[..2])
+            .join(format!("{key}.{ENTRY_EXTENSION}"))
+    }
+
+    /// Returns the stored output for `key`, marking it as recently used.
+    ///
+    /// Unreadable entries are removed and reported as a miss.
+    pub fn get(&self, key: &CacheKey) -> Option<TransformOutput> {
+        let path = self.entry_path(key.as_str());
+
+        let content = match fs::read(&path) {
+            Ok(v) => v,
+            Err(err) => {
+                if err.kind() != ErrorKind::NotFound {
+                    tracing::warn!("failed to read cache entry {}: {err}", path.display());
+                }
+                self.index.lock().remove(key.as_str());
+                return None;
+            }
+        };
+
+        let entry: CacheEntry = match serde_json::from_slice(&content) {
+            Ok(v) => v,
+            Err(err) => {
+                tracing::warn!("removing corrupted cache entry {}: {err}", path.display());
+                let _ = self.invalidate(key);
+                return None;
+            }
+        };
+
+        let now = SystemTime::now();
+        let _ = filetime::set_file_mtime(&path, FileTime::from_system_time(now));
+        self.index.lock().insert(
+            key.0.clone(),
+            EntryMeta {
+                size: content.len() as u64,
+                last_access: now,
+            },
+        );
+
+        Some(entry.into())
+    }
+
+    /// Stores `output` for `key`, evicting least recently used entries if the
+    /// cache grows over its size limit.
+    pub fn insert(&self, key: &CacheKey, output: &TransformOutput) -> Result<(), Error> {
+        let content = serde_json::to_vec(&CacheEntry {
+            code: output.code.clone(),
+            map: output.map.clone(),
+            output: output.output.clone(),
+            diagnostics: output.diagnostics.clone(),
+        })?;
+
+        let path = self.entry_path(key.as_str());
+        let dir = path.parent().expect("cache entry should have a parent");
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create cache directory at {}", dir.display()))?;
+
+        // Write to a temporary file first so other processes never observe a
+        // partially written entry. The name is unique per write, as other threads
+        // may store the same key concurrently.
+        static TMP_ID: AtomicU64 = AtomicU64::new(0);
+        let tmp = dir.join(format!(
+            "{}.{}.{}.tmp",
+            key.as_str(),
+            std::process::id(),
+            TMP_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        {
+            let mut file = fs::File::create(&tmp)
+                .with_context(|| format!("failed to create {}", tmp.display()))?;
+            file.write_all(&content)?;
+        }
+        fs::rename(&tmp, &path)
+            .with_context(|| format!("failed to write cache entry {}", path.display()))?;
+
+        self.index.lock().insert(
+            key.0.clone(),
+            EntryMeta {
+                size: content.len() as u64,
+                last_access: SystemTime::now(),
+            },
+        );
+        self.evict();
+
+        Ok(())
+    }
+
+    /// Removes the entry for `key`. Returns `true` if an entry was removed.
+    pub fn invalidate(&self, key: &CacheKey) -> Result<bool, Error> {
+        self.index.lock().remove(key.as_str());
+
+        match fs::remove_file(self.entry_path(key.as_str())) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) -> Result<(), Error> {
+        let mut index = self.index.lock();
+
+        for key in index.entries.keys() {
+            match fs::remove_file(self.entry_path(key)) {
+                Ok(()) => {}
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        *index = Index::default();
+
+        Ok(())
+    }
+
+    fn evict(&self) {
+        let mut index = self.index.lock();
+
+        for key in index.overflow(self.max_size) {
+            index.remove(&key);
+            if let Err(err) = fs::remove_file(self.entry_path(&key)) {
+                if err.kind() != ErrorKind::NotFound {
+                    tracing::warn!("failed to evict cache entry {key}: {err}");
+                }
+            }
+        }
+    }
+
+    /// Hashes the binaries of `plugins`, resolving them the same way as
+    /// plugins are loaded.
+    pub(crate) fn plugin_hashes(&self, plugins: &[PluginConfig]) -> Result<Vec<String>, Error> {
+        let resolver = CachingResolver::new(
+            40,
+            NodeModulesResolver::new(swc_ecma_loader::TargetEnv::Node, Default::default(), true),
+        );
+
+        plugins
+            .iter()
+            .map(|PluginConfig(name, ..)| {
+                if let Some(hash) = self.plugin_hashes.get(name) {
+                    return Ok(hash.clone());
+                }
+
+                let resolved = resolver
+                    .resolve(&FileName::Real(PathBuf::from(name)), name)
+                    .with_context(|| format!("failed to resolve plugin path: {name}"))?;
+                let FileName::Real(path) = resolved.filename else {
+                    anyhow::bail!("failed to resolve plugin path: {:?}", resolved.filename);
+                };
+                let bytes = fs::read(&path)
+                    .with_context(|| format!("failed to read plugin at {}", path.display()))?;
+                let hash = hex::encode(Sha256::digest(&bytes));
+
+                self.plugin_hashes.insert(name.clone(), hash.clone());
+                Ok(hash)
+            })
+            .collect()
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
     collections::HashMap,
-    env,
+    env, fs,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -30,15 +30,19 @@ use swc_ecma_ast::{noop_pass, EsVersion, Expr, Pass, Program};
 use swc_ecma_ext_transforms::jest;
 use swc_ecma_lints::{
     config::LintConfig,
+    directives::{with_directives, Directives},
     rules::{lint_pass, LintParams},
 };
 use swc_ecma_loader::resolvers::{
-    lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
+    import_map::{ImportMap, ImportMapResolver},
+    lru::CachingResolver,
+    node::NodeModulesResolver,
+    tsc::TsConfigResolver,
 };
 pub use swc_ecma_minifier::js::*;
 use swc_ecma_minifier::option::terser::TerserTopLevelOptions;
-use swc_ecma_parser::{parse_file_as_expr, Syntax, TsSyntax};
-use swc_ecma_preset_env::Feature;
+use swc_ecma_parser::{parse_file_as_expr, EsSyntax, Syntax, TsSyntax};
+use swc_ecma_preset_env::{EnvConfig, EnvReporter, Feature};
 pub use swc_ecma_transforms::proposals::DecoratorVersion;
 use swc_ecma_transforms::{
     hygiene,
@@ -51,7 +55,7 @@ use swc_ecma_transforms::{
     optimization::{const_modules, json_parse, simplifier},
     proposals::{
         decorators, explicit_resource_management::explicit_resource_management,
-        export_default_from, import_attributes,
+        export_default_from, import_attributes, pipeline_operator,
     },
     react::{self, default_pragma, default_pragma_frag},
     resolver,
@@ -68,13 +72,17 @@ use swc_ecma_utils::NodeIgnoringSpan;
 use swc_ecma_visit::VisitMutWith;
 use swc_visit::Optional;
 
-pub use crate::plugin::PluginConfig;
+pub use crate::plugin::{PluginConfig, PluginSandboxConfig};
 use crate::{
-    builder::PassBuilder, dropped_comments_preserver::dropped_comments_preserver, SwcImportResolver,
+    builder::PassBuilder,
+    dropped_comments_preserver::dropped_comments_preserver,
+    explain::{explained, ExplainFormat, TransformExplainer},
+    SwcImportResolver,
 };
 
 #[cfg(test)]
 mod tests;
+pub mod tsconfig;
 
 #[cfg(feature = "plugin")]
 /// A shared instance to plugin's module bytecode cache.
@@ -118,7 +126,54 @@ pub struct ParseOptions {
     pub target: EsVersion,
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+/// Options for [crate::Compiler::format_js].
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsFormatOptions {
+    /// Inferred from the extension of the file if not specified.
+    #[serde(rename = "parser", default)]
+    pub syntax: Option<Syntax>,
+
+    #[serde(flatten)]
+    pub style: swc_ecma_formatter::Config,
+}
+
+impl JsFormatOptions {
+    /// Returns the syntax to parse `file` with.
+    pub fn syntax_for(&self, file: &FileName) -> Syntax {
+        if let Some(syntax) = self.syntax {
+            return syntax;
+        }
+
+        let ext = match file {
+            FileName::Real(path) => path.extension().and_then(|ext| ext.to_str()),
+            _ => None,
+        };
+
+        match ext {
+            Some("ts" | "mts" | "cts") => Syntax::Typescript(TsSyntax {
+                decorators: true,
+                dts: matches!(file, FileName::Real(path) if path.to_string_lossy().ends_with(".d.ts")),
+                ..Default::default()
+            }),
+            Some("tsx") => Syntax::Typescript(TsSyntax {
+                tsx: true,
+                decorators: true,
+                ..Default::default()
+            }),
+            // Like prettier, `.js` files may contain jsx.
+            _ => Syntax::Es(EsSyntax {
+                jsx: true,
+                decorators: true,
+                import_attributes: true,
+                explicit_resource_management: true,
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Options {
     #[serde(flatten)]
@@ -133,10 +188,10 @@ pub struct Options {
     #[serde(skip_deserializing, default)]
     pub disable_fixer: bool,
 
-    #[serde(skip_deserializing, default)]
+    #[serde(skip, default)]
     pub top_level_mark: Option<Mark>,
 
-    #[serde(skip_deserializing, default)]
+    #[serde(skip, default)]
     pub unresolved_mark: Option<Mark>,
 
     #[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
@@ -184,7 +239,7 @@ pub struct Options {
     pub experimental: ExperimentalOptions,
 }
 
-#[derive(Debug, Clone, Default, Deserialize, Merge)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Merge)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ExperimentalOptions {
     #[serde(default)]
@@ -256,6 +311,7 @@ impl Options {
             keep_class_names,
             base_url,
             paths,
+            import_map,
             minify: mut js_minify,
             experimental,
             lints,
@@ -306,6 +362,13 @@ impl Options {
             syntax.typescript(),
         ));
 
+        let env = cfg.env.map(EnvConfig::from);
+        let env_reporter = env.as_ref().and_then(|env| env.reporter());
+
+        let explainer = experimental
+            .explain
+            .map(|format| TransformExplainer::new(cm.clone(), format, base.to_string(), &program));
+
         let default_top_level = program.is_module();
 
         js_minify = js_minify.map(|mut c| {
@@ -523,6 +586,8 @@ impl Options {
         };
 
         let verbatim_module_syntax = transform.verbatim_module_syntax.into_bool();
+        let rewrite_relative_import_extensions =
+            transform.rewrite_relative_import_extensions.into_bool();
 
         let charset = cfg.jsc.output.charset.or_else(|| {
             if js_minify.as_ref()?.format.ascii_only {
@@ -548,7 +613,13 @@ impl Options {
         };
 
         let paths = paths.into_iter().collect();
-        let resolver = ModuleConfig::get_resolver(&base_url, paths, base, cfg.module.as_ref());
+        let resolver = ModuleConfig::get_resolver(
+            &base_url,
+            paths,
+            import_map.as_deref(),
+            base,
+            cfg.module.as_ref(),
+        )?;
 
         let pass = PassBuilder::new(
             cm,
@@ -571,8 +642,9 @@ impl Options {
             })
         })
         .fixer(!self.disable_fixer)
-        .preset_env(cfg.env)
+        .preset_env_config(env)
         .regenerator(regenerator)
+        .explain(explainer.clone())
         .finalize(
             syntax,
             cfg.module,
@@ -663,7 +735,9 @@ impl Options {
                     DecoratorVersion::V202203 => Box::new(
                         swc_ecma_transforms::proposals::decorator_2022_03::decorator_2022_03(),
                     ),
-                    DecoratorVersion::V202311 => todo!("2023-11 decorator"),
+                    DecoratorVersion::V202311 => Box::new(
+                        swc_ecma_transforms::proposals::decorator_2023_11::decorator_2023_11(),
+                    ),
                 };
 
             Box::new((
@@ -674,69 +748,102 @@ impl Options {
                         None
                     },
                     Optional::new(
-                        lint_pass(swc_ecma_lints::rules::all(LintParams {
-                            program: &program,
-                            lint_config: &lints,
-                            top_level_ctxt,
-                            unresolved_ctxt,
-                            es_version,
-                            source_map: cm.clone(),
-                        })),
+                        lint_pass(with_directives(
+                            swc_ecma_lints::rules::all(LintParams {
+                                program: &program,
+                                lint_config: &lints,
+                                top_level_ctxt,
+                                unresolved_ctxt,
+                                es_version,
+                                source_map: cm.clone(),
+                            }),
+                            comments
+                                .map(|comments| Directives::parse(cm, comments))
+                                .unwrap_or_default(),
+                            &lints,
+                        )),
                         !disable_all_lints,
                     ),
                     // Decorators may use type information
-                    Optional::new(decorator_pass, syntax.decorators()),
                     Optional::new(
-                        explicit_resource_management(),
+                        explained(&explainer, "decorators", decorator_pass),
+                        syntax.decorators(),
+                    ),
+                    Optional::new(
+                        explained(
+                            &explainer,
+                            "explicit_resource_management",
+                            explicit_resource_management(),
+                        ),
                         syntax.explicit_resource_management(),
                     ),
+                    Optional::new(
+                        explained(&explainer, "pipeline_operator", pipeline_operator()),
+                        syntax.pipeline_operator(),
+                    ),
                 ),
                 // The transform strips import assertions, so it's only enabled if
                 // keep_import_assertions is false.
                 (
-                    Optional::new(import_attributes(), !keep_import_attributes),
                     Optional::new(
-                        typescript::tsx::<Option<&dyn Comments>>(
-                            cm.clone(),
-                            typescript::Config {
-                                import_export_assign_config,
-                                verbatim_module_syntax,
-                                ..Default::default()
-                            },
-                            typescript::TsxConfig {
-                                pragma: Some(
-                                    transform
-                                        .react
-                                        .pragma
-                                        .clone()
-                                        .unwrap_or_else(default_pragma),
-                                ),
-                                pragma_frag: Some(
-                                    transform
-                                        .react
-                                        .pragma_frag
-                                        .clone()
-                                        .unwrap_or_else(default_pragma_frag),
-                                ),
-                            },
-                            comments.map(|v| v as _),
-                            unresolved_mark,
-                            top_level_mark,
+                        explained(&explainer, "import_attributes", import_attributes()),
+                        !keep_import_attributes,
+                    ),
+                    Optional::new(
+                        explained(
+                            &explainer,
+                            "typescript::strip",
+                            typescript::tsx::<Option<&dyn Comments>>(
+                                cm.clone(),
+                                typescript::Config {
+                                    import_export_assign_config,
+                                    verbatim_module_syntax,
+                                    rewrite_relative_import_extensions,
+                                    ..Default::default()
+                                },
+                                typescript::TsxConfig {
+                                    pragma: Some(
+                                        transform
+                                            .react
+                                            .pragma
+                                            .clone()
+                                            .unwrap_or_else(default_pragma),
+                                    ),
+                                    pragma_frag: Some(
+                                        transform
+                                            .react
+                                            .pragma_frag
+                                            .clone()
+                                            .unwrap_or_else(default_pragma_frag),
+                                    ),
+                                },
+                                comments.map(|v| v as _),
+                                unresolved_mark,
+                                top_level_mark,
+                            ),
                         ),
                         syntax.typescript(),
                     ),
                 ),
                 (
-                    plugin_transforms.take(),
-                    custom_before_pass(&program),
+                    explained(&explainer, "plugins", plugin_transforms.take()),
+                    explained(
+                        &explainer,
+                        "custom_before_pass",
+                        custom_before_pass(&program),
+                    ),
                     // handle jsx
                     Optional::new(
-                        react::react::<&dyn Comments>(
-                            cm.clone(),
-                            comments.map(|v| v as _),
-                            transform.react,
-                            top_level_mark,
-                            unresolved_mark,
+                        explained(
+                            &explainer,
+                            "react",
+                            react::react::<&dyn Comments>(
+                                cm.clone(),
+                                comments.map(|v| v as _),
+                                transform.react,
+                                top_level_mark,
+                                unresolved_mark,
+                            ),
                         ),
                         syntax.jsx(),
                     ),
@@ -780,6 +887,8 @@ impl Options {
             emit_isolated_dts: experimental.emit_isolated_dts.into_bool(),
             unresolved_mark,
             resolver,
+            explainer,
+            env_reporter,
         })
     }
 }
@@ -931,7 +1040,7 @@ impl Rc {
 }
 
 /// A single object in the `.swcrc` file
-#[derive(Debug, Default, Clone, Deserialize, Merge)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Merge)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Config {
     #[serde(default)]
@@ -985,6 +1094,8 @@ impl Config {
     ///
     /// - typescript: `tsx` will be modified if file extension is `ts`.
     pub fn adjust(&mut self, file: &Path) {
+        self.jsc.lints.apply_overrides(file);
+
         if let Some(Syntax::Typescript(TsSyntax { tsx, dts, .. })) = &mut self.jsc.syntax {
             let is_dts = file
                 .file_name()
@@ -1099,6 +1210,12 @@ pub struct BuiltInput<P: Pass> {
     pub emit_isolated_dts: bool,
     pub unresolved_mark: Mark,
     pub resolver: Option<(FileName, Arc<dyn ImportResolver>)>,
+
+    /// Set if `jsc.experimental.explain` is enabled.
+    pub explainer: Option<TransformExplainer>,
+
+    /// Set if `env.report` is enabled.
+    pub env_reporter: Option<EnvReporter>,
 }
 
 impl<P> BuiltInput<P>
@@ -1133,6 +1250,8 @@ where
             emit_isolated_dts: self.emit_isolated_dts,
             unresolved_mark: self.unresolved_mark,
             resolver: self.resolver,
+            explainer: self.explainer,
+            env_reporter: self.env_reporter,
         }
     }
 }
@@ -1168,6 +1287,14 @@ pub struct JscConfig {
     #[serde(default)]
     pub paths: Paths,
 
+    /// Path to an [import map](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps),
+    /// relative to `.swcrc`.
+    ///
+    /// Specifiers mapped to a local file are rewritten to a relative path, and
+    /// specifiers mapped to a URL are rewritten to the URL.
+    #[serde(default)]
+    pub import_map: Option<PathBuf>,
+
     #[serde(default)]
     pub minify: Option<JsMinifyOptions>,
 
@@ -1182,6 +1309,15 @@ pub struct JscConfig {
 
     #[serde(default)]
     pub output: JscOutputConfig,
+
+    /// Reads compiler options from `tsconfig.json`. `true` uses the nearest
+    /// `tsconfig.json` of the input file, and a string is the path of the
+    /// file, relative to `.swcrc`.
+    ///
+    /// Options in `.swcrc` take priority. See [tsconfig::TsConfig::to_config]
+    /// for the supported options.
+    #[serde(default)]
+    pub tsconfig: BoolOrDataConfig<PathBuf>,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Merge)]
@@ -1247,6 +1383,12 @@ pub struct JscExperimental {
 
     #[serde(default)]
     pub disable_all_lints: BoolConfig<true>,
+
+    /// Records which pass changed each top-level statement and emits the
+    /// report to the `output` of the transform. This is slow and meant for
+    /// debugging only.
+    #[serde(default)]
+    pub explain: Option<ExplainFormat>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -1374,13 +1516,15 @@ impl ModuleConfig {
     pub fn get_resolver(
         base_url: &Path,
         paths: CompiledPaths,
+        import_map: Option<&Path>,
         base: &FileName,
         config: Option<&ModuleConfig>,
-    ) -> Option<(FileName, Arc<dyn ImportResolver>)> {
-        let skip_resolver = base_url.as_os_str().is_empty() && paths.is_empty();
+    ) -> Result<Option<(FileName, Arc<dyn ImportResolver>)>, Error> {
+        let skip_resolver =
+            base_url.as_os_str().is_empty() && paths.is_empty() && import_map.is_none();
 
         if skip_resolver {
-            return None;
+            return Ok(None);
         }
 
         let base = match base {
@@ -1391,12 +1535,20 @@ impl ModuleConfig {
         };
 
         let base_url = base_url.to_path_buf();
+        let import_map = import_map.map(Path::to_path_buf);
         let resolver = match config {
-            None => build_resolver(base_url, paths, false, &util::Config::default_js_ext()),
+            None => build_resolver(
+                base_url,
+                paths,
+                import_map,
+                false,
+                &util::Config::default_js_ext(),
+            ),
             Some(ModuleConfig::Es6(config)) | Some(ModuleConfig::NodeNext(config)) => {
                 build_resolver(
                     base_url,
                     paths,
+                    import_map,
                     config.config.resolve_fully,
                     &config.config.out_file_extension,
                 )
@@ -1404,30 +1556,34 @@ impl ModuleConfig {
             Some(ModuleConfig::CommonJs(config)) => build_resolver(
                 base_url,
                 paths,
+                import_map,
                 config.resolve_fully,
                 &config.out_file_extension,
             ),
             Some(ModuleConfig::Umd(config)) => build_resolver(
                 base_url,
                 paths,
+                import_map,
                 config.config.resolve_fully,
                 &config.config.out_file_extension,
             ),
             Some(ModuleConfig::Amd(config)) => build_resolver(
                 base_url,
                 paths,
+                import_map,
                 config.config.resolve_fully,
                 &config.config.out_file_extension,
             ),
             Some(ModuleConfig::SystemJs(config)) => build_resolver(
                 base_url,
                 paths,
+                import_map,
                 config.config.resolve_fully,
                 &config.config.out_file_extension,
             ),
-        };
+        }?;
 
-        Some((base, resolver))
+        Ok(Some((base, resolver)))
     }
 }
 
@@ -1467,6 +1623,10 @@ pub struct TransformConfig {
     #[serde(default)]
     pub verbatim_module_syntax: BoolConfig<false>,
 
+    /// https://www.typescriptlang.org/tsconfig/#rewriteRelativeImportExtensions
+    #[serde(default)]
+    pub rewrite_relative_import_extensions: BoolConfig<false>,
+
     #[serde(default)]
     pub decorator_version: Option<DecoratorVersion>,
 }
@@ -1748,12 +1908,25 @@ pub(crate) fn default_env_name() -> String {
 fn build_resolver(
     mut base_url: PathBuf,
     paths: CompiledPaths,
+    import_map: Option<PathBuf>,
     resolve_fully: bool,
     file_extension: &str,
-) -> SwcImportResolver {
-    static CACHE: Lazy<DashMap<(PathBuf, CompiledPaths, bool), SwcImportResolver, FxBuildHasher>> =
+) -> Result<SwcImportResolver, Error> {
+    // The content of the import map is a part of the key, so that changes to
+    // the file are picked up in watch mode.
+    type CacheKey = (PathBuf, CompiledPaths, Option<(PathBuf, String)>, bool);
+
+    static CACHE: Lazy<DashMap<CacheKey, SwcImportResolver, FxBuildHasher>> =
         Lazy::new(Default::default);
 
+    // Import maps are resolved relative to their own directory, which is a
+    // better default than the current working directory.
+    if base_url.as_os_str().is_empty() {
+        if let Some(dir) = import_map.as_deref().and_then(Path::parent) {
+            base_url = dir.to_path_buf();
+        }
+    }
+
     // On Windows, we need to normalize path as UNC path.
     if cfg!(target_os = "windows") {
         base_url = base_url
@@ -1768,8 +1941,22 @@ fn build_resolver(
             .unwrap();
     }
 
-    if let Some(cached) = CACHE.get(&(base_url.clone(), paths.clone(), resolve_fully)) {
-        return cached.clone();
+    let import_map = import_map
+        .map(|path| {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("failed to load import map at {}", path.display()))?;
+            Ok::<_, Error>((path, content))
+        })
+        .transpose()?;
+
+    let key = (
+        base_url.clone(),
+        paths.clone(),
+        import_map.clone(),
+        resolve_fully,
+    );
+    if let Some(cached) = CACHE.get(&key) {
+        return Ok(cached.clone());
     }
 
     let r = {
@@ -1782,6 +1969,16 @@ fn build_resolver(
         let r = CachingResolver::new(1024, r);
 
         let r = TsConfigResolver::new(r, base_url.clone(), paths.clone());
+
+        let r = match &import_map {
+            Some((path, content)) => ImportMapResolver::new(
+                r,
+                serde_json::from_str::<ImportMap>(content)
+                    .with_context(|| format!("failed to load import map at {}", path.display()))?,
+                path.parent().unwrap_or(&base_url),
+            ),
+            None => ImportMapResolver::new(r, Default::default(), &base_url),
+        };
         let r = CachingResolver::new(256, r);
 
         let r = NodeImportResolver::with_config(
@@ -1795,7 +1992,7 @@ fn build_resolver(
         Arc::new(r)
     };
 
-    CACHE.insert((base_url, paths, resolve_fully), r.clone());
+    CACHE.insert(key, r.clone());
 
-    r
+    Ok(r)
 }
@@ -1,3 +1,8 @@
+use std::path::Path;
+
+use swc_common::FileName;
+
+use super::{ModuleConfig, PluginSandboxConfig, Rc};
 use crate::parse_swcrc;
 
 #[test]
@@ -30,3 +35,46 @@ fn issue_6996() {
     let rc = parse_swcrc(include_str!("issue-6996.json")).expect("failed to parse");
     dbg!(&rc);
 }
+
+#[test]
+fn plugin_sandbox() {
+    let rc = parse_swcrc(include_str!("plugin-sandbox.json")).expect("failed to parse");
+    let Rc::Single(config) = rc else {
+        panic!("expected a single config");
+    };
+    let plugins = config
+        .jsc
+        .experimental
+        .plugins
+        .expect("plugins should exist");
+
+    assert_eq!(plugins[0].2, PluginSandboxConfig::default());
+    assert_eq!(
+        plugins[1].2,
+        PluginSandboxConfig {
+            fuel: Some(1_000_000),
+            max_memory: Some(64 * 1024 * 1024),
+            fs: [("/cwd".to_string(), ".".into())].into_iter().collect(),
+            env: vec!["NODE_ENV".into()],
+            deterministic: true,
+        }
+    );
+}
+
+#[test]
+fn missing_import_map() {
+    let Err(err) = ModuleConfig::get_resolver(
+        Path::new(""),
+        Default::default(),
+        Some(Path::new("does-not-exist/import-map.json")),
+        &FileName::Anon,
+        None,
+    ) else {
+        panic!("a missing import map should be an error");
+    };
+
+    assert_eq!(
+        err.to_string(),
+        "failed to load import map at does-not-exist/import-map.json"
+    );
+}
@@ -0,0 +1,405 @@
+//! Support for `tsconfig.json` as a source of [Config]. See
+//! [JscConfig::tsconfig](super::JscConfig::tsconfig).
+
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Error};
+use jsonc_parser::{parse_to_serde_value, ParseOptions};
+use serde_json::{json, Map, Value};
+use swc_config::merge::Merge;
+use swc_ecma_parser::Syntax;
+
+use super::Config;
+
+/// Compiler options which change the emitted code but can't be honored by
+/// swc.
+const UNSUPPORTED_OPTIONS: &[&str] = &[
+    "declaration",
+    "emitBOM",
+    "emitDeclarationOnly",
+    "importsNotUsedAsValues",
+    "noEmitHelpers",
+    "out",
+    "outFile",
+    "preserveValueImports",
+    "removeComments",
+];
+
+/// A `tsconfig.json` file, with its `extends` chain resolved.
+#[derive(Debug, Default, Clone)]
+pub struct TsConfig {
+    /// The path of the loaded file.
+    pub path: PathBuf,
+
+    /// `compilerOptions`, where the options of a config override the ones it
+    /// extends.
+    ///
+    /// `baseUrl` is made absolute.
+    pub compiler_options: Map<String, Value>,
+
+    /// The directory of the config defining `paths`, which is the base of the
+    /// patterns if `baseUrl` is not set.
+    paths_base: Option<PathBuf>,
+}
+
+impl TsConfig {
+    /// Loads `path` and the configs it extends.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let mut stack = Vec::new();
+
+        load(path, &mut stack)
+    }
+
+    /// Merges the compiler options into `config`, with the options of `config`
+    /// taking priority.
+    pub fn apply(&self, config: &mut Config, file: Option<&Path>) -> Result<(), Error> {
+        config.merge(self.to_config(file)?);
+
+        // The parser config of `.swcrc` can't be told apart from the default
+        // one, so decorators are enabled on top of it.
+        if let Some(Syntax::Typescript(syntax)) = &mut config.jsc.syntax {
+            syntax.decorators |= self.bool_option("experimentalDecorators") == Some(true);
+        }
+
+        Ok(())
+    }
+
+    /// Maps the compiler options to a [Config] for `file`.
+    ///
+    /// The parser is only configured for TypeScript files.
+    pub fn to_config(&self, file: Option<&Path>) -> Result<Config, Error> {
+        let options = &self.compiler_options;
+        let mut jsc = Map::new();
+        let mut transform = Map::new();
+        let mut react = Map::new();
+        let mut root = Map::new();
+
+        let is_ts = file
+            .and_then(|file| file.extension())
+            .is_some_and(|ext| matches!(ext.to_str(), Some("ts" | "tsx" | "mts" | "cts")));
+        if is_ts {
+            let tsx = file.and_then(|file| file.extension()) == Some("tsx".as_ref());
+
+            jsc.insert(
+                "parser".into(),
+                json!({
+                    "syntax": "typescript",
+                    "tsx": tsx,
+                    "decorators": self.bool_option("experimentalDecorators") == Some(true),
+                }),
+            );
+        }
+
+        let target = self.target();
+        if let Some(target) = target {
+            jsc.insert("target".into(), target.into());
+        }
+
+        if let Some(base_url) = options.get("baseUrl") {
+            jsc.insert("baseUrl".into(), base_url.clone());
+        }
+        if let Some(paths) = options.get("paths") {
+            if !options.contains_key("baseUrl") {
+                if let Some(base) = &self.paths_base {
+                    jsc.insert("baseUrl".into(), base.to_string_lossy().into());
+                }
+            }
+            jsc.insert("paths".into(), paths.clone());
+        }
+
+        if let Some(v) = options.get("importHelpers") {
+            jsc.insert("externalHelpers".into(), v.clone());
+        }
+
+        if self.bool_option("experimentalDecorators") == Some(true) {
+            transform.insert("legacyDecorator".into(), true.into());
+        }
+        if let Some(v) = options.get("emitDecoratorMetadata") {
+            transform.insert("decoratorMetadata".into(), v.clone());
+        }
+        if let Some(v) = options.get("verbatimModuleSyntax") {
+            transform.insert("verbatimModuleSyntax".into(), v.clone());
+        }
+        if let Some(v) = options.get("rewriteRelativeImportExtensions") {
+            transform.insert("rewriteRelativeImportExtensions".into(), v.clone());
+        }
+        match options.get("useDefineForClassFields") {
+            Some(v) => {
+                transform.insert("useDefineForClassFields".into(), v.clone());
+            }
+            // tsc defaults to `true` only for ES2022 and later.
+            None => {
+                if let Some(target) = target {
+                    if !matches!(target, "es2022" | "es2023" | "es2024" | "esnext") {
+                        transform.insert("useDefineForClassFields".into(), false.into());
+                    }
+                }
+            }
+        }
+
+        match self.string_option("jsx").as_deref() {
+            Some("react") => {
+                react.insert("runtime".into(), "classic".into());
+            }
+            Some("react-jsx") => {
+                react.insert("runtime".into(), "automatic".into());
+            }
+            Some("react-jsxdev") => {
+                react.insert("runtime".into(), "automatic".into());
+                react.insert("development".into(), true.into());
+            }
+            _ => {}
+        }
+        for (from, to) in [
+            ("jsxFactory", "pragma"),
+            ("jsxFragmentFactory", "pragmaFrag"),
+            ("jsxImportSource", "importSource"),
+        ] {
+            if let Some(v) = options.get(from) {
+                react.insert(to.into(), v.clone());
+            }
+        }
+
+        if let Some(module) = self.module_type() {
+            let mut module = json!({ "type": module });
+            if self.bool_option("esModuleInterop") == Some(false)
+                && matches!(module["type"].as_str(), Some("commonjs" | "amd" | "umd"))
+            {
+                module["noInterop"] = true.into();
+            }
+            root.insert("module".into(), module);
+        }
+
+        if self.bool_option("inlineSourceMap") == Some(true) {
+            root.insert("sourceMaps".into(), "inline".into());
+        } else if let Some(v) = options.get("sourceMap") {
+            root.insert("sourceMaps".into(), v.clone());
+        }
+        if let Some(v) = options.get("inlineSources") {
+            root.insert("inlineSourcesContent".into(), v.clone());
+        }
+
+        if !react.is_empty() {
+            transform.insert("react".into(), react.into());
+        }
+        if !transform.is_empty() {
+            jsc.insert("transform".into(), transform.into());
+        }
+        root.insert("jsc".into(), jsc.into());
+
+        serde_json::from_value(root.into())
+            .with_context(|| format!("failed to map {} to swc config", self.path.display()))
+    }
+
+    /// Returns the names of the compiler options which are set but ignored by
+    /// [TsConfig::to_config].
+    pub fn unsupported_options(&self) -> Vec<String> {
+        let options = &self.compiler_options;
+        let mut unsupported = Vec::new();
+
+        for &name in UNSUPPORTED_OPTIONS {
+            match options.get(name) {
+                None | Some(Value::Bool(false)) | Some(Value::Null) => {}
+                Some(_) => unsupported.push(name.to_string()),
+            }
+        }
+
+        if let Some(jsx) = self.string_option("jsx") {
+            if !matches!(&*jsx, "react" | "react-jsx" | "react-jsxdev") {
+                unsupported.push(format!("jsx: {jsx}"));
+            }
+        }
+        if let Some(module) = self.string_option("module") {
+            if self.module_type().is_none() {
+                unsupported.push(format!("module: {module}"));
+            }
+        }
+        if let Some(target) = self.string_option("target") {
+            if self.target().is_none() {
+                unsupported.push(format!("target: {target}"));
+            }
+        }
+
+        unsupported
+    }
+
+    fn bool_option(&self, name: &str) -> Option<bool> {
+        self.compiler_options.get(name).and_then(Value::as_bool)
+    }
+
+    /// Option values of tsc are case-insensitive.
+    fn string_option(&self, name: &str) -> Option<String> {
+        self.compiler_options
+            .get(name)
+            .and_then(Value::as_str)
+            .map(str::to_ascii_lowercase)
+    }
+
+    fn target(&self) -> Option<&'static str> {
+        Some(match &*self.string_option("target")? {
+            "es3" => "es3",
+            "es5" => "es5",
+            "es6" | "es2015" => "es2015",
+            "es2016" => "es2016",
+            "es2017" => "es2017",
+            "es2018" => "es2018",
+            "es2019" => "es2019",
+            "es2020" => "es2020",
+            "es2021" => "es2021",
+            "es2022" => "es2022",
+            "es2023" => "es2023",
+            "es2024" => "es2024",
+            "esnext" => "esnext",
+            _ => return None,
+        })
+    }
+
+    fn module_type(&self) -> Option<&'static str> {
+        Some(match &*self.string_option("module")? {
+            "commonjs" => "commonjs",
+            "amd" => "amd",
+            "umd" => "umd",
+            "system" => "systemjs",
+            "es6" | "es2015" | "es2020" | "es2022" | "esnext" => "es6",
+            "node16" | "node18" | "nodenext" => "nodenext",
+            _ => return None,
+        })
+    }
+}
+
+/// Finds the nearest `tsconfig.json` of `file`.
+pub(crate) fn find_tsconfig(file: &Path) -> Option<PathBuf> {
+    file.ancestors()
+        .skip(1)
+        .map(|dir| dir.join("tsconfig.json"))
+        .find(|path| path.is_file())
+}
+
+fn load(path: &Path, stack: &mut Vec<PathBuf>) -> Result<TsConfig, Error> {
+    if stack.iter().any(|p| p == path) {
+        bail!(
+            "circularity detected while resolving `extends` of {}",
+            path.display()
+        );
+    }
+
+    let content = read_to_string(path)
+        .with_context(|| format!("failed to read tsconfig file at {}", path.display()))?;
+    let value = parse_to_serde_value(
+        content.trim_start_matches('\u{feff}'),
+        &ParseOptions {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            allow_loose_object_property_names: false,
+        },
+    )
+    .with_context(|| format!("failed to parse tsconfig file at {}", path.display()))?;
+
+    let Some(Value::Object(mut value)) = value else {
+        bail!("tsconfig file at {} should be an object", path.display());
+    };
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let extends = match value.remove("extends") {
+        None => Vec::new(),
+        Some(Value::String(s)) => vec![s],
+        Some(Value::Array(v)) => v
+            .into_iter()
+            .map(|v| match v {
+                Value::String(s) => Ok(s),
+                _ => bail!(
+                    "`extends` of {} should be an array of strings",
+                    path.display()
+                ),
+            })
+            .collect::<Result<_, _>>()?,
+        Some(_) => bail!(
+            "`extends` of {} should be a string or an array",
+            path.display()
+        ),
+    };
+
+    let mut config = TsConfig {
+        path: path.to_path_buf(),
+        ..Default::default()
+    };
+
+    stack.push(path.to_path_buf());
+    for specifier in extends {
+        let base_path = resolve_extends(dir, &specifier).with_context(|| {
+            format!(
+                "failed to resolve `extends` ({specifier}) of {}",
+                path.display()
+            )
+        })?;
+        let base = load(&base_path, stack)?;
+
+        config.compiler_options.extend(base.compiler_options);
+        if base.paths_base.is_some() {
+            config.paths_base = base.paths_base;
+        }
+    }
+    stack.pop();
+
+    if let Some(Value::Object(mut options)) = value.remove("compilerOptions") {
+        if let Some(Value::String(base_url)) = options.get("baseUrl") {
+            let base_url = dir.join(base_url);
+            let base_url = base_url.canonicalize().unwrap_or(base_url);
+            options.insert("baseUrl".into(), base_url.to_string_lossy().into());
+        }
+        if options.contains_key("paths") {
+            config.paths_base = Some(dir.to_path_buf());
+        }
+
+        config.compiler_options.extend(options);
+    }
+
+    Ok(config)
+}
+
+/// Resolves `specifier` like tsc does, either as a path or as a file in a
+/// package.
+fn resolve_extends(dir: &Path, specifier: &str) -> Result<PathBuf, Error> {
+    let with_json = |path: PathBuf| {
+        if path.is_file() {
+            return Some(path);
+        }
+
+        let mut name = path.into_os_string();
+        name.push(".json");
+        let path = PathBuf::from(name);
+        path.is_file().then_some(path)
+    };
+
+    if specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || Path::new(specifier).is_absolute()
+    {
+        return with_json(dir.join(specifier)).context("file not found");
+    }
+
+    for dir in dir.ancestors() {
+        let package = dir.join("node_modules").join(specifier);
+
+        if let Some(path) = with_json(package.clone()) {
+            return Ok(path);
+        }
+
+        if package.is_dir() {
+            let tsconfig = read_to_string(package.join("package.json"))
+                .ok()
+                .and_then(|s| serde_json::from_str::<Value>(&s).ok())
+                .and_then(|v| v.get("tsconfig")?.as_str().map(|s| package.join(s)))
+                .unwrap_or_else(|| package.join("tsconfig.json"));
+
+            if tsconfig.is_file() {
+                return Ok(tsconfig);
+            }
+        }
+    }
+
+    bail!("package not found")
+}
@@ -0,0 +1,336 @@
+//! Transform explain mode (`jsc.experimental.explain`).
+//!
+//! When enabled, the program is snapshotted after each major pass and the
+//! top-level statements which were modified, added or removed by the pass are
+//! recorded. Statements are matched by their original spans, so the report
+//! tells which pass changed a statement of the input file. The report is
+//! separate from the source map of the output, which doesn't record passes.
+//!
+//! The report is emitted to the `output` of
+//! [TransformOutput](crate::TransformOutput) using the key
+//! [EXPLAIN_OUTPUT_KEY].
+
+use std::{cell::RefCell, fmt::Write, rc::Rc};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use swc_common::{sync::Lrc, EqIgnoreSpan, SourceMap, Span, Spanned};
+use swc_ecma_ast::{Ident, ModuleItem, Pass, Program};
+use swc_ecma_codegen::to_code;
+
+/// Key of the report in the `output` of
+/// [TransformOutput](crate::TransformOutput).
+pub const EXPLAIN_OUTPUT_KEY: &str = "__swc_transform_explain__";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExplainFormat {
+    #[default]
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "html")]
+    Html,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainReport {
+    pub file: String,
+    pub passes: Vec<PassReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PassReport {
+    pub name: String,
+    pub changes: Vec<StmtChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    Modified,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StmtChange {
+    pub kind: ChangeKind,
+    /// Location of the statement in the input file. [None] if the statement
+    /// was synthesized by a pass.
+    pub loc: Option<SourceLoc>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceLoc {
+    pub start: u32,
+    pub end: u32,
+    /// 1-based line.
+    pub line: usize,
+    /// 0-based column.
+    pub column: usize,
+}
+
+type Snapshot = Vec<ModuleItem>;
+
+struct State {
+    report: ExplainReport,
+    last: Snapshot,
+}
+
+/// Shared between all checkpoints of a single transform.
+#[derive(Clone)]
+pub struct TransformExplainer {
+    cm: Lrc<SourceMap>,
+    format: ExplainFormat,
+    state: Rc<RefCell<State>>,
+}
+
+impl TransformExplainer {
+    /// `program` is the parsed input, which is used as the baseline for the
+    /// first checkpoint.
+    pub fn new(cm: Lrc<SourceMap>, format: ExplainFormat, file: String, program: &Program) -> Self {
+        TransformExplainer {
+            cm,
+            format,
+            state: Rc::new(RefCell::new(State {
+                report: ExplainReport {
+                    file,
+                    passes: Default::default(),
+                },
+                last: snapshot(program),
+            })),
+        }
+    }
+
+    /// Records the changes made to `program` since the previous checkpoint as
+    /// the changes of the pass `name`.
+    pub fn checkpoint(&self, name: &str, program: &Program) {
+        let current = snapshot(program);
+        let mut state = self.state.borrow_mut();
+
+        let changes = diff(&state.last, &current)
+            .into_iter()
+            .map(|(kind, span, before, after)| StmtChange {
+                kind,
+                loc: self.loc(span),
+                before,
+                after,
+            })
+            .collect();
+
+        state.report.passes.push(PassReport {
+            name: name.to_string(),
+            changes,
+        });
+        state.last = current;
+    }
+
+    fn loc(&self, span: Span) -> Option<SourceLoc> {
+        if span.is_dummy() {
+            return None;
+        }
+
+        let loc = self.cm.lookup_char_pos(span.lo);
+
+        Some(SourceLoc {
+            start: span.lo.0,
+            end: span.hi.0,
+            line: loc.line,
+            column: loc.col_display,
+        })
+    }
+
+    pub fn report(&self) -> ExplainReport {
+        self.state.borrow().report.clone()
+    }
+
+    /// Renders the report in the configured format.
+    pub fn render(&self) -> String {
+        let report = self.report();
+
+        match self.format {
+            ExplainFormat::Json => {
+                serde_json::to_string(&report).expect("failed to serialize explain report")
+            }
+            ExplainFormat::Html => render_html(&report),
+        }
+    }
+}
+
+/// Used for the phases of the minifier.
+impl swc_ecma_minifier::observer::PhaseObserver for TransformExplainer {
+    fn after_phase(&self, phase: &'static str, program: &Program) {
+        self.checkpoint(&format!("minifier::{phase}"), program);
+    }
+}
+
+/// Wraps `pass` so that a checkpoint named `name` is recorded after it, if
+/// explain mode is enabled.
+pub(crate) fn explained<P>(
+    explainer: &Option<TransformExplainer>,
+    name: &'static str,
+    pass: P,
+) -> impl Pass
+where
+    P: Pass,
+{
+    Explained {
+        explainer: explainer.clone(),
+        name,
+        pass,
+    }
+}
+
+struct Explained<P> {
+    explainer: Option<TransformExplainer>,
+    name: &'static str,
+    pass: P,
+}
+
+impl<P> Pass for Explained<P>
+where
+    P: Pass,
+{
+    fn process(&mut self, program: &mut Program) {
+        self.pass.process(program);
+
+        if let Some(explainer) = &self.explainer {
+            explainer.checkpoint(self.name, program);
+        }
+    }
+}
+
+fn snapshot(program: &Program) -> Snapshot {
+    match program {
+        Program::Module(m) => m.body.clone(),
+        Program::Script(s) => s.body.iter().cloned().map(ModuleItem::Stmt).collect(),
+    }
+}
+
+type Change = (ChangeKind, Span, Option<String>, Option<String>);
+
+/// Statements are compared structurally, ignoring spans and syntax contexts,
+/// so passes which only assign marks, like `resolver`, don't report changes.
+/// Only the changed statements are printed.
+fn diff(prev: &Snapshot, current: &Snapshot) -> Vec<Change> {
+    let mut prev_by_span = FxHashMap::<Span, Vec<usize>>::default();
+    for (j, item) in prev.iter().enumerate() {
+        prev_by_span.entry(item.span()).or_default().push(j);
+    }
+
+    let mut unmatched_prev = vec![true; prev.len()];
+    let mut unmatched_current = vec![true; current.len()];
+
+    // Identical statements.
+    for (i, item) in current.iter().enumerate() {
+        let Some(candidates) = prev_by_span.get(&item.span()) else {
+            continue;
+        };
+
+        if let Some(&j) = candidates.iter().find(|&&j| {
+            unmatched_prev[j] && Ident::within_ignored_ctxt(|| prev[j].eq_ignore_span(item))
+        }) {
+            unmatched_prev[j] = false;
+            unmatched_current[i] = false;
+        }
+    }
+
+    let mut changes = Vec::new();
+
+    // Statements with the same original span but different code.
+    for (i, item) in current.iter().enumerate() {
+        if !unmatched_current[i] {
+            continue;
+        }
+
+        let span = item.span();
+        let prev_idx = if span.is_dummy() {
+            None
+        } else {
+            prev_by_span
+                .get(&span)
+                .and_then(|candidates| candidates.iter().copied().find(|&j| unmatched_prev[j]))
+        };
+
+        match prev_idx {
+            Some(j) => {
+                unmatched_prev[j] = false;
+                changes.push((
+                    ChangeKind::Modified,
+                    span,
+                    Some(to_code(&prev[j])),
+                    Some(to_code(item)),
+                ));
+            }
+            None => {
+                changes.push((ChangeKind::Added, span, None, Some(to_code(item))));
+            }
+        }
+    }
+
+    for (j, item) in prev.iter().enumerate() {
+        if unmatched_prev[j] {
+            changes.push((ChangeKind::Removed, item.span(), Some(to_code(item)), None));
+        }
+    }
+
+    changes
+}
+
+fn render_html(report: &ExplainReport) -> String {
+    fn escape(s: &str) -> String {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    let mut buf = String::new();
+
+    let _ = write!(
+        buf,
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>swc transform explain: \
+         {}</title></head><body><h1>{}</h1>",
+        escape(&report.file),
+        escape(&report.file)
+    );
+
+    for pass in &report.passes {
+        if pass.changes.is_empty() {
+            continue;
+        }
+
+        let _ = write!(
+            buf,
+            "<h2>{}</h2><table \
+             border=\"1\"><tr><th>kind</th><th>location</th><th>before</th><th>after</th></tr>",
+            escape(&pass.name)
+        );
+
+        for change in &pass.changes {
+            let loc = change
+                .loc
+                .map(|l| format!("{}:{}", l.line, l.column))
+                .unwrap_or_else(|| "(synthesized)".into());
+
+            let _ = write!(
+                buf,
+                "<tr><td>{:?}</td><td>{}</td><td><pre>{}</pre></td><td><pre>{}</pre></td></tr>",
+                change.kind,
+                loc,
+                escape(change.before.as_deref().unwrap_or_default()),
+                escape(change.after.as_deref().unwrap_or_default()),
+            );
+        }
+
+        buf.push_str("</table>");
+    }
+
+    buf.push_str("</body></html>");
+    buf
+}
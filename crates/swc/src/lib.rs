@@ -126,20 +126,30 @@ use common::{
     comments::{Comment, SingleThreadedComments},
     errors::HANDLER,
 };
+use dashmap::DashSet;
 use jsonc_parser::{parse_to_serde_value, ParseOptions};
 use once_cell::sync::Lazy;
 use serde_json::error::Category;
 pub use sourcemap;
 use swc_common::{
-    comments::Comments, errors::Handler, sync::Lrc, FileName, Mark, SourceFile, SourceMap, Spanned,
-    GLOBALS,
+    comments::Comments,
+    errors::{Diagnostic, Handler},
+    sync::Lrc,
+    FileName, Mark, SourceFile, SourceMap, Spanned, SyntaxContext, GLOBALS,
 };
 pub use swc_compiler_base::{PrintArgs, TransformOutput};
+use swc_config::merge::Merge;
 pub use swc_config::types::{BoolConfig, BoolOr, BoolOrDataConfig};
 use swc_ecma_ast::{noop_pass, EsVersion, Pass, Program};
 use swc_ecma_codegen::{to_code_with_comments, Node};
+use swc_ecma_lints::{
+    directives::{with_directives, Directives},
+    fix::capture_diagnostics,
+    rules::{lint_pass, LintParams},
+};
 use swc_ecma_loader::resolvers::{
-    lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
+    import_map::ImportMapResolver, lru::CachingResolver, node::NodeModulesResolver,
+    tsc::TsConfigResolver,
 };
 use swc_ecma_minifier::option::{MangleCache, MinifyOptions, TopLevelOptions};
 use swc_ecma_parser::{EsSyntax, Syntax};
@@ -161,22 +171,40 @@ use tracing::warn;
 use url::Url;
 
 pub use crate::builder::PassBuilder;
-use crate::config::{
-    BuiltInput, Config, ConfigFile, InputSourceMap, IsModule, JsMinifyCommentOption,
-    JsMinifyOptions, Options, OutputCharset, Rc, RootMode, SourceMapsConfig,
+use crate::{
+    cache::{CacheKey, TransformCache},
+    config::{
+        tsconfig::{find_tsconfig, TsConfig},
+        BuiltInput, Config, ConfigFile, InputSourceMap, IsModule, JsFormatOptions,
+        JsMinifyCommentOption, JsMinifyOptions, Options, OutputCharset, Rc, RootMode,
+        SourceMapsConfig,
+    },
 };
 
 mod builder;
+pub mod cache;
 pub mod config;
 mod dropped_comments_preserver;
+pub mod explain;
 mod plugin;
 pub mod wasm_analysis;
+
+/// Key of the [swc_ecma_preset_env::EnvReport] in the `output` of
+/// [TransformOutput], which is emitted if `env.report` is enabled.
+pub const PRESET_ENV_REPORT_OUTPUT_KEY: &str = "__swc_preset_env_report__";
 pub mod resolver {
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
 
+    use anyhow::Error;
     use rustc_hash::FxHashMap;
     use swc_ecma_loader::{
-        resolvers::{lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver},
+        resolve::Resolve,
+        resolvers::{
+            import_map::{ImportMap, ImportMapResolver},
+            lru::CachingResolver,
+            node::NodeModulesResolver,
+            tsc::TsConfigResolver,
+        },
         TargetEnv,
     };
 
@@ -209,10 +237,26 @@ pub mod resolver {
             NodeModulesResolver::new(target_env, alias, preserve_symlinks),
         )
     }
+
+    /// Applies the import map at `path` before resolving with `inner`.
+    pub fn import_map_resolver<R>(inner: R, path: &Path) -> Result<ImportMapResolver<R>, Error>
+    where
+        R: Resolve,
+    {
+        let import_map = ImportMap::from_file(path)?;
+
+        Ok(ImportMapResolver::new(
+            inner,
+            import_map,
+            path.parent().unwrap_or(Path::new(".")),
+        ))
+    }
 }
 
 type SwcImportResolver = Arc<
-    NodeImportResolver<CachingResolver<TsConfigResolver<CachingResolver<NodeModulesResolver>>>>,
+    NodeImportResolver<
+        CachingResolver<ImportMapResolver<TsConfigResolver<CachingResolver<NodeModulesResolver>>>>,
+    >,
 >;
 
 /// All methods accept [Handler], which is a storage for errors.
@@ -223,6 +267,7 @@ pub struct Compiler {
     /// CodeMap
     pub cm: Arc<SourceMap>,
     comments: SwcComments,
+    transform_cache: Option<Arc<TransformCache>>,
 }
 
 /// These are **low-level** apis.
@@ -464,11 +509,77 @@ impl Compiler {
         Compiler {
             cm,
             comments: Default::default(),
+            transform_cache: None,
         }
     }
 
+    /// Enables the persistent transform cache for
+    /// [process_js_file](Self::process_js_file).
+    ///
+    /// See [TransformCache] for what is part of the cache key.
+    pub fn with_transform_cache(mut self, cache: Arc<TransformCache>) -> Self {
+        self.transform_cache = Some(cache);
+        self
+    }
+
+    pub fn transform_cache(&self) -> Option<&Arc<TransformCache>> {
+        self.transform_cache.as_ref()
+    }
+
+    /// Reads `.swcrc` for `name`, and `tsconfig.json` if `jsc.tsconfig` is
+    /// set.
+    ///
+    /// The returned config doesn't include [Options::config].
     #[tracing::instrument(skip_all)]
     pub fn read_config(&self, opts: &Options, name: &FileName) -> Result<Option<Config>, Error> {
+        let Some(mut config) = self.read_swcrc(opts, name)? else {
+            return Ok(None);
+        };
+
+        let tsconfig = opts
+            .config
+            .jsc
+            .tsconfig
+            .clone()
+            .or(|| config.jsc.tsconfig.clone());
+        let filename_path = match name {
+            FileName::Real(p) => Some(&**p),
+            _ => None,
+        };
+        let tsconfig_path = match tsconfig.into_inner() {
+            Some(BoolOr::Data(path)) => Some(opts.cwd.join(path)),
+            Some(BoolOr::Bool(true)) => filename_path.and_then(find_tsconfig),
+            _ => None,
+        };
+
+        if let Some(path) = tsconfig_path {
+            let tsconfig = TsConfig::load(&path)?;
+
+            for option in tsconfig.unsupported_options() {
+                static WARNED: Lazy<DashSet<(PathBuf, String)>> = Lazy::new(Default::default);
+
+                if !WARNED.insert((path.clone(), option.clone())) {
+                    continue;
+                }
+
+                let msg = format!(
+                    "tsconfig option `{option}` is not supported by swc and will be ignored ({})",
+                    path.display()
+                );
+                if HANDLER.is_set() {
+                    HANDLER.with(|handler| handler.warn(&msg));
+                } else {
+                    warn!("{}", msg);
+                }
+            }
+
+            tsconfig.apply(&mut config, filename_path)?;
+        }
+
+        Ok(Some(config))
+    }
+
+    fn read_swcrc(&self, opts: &Options, name: &FileName) -> Result<Option<Config>, Error> {
         static CUR_DIR: Lazy<PathBuf> = Lazy::new(|| {
             if cfg!(target_arch = "wasm32") {
                 PathBuf::new()
@@ -524,6 +635,9 @@ impl Compiler {
                         .context("failed to process config file")?;
 
                     if let Some(c) = &mut config {
+                        c.jsc.tsconfig = c.jsc.tsconfig.clone().map(|path| dir.join(path));
+                        c.jsc.import_map = c.jsc.import_map.clone().map(|path| dir.join(path));
+
                         if c.jsc.base_url != PathBuf::new() {
                             let joined = dir.join(&c.jsc.base_url);
                             c.jsc.base_url = if cfg!(target_os = "windows")
@@ -736,15 +850,177 @@ impl Compiler {
         handler: &Handler,
         opts: &Options,
     ) -> Result<TransformOutput, Error> {
-        self.process_js_with_custom_pass(
-            fm,
-            None,
-            handler,
-            opts,
-            SingleThreadedComments::default(),
-            |_| noop_pass(),
-            |_| noop_pass(),
-        )
+        let cache_key = match &self.transform_cache {
+            Some(cache) => self.transform_cache_key(cache, &fm, opts)?,
+            None => None,
+        };
+
+        if let (Some(cache), Some(key)) = (&self.transform_cache, &cache_key) {
+            if let Some(output) = cache.get(key) {
+                return Ok(output);
+            }
+        }
+
+        let (output, emitted_diagnostics) = cache::track_diagnostics(|| {
+            self.process_js_with_custom_pass(
+                fm,
+                None,
+                handler,
+                opts,
+                SingleThreadedComments::default(),
+                |_| noop_pass(),
+                |_| noop_pass(),
+            )
+        });
+        let output = output?;
+
+        if let (Some(cache), Some(key)) = (&self.transform_cache, &cache_key) {
+            // A cache hit can't report the diagnostics of this run again.
+            if !emitted_diagnostics && !handler.has_errors() {
+                if let Err(err) = cache.insert(key, &output) {
+                    warn!("failed to store transform output in cache: {:?}", err);
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Runs the lint rules configured by `jsc.lints` on `fm`, without
+    /// transforming it.
+    ///
+    /// Parse errors are reported to `handler`, but the diagnostics of the lint
+    /// rules are returned so that the caller can apply the fixes attached to
+    /// them. See [swc_ecma_lints::fix].
+    ///
+    /// Returns an empty list if the file is ignored by `.swcrc`.
+    pub fn lint_js_file(
+        &self,
+        fm: Arc<SourceFile>,
+        handler: &Handler,
+        opts: &Options,
+    ) -> Result<Vec<Diagnostic>, Error> {
+        self.run(|| {
+            if let FileName::Real(ref path) = *fm.name {
+                if !opts.config.matches(path)? {
+                    return Ok(Vec::new());
+                }
+            }
+
+            let Some(swcrc) = self.read_config(opts, &fm.name)? else {
+                return Ok(Vec::new());
+            };
+
+            let mut config = opts.config.clone();
+            config.merge(swcrc);
+            if let FileName::Real(ref path) = *fm.name {
+                config.adjust(path);
+            }
+
+            let syntax = config.jsc.syntax.unwrap_or_default();
+            let es_version = config.jsc.target.unwrap_or_default();
+            let comments = SingleThreadedComments::default();
+
+            let mut program = self.parse_js(
+                fm.clone(),
+                handler,
+                es_version,
+                syntax,
+                config.is_module.unwrap_or_default(),
+                Some(&comments),
+            )?;
+
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+            program.mutate(resolver(
+                unresolved_mark,
+                top_level_mark,
+                syntax.typescript(),
+            ));
+
+            let mut pass = lint_pass(with_directives(
+                swc_ecma_lints::rules::all(LintParams {
+                    program: &program,
+                    lint_config: &config.jsc.lints,
+                    unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+                    top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+                    es_version,
+                    source_map: self.cm.clone(),
+                }),
+                Directives::parse(&self.cm, &comments),
+                &config.jsc.lints,
+            ));
+
+            Ok(capture_diagnostics(|| program.mutate(&mut pass)))
+        })
+    }
+
+    /// Formats a javascript or typescript file in the style of prettier.
+    ///
+    /// The syntax is inferred from the file name unless specified in `opts`.
+    #[tracing::instrument(skip_all)]
+    pub fn format_js(
+        &self,
+        fm: Arc<SourceFile>,
+        handler: &Handler,
+        opts: &JsFormatOptions,
+    ) -> Result<TransformOutput, Error> {
+        self.run(|| {
+            let _timer = timer!("Compiler::format_js");
+
+            let comments = SingleThreadedComments::default();
+            let program = self.parse_js(
+                fm.clone(),
+                handler,
+                EsVersion::latest(),
+                opts.syntax_for(&fm.name),
+                IsModule::Unknown,
+                Some(&comments),
+            )?;
+
+            let code = swc_ecma_formatter::format_program(
+                self.cm.clone(),
+                &fm,
+                &program,
+                &comments,
+                &opts.style,
+            );
+
+            Ok(TransformOutput {
+                code,
+                map: None,
+                output: None,
+                diagnostics: Default::default(),
+            })
+        })
+    }
+
+    /// Returns [None] if the file is ignored by `.swcrc`, in which case
+    /// nothing is cached.
+    fn transform_cache_key(
+        &self,
+        cache: &TransformCache,
+        fm: &SourceFile,
+        opts: &Options,
+    ) -> Result<Option<CacheKey>, Error> {
+        let config = self.read_config(opts, &fm.name)?;
+        let Some(config) = config else {
+            return Ok(None);
+        };
+
+        let plugins = opts
+            .config
+            .jsc
+            .experimental
+            .plugins
+            .iter()
+            .chain(config.jsc.experimental.plugins.iter())
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        let plugin_hashes = cache.plugin_hashes(&plugins)?;
+
+        CacheKey::new(fm, opts, Some(&config), &plugin_hashes).map(Some)
     }
 
     #[tracing::instrument(skip_all)]
@@ -1027,12 +1303,26 @@ impl Compiler {
                     experimental_emit("__swc_isolated_declarations__".into(), dts_code);
                 }
 
-                helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
+                let program = helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                     HANDLER.set(handler, || {
                         // Fold module
                         program.apply(pass)
                     })
-                })
+                });
+
+                if let Some(explainer) = &config.explainer {
+                    experimental_emit(explain::EXPLAIN_OUTPUT_KEY.into(), explainer.render());
+                }
+
+                if let Some(reporter) = &config.env_reporter {
+                    experimental_emit(
+                        PRESET_ENV_REPORT_OUTPUT_KEY.into(),
+                        serde_json::to_string(&reporter.report())
+                            .expect("failed to serialize preset-env report"),
+                    );
+                }
+
+                program
             });
 
             if let Some(comments) = &config.comments {
@@ -6,7 +6,7 @@
     allow(unused)
 )]
 
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use anyhow::{Context, Result};
 use atoms::Atom;
@@ -29,9 +29,67 @@ use swc_ecma_visit::{fold_pass, noop_fold_type, Fold};
 /// Type of plugin's configuration is up to each plugin - swc/core does not have
 /// strong type and it'll be serialized into plain string when it's passed to
 /// plugin's entrypoint function.
+///
+/// The optional third element configures the sandbox of the plugin.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
-pub struct PluginConfig(pub String, pub serde_json::Value);
+pub struct PluginConfig(
+    pub String,
+    pub serde_json::Value,
+    #[serde(default)] pub PluginSandboxConfig,
+);
+
+/// Limits and capabilities of a plugin.
+///
+/// A plugin can't access the filesystem or environment variables unless they
+/// are granted here, and has no resource limits by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PluginSandboxConfig {
+    /// The number of Wasm instructions the plugin can execute per file.
+    #[serde(default)]
+    pub fuel: Option<u64>,
+
+    /// The maximum size of the memory of the plugin, in bytes.
+    #[serde(default)]
+    pub max_memory: Option<u64>,
+
+    /// Directories the plugin can access, from the path seen by the plugin to
+    /// the path on the host. Relative host paths are resolved from the
+    /// current directory.
+    ///
+    /// e.g. `{ "/cwd": "." }`
+    #[serde(default)]
+    pub fs: BTreeMap<String, PathBuf>,
+
+    /// Environment variables the plugin can read.
+    #[serde(default)]
+    pub env: Vec<Atom>,
+
+    /// Makes clocks and random number generators unavailable, so the output
+    /// of the plugin only depends on its input.
+    #[serde(default)]
+    pub deterministic: bool,
+}
+
+#[cfg(feature = "plugin")]
+impl PluginSandboxConfig {
+    pub(crate) fn to_sandbox(&self) -> Result<swc_plugin_runner::sandbox::PluginSandbox> {
+        let cwd = std::env::current_dir().context("failed to get the current directory")?;
+
+        Ok(swc_plugin_runner::sandbox::PluginSandbox {
+            fuel: self.fuel,
+            max_memory: self.max_memory,
+            mapped_dirs: self
+                .fs
+                .iter()
+                .map(|(guest, host)| (guest.clone(), cwd.join(host)))
+                .collect(),
+            env_vars: self.env.clone(),
+            deterministic: self.deterministic,
+        })
+    }
+}
 
 pub fn plugins(
     configured_plugins: Option<Vec<PluginConfig>>,
@@ -139,7 +197,8 @@ impl RustPlugins {
                                 plugin_module_bytes,
                                 Some(p.1),
                                 runtime,
-                            );
+                            )
+                            .with_sandbox(p.2.to_sandbox()?);
 
                         let span = tracing::span!(
                             tracing::Level::INFO,
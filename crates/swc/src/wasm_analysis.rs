@@ -153,7 +153,8 @@ impl Compiler {
                         plugin_module_bytes,
                         Some(p.1.clone()),
                         runtime,
-                    );
+                    )
+                    .with_sandbox(p.2.to_sandbox()?);
 
                 let span = tracing::span!(
                     tracing::Level::INFO,
@@ -0,0 +1,8 @@
+/* swc-lint-disable no-var */
+
+var a = 1;
+
+function foo() {
+    var b = 2;
+    debugger;
+}
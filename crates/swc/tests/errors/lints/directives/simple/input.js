@@ -0,0 +1,14 @@
+// swc-lint-disable-next-line no-var
+var a = 1;
+var b = 2; // swc-lint-disable-line
+
+/* swc-lint-disable no-var, no-debugger */
+var c = 3;
+debugger;
+/* swc-lint-enable no-var */
+var d = 4;
+debugger;
+/* swc-lint-enable */
+
+// swc-lint-disable-next-line no-debugger, no-var -- only `var` is reported
+var e = 5;
@@ -0,0 +1,4 @@
+var Object = 1;
+
+var x = 1;
+var x = 2;
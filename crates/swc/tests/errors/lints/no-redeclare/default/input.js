@@ -0,0 +1,18 @@
+var a = 1;
+var a = 2;
+
+var Object = 3;
+
+function foo() {
+    var b = 1;
+    if (b) {
+        var b = 2;
+    }
+
+    function bar() {}
+    function bar() {}
+
+    return b;
+}
+
+function foo() {}
@@ -0,0 +1,32 @@
+const a = 1;
+
+function foo(a) {
+    const b = 2;
+
+    if (a) {
+        const b = 3;
+        return b;
+    }
+
+    return function () {
+        const hoisted = 4;
+        return hoisted;
+    };
+}
+
+function bar() {
+    const later = 1;
+    const fn = 2;
+    return later + fn;
+}
+
+const later = 5;
+function fn() {}
+
+try {
+    foo();
+} catch (a) {
+    bar();
+}
+
+export { a, later, fn };
@@ -0,0 +1,14 @@
+function foo(done) {
+    const Object = 1;
+
+    function bar(done) {
+        const later = 2;
+        return later + Object;
+    }
+
+    return bar(done);
+}
+
+const later = 3;
+
+export { foo, later };
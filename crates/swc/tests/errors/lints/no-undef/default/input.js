@@ -0,0 +1,13 @@
+const defined = 1;
+
+console.log(defined, undefinedVar);
+
+if (typeof maybeDefined === "undefined") {
+    missing();
+}
+
+label: for (;;) {
+    break label;
+}
+
+export const a = new Map([[Symbol.iterator, globalThis]]);
@@ -0,0 +1,7 @@
+describe("a", () => {
+    it("b", () => {
+        expect(document.title).toBe(MY_GLOBAL);
+        expect(typeof process).toBe("undefined");
+        expect(require("x")).toBeTruthy();
+    });
+});
@@ -0,0 +1,25 @@
+export class Foo {
+    #used = 1;
+    #unused = 2;
+    #writeOnly = 3;
+    #incremented = 0;
+    #checked;
+
+    get #accessor() {
+        return 1;
+    }
+
+    set #accessor(value) {}
+
+    #unusedMethod() {}
+
+    #usedMethod() {
+        return this.#used;
+    }
+
+    method(other) {
+        this.#writeOnly = 4;
+        this.#incremented++;
+        return this.#usedMethod() + this.#accessor + (#checked in other);
+    }
+}
@@ -0,0 +1,24 @@
+import { used, unused } from "./a";
+import Default from "./b";
+
+const a = 1;
+let b = 2;
+b = 3;
+
+function recursive(n) {
+    return n > 0 ? recursive(n - 1) : 0;
+}
+
+function foo(first, second, third) {
+    return second;
+}
+
+export function bar({ x, ...rest }) {
+    try {
+        return used(x);
+    } catch (err) {
+        return null;
+    }
+}
+
+export const baz = () => Default;
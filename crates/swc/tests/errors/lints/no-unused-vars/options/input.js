@@ -0,0 +1,16 @@
+var topLevel = 1;
+
+function outer(a, _b) {
+    var _ignored = 1;
+    var local = 2;
+
+    const { omitted, ...others } = a;
+
+    try {
+        return others;
+    } catch (err) {
+        return null;
+    }
+}
+
+outer();
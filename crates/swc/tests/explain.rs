@@ -0,0 +1,84 @@
+use swc::{
+    config::{Config, JscConfig, JscExperimental, Options},
+    explain::{ChangeKind, ExplainFormat, ExplainReport, EXPLAIN_OUTPUT_KEY},
+    Compiler,
+};
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{Syntax, TsSyntax};
+
+fn explain(src: &str, format: ExplainFormat) -> String {
+    testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+
+        let output = c
+            .process_js_file(
+                fm,
+                &handler,
+                &Options {
+                    config: Config {
+                        jsc: JscConfig {
+                            syntax: Some(Syntax::Typescript(TsSyntax::default())),
+                            target: Some(EsVersion::Es5),
+                            experimental: JscExperimental {
+                                explain: Some(format),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    swcrc: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let output: serde_json::Value = serde_json::from_str(&output.output.unwrap()).unwrap();
+
+        Ok(output[EXPLAIN_OUTPUT_KEY].as_str().unwrap().to_string())
+    })
+    .unwrap()
+}
+
+#[test]
+fn json_report_names_the_pass() {
+    let report = explain(
+        "type T = number;\nconst a: T = 1;\nconst f = () => a;",
+        ExplainFormat::Json,
+    );
+    let report: ExplainReport = serde_json::from_str(&report).unwrap();
+
+    let strip = report
+        .passes
+        .iter()
+        .find(|p| p.name == "typescript::strip")
+        .unwrap();
+    assert!(strip
+        .changes
+        .iter()
+        .any(|c| c.kind == ChangeKind::Removed && c.loc.unwrap().line == 1));
+    assert!(strip
+        .changes
+        .iter()
+        .any(|c| c.kind == ChangeKind::Modified && c.loc.unwrap().line == 2));
+
+    let compat = report.passes.iter().find(|p| p.name == "compat").unwrap();
+    assert_eq!(compat.changes.len(), 2);
+    assert!(compat.changes.iter().all(|c| c.loc.unwrap().line != 1));
+    let arrow = compat
+        .changes
+        .iter()
+        .find(|c| c.loc.unwrap().line == 3)
+        .unwrap();
+    assert!(arrow.after.as_deref().unwrap().contains("function"));
+}
+
+#[test]
+fn html_report() {
+    let report = explain("const f = () => 1;", ExplainFormat::Html);
+
+    assert!(report.starts_with("<!DOCTYPE html>"));
+    assert!(report.contains("<h2>compat</h2>"));
+}
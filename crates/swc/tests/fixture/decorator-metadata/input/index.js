@@ -0,0 +1,15 @@
+function meta(key, value) {
+    return (_, context) => {
+        context.metadata[key] = value;
+    };
+}
+
+@meta("kind", "base")
+class Base {}
+
+export class Child extends Base {
+    @meta("field", "child")
+    field;
+}
+
+console.log(Child[Symbol.metadata].kind, Child[Symbol.metadata].field);
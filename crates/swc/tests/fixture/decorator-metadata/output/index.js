@@ -0,0 +1,33 @@
+import { _ as _apply_decs_2203_r } from "@swc/helpers/_/_apply_decs_2203_r";
+var _dec, _initClass, _dec1, _init_field, _Base;
+function meta(key, value) {
+    return (_, context)=>{
+        context.metadata[key] = value;
+    };
+}
+let _Base1;
+_dec = meta("kind", "base");
+class Base {
+    static{
+        ({ c: [_Base1, _initClass] } = _apply_decs_2203_r(this, [], [
+            _dec
+        ]));
+    }
+    static{
+        _initClass();
+    }
+}
+_dec1 = meta("field", "child");
+export class Child extends (_Base = _Base1) {
+    static{
+        ({ e: [_init_field] } = _apply_decs_2203_r(this, [
+            [
+                _dec1,
+                0,
+                "field"
+            ]
+        ], [], _Base));
+    }
+    field = _init_field(this);
+}
+console.log(Child[Symbol.metadata].kind, Child[Symbol.metadata].field);
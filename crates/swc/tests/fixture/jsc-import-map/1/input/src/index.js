@@ -0,0 +1,6 @@
+import React from "react";
+import { map } from "lodash";
+import { format } from "#utils/format.js";
+import { legacy } from "./legacy/widget.js";
+
+export const render = () => React.createElement("div", null, format(map([legacy])));
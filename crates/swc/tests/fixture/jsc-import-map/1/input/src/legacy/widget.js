@@ -0,0 +1,4 @@
+import React from "react";
+import { format } from "#utils/format.js";
+
+export const legacy = React.createElement("span", null, format("legacy"));
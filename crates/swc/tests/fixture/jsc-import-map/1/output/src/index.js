@@ -0,0 +1,7 @@
+import React from "https://esm.sh/react@18";
+import { map } from "../vendor/lodash.js";
+import { format } from "./utils/format.js";
+import { legacy } from "./legacy/widget.js";
+export const render = ()=>React.createElement("div", null, format(map([
+        legacy
+    ])));
@@ -0,0 +1,3 @@
+import React from "https://esm.sh/react@16";
+import { format } from "../utils/format.js";
+export const legacy = React.createElement("span", null, format("legacy"));
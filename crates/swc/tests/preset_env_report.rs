@@ -0,0 +1,67 @@
+use swc::{
+    config::{Config, Options},
+    Compiler, PRESET_ENV_REPORT_OUTPUT_KEY,
+};
+use swc_common::FileName;
+
+#[test]
+fn report_is_emitted_to_output() {
+    let report = testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "const a = b?.c;\nexport const d = Object.fromEntries(a);\n".to_string(),
+        );
+
+        let config: Config = serde_json::from_str(
+            r#"{
+                "env": {
+                    "targets": { "chrome": "70", "firefox": "100" },
+                    "mode": "usage",
+                    "coreJs": "3.30",
+                    "report": true
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let output = c
+            .process_js_file(
+                fm,
+                &handler,
+                &Options {
+                    config,
+                    swcrc: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let output: serde_json::Value = serde_json::from_str(&output.output.unwrap()).unwrap();
+        let report = output[PRESET_ENV_REPORT_OUTPUT_KEY].as_str().unwrap();
+
+        Ok(serde_json::from_str::<serde_json::Value>(report).unwrap())
+    })
+    .unwrap();
+
+    assert_eq!(report["targets"]["chrome"], "70.0.0");
+    assert_eq!(report["targets"]["firefox"], "100.0.0");
+
+    let transforms = report["transforms"].as_array().unwrap();
+    let optional_chaining = transforms
+        .iter()
+        .find(|t| t["feature"] == "transform-optional-chaining")
+        .unwrap();
+    assert_eq!(
+        optional_chaining["reason"],
+        serde_json::json!({
+            "type": "targets",
+            "browsers": { "chrome": "70.0.0" }
+        })
+    );
+
+    let polyfills = report["polyfills"].as_array().unwrap();
+    assert!(polyfills
+        .iter()
+        .any(|p| p["module"] == "core-js/modules/es.object.from-entries.js"));
+}
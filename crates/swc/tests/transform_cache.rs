@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use swc::{
+    cache::{CacheKey, TransformCache},
+    config::{Config, JscConfig, Options},
+    Compiler,
+};
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+
+fn opts(target: EsVersion) -> Options {
+    Options {
+        config: Config {
+            jsc: JscConfig {
+                target: Some(target),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        swcrc: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn reuses_output_for_unchanged_input() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Arc::new(TransformCache::open(dir.path(), 1024 * 1024).unwrap());
+
+    testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone()).with_transform_cache(cache.clone());
+        let fm = cm.new_source_file(
+            FileName::Real("input.js".into()).into(),
+            "const a = () => 1;".into(),
+        );
+
+        let first = c
+            .process_js_file(fm.clone(), &handler, &opts(EsVersion::Es5))
+            .unwrap();
+        assert!(first.code.contains("function"));
+        assert!(cache.size() > 0);
+
+        let config = c.read_config(&opts(EsVersion::Es5), &fm.name).unwrap();
+        let key = CacheKey::new(&fm, &opts(EsVersion::Es5), config.as_ref(), &[]).unwrap();
+        let cached = cache.get(&key).expect("output should be cached");
+        assert_eq!(cached.code, first.code);
+
+        // A different target must not hit the entry above.
+        let other = c
+            .process_js_file(fm.clone(), &handler, &opts(EsVersion::Es2015))
+            .unwrap();
+        assert!(other.code.contains("=>"));
+
+        assert!(cache.invalidate(&key).unwrap());
+        assert!(cache.get(&key).is_none());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn evicts_least_recently_used_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = TransformCache::open(dir.path(), 200).unwrap();
+
+    testing::run_test2(false, |cm, _| {
+        let output = |code: &str| swc::TransformOutput {
+            code: code.repeat(20),
+            map: None,
+            output: None,
+            diagnostics: Default::default(),
+        };
+        let key = |src: &str| {
+            let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+            CacheKey::new(&fm, &opts(EsVersion::Es5), None, &[]).unwrap()
+        };
+
+        let a = key("a");
+        let b = key("b");
+        cache.insert(&a, &output("a")).unwrap();
+        cache.insert(&b, &output("b")).unwrap();
+        assert!(cache.get(&a).is_some());
+
+        // `b` is the least recently used entry now.
+        let c = key("c");
+        cache.insert(&c, &output("c")).unwrap();
+        assert!(cache.size() <= 200);
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&c).is_some());
+
+        // Reopening the cache must see the remaining entries.
+        let reopened = TransformCache::open(dir.path(), 200).unwrap();
+        assert_eq!(reopened.size(), cache.size());
+
+        cache.clear().unwrap();
+        assert_eq!(cache.size(), 0);
+        assert!(cache.get(&c).is_none());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn does_not_cache_runs_with_diagnostics() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Arc::new(TransformCache::open(dir.path(), 1024 * 1024).unwrap());
+
+    testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone()).with_transform_cache(cache.clone());
+        let fm = cm.new_source_file(FileName::Real("input.js".into()).into(), "debugger;".into());
+        let opts: Options = serde_json::from_str(
+            r#"{
+                "swcrc": false,
+                "jsc": {
+                    "experimental": { "disableAllLints": false },
+                    "lints": { "noDebugger": ["warning"] }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        c.process_js_file(fm, &handler, &opts).unwrap();
+        assert_eq!(cache.size(), 0);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn key_does_not_depend_on_map_order() {
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon.into(), "a".into());
+        let opts = |typeofs: &str| -> Options {
+            serde_json::from_str(&format!(
+                r#"{{ "jsc": {{ "transform": {{ "optimizer": {{ "globals": {{ "typeofs": {typeofs} }} }} }} }} }}"#
+            ))
+            .unwrap()
+        };
+
+        let a = opts(r#"{ "a": "object", "b": "function" }"#);
+        let b = opts(r#"{ "b": "function", "a": "object" }"#);
+
+        assert_eq!(
+            CacheKey::new(&fm, &a, None, &[]).unwrap(),
+            CacheKey::new(&fm, &b, None, &[]).unwrap()
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
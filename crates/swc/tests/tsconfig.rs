@@ -0,0 +1,172 @@
+use std::{fs, path::Path};
+
+use swc::{
+    config::{tsconfig::TsConfig, Options},
+    BoolOrDataConfig, Compiler,
+};
+use swc_common::FileName;
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::Syntax;
+
+fn write(dir: &Path, path: &str, content: &str) {
+    let path = dir.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn opts(dir: &Path) -> Options {
+    let mut opts = Options {
+        cwd: dir.to_path_buf(),
+        root: Some(dir.to_path_buf()),
+        ..Default::default()
+    };
+    opts.config.jsc.tsconfig = BoolOrDataConfig::from_bool(true);
+    opts
+}
+
+#[test]
+fn resolves_extends_chain() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = &dir.path().canonicalize().unwrap();
+
+    write(
+        dir,
+        "node_modules/@company/tsconfig/package.json",
+        r#"{ "tsconfig": "./base.json" }"#,
+    );
+    write(
+        dir,
+        "node_modules/@company/tsconfig/base.json",
+        r#"{
+            "compilerOptions": {
+                "target": "ES5",
+                "jsx": "react",
+                "jsxFactory": "h",
+                "experimentalDecorators": true
+            }
+        }"#,
+    );
+    write(
+        dir,
+        "node_modules/@company/tsconfig/strict.json",
+        r#"{ "compilerOptions": { "strict": true, "target": "ES2017" } }"#,
+    );
+    write(
+        dir,
+        "configs/paths.json",
+        r#"{
+            // Relative to this file
+            "compilerOptions": {
+                "paths": { "~/*": ["../src/*"] },
+            },
+        }"#,
+    );
+    write(
+        dir,
+        "tsconfig.json",
+        r#"{
+            "extends": ["@company/tsconfig", "@company/tsconfig/strict", "./configs/paths"],
+            "compilerOptions": { "emitDecoratorMetadata": true }
+        }"#,
+    );
+    write(dir, "src/index.tsx", "");
+
+    let tsconfig = TsConfig::load(&dir.join("tsconfig.json")).unwrap();
+    assert_eq!(tsconfig.compiler_options["strict"], true);
+    assert!(tsconfig.unsupported_options().is_empty());
+
+    testing::run_test2(false, |cm, _| {
+        let c = Compiler::new(cm);
+        let config = c
+            .read_config(&opts(dir), &FileName::Real(dir.join("src/index.tsx")))
+            .unwrap()
+            .unwrap();
+
+        // The later config in `extends` wins.
+        assert_eq!(config.jsc.target, Some(EsVersion::Es2017));
+        match config.jsc.syntax {
+            Some(Syntax::Typescript(syntax)) => {
+                assert!(syntax.tsx);
+                assert!(syntax.decorators);
+            }
+            syntax => panic!("unexpected syntax: {syntax:?}"),
+        }
+
+        let transform = config.jsc.transform.into_inner().unwrap();
+        assert!(transform.legacy_decorator.into_bool());
+        assert!(transform.decorator_metadata.into_bool());
+        assert!(!transform.use_define_for_class_fields.into_bool());
+        assert_eq!(transform.react.pragma.as_deref().map(|s| &**s), Some("h"));
+
+        assert_eq!(config.jsc.base_url, dir.join("configs"));
+        assert_eq!(config.jsc.paths["~/*"], vec!["../src/*".to_string()]);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn swcrc_takes_priority() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = &dir.path().canonicalize().unwrap();
+
+    write(
+        dir,
+        "tsconfig.json",
+        r#"{
+            "compilerOptions": {
+                "target": "es5",
+                "module": "commonjs",
+                "experimentalDecorators": true,
+                "outFile": "out.js"
+            }
+        }"#,
+    );
+    write(
+        dir,
+        ".swcrc",
+        r#"{ "jsc": { "target": "es2020", "tsconfig": "./tsconfig.json" } }"#,
+    );
+    write(
+        dir,
+        "src/index.ts",
+        "@dec class Foo {}\nexport const foo = async () => new Foo();\n",
+    );
+
+    let tsconfig = TsConfig::load(&dir.join("tsconfig.json")).unwrap();
+    assert_eq!(tsconfig.unsupported_options(), vec!["outFile".to_string()]);
+
+    testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let opts = Options {
+            cwd: dir.to_path_buf(),
+            root: Some(dir.to_path_buf()),
+            swcrc: true,
+            ..Default::default()
+        };
+
+        let fm = cm.load_file(&dir.join("src/index.ts")).unwrap();
+        let output = c.process_js_file(fm, &handler, &opts).unwrap();
+
+        assert!(output.code.contains("_ts_decorate"), "{}", output.code);
+        assert!(output.code.contains("exports"), "{}", output.code);
+        // `target` of `.swcrc` is used.
+        assert!(output.code.contains("async ()=>"), "{}", output.code);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn circular_extends() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+
+    write(dir, "a.json", r#"{ "extends": "./b.json" }"#);
+    write(dir, "b.json", r#"{ "extends": "./a" }"#);
+
+    let err = TsConfig::load(&dir.join("a.json")).unwrap_err();
+    assert!(format!("{err:?}").contains("circularity"), "{err:?}");
+}
@@ -467,7 +467,7 @@ where
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.is_preserved_import(import) {
                         return true;
                     }
 
@@ -584,8 +584,8 @@ where
             for item in items {
                 match item {
                     ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) => {
-                        // Preserve imports from node.js builtin modules.
-                        if self.config.external_modules.contains(&import.src.value) {
+                        // Preserve imports from node.js builtin modules and `import source`.
+                        if self.is_preserved_import(&import) {
                             new.push(import.into());
                             continue;
                         }
@@ -1247,7 +1247,7 @@ where
 
             for stmt in stmts {
                 if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = &stmt {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.is_preserved_import(import) {
                         new.push(stmt);
                         continue;
                     }
@@ -412,8 +412,8 @@ where
     }
 
     fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
-        // Ignore if it's a core module.
-        if self.bundler.is_external(&import.src.value) {
+        // Ignore if it's a core module or `import source`.
+        if self.bundler.is_preserved_import(import) {
             return;
         }
 
@@ -1,18 +1,23 @@
 #![allow(dead_code)]
 
-use anyhow::{Context, Error};
+use anyhow::{bail, Context, Error};
 use is_macro::Is;
 #[cfg(feature = "rayon")]
 use rayon::iter::ParallelIterator;
+use rustc_hash::FxHashSet;
+use swc_atoms::Atom;
 use swc_common::{
     sync::{Lock, Lrc},
-    FileName, SourceFile, SyntaxContext,
+    util::take::Take,
+    FileName, SourceFile, SyntaxContext, DUMMY_SP,
 };
 use swc_ecma_ast::{
-    CallExpr, Callee, Expr, Ident, ImportDecl, ImportSpecifier, MemberExpr, MemberProp, Module,
-    ModuleDecl, ModuleExportName, Str, SuperProp, SuperPropExpr,
+    CallExpr, Callee, Expr, Ident, ImportDecl, ImportPhase, ImportSpecifier, MemberExpr,
+    MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem, Str, SuperProp, SuperPropExpr,
+    VarDeclKind,
 };
-use swc_ecma_transforms_base::resolver;
+use swc_ecma_transforms_base::{helper_expr, helpers::HELPERS, resolver};
+use swc_ecma_utils::{ExprFactory, FunctionFactory};
 use swc_ecma_visit::{
     noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith,
 };
@@ -135,6 +140,8 @@ where
     ) -> Result<(TransformedModule, Vec<(Source, Lrc<FileName>)>), Error> {
         self.run(|| {
             tracing::trace!("transform_module({})", data.fm.name);
+            self.lower_deferred_imports(file_name, &mut data)?;
+
             let (id, local_mark, export_mark) = self.scope.module_id_gen.gen(file_name);
 
             data.module.visit_mut_with(&mut ClearMark);
@@ -274,6 +281,84 @@ where
         })
     }
 
+    /// Lowers `import defer` of bundled modules.
+    ///
+    /// The dependency is merged like any other module, so it's evaluated
+    /// eagerly, but the namespace object is only created on first access.
+    ///
+    /// ```js
+    /// import defer * as ns from "./mod";
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// import * as _ns from "./mod";
+    /// const ns = _import_defer_proxy(function() {
+    ///     return _ns;
+    /// });
+    /// ```
+    fn lower_deferred_imports(
+        &self,
+        file_name: &FileName,
+        data: &mut ModuleData,
+    ) -> Result<(), Error> {
+        let mut names = IdentNames::default();
+        data.module.visit_with(&mut names);
+
+        let mut body = Vec::with_capacity(data.module.body.len());
+
+        for item in data.module.body.take() {
+            let mut import = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import))
+                    if import.phase == ImportPhase::Defer
+                        && !self.is_external(&import.src.value) =>
+                {
+                    import
+                }
+                _ => {
+                    body.push(item);
+                    continue;
+                }
+            };
+
+            let [ImportSpecifier::Namespace(specifier)] = &mut *import.specifiers else {
+                bail!(
+                    "`import defer` of `{}` in {} can only be used with a namespace import, like \
+                     `import defer * as ns from \"{}\"`",
+                    import.src.value,
+                    file_name,
+                    import.src.value
+                );
+            };
+
+            let local = specifier.local.clone();
+            let ns = Ident::new_no_ctxt(names.fresh(&local.sym), local.span);
+            specifier.local = ns.clone();
+            import.phase = ImportPhase::Evaluation;
+
+            let proxy = HELPERS.set(&data.helpers, || helper_expr!(import_defer_proxy));
+
+            body.push(import.into());
+            body.push(
+                proxy
+                    .as_call(
+                        DUMMY_SP,
+                        vec![ns
+                            .into_lazy_fn(Default::default())
+                            .into_fn_expr(None)
+                            .as_arg()],
+                    )
+                    .into_var_decl(VarDeclKind::Const, local.into())
+                    .into(),
+            );
+        }
+
+        data.module.body = body;
+
+        Ok(())
+    }
+
     /// Resolve dependencies
     fn resolve_imports(
         &self,
@@ -492,3 +577,30 @@ impl VisitMut for ClearMark {
         ident.ctxt = SyntaxContext::empty();
     }
 }
+
+/// Names of the identifiers in a module.
+#[derive(Default)]
+struct IdentNames(FxHashSet<Atom>);
+
+impl IdentNames {
+    /// Returns a name based on `base` which isn't used yet.
+    fn fresh(&mut self, base: &Atom) -> Atom {
+        let mut name: Atom = format!("_{base}").into();
+        let mut i = 1;
+        while self.0.contains(&name) {
+            name = format!("_{base}{i}").into();
+            i += 1;
+        }
+        self.0.insert(name.clone());
+
+        name
+    }
+}
+
+impl Visit for IdentNames {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.0.insert(ident.sym.clone());
+    }
+}
@@ -4,7 +4,7 @@ use anyhow::{Context, Error};
 use rustc_hash::FxHashMap;
 use swc_atoms::Atom;
 use swc_common::{sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, GLOBALS};
-use swc_ecma_ast::Module;
+use swc_ecma_ast::{ImportDecl, ImportPhase, Module};
 
 use self::scope::Scope;
 use crate::{Hook, Load, ModuleId, Resolve};
@@ -143,6 +143,16 @@ where
         self.config.external_modules.iter().any(|v| v == src)
     }
 
+    /// Returns `true` if `import` is kept in the bundle instead of being
+    /// merged.
+    ///
+    /// `import source` doesn't evaluate the module, and the source of a Wasm or
+    /// JS module can't be merged into the bundle, so it's preserved like an
+    /// import of an external module.
+    pub(crate) fn is_preserved_import(&self, import: &ImportDecl) -> bool {
+        import.phase == ImportPhase::Source || self.is_external(&import.src.value)
+    }
+
     ///
     ///
     ///
@@ -0,0 +1,8 @@
+import defer * as heavy from "./heavy.js";
+import source wasm from "./module.wasm";
+import source aSource from "./a.js";
+import { a } from "./a.js";
+
+export function run() {
+    return heavy.compute(a, wasm, aSource);
+}
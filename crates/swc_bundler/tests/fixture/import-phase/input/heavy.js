@@ -0,0 +1,3 @@
+export function compute(a, wasm, aSource) {
+    return [a, wasm, aSource];
+}
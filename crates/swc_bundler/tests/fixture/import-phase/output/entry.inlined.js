@@ -0,0 +1,49 @@
+function _import_defer_proxy(init) {
+    var ns = null;
+    var constValue = function(v) {
+        return function() {
+            return v;
+        };
+    };
+    var proxy = function(run) {
+        return function(_target, p, receiver) {
+            if (ns === null) ns = init();
+            return run(ns, p, receiver);
+        };
+    };
+    var getOwnPropertyDescriptor = function(target, p) {
+        var desc = Reflect.getOwnPropertyDescriptor(target, p);
+        if (desc) desc.configurable = true;
+        return desc;
+    };
+    return new Proxy({}, {
+        defineProperty: constValue(false),
+        deleteProperty: constValue(false),
+        get: proxy(Reflect.get),
+        getOwnPropertyDescriptor: proxy(getOwnPropertyDescriptor),
+        getPrototypeOf: constValue(null),
+        has: proxy(Reflect.has),
+        ownKeys: proxy(Reflect.ownKeys),
+        set: constValue(false),
+        setPrototypeOf: constValue(false)
+    });
+}
+function compute(a, wasm, aSource) {
+    return [
+        a,
+        wasm,
+        aSource
+    ];
+}
+const mod = {
+    compute: compute
+};
+const heavy = _import_defer_proxy(function() {
+    return mod;
+});
+import source wasm from "./module.wasm";
+import source aSource from "./a.js";
+function run() {
+    return heavy.compute(1, wasm, aSource);
+}
+export { run as run };
@@ -110,7 +110,7 @@ pub struct CompileOptions {
      *no_swcrc: bool, */
 }
 
-fn parse_config(s: &str) -> Result<Config, serde_json::Error> {
+pub(crate) fn parse_config(s: &str) -> Result<Config, serde_json::Error> {
     serde_json::from_str(s)
 }
 
@@ -121,13 +121,14 @@ static COMPILER: Lazy<Arc<Compiler>> = Lazy::new(|| {
 });
 
 /// List of file extensions supported by default.
-static DEFAULT_EXTENSIONS: &[&str] = &["js", "jsx", "es6", "es", "mjs", "ts", "tsx", "cts", "mts"];
+pub(crate) static DEFAULT_EXTENSIONS: &[&str] =
+    &["js", "jsx", "es6", "es", "mjs", "ts", "tsx", "cts", "mts"];
 
 /// Infer list of files to be transformed from cli arguments.
 /// If given input is a directory, it'll traverse it and collect all supported
 /// files.
 #[tracing::instrument(level = "info", skip_all)]
-fn get_files_list(
+pub(crate) fn get_files_list(
     raw_files_input: &[PathBuf],
     extensions: &[String],
     ignore_pattern: Option<&str>,
@@ -314,6 +315,7 @@ impl CompileOptions {
                                 _ => p.0,
                             },
                             p.1,
+                            p.2,
                         )
                     })
                     .collect()
@@ -0,0 +1,132 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Context;
+use clap::Parser;
+use swc_core::{
+    base::{config::JsFormatOptions, Compiler},
+    common::{
+        errors::{ColorConfig, Handler},
+        FileName, FilePathMapping, SourceMap, GLOBALS,
+    },
+    trace_macro::swc_trace,
+};
+
+use super::{get_files_list, DEFAULT_EXTENSIONS};
+
+/// Configuration option for formatting files.
+#[derive(Parser)]
+pub struct FmtOptions {
+    /// Formatting options as a JSON object, using the option names of
+    /// prettier. e.g. `{"printWidth": 100, "singleQuote": true}`
+    #[clap(long = "config-json", value_parser = parse_format_options)]
+    config: Option<JsFormatOptions>,
+
+    /// Exit with an error if any of the files is not formatted, instead of
+    /// printing the formatted code.
+    #[clap(long, conflicts_with = "write")]
+    check: bool,
+
+    /// Overwrite the files with the formatted code.
+    #[clap(long)]
+    write: bool,
+
+    /// List of glob paths to not format.
+    #[clap(long)]
+    ignore: Option<String>,
+
+    /// Specify specific file extensions to format.
+    #[clap(long)]
+    extensions: Option<Vec<String>>,
+
+    /// Files to format
+    #[clap(required = true)]
+    files: Vec<PathBuf>,
+}
+
+fn parse_format_options(s: &str) -> Result<JsFormatOptions, serde_json::Error> {
+    serde_json::from_str(s)
+}
+
+#[swc_trace]
+impl FmtOptions {
+    /// Formats a single file. Returns `false` if `--check` is used and the
+    /// file is not formatted.
+    fn format_file(
+        &self,
+        compiler: &Compiler,
+        handler: &Handler,
+        file_path: &Path,
+    ) -> anyhow::Result<bool> {
+        let src = fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to open file {}", file_path.display()))?;
+        let fm = compiler.cm.new_source_file(
+            Arc::new(FileName::Real(file_path.to_path_buf())),
+            src.clone(),
+        );
+
+        let output = compiler.format_js(fm, handler, &self.config.clone().unwrap_or_default())?;
+
+        if self.check {
+            if output.code != src {
+                println!("{}", file_path.display());
+                return Ok(false);
+            }
+        } else if self.write {
+            if output.code != src {
+                fs::write(file_path, &output.code)
+                    .with_context(|| format!("Failed to write file {}", file_path.display()))?;
+            }
+        } else {
+            io::stdout().write_all(output.code.as_bytes())?;
+        }
+
+        Ok(true)
+    }
+}
+
+#[swc_trace]
+impl super::CommandRunner for FmtOptions {
+    fn execute(&self) -> anyhow::Result<()> {
+        let extensions = if let Some(extensions) = &self.extensions {
+            extensions.clone()
+        } else {
+            DEFAULT_EXTENSIONS.iter().map(|v| v.to_string()).collect()
+        };
+        let files = get_files_list(&self.files, &extensions, self.ignore.as_deref(), false)?;
+
+        let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+        let compiler = Compiler::new(cm.clone());
+        let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm));
+
+        let mut failed = 0;
+        let mut unformatted = 0;
+
+        GLOBALS.set(&Default::default(), || {
+            for file_path in &files {
+                match self.format_file(&compiler, &handler, file_path) {
+                    Ok(true) => {}
+                    Ok(false) => unformatted += 1,
+                    Err(err) => {
+                        eprintln!("{err:?}");
+                        failed += 1;
+                    }
+                }
+            }
+        });
+
+        if failed > 0 {
+            anyhow::bail!("Failed to format {} file(s)", failed);
+        }
+
+        if unformatted > 0 {
+            anyhow::bail!("Found {} unformatted file(s)", unformatted);
+        }
+
+        Ok(())
+    }
+}
@@ -1,12 +1,144 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Context;
 use clap::Parser;
-use swc_core::trace_macro::swc_trace;
+use swc_core::{
+    base::{
+        config::{Config, ConfigFile, Options},
+        Compiler,
+    },
+    common::{
+        errors::{ColorConfig, DiagnosticBuilder, Handler},
+        FileName, FilePathMapping, SourceMap, GLOBALS,
+    },
+    ecma::lints::fix::fix_until_stable,
+    trace_macro::swc_trace,
+};
+
+use super::{get_files_list, parse_config, DEFAULT_EXTENSIONS};
 
+/// Configuration option for linting files.
 #[derive(Parser)]
-pub struct LintOptions {}
+pub struct LintOptions {
+    /// Experimental: provide an additional JSON config object to override the
+    /// .swcrc. Lint rules are configured in `jsc.lints`.
+    #[clap(long = "config-json", value_parser = parse_config)]
+    config: Option<Config>,
+
+    /// Path to a .swcrc file to use
+    #[clap(long)]
+    config_file: Option<PathBuf>,
+
+    /// Apply the fixes of lint rules to the input files.
+    #[clap(long)]
+    fix: bool,
+
+    /// Maximum number of times to re-lint a file while applying fixes.
+    #[clap(long, default_value_t = 10)]
+    max_fix_passes: usize,
+
+    /// List of glob paths to not lint.
+    #[clap(long)]
+    ignore: Option<String>,
+
+    /// Specify specific file extensions to lint.
+    #[clap(long)]
+    extensions: Option<Vec<String>>,
+
+    /// Files to lint
+    #[clap(required = true)]
+    files: Vec<PathBuf>,
+}
+
+#[swc_trace]
+impl LintOptions {
+    fn build_options(&self, file_path: &Path) -> Options {
+        Options {
+            config: self.config.clone().unwrap_or_default(),
+            config_file: self.config_file.as_ref().map(|config_file_path| {
+                ConfigFile::Str(config_file_path.to_string_lossy().to_string())
+            }),
+            filename: file_path.to_string_lossy().to_string(),
+            swcrc: true,
+            ..Options::default()
+        }
+    }
+
+    /// Lints a single file, emitting diagnostics to `handler`.
+    fn lint_file(
+        &self,
+        compiler: &Compiler,
+        handler: &Handler,
+        file_path: &Path,
+    ) -> anyhow::Result<()> {
+        let options = self.build_options(file_path);
+        let src = fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to open file {}", file_path.display()))?;
+        let name = Arc::new(FileName::Real(file_path.to_path_buf()));
+
+        let diagnostics = if self.fix {
+            let output =
+                fix_until_stable(&compiler.cm, name, src.clone(), self.max_fix_passes, |fm| {
+                    compiler.lint_js_file(fm.clone(), handler, &options)
+                })?;
+
+            if output.code != src {
+                fs::write(file_path, &output.code)
+                    .with_context(|| format!("Failed to write file {}", file_path.display()))?;
+            }
+
+            output.diagnostics
+        } else {
+            let fm = compiler.cm.new_source_file(name, src);
+
+            compiler.lint_js_file(fm, handler, &options)?
+        };
+
+        for diagnostic in diagnostics {
+            DiagnosticBuilder::new_diagnostic(handler, diagnostic).emit();
+        }
+
+        Ok(())
+    }
+}
 
 #[swc_trace]
 impl super::CommandRunner for LintOptions {
     fn execute(&self) -> anyhow::Result<()> {
-        unimplemented!("Lint command is not yet implemented")
+        let extensions = if let Some(extensions) = &self.extensions {
+            extensions.clone()
+        } else {
+            DEFAULT_EXTENSIONS.iter().map(|v| v.to_string()).collect()
+        };
+        let files = get_files_list(&self.files, &extensions, self.ignore.as_deref(), false)?;
+
+        let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
+        let compiler = Compiler::new(cm.clone());
+        let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm));
+
+        let mut failed = 0;
+
+        GLOBALS.set(&Default::default(), || {
+            for file_path in &files {
+                if let Err(err) = self.lint_file(&compiler, &handler, file_path) {
+                    eprintln!("{err:?}");
+                    failed += 1;
+                }
+            }
+        });
+
+        if failed > 0 {
+            anyhow::bail!("Failed to lint {} file(s)", failed);
+        }
+
+        if handler.has_errors() {
+            anyhow::bail!("Found {} error(s)", handler.err_count());
+        }
+
+        Ok(())
     }
 }
@@ -2,12 +2,14 @@ use clap::{Parser, Subcommand};
 
 mod bundle;
 mod compile;
+mod fmt;
 mod lint;
 mod minify;
 mod plugin;
 
 pub use bundle::*;
 pub use compile::*;
+pub use fmt::*;
 pub use lint::*;
 pub use minify::*;
 pub use plugin::PluginSubcommand;
@@ -22,7 +24,9 @@ pub enum Command {
     Compile(Box<CompileOptions>),
     Bundle(BundleOptions),
     Minify(MinifyOptions),
-    Lint(LintOptions),
+    Lint(Box<LintOptions>),
+    /// Format files in the style of prettier.
+    Fmt(FmtOptions),
 }
 
 #[derive(Parser)]
@@ -0,0 +1,70 @@
+use std::{
+    fs,
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+use assert_cmd::prelude::*;
+use assert_fs::TempDir;
+
+fn cli() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("swc").context("Failed to get swc binary")?;
+    cmd.stderr(Stdio::inherit());
+    Ok(cmd)
+}
+
+fn sandbox() -> Result<TempDir> {
+    let sandbox = TempDir::new()?;
+    fs::write(
+        sandbox.path().join("index.ts"),
+        "const a : number=1\nfunction foo(){return a}\n",
+    )?;
+
+    Ok(sandbox)
+}
+
+#[test]
+fn fmt_check() -> Result<()> {
+    let sandbox = sandbox()?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("fmt")
+        .arg("--check")
+        .arg("index.ts");
+
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+#[test]
+fn fmt_write() -> Result<()> {
+    let sandbox = sandbox()?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("fmt")
+        .arg("--write")
+        .arg("--config-json")
+        .arg(r#"{"semi": false}"#)
+        .arg("index.ts");
+
+    cmd.assert().success();
+    assert_eq!(
+        fs::read_to_string(sandbox.path().join("index.ts"))?,
+        "const a: number = 1\nfunction foo() {\n  return a\n}\n"
+    );
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("fmt")
+        .arg("--check")
+        .arg("--config-json")
+        .arg(r#"{"semi": false}"#)
+        .arg("index.ts");
+
+    cmd.assert().success();
+
+    Ok(())
+}
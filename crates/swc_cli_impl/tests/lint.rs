@@ -0,0 +1,72 @@
+use std::{
+    fs,
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+use assert_cmd::prelude::*;
+use assert_fs::TempDir;
+
+fn cli() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("swc").context("Failed to get swc binary")?;
+    cmd.stderr(Stdio::inherit());
+    Ok(cmd)
+}
+
+fn sandbox() -> Result<TempDir> {
+    let sandbox = TempDir::new()?;
+    fs::write(
+        sandbox.path().join(".swcrc"),
+        r#"{
+            "jsc": {
+                "lints": {
+                    "no-var": ["error"],
+                    "prefer-const": ["error"],
+                    "no-debugger": ["warning"]
+                }
+            }
+        }"#,
+    )?;
+    fs::write(
+        sandbox.path().join("index.js"),
+        "var a = 1;\nconsole.log(a);\ndebugger;\n",
+    )?;
+
+    Ok(sandbox)
+}
+
+#[test]
+fn lint_reports_errors() -> Result<()> {
+    let sandbox = sandbox()?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox).arg("lint").arg("index.js");
+
+    cmd.assert().failure();
+    assert_eq!(
+        fs::read_to_string(sandbox.path().join("index.js"))?,
+        "var a = 1;\nconsole.log(a);\ndebugger;\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lint_fix() -> Result<()> {
+    let sandbox = sandbox()?;
+
+    let mut cmd = cli()?;
+    cmd.current_dir(&sandbox)
+        .arg("lint")
+        .arg("--fix")
+        .arg("index.js");
+
+    // Only a warning without a fix is left.
+    cmd.assert().success();
+    assert_eq!(
+        fs::read_to_string(sandbox.path().join("index.js"))?,
+        "const a = 1;\nconsole.log(a);\ndebugger;\n"
+    );
+
+    Ok(())
+}
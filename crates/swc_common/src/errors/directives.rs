@@ -0,0 +1,253 @@
+//! Suppression of lint diagnostics using comments.
+//!
+//! Directives start with a prefix chosen by the linter, e.g. `swc-lint-`.
+//!
+//! - `disable-next-line` suppresses diagnostics on the next line.
+//! - `disable-line` suppresses diagnostics on the line of the comment.
+//! - `disable` and `enable` enclose a range in which diagnostics are
+//!   suppressed. Without a following enable comment, the range extends to the
+//!   end of the file, so a disable comment at the top of a file disables rules
+//!   for the whole file.
+//!
+//! Each directive may be followed by a comma-separated list of rule names, in
+//! which case only those rules are affected. Text after `--` is treated as a
+//! description.
+//!
+//! Rules are identified by the [DiagnosticId::Lint] of their diagnostics.
+
+use super::{Diagnostic, DiagnosticBuilder, DiagnosticId, Level, HANDLER};
+use crate::{
+    comments::{Comment, SingleThreadedComments},
+    errors::fix::capture_diagnostics,
+    BytePos, SourceMap, Span,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveKind {
+    /// Applies to the diagnostics starting in `lo..hi`.
+    Line {
+        lo: BytePos,
+        hi: BytePos,
+    },
+    Disable,
+    Enable,
+}
+
+#[derive(Debug)]
+struct Directive {
+    kind: DirectiveKind,
+    span: Span,
+    /// Empty if the directive applies to all rules.
+    rules: Vec<String>,
+    /// Whether the directive suppressed a diagnostic, for each rule in
+    /// [Directive::rules], or for all rules.
+    used: Vec<bool>,
+}
+
+impl Directive {
+    /// Returns the index into [Directive::used] if `rule` is affected by this
+    /// directive.
+    fn position(&self, rule: &str) -> Option<usize> {
+        if self.rules.is_empty() {
+            Some(0)
+        } else {
+            self.rules.iter().position(|r| r == rule)
+        }
+    }
+}
+
+/// Lint directives of a file, parsed from its comments.
+#[derive(Debug, Default)]
+pub struct Directives {
+    prefix: &'static str,
+    /// Sorted by position.
+    directives: Vec<Directive>,
+}
+
+impl Directives {
+    /// Parses the directives starting with `prefix`.
+    ///
+    /// `text` returns the text of a comment without its delimiters, and the
+    /// span of the whole comment.
+    pub fn parse(
+        cm: &SourceMap,
+        comments: &SingleThreadedComments,
+        prefix: &'static str,
+        text: impl Fn(&Comment) -> Option<(&str, Span)>,
+    ) -> Self {
+        let mut directives = {
+            let (leading, trailing) = comments.borrow_all();
+
+            leading
+                .values()
+                .chain(trailing.values())
+                .flatten()
+                .filter_map(|comment| {
+                    let (text, span) = text(comment)?;
+
+                    parse_directive(cm, prefix, text, span)
+                })
+                .collect::<Vec<_>>()
+        };
+        directives.sort_by_key(|directive| directive.span.lo);
+
+        Self { prefix, directives }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// Runs `op`, emitting the diagnostics it reports unless a directive
+    /// suppresses them.
+    ///
+    /// Directives which didn't suppress any diagnostic are reported with
+    /// `report_unused`, if any.
+    pub fn run(&mut self, report_unused: Option<Level>, op: impl FnOnce()) {
+        if self.is_empty() {
+            op();
+            return;
+        }
+
+        let diagnostics = capture_diagnostics(op);
+
+        HANDLER.with(|handler| {
+            for diagnostic in diagnostics {
+                if !self.suppresses(&diagnostic) {
+                    DiagnosticBuilder::new_diagnostic(handler, diagnostic).emit();
+                }
+            }
+        });
+
+        if let Some(level) = report_unused {
+            self.report_unused(level);
+        }
+    }
+
+    /// Returns `true` if `diagnostic` is suppressed by a directive, marking
+    /// the directive as used.
+    fn suppresses(&mut self, diagnostic: &Diagnostic) -> bool {
+        let Some(DiagnosticId::Lint(rule)) = &diagnostic.code else {
+            return false;
+        };
+        let Some(pos) = diagnostic
+            .span
+            .primary_span()
+            .map(|span| span.lo)
+            .filter(|pos| !pos.is_dummy())
+        else {
+            return false;
+        };
+
+        let mut suppressed = false;
+
+        for directive in &mut self.directives {
+            if let DirectiveKind::Line { lo, hi } = directive.kind {
+                if lo <= pos && pos < hi {
+                    if let Some(idx) = directive.position(rule) {
+                        directive.used[idx] = true;
+                        suppressed = true;
+                    }
+                }
+            }
+        }
+
+        // The last range directive affecting `rule` decides.
+        let range = self
+            .directives
+            .iter_mut()
+            .take_while(|directive| directive.span.hi <= pos)
+            .filter(|directive| {
+                directive.kind == DirectiveKind::Disable || directive.kind == DirectiveKind::Enable
+            })
+            .filter_map(|directive| Some((directive.position(rule)?, directive)))
+            .last();
+
+        if let Some((idx, directive)) = range {
+            if directive.kind == DirectiveKind::Disable {
+                directive.used[idx] = true;
+                suppressed = true;
+            }
+        }
+
+        suppressed
+    }
+
+    fn report_unused(&self, level: Level) {
+        let prefix = self.prefix;
+
+        for directive in &self.directives {
+            if directive.kind == DirectiveKind::Enable {
+                continue;
+            }
+
+            let message = if directive.rules.is_empty() {
+                if directive.used[0] {
+                    continue;
+                }
+
+                format!("Unused {prefix}disable directive (no problems were reported)")
+            } else {
+                let unused = directive
+                    .rules
+                    .iter()
+                    .zip(&directive.used)
+                    .filter(|(_, used)| !**used)
+                    .map(|(rule, _)| format!("'{rule}'"))
+                    .collect::<Vec<_>>();
+                if unused.is_empty() {
+                    continue;
+                }
+
+                format!(
+                    "Unused {prefix}disable directive (no problems were reported from {})",
+                    unused.join(", ")
+                )
+            };
+
+            HANDLER.with(|handler| {
+                let mut db = DiagnosticBuilder::new(handler, level, &message);
+                db.set_span(directive.span);
+                db.emit();
+            });
+        }
+    }
+}
+
+fn parse_directive(cm: &SourceMap, prefix: &str, text: &str, span: Span) -> Option<Directive> {
+    let text = text.trim().strip_prefix(prefix)?;
+    let (text, _description) = text.split_once("--").unwrap_or((text, ""));
+    let (kind, rules) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+
+    let line = |offset: usize| {
+        let line = cm.lookup_line(span.lo).ok()?;
+        let line_index = line.line + offset;
+        if line_index >= line.sf.count_lines() {
+            return None;
+        }
+        let (lo, hi) = line.sf.line_bounds(line_index);
+
+        Some(DirectiveKind::Line { lo, hi })
+    };
+
+    let kind = match kind {
+        "disable-next-line" => line(1)?,
+        "disable-line" => line(0)?,
+        "disable" => DirectiveKind::Disable,
+        "enable" => DirectiveKind::Enable,
+        _ => return None,
+    };
+    let rules = rules
+        .split(',')
+        .map(str::trim)
+        .filter(|rule| !rule.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    Some(Directive {
+        kind,
+        span,
+        used: vec![false; rules.len().max(1)],
+        rules,
+    })
+}
@@ -0,0 +1,207 @@
+//! Autofix support for lint rules.
+//!
+//! Rules attach fixes to their diagnostics as suggestions with
+//! [Applicability::MachineApplicable]. This module extracts those suggestions
+//! and applies them to the source text.
+//!
+//! Fixes of a single lint run may overlap, so only non-overlapping fixes are
+//! applied at once. [fix_until_stable] re-runs the linter on the fixed code
+//! until there's nothing left to fix.
+
+use std::mem::take;
+
+use super::{Applicability, Diagnostic, DiagnosticBuilder, Emitter, Handler, HANDLER};
+use crate::{
+    sync::{Lock, Lrc},
+    FileName, SourceFile, SourceMap, Span,
+};
+
+/// Replaces the text at `span` with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub text: String,
+}
+
+/// A set of edits which must be applied together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub msg: String,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Returns the machine-applicable fixes attached to `diagnostic`.
+///
+/// If a suggestion has multiple alternatives, the first one is used.
+pub fn fixes(diagnostic: &Diagnostic) -> impl '_ + Iterator<Item = Fix> {
+    diagnostic
+        .suggestions
+        .iter()
+        .filter(|s| s.applicability == Applicability::MachineApplicable)
+        .filter_map(|s| {
+            let substitution = s.substitutions.first()?;
+
+            Some(Fix {
+                msg: s.msg.clone(),
+                edits: substitution
+                    .parts
+                    .iter()
+                    .map(|part| TextEdit {
+                        span: part.span,
+                        text: part.snippet.clone(),
+                    })
+                    .collect(),
+            })
+        })
+}
+
+/// Applies `fixes` to the source text of `fm`.
+///
+/// Fixes are applied in the order of their positions. A fix is skipped as a
+/// whole if one of its edits overlaps an edit which is already applied or
+/// points outside of `fm`.
+///
+/// Returns the fixed code and the number of applied fixes.
+pub fn apply_fixes(fm: &SourceFile, fixes: impl IntoIterator<Item = Fix>) -> (String, usize) {
+    let mut fixes = fixes
+        .into_iter()
+        .filter(|fix| {
+            !fix.edits.is_empty()
+                && fix.edits.iter().all(|edit| {
+                    edit.span.lo <= edit.span.hi
+                        && fm.start_pos <= edit.span.lo
+                        && edit.span.hi <= fm.end_pos
+                })
+        })
+        .collect::<Vec<_>>();
+    fixes.sort_by_key(|fix| fix.edits.iter().map(|edit| edit.span.lo).min());
+
+    let mut edits: Vec<TextEdit> = Vec::new();
+    let mut applied = 0;
+
+    for fix in fixes {
+        let overlaps = fix.edits.iter().enumerate().any(|(i, edit)| {
+            edits
+                .iter()
+                .chain(&fix.edits[..i])
+                .any(|other| overlaps(edit.span, other.span))
+        });
+        if overlaps {
+            continue;
+        }
+
+        edits.extend(fix.edits);
+        applied += 1;
+    }
+
+    edits.sort_by_key(|edit| (edit.span.lo, edit.span.hi));
+
+    let src = &fm.src;
+    let mut code = String::with_capacity(src.len());
+    let mut pos = 0;
+
+    for edit in edits {
+        let lo = (edit.span.lo - fm.start_pos).0 as usize;
+        let hi = (edit.span.hi - fm.start_pos).0 as usize;
+
+        code.push_str(&src[pos..lo]);
+        code.push_str(&edit.text);
+        pos = hi;
+    }
+    code.push_str(&src[pos..]);
+
+    (code, applied)
+}
+
+/// An insertion also conflicts with edits touching its position, as the order
+/// of the two would be ambiguous.
+fn overlaps(a: Span, b: Span) -> bool {
+    if a.lo == a.hi || b.lo == b.hi {
+        return a.lo >= b.lo && a.lo <= b.hi || b.lo >= a.lo && b.lo <= a.hi;
+    }
+
+    a.lo < b.hi && b.lo < a.hi
+}
+
+#[derive(Debug)]
+pub struct FixOutput {
+    /// The fixed code.
+    pub code: String,
+    /// The number of applied fixes.
+    pub applied: usize,
+    /// The number of lint runs.
+    pub passes: usize,
+    /// Diagnostics of the last lint run, which are reported for [Self::code].
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Lints and fixes `src` until no more fixes can be applied, or until
+/// `max_passes` passes were made.
+///
+/// `lint` is called with a new [SourceFile] for each pass and should return
+/// the diagnostics reported for it. See [capture_diagnostics].
+pub fn fix_until_stable<E>(
+    cm: &Lrc<SourceMap>,
+    name: Lrc<FileName>,
+    src: String,
+    max_passes: usize,
+    mut lint: impl FnMut(&Lrc<SourceFile>) -> Result<Vec<Diagnostic>, E>,
+) -> Result<FixOutput, E> {
+    let mut code = src;
+    let mut applied = 0;
+    let mut passes = 0;
+
+    loop {
+        let fm = cm.new_source_file(name.clone(), code);
+        let diagnostics = lint(&fm)?;
+        passes += 1;
+
+        if passes > max_passes {
+            return Ok(FixOutput {
+                code: fm.src.to_string(),
+                applied,
+                passes,
+                diagnostics,
+            });
+        }
+
+        let (fixed, cnt) = apply_fixes(&fm, diagnostics.iter().flat_map(fixes));
+
+        if cnt == 0 {
+            return Ok(FixOutput {
+                code: fixed,
+                applied,
+                passes,
+                diagnostics,
+            });
+        }
+
+        code = fixed;
+        applied += cnt;
+    }
+}
+
+/// Runs `op` and returns the diagnostics reported to [HANDLER] while running
+/// it, instead of emitting them.
+pub fn capture_diagnostics(op: impl FnOnce()) -> Vec<Diagnostic> {
+    let capturing = Capturing::default();
+
+    HANDLER.set(
+        &Handler::with_emitter(true, false, Box::new(capturing.clone())),
+        op,
+    );
+
+    let mut diagnostics = capturing.diagnostics.lock();
+    take(&mut *diagnostics)
+}
+
+#[derive(Default, Clone)]
+struct Capturing {
+    diagnostics: Lrc<Lock<Vec<Diagnostic>>>,
+}
+
+impl Emitter for Capturing {
+    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+        self.diagnostics.lock().push(db.take());
+    }
+}
@@ -38,7 +38,9 @@ use crate::{
 mod diagnostic;
 mod diagnostic_builder;
 
+pub mod directives;
 pub mod emitter;
+pub mod fix;
 mod lock;
 mod snippet;
 mod styled_buffer;
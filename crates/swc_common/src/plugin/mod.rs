@@ -18,13 +18,29 @@ pub mod serialized;
  *     flag, or add previous latest feature flag.
  *
  * - When removing, or changing existing properties in the AST struct: TBD
+ *
+ * - When adding a new variant to an AST enum, append it after the existing
+ *   variants and add an adapter between the previous version and the new
+ *   one to `swc_plugin_runner::schema`, so plugins built against the
+ *   previous version keep working.
+ *
+ * Versions:
+ *  1. Initial version.
+ *  2. Adds `Expr::Pipeline` and `Expr::TopicRef`.
  */
-#[cfg(feature = "plugin_transform_schema_v1")]
+#[cfg(feature = "plugin_transform_schema_v2")]
+pub const PLUGIN_TRANSFORM_AST_SCHEMA_VERSION: u32 = 2;
+
+#[cfg(all(
+    feature = "plugin_transform_schema_v1",
+    not(feature = "plugin_transform_schema_v2")
+))]
 pub const PLUGIN_TRANSFORM_AST_SCHEMA_VERSION: u32 = 1;
 
 // Reserved for the testing purpose.
 #[cfg(all(
     feature = "plugin_transform_schema_vtest",
-    not(feature = "plugin_transform_schema_v1")
+    not(feature = "plugin_transform_schema_v1"),
+    not(feature = "plugin_transform_schema_v2")
 ))]
 pub const PLUGIN_TRANSFORM_AST_SCHEMA_VERSION: u32 = u32::MAX - 1;
@@ -0,0 +1,97 @@
+//! Preserves the conditions of inlined `@import`s.
+
+use swc_common::Span;
+use swc_css_ast::*;
+
+/// Wraps the rules of a stylesheet imported through `chain`, which is ordered
+/// from the outermost `@import`.
+///
+/// `@import "a.css" layer(base) supports(display: grid) screen` becomes
+/// `@media screen { @supports (display: grid) { @layer base { ... } } }`.
+pub(crate) fn wrap_rules(rules: Vec<Rule>, chain: &[ImportPrelude]) -> Vec<Rule> {
+    chain.iter().rev().fold(rules, |mut rules, prelude| {
+        if let Some(layer_name) = &prelude.layer_name {
+            // `layer` without a name creates an anonymous layer
+            let layer_prelude = match &**layer_name {
+                ImportLayerName::Ident(_) => None,
+                ImportLayerName::Function(function) => {
+                    function.value.iter().find_map(|value| match value {
+                        ComponentValue::LayerName(name) => Some(AtRulePrelude::LayerPrelude(
+                            LayerPrelude::Name((**name).clone()),
+                        )),
+                        _ => None,
+                    })
+                }
+            };
+
+            rules = vec![at_rule(prelude.span, "layer", layer_prelude, rules)];
+        }
+
+        if let Some(import_conditions) = &prelude.import_conditions {
+            if let Some(condition) = import_conditions
+                .supports
+                .as_deref()
+                .and_then(get_supports_condition)
+            {
+                rules = vec![at_rule(
+                    prelude.span,
+                    "supports",
+                    Some(AtRulePrelude::SupportsPrelude(condition)),
+                    rules,
+                )];
+            }
+
+            if let Some(media) = &import_conditions.media {
+                if !media.queries.is_empty() {
+                    rules = vec![at_rule(
+                        prelude.span,
+                        "media",
+                        Some(AtRulePrelude::MediaPrelude((**media).clone())),
+                        rules,
+                    )];
+                }
+            }
+        }
+
+        rules
+    })
+}
+
+/// Returns `true` if `prelude` changes how the imported rules apply.
+pub(crate) fn is_conditional(prelude: &ImportPrelude) -> bool {
+    prelude.layer_name.is_some() || prelude.import_conditions.is_some()
+}
+
+/// `supports()` contains either a declaration or a condition.
+fn get_supports_condition(function: &Function) -> Option<SupportsCondition> {
+    function.value.iter().find_map(|value| match value {
+        ComponentValue::SupportsCondition(condition) => Some((**condition).clone()),
+        ComponentValue::Declaration(declaration) => Some(SupportsCondition {
+            span: declaration.span,
+            conditions: vec![SupportsConditionType::SupportsInParens(
+                SupportsInParens::Feature(SupportsFeature::Declaration(declaration.clone())),
+            )],
+        }),
+        _ => None,
+    })
+}
+
+fn at_rule(span: Span, name: &str, prelude: Option<AtRulePrelude>, rules: Vec<Rule>) -> Rule {
+    Rule::AtRule(Box::new(AtRule {
+        span,
+        name: AtRuleName::Ident(Ident {
+            span,
+            value: name.into(),
+            raw: None,
+        }),
+        prelude: prelude.map(Box::new),
+        block: Some(SimpleBlock {
+            span,
+            name: TokenAndSpan {
+                span,
+                token: Token::LBrace,
+            },
+            value: rules.into_iter().map(ComponentValue::from).collect(),
+        }),
+    }))
+}
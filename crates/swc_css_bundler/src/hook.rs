@@ -0,0 +1,13 @@
+use anyhow::Error;
+use swc_atoms::Atom;
+use swc_common::FileName;
+
+pub trait Hook: swc_common::sync::Sync + swc_common::sync::Send {
+    /// Called for each `url()` of each inlined stylesheet, except `data:`
+    /// urls and the hrefs of `@import`s.
+    ///
+    /// `file` is the stylesheet which contains the `url()`, so relative urls
+    /// can be resolved against it. Returns the new url, e.g. the path of a
+    /// hashed copy of the asset, or `None` to keep the url.
+    fn rewrite_url(&self, file: &FileName, url: &Atom) -> Result<Option<Atom>, Error>;
+}
@@ -0,0 +1,368 @@
+//! Bundles CSS files by inlining their `@import`s.
+//!
+//! `@import`s are resolved by [Resolve], loaded by [Load] and replaced by the
+//! rules of the imported stylesheets in cascade order. Layers and conditions
+//! of `@import`s are preserved by wrapping the inlined rules, e.g.
+//!
+//! ```css
+//! @import "a.css" layer(base) supports(display: grid) screen;
+//! ```
+//!
+//! becomes
+//!
+//! ```css
+//! @media screen { @supports (display: grid) { @layer base { /* a.css */ } } }
+//! ```
+//!
+//! `url()`s of every inlined stylesheet are passed to [Hook::rewrite_url], so
+//! assets can be hashed or copied.
+//!
+//! Inlined rules keep the spans of their files, so `swc_css_codegen` can
+//! generate a source map of the bundle which points to the original files.
+//!
+//! [Resolve] is shared with `swc_bundler`, so the CSS files imported by a
+//! JavaScript bundle can be bundled with the same resolver by passing them to
+//! [Bundler::bundle] in the order of their imports.
+#![deny(clippy::all)]
+
+use anyhow::{bail, Context, Error};
+use rustc_hash::FxHashMap;
+use swc_common::{EqIgnoreSpan, FileName, DUMMY_SP};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitMutWith, VisitWith};
+pub use swc_ecma_loader::resolve::Resolve;
+
+use self::{
+    conditions::{is_conditional, wrap_rules},
+    url::UrlRewriter,
+};
+pub use self::{hook::Hook, load::Load};
+
+mod conditions;
+mod hook;
+mod load;
+mod url;
+
+#[derive(Debug)]
+pub struct Bundle {
+    pub stylesheet: Stylesheet,
+    /// Inlined files in the order of their first `@import`, including the
+    /// entries.
+    pub files: Vec<FileName>,
+}
+
+pub struct Bundler<'a, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    loader: L,
+    resolver: R,
+    hook: Box<dyn 'a + Hook>,
+}
+
+impl<'a, L, R> Bundler<'a, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    pub fn new(loader: L, resolver: R, hook: Box<dyn 'a + Hook>) -> Self {
+        Bundler {
+            loader,
+            resolver,
+            hook,
+        }
+    }
+
+    /// Bundles `entries` into one stylesheet, as if they were imported in
+    /// order.
+    ///
+    /// `@import`s of urls like `https://example.com/a.css` are kept, and
+    /// moved to the top of the bundle.
+    pub fn bundle(&self, entries: &[FileName]) -> Result<Bundle, Error> {
+        let mut state = State::default();
+
+        for entry in entries {
+            self.inline(&mut state, entry, Vec::new())?;
+        }
+
+        let mut rules = Vec::new();
+
+        rules.extend(state.charset);
+        rules.extend(state.external_imports);
+
+        for chunk in state.chunks {
+            let import = &state.imports[chunk.import];
+
+            if state.imports[chunk.import + 1..]
+                .iter()
+                .any(|later| import.is_duplicated_by(later))
+            {
+                continue;
+            }
+
+            rules.extend(wrap_rules(chunk.rules, &import.chain));
+        }
+
+        Ok(Bundle {
+            stylesheet: Stylesheet {
+                span: DUMMY_SP,
+                rules,
+            },
+            files: state.files,
+        })
+    }
+
+    fn inline(
+        &self,
+        state: &mut State,
+        file: &FileName,
+        chain: Vec<ImportPrelude>,
+    ) -> Result<(), Error> {
+        if let Some(pos) = state.stack.iter().position(|f| f == file) {
+            let cycle = state.stack[pos..]
+                .iter()
+                .chain([file])
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+
+            bail!("circular `@import`: {cycle}")
+        }
+
+        let stylesheet = self.load(state, file)?;
+        let import = state.imports.len();
+
+        state.imports.push(Import {
+            file: file.clone(),
+            // The order of layers is defined by their first occurrence
+            can_be_deduplicated: !chain.iter().any(|prelude| prelude.layer_name.is_some())
+                && !has_layer(&stylesheet),
+            chain: chain.clone(),
+        });
+        state.stack.push(file.clone());
+
+        let mut rules = Vec::new();
+
+        for rule in stylesheet.rules {
+            let Rule::AtRule(at_rule) = rule else {
+                rules.push(rule);
+                continue;
+            };
+
+            if at_rule.name == *"charset" {
+                // Only the charset of the first file is used by browsers
+                if import == 0 {
+                    state.charset = Some(Rule::AtRule(at_rule));
+                }
+
+                continue;
+            }
+
+            let Some(AtRulePrelude::ImportPrelude(prelude)) = at_rule.prelude.as_deref() else {
+                rules.push(Rule::AtRule(at_rule));
+                continue;
+            };
+
+            let href = match &*prelude.href {
+                ImportHref::Url(Url { value, .. }) => match value.as_deref() {
+                    Some(UrlValue::Str(Str { value, .. }))
+                    | Some(UrlValue::Raw(UrlValueRaw { value, .. })) => value,
+                    None => {
+                        rules.push(Rule::AtRule(at_rule));
+                        continue;
+                    }
+                },
+                ImportHref::Str(Str { value, .. }) => value,
+            };
+
+            if is_external(href) {
+                state.add_external_import(prelude, &chain)?;
+                continue;
+            }
+
+            let resolution = self
+                .resolver
+                .resolve(file, href)
+                .with_context(|| format!("failed to resolve `{href}` from `{file}`"))?;
+
+            if !rules.is_empty() {
+                state.chunks.push(Chunk {
+                    import,
+                    rules: std::mem::take(&mut rules),
+                });
+            }
+
+            let mut chain = chain.clone();
+
+            if is_conditional(prelude) {
+                chain.push(prelude.clone());
+            }
+
+            self.inline(state, &resolution.filename, chain)?;
+        }
+
+        if !rules.is_empty() {
+            state.chunks.push(Chunk { import, rules });
+        }
+
+        state.stack.pop();
+
+        Ok(())
+    }
+
+    fn load(&self, state: &mut State, file: &FileName) -> Result<Stylesheet, Error> {
+        if let Some(stylesheet) = state.cache.get(file) {
+            return Ok(stylesheet.clone());
+        }
+
+        let mut stylesheet = self
+            .loader
+            .load(file)
+            .with_context(|| format!("failed to load `{file}`"))?;
+        let mut rewriter = UrlRewriter {
+            file,
+            hook: &*self.hook,
+            error: None,
+        };
+
+        stylesheet.visit_mut_with(&mut rewriter);
+
+        if let Some(err) = rewriter.error {
+            return Err(err.context(format!("failed to rewrite urls of `{file}`")));
+        }
+
+        state.files.push(file.clone());
+        state.cache.insert(file.clone(), stylesheet.clone());
+
+        Ok(stylesheet)
+    }
+}
+
+#[derive(Default)]
+struct State {
+    files: Vec<FileName>,
+    cache: FxHashMap<FileName, Stylesheet>,
+    /// Files being inlined, used to detect cycles.
+    stack: Vec<FileName>,
+
+    charset: Option<Rule>,
+    external_imports: Vec<Rule>,
+    imports: Vec<Import>,
+    chunks: Vec<Chunk>,
+}
+
+impl State {
+    /// External `@import`s must precede other rules, so we move them to the
+    /// top of the bundle.
+    fn add_external_import(
+        &mut self,
+        prelude: &ImportPrelude,
+        chain: &[ImportPrelude],
+    ) -> Result<(), Error> {
+        let mut prelude = prelude.clone();
+
+        match chain {
+            [] => {}
+            // `@import` can't be nested, so we can only move the conditions of
+            // the parent `@import`
+            [parent] if !is_conditional(&prelude) => {
+                prelude.layer_name.clone_from(&parent.layer_name);
+                prelude
+                    .import_conditions
+                    .clone_from(&parent.import_conditions);
+            }
+            _ => bail!(
+                "cannot move the external `@import` at {:?} out of conditional `@import`s",
+                prelude.span
+            ),
+        }
+
+        let rule = Rule::AtRule(Box::new(AtRule {
+            span: prelude.span,
+            name: AtRuleName::Ident(Ident {
+                span: prelude.span,
+                value: "import".into(),
+                raw: None,
+            }),
+            prelude: Some(Box::new(AtRulePrelude::ImportPrelude(prelude))),
+            block: None,
+        }));
+
+        if !self
+            .external_imports
+            .iter()
+            .any(|external_import| external_import.eq_ignore_span(&rule))
+        {
+            self.external_imports.push(rule);
+        }
+
+        Ok(())
+    }
+}
+
+/// An inlined `@import`.
+struct Import {
+    file: FileName,
+    /// Conditional `@import`s from the outermost one.
+    chain: Vec<ImportPrelude>,
+    can_be_deduplicated: bool,
+}
+
+impl Import {
+    /// Rules of a file imported twice under the same conditions only apply at
+    /// the last `@import`.
+    fn is_duplicated_by(&self, later: &Import) -> bool {
+        self.can_be_deduplicated
+            && later.can_be_deduplicated
+            && self.file == later.file
+            && self.chain.eq_ignore_span(&later.chain)
+    }
+}
+
+/// Rules of a file between its `@import`s.
+struct Chunk {
+    import: usize,
+    rules: Vec<Rule>,
+}
+
+/// Returns `true` for `@import`s which can't be resolved locally, e.g.
+/// `https://example.com/a.css` or `//example.com/a.css`.
+fn is_external(href: &str) -> bool {
+    if href.starts_with("//") {
+        return true;
+    }
+
+    let Some((scheme, _)) = href.split_once(':') else {
+        return false;
+    };
+
+    // A single letter is a drive letter of Windows
+    scheme.len() > 1
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+fn has_layer(stylesheet: &Stylesheet) -> bool {
+    struct LayerFinder {
+        found: bool,
+    }
+
+    impl Visit for LayerFinder {
+        fn visit_at_rule(&mut self, n: &AtRule) {
+            if n.name == *"layer" {
+                self.found = true;
+            }
+
+            n.visit_children_with(self);
+        }
+    }
+
+    let mut finder = LayerFinder { found: false };
+
+    stylesheet.visit_with(&mut finder);
+
+    finder.found
+}
@@ -0,0 +1,24 @@
+use anyhow::Error;
+use swc_common::FileName;
+use swc_css_ast::Stylesheet;
+
+/// Responsible for providing stylesheets to the bundler.
+///
+/// Stylesheets should be parsed into the [swc_common::SourceMap] which is
+/// used to generate the code of the bundle, otherwise the source map of the
+/// bundle will point to wrong files.
+pub trait Load: swc_common::sync::Send + swc_common::sync::Sync {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error>;
+}
+
+impl<T: ?Sized + Load> Load for Box<T> {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        (**self).load(file)
+    }
+}
+
+impl<T: ?Sized + Load> Load for &T {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        (**self).load(file)
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::Error;
+use swc_common::FileName;
+use swc_css_ast::{ImportPrelude, Str, Url, UrlValue};
+use swc_css_visit::VisitMut;
+
+use crate::Hook;
+
+pub(crate) struct UrlRewriter<'a> {
+    pub file: &'a FileName,
+    pub hook: &'a dyn Hook,
+    pub error: Option<Error>,
+}
+
+impl VisitMut for UrlRewriter<'_> {
+    /// Inlined by the bundler.
+    fn visit_mut_import_prelude(&mut self, _: &mut ImportPrelude) {}
+
+    fn visit_mut_url(&mut self, n: &mut Url) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let Some(value) = &mut n.value else {
+            return;
+        };
+        let url = match &**value {
+            UrlValue::Str(s) => &s.value,
+            UrlValue::Raw(raw) => &raw.value,
+        };
+
+        if url.starts_with("data:") {
+            return;
+        }
+
+        let new_url = match self.hook.rewrite_url(self.file, url) {
+            Ok(Some(new_url)) => new_url,
+            Ok(None) => return,
+            Err(err) => {
+                self.error = Some(err);
+
+                return;
+            }
+        };
+
+        match &mut **value {
+            UrlValue::Str(s) => {
+                s.value = new_url;
+                s.raw = None;
+            }
+            // Unquoted urls can't contain these characters
+            UrlValue::Raw(raw)
+                if new_url.contains(|c: char| {
+                    c.is_ascii_whitespace() || matches!(c, '(' | ')' | '\'' | '"' | '\\')
+                }) =>
+            {
+                **value = UrlValue::Str(Str {
+                    span: raw.span,
+                    value: new_url,
+                    raw: None,
+                });
+            }
+            UrlValue::Raw(raw) => {
+                raw.value = new_url;
+                raw.raw = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{anyhow, Error};
+use swc_atoms::Atom;
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_css_ast::Stylesheet;
+use swc_css_bundler::{Bundler, Hook, Load, Resolve};
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_parser::parse_file;
+use swc_ecma_loader::resolve::Resolution;
+use testing::NormalizedOutput;
+
+struct Loader {
+    cm: Lrc<SourceMap>,
+}
+
+impl Load for Loader {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        let FileName::Real(path) = file else {
+            unreachable!()
+        };
+        let fm = self.cm.load_file(path)?;
+
+        parse_file(&fm, None, Default::default(), &mut Vec::new())
+            .map_err(|err| anyhow!("failed to parse: {:?}", err))
+    }
+}
+
+struct Resolver;
+
+impl Resolve for Resolver {
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error> {
+        let FileName::Real(base) = base else {
+            unreachable!()
+        };
+        let path = base.parent().unwrap().join(module_specifier);
+
+        if !path.exists() {
+            return Err(anyhow!("{} does not exist", path.display()));
+        }
+
+        Ok(Resolution {
+            filename: FileName::Real(path),
+            slug: None,
+        })
+    }
+}
+
+/// Rebases relative urls on the directory of the entry.
+struct RebaseUrls {
+    dir: PathBuf,
+}
+
+impl Hook for RebaseUrls {
+    fn rewrite_url(&self, file: &FileName, url: &Atom) -> Result<Option<Atom>, Error> {
+        let FileName::Real(file) = file else {
+            unreachable!()
+        };
+
+        if url.starts_with('/') || url.starts_with('#') || url.contains(':') {
+            return Ok(None);
+        }
+
+        let mut path = file.parent().unwrap().to_path_buf();
+
+        for component in Path::new(&**url).components() {
+            match component {
+                Component::ParentDir => {
+                    path.pop();
+                }
+                _ => path.push(component),
+            }
+        }
+
+        let path = path.strip_prefix(&self.dir)?;
+
+        Ok(Some(path.to_string_lossy().replace('\\', "/").into()))
+    }
+}
+
+fn bundle(cm: Lrc<SourceMap>, entry: &Path) -> Result<(String, Vec<FileName>), Error> {
+    let bundler = Bundler::new(
+        Loader { cm: cm.clone() },
+        Resolver,
+        Box::new(RebaseUrls {
+            dir: entry.parent().unwrap().to_path_buf(),
+        }),
+    );
+    let bundle = bundler.bundle(&[FileName::Real(entry.to_path_buf())])?;
+
+    let mut css_str = String::new();
+    let mut src_map_buf = Vec::new();
+    {
+        let wr = BasicCssWriter::new(
+            &mut css_str,
+            Some(&mut src_map_buf),
+            BasicCssWriterConfig::default(),
+        );
+        let mut gen = CodeGenerator::new(wr, CodegenConfig { minify: false });
+
+        gen.emit(&bundle.stylesheet).unwrap();
+    }
+
+    let source_map = cm.build_source_map(&src_map_buf);
+
+    // Every inlined file should be mapped
+    for file in &bundle.files {
+        assert!(
+            source_map
+                .sources()
+                .any(|source| *source == file.to_string()),
+            "{file} is not in the source map"
+        );
+    }
+
+    Ok((css_str, bundle.files))
+}
+
+#[testing::fixture("tests/fixture/**/input.css")]
+fn fixture(input: PathBuf) {
+    let output = input.with_file_name("output.css");
+
+    testing::run_test2(false, |cm, _| {
+        let (css_str, _) = bundle(cm, &input).unwrap();
+
+        NormalizedOutput::from(css_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn circular_import() {
+    let input = PathBuf::from("tests/error/circular/a.css")
+        .canonicalize()
+        .unwrap();
+
+    testing::run_test2(false, |cm, _| {
+        let err = bundle(cm, &input).unwrap_err();
+
+        assert!(format!("{err:#}").contains("circular `@import`"), "{err:#}");
+
+        Ok(())
+    })
+    .unwrap();
+}
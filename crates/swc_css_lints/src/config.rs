@@ -1,14 +1,18 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, mem::take, path::Path};
 
 use serde::{Deserialize, Serialize};
+use swc_config::{glob::CachedGlob, merge::Merge};
 
 use crate::rules::{
     at_rule_no_unknown::AtRuleNoUnknownConfig, color_hex_alpha::ColorHexAlphaConfig,
     color_hex_length::ColorHexLengthConfig,
+    declaration_block_no_duplicate_properties::DeclarationBlockNoDuplicatePropertiesConfig,
     font_family_no_duplicate_names::FontFamilyNoDuplicateNamesConfig,
     no_invalid_position_at_import_rule::NoInvalidPositionAtImportRuleConfig,
-    selector_max_class::SelectorMaxClassConfig,
-    selector_max_combinators::SelectorMaxCombinatorsConfig, unit_no_unknown::UnitNoUnknownConfig,
+    property_no_unknown::PropertyNoUnknownConfig, selector_max_class::SelectorMaxClassConfig,
+    selector_max_combinators::SelectorMaxCombinatorsConfig,
+    selector_pseudo_class_no_unknown::SelectorPseudoClassNoUnknownConfig,
+    unit_no_unknown::UnitNoUnknownConfig,
 };
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -30,11 +34,12 @@ impl Default for LintRuleReaction {
 enum LintRuleLevel {
     Str(LintRuleReaction),
     Number(u8),
+    Unspecified,
 }
 
 impl Default for LintRuleLevel {
     fn default() -> Self {
-        Self::Str(LintRuleReaction::Off)
+        Self::Unspecified
     }
 }
 
@@ -47,6 +52,7 @@ impl From<LintRuleLevel> for LintRuleReaction {
                 2 => LintRuleReaction::Error,
                 _ => LintRuleReaction::Off,
             },
+            LintRuleLevel::Unspecified => LintRuleReaction::Off,
         }
     }
 }
@@ -69,7 +75,19 @@ impl<T: Debug + Clone + Serialize + Default> RuleConfig<T> {
     }
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+impl<T> Merge for RuleConfig<T>
+where
+    T: Debug + Clone + Serialize + Default,
+{
+    fn merge(&mut self, other: Self) {
+        if let LintRuleLevel::Unspecified = self.0 {
+            self.0 = other.0;
+            self.1 = other.1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Merge)]
 #[non_exhaustive]
 #[serde(rename_all = "kebab-case")]
 pub struct RulesConfig {
@@ -117,10 +135,106 @@ pub struct RulesConfig {
 
     #[serde(default, alias = "customPropertyNoMissingVarFunction")]
     pub custom_property_no_missing_var_function: RuleConfig<()>,
+
+    #[serde(default, alias = "declarationBlockNoDuplicateProperties")]
+    pub declaration_block_no_duplicate_properties:
+        RuleConfig<DeclarationBlockNoDuplicatePropertiesConfig>,
+
+    #[serde(default, alias = "declarationBlockNoShorthandPropertyOverrides")]
+    pub declaration_block_no_shorthand_property_overrides: RuleConfig<()>,
+
+    #[serde(default, alias = "lengthZeroNoUnit")]
+    pub length_zero_no_unit: RuleConfig<()>,
+
+    #[serde(default, alias = "noDescendingSpecificity")]
+    pub no_descending_specificity: RuleConfig<()>,
+
+    #[serde(default, alias = "propertyNoUnknown")]
+    pub property_no_unknown: RuleConfig<PropertyNoUnknownConfig>,
+
+    #[serde(default, alias = "selectorPseudoClassNoUnknown")]
+    pub selector_pseudo_class_no_unknown: RuleConfig<SelectorPseudoClassNoUnknownConfig>,
+
+    #[serde(default, alias = "shorthandPropertyNoRedundantValues")]
+    pub shorthand_property_no_redundant_values: RuleConfig<()>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct LintConfig {
     #[serde(default)]
     pub rules: RulesConfig,
+
+    /// Reports `swc-css-lint-disable` comments which don't suppress anything.
+    /// See [crate::directives].
+    #[serde(default, alias = "reportUnusedDisableDirectives")]
+    pub report_unused_disable_directives: RuleConfig<()>,
+
+    /// Rule configs for specific files. See [LintConfig::apply_overrides].
+    #[serde(default)]
+    pub overrides: Vec<LintOverride>,
+}
+
+impl LintConfig {
+    /// Applies the [LintConfig::overrides] matching `file`, in order, so the
+    /// last matching override takes precedence.
+    ///
+    /// Globs are matched against the path of `file` and against the path
+    /// relative to the current directory.
+    pub fn apply_overrides(&mut self, file: &Path) {
+        let overrides = take(&mut self.overrides);
+        if overrides.is_empty() {
+            return;
+        }
+
+        let relative = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| file.strip_prefix(cwd).ok().map(Path::to_path_buf));
+        let paths = [Some(file), relative.as_deref()];
+        let paths = paths
+            .iter()
+            .flatten()
+            .map(|path| path.to_string_lossy())
+            .collect::<Vec<_>>();
+
+        for o in overrides {
+            if !o.files.is_match(&paths) {
+                continue;
+            }
+
+            let mut rules = o.rules;
+            rules.merge(take(&mut self.rules));
+            self.rules = rules;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintOverride {
+    /// Glob patterns of the files this override applies to.
+    pub files: LintOverrideFiles,
+
+    /// Rules configured for matching files. Rules which are not specified
+    /// here keep their original config.
+    pub rules: RulesConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LintOverrideFiles {
+    Single(CachedGlob),
+    Multiple(Vec<CachedGlob>),
+}
+
+impl LintOverrideFiles {
+    fn is_match<S: AsRef<str>>(&self, paths: &[S]) -> bool {
+        let globs = match self {
+            LintOverrideFiles::Single(glob) => std::slice::from_ref(glob),
+            LintOverrideFiles::Multiple(globs) => globs,
+        };
+
+        globs
+            .iter()
+            .any(|glob| paths.iter().any(|path| glob.is_match(path.as_ref())))
+    }
 }
@@ -0,0 +1,105 @@
+//! Suppression of lint diagnostics using comments.
+//!
+//! - `/* swc-css-lint-disable-next-line */` suppresses diagnostics on the next
+//!   line.
+//! - `/* swc-css-lint-disable-line */` suppresses diagnostics on the line of
+//!   the comment.
+//! - `/* swc-css-lint-disable */` and `/* swc-css-lint-enable */` enclose a
+//!   range in which diagnostics are suppressed. Without a following enable
+//!   comment, the range extends to the end of the file, so a disable comment at
+//!   the top of a file disables rules for the whole file.
+//!
+//! Each directive may be followed by a comma-separated list of rule names, in
+//! which case only those rules are affected. Text after `--` is treated as a
+//! description.
+//!
+//! ```css
+//! a {
+//!     /* swc-css-lint-disable-next-line declaration-no-important -- legacy */
+//!     color: red !important;
+//! }
+//! ```
+//!
+//! Comments are collected by passing [SingleThreadedComments] to the lexer of
+//! `swc_css_parser`.
+
+//!
+//! See [swc_common::errors::directives] for the shared implementation.
+
+use swc_common::{comments::SingleThreadedComments, errors::Level, BytePos, SourceMap, Span};
+use swc_css_ast::Stylesheet;
+
+use crate::{
+    config::{LintConfig, LintRuleReaction},
+    rule::LintRule,
+};
+
+const PREFIX: &str = "swc-css-lint-";
+
+/// Lint directives of a file, parsed from its comments.
+#[derive(Debug, Default)]
+pub struct Directives(swc_common::errors::directives::Directives);
+
+impl Directives {
+    pub fn parse(cm: &SourceMap, comments: &SingleThreadedComments) -> Self {
+        Self(swc_common::errors::directives::Directives::parse(
+            cm,
+            comments,
+            PREFIX,
+            |comment| {
+                // The lexer of `swc_css_parser` keeps the delimiters in the text, and the
+                // span may start at the previous token, so we only rely on its end.
+                let text = comment.text.strip_prefix("/*")?.strip_suffix("*/")?;
+                let span = Span::new(
+                    comment.span.hi - BytePos(comment.text.len() as u32),
+                    comment.span.hi,
+                );
+
+                Some((text, span))
+            },
+        ))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Filters the diagnostics of `rule` using `directives`, and reports unused
+/// directives if [LintConfig::report_unused_disable_directives] is enabled.
+pub fn with_directives<R>(rule: R, directives: Directives, config: &LintConfig) -> impl LintRule
+where
+    R: LintRule,
+{
+    WithDirectives {
+        rule,
+        directives,
+        report_unused: match config.report_unused_disable_directives.get_rule_reaction() {
+            LintRuleReaction::Off => None,
+            LintRuleReaction::Warning => Some(Level::Warning),
+            LintRuleReaction::Error => Some(Level::Error),
+        },
+    }
+}
+
+#[derive(Debug)]
+struct WithDirectives<R>
+where
+    R: LintRule,
+{
+    rule: R,
+    directives: Directives,
+    report_unused: Option<Level>,
+}
+
+impl<R> LintRule for WithDirectives<R>
+where
+    R: LintRule,
+{
+    fn lint_stylesheet(&mut self, stylesheet: &Stylesheet) {
+        let rule = &mut self.rule;
+        self.directives
+            .0
+            .run(self.report_unused, || rule.lint_stylesheet(stylesheet));
+    }
+}
@@ -2,12 +2,14 @@
 
 mod config;
 mod dataset;
+pub mod directives;
 mod error;
+pub mod fix;
 mod pattern;
 mod rule;
 mod rules;
 
-pub use config::LintConfig;
+pub use config::{LintConfig, LintOverride, LintOverrideFiles};
 pub(crate) use error::ConfigError;
-pub use rule::LintRule;
+pub use rule::{rule_name, LintRule};
 pub use rules::{get_rules, LintParams};
@@ -1,17 +1,17 @@
-use std::{fmt::Debug, sync::Arc};
+use std::fmt::Debug;
 
 use auto_impl::auto_impl;
-use parking_lot::Mutex;
 use rayon::prelude::*;
 use serde::Serialize;
 use swc_common::{
-    errors::{Diagnostic, DiagnosticBuilder, Emitter, Handler, HANDLER},
-    Spanned,
+    errors::{Applicability, Diagnostic, DiagnosticBuilder, DiagnosticId, HANDLER},
+    Span, Spanned,
 };
 use swc_css_ast::Stylesheet;
 use swc_css_visit::{Visit, VisitWith};
 
 use super::config::{LintRuleReaction, RuleConfig};
+use crate::fix::capture_diagnostics;
 
 /// A lint rule.
 ///
@@ -36,17 +36,7 @@ where
         } else {
             let errors = self
                 .par_iter_mut()
-                .flat_map(|rule| {
-                    let emitter = Capturing::default();
-                    {
-                        let handler = Handler::with_emitter(true, false, Box::new(emitter.clone()));
-                        HANDLER.set(&handler, || {
-                            rule.lint_stylesheet(stylesheet);
-                        });
-                    }
-
-                    Arc::try_unwrap(emitter.errors).unwrap().into_inner()
-                })
+                .flat_map(|rule| capture_diagnostics(|| rule.lint_stylesheet(stylesheet)))
                 .collect::<Vec<_>>();
 
             HANDLER.with(|handler| {
@@ -58,14 +48,35 @@ where
     }
 }
 
-#[derive(Default, Clone)]
-struct Capturing {
-    errors: Arc<Mutex<Vec<Diagnostic>>>,
+/// Sets the code of diagnostics reported by `rule` to `name`.
+pub(crate) fn named_rule(name: &'static str, rule: Box<dyn LintRule>) -> Box<dyn LintRule> {
+    Box::new(NamedRule { name, rule })
 }
 
-impl Emitter for Capturing {
-    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
-        self.errors.lock().push(db.take());
+/// Returns the name of the rule which reported `diagnostic`.
+pub fn rule_name(diagnostic: &Diagnostic) -> Option<&str> {
+    match &diagnostic.code {
+        Some(DiagnosticId::Lint(name)) => Some(name),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+struct NamedRule {
+    name: &'static str,
+    rule: Box<dyn LintRule>,
+}
+
+impl LintRule for NamedRule {
+    fn lint_stylesheet(&mut self, stylesheet: &Stylesheet) {
+        let diagnostics = capture_diagnostics(|| self.rule.lint_stylesheet(stylesheet));
+
+        HANDLER.with(|handler| {
+            for mut diagnostic in diagnostics {
+                diagnostic.code = Some(DiagnosticId::Lint(self.name.into()));
+                DiagnosticBuilder::new_diagnostic(handler, diagnostic).emit();
+            }
+        });
     }
 }
 
@@ -121,6 +132,64 @@ where
         });
     }
 
+    /// Reports `ast_node` with a note pointing to another node, e.g. the node
+    /// it conflicts with.
+    pub(crate) fn report_with_note<N, S>(
+        &self,
+        ast_node: N,
+        message: S,
+        note_span: Span,
+        note: &str,
+    ) where
+        N: Spanned,
+        S: AsRef<str>,
+    {
+        HANDLER.with(|handler| match self.reaction {
+            LintRuleReaction::Error => handler
+                .struct_span_err(ast_node.span(), message.as_ref())
+                .span_note(note_span, note)
+                .emit(),
+            LintRuleReaction::Warning => handler
+                .struct_span_warn(ast_node.span(), message.as_ref())
+                .span_note(note_span, note)
+                .emit(),
+            _ => {}
+        });
+    }
+
+    /// Reports `ast_node` with a fix which replaces the text of each span with
+    /// its string. Fixes are applied by [crate::fix::apply_fixes].
+    pub(crate) fn report_with_fix<N, S>(
+        &self,
+        ast_node: N,
+        message: S,
+        fix_message: &str,
+        edits: Vec<(Span, String)>,
+    ) where
+        N: Spanned,
+        S: AsRef<str>,
+    {
+        HANDLER.with(|handler| {
+            let mut diagnostic = match self.reaction {
+                LintRuleReaction::Error => {
+                    handler.struct_span_err(ast_node.span(), message.as_ref())
+                }
+                LintRuleReaction::Warning => {
+                    handler.struct_span_warn(ast_node.span(), message.as_ref())
+                }
+                _ => return,
+            };
+
+            diagnostic
+                .multipart_suggestion_with_applicability(
+                    fix_message,
+                    edits,
+                    Applicability::MachineApplicable,
+                )
+                .emit();
+        });
+    }
+
     #[inline]
     pub(crate) fn config(&self) -> &C {
         &self.config
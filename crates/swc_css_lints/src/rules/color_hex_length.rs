@@ -31,8 +31,18 @@ struct ColorHexLength {
 }
 
 impl ColorHexLength {
-    fn build_message(&self, actual: &str, expected: &str) -> String {
-        format!("Hex color value '#{actual}' should be written into: '#{expected}'.")
+    fn report(&self, hex_color: &HexColor, expected: &str) {
+        let message = format!(
+            "Hex color value '#{}' should be written into: '#{expected}'.",
+            hex_color.value
+        );
+
+        self.ctx.report_with_fix(
+            hex_color,
+            message,
+            "replace the hex color",
+            vec![(hex_color.span, format!("#{expected}"))],
+        );
     }
 }
 
@@ -41,14 +51,12 @@ impl Visit for ColorHexLength {
         match self.form {
             HexForm::Long => {
                 if let Some(lengthened) = lengthen(&hex_color.value) {
-                    let message = self.build_message(&hex_color.value, &lengthened);
-                    self.ctx.report(hex_color, message);
+                    self.report(hex_color, &lengthened);
                 }
             }
             HexForm::Short => {
                 if let Some(shortened) = shorten(&hex_color.value) {
-                    let message = self.build_message(&hex_color.value, &shortened);
-                    self.ctx.report(hex_color, message);
+                    self.report(hex_color, &shortened);
                 }
             }
         }
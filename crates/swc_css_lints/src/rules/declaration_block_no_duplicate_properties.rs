@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use swc_atoms::Atom;
+use swc_common::{EqIgnoreSpan, Span, Spanned};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclarationBlockNoDuplicatePropertiesConfig {
+    ignore: Option<Vec<IgnoreDuplicates>>,
+    ignore_properties: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IgnoreDuplicates {
+    /// e.g. `display: flex; display: flex;`
+    ConsecutiveDuplicates,
+    /// Fallbacks, e.g. `display: -webkit-box; display: flex;`
+    ConsecutiveDuplicatesWithDifferentValues,
+}
+
+pub fn declaration_block_no_duplicate_properties(
+    ctx: LintRuleContext<DeclarationBlockNoDuplicatePropertiesConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignore = ctx.config().ignore.clone().unwrap_or_default();
+    let ignored_properties = ctx
+        .config()
+        .ignore_properties
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+
+    Ok(visitor_rule(
+        ctx.reaction(),
+        DeclarationBlockNoDuplicateProperties {
+            ctx,
+            ignore,
+            ignored_properties,
+        },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct DeclarationBlockNoDuplicateProperties {
+    ctx: LintRuleContext<DeclarationBlockNoDuplicatePropertiesConfig>,
+    ignore: Vec<IgnoreDuplicates>,
+    ignored_properties: Vec<NamePattern>,
+}
+
+impl Visit for DeclarationBlockNoDuplicateProperties {
+    fn visit_simple_block(&mut self, simple_block: &SimpleBlock) {
+        // Names and indexes of the last declarations of properties
+        let mut seen: Vec<(Atom, usize)> = Vec::new();
+        let mut last_declaration = None;
+
+        for (index, node) in simple_block.value.iter().enumerate() {
+            let ComponentValue::Declaration(declaration) = node else {
+                continue;
+            };
+            // Custom properties are often redefined on purpose
+            let DeclarationName::Ident(Ident { value, .. }) = &declaration.name else {
+                last_declaration = Some(index);
+                continue;
+            };
+            let name = value.to_ascii_lowercase();
+
+            if self
+                .ignored_properties
+                .iter()
+                .any(|pattern| pattern.is_match(&name))
+            {
+                last_declaration = Some(index);
+                continue;
+            }
+
+            match seen.iter_mut().find(|(seen_name, _)| *seen_name == name) {
+                Some((_, prev_index)) => {
+                    let prev = simple_block.value[*prev_index]
+                        .as_declaration()
+                        .expect("should be a declaration");
+                    let is_consecutive = last_declaration == Some(*prev_index);
+                    let is_same_value = prev.value.eq_ignore_span(&declaration.value)
+                        && prev.important.is_some() == declaration.important.is_some();
+
+                    let is_ignored = is_consecutive
+                        && (self
+                            .ignore
+                            .contains(&IgnoreDuplicates::ConsecutiveDuplicates)
+                            || (!is_same_value
+                                && self.ignore.contains(
+                                    &IgnoreDuplicates::ConsecutiveDuplicatesWithDifferentValues,
+                                )));
+
+                    if !is_ignored {
+                        let message = format!("Unexpected duplicate \"{name}\".");
+
+                        if is_same_value {
+                            // Removes the previous declaration up to the next node, so the
+                            // semicolon is also removed
+                            let next = simple_block.value[*prev_index + 1].span();
+
+                            self.ctx.report_with_fix(
+                                &declaration.name,
+                                message,
+                                "remove the previous declaration",
+                                vec![(Span::new(prev.span.lo, next.lo), String::new())],
+                            );
+                        } else {
+                            self.ctx.report(&declaration.name, message);
+                        }
+                    }
+
+                    *prev_index = index;
+                }
+                None => {
+                    seen.push((name, index));
+                }
+            }
+
+            last_declaration = Some(index);
+        }
+
+        simple_block.visit_children_with(self);
+    }
+}
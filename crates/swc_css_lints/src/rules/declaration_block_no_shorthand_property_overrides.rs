@@ -0,0 +1,77 @@
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::get_longhands,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+};
+
+pub fn declaration_block_no_shorthand_property_overrides(
+    ctx: LintRuleContext<()>,
+) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        DeclarationBlockNoShorthandPropertyOverrides { ctx },
+    )
+}
+
+#[derive(Debug, Default)]
+struct DeclarationBlockNoShorthandPropertyOverrides {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for DeclarationBlockNoShorthandPropertyOverrides {
+    fn visit_simple_block(&mut self, simple_block: &SimpleBlock) {
+        let mut seen: Vec<&Ident> = Vec::new();
+
+        for node in &simple_block.value {
+            let ComponentValue::Declaration(declaration) = node else {
+                continue;
+            };
+            let DeclarationName::Ident(ident) = &declaration.name else {
+                continue;
+            };
+            let (prefix, name) = split_vendor_prefix(&ident.value);
+
+            if let Some(longhand) = seen.iter().find(|seen_ident| {
+                let (seen_prefix, seen_name) = split_vendor_prefix(&seen_ident.value);
+
+                prefix == seen_prefix && is_longhand_of(&name, &seen_name)
+            }) {
+                let message = format!(
+                    "Unexpected shorthand \"{}\" after \"{}\".",
+                    ident.value, longhand.value
+                );
+
+                self.ctx.report(ident, message);
+            }
+
+            seen.push(ident);
+        }
+
+        simple_block.visit_children_with(self);
+    }
+}
+
+/// Returns the lowercase vendor prefix and the rest of `name`, e.g. `-webkit-`
+/// and `transition` for `-webkit-transition`.
+fn split_vendor_prefix(name: &str) -> (String, String) {
+    let name = name.to_ascii_lowercase();
+
+    if let Some(rest) = name.strip_prefix('-') {
+        if let Some(pos) = rest.find('-') {
+            let (prefix, rest) = name.split_at(pos + 2);
+
+            return (prefix.to_string(), rest.to_string());
+        }
+    }
+
+    (String::new(), name)
+}
+
+/// Includes the longhands of longhands, e.g. `border-top-width` of `border`.
+fn is_longhand_of(shorthand: &str, name: &str) -> bool {
+    get_longhands(shorthand)
+        .iter()
+        .any(|longhand| *longhand == name || is_longhand_of(longhand, name))
+}
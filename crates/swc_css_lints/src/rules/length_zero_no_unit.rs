@@ -0,0 +1,78 @@
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::rule::{visitor_rule, LintRule, LintRuleContext};
+
+pub fn length_zero_no_unit(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        LengthZeroNoUnit {
+            ctx,
+            math_function_depth: 0,
+            in_flex: false,
+        },
+    )
+}
+
+const MESSAGE: &str = "Unexpected unit.";
+
+#[derive(Debug, Default)]
+struct LengthZeroNoUnit {
+    ctx: LintRuleContext<()>,
+    math_function_depth: usize,
+    in_flex: bool,
+}
+
+impl Visit for LengthZeroNoUnit {
+    fn visit_declaration(&mut self, declaration: &Declaration) {
+        let old_in_flex = self.in_flex;
+
+        // `0` is a `flex-grow` or `flex-shrink` in `flex`
+        self.in_flex = matches!(
+            &declaration.name,
+            DeclarationName::Ident(Ident { value, .. })
+                if value.eq_ignore_ascii_case("flex") || value.eq_ignore_ascii_case("-webkit-flex")
+                    || value.eq_ignore_ascii_case("-ms-flex")
+        );
+
+        declaration.visit_children_with(self);
+
+        self.in_flex = old_in_flex;
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        // `calc(0 + 1px)` is invalid
+        let is_math_function = matches!(
+            &function.name,
+            FunctionName::Ident(Ident { value, .. })
+                if matches!(
+                    &*value.to_ascii_lowercase(),
+                    "calc" | "-webkit-calc" | "-moz-calc" | "min" | "max" | "clamp" | "round"
+                        | "mod" | "rem" | "abs" | "sign" | "hypot"
+                )
+        );
+
+        if is_math_function {
+            self.math_function_depth += 1;
+        }
+
+        function.visit_children_with(self);
+
+        if is_math_function {
+            self.math_function_depth -= 1;
+        }
+    }
+
+    fn visit_length(&mut self, length: &Length) {
+        if length.value.value == 0.0 && self.math_function_depth == 0 && !self.in_flex {
+            self.ctx.report_with_fix(
+                length,
+                MESSAGE,
+                "remove the unit",
+                vec![(length.unit.span, String::new())],
+            );
+        }
+
+        length.visit_children_with(self);
+    }
+}
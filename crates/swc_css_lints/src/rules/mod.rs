@@ -1,19 +1,26 @@
 use crate::{
     config::LintConfig,
     error::ConfigError,
-    rule::LintRule,
+    rule::{named_rule, LintRule},
     rules::{
         at_rule_no_unknown::at_rule_no_unknown, block_no_empty::block_no_empty,
         color_hex_alpha::color_hex_alpha, color_hex_length::color_hex_length,
         color_no_invalid_hex::color_no_invalid_hex,
         custom_property_no_missing_var_function::custom_property_no_missing_var_function,
+        declaration_block_no_duplicate_properties::declaration_block_no_duplicate_properties,
+        declaration_block_no_shorthand_property_overrides::declaration_block_no_shorthand_property_overrides,
         declaration_no_important::declaration_no_important,
         font_family_no_duplicate_names::font_family_no_duplicate_names,
         keyframe_declaration_no_important::keyframe_declaration_no_important,
+        length_zero_no_unit::length_zero_no_unit,
+        no_descending_specificity::no_descending_specificity,
         no_duplicate_at_import_rules::no_duplicate_at_import_rules,
         no_empty_source::no_empty_source,
         no_invalid_position_at_import_rule::no_invalid_position_at_import_rule,
-        selector_max_class::selector_max_class, selector_max_combinators::selector_max_combinators,
+        property_no_unknown::property_no_unknown, selector_max_class::selector_max_class,
+        selector_max_combinators::selector_max_combinators,
+        selector_pseudo_class_no_unknown::selector_pseudo_class_no_unknown,
+        shorthand_property_no_redundant_values::shorthand_property_no_redundant_values,
         unit_no_unknown::unit_no_unknown,
     },
 };
@@ -24,14 +31,21 @@ pub mod color_hex_alpha;
 pub mod color_hex_length;
 pub mod color_no_invalid_hex;
 pub mod custom_property_no_missing_var_function;
+pub mod declaration_block_no_duplicate_properties;
+pub mod declaration_block_no_shorthand_property_overrides;
 pub mod declaration_no_important;
 pub mod font_family_no_duplicate_names;
 pub mod keyframe_declaration_no_important;
+pub mod length_zero_no_unit;
+pub mod no_descending_specificity;
 pub mod no_duplicate_at_import_rules;
 pub mod no_empty_source;
 pub mod no_invalid_position_at_import_rule;
+pub mod property_no_unknown;
 pub mod selector_max_class;
 pub mod selector_max_combinators;
+pub mod selector_pseudo_class_no_unknown;
+pub mod shorthand_property_no_redundant_values;
 pub mod unit_no_unknown;
 
 pub struct LintParams<'a> {
@@ -44,24 +58,107 @@ pub fn get_rules(
     let rules_config = &lint_config.rules;
 
     let rules = vec![
-        block_no_empty((&rules_config.block_no_empty).into()),
-        at_rule_no_unknown((&rules_config.at_rule_no_unknown).into())?,
-        no_empty_source((&rules_config.no_empty_source).into()),
-        declaration_no_important((&rules_config.declaration_no_important).into()),
-        keyframe_declaration_no_important((&rules_config.keyframe_declaration_no_important).into()),
-        no_invalid_position_at_import_rule(
-            (&rules_config.no_invalid_position_at_import_rule).into(),
-        )?,
-        selector_max_class((&rules_config.selector_max_class).into()),
-        color_hex_length((&rules_config.color_hex_length).into()),
-        color_no_invalid_hex((&rules_config.color_no_invalid_hex).into()),
-        unit_no_unknown((&rules_config.unit_no_unknown).into())?,
-        selector_max_combinators((&rules_config.selector_max_combinators).into()),
-        font_family_no_duplicate_names((&rules_config.font_family_no_duplicate_names).into())?,
-        color_hex_alpha((&rules_config.color_hex_alpha).into()),
-        no_duplicate_at_import_rules((&rules_config.no_duplicate_at_import_rules).into()),
-        custom_property_no_missing_var_function(
-            (&rules_config.custom_property_no_missing_var_function).into(),
+        named_rule(
+            "block-no-empty",
+            block_no_empty((&rules_config.block_no_empty).into()),
+        ),
+        named_rule(
+            "at-rule-no-unknown",
+            at_rule_no_unknown((&rules_config.at_rule_no_unknown).into())?,
+        ),
+        named_rule(
+            "no-empty-source",
+            no_empty_source((&rules_config.no_empty_source).into()),
+        ),
+        named_rule(
+            "declaration-no-important",
+            declaration_no_important((&rules_config.declaration_no_important).into()),
+        ),
+        named_rule(
+            "keyframe-declaration-no-important",
+            keyframe_declaration_no_important(
+                (&rules_config.keyframe_declaration_no_important).into(),
+            ),
+        ),
+        named_rule(
+            "no-invalid-position-at-import-rule",
+            no_invalid_position_at_import_rule(
+                (&rules_config.no_invalid_position_at_import_rule).into(),
+            )?,
+        ),
+        named_rule(
+            "selector-max-class",
+            selector_max_class((&rules_config.selector_max_class).into()),
+        ),
+        named_rule(
+            "color-hex-length",
+            color_hex_length((&rules_config.color_hex_length).into()),
+        ),
+        named_rule(
+            "color-no-invalid-hex",
+            color_no_invalid_hex((&rules_config.color_no_invalid_hex).into()),
+        ),
+        named_rule(
+            "unit-no-unknown",
+            unit_no_unknown((&rules_config.unit_no_unknown).into())?,
+        ),
+        named_rule(
+            "selector-max-combinators",
+            selector_max_combinators((&rules_config.selector_max_combinators).into()),
+        ),
+        named_rule(
+            "font-family-no-duplicate-names",
+            font_family_no_duplicate_names((&rules_config.font_family_no_duplicate_names).into())?,
+        ),
+        named_rule(
+            "color-hex-alpha",
+            color_hex_alpha((&rules_config.color_hex_alpha).into()),
+        ),
+        named_rule(
+            "no-duplicate-at-import-rules",
+            no_duplicate_at_import_rules((&rules_config.no_duplicate_at_import_rules).into()),
+        ),
+        named_rule(
+            "custom-property-no-missing-var-function",
+            custom_property_no_missing_var_function(
+                (&rules_config.custom_property_no_missing_var_function).into(),
+            ),
+        ),
+        named_rule(
+            "declaration-block-no-duplicate-properties",
+            declaration_block_no_duplicate_properties(
+                (&rules_config.declaration_block_no_duplicate_properties).into(),
+            )?,
+        ),
+        named_rule(
+            "declaration-block-no-shorthand-property-overrides",
+            declaration_block_no_shorthand_property_overrides(
+                (&rules_config.declaration_block_no_shorthand_property_overrides).into(),
+            ),
+        ),
+        named_rule(
+            "length-zero-no-unit",
+            length_zero_no_unit((&rules_config.length_zero_no_unit).into()),
+        ),
+        named_rule(
+            "no-descending-specificity",
+            no_descending_specificity((&rules_config.no_descending_specificity).into()),
+        ),
+        named_rule(
+            "property-no-unknown",
+            property_no_unknown((&rules_config.property_no_unknown).into())?,
+        ),
+        named_rule(
+            "selector-pseudo-class-no-unknown",
+            selector_pseudo_class_no_unknown(
+                (&rules_config.selector_pseudo_class_no_unknown).into(),
+            )?,
+        ),
+        named_rule(
+            "shorthand-property-no-redundant-values",
+            shorthand_property_no_redundant_values(
+                (&rules_config.shorthand_property_no_redundant_values).into(),
+            ),
         ),
     ];
 
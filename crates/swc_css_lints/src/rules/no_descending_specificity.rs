@@ -0,0 +1,263 @@
+use rustc_hash::FxHashMap;
+use swc_common::Span;
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::rule::{visitor_rule, LintRule, LintRuleContext};
+
+pub fn no_descending_specificity(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        NoDescendingSpecificity {
+            ctx,
+            contexts: Default::default(),
+        },
+    )
+}
+
+const MESSAGE: &str = "Expected selector to come before the selectors with higher specificity \
+                       which match the same elements.";
+
+/// `(ids, classes, types)`
+type Specificity = (u32, u32, u32);
+
+#[derive(Debug, Default)]
+struct NoDescendingSpecificity {
+    ctx: LintRuleContext<()>,
+    /// Selectors are only compared with the selectors of the same at-rule,
+    /// mapped by their last compound selector without pseudo-classes.
+    contexts: Vec<FxHashMap<String, Vec<(Specificity, Span)>>>,
+}
+
+impl NoDescendingSpecificity {
+    fn check(&mut self, selector: &ComplexSelector) {
+        let Some(ComplexSelectorChildren::CompoundSelector(last)) = selector.children.last() else {
+            return;
+        };
+        let Some(context) = self.contexts.last_mut() else {
+            return;
+        };
+
+        let specificity = get_complex_selector_specificity(selector);
+        let selectors = context.entry(get_compound_selector_key(last)).or_default();
+
+        if let Some((_, span)) = selectors
+            .iter()
+            .find(|(other_specificity, _)| *other_specificity > specificity)
+        {
+            self.ctx.report_with_note(
+                selector,
+                MESSAGE,
+                *span,
+                "this selector has higher specificity",
+            );
+        }
+
+        selectors.push((specificity, selector.span));
+    }
+}
+
+impl Visit for NoDescendingSpecificity {
+    fn visit_stylesheet(&mut self, stylesheet: &Stylesheet) {
+        self.contexts.push(Default::default());
+
+        stylesheet.visit_children_with(self);
+
+        self.contexts.pop();
+    }
+
+    fn visit_at_rule(&mut self, at_rule: &AtRule) {
+        self.contexts.push(Default::default());
+
+        at_rule.visit_children_with(self);
+
+        self.contexts.pop();
+    }
+
+    fn visit_qualified_rule(&mut self, qualified_rule: &QualifiedRule) {
+        if let QualifiedRulePrelude::SelectorList(selector_list) = &qualified_rule.prelude {
+            for selector in &selector_list.children {
+                self.check(selector);
+            }
+        }
+
+        // Nested rules are relative to their parents, so we don't compare them
+    }
+}
+
+/// e.g. `a.foo[href]::before` for `.bar a.foo[href]:hover::before`
+fn get_compound_selector_key(compound_selector: &CompoundSelector) -> String {
+    let mut key = String::new();
+
+    if let Some(type_selector) = &compound_selector.type_selector {
+        match &**type_selector {
+            TypeSelector::TagName(tag_name_selector) => {
+                key.push_str(&tag_name_selector.name.value.value.to_ascii_lowercase());
+            }
+            TypeSelector::Universal(_) => key.push('*'),
+        }
+    }
+
+    for subclass_selector in &compound_selector.subclass_selectors {
+        match subclass_selector {
+            SubclassSelector::Id(IdSelector { text, .. }) => {
+                key.push('#');
+                key.push_str(&text.value);
+            }
+            SubclassSelector::Class(ClassSelector { text, .. }) => {
+                key.push('.');
+                key.push_str(&text.value);
+            }
+            SubclassSelector::Attribute(attribute_selector) => {
+                key.push('[');
+                key.push_str(&attribute_selector.name.value.value);
+
+                if let (Some(matcher), Some(value)) =
+                    (&attribute_selector.matcher, &attribute_selector.value)
+                {
+                    key.push_str(match matcher.value {
+                        AttributeSelectorMatcherValue::Equals => "=",
+                        AttributeSelectorMatcherValue::Tilde => "~=",
+                        AttributeSelectorMatcherValue::Bar => "|=",
+                        AttributeSelectorMatcherValue::Caret => "^=",
+                        AttributeSelectorMatcherValue::Dollar => "$=",
+                        AttributeSelectorMatcherValue::Asterisk => "*=",
+                    });
+                    key.push_str(match value {
+                        AttributeSelectorValue::Str(Str { value, .. })
+                        | AttributeSelectorValue::Ident(Ident { value, .. }) => value,
+                    });
+                }
+
+                key.push(']');
+            }
+            SubclassSelector::PseudoClass(_) => {}
+            SubclassSelector::PseudoElement(PseudoElementSelector { name, .. }) => {
+                key.push_str("::");
+                key.push_str(&name.value.to_ascii_lowercase());
+            }
+        }
+    }
+
+    key
+}
+
+fn get_complex_selector_specificity(complex_selector: &ComplexSelector) -> Specificity {
+    complex_selector
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            ComplexSelectorChildren::CompoundSelector(compound_selector) => {
+                Some(get_compound_selector_specificity(compound_selector))
+            }
+            ComplexSelectorChildren::Combinator(_) => None,
+        })
+        .fold((0, 0, 0), add)
+}
+
+fn get_compound_selector_specificity(compound_selector: &CompoundSelector) -> Specificity {
+    let mut specificity = match compound_selector.type_selector.as_deref() {
+        Some(TypeSelector::TagName(_)) => (0, 0, 1),
+        _ => (0, 0, 0),
+    };
+
+    for subclass_selector in &compound_selector.subclass_selectors {
+        let subclass_specificity = match subclass_selector {
+            SubclassSelector::Id(_) => (1, 0, 0),
+            SubclassSelector::Class(_) | SubclassSelector::Attribute(_) => (0, 1, 0),
+            SubclassSelector::PseudoClass(pseudo_class_selector) => {
+                get_pseudo_class_specificity(pseudo_class_selector)
+            }
+            SubclassSelector::PseudoElement(_) => (0, 0, 1),
+        };
+
+        specificity = add(specificity, subclass_specificity);
+    }
+
+    specificity
+}
+
+fn get_pseudo_class_specificity(pseudo_class_selector: &PseudoClassSelector) -> Specificity {
+    let name = pseudo_class_selector.name.value.to_ascii_lowercase();
+    // The most specific selector of the arguments
+    let max_argument_specificity = || {
+        pseudo_class_selector
+            .children
+            .iter()
+            .flatten()
+            .flat_map(get_selector_list_specificities)
+            .max()
+            .unwrap_or_default()
+    };
+
+    match &*name {
+        "where" => (0, 0, 0),
+        "is" | "not" | "has" | "matches" | "-webkit-any" | "-moz-any" => max_argument_specificity(),
+        // e.g. `:nth-child(2n of .foo)`
+        "nth-child" | "nth-last-child" => add((0, 1, 0), max_argument_specificity()),
+        // Pseudo-elements written with a single colon
+        "before" | "after" | "first-line" | "first-letter" => (0, 0, 1),
+        _ => (0, 1, 0),
+    }
+}
+
+fn get_selector_list_specificities(children: &PseudoClassSelectorChildren) -> Vec<Specificity> {
+    match children {
+        PseudoClassSelectorChildren::ComplexSelector(complex_selector) => {
+            vec![get_complex_selector_specificity(complex_selector)]
+        }
+        PseudoClassSelectorChildren::CompoundSelector(compound_selector) => {
+            vec![get_compound_selector_specificity(compound_selector)]
+        }
+        PseudoClassSelectorChildren::SelectorList(selector_list) => selector_list
+            .children
+            .iter()
+            .map(get_complex_selector_specificity)
+            .collect(),
+        PseudoClassSelectorChildren::CompoundSelectorList(compound_selector_list) => {
+            compound_selector_list
+                .children
+                .iter()
+                .map(get_compound_selector_specificity)
+                .collect()
+        }
+        PseudoClassSelectorChildren::ForgivingSelectorList(forgiving_selector_list) => {
+            forgiving_selector_list
+                .children
+                .iter()
+                .filter_map(|child| match child {
+                    ForgivingComplexSelector::ComplexSelector(complex_selector) => {
+                        Some(get_complex_selector_specificity(complex_selector))
+                    }
+                    ForgivingComplexSelector::ListOfComponentValues(_) => None,
+                })
+                .collect()
+        }
+        PseudoClassSelectorChildren::RelativeSelectorList(relative_selector_list) => {
+            relative_selector_list
+                .children
+                .iter()
+                .map(|relative_selector| {
+                    get_complex_selector_specificity(&relative_selector.selector)
+                })
+                .collect()
+        }
+        PseudoClassSelectorChildren::ForgivingRelativeSelectorList(
+            forgiving_relative_selector_list,
+        ) => forgiving_relative_selector_list
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                ForgivingRelativeSelector::RelativeSelector(relative_selector) => Some(
+                    get_complex_selector_specificity(&relative_selector.selector),
+                ),
+                ForgivingRelativeSelector::ListOfComponentValues(_) => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn add(a: Specificity, b: Specificity) -> Specificity {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::is_known_property,
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyNoUnknownConfig {
+    ignore_properties: Option<Vec<String>>,
+}
+
+pub fn property_no_unknown(
+    ctx: LintRuleContext<PropertyNoUnknownConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored_properties = ctx
+        .config()
+        .ignore_properties
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+
+    Ok(visitor_rule(
+        ctx.reaction(),
+        PropertyNoUnknown {
+            ctx,
+            ignored_properties,
+        },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct PropertyNoUnknown {
+    ctx: LintRuleContext<PropertyNoUnknownConfig>,
+    ignored_properties: Vec<NamePattern>,
+}
+
+impl Visit for PropertyNoUnknown {
+    fn visit_declaration(&mut self, declaration: &Declaration) {
+        // Custom properties are always valid
+        if let DeclarationName::Ident(ident) = &declaration.name {
+            let name = ident.value.to_ascii_lowercase();
+
+            // Vendor-prefixed properties are not in the dataset
+            if !name.starts_with('-')
+                && !is_known_property(&name)
+                && self
+                    .ignored_properties
+                    .iter()
+                    .all(|pattern| !pattern.is_match(&ident.value))
+            {
+                let message = format!("Unexpected unknown property \"{}\".", ident.value);
+
+                self.ctx.report(ident, message);
+            }
+        }
+
+        declaration.visit_children_with(self);
+    }
+
+    // e.g. `@supports (foo: bar)` tests properties which may not exist
+    fn visit_supports_condition(&mut self, _: &SupportsCondition) {}
+
+    fn visit_import_conditions(&mut self, _: &ImportConditions) {}
+}
@@ -0,0 +1,97 @@
+use swc_common::{EqIgnoreSpan, Span, Spanned};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::rule::{visitor_rule, LintRule, LintRuleContext};
+
+pub fn shorthand_property_no_redundant_values(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), ShorthandPropertyNoRedundantValues { ctx })
+}
+
+#[derive(Debug, Default)]
+struct ShorthandPropertyNoRedundantValues {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for ShorthandPropertyNoRedundantValues {
+    fn visit_declaration(&mut self, declaration: &Declaration) {
+        declaration.visit_children_with(self);
+
+        let DeclarationName::Ident(Ident { value: name, .. }) = &declaration.name else {
+            return;
+        };
+        let max_values = match &*name.to_ascii_lowercase() {
+            // `top right bottom left`
+            "margin" | "padding" | "border-color" | "border-style" | "border-width"
+            | "border-radius" | "inset" | "scroll-margin" | "scroll-padding" => 4,
+            // `start end` or `row column`
+            "margin-block" | "margin-inline" | "padding-block" | "padding-inline"
+            | "inset-block" | "inset-inline" | "gap" | "grid-gap" => 2,
+            _ => return,
+        };
+        let values = &declaration.value;
+
+        if values.len() < 2 || values.len() > max_values || !values.iter().all(is_single_value) {
+            return;
+        }
+
+        let len = get_shortest_len(values);
+
+        if len == values.len() {
+            return;
+        }
+
+        let message = format!(
+            "Expected \"{name}\" to be written with {len} value{}.",
+            if len == 1 { "" } else { "s" }
+        );
+
+        self.ctx.report_with_fix(
+            declaration,
+            message,
+            "remove redundant values",
+            vec![(
+                Span::new(
+                    values[len - 1].span().hi,
+                    values[values.len() - 1].span().hi,
+                ),
+                String::new(),
+            )],
+        );
+    }
+}
+
+/// `var()` may contain multiple values, and `/` separates two lists of values,
+/// e.g. `border-radius: 1px 2px / 3px`.
+fn is_single_value(value: &ComponentValue) -> bool {
+    match value {
+        ComponentValue::Delimiter(_) | ComponentValue::PreservedToken(_) => false,
+        ComponentValue::Function(function) => match &function.name {
+            FunctionName::Ident(Ident { value, .. }) => {
+                !matches!(&*value.to_ascii_lowercase(), "var" | "env" | "attr")
+            }
+            FunctionName::DashedIdent(_) => false,
+        },
+        _ => true,
+    }
+}
+
+/// Omitted values are copied from the opposite side, e.g. `1px 2px 1px 2px` is
+/// `1px 2px`.
+fn get_shortest_len(values: &[ComponentValue]) -> usize {
+    let mut len = values.len();
+
+    if len == 4 && values[3].eq_ignore_span(&values[1]) {
+        len = 3;
+    }
+
+    if len == 3 && values[2].eq_ignore_span(&values[0]) {
+        len = 2;
+    }
+
+    if len == 2 && values[1].eq_ignore_span(&values[0]) {
+        len = 1;
+    }
+
+    len
+}
@@ -0,0 +1,49 @@
+use std::{fs, path::PathBuf};
+
+use swc_common::{input::SourceFileInput, FileName};
+use swc_css_lints::{
+    fix::{capture_diagnostics, fix_until_stable},
+    get_rules, LintConfig, LintParams, LintRule,
+};
+use swc_css_parser::{lexer::Lexer, parser::Parser};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fix/**/input.css")]
+fn fix(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let lint_config: LintConfig =
+        serde_json::from_str(&fs::read_to_string(dir.join("config.json")).unwrap()).unwrap();
+
+    testing::run_test(false, |cm, _| {
+        let src = fs::read_to_string(&input).unwrap();
+
+        let output = fix_until_stable(
+            &cm,
+            FileName::Real(input.clone()).into(),
+            src,
+            10,
+            |fm| -> Result<_, ()> {
+                let config = Default::default();
+
+                let lexer = Lexer::new(SourceFileInput::from(&**fm), None, config);
+                let mut parser = Parser::new(lexer, config);
+                let stylesheet = parser.parse_all().unwrap();
+
+                let mut rules = get_rules(&LintParams {
+                    lint_config: &lint_config,
+                })
+                .unwrap();
+
+                Ok(capture_diagnostics(|| rules.lint_stylesheet(&stylesheet)))
+            },
+        )
+        .unwrap();
+
+        NormalizedOutput::from(output.code)
+            .compare_to_file(dir.join("output.css"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
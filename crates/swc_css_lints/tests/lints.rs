@@ -2,21 +2,26 @@
 
 use std::{fs, path::PathBuf};
 
-use swc_common::{errors::HANDLER, input::SourceFileInput};
-use swc_css_lints::{get_rules, LintConfig, LintParams, LintRule};
+use swc_common::{comments::SingleThreadedComments, errors::HANDLER, input::SourceFileInput};
+use swc_css_lints::{
+    directives::{with_directives, Directives},
+    get_rules, LintConfig, LintParams, LintRule,
+};
 use swc_css_parser::{lexer::Lexer, parser::Parser};
 
 #[testing::fixture("tests/rules/pass/**/input.css")]
 fn pass(input: PathBuf) {
     let config_path = input.parent().unwrap().join("config.json");
-    let lint_config =
+    let mut lint_config =
         serde_json::from_str::<LintConfig>(&fs::read_to_string(config_path).unwrap()).unwrap();
+    lint_config.apply_overrides(&input);
 
     testing::run_test2(false, |cm, handler| -> Result<(), _> {
         let config = Default::default();
 
         let fm = cm.load_file(&input).unwrap();
-        let lexer = Lexer::new(SourceFileInput::from(&*fm), None, config);
+        let comments = SingleThreadedComments::default();
+        let lexer = Lexer::new(SourceFileInput::from(&*fm), Some(&comments), config);
         let mut parser = Parser::new(lexer, config);
 
         let stylesheet = match parser.parse_all() {
@@ -27,10 +32,14 @@ fn pass(input: PathBuf) {
             }
         };
 
-        let mut rules = get_rules(&LintParams {
-            lint_config: &lint_config,
-        })
-        .unwrap();
+        let mut rules = with_directives(
+            get_rules(&LintParams {
+                lint_config: &lint_config,
+            })
+            .unwrap(),
+            Directives::parse(&cm, &comments),
+            &lint_config,
+        );
 
         HANDLER.set(&handler, || {
             rules.lint_stylesheet(&stylesheet);
@@ -49,14 +58,16 @@ fn pass(input: PathBuf) {
 fn fail(input: PathBuf) {
     let stderr_path = input.parent().unwrap().join("output.swc-stderr");
     let config_path = input.parent().unwrap().join("config.json");
-    let lint_config =
+    let mut lint_config =
         serde_json::from_str::<LintConfig>(&fs::read_to_string(config_path).unwrap()).unwrap();
+    lint_config.apply_overrides(&input);
 
     let stderr = testing::run_test2(false, |cm, handler| -> Result<(), _> {
         let config = Default::default();
 
         let fm = cm.load_file(&input).unwrap();
-        let lexer = Lexer::new(SourceFileInput::from(&*fm), None, config);
+        let comments = SingleThreadedComments::default();
+        let lexer = Lexer::new(SourceFileInput::from(&*fm), Some(&comments), config);
         let mut parser = Parser::new(lexer, config);
 
         let stylesheet = match parser.parse_all() {
@@ -67,10 +78,14 @@ fn fail(input: PathBuf) {
             }
         };
 
-        let mut rules = get_rules(&LintParams {
-            lint_config: &lint_config,
-        })
-        .unwrap();
+        let mut rules = with_directives(
+            get_rules(&LintParams {
+                lint_config: &lint_config,
+            })
+            .unwrap(),
+            Directives::parse(&cm, &comments),
+            &lint_config,
+        );
 
         HANDLER.set(&handler, || {
             rules.lint_stylesheet(&stylesheet);
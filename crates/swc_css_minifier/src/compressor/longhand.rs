@@ -0,0 +1,507 @@
+use swc_atoms::Atom;
+use swc_common::{EqIgnoreSpan, Span, Spanned};
+use swc_css_ast::*;
+
+use super::Compressor;
+use crate::{is_css_wide_keyword, options::MinifyLevel};
+
+#[derive(Clone, Copy)]
+enum Kind {
+    // `top right bottom left`, e.g. `margin`
+    Box,
+    // Space separated list of all longhands, e.g. `border-top`
+    List,
+    Font,
+}
+
+struct Shorthand {
+    name: &'static str,
+    longhands: &'static [&'static str],
+    kind: Kind,
+    // Also resets properties which are not listed in `longhands`
+    resets_others: bool,
+}
+
+// Order matters, `border-width` and `border-top` need to be created before
+// `border`
+static SHORTHANDS: &[Shorthand] = &[
+    Shorthand {
+        name: "margin",
+        longhands: &["margin-top", "margin-right", "margin-bottom", "margin-left"],
+        kind: Kind::Box,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "padding",
+        longhands: &[
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+        kind: Kind::Box,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border-width",
+        longhands: &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+        kind: Kind::Box,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border-style",
+        longhands: &[
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+        kind: Kind::Box,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border-color",
+        longhands: &[
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+        kind: Kind::Box,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border-top",
+        longhands: &["border-top-width", "border-top-style", "border-top-color"],
+        kind: Kind::List,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border-right",
+        longhands: &[
+            "border-right-width",
+            "border-right-style",
+            "border-right-color",
+        ],
+        kind: Kind::List,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border-bottom",
+        longhands: &[
+            "border-bottom-width",
+            "border-bottom-style",
+            "border-bottom-color",
+        ],
+        kind: Kind::List,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border-left",
+        longhands: &[
+            "border-left-width",
+            "border-left-style",
+            "border-left-color",
+        ],
+        kind: Kind::List,
+        resets_others: false,
+    },
+    Shorthand {
+        name: "border",
+        longhands: &["border-width", "border-style", "border-color"],
+        kind: Kind::List,
+        resets_others: true,
+    },
+    Shorthand {
+        name: "font",
+        longhands: &[
+            "font-style",
+            "font-variant",
+            "font-weight",
+            "font-stretch",
+            "font-size",
+            "line-height",
+            "font-family",
+        ],
+        kind: Kind::Font,
+        resets_others: true,
+    },
+];
+
+/// Returns the properties which are set by the shorthand `name`, shorthands
+/// are not expanded recursively.
+pub(super) fn longhands_of(name: &str) -> &'static [&'static str] {
+    match name {
+        "border" => &[
+            "border-width",
+            "border-style",
+            "border-color",
+            "border-image",
+        ],
+        "border-image" => &[
+            "border-image-source",
+            "border-image-slice",
+            "border-image-width",
+            "border-image-outset",
+            "border-image-repeat",
+        ],
+        "border-radius" => &[
+            "border-top-left-radius",
+            "border-top-right-radius",
+            "border-bottom-right-radius",
+            "border-bottom-left-radius",
+        ],
+        "font" => &[
+            "font-style",
+            "font-variant",
+            "font-weight",
+            "font-stretch",
+            "font-size",
+            "line-height",
+            "font-family",
+        ],
+        "font-variant" => &[
+            "font-variant-caps",
+            "font-variant-ligatures",
+            "font-variant-numeric",
+            "font-variant-east-asian",
+            "font-variant-position",
+        ],
+        "background" => &[
+            "background-color",
+            "background-image",
+            "background-repeat",
+            "background-attachment",
+            "background-position",
+            "background-size",
+            "background-origin",
+            "background-clip",
+        ],
+        "background-position" => &["background-position-x", "background-position-y"],
+        "list-style" => &["list-style-type", "list-style-position", "list-style-image"],
+        "outline" => &["outline-color", "outline-style", "outline-width"],
+        "overflow" => &["overflow-x", "overflow-y"],
+        "flex" => &["flex-grow", "flex-shrink", "flex-basis"],
+        "flex-flow" => &["flex-direction", "flex-wrap"],
+        "text-decoration" => &[
+            "text-decoration-line",
+            "text-decoration-style",
+            "text-decoration-color",
+        ],
+        "transition" => &[
+            "transition-property",
+            "transition-duration",
+            "transition-timing-function",
+            "transition-delay",
+        ],
+        "animation" => &[
+            "animation-name",
+            "animation-duration",
+            "animation-timing-function",
+            "animation-delay",
+            "animation-iteration-count",
+            "animation-direction",
+            "animation-fill-mode",
+            "animation-play-state",
+        ],
+        _ => {
+            for shorthand in SHORTHANDS {
+                if shorthand.name == name {
+                    return shorthand.longhands;
+                }
+            }
+
+            &[]
+        }
+    }
+}
+
+/// Collects all the longhands which are set by `name`, or `name` itself if it
+/// is not a shorthand.
+pub(super) fn expand_shorthand<'a>(name: &'a str, result: &mut Vec<&'a str>) {
+    let longhands = longhands_of(name);
+
+    if longhands.is_empty() {
+        result.push(name);
+
+        return;
+    }
+
+    for longhand in longhands {
+        expand_shorthand(longhand, result);
+    }
+}
+
+fn is_logical_property(name: &str) -> bool {
+    name.contains("-inline") || name.contains("-block")
+}
+
+fn get_declaration_name(declaration: &Declaration) -> Option<&Atom> {
+    match &declaration.name {
+        DeclarationName::Ident(Ident { value, .. }) => Some(value),
+        _ => None,
+    }
+}
+
+fn is_single_value(value: &[ComponentValue]) -> bool {
+    match value {
+        [ComponentValue::Ident(ident)] => !is_css_wide_keyword(&ident.value),
+        [ComponentValue::Function(function)] => !is_var_function(function),
+        [_] => true,
+        _ => false,
+    }
+}
+
+fn is_var_function(function: &Function) -> bool {
+    matches_eq_ignore_ascii_case!(function.name.as_str(), "var", "env")
+}
+
+fn contains_var_or_css_wide_keyword(value: &[ComponentValue]) -> bool {
+    value.iter().any(|node| match node {
+        ComponentValue::Ident(ident) => is_css_wide_keyword(&ident.value),
+        ComponentValue::Function(function) => {
+            is_var_function(function) || contains_var_or_css_wide_keyword(&function.value)
+        }
+        _ => false,
+    })
+}
+
+fn is_ident(value: &[ComponentValue], f: impl Fn(&str) -> bool) -> bool {
+    match value {
+        [ComponentValue::Ident(ident)] => f(&ident.value.to_ascii_lowercase()),
+        _ => false,
+    }
+}
+
+fn is_normal(value: &[ComponentValue]) -> bool {
+    is_ident(value, |value| value == "normal")
+}
+
+impl Compressor {
+    pub(super) fn merge_longhands(&self, simple_block: &mut SimpleBlock) {
+        for shorthand in SHORTHANDS {
+            if shorthand.resets_others && self.options.level != MinifyLevel::Aggressive {
+                continue;
+            }
+
+            self.merge_into_shorthand(simple_block, shorthand);
+        }
+    }
+
+    fn merge_into_shorthand(&self, simple_block: &mut SimpleBlock, shorthand: &Shorthand) {
+        let mut expanded = Vec::new();
+
+        expand_shorthand(shorthand.name, &mut expanded);
+
+        let mut indexes: Vec<Option<usize>> = vec![None; shorthand.longhands.len()];
+
+        for (index, node) in simple_block.value.iter().enumerate() {
+            let ComponentValue::Declaration(declaration) = node else {
+                continue;
+            };
+            let Some(name) = get_declaration_name(declaration) else {
+                continue;
+            };
+
+            if let Some(position) = shorthand.longhands.iter().position(|l| **l == **name) {
+                // Duplicated longhand, i.e. a fallback
+                if indexes[position].is_some() {
+                    return;
+                }
+
+                indexes[position] = Some(index);
+
+                continue;
+            }
+
+            // Other properties which are set by the shorthand, or which are the logical
+            // equivalent of the longhands, and which would be reordered
+            let mut other = Vec::new();
+
+            expand_shorthand(name, &mut other);
+
+            if other.iter().any(|name| expanded.contains(name))
+                || (is_logical_property(name)
+                    && name.split('-').next() == shorthand.name.split('-').next())
+            {
+                return;
+            }
+        }
+
+        let Some(indexes) = indexes.into_iter().collect::<Option<Vec<_>>>() else {
+            return;
+        };
+
+        let declarations = indexes
+            .iter()
+            .map(|index| match &simple_block.value[*index] {
+                ComponentValue::Declaration(declaration) => &**declaration,
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+
+        let important = declarations[0].important.is_some();
+
+        if declarations
+            .iter()
+            .any(|declaration| declaration.important.is_some() != important)
+        {
+            return;
+        }
+
+        let value = match shorthand.kind {
+            Kind::Box => {
+                if !declarations.iter().all(|d| is_single_value(&d.value)) {
+                    return;
+                }
+
+                let [top, right, bottom, left] = &declarations[..] else {
+                    unreachable!();
+                };
+
+                let mut value = vec![
+                    top.value[0].clone(),
+                    right.value[0].clone(),
+                    bottom.value[0].clone(),
+                    left.value[0].clone(),
+                ];
+
+                if left.value.eq_ignore_span(&right.value) {
+                    value.pop();
+
+                    if bottom.value.eq_ignore_span(&top.value) {
+                        value.pop();
+
+                        if right.value.eq_ignore_span(&top.value) {
+                            value.pop();
+                        }
+                    }
+                }
+
+                value
+            }
+            Kind::List => {
+                if !declarations.iter().all(|d| is_single_value(&d.value)) {
+                    return;
+                }
+
+                declarations.iter().map(|d| d.value[0].clone()).collect()
+            }
+            Kind::Font => {
+                let Some(value) = self.create_font_shorthand(&declarations) else {
+                    return;
+                };
+
+                value
+            }
+        };
+
+        let last_index = *indexes.iter().max().unwrap();
+        let span = Span::new(
+            indexes
+                .iter()
+                .map(|index| simple_block.value[*index].span_lo())
+                .min()
+                .unwrap(),
+            simple_block.value[last_index].span_hi(),
+        );
+        let important = declarations
+            .iter()
+            .find_map(|declaration| declaration.important.clone());
+
+        let mut declaration = Declaration {
+            span,
+            name: DeclarationName::Ident(Ident {
+                span: Default::default(),
+                value: shorthand.name.into(),
+                raw: None,
+            }),
+            value,
+            important,
+        };
+
+        self.compress_declaration(&mut declaration);
+
+        simple_block.value[last_index] = ComponentValue::Declaration(Box::new(declaration));
+        simple_block.value = simple_block
+            .value
+            .drain(..)
+            .enumerate()
+            .filter_map(|(index, node)| {
+                if index != last_index && indexes.contains(&index) {
+                    None
+                } else {
+                    Some(node)
+                }
+            })
+            .collect();
+    }
+
+    fn create_font_shorthand(&self, declarations: &[&Declaration]) -> Option<Vec<ComponentValue>> {
+        let [style, variant, weight, stretch, size, line_height, family] = declarations else {
+            unreachable!();
+        };
+
+        if declarations
+            .iter()
+            .any(|d| d.value.is_empty() || contains_var_or_css_wide_keyword(&d.value))
+        {
+            return None;
+        }
+
+        // Only the values of CSS 2 are allowed for `font-variant` and keywords for
+        // `font-stretch` in the shorthand
+        if !is_ident(&variant.value, |value| {
+            matches!(value, "normal" | "small-caps")
+        }) || !is_ident(&stretch.value, |value| {
+            matches!(
+                value,
+                "normal"
+                    | "ultra-condensed"
+                    | "extra-condensed"
+                    | "condensed"
+                    | "semi-condensed"
+                    | "semi-expanded"
+                    | "expanded"
+                    | "extra-expanded"
+                    | "ultra-expanded"
+            )
+        }) {
+            return None;
+        }
+
+        if ![style, weight, size, line_height]
+            .iter()
+            .all(|d| is_single_value(&d.value))
+        {
+            return None;
+        }
+
+        let mut value = Vec::new();
+
+        for declaration in [style, variant, weight, stretch] {
+            if !is_normal(&declaration.value) {
+                value.push(declaration.value[0].clone());
+            }
+        }
+
+        value.push(size.value[0].clone());
+
+        if !is_normal(&line_height.value) {
+            value.push(ComponentValue::Delimiter(Box::new(Delimiter {
+                span: Default::default(),
+                value: DelimiterValue::Solidus,
+            })));
+            value.push(line_height.value[0].clone());
+        }
+
+        value.extend(family.value.iter().cloned());
+
+        Some(value)
+    }
+}
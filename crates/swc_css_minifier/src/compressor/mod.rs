@@ -3,6 +3,7 @@ use swc_css_utils::serialize_ident;
 use swc_css_visit::{VisitMut, VisitMutWith};
 
 use self::ctx::Ctx;
+use crate::options::MinifyOptions;
 
 mod alpha_value;
 mod angle;
@@ -16,8 +17,10 @@ mod frequency;
 mod import;
 mod keyframes;
 mod length;
+mod longhand;
 mod math;
 mod media;
+mod overridden;
 mod rules;
 mod selector;
 mod supports;
@@ -26,12 +29,16 @@ mod transform_function;
 mod unicode_range;
 mod url;
 
-pub fn compressor() -> impl VisitMut {
-    Compressor::default()
+pub fn compressor(options: MinifyOptions) -> impl VisitMut {
+    Compressor {
+        options,
+        ..Default::default()
+    }
 }
 
 #[derive(Default)]
 struct Compressor {
+    options: MinifyOptions,
     ctx: Ctx,
     need_utf8_at_rule: bool,
     in_supports_condition: bool,
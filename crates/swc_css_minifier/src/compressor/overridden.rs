@@ -0,0 +1,84 @@
+use swc_common::EqIgnoreSpan;
+use swc_css_ast::*;
+
+use super::{longhand::expand_shorthand, Compressor};
+use crate::options::MinifyLevel;
+
+impl Compressor {
+    /// Removes declarations which are overridden by a later declaration of the
+    /// same property, or of its shorthand, in the same block.
+    pub(super) fn discard_overridden_declarations(&self, simple_block: &mut SimpleBlock) {
+        let mut remove_list = Vec::new();
+
+        for (index, node) in simple_block.value.iter().enumerate() {
+            let ComponentValue::Declaration(declaration) = node else {
+                continue;
+            };
+
+            let is_overridden = simple_block.value[index + 1..]
+                .iter()
+                .any(|node| match node {
+                    ComponentValue::Declaration(later) => self.is_overridden_by(declaration, later),
+                    _ => false,
+                });
+
+            if is_overridden {
+                remove_list.push(index);
+            }
+        }
+
+        if remove_list.is_empty() {
+            return;
+        }
+
+        simple_block.value = simple_block
+            .value
+            .drain(..)
+            .enumerate()
+            .filter_map(|(index, node)| {
+                if remove_list.contains(&index) {
+                    None
+                } else {
+                    Some(node)
+                }
+            })
+            .collect();
+    }
+
+    fn is_overridden_by(&self, declaration: &Declaration, later: &Declaration) -> bool {
+        if declaration.important.is_some() && later.important.is_none() {
+            return false;
+        }
+
+        match (&declaration.name, &later.name) {
+            // Custom properties accept any value, so the later one is always used
+            (DeclarationName::DashedIdent(name), DeclarationName::DashedIdent(later_name)) => {
+                name.value == later_name.value
+            }
+            (DeclarationName::Ident(name), DeclarationName::Ident(later_name)) => {
+                let name = name.value.to_ascii_lowercase();
+                let later_name = later_name.value.to_ascii_lowercase();
+
+                if name == later_name && declaration.value.eq_ignore_span(&later.value) {
+                    return true;
+                }
+
+                // The earlier declaration may be a fallback for browsers which do not
+                // support the later value, and the later value is ignored if it's invalid
+                // for the property, like `color: red; color: 5px`
+                if self.options.level != MinifyLevel::Aggressive {
+                    return false;
+                }
+
+                let mut longhands = Vec::new();
+                let mut later_longhands = Vec::new();
+
+                expand_shorthand(&name, &mut longhands);
+                expand_shorthand(&later_name, &mut later_longhands);
+
+                longhands.iter().all(|name| later_longhands.contains(name))
+            }
+            _ => false,
+        }
+    }
+}
@@ -506,10 +506,20 @@ impl Compressor {
             //     color: green;
             // }
             self.compress_stylesheet(stylesheet);
+        } else if self.merge_non_adjacent_rules(&mut stylesheet.rules) {
+            self.compress_stylesheet(stylesheet);
         }
     }
 
     pub(super) fn compress_simple_block(&mut self, simple_block: &mut SimpleBlock) {
+        if self.options.discard_overridden {
+            self.discard_overridden_declarations(simple_block);
+        }
+
+        if self.options.merge_longhand {
+            self.merge_longhands(simple_block);
+        }
+
         let mut names: FxHashMap<Name, isize> = Default::default();
         let mut prev_rule_idx = None;
         let mut remove_rules_list = Vec::new();
@@ -659,8 +669,355 @@ impl Compressor {
             //     }
             // }
             self.compress_simple_block(simple_block);
+        } else if self.merge_non_adjacent_rules(&mut simple_block.value) {
+            self.compress_simple_block(simple_block);
+        }
+    }
+}
+
+impl Compressor {
+    /// Merges rules which are not adjacent, e.g.
+    ///
+    /// ```css
+    /// a { color: red }
+    /// b { margin: 0 }
+    /// a { padding: 0 }
+    /// ```
+    ///
+    /// The earlier rule is moved to the position of the later one, so this is
+    /// only done if the rules between them do not declare any of the moved
+    /// properties.
+    fn merge_non_adjacent_rules<T: RuleNode>(&mut self, nodes: &mut Vec<T>) -> bool {
+        let mut changed = false;
+        let mut index = 0;
+
+        while index < nodes.len() {
+            match self.find_merge_candidate(nodes, index) {
+                Some(MergeCandidate::Duplicate(prev_index)) => {
+                    nodes.remove(prev_index);
+                    changed = true;
+
+                    continue;
+                }
+                Some(MergeCandidate::Merge(prev_index)) => {
+                    // We need two &mut
+                    let (a, b) = nodes.split_at_mut(index);
+
+                    if let Some(merged) = self.try_merge_nodes(&mut a[prev_index], &mut b[0]) {
+                        b[0] = merged;
+                        nodes.remove(prev_index);
+                        changed = true;
+
+                        continue;
+                    }
+                }
+                None => {}
+            }
+
+            index += 1;
+        }
+
+        changed
+    }
+
+    fn find_merge_candidate<T: RuleNode>(
+        &self,
+        nodes: &[T],
+        index: usize,
+    ) -> Option<MergeCandidate> {
+        let node = &nodes[index];
+
+        if let Some(qualified_rule) = node.as_qualified_rule() {
+            if !self.options.merge_rules || !has_only_declarations(&qualified_rule.block) {
+                return None;
+            }
+
+            for prev_index in (0..index).rev() {
+                let Some(prev_rule) = nodes[prev_index].as_qualified_rule() else {
+                    continue;
+                };
+
+                if !has_only_declarations(&prev_rule.block) {
+                    continue;
+                }
+
+                let is_same_prelude = prev_rule.prelude.eq_ignore_span(&qualified_rule.prelude);
+                let is_same_block = prev_rule.block.eq_ignore_span(&qualified_rule.block);
+
+                // Always safe, the declarations of the later rule win anyway
+                if is_same_prelude && is_same_block {
+                    return Some(MergeCandidate::Duplicate(prev_index));
+                }
+
+                if !is_same_prelude && !is_same_block {
+                    continue;
+                }
+
+                if self.can_merge_qualified_rules(prev_rule, qualified_rule)
+                    && can_move_over(&nodes[prev_index], &nodes[prev_index + 1..index])
+                {
+                    return Some(MergeCandidate::Merge(prev_index));
+                }
+
+                return None;
+            }
+        } else if let Some(at_rule) = node.as_at_rule() {
+            if !self.options.merge_at_rules || !self.can_reorder_at_rule(at_rule) {
+                return None;
+            }
+
+            for prev_index in (0..index).rev() {
+                let Some(prev_rule) = nodes[prev_index].as_at_rule() else {
+                    continue;
+                };
+
+                if !prev_rule.name.eq_ignore_span(&at_rule.name)
+                    || !prev_rule.prelude.eq_ignore_span(&at_rule.prelude)
+                    || !self.can_reorder_at_rule(prev_rule)
+                {
+                    continue;
+                }
+
+                if prev_rule.block.eq_ignore_span(&at_rule.block) {
+                    return Some(MergeCandidate::Duplicate(prev_index));
+                }
+
+                if can_move_over(&nodes[prev_index], &nodes[prev_index + 1..index]) {
+                    return Some(MergeCandidate::Merge(prev_index));
+                }
+
+                return None;
+            }
+        }
+
+        None
+    }
+
+    fn can_reorder_at_rule(&self, at_rule: &AtRule) -> bool {
+        // The order of `@layer` is defined by their first occurrence
+        if !self.is_mergeable_at_rule(at_rule)
+            || at_rule
+                .name
+                .as_ident()
+                .map_or(true, |ident| ident.value.eq_ignore_ascii_case("layer"))
+        {
+            return false;
+        }
+
+        let mut collector = PropertyCollector::default();
+
+        at_rule.visit_with(&mut collector);
+
+        at_rule.block.is_some() && !collector.has_layer
+    }
+
+    fn try_merge_nodes<T: RuleNode>(&mut self, left: &mut T, right: &mut T) -> Option<T> {
+        if let (Some(left), Some(right)) =
+            (left.as_mut_qualified_rule(), right.as_mut_qualified_rule())
+        {
+            return self
+                .try_merge_qualified_rules(left, right)
+                .map(T::from_qualified_rule);
+        }
+
+        if let (Some(left), Some(right)) = (left.as_mut_at_rule(), right.as_mut_at_rule()) {
+            return self.try_merge_at_rule(left, right).map(T::from_at_rule);
+        }
+
+        None
+    }
+}
+
+enum MergeCandidate {
+    /// The rule at the index is identical and can be removed.
+    Duplicate(usize),
+    /// The rule at the index can be merged into the current one.
+    Merge(usize),
+}
+
+/// [Rule] for stylesheets and [ComponentValue] for blocks.
+trait RuleNode: VisitWith<PropertyCollector> {
+    fn as_qualified_rule(&self) -> Option<&QualifiedRule>;
+
+    fn as_mut_qualified_rule(&mut self) -> Option<&mut QualifiedRule>;
+
+    fn as_at_rule(&self) -> Option<&AtRule>;
+
+    fn as_mut_at_rule(&mut self) -> Option<&mut AtRule>;
+
+    fn from_qualified_rule(qualified_rule: QualifiedRule) -> Self;
+
+    fn from_at_rule(at_rule: AtRule) -> Self;
+}
+
+impl RuleNode for Rule {
+    fn as_qualified_rule(&self) -> Option<&QualifiedRule> {
+        match self {
+            Rule::QualifiedRule(qualified_rule) => Some(qualified_rule),
+            _ => None,
+        }
+    }
+
+    fn as_mut_qualified_rule(&mut self) -> Option<&mut QualifiedRule> {
+        match self {
+            Rule::QualifiedRule(qualified_rule) => Some(qualified_rule),
+            _ => None,
+        }
+    }
+
+    fn as_at_rule(&self) -> Option<&AtRule> {
+        match self {
+            Rule::AtRule(at_rule) => Some(at_rule),
+            _ => None,
+        }
+    }
+
+    fn as_mut_at_rule(&mut self) -> Option<&mut AtRule> {
+        match self {
+            Rule::AtRule(at_rule) => Some(at_rule),
+            _ => None,
+        }
+    }
+
+    fn from_qualified_rule(qualified_rule: QualifiedRule) -> Self {
+        Rule::QualifiedRule(Box::new(qualified_rule))
+    }
+
+    fn from_at_rule(at_rule: AtRule) -> Self {
+        Rule::AtRule(Box::new(at_rule))
+    }
+}
+
+impl RuleNode for ComponentValue {
+    fn as_qualified_rule(&self) -> Option<&QualifiedRule> {
+        match self {
+            ComponentValue::QualifiedRule(qualified_rule) => Some(qualified_rule),
+            _ => None,
+        }
+    }
+
+    fn as_mut_qualified_rule(&mut self) -> Option<&mut QualifiedRule> {
+        match self {
+            ComponentValue::QualifiedRule(qualified_rule) => Some(qualified_rule),
+            _ => None,
+        }
+    }
+
+    fn as_at_rule(&self) -> Option<&AtRule> {
+        match self {
+            ComponentValue::AtRule(at_rule) => Some(at_rule),
+            _ => None,
+        }
+    }
+
+    fn as_mut_at_rule(&mut self) -> Option<&mut AtRule> {
+        match self {
+            ComponentValue::AtRule(at_rule) => Some(at_rule),
+            _ => None,
         }
     }
+
+    fn from_qualified_rule(qualified_rule: QualifiedRule) -> Self {
+        ComponentValue::QualifiedRule(Box::new(qualified_rule))
+    }
+
+    fn from_at_rule(at_rule: AtRule) -> Self {
+        ComponentValue::AtRule(Box::new(at_rule))
+    }
+}
+
+/// Collects the properties declared by rules which apply to elements.
+#[derive(Default)]
+struct PropertyCollector {
+    families: Vec<Atom>,
+    has_layer: bool,
+}
+
+impl Visit for PropertyCollector {
+    fn visit_at_rule(&mut self, n: &AtRule) {
+        if let AtRuleName::Ident(Ident { value, .. }) = &n.name {
+            let name = value.to_ascii_lowercase();
+
+            if name.ends_with("keyframes")
+                || matches!(
+                    &*name,
+                    "font-face"
+                        | "counter-style"
+                        | "property"
+                        | "font-feature-values"
+                        | "font-palette-values"
+                )
+            {
+                return;
+            }
+
+            if name == "layer" {
+                self.has_layer = true;
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_declaration(&mut self, n: &Declaration) {
+        self.families.push(get_property_family(&n.name));
+    }
+}
+
+fn has_only_declarations(block: &SimpleBlock) -> bool {
+    block
+        .value
+        .iter()
+        .all(|node| matches!(node, ComponentValue::Declaration(_)))
+}
+
+/// Returns `true` if `node` can be moved after `nodes` without changing the
+/// result of the cascade.
+fn can_move_over<T: RuleNode>(node: &T, nodes: &[T]) -> bool {
+    let mut moved = PropertyCollector::default();
+
+    node.visit_with(&mut moved);
+
+    let mut others = PropertyCollector::default();
+
+    for node in nodes {
+        node.visit_with(&mut others);
+    }
+
+    !moved.families.iter().any(|family| {
+        others
+            .families
+            .iter()
+            .any(|other| other == family || *other == "all" || *family == "all")
+    })
+}
+
+/// Returns a name shared by all the properties which can override each other,
+/// e.g. `border` for `border-top-width`, or `size` for `width` and
+/// `inline-size`.
+fn get_property_family(name: &DeclarationName) -> Atom {
+    let name = match name {
+        DeclarationName::Ident(Ident { value, .. }) => value.to_ascii_lowercase(),
+        DeclarationName::DashedIdent(DashedIdent { value, .. }) => return value.clone(),
+    };
+
+    // Vendor prefix, e.g. `-webkit-`
+    let unprefixed = match name.strip_prefix('-') {
+        Some(rest) => rest.split_once('-').map_or(rest, |(_, rest)| rest),
+        None => &name,
+    };
+    let first = unprefixed.split('-').next().unwrap_or_default();
+
+    match first {
+        "top" | "right" | "bottom" | "left" | "inset" => "inset",
+        "place" | "align" | "justify" => "align",
+        "width" | "height" | "min" | "max" | "inline" | "block" => "size",
+        "grid" | "gap" | "row" | "column" | "columns" => "grid",
+        "font" | "line" => "font",
+        "overflow" | "word" => "overflow",
+        _ => first,
+    }
+    .into()
 }
 
 #[inline]
@@ -3,4 +3,54 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
-pub struct MinifyOptions {}
+pub struct MinifyOptions {
+    /// Controls how far the structural optimizations may go.
+    ///
+    /// The structural optimizations below are disabled by default.
+    #[serde(default)]
+    pub level: MinifyLevel,
+
+    /// Merge rules with identical selectors or identical declarations which
+    /// are not adjacent. Adjacent rules are always merged.
+    ///
+    /// The rules are merged only if the rules between them do not declare any
+    /// of the moved properties.
+    #[serde(default)]
+    pub merge_rules: bool,
+
+    /// Remove declarations which are overridden by a later declaration of the
+    /// same property in the same block.
+    ///
+    /// At the safe level, only repeated declarations with the same value are
+    /// removed. The aggressive level also removes declarations overridden by a
+    /// different value, e.g. `color: red; color: blue`, or by a shorthand.
+    #[serde(default)]
+    pub discard_overridden: bool,
+
+    /// Merge longhand properties into their shorthand, e.g. `margin-top`,
+    /// `margin-right`, `margin-bottom` and `margin-left` into `margin`.
+    #[serde(default)]
+    pub merge_longhand: bool,
+
+    /// Merge and deduplicate at-rules with identical preludes, like `@media`
+    /// and `@supports`, which are not adjacent. Adjacent at-rules are always
+    /// merged.
+    #[serde(default)]
+    pub merge_at_rules: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MinifyLevel {
+    /// Only optimizations which do not change the result of the cascade in any
+    /// browser.
+    #[default]
+    Safe,
+
+    /// Also optimizations which are correct for browsers supporting all the
+    /// used values, assuming the values are valid for their properties.
+    /// Fallback declarations like `width: 100px; width: calc(100% - 10px)`
+    /// are removed, and `font` and `border` shorthands, which reset other
+    /// properties, are created from longhands.
+    Aggressive,
+}
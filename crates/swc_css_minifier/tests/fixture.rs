@@ -1,11 +1,11 @@
-use std::path::PathBuf;
+use std::{fs::read_to_string, path::PathBuf};
 
 use swc_css_ast::Stylesheet;
 use swc_css_codegen::{
     writer::basic::{BasicCssWriter, BasicCssWriterConfig},
     CodeGenerator, CodegenConfig, Emit,
 };
-use swc_css_minifier::minify;
+use swc_css_minifier::{minify, options::MinifyOptions};
 use swc_css_parser::parse_file;
 use testing::NormalizedOutput;
 
@@ -16,6 +16,14 @@ fn minify_fixtures(input: PathBuf) {
         "output.min.{}",
         input.extension().unwrap().to_string_lossy()
     ));
+    let config = dir.join("config.json");
+    let options: MinifyOptions = if config.exists() {
+        let config = read_to_string(&config).expect("failed to read config.json");
+
+        serde_json::from_str(&config).expect("failed to parse config.json")
+    } else {
+        Default::default()
+    };
 
     testing::run_test(false, |cm, handler| {
         let fm = cm.load_file(&input).unwrap();
@@ -34,7 +42,7 @@ fn minify_fixtures(input: PathBuf) {
         let mut ss = res.unwrap();
 
         // Apply transforms
-        minify(&mut ss, Default::default());
+        minify(&mut ss, options.clone());
 
         let mut css_str = String::new();
         {
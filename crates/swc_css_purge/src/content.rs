@@ -0,0 +1,212 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_css_modules::{CssClassName, TransformResult};
+use swc_ecma_ast::{
+    IdentName, JSXAttr, JSXAttrName, JSXElementName, JSXOpeningElement, JSXText, MemberProp,
+    ObjectPatProp, Program, PropName, Str, TplElement,
+};
+use swc_ecma_visit::{Visit as EcmaVisit, VisitWith as EcmaVisitWith};
+use swc_html_ast::{Child, Document, DocumentFragment, Element};
+use swc_html_visit::{Visit as HtmlVisit, VisitWith as HtmlVisitWith};
+
+/// Names which are used by the content of a project, i.e. HTML documents and
+/// JavaScript programs.
+///
+/// Names are collected conservatively. Every string in a program is treated
+/// as a list of class names, ids and tag names, because there's no way to
+/// know how it's used.
+#[derive(Debug, Default, Clone)]
+pub struct Content {
+    pub(crate) tags: FxHashSet<Atom>,
+    pub(crate) classes: FxHashSet<Atom>,
+    pub(crate) ids: FxHashSet<Atom>,
+    /// Lowercase names of attributes.
+    pub(crate) attributes: FxHashSet<Atom>,
+    /// Words from strings, attribute values and scripts.
+    pub(crate) words: FxHashSet<Atom>,
+    /// Local names of CSS Modules to the generated class names.
+    pub(crate) css_modules: FxHashMap<Atom, Vec<Atom>>,
+}
+
+impl Content {
+    pub fn add_html_document(&mut self, document: &Document) {
+        document.visit_with(&mut HtmlCollector { content: self });
+    }
+
+    pub fn add_html_document_fragment(&mut self, document_fragment: &DocumentFragment) {
+        document_fragment.visit_with(&mut HtmlCollector { content: self });
+    }
+
+    /// Adds class names, ids and tag names used by a program, including JSX
+    /// elements and `className` attributes.
+    pub fn add_program(&mut self, program: &Program) {
+        program.visit_with(&mut EcmaCollector { content: self });
+    }
+
+    /// Adds the class names generated by [swc_css_modules::compile].
+    ///
+    /// A generated class name is used if its local name is used by the
+    /// content, e.g. `styles.button` or `styles["button"]` for `.button`.
+    pub fn add_css_modules(&mut self, result: &TransformResult) {
+        for (local, names) in &result.renamed {
+            let entry = self.css_modules.entry(local.clone()).or_default();
+
+            for name in names {
+                match name {
+                    CssClassName::Local { name } | CssClassName::Global { name } => {
+                        entry.push(name.value.clone());
+                    }
+                    // Belongs to another stylesheet
+                    CssClassName::Import { .. } => {}
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if `name` appears in the content in any form.
+    pub(crate) fn has_word(&self, name: &str) -> bool {
+        let name = &Atom::from(name);
+
+        self.words.contains(name)
+            || self.classes.contains(name)
+            || self.ids.contains(name)
+            || self.tags.contains(name)
+    }
+
+    /// Class names generated by CSS Modules which are used by the content.
+    pub(crate) fn used_css_module_classes(&self) -> FxHashSet<Atom> {
+        self.css_modules
+            .iter()
+            .filter(|(local, _)| self.has_word(local))
+            .flat_map(|(_, names)| names.iter().cloned())
+            .collect()
+    }
+
+    fn add_words(&mut self, value: &str) {
+        for word in value.split_ascii_whitespace() {
+            self.words.insert(word.into());
+
+            // e.g. `"foo"` and `foo;` in inline scripts and styles
+            for part in word.split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_')) {
+                if !part.is_empty() && part != word {
+                    self.words.insert(part.into());
+                }
+            }
+        }
+    }
+}
+
+struct HtmlCollector<'a> {
+    content: &'a mut Content,
+}
+
+impl HtmlVisit for HtmlCollector<'_> {
+    fn visit_element(&mut self, n: &Element) {
+        self.content.tags.insert(n.tag_name.to_ascii_lowercase());
+
+        for attribute in &n.attributes {
+            let name = attribute.name.to_ascii_lowercase();
+            let Some(value) = &attribute.value else {
+                self.content.attributes.insert(name);
+
+                continue;
+            };
+
+            match &*name {
+                "class" => {
+                    for class in value.split_ascii_whitespace() {
+                        self.content.classes.insert(class.into());
+                    }
+                }
+                "id" => {
+                    self.content.ids.insert(value.trim().into());
+                }
+                _ => {}
+            }
+
+            self.content.add_words(value);
+            self.content.attributes.insert(name);
+        }
+
+        // Inline scripts may add classes
+        if n.tag_name == "script" {
+            for child in &n.children {
+                if let Child::Text(text) = child {
+                    self.content.add_words(&text.data);
+                }
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+struct EcmaCollector<'a> {
+    content: &'a mut Content,
+}
+
+impl EcmaVisit for EcmaCollector<'_> {
+    fn visit_jsx_attr(&mut self, n: &JSXAttr) {
+        if let JSXAttrName::Ident(IdentName { sym, .. }) = &n.name {
+            let name = match &**sym {
+                "className" => "class".into(),
+                "htmlFor" => "for".into(),
+                _ => sym.to_ascii_lowercase(),
+            };
+
+            self.content.attributes.insert(name);
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_opening_element(&mut self, n: &JSXOpeningElement) {
+        // Components start with an uppercase letter
+        if let JSXElementName::Ident(ident) = &n.name {
+            if ident.sym.starts_with(|c: char| c.is_ascii_lowercase()) {
+                self.content.tags.insert(ident.sym.clone());
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_text(&mut self, n: &JSXText) {
+        self.content.add_words(&n.value);
+    }
+
+    fn visit_member_prop(&mut self, n: &MemberProp) {
+        // e.g. `styles.button` of CSS Modules
+        if let MemberProp::Ident(IdentName { sym, .. }) = n {
+            self.content.words.insert(sym.clone());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_object_pat_prop(&mut self, n: &ObjectPatProp) {
+        // e.g. `const { button } = styles`
+        if let ObjectPatProp::Assign(prop) = n {
+            self.content.words.insert(prop.key.sym.clone());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName) {
+        // e.g. `classNames({ active: isActive })`
+        if let PropName::Ident(IdentName { sym, .. }) = n {
+            self.content.words.insert(sym.clone());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_str(&mut self, n: &Str) {
+        self.content.add_words(&n.value);
+    }
+
+    fn visit_tpl_element(&mut self, n: &TplElement) {
+        self.content.add_words(n.cooked.as_ref().unwrap_or(&n.raw));
+    }
+}
@@ -0,0 +1,38 @@
+//! Removes CSS rules which are not used by the content of a project, like
+//! [PurgeCSS](https://purgecss.com/).
+//!
+//! The content is collected from HTML documents and JavaScript programs with
+//! [Content]. A selector is removed only if one of its class names, ids, tag
+//! names or attribute names does not appear in the content.
+
+#![deny(clippy::all)]
+
+use swc_css_ast::Stylesheet;
+
+pub use self::content::Content;
+use self::{
+    options::PurgeOptions,
+    purge::Purger,
+    unused::{remove_unused_font_faces, remove_unused_keyframes, remove_unused_variables},
+};
+
+mod content;
+pub mod options;
+mod purge;
+mod unused;
+
+pub fn purge(stylesheet: &mut Stylesheet, content: &Content, options: &PurgeOptions) {
+    Purger::new(content, options).purge_stylesheet(stylesheet);
+
+    if options.keyframes {
+        remove_unused_keyframes(stylesheet, content, &options.safelist);
+    }
+
+    if options.font_face {
+        remove_unused_font_faces(stylesheet, content, &options.safelist);
+    }
+
+    if options.variables {
+        remove_unused_variables(stylesheet, content, &options.safelist);
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use swc_config::regex::CachedRegex;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct PurgeOptions {
+    #[serde(default)]
+    pub safelist: Safelist,
+
+    /// Remove `@keyframes` which are not used by any `animation` or
+    /// `animation-name` declaration.
+    #[serde(default = "true_by_default")]
+    pub keyframes: bool,
+
+    /// Remove `@font-face` rules whose `font-family` is not used by any `font`
+    /// or `font-family` declaration.
+    #[serde(default = "true_by_default")]
+    pub font_face: bool,
+
+    /// Remove custom properties which are not used by any `var()`.
+    #[serde(default = "true_by_default")]
+    pub variables: bool,
+}
+
+impl Default for PurgeOptions {
+    fn default() -> Self {
+        PurgeOptions {
+            safelist: Default::default(),
+            keyframes: true,
+            font_face: true,
+            variables: true,
+        }
+    }
+}
+
+/// Names which are never removed.
+///
+/// `exact` and `regex` are matched against class names, ids and tag names of
+/// selectors, and against the names of keyframes, lowercase font families and
+/// custom properties including `--`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct Safelist {
+    /// e.g. `["active"]` for `.active`
+    #[serde(default)]
+    pub exact: Vec<String>,
+
+    #[serde(default)]
+    pub regex: Vec<CachedRegex>,
+
+    /// Keep a selector if any part of it matches, and everything nested in the
+    /// rule. e.g. `"^modal"` keeps `.modal .unused-header`.
+    #[serde(default)]
+    pub deep: Vec<CachedRegex>,
+}
+
+impl Safelist {
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.exact.iter().any(|exact| exact == name)
+            || self.regex.iter().any(|regex| regex.is_match(name))
+    }
+
+    pub(crate) fn contains_deep(&self, name: &str) -> bool {
+        self.deep.iter().any(|regex| regex.is_match(name))
+    }
+}
+
+const fn true_by_default() -> bool {
+    true
+}
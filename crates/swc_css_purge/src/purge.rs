@@ -0,0 +1,219 @@
+use rustc_hash::FxHashSet;
+use swc_atoms::Atom;
+use swc_css_ast::*;
+
+use crate::{options::PurgeOptions, Content};
+
+/// Removes selectors which do not match any element of the content.
+pub(crate) struct Purger<'a> {
+    content: &'a Content,
+    options: &'a PurgeOptions,
+    css_module_classes: FxHashSet<Atom>,
+}
+
+impl<'a> Purger<'a> {
+    pub fn new(content: &'a Content, options: &'a PurgeOptions) -> Self {
+        Purger {
+            content,
+            options,
+            css_module_classes: content.used_css_module_classes(),
+        }
+    }
+
+    pub fn purge_stylesheet(&self, stylesheet: &mut Stylesheet) {
+        stylesheet.rules.retain_mut(|rule| match rule {
+            Rule::QualifiedRule(qualified_rule) => self.purge_qualified_rule(qualified_rule),
+            Rule::AtRule(at_rule) => self.purge_at_rule(at_rule),
+            Rule::ListOfComponentValues(_) => true,
+        });
+    }
+
+    fn purge_simple_block(&self, simple_block: &mut SimpleBlock) {
+        simple_block.value.retain_mut(|node| match node {
+            ComponentValue::QualifiedRule(qualified_rule) => {
+                self.purge_qualified_rule(qualified_rule)
+            }
+            ComponentValue::AtRule(at_rule) => self.purge_at_rule(at_rule),
+            _ => true,
+        });
+    }
+
+    /// Returns `false` if the rule should be removed.
+    fn purge_qualified_rule(&self, qualified_rule: &mut QualifiedRule) -> bool {
+        match &mut qualified_rule.prelude {
+            QualifiedRulePrelude::SelectorList(selector_list) => {
+                if selector_list
+                    .children
+                    .iter()
+                    .any(|selector| self.is_deep_safelisted(selector))
+                {
+                    return true;
+                }
+
+                selector_list
+                    .children
+                    .retain(|selector| self.can_match(selector));
+
+                if selector_list.children.is_empty() {
+                    return false;
+                }
+            }
+            QualifiedRulePrelude::RelativeSelectorList(relative_selector_list) => {
+                if relative_selector_list
+                    .children
+                    .iter()
+                    .any(|selector| self.is_deep_safelisted(&selector.selector))
+                {
+                    return true;
+                }
+
+                relative_selector_list
+                    .children
+                    .retain(|selector| self.can_match(&selector.selector));
+
+                if relative_selector_list.children.is_empty() {
+                    return false;
+                }
+            }
+            QualifiedRulePrelude::ListOfComponentValues(_) => return true,
+        }
+
+        self.purge_simple_block(&mut qualified_rule.block);
+
+        true
+    }
+
+    /// Returns `false` if the rule should be removed.
+    fn purge_at_rule(&self, at_rule: &mut AtRule) -> bool {
+        let AtRuleName::Ident(Ident { value: name, .. }) = &at_rule.name else {
+            return true;
+        };
+        let Some(block) = &mut at_rule.block else {
+            return true;
+        };
+
+        match &*name.to_ascii_lowercase() {
+            // The order of layers is defined by their first occurrence, so we keep empty
+            // layers
+            "layer" => {
+                self.purge_simple_block(block);
+
+                true
+            }
+            "media" | "supports" | "container" | "document" | "-moz-document" | "scope"
+            | "starting-style" => {
+                if block.value.is_empty() {
+                    return true;
+                }
+
+                self.purge_simple_block(block);
+
+                !block.value.is_empty()
+            }
+            _ => true,
+        }
+    }
+
+    fn can_match(&self, selector: &ComplexSelector) -> bool {
+        selector.children.iter().all(|child| match child {
+            ComplexSelectorChildren::CompoundSelector(compound_selector) => {
+                self.can_match_compound_selector(compound_selector)
+            }
+            ComplexSelectorChildren::Combinator(_) => true,
+        })
+    }
+
+    /// Pseudo-classes and pseudo-elements are ignored, e.g. `.foo:hover` is
+    /// used if `.foo` is used.
+    fn can_match_compound_selector(&self, compound_selector: &CompoundSelector) -> bool {
+        if let Some(type_selector) = &compound_selector.type_selector {
+            if let TypeSelector::TagName(tag_name_selector) = &**type_selector {
+                if !self.is_tag_used(&tag_name_selector.name.value.value) {
+                    return false;
+                }
+            }
+        }
+
+        compound_selector
+            .subclass_selectors
+            .iter()
+            .all(|subclass_selector| match subclass_selector {
+                SubclassSelector::Id(IdSelector { text, .. }) => self.is_id_used(&text.value),
+                SubclassSelector::Class(ClassSelector { text, .. }) => {
+                    self.is_class_used(&text.value)
+                }
+                SubclassSelector::Attribute(attribute_selector) => {
+                    self.is_attribute_used(&attribute_selector.name.value.value)
+                }
+                SubclassSelector::PseudoClass(_) | SubclassSelector::PseudoElement(_) => true,
+            })
+    }
+
+    fn is_deep_safelisted(&self, selector: &ComplexSelector) -> bool {
+        if self.options.safelist.deep.is_empty() {
+            return false;
+        }
+
+        selector.children.iter().any(|child| {
+            let ComplexSelectorChildren::CompoundSelector(compound_selector) = child else {
+                return false;
+            };
+
+            let is_type_safelisted = compound_selector
+                .type_selector
+                .as_deref()
+                .and_then(|type_selector| match type_selector {
+                    TypeSelector::TagName(tag_name_selector) => Some(tag_name_selector),
+                    TypeSelector::Universal(_) => None,
+                })
+                .is_some_and(|tag_name_selector| {
+                    self.options
+                        .safelist
+                        .contains_deep(&tag_name_selector.name.value.value)
+                });
+
+            is_type_safelisted
+                || compound_selector
+                    .subclass_selectors
+                    .iter()
+                    .any(|subclass_selector| match subclass_selector {
+                        SubclassSelector::Id(IdSelector { text, .. })
+                        | SubclassSelector::Class(ClassSelector { text, .. }) => {
+                            self.options.safelist.contains_deep(&text.value)
+                        }
+                        _ => false,
+                    })
+        })
+    }
+
+    fn is_tag_used(&self, name: &str) -> bool {
+        let name = Atom::from(name.to_ascii_lowercase());
+
+        // Always exist, even if the content is a fragment
+        matches!(&*name, "html" | "body")
+            || self.content.tags.contains(&name)
+            || self.content.words.contains(&name)
+            || self.options.safelist.contains(&name)
+    }
+
+    fn is_class_used(&self, name: &Atom) -> bool {
+        self.content.classes.contains(name)
+            || self.content.words.contains(name)
+            || self.css_module_classes.contains(name)
+            || self.options.safelist.contains(name)
+    }
+
+    fn is_id_used(&self, name: &Atom) -> bool {
+        self.content.ids.contains(name)
+            || self.content.words.contains(name)
+            || self.options.safelist.contains(name)
+    }
+
+    fn is_attribute_used(&self, name: &str) -> bool {
+        let name = Atom::from(name.to_ascii_lowercase());
+
+        self.content.attributes.contains(&name)
+            || self.content.words.contains(&name)
+            || self.options.safelist.contains(&name)
+    }
+}
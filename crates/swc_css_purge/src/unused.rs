@@ -0,0 +1,329 @@
+//! Removes at-rules and custom properties which are not referenced by the
+//! remaining rules.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::{options::Safelist, Content};
+
+pub(crate) fn remove_unused_keyframes(
+    stylesheet: &mut Stylesheet,
+    content: &Content,
+    safelist: &Safelist,
+) {
+    let mut collector = AnimationNameCollector::default();
+
+    stylesheet.visit_with(&mut collector);
+
+    stylesheet.visit_mut_with(&mut AtRuleRemover {
+        should_remove: |at_rule: &AtRule| {
+            let Some(name) = get_keyframes_name(at_rule) else {
+                return false;
+            };
+
+            !collector.names.contains(name) && !content.has_word(name) && !safelist.contains(name)
+        },
+    });
+}
+
+pub(crate) fn remove_unused_font_faces(
+    stylesheet: &mut Stylesheet,
+    content: &Content,
+    safelist: &Safelist,
+) {
+    let mut collector = FontFamilyCollector::default();
+
+    stylesheet.visit_with(&mut collector);
+
+    stylesheet.visit_mut_with(&mut AtRuleRemover {
+        should_remove: |at_rule: &AtRule| {
+            if !at_rule
+                .name
+                .as_ident()
+                .is_some_and(|ident| ident.value.eq_ignore_ascii_case("font-face"))
+            {
+                return false;
+            }
+
+            let mut font_face_collector = FontFamilyCollector::default();
+
+            at_rule.visit_children_with(&mut font_face_collector);
+
+            // Keep rules we don't understand
+            if font_face_collector.families.is_empty() {
+                return false;
+            }
+
+            font_face_collector.families.iter().all(|family| {
+                !collector.families.contains(family)
+                    && !content.has_word(family)
+                    && !safelist.contains(family)
+            })
+        },
+    });
+}
+
+pub(crate) fn remove_unused_variables(
+    stylesheet: &mut Stylesheet,
+    content: &Content,
+    safelist: &Safelist,
+) {
+    // Custom properties may reference each other, so we start from the
+    // references of other properties and follow them
+    let mut collector = VariableCollector::default();
+
+    stylesheet.visit_with(&mut collector);
+
+    let mut used: FxHashSet<Atom> = collector.roots;
+    let mut queue: Vec<Atom> = used.iter().cloned().collect();
+
+    for name in collector.declared.keys() {
+        // `name` does not include `--`
+        let name_with_dashes = format!("--{name}");
+
+        if (content.has_word(&name_with_dashes) || safelist.contains(&name_with_dashes))
+            && used.insert(name.clone())
+        {
+            queue.push(name.clone());
+        }
+    }
+
+    while let Some(name) = queue.pop() {
+        if let Some(references) = collector.declared.get(&name) {
+            for reference in references {
+                if used.insert(reference.clone()) {
+                    queue.push(reference.clone());
+                }
+            }
+        }
+    }
+
+    stylesheet.visit_mut_with(&mut VariableRemover { used: &used });
+}
+
+fn get_keyframes_name(at_rule: &AtRule) -> Option<&Atom> {
+    let AtRuleName::Ident(Ident { value, .. }) = &at_rule.name else {
+        return None;
+    };
+
+    // e.g. `@-webkit-keyframes`
+    if !value.to_ascii_lowercase().ends_with("keyframes") {
+        return None;
+    }
+
+    match at_rule.prelude.as_deref() {
+        Some(AtRulePrelude::KeyframesPrelude(KeyframesName::CustomIdent(custom_ident))) => {
+            Some(&custom_ident.value)
+        }
+        Some(AtRulePrelude::KeyframesPrelude(KeyframesName::Str(s))) => Some(&s.value),
+        _ => None,
+    }
+}
+
+fn get_unprefixed_name(name: &DeclarationName) -> Option<String> {
+    let DeclarationName::Ident(Ident { value, .. }) = name else {
+        return None;
+    };
+    let name = value.to_ascii_lowercase();
+
+    match name.strip_prefix('-') {
+        Some(rest) => rest.split_once('-').map(|(_, name)| name.to_string()),
+        None => Some(name.to_string()),
+    }
+}
+
+/// Removes at-rules from the stylesheet and from nested blocks.
+struct AtRuleRemover<F>
+where
+    F: FnMut(&AtRule) -> bool,
+{
+    should_remove: F,
+}
+
+impl<F> VisitMut for AtRuleRemover<F>
+where
+    F: FnMut(&AtRule) -> bool,
+{
+    fn visit_mut_stylesheet(&mut self, n: &mut Stylesheet) {
+        n.rules.retain(|rule| match rule {
+            Rule::AtRule(at_rule) => !(self.should_remove)(at_rule),
+            _ => true,
+        });
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_simple_block(&mut self, n: &mut SimpleBlock) {
+        n.value.retain(|node| match node {
+            ComponentValue::AtRule(at_rule) => !(self.should_remove)(at_rule),
+            _ => true,
+        });
+
+        n.visit_mut_children_with(self);
+    }
+}
+
+#[derive(Default)]
+struct AnimationNameCollector {
+    names: FxHashSet<Atom>,
+}
+
+impl Visit for AnimationNameCollector {
+    fn visit_declaration(&mut self, n: &Declaration) {
+        if !matches!(
+            get_unprefixed_name(&n.name).as_deref(),
+            Some("animation" | "animation-name")
+        ) {
+            return;
+        }
+
+        for value in &n.value {
+            match value {
+                ComponentValue::Ident(ident) => {
+                    self.names.insert(ident.value.clone());
+                }
+                ComponentValue::Str(s) => {
+                    self.names.insert(s.value.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Collects lowercase font family names.
+#[derive(Default)]
+struct FontFamilyCollector {
+    families: FxHashSet<Atom>,
+}
+
+impl Visit for FontFamilyCollector {
+    fn visit_at_rule(&mut self, n: &AtRule) {
+        // Declares a font family rather than using it
+        if n.name
+            .as_ident()
+            .is_some_and(|ident| ident.value.eq_ignore_ascii_case("font-face"))
+        {
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_declaration(&mut self, n: &Declaration) {
+        if !matches!(
+            get_unprefixed_name(&n.name).as_deref(),
+            Some("font" | "font-family")
+        ) {
+            return;
+        }
+
+        // Names with spaces can be written without quotes, e.g. `Open Sans`
+        let mut words: Vec<&str> = Vec::new();
+
+        for value in n.value.iter().map(Some).chain([None]) {
+            match value {
+                Some(ComponentValue::Ident(ident)) => {
+                    words.push(&ident.value);
+                }
+                Some(ComponentValue::Str(s)) => {
+                    self.families.insert(s.value.to_ascii_lowercase());
+                }
+                _ => {
+                    if !words.is_empty() {
+                        self.families
+                            .insert(words.join(" ").to_ascii_lowercase().into());
+                        words.clear();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct VariableCollector {
+    /// Custom properties referenced by other properties, without `--`.
+    roots: FxHashSet<Atom>,
+    /// Declared custom properties and the custom properties they reference.
+    declared: FxHashMap<Atom, FxHashSet<Atom>>,
+}
+
+impl Visit for VariableCollector {
+    fn visit_declaration(&mut self, n: &Declaration) {
+        let mut references = ReferenceCollector::default();
+
+        n.value.visit_with(&mut references);
+
+        match &n.name {
+            DeclarationName::DashedIdent(name) => {
+                self.declared
+                    .entry(name.value.clone())
+                    .or_default()
+                    .extend(references.names);
+            }
+            DeclarationName::Ident(_) => {
+                self.roots.extend(references.names);
+            }
+        }
+    }
+}
+
+/// Collects the names used by `var()`.
+#[derive(Default)]
+struct ReferenceCollector {
+    names: FxHashSet<Atom>,
+}
+
+impl Visit for ReferenceCollector {
+    fn visit_function(&mut self, n: &Function) {
+        if n.name == "var" {
+            // Values of custom properties are not parsed
+            let name = n.value.iter().find_map(|value| match value {
+                ComponentValue::DashedIdent(name) => Some(name.value.clone()),
+                ComponentValue::PreservedToken(token) => match &token.token {
+                    Token::Ident { value, .. } => value.strip_prefix("--").map(Atom::from),
+                    _ => None,
+                },
+                _ => None,
+            });
+
+            if let Some(name) = name {
+                self.names.insert(name);
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+struct VariableRemover<'a> {
+    used: &'a FxHashSet<Atom>,
+}
+
+/// Also removes the rules which become empty.
+impl VisitMut for VariableRemover<'_> {
+    fn visit_mut_stylesheet(&mut self, n: &mut Stylesheet) {
+        n.visit_mut_children_with(self);
+
+        n.rules.retain(|rule| match rule {
+            Rule::QualifiedRule(qualified_rule) => !qualified_rule.block.value.is_empty(),
+            _ => true,
+        });
+    }
+
+    fn visit_mut_simple_block(&mut self, n: &mut SimpleBlock) {
+        n.visit_mut_children_with(self);
+
+        n.value.retain(|node| match node {
+            ComponentValue::Declaration(declaration) => match &declaration.name {
+                DeclarationName::DashedIdent(name) => self.used.contains(&name.value),
+                DeclarationName::Ident(_) => true,
+            },
+            ComponentValue::QualifiedRule(qualified_rule) => !qualified_rule.block.value.is_empty(),
+            _ => true,
+        });
+    }
+}
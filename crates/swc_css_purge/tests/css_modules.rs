@@ -0,0 +1,85 @@
+use swc_atoms::Atom;
+use swc_common::FileName;
+use swc_css_ast::Stylesheet;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_modules::TransformConfig;
+use swc_css_parser::parser::ParserConfig;
+use swc_css_purge::{purge, Content};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{parse_file_as_program, Syntax};
+
+struct Config;
+
+impl TransformConfig for Config {
+    fn new_name_for(&self, local: &Atom) -> Atom {
+        format!("{local}__hash").into()
+    }
+}
+
+#[test]
+fn css_modules() {
+    let output = testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            ".button { color: red }
+            .primary { composes: button; color: blue }
+            .unused { color: green }
+            .title { color: black }"
+                .to_string(),
+        );
+        let mut ss: Stylesheet = swc_css_parser::parse_file(
+            &fm,
+            None,
+            ParserConfig {
+                css_modules: true,
+                ..Default::default()
+            },
+            &mut Vec::new(),
+        )
+        .unwrap();
+        let result = swc_css_modules::compile(&mut ss, Config);
+
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "import styles from './button.module.css';
+            const { title } = styles;
+            el.className = `${styles.primary} ${title}`;"
+                .to_string(),
+        );
+        let program = parse_file_as_program(
+            &fm,
+            Syntax::default(),
+            EsVersion::latest(),
+            None,
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        let mut content = Content::default();
+
+        content.add_program(&program);
+        content.add_css_modules(&result);
+
+        purge(&mut ss, &content, &Default::default());
+
+        let mut css_str = String::new();
+        {
+            let wr = BasicCssWriter::new(&mut css_str, None, BasicCssWriterConfig::default());
+            let mut gen = CodeGenerator::new(wr, CodegenConfig { minify: true });
+
+            gen.emit(&ss).unwrap();
+        }
+
+        Ok(css_str)
+    })
+    .unwrap();
+
+    // `primary` composes `button`
+    assert_eq!(
+        output,
+        ".button__hash{color:red}.primary__hash{color:blue}.title__hash{color:black}"
+    );
+}
@@ -0,0 +1,92 @@
+use std::{fs::read_to_string, path::PathBuf};
+
+use swc_css_ast::Stylesheet;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, Emit,
+};
+use swc_css_purge::{options::PurgeOptions, purge, Content};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{parse_file_as_program, EsSyntax, Syntax};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/**/input.css")]
+fn purge_fixtures(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.css");
+    let config = dir.join("config.json");
+    let options: PurgeOptions = if config.exists() {
+        let config = read_to_string(&config).expect("failed to read config.json");
+
+        serde_json::from_str(&config).expect("failed to parse config.json")
+    } else {
+        Default::default()
+    };
+
+    testing::run_test(false, |cm, handler| {
+        let mut content = Content::default();
+
+        let html = dir.join("content.html");
+
+        if html.exists() {
+            let fm = cm.load_file(&html).unwrap();
+            let mut errors = Vec::new();
+            let document =
+                swc_html_parser::parse_file_as_document(&fm, Default::default(), &mut errors)
+                    .unwrap();
+
+            content.add_html_document(&document);
+        }
+
+        let jsx = dir.join("content.jsx");
+
+        if jsx.exists() {
+            let fm = cm.load_file(&jsx).unwrap();
+            let program = parse_file_as_program(
+                &fm,
+                Syntax::Es(EsSyntax {
+                    jsx: true,
+                    ..Default::default()
+                }),
+                EsVersion::latest(),
+                None,
+                &mut Vec::new(),
+            )
+            .unwrap();
+
+            content.add_program(&program);
+        }
+
+        let fm = cm.load_file(&input).unwrap();
+        let mut errors = Vec::new();
+        let res: Result<Stylesheet, _> =
+            swc_css_parser::parse_file(&fm, None, Default::default(), &mut errors);
+
+        for err in errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        let mut ss = res.unwrap();
+
+        purge(&mut ss, &content, &options);
+
+        let mut css_str = String::new();
+        {
+            let wr = BasicCssWriter::new(&mut css_str, None, BasicCssWriterConfig::default());
+            let mut gen = CodeGenerator::new(wr, Default::default());
+
+            gen.emit(&ss).unwrap();
+        }
+
+        NormalizedOutput::from(css_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,19 @@
+import classNames from "classnames";
+
+export function Button({ primary, label }) {
+    return (
+        <button
+            className={classNames("btn", { "btn-primary": primary, disabled: !primary })}
+            htmlFor="x"
+        >
+            <Icon className={`icon icon-${label}`} />
+            {label}
+        </button>
+    );
+}
+
+export function List({ items }) {
+    return <ul className="list">{items.map((item) => <li key={item}>{item}</li>)}</ul>;
+}
+
+document.body.classList.toggle("dark-mode");
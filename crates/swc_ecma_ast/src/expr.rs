@@ -167,6 +167,21 @@ pub enum Expr {
 
     #[tag("Invalid")]
     Invalid(Invalid),
+
+    // New variants must be added after the existing ones, so serialized
+    // programs of older AST schema versions keep their variant indices.
+    // See `swc_plugin_runner::schema`.
+    /// Hack-style pipeline, e.g. `value |> f(%)`
+    ///
+    /// Added in AST schema version 2.
+    #[tag("PipelineExpression")]
+    Pipeline(PipelineExpr),
+
+    /// `%` in the body of a pipeline
+    ///
+    /// Added in AST schema version 2.
+    #[tag("TopicReference")]
+    TopicRef(TopicRef),
 }
 
 bridge_from!(Box<Expr>, Box<JSXElement>, JSXElement);
@@ -375,6 +390,8 @@ impl Expr {
             Expr::JSXFragment(e) => e.span = span,
             Expr::PrivateName(e) => e.span = span,
             Expr::OptChain(e) => e.span = span,
+            Expr::Pipeline(e) => e.span = span,
+            Expr::TopicRef(e) => e.span = span,
             Expr::Lit(e) => e.set_span(span),
         }
     }
@@ -422,6 +439,8 @@ impl Clone for Expr {
             TsInstantiation(e) => TsInstantiation(e.clone()),
             PrivateName(e) => PrivateName(e.clone()),
             OptChain(e) => OptChain(e.clone()),
+            Pipeline(e) => Pipeline(e.clone()),
+            TopicRef(e) => TopicRef(e.clone()),
             Invalid(e) => Invalid(e.clone()),
             TsSatisfies(e) => TsSatisfies(e.clone()),
         }
@@ -488,6 +507,8 @@ boxed_expr!(TsAsExpr);
 boxed_expr!(TsInstantiation);
 boxed_expr!(PrivateName);
 boxed_expr!(OptChainExpr);
+boxed_expr!(PipelineExpr);
+boxed_expr!(TopicRef);
 boxed_expr!(Invalid);
 
 #[ast_node("ThisExpression")]
@@ -1134,6 +1155,47 @@ pub struct AwaitExpr {
     pub arg: Box<Expr>,
 }
 
+/// Hack-style pipeline, e.g. `value |> f(%)`.
+///
+/// `head` is evaluated first, and its value is referenced by [TopicRef] in
+/// `body`.
+#[ast_node("PipelineExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct PipelineExpr {
+    pub span: Span,
+
+    pub head: Box<Expr>,
+
+    pub body: Box<Expr>,
+}
+
+impl Take for PipelineExpr {
+    fn dummy() -> Self {
+        PipelineExpr {
+            span: DUMMY_SP,
+            head: Take::dummy(),
+            body: Take::dummy(),
+        }
+    }
+}
+
+/// The topic reference (`%`) in the body of a [PipelineExpr].
+#[ast_node("TopicReference")]
+#[derive(Eq, Hash, Copy, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct TopicRef {
+    pub span: Span,
+}
+
+impl Take for TopicRef {
+    fn dummy() -> Self {
+        TopicRef { span: DUMMY_SP }
+    }
+}
+
 #[ast_node("TemplateLiteral")]
 #[derive(Eq, Hash, EqIgnoreSpan, Default)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -425,9 +425,10 @@ pub use self::{
         ArchivedFnExpr, ArchivedImport, ArchivedMemberExpr, ArchivedMemberProp,
         ArchivedMetaPropExpr, ArchivedMetaPropKind, ArchivedNewExpr, ArchivedObjectLit,
         ArchivedOptCall, ArchivedOptChainBase, ArchivedOptChainExpr, ArchivedParenExpr,
-        ArchivedPropOrSpread, ArchivedSeqExpr, ArchivedSpreadElement, ArchivedSuper,
-        ArchivedSuperProp, ArchivedSuperPropExpr, ArchivedTaggedTpl, ArchivedThisExpr, ArchivedTpl,
-        ArchivedTplElement, ArchivedUnaryExpr, ArchivedUpdateExpr, ArchivedYieldExpr,
+        ArchivedPipelineExpr, ArchivedPropOrSpread, ArchivedSeqExpr, ArchivedSpreadElement,
+        ArchivedSuper, ArchivedSuperProp, ArchivedSuperPropExpr, ArchivedTaggedTpl,
+        ArchivedThisExpr, ArchivedTopicRef, ArchivedTpl, ArchivedTplElement, ArchivedUnaryExpr,
+        ArchivedUpdateExpr, ArchivedYieldExpr,
     },
     function::{ArchivedFunction, ArchivedParam, ArchivedParamOrTsParamProp},
     ident::{ArchivedBindingIdent, ArchivedIdent, ArchivedIdentName, ArchivedPrivateName},
@@ -0,0 +1,727 @@
+//! Printing which preserves the original formatting of unchanged nodes.
+
+use rustc_hash::FxHashMap;
+use swc_common::{
+    comments::Comments, sync::Lrc, util::take::Take, BytePos, EqIgnoreSpan, SourceFile, SourceMap,
+    Span, Spanned, SyntaxContext, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+use crate::{
+    text_writer::{JsWriter, WriteJs},
+    Config, Emitter, Node,
+};
+
+/// Emits a modified program by copying the original text of unchanged nodes,
+/// so that a small change produces a small diff.
+///
+/// Nodes are reprinted only if they are modified or synthesized. Comments
+/// and blank lines between unchanged statements are preserved as-is.
+///
+/// Like [Emitter], this does not add parentheses, so the `fixer` pass should
+/// be applied to the modified program first. Source maps are not generated.
+pub struct FormatPreservingEmitter<'a> {
+    pub cfg: Config,
+    pub cm: Lrc<SourceMap>,
+    /// Used for reprinted nodes.
+    pub comments: Option<&'a dyn Comments>,
+}
+
+impl FormatPreservingEmitter<'_> {
+    /// `original` should be parsed from `fm` and should not be modified.
+    /// Clone it before applying transforms to get `program`.
+    pub fn emit_program(&self, fm: &SourceFile, original: &Program, program: &Program) -> String {
+        let p = Printer {
+            emitter: self,
+            fm,
+            indent_unit: detect_indent_unit(&fm.src),
+        };
+
+        let span = original.span();
+        if !p.is_valid(span) {
+            return p.emit(&Unit::Program(program.clone()), "");
+        }
+
+        let body = match (program.clone(), original.clone()) {
+            (Program::Module(n), Program::Module(o)) if n.shebang == o.shebang => p.print_list(
+                n.body
+                    .into_iter()
+                    .map(Box::new)
+                    .map(Unit::ModuleItem)
+                    .collect(),
+                o.body
+                    .into_iter()
+                    .map(Box::new)
+                    .map(Unit::ModuleItem)
+                    .collect(),
+                span,
+                span.lo,
+                "",
+            ),
+            (Program::Script(n), Program::Script(o)) if n.shebang == o.shebang => p.print_list(
+                n.body.into_iter().map(Box::new).map(Unit::Stmt).collect(),
+                o.body.into_iter().map(Box::new).map(Unit::Stmt).collect(),
+                span,
+                span.lo,
+                "",
+            ),
+            _ => return p.emit(&Unit::Program(program.clone()), ""),
+        };
+
+        format!(
+            "{}{}{}",
+            p.text(Span::new(fm.start_pos, span.lo)),
+            body,
+            p.text(Span::new(span.hi, fm.end_pos))
+        )
+    }
+}
+
+/// A node which can be copied or reprinted on its own.
+#[derive(Debug, Clone)]
+enum Unit {
+    ModuleItem(Box<ModuleItem>),
+    Stmt(Box<Stmt>),
+    Expr(Box<Expr>),
+    Block(Box<BlockStmt>),
+    ClassMember(Box<ClassMember>),
+    Program(Program),
+}
+
+impl Unit {
+    fn kind(&self) -> u8 {
+        match self {
+            Unit::ModuleItem(..) => 0,
+            Unit::Stmt(..) => 1,
+            Unit::Expr(..) => 2,
+            Unit::Block(..) => 3,
+            Unit::ClassMember(..) => 4,
+            Unit::Program(..) => 5,
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Unit::ModuleItem(n) => n.span(),
+            Unit::Stmt(n) => n.span(),
+            Unit::Expr(n) => n.span(),
+            Unit::Block(n) => n.span,
+            Unit::ClassMember(n) => n.span(),
+            Unit::Program(n) => n.span(),
+        }
+    }
+
+    fn visit_children_with<V: Visit>(&self, v: &mut V) {
+        match self {
+            Unit::ModuleItem(n) => n.visit_children_with(v),
+            Unit::Stmt(n) => n.visit_children_with(v),
+            Unit::Expr(n) => n.visit_children_with(v),
+            Unit::Block(n) => n.visit_children_with(v),
+            Unit::ClassMember(n) => n.visit_children_with(v),
+            Unit::Program(n) => n.visit_children_with(v),
+        }
+    }
+
+    fn visit_mut_children_with<V: VisitMut>(&mut self, v: &mut V) {
+        match self {
+            Unit::ModuleItem(n) => n.visit_mut_children_with(v),
+            Unit::Stmt(n) => n.visit_mut_children_with(v),
+            Unit::Expr(n) => n.visit_mut_children_with(v),
+            Unit::Block(n) => n.visit_mut_children_with(v),
+            Unit::ClassMember(n) => n.visit_mut_children_with(v),
+            Unit::Program(n) => n.visit_mut_children_with(v),
+        }
+    }
+
+    /// Syntax contexts are ignored because `program` is usually resolved
+    /// while `original` is not.
+    fn eq_ignore_span(&self, other: &Unit) -> bool {
+        SyntaxContext::within_ignored_ctxt(|| match (self, other) {
+            (Unit::ModuleItem(a), Unit::ModuleItem(b)) => a.eq_ignore_span(b),
+            (Unit::Stmt(a), Unit::Stmt(b)) => a.eq_ignore_span(b),
+            (Unit::Expr(a), Unit::Expr(b)) => a.eq_ignore_span(b),
+            (Unit::Block(a), Unit::Block(b)) => a.eq_ignore_span(b),
+            (Unit::ClassMember(a), Unit::ClassMember(b)) => a.eq_ignore_span(b),
+            (Unit::Program(a), Unit::Program(b)) => a.eq_ignore_span(b),
+            _ => false,
+        })
+    }
+}
+
+struct Printer<'a, 'b> {
+    emitter: &'a FormatPreservingEmitter<'b>,
+    fm: &'a SourceFile,
+    indent_unit: &'static str,
+}
+
+impl Printer<'_, '_> {
+    fn is_valid(&self, span: Span) -> bool {
+        !span.is_dummy()
+            && span.lo <= span.hi
+            && self.fm.start_pos <= span.lo
+            && span.hi <= self.fm.end_pos
+    }
+
+    fn text(&self, span: Span) -> &str {
+        let start = self.fm.start_pos.0;
+
+        &self.fm.src[(span.lo.0 - start) as usize..(span.hi.0 - start) as usize]
+    }
+
+    /// Returns the indentation of the line containing `pos`.
+    fn indent_of(&self, pos: BytePos) -> &str {
+        let line = self.text(Span::new(self.fm.start_pos, pos));
+        let line = &line[line.rfind('\n').map_or(0, |i| i + 1)..];
+
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+
+    fn is_line_start(&self, pos: BytePos) -> bool {
+        let before = self.text(Span::new(self.fm.start_pos, pos));
+
+        before.trim_end_matches([' ', '\t']).is_empty()
+            || before.trim_end_matches([' ', '\t']).ends_with('\n')
+    }
+
+    /// `n` is the modified node and `o` is the original node at the same
+    /// position.
+    fn print(&self, mut n: Unit, mut o: Unit) -> String {
+        let o_span = o.span();
+        if !self.is_valid(o_span) {
+            return self.fresh(n, None, "");
+        }
+
+        if n.eq_ignore_span(&o) {
+            return self.text(o_span).to_string();
+        }
+
+        match (n, o) {
+            (Unit::Block(n), Unit::Block(o))
+                if self.text(o_span).starts_with('{') && self.text(o_span).ends_with('}') =>
+            {
+                let inner_indent = match o.stmts.first() {
+                    Some(first) => self.indent_of(first.span().lo).to_string(),
+                    None => format!("{}{}", self.indent_of(o_span.lo), self.indent_unit),
+                };
+
+                return self.print_list(
+                    n.stmts.into_iter().map(Box::new).map(Unit::Stmt).collect(),
+                    o.stmts.into_iter().map(Box::new).map(Unit::Stmt).collect(),
+                    o_span,
+                    o_span.lo + BytePos(1),
+                    &inner_indent,
+                );
+            }
+            (n2, o2) => {
+                n = n2;
+                o = o2;
+            }
+        }
+
+        // Reuse the text of `o` if only its children are modified.
+        let n_units = stub_children(&mut n);
+        let o_units = stub_children(&mut o);
+
+        if n_units.len() == o_units.len()
+            && n_units
+                .iter()
+                .zip(&o_units)
+                .all(|(n, o)| n.kind() == o.kind())
+            && n.eq_ignore_span(&o)
+            && self.can_splice(o_span, &o_units)
+        {
+            let mut pairs = n_units.into_iter().zip(o_units).collect::<Vec<_>>();
+            pairs.sort_by_key(|(_, o)| o.span().lo);
+
+            let mut buf = String::new();
+            let mut pos = o_span.lo;
+            for (n, o) in pairs {
+                let span = o.span();
+                buf.push_str(self.text(span.with_lo(pos).with_hi(span.lo)));
+                buf.push_str(&self.print(n, o));
+                pos = span.hi;
+            }
+            buf.push_str(self.text(o_span.with_lo(pos)));
+
+            return buf;
+        }
+
+        unstub_children(&mut n, n_units);
+        unstub_children(&mut o, o_units);
+
+        let indent = self.indent_of(o_span.lo).to_string();
+        self.fresh(n, Some(o), &indent)
+    }
+
+    fn can_splice(&self, span: Span, units: &[Unit]) -> bool {
+        let mut spans = units.iter().map(Unit::span).collect::<Vec<_>>();
+        spans.sort_by_key(|span| span.lo);
+
+        let mut pos = span.lo;
+        for s in spans {
+            if !self.is_valid(s) || s.lo < pos || s.hi > span.hi {
+                return false;
+            }
+            pos = s.hi;
+        }
+
+        true
+    }
+
+    /// Prints a list of statements or module items.
+    ///
+    /// `span` is the span of the original list including braces, and `start`
+    /// is the position after the opening brace.
+    fn print_list(
+        &self,
+        n_items: Vec<Unit>,
+        o_items: Vec<Unit>,
+        span: Span,
+        start: BytePos,
+        indent: &str,
+    ) -> String {
+        let o_spans = o_items.iter().map(Unit::span).collect::<Vec<_>>();
+        if !self.can_splice(span.with_lo(start), &o_items) {
+            return self.fresh_list(n_items, span, start, indent);
+        }
+
+        // Pair modified items with original items. Items with the same span
+        // are paired first, and the remaining items are paired by position.
+        let by_span = o_spans
+            .iter()
+            .enumerate()
+            .map(|(i, span)| (*span, i))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut used = vec![false; o_items.len()];
+        let mut pairs = n_items
+            .iter()
+            .map(|n| {
+                let i = *by_span.get(&n.span())?;
+                if used[i] {
+                    return None;
+                }
+                used[i] = true;
+                Some(i)
+            })
+            .collect::<Vec<_>>();
+
+        let mut last = None;
+        for i in 0..pairs.len() {
+            if let Some(j) = pairs[i] {
+                last = Some(j);
+                continue;
+            }
+
+            let next = pairs[i..].iter().flatten().next().copied();
+            let candidate =
+                (last.map_or(0, |j| j + 1)..next.unwrap_or(o_items.len())).find(|&j| !used[j]);
+            if let Some(j) = candidate {
+                used[j] = true;
+                pairs[i] = Some(j);
+                last = Some(j);
+            }
+        }
+
+        // Text after an item on the same line, like a trailing comment, stays with the
+        // item.
+        let ends = o_spans
+            .iter()
+            .enumerate()
+            .map(|(j, s)| {
+                let limit = o_spans.get(j + 1).map_or(span.hi, |next| next.lo);
+                let rest = self.text(s.with_lo(s.hi).with_hi(limit));
+                rest.find('\n')
+                    .map_or(s.hi, |i| s.hi + BytePos(rest[..i].trim_end().len() as u32))
+            })
+            .collect::<Vec<_>>();
+
+        let mut o_items = o_items.into_iter().map(Some).collect::<Vec<_>>();
+        let mut buf = self.text(span.with_hi(start)).to_string();
+        let mut after_fresh = false;
+        let mut first = true;
+
+        for (n, pair) in n_items.into_iter().zip(pairs) {
+            match pair {
+                Some(j) => {
+                    let prev = if j == 0 { start } else { ends[j - 1] };
+                    let gap = self.text(o_spans[j].with_lo(prev).with_hi(o_spans[j].lo));
+                    if first && j != 0 {
+                        // Preceding items are removed, so the gap should not start with their
+                        // line breaks.
+                        let first_gap = self.text(o_spans[0].with_lo(start).with_hi(o_spans[0].lo));
+                        let gap = gap.trim_start();
+                        buf.push_str(&first_gap[..first_gap.len() - first_gap.trim_start().len()]);
+                        buf.push_str(gap);
+                    } else {
+                        if after_fresh && !gap.contains('\n') {
+                            buf.push('\n');
+                            buf.push_str(indent);
+                        }
+                        buf.push_str(gap);
+                    }
+                    buf.push_str(&self.print(n, o_items[j].take().unwrap()));
+                    buf.push_str(self.text(o_spans[j].with_lo(o_spans[j].hi).with_hi(ends[j])));
+                    after_fresh = false;
+                    first = false;
+                }
+                None => {
+                    if !(first && start == span.lo && self.is_line_start(start)) {
+                        buf.push('\n');
+                    }
+                    buf.push_str(indent);
+                    buf.push_str(&self.fresh(n, None, indent));
+                    after_fresh = true;
+                    first = false;
+                }
+            }
+        }
+
+        let tail = self.text(span.with_lo(ends.last().copied().unwrap_or(start)));
+        if after_fresh && !tail.is_empty() && !tail.contains('\n') {
+            buf.push('\n');
+            if tail.starts_with('}') {
+                buf.push_str(self.indent_of(span.lo));
+            }
+        }
+        buf.push_str(tail);
+
+        buf
+    }
+
+    fn fresh_list(&self, items: Vec<Unit>, span: Span, start: BytePos, indent: &str) -> String {
+        let mut buf = self.text(span.with_hi(start)).to_string();
+        for n in items {
+            if !buf.is_empty() {
+                buf.push('\n');
+            }
+            buf.push_str(indent);
+            buf.push_str(&self.fresh(n, None, indent));
+        }
+        if start != span.lo {
+            buf.push('\n');
+            buf.push_str(self.indent_of(span.lo));
+            buf.push_str(&self.text(span)[(span.hi - span.lo).0 as usize - 1..]);
+        }
+
+        buf
+    }
+
+    /// Reprints `n`, copying the original text of nodes from `o` if
+    /// possible.
+    fn fresh(&self, mut n: Unit, o: Option<Unit>, indent: &str) -> String {
+        let mut placer = Placer::default();
+        if let Some(o) = &o {
+            placer.pool.add(o.clone());
+            o.visit_children_with(&mut placer.pool);
+        }
+        n.visit_mut_children_with(&mut placer);
+
+        // Comments around the node are part of the copied text.
+        let positions = [Some(n.span()), o.as_ref().map(Unit::span)];
+        let mut taken = Vec::new();
+        if let Some(comments) = self.emitter.comments {
+            for span in positions.into_iter().flatten() {
+                taken.push((
+                    span,
+                    comments.take_leading(span.lo),
+                    comments.take_trailing(span.hi),
+                ));
+            }
+        }
+
+        let mut text = self.emit(&n, indent);
+
+        if let Some(comments) = self.emitter.comments {
+            for (span, leading, trailing) in taken {
+                if let Some(leading) = leading {
+                    comments.add_leading_comments(span.lo, leading);
+                }
+                if let Some(trailing) = trailing {
+                    comments.add_trailing_comments(span.hi, trailing);
+                }
+            }
+        }
+
+        for (id, (n, o)) in placer.found.into_iter().enumerate() {
+            let marker = marker(id);
+            let Some(start) = text.find(&marker) else {
+                continue;
+            };
+            let mut end = start + marker.len();
+
+            let start = match n {
+                Unit::Block(..) => {
+                    end = text[end..].find('}').map_or(end, |i| end + i + 1);
+                    text[..start].rfind('{').unwrap_or(start)
+                }
+                Unit::Stmt(..) | Unit::ModuleItem(..) => {
+                    if text[end..].starts_with(';') {
+                        end += 1;
+                    }
+                    start
+                }
+                _ => start,
+            };
+
+            let replacement = self.print(n, o);
+            text.replace_range(start..end, &replacement);
+        }
+
+        text
+    }
+
+    fn emit(&self, node: &Unit, indent: &str) -> String {
+        let levels = indent.len() / self.indent_unit.len();
+
+        let mut buf = Vec::new();
+        {
+            let mut wr = JsWriter::new(self.emitter.cm.clone(), "\n", &mut buf, None);
+            wr.set_indent_str(self.indent_unit);
+            for _ in 0..levels {
+                wr.increase_indent().unwrap();
+            }
+
+            let mut emitter = Emitter {
+                cfg: self.emitter.cfg,
+                cm: self.emitter.cm.clone(),
+                comments: self.emitter.comments,
+                wr,
+            };
+
+            match node {
+                Unit::ModuleItem(n) => n.emit_with(&mut emitter),
+                Unit::Stmt(n) => n.emit_with(&mut emitter),
+                Unit::Expr(n) => n.emit_with(&mut emitter),
+                Unit::Block(n) => n.emit_with(&mut emitter),
+                Unit::ClassMember(n) => n.emit_with(&mut emitter),
+                Unit::Program(n) => n.emit_with(&mut emitter),
+            }
+            .unwrap();
+        }
+
+        let text = String::from_utf8(buf).expect("codegen generated non-utf8 output");
+
+        text.trim_start_matches([' ', '\t']).trim_end().to_string()
+    }
+}
+
+/// Replaces children units with dummy nodes and returns them.
+fn stub_children(n: &mut Unit) -> Vec<Unit> {
+    let mut v = Stubber::default();
+    n.visit_mut_children_with(&mut v);
+    v.units
+}
+
+/// Reverts [stub_children].
+fn unstub_children(n: &mut Unit, units: Vec<Unit>) {
+    let mut v = Stubber {
+        units,
+        restore: true,
+    };
+    v.units.reverse();
+    n.visit_mut_children_with(&mut v);
+}
+
+#[derive(Default)]
+struct Stubber {
+    units: Vec<Unit>,
+    restore: bool,
+}
+
+macro_rules! stub {
+    ($name:ident, $T:ty, $variant:ident, $unwrap:expr) => {
+        fn $name(&mut self, n: &mut $T) {
+            if self.restore {
+                if let Some(Unit::$variant(unit)) = self.units.pop() {
+                    *n = $unwrap(unit);
+                }
+            } else {
+                self.units.push(Unit::$variant(Box::new(n.take())));
+            }
+        }
+    };
+}
+
+impl VisitMut for Stubber {
+    noop_visit_mut_type!();
+
+    stub!(visit_mut_module_item, ModuleItem, ModuleItem, |n: Box<
+        _,
+    >| *n);
+
+    stub!(visit_mut_stmt, Stmt, Stmt, |n: Box<_>| *n);
+
+    stub!(visit_mut_expr, Expr, Expr, |n: Box<_>| *n);
+
+    stub!(visit_mut_block_stmt, BlockStmt, Block, |n: Box<_>| *n);
+
+    fn visit_mut_class_member(&mut self, n: &mut ClassMember) {
+        if self.restore {
+            if let Some(Unit::ClassMember(unit)) = self.units.pop() {
+                *n = *unit;
+            }
+        } else {
+            let dummy = ClassMember::Empty(EmptyStmt { span: DUMMY_SP });
+            self.units
+                .push(Unit::ClassMember(Box::new(std::mem::replace(n, dummy))));
+        }
+    }
+}
+
+/// Original nodes which may be reused by reprinted nodes.
+#[derive(Default)]
+struct Pool {
+    units: FxHashMap<(u8, Span), Unit>,
+}
+
+impl Pool {
+    fn add(&mut self, unit: Unit) {
+        if !unit.span().is_dummy() {
+            self.units.insert((unit.kind(), unit.span()), unit);
+        }
+    }
+
+    fn take(&mut self, unit: &Unit) -> Option<Unit> {
+        self.units.remove(&(unit.kind(), unit.span()))
+    }
+}
+
+impl Visit for Pool {
+    noop_visit_type!();
+
+    fn visit_block_stmt(&mut self, n: &BlockStmt) {
+        self.add(Unit::Block(Box::new(n.clone())));
+        n.visit_children_with(self);
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        self.add(Unit::Expr(Box::new(n.clone())));
+        n.visit_children_with(self);
+    }
+
+    fn visit_module_item(&mut self, n: &ModuleItem) {
+        self.add(Unit::ModuleItem(Box::new(n.clone())));
+        n.visit_children_with(self);
+    }
+
+    fn visit_stmt(&mut self, n: &Stmt) {
+        self.add(Unit::Stmt(Box::new(n.clone())));
+        n.visit_children_with(self);
+    }
+}
+
+/// Replaces nodes found in the pool with markers, so their original text can
+/// be reused after printing.
+#[derive(Default)]
+struct Placer {
+    pool: Pool,
+    found: Vec<(Unit, Unit)>,
+}
+
+impl Placer {
+    fn place(&mut self, n: Unit) -> Result<Ident, Unit> {
+        match self.pool.take(&n) {
+            Some(o) => {
+                let span = n.span();
+                let id = self.found.len();
+                self.found.push((n, o));
+
+                Ok(Ident::new_no_ctxt(marker(id).into(), span))
+            }
+            None => Err(n),
+        }
+    }
+}
+
+fn marker(id: usize) -> String {
+    format!("__swc_preserved_{id}__")
+}
+
+fn marker_stmt(marker: Ident) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        span: marker.span,
+        expr: marker.into(),
+    })
+}
+
+impl VisitMut for Placer {
+    noop_visit_mut_type!();
+
+    fn visit_mut_block_stmt(&mut self, n: &mut BlockStmt) {
+        match self.place(Unit::Block(Box::new(n.take()))) {
+            Ok(marker) => {
+                *n = BlockStmt {
+                    span: marker.span,
+                    stmts: vec![marker_stmt(marker)],
+                    ..Default::default()
+                }
+            }
+            Err(Unit::Block(unit)) => {
+                *n = *unit;
+                n.visit_mut_children_with(self);
+            }
+            Err(..) => unreachable!(),
+        }
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        match self.place(Unit::Expr(Box::new(n.take()))) {
+            Ok(marker) => *n = marker.into(),
+            Err(Unit::Expr(unit)) => {
+                *n = *unit;
+                n.visit_mut_children_with(self);
+            }
+            Err(..) => unreachable!(),
+        }
+    }
+
+    fn visit_mut_module_item(&mut self, n: &mut ModuleItem) {
+        match self.place(Unit::ModuleItem(Box::new(n.take()))) {
+            Ok(marker) => *n = marker_stmt(marker).into(),
+            Err(Unit::ModuleItem(unit)) => {
+                *n = *unit;
+                n.visit_mut_children_with(self);
+            }
+            Err(..) => unreachable!(),
+        }
+    }
+
+    fn visit_mut_stmt(&mut self, n: &mut Stmt) {
+        match self.place(Unit::Stmt(Box::new(n.take()))) {
+            Ok(marker) => *n = marker_stmt(marker),
+            Err(Unit::Stmt(unit)) => {
+                *n = *unit;
+                n.visit_mut_children_with(self);
+            }
+            Err(..) => unreachable!(),
+        }
+    }
+}
+
+/// Returns the indentation string used by `src`, which is used for
+/// reprinted nodes.
+fn detect_indent_unit(src: &str) -> &'static str {
+    let mut tabs = 0;
+    let mut min_spaces = usize::MAX;
+
+    for line in src.lines() {
+        let trimmed = line.trim_start_matches(' ');
+        let spaces = line.len() - trimmed.len();
+
+        if line.starts_with('\t') {
+            tabs += 1;
+        } else if spaces > 0 && !trimmed.is_empty() && !trimmed.starts_with('*') {
+            min_spaces = min_spaces.min(spaces);
+        }
+    }
+
+    match min_spaces {
+        _ if tabs > 0 && min_spaces == usize::MAX => "\t",
+        1 | 2 => "  ",
+        _ => "    ",
+    }
+}
@@ -19,7 +19,7 @@ use swc_common::{
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::node_impl;
 
-pub use self::config::Config;
+pub use self::{config::Config, format_preserving::FormatPreservingEmitter};
 use self::{text_writer::WriteJs, util::StartsWithAlphaNum};
 use crate::util::EndsWithAlphaNum;
 
@@ -30,6 +30,7 @@ mod comments;
 mod config;
 mod decl;
 mod expr;
+mod format_preserving;
 mod jsx;
 mod lit;
 mod module_decls;
@@ -1573,6 +1574,8 @@ impl MacroNode for Expr {
             Expr::TsConstAssertion(n) => emit!(n),
             Expr::TsInstantiation(n) => emit!(n),
             Expr::OptChain(n) => emit!(n),
+            Expr::Pipeline(n) => emit!(n),
+            Expr::TopicRef(n) => emit!(n),
             Expr::Invalid(n) => emit!(n),
             Expr::TsSatisfies(n) => {
                 emit!(n)
@@ -1955,6 +1958,34 @@ impl MacroNode for CondExpr {
     }
 }
 
+#[node_impl]
+impl MacroNode for PipelineExpr {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        emit!(self.head);
+        formatting_space!(emitter);
+        operator!(emitter, "|>");
+        formatting_space!(emitter);
+        emit!(self.body);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for TopicRef {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        punct!(emitter, self.span, "%");
+
+        Ok(())
+    }
+}
+
 #[node_impl]
 impl MacroNode for FnExpr {
     fn emit(&mut self, emitter: &mut Macro) -> Result {
@@ -352,6 +352,35 @@ fn named_and_namespace_export_from_min() {
     );
 }
 
+#[test]
+fn pipeline_operator() {
+    test_from_to_custom_config(
+        "value |> double(%) |> % / 2;",
+        "value |> double(%) |> % / 2;",
+        Default::default(),
+        Syntax::Es(EsSyntax {
+            pipeline_operator: true,
+            ..EsSyntax::default()
+        }),
+    );
+}
+
+#[test]
+fn pipeline_operator_min() {
+    test_from_to_custom_config(
+        "value |> % % 2 |> (() => %)();",
+        "value|>%%2|>(()=>%)()",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::Es(EsSyntax {
+            pipeline_operator: true,
+            ..EsSyntax::default()
+        }),
+    );
+}
+
 #[test]
 fn issue_450() {
     test_from_to(
@@ -245,6 +245,9 @@ impl StartsWithAlphaNum for Expr {
 
             Expr::OptChain(e) => e.starts_with_alpha_num(),
 
+            Expr::Pipeline(PipelineExpr { ref head, .. }) => head.starts_with_alpha_num(),
+            Expr::TopicRef(..) => false,
+
             Expr::Invalid(..) => true,
         }
     }
@@ -0,0 +1,179 @@
+use swc_common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::FormatPreservingEmitter;
+use swc_ecma_parser::{parse_file_as_program, Syntax};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+fn run(src: &str, op: impl FnOnce(&mut Program)) -> String {
+    let cm: Lrc<SourceMap> = Default::default();
+    let comments = SingleThreadedComments::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+    let original = parse_file_as_program(
+        &fm,
+        Syntax::default(),
+        Default::default(),
+        Some(&comments),
+        &mut vec![],
+    )
+    .unwrap();
+
+    let mut program = original.clone();
+    op(&mut program);
+
+    FormatPreservingEmitter {
+        cfg: Default::default(),
+        cm: cm.clone(),
+        comments: Some(&comments),
+    }
+    .emit_program(&fm, &original, &program)
+}
+
+struct Rename(&'static str, &'static str);
+
+impl VisitMut for Rename {
+    fn visit_mut_ident(&mut self, n: &mut Ident) {
+        if &*n.sym == self.0 {
+            n.sym = self.1.into();
+        }
+    }
+}
+
+fn call(callee: &str, args: Vec<Expr>) -> Expr {
+    CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Expr(Ident::new_no_ctxt(callee.into(), DUMMY_SP).into()),
+        args: args.into_iter().map(|arg| arg.into()).collect(),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn stmt(expr: Expr) -> Stmt {
+    ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(expr),
+    }
+    .into()
+}
+
+fn body(program: &mut Program) -> &mut Vec<Stmt> {
+    match program {
+        Program::Script(s) => &mut s.body,
+        _ => unreachable!(),
+    }
+}
+
+const SRC: &str = "// header
+const  a = foo( 1,2 ) ;
+
+
+function   f ( x ) {
+    // inner
+    if (x)   return   bar ;  // trailing
+
+    return [ x,
+             x ];
+}
+";
+
+#[test]
+fn unchanged() {
+    assert_eq!(run(SRC, |_| {}), SRC);
+}
+
+#[test]
+fn rename() {
+    assert_eq!(
+        run(SRC, |p| p.visit_mut_with(&mut Rename("bar", "baz"))),
+        SRC.replace("bar", "baz")
+    );
+}
+
+#[test]
+fn replace_expr() {
+    let out = run(SRC, |p| {
+        p.visit_mut_with(&mut Rename("foo", "__unused"));
+        let Stmt::Decl(Decl::Var(var)) = &mut body(p)[0] else {
+            unreachable!()
+        };
+        let init = var.decls[0].init.take().unwrap();
+        var.decls[0].init = Some(Box::new(call("wrap", vec![*init])));
+    });
+
+    assert_eq!(out, SRC.replace("foo( 1,2 )", "wrap(__unused( 1,2 ))"));
+}
+
+#[test]
+fn insert_and_remove_stmts() {
+    let out = run(SRC, |p| {
+        let stmts = body(p);
+        stmts.remove(0);
+
+        let Stmt::Decl(Decl::Fn(f)) = &mut stmts[0] else {
+            unreachable!()
+        };
+        let stmts = &mut f.function.body.as_mut().unwrap().stmts;
+        stmts.insert(
+            1,
+            stmt(call(
+                "log",
+                vec![Ident::new_no_ctxt("x".into(), DUMMY_SP).into()],
+            )),
+        );
+    });
+
+    assert_eq!(
+        out,
+        "// header
+function   f ( x ) {
+    // inner
+    if (x)   return   bar ;  // trailing
+    log(x);
+
+    return [ x,
+             x ];
+}
+"
+    );
+}
+
+#[test]
+fn insert_into_empty_block() {
+    let out = run("if (a) {}\nb;\n", |p| {
+        let Stmt::If(s) = &mut body(p)[0] else {
+            unreachable!()
+        };
+        let Stmt::Block(block) = &mut *s.cons else {
+            unreachable!()
+        };
+        block.stmts.push(stmt(call("c", vec![])));
+    });
+
+    assert_eq!(out, "if (a) {\n    c();\n}\nb;\n");
+}
+
+#[test]
+fn reprint_keeps_children() {
+    // The function is reprinted because its name is changed, but its body is
+    // copied.
+    let out = run(SRC, |p| {
+        let Stmt::Decl(Decl::Fn(f)) = &mut body(p)[1] else {
+            unreachable!()
+        };
+        f.ident.sym = "g".into();
+    });
+
+    assert_eq!(out, SRC.replace("function   f ( x )", "function g(x)"));
+}
+
+#[test]
+fn comments_are_not_duplicated() {
+    let out = run("/* a */\nlet x = 1; // b\n", |p| {
+        let Stmt::Decl(Decl::Var(var)) = &mut body(p)[0] else {
+            unreachable!()
+        };
+        var.kind = VarDeclKind::Const;
+    });
+
+    assert_eq!(out, "/* a */\nconst x = 1; // b\n");
+}
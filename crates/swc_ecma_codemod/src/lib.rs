@@ -0,0 +1,359 @@
+//! Runtime pattern matching and rewriting of ecmascript code.
+//!
+//! This is a runtime counterpart of `swc_ecma_quote`, for codemods.
+//!
+//! ```
+//! use swc_common::{sync::Lrc, FileName, SourceMap};
+//! use swc_ecma_codemod::Codemod;
+//! use swc_ecma_parser::{parse_file_as_program, Syntax};
+//!
+//! let cm: Lrc<SourceMap> = Default::default();
+//! let fm = cm.new_source_file(
+//!     FileName::Anon.into(),
+//!     "if (map.hasOwnProperty(key)) {} // cached".into(),
+//! );
+//! let program =
+//!     parse_file_as_program(&fm, Default::default(), Default::default(), None, &mut vec![])
+//!         .unwrap();
+//!
+//! let codemod = Codemod::new(Syntax::default())
+//!     .expr("$obj.hasOwnProperty($key)", "Object.hasOwn($obj, $key)")
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     codemod.rewrite(&fm, &program).unwrap(),
+//!     "if (Object.hasOwn(map, key)) {} // cached"
+//! );
+//! ```
+//!
+//! See [Pattern] for the syntax of metavariables.
+
+use std::mem;
+
+use anyhow::{bail, Context, Error};
+use swc_common::{sync::Lrc, BytePos, FileName, SourceFile, SourceMap, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_expr, parse_file_as_script, PResult, Syntax};
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use self::template::{ExprCtx, Site};
+pub use self::{
+    pattern::{Capture, Captures, Pattern},
+    template::Template,
+};
+
+mod pattern;
+mod template;
+mod var;
+
+/// A pattern and its replacement.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pattern: Pattern,
+    template: Template,
+}
+
+impl Rule {
+    /// Fails if `template` uses a metavariable not bound by `pattern`, or if
+    /// only one of them is an expression.
+    pub fn new(pattern: Pattern, template: Template) -> Result<Self, Error> {
+        if pattern.is_expr() != template.is_expr() {
+            bail!("a pattern and its template should both be expressions or statements");
+        }
+
+        let vars = pattern.vars();
+        for name in template.vars() {
+            if name == "_" || !vars.contains(name) {
+                bail!(
+                    "`${}` is used in the template but not bound by the pattern",
+                    name
+                );
+            }
+        }
+
+        Ok(Rule { pattern, template })
+    }
+}
+
+/// A set of [Rule]s applied in a single pass.
+///
+/// If multiple rules match a node, the first one wins. Nodes inside matched
+/// code are not matched again.
+#[derive(Debug, Clone)]
+pub struct Codemod {
+    syntax: Syntax,
+    rules: Vec<Rule>,
+}
+
+/// Matched code.
+#[derive(Debug, Clone)]
+pub struct Match {
+    /// Index of the matched [Rule].
+    pub rule: usize,
+    pub span: Span,
+    pub captures: Captures,
+}
+
+impl Codemod {
+    /// `syntax` is used to parse patterns and templates.
+    pub fn new(syntax: Syntax) -> Self {
+        Codemod {
+            syntax,
+            rules: Default::default(),
+        }
+    }
+
+    /// Adds a rule rewriting expressions.
+    pub fn expr(self, pattern: &str, template: &str) -> Result<Self, Error> {
+        let rule = Rule::new(
+            Pattern::expr(pattern, self.syntax)?,
+            Template::expr(template, self.syntax)?,
+        )?;
+
+        Ok(self.rule(rule))
+    }
+
+    /// Adds a rule rewriting statements. `template` may contain zero or
+    /// multiple statements.
+    pub fn stmt(self, pattern: &str, template: &str) -> Result<Self, Error> {
+        let rule = Rule::new(
+            Pattern::stmt(pattern, self.syntax)?,
+            Template::stmts(template, self.syntax)?,
+        )?;
+
+        Ok(self.rule(rule))
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn find(&self, program: &Program) -> Vec<Match> {
+        self.find_with_site(program)
+            .into_iter()
+            .map(|(m, _)| m)
+            .collect()
+    }
+
+    /// Returns the source text of `fm` with all matches replaced.
+    ///
+    /// `program` should be parsed from `fm`. Code outside of matches,
+    /// including comments, is preserved as-is.
+    pub fn rewrite(&self, fm: &SourceFile, program: &Program) -> Result<String, Error> {
+        let src = &**fm.src;
+        let mut buf = String::with_capacity(src.len());
+        let mut pos = 0;
+
+        for (m, site) in self.find_with_site(program) {
+            let rule = &self.rules[m.rule];
+            let text = rule
+                .template
+                .render(&m.captures, fm, site)
+                .with_context(|| format!("failed to render the template of rule {}", m.rule))?;
+
+            snippet(fm, m.span).context("`program` should be parsed from `fm`")?;
+            buf.push_str(&src[pos..offset(fm, m.span.lo)]);
+            buf.push_str(&text);
+            pos = offset(fm, m.span.hi);
+        }
+        buf.push_str(&src[pos..]);
+
+        Ok(buf)
+    }
+
+    fn find_with_site(&self, program: &Program) -> Vec<(Match, Site)> {
+        let mut v = Finder {
+            rules: &self.rules,
+            ctx: ExprCtx::Any,
+            in_list: false,
+            found: Default::default(),
+        };
+        program.visit_with(&mut v);
+        v.found
+    }
+}
+
+struct Finder<'a> {
+    rules: &'a [Rule],
+    ctx: ExprCtx,
+    in_list: bool,
+    found: Vec<(Match, Site)>,
+}
+
+impl Finder<'_> {
+    fn add(&mut self, rule: usize, span: Span, captures: Captures, site: Site) {
+        self.found.push((
+            Match {
+                rule,
+                span,
+                captures,
+            },
+            site,
+        ));
+    }
+}
+
+impl Visit for Finder<'_> {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        n.left.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.right.visit_with(self);
+    }
+
+    fn visit_cond_expr(&mut self, n: &CondExpr) {
+        n.test.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.cons.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.alt.visit_with(self);
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        let ctx = mem::replace(&mut self.ctx, ExprCtx::Operand);
+
+        for (i, rule) in self.rules.iter().enumerate() {
+            if let Some(captures) = rule.pattern.match_expr(n) {
+                let site = Site {
+                    ctx,
+                    in_list: false,
+                };
+                self.add(i, n.span(), captures, site);
+                return;
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_expr_or_spread(&mut self, n: &ExprOrSpread) {
+        self.ctx = ExprCtx::Assign;
+        n.expr.visit_with(self);
+    }
+
+    fn visit_expr_stmt(&mut self, n: &ExprStmt) {
+        self.ctx = ExprCtx::Stmt;
+        n.expr.visit_with(self);
+    }
+
+    fn visit_key_value_prop(&mut self, n: &KeyValueProp) {
+        n.key.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.value.visit_with(self);
+    }
+
+    fn visit_module_items(&mut self, n: &[ModuleItem]) {
+        for item in n {
+            self.in_list = true;
+            item.visit_with(self);
+        }
+    }
+
+    fn visit_paren_expr(&mut self, n: &ParenExpr) {
+        self.ctx = ExprCtx::Any;
+        n.expr.visit_with(self);
+    }
+
+    fn visit_return_stmt(&mut self, n: &ReturnStmt) {
+        self.ctx = ExprCtx::Any;
+        n.arg.visit_with(self);
+    }
+
+    fn visit_seq_expr(&mut self, n: &SeqExpr) {
+        for e in &n.exprs {
+            self.ctx = ExprCtx::Assign;
+            e.visit_with(self);
+        }
+    }
+
+    fn visit_stmt(&mut self, n: &Stmt) {
+        let in_list = mem::replace(&mut self.in_list, false);
+
+        for (i, rule) in self.rules.iter().enumerate() {
+            if let Some(captures) = rule.pattern.match_stmt(n) {
+                let site = Site {
+                    ctx: ExprCtx::Stmt,
+                    in_list,
+                };
+                self.add(i, n.span(), captures, site);
+                return;
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_stmts(&mut self, n: &[Stmt]) {
+        for stmt in n {
+            self.in_list = true;
+            stmt.visit_with(self);
+        }
+    }
+
+    fn visit_throw_stmt(&mut self, n: &ThrowStmt) {
+        self.ctx = ExprCtx::Any;
+        n.arg.visit_with(self);
+    }
+
+    fn visit_tpl(&mut self, n: &Tpl) {
+        for e in &n.exprs {
+            self.ctx = ExprCtx::Any;
+            e.visit_with(self);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+        n.name.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.init.visit_with(self);
+    }
+}
+
+fn parse<T>(
+    src: &str,
+    op: impl FnOnce(&SourceFile, &mut Vec<swc_ecma_parser::error::Error>) -> PResult<T>,
+) -> Result<(Lrc<SourceFile>, T), Error> {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(Lrc::new(FileName::Anon), src.to_string());
+
+    let mut errors = Vec::new();
+    let res = op(&fm, &mut errors);
+
+    match (res, errors.into_iter().next()) {
+        (Ok(node), None) => Ok((fm, node)),
+        (Ok(..), Some(err)) | (Err(err), _) => {
+            bail!("failed to parse `{}`: {}", src, err.kind().msg())
+        }
+    }
+}
+
+pub(crate) fn parse_expr(src: &str, syntax: Syntax) -> Result<(Lrc<SourceFile>, Box<Expr>), Error> {
+    parse(src, |fm, errors| {
+        parse_file_as_expr(fm, syntax, EsVersion::latest(), None, errors)
+    })
+}
+
+pub(crate) fn parse_stmts(
+    src: &str,
+    syntax: Syntax,
+) -> Result<(Lrc<SourceFile>, Vec<Stmt>), Error> {
+    parse(src, |fm, errors| {
+        parse_file_as_script(fm, syntax, EsVersion::latest(), None, errors)
+    })
+    .map(|(fm, script)| (fm, script.body))
+}
+
+pub(crate) fn offset(fm: &SourceFile, pos: BytePos) -> usize {
+    (pos.0 - fm.start_pos.0) as usize
+}
+
+/// Returns the source text of `span`, which should be in `fm`.
+pub(crate) fn snippet(fm: &SourceFile, span: Span) -> Result<&str, Error> {
+    if span.lo < fm.start_pos || span.hi > fm.end_pos || span.lo > span.hi {
+        bail!("{:?} is not in `{}`", span, fm.name);
+    }
+
+    Ok(&fm.src[offset(fm, span.lo)..offset(fm, span.hi)])
+}
@@ -0,0 +1,555 @@
+use anyhow::{bail, Error};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_common::{EqIgnoreSpan, Span, Spanned, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_parser::Syntax;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    parse_expr, parse_stmts,
+    var::{
+        many_of_arg, many_of_elem, many_of_expr, many_of_prop, many_of_stmt, metavar, one_of_expr,
+        Var,
+    },
+};
+
+/// A pattern parsed at runtime, which can be matched against AST nodes.
+///
+/// # Metavariables
+///
+///  - `$name` matches any expression. It also matches any identifier if used as
+///    a property name or a binding, and any statement if used as an expression
+///    statement (`$name;`).
+///  - `$$$name` matches zero or more arguments, array elements, sequence
+///    expressions, object properties or statements.
+///  - `$_` and `$$$_` match without binding anything.
+///
+/// If a metavariable is used more than once, all occurrences should match
+/// the same code.
+///
+/// Metavariables are supported in expressions and common statements. Other
+/// nodes, like functions and classes, are compared as-is.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    kind: PatternKind,
+}
+
+#[derive(Debug, Clone)]
+enum PatternKind {
+    Expr(Box<Expr>),
+    Stmt(Box<Stmt>),
+}
+
+impl Pattern {
+    /// Parses a pattern matching expressions, like `$obj.hasOwnProperty($key)`.
+    pub fn expr(src: &str, syntax: Syntax) -> Result<Self, Error> {
+        let (_, expr) = parse_expr(src, syntax)?;
+
+        Ok(Pattern {
+            kind: PatternKind::Expr(expr),
+        })
+    }
+
+    /// Parses a pattern matching statements, like `if ($cond) { $$$body }`.
+    pub fn stmt(src: &str, syntax: Syntax) -> Result<Self, Error> {
+        let (_, mut stmts) = parse_stmts(src, syntax)?;
+        if stmts.len() != 1 {
+            bail!(
+                "a statement pattern should contain exactly one statement, but `{}` contains {}",
+                src,
+                stmts.len()
+            );
+        }
+
+        Ok(Pattern {
+            kind: PatternKind::Stmt(Box::new(stmts.remove(0))),
+        })
+    }
+
+    pub fn is_expr(&self) -> bool {
+        matches!(self.kind, PatternKind::Expr(..))
+    }
+
+    /// Returns [None] if `target` does not match or if this is a statement
+    /// pattern.
+    pub fn match_expr(&self, target: &Expr) -> Option<Captures> {
+        match &self.kind {
+            PatternKind::Expr(pat) => Matcher::run(|m| m.expr(pat, target)),
+            PatternKind::Stmt(..) => None,
+        }
+    }
+
+    /// Returns [None] if `target` does not match or if this is an expression
+    /// pattern.
+    pub fn match_stmt(&self, target: &Stmt) -> Option<Captures> {
+        match &self.kind {
+            PatternKind::Stmt(pat) => Matcher::run(|m| m.stmt(pat, target)),
+            PatternKind::Expr(..) => None,
+        }
+    }
+
+    /// Names of metavariables bound by this pattern.
+    pub(crate) fn vars(&self) -> FxHashSet<Atom> {
+        let mut v = VarCollector::default();
+        match &self.kind {
+            PatternKind::Expr(e) => e.visit_with(&mut v),
+            PatternKind::Stmt(s) => s.visit_with(&mut v),
+        }
+        v.vars
+    }
+}
+
+/// Nodes bound to metavariables.
+#[derive(Debug, Default, Clone)]
+pub struct Captures {
+    vars: FxHashMap<Atom, Capture>,
+}
+
+impl Captures {
+    /// `name` should not contain `$`.
+    pub fn get(&self, name: &str) -> Option<&Capture> {
+        self.vars.get(&Atom::from(name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Atom, &Capture)> {
+        self.vars.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Capture {
+    Expr(Box<Expr>),
+    Ident(Ident),
+    Stmt(Box<Stmt>),
+    /// Arguments, array elements or expressions of a sequence expression.
+    ///
+    /// Holes in arrays are not included.
+    Exprs(Vec<ExprOrSpread>),
+    Props(Vec<PropOrSpread>),
+    Stmts(Vec<Stmt>),
+}
+
+impl Capture {
+    /// Returns [None] for an empty list.
+    pub fn span(&self) -> Option<Span> {
+        fn list<T: Spanned>(nodes: &[T]) -> Option<Span> {
+            let first = nodes.first()?.span();
+            let last = nodes.last()?.span();
+
+            Some(first.with_hi(last.hi))
+        }
+
+        match self {
+            Capture::Expr(e) => Some(e.span()),
+            Capture::Ident(i) => Some(i.span),
+            Capture::Stmt(s) => Some(s.span()),
+            Capture::Exprs(nodes) => list(nodes),
+            Capture::Props(nodes) => list(nodes),
+            Capture::Stmts(nodes) => list(nodes),
+        }
+    }
+}
+
+impl EqIgnoreSpan for Capture {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Capture::Expr(a), Capture::Expr(b)) => a.eq_ignore_span(b),
+            (Capture::Ident(a), Capture::Ident(b)) => a.sym == b.sym,
+            (Capture::Ident(a), Capture::Expr(b)) | (Capture::Expr(b), Capture::Ident(a)) => {
+                matches!(&**b, Expr::Ident(b) if a.sym == b.sym)
+            }
+            (Capture::Stmt(a), Capture::Stmt(b)) => a.eq_ignore_span(b),
+            (Capture::Exprs(a), Capture::Exprs(b)) => a.eq_ignore_span(b),
+            (Capture::Props(a), Capture::Props(b)) => a.eq_ignore_span(b),
+            (Capture::Stmts(a), Capture::Stmts(b)) => a.eq_ignore_span(b),
+            _ => false,
+        }
+    }
+}
+
+/// Describes how a list which may contain `$$$name` is matched.
+struct ListOps<T> {
+    many: fn(&T) -> Option<&str>,
+    elem: fn(&mut Matcher, &T, &T) -> bool,
+    capture: fn(&[T]) -> Capture,
+}
+
+const ARGS: ListOps<ExprOrSpread> = ListOps {
+    many: many_of_arg,
+    elem: Matcher::arg,
+    capture: |nodes| Capture::Exprs(nodes.to_vec()),
+};
+
+const ELEMS: ListOps<Option<ExprOrSpread>> = ListOps {
+    many: many_of_elem,
+    elem: |m, p, t| match (p, t) {
+        (Some(p), Some(t)) => m.arg(p, t),
+        (None, None) => true,
+        _ => false,
+    },
+    capture: |nodes| Capture::Exprs(nodes.iter().flatten().cloned().collect()),
+};
+
+const EXPRS: ListOps<Box<Expr>> = ListOps {
+    many: |n| many_of_expr(n),
+    elem: |m, p, t| m.expr(p, t),
+    capture: |nodes| {
+        Capture::Exprs(
+            nodes
+                .iter()
+                .map(|expr| ExprOrSpread {
+                    spread: None,
+                    expr: expr.clone(),
+                })
+                .collect(),
+        )
+    },
+};
+
+const PROPS: ListOps<PropOrSpread> = ListOps {
+    many: many_of_prop,
+    elem: Matcher::prop_or_spread,
+    capture: |nodes| Capture::Props(nodes.to_vec()),
+};
+
+const STMTS: ListOps<Stmt> = ListOps {
+    many: many_of_stmt,
+    elem: Matcher::stmt,
+    capture: |nodes| Capture::Stmts(nodes.to_vec()),
+};
+
+#[derive(Default)]
+struct Matcher {
+    captures: Captures,
+}
+
+impl Matcher {
+    fn run(op: impl FnOnce(&mut Matcher) -> bool) -> Option<Captures> {
+        let mut m = Matcher::default();
+
+        // Patterns are parsed without `resolver`, so we can't compare syntax
+        // contexts.
+        let matched = SyntaxContext::within_ignored_ctxt(|| op(&mut m));
+
+        matched.then_some(m.captures)
+    }
+
+    fn bind(&mut self, name: &str, capture: Capture) -> bool {
+        if name == "_" {
+            return true;
+        }
+
+        match self.captures.vars.get(&Atom::from(name)) {
+            Some(prev) => prev.eq_ignore_span(&capture),
+            None => {
+                self.captures.vars.insert(name.into(), capture);
+                true
+            }
+        }
+    }
+
+    fn bind_ident(&mut self, pat: &Atom, target: Ident) -> bool {
+        match metavar(pat) {
+            Some(Var::One(name)) => self.bind(name, Capture::Ident(target)),
+            _ => *pat == target.sym,
+        }
+    }
+
+    fn list<T>(&mut self, pats: &[T], targets: &[T], ops: &ListOps<T>) -> bool {
+        let Some((pat, pats)) = pats.split_first() else {
+            return targets.is_empty();
+        };
+
+        if let Some(name) = (ops.many)(pat) {
+            for len in 0..=targets.len() {
+                let snapshot = self.captures.clone();
+
+                if self.bind(name, (ops.capture)(&targets[..len]))
+                    && self.list(pats, &targets[len..], ops)
+                {
+                    return true;
+                }
+
+                self.captures = snapshot;
+            }
+
+            return false;
+        }
+
+        match targets.split_first() {
+            Some((target, targets)) => {
+                (ops.elem)(self, pat, target) && self.list(pats, targets, ops)
+            }
+            None => false,
+        }
+    }
+
+    fn zip<T>(&mut self, pats: &[T], targets: &[T], elem: fn(&mut Self, &T, &T) -> bool) -> bool {
+        pats.len() == targets.len()
+            && pats
+                .iter()
+                .zip(targets)
+                .all(|(pat, target)| elem(self, pat, target))
+    }
+
+    fn opt<T>(
+        &mut self,
+        pat: Option<&T>,
+        target: Option<&T>,
+        elem: fn(&mut Self, &T, &T) -> bool,
+    ) -> bool {
+        match (pat, target) {
+            (Some(pat), Some(target)) => elem(self, pat, target),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn expr(&mut self, pat: &Expr, target: &Expr) -> bool {
+        if let Some(name) = one_of_expr(pat) {
+            return self.bind(name, Capture::Expr(Box::new(target.clone())));
+        }
+
+        match (strip_parens(pat), strip_parens(target)) {
+            (Expr::Ident(p), Expr::Ident(t)) => p.sym == t.sym,
+            (Expr::Member(p), Expr::Member(t)) => self.member(p, t),
+            (Expr::Call(p), Expr::Call(t)) => self.call(p, t),
+            (Expr::New(p), Expr::New(t)) => {
+                self.expr(&p.callee, &t.callee)
+                    && self.list(
+                        p.args.as_deref().unwrap_or_default(),
+                        t.args.as_deref().unwrap_or_default(),
+                        &ARGS,
+                    )
+            }
+            (Expr::Bin(p), Expr::Bin(t)) => {
+                p.op == t.op && self.expr(&p.left, &t.left) && self.expr(&p.right, &t.right)
+            }
+            (Expr::Unary(p), Expr::Unary(t)) => p.op == t.op && self.expr(&p.arg, &t.arg),
+            (Expr::Update(p), Expr::Update(t)) => {
+                p.op == t.op && p.prefix == t.prefix && self.expr(&p.arg, &t.arg)
+            }
+            (Expr::Assign(p), Expr::Assign(t)) => {
+                p.op == t.op
+                    && self.assign_target(&p.left, &t.left)
+                    && self.expr(&p.right, &t.right)
+            }
+            (Expr::Cond(p), Expr::Cond(t)) => {
+                self.expr(&p.test, &t.test)
+                    && self.expr(&p.cons, &t.cons)
+                    && self.expr(&p.alt, &t.alt)
+            }
+            (Expr::Seq(p), Expr::Seq(t)) => self.list(&p.exprs, &t.exprs, &EXPRS),
+            (Expr::Array(p), Expr::Array(t)) => self.list(&p.elems, &t.elems, &ELEMS),
+            (Expr::Object(p), Expr::Object(t)) => self.list(&p.props, &t.props, &PROPS),
+            (Expr::Await(p), Expr::Await(t)) => self.expr(&p.arg, &t.arg),
+            (Expr::Yield(p), Expr::Yield(t)) => {
+                p.delegate == t.delegate && self.opt(p.arg.as_deref(), t.arg.as_deref(), Self::expr)
+            }
+            (Expr::Tpl(p), Expr::Tpl(t)) => self.tpl(p, t),
+            (Expr::TaggedTpl(p), Expr::TaggedTpl(t)) => {
+                self.expr(&p.tag, &t.tag) && self.tpl(&p.tpl, &t.tpl)
+            }
+            (Expr::OptChain(p), Expr::OptChain(t)) => {
+                p.optional == t.optional
+                    && match (&*p.base, &*t.base) {
+                        (OptChainBase::Member(p), OptChainBase::Member(t)) => self.member(p, t),
+                        (OptChainBase::Call(p), OptChainBase::Call(t)) => {
+                            self.expr(&p.callee, &t.callee) && self.list(&p.args, &t.args, &ARGS)
+                        }
+                        _ => false,
+                    }
+            }
+            (Expr::Arrow(p), Expr::Arrow(t)) => {
+                p.is_async == t.is_async
+                    && p.is_generator == t.is_generator
+                    && self.zip(&p.params, &t.params, Self::pat)
+                    && match (&*p.body, &*t.body) {
+                        (BlockStmtOrExpr::BlockStmt(p), BlockStmtOrExpr::BlockStmt(t)) => {
+                            self.list(&p.stmts, &t.stmts, &STMTS)
+                        }
+                        (BlockStmtOrExpr::Expr(p), BlockStmtOrExpr::Expr(t)) => self.expr(p, t),
+                        _ => false,
+                    }
+            }
+            (pat, target) => pat.eq_ignore_span(target),
+        }
+    }
+
+    fn arg(&mut self, pat: &ExprOrSpread, target: &ExprOrSpread) -> bool {
+        pat.spread.is_some() == target.spread.is_some() && self.expr(&pat.expr, &target.expr)
+    }
+
+    fn member(&mut self, pat: &MemberExpr, target: &MemberExpr) -> bool {
+        self.expr(&pat.obj, &target.obj)
+            && match (&pat.prop, &target.prop) {
+                (MemberProp::Ident(p), MemberProp::Ident(t)) => {
+                    self.bind_ident(&p.sym, Ident::new_no_ctxt(t.sym.clone(), t.span))
+                }
+                (MemberProp::Computed(p), MemberProp::Computed(t)) => self.expr(&p.expr, &t.expr),
+                (p, t) => p.eq_ignore_span(t),
+            }
+    }
+
+    fn call(&mut self, pat: &CallExpr, target: &CallExpr) -> bool {
+        (match (&pat.callee, &target.callee) {
+            (Callee::Expr(p), Callee::Expr(t)) => self.expr(p, t),
+            (p, t) => p.eq_ignore_span(t),
+        }) && self.list(&pat.args, &target.args, &ARGS)
+    }
+
+    fn tpl(&mut self, pat: &Tpl, target: &Tpl) -> bool {
+        pat.quasis.eq_ignore_span(&target.quasis)
+            && self.zip(&pat.exprs, &target.exprs, |m, p, t| m.expr(p, t))
+    }
+
+    fn assign_target(&mut self, pat: &AssignTarget, target: &AssignTarget) -> bool {
+        match (pat, target) {
+            (AssignTarget::Simple(SimpleAssignTarget::Ident(p)), AssignTarget::Simple(t)) => {
+                match metavar(&p.sym) {
+                    Some(Var::One(name)) => self.bind(name, Capture::Expr(t.clone().into())),
+                    _ => matches!(t, SimpleAssignTarget::Ident(t) if p.sym == t.sym),
+                }
+            }
+            (
+                AssignTarget::Simple(SimpleAssignTarget::Member(p)),
+                AssignTarget::Simple(SimpleAssignTarget::Member(t)),
+            ) => self.member(p, t),
+            (p, t) => p.eq_ignore_span(t),
+        }
+    }
+
+    fn prop_or_spread(&mut self, pat: &PropOrSpread, target: &PropOrSpread) -> bool {
+        match (pat, target) {
+            (PropOrSpread::Spread(p), PropOrSpread::Spread(t)) => self.expr(&p.expr, &t.expr),
+            (PropOrSpread::Prop(p), PropOrSpread::Prop(t)) => match (&**p, &**t) {
+                (Prop::Shorthand(p), Prop::Shorthand(t)) => self.bind_ident(&p.sym, t.clone()),
+                (Prop::KeyValue(p), Prop::KeyValue(t)) => {
+                    self.prop_name(&p.key, &t.key) && self.expr(&p.value, &t.value)
+                }
+                (p, t) => p.eq_ignore_span(t),
+            },
+            _ => false,
+        }
+    }
+
+    fn prop_name(&mut self, pat: &PropName, target: &PropName) -> bool {
+        match (pat, target) {
+            (PropName::Ident(p), PropName::Ident(t)) => {
+                self.bind_ident(&p.sym, Ident::new_no_ctxt(t.sym.clone(), t.span))
+            }
+            (PropName::Computed(p), PropName::Computed(t)) => self.expr(&p.expr, &t.expr),
+            (p, t) => p.eq_ignore_span(t),
+        }
+    }
+
+    fn pat(&mut self, pat: &Pat, target: &Pat) -> bool {
+        match (pat, target) {
+            (Pat::Ident(p), Pat::Ident(t)) => self.bind_ident(&p.sym, t.id.clone()),
+            (Pat::Assign(p), Pat::Assign(t)) => {
+                self.pat(&p.left, &t.left) && self.expr(&p.right, &t.right)
+            }
+            (p, t) => p.eq_ignore_span(t),
+        }
+    }
+
+    fn stmt(&mut self, pat: &Stmt, target: &Stmt) -> bool {
+        if let Stmt::Expr(ExprStmt { expr, .. }) = pat {
+            if let Some(name) = one_of_expr(expr) {
+                return self.bind(name, Capture::Stmt(Box::new(target.clone())));
+            }
+        }
+
+        match (pat, target) {
+            (Stmt::Expr(p), Stmt::Expr(t)) => self.expr(&p.expr, &t.expr),
+            (Stmt::Block(p), Stmt::Block(t)) => self.list(&p.stmts, &t.stmts, &STMTS),
+            (Stmt::Return(p), Stmt::Return(t)) => {
+                self.opt(p.arg.as_deref(), t.arg.as_deref(), Self::expr)
+            }
+            (Stmt::Throw(p), Stmt::Throw(t)) => self.expr(&p.arg, &t.arg),
+            (Stmt::If(p), Stmt::If(t)) => {
+                self.expr(&p.test, &t.test)
+                    && self.stmt(&p.cons, &t.cons)
+                    && self.opt(p.alt.as_deref(), t.alt.as_deref(), Self::stmt)
+            }
+            (Stmt::While(p), Stmt::While(t)) => {
+                self.expr(&p.test, &t.test) && self.stmt(&p.body, &t.body)
+            }
+            (Stmt::DoWhile(p), Stmt::DoWhile(t)) => {
+                self.stmt(&p.body, &t.body) && self.expr(&p.test, &t.test)
+            }
+            (Stmt::ForOf(p), Stmt::ForOf(t)) => {
+                p.is_await == t.is_await
+                    && self.for_head(&p.left, &t.left)
+                    && self.expr(&p.right, &t.right)
+                    && self.stmt(&p.body, &t.body)
+            }
+            (Stmt::ForIn(p), Stmt::ForIn(t)) => {
+                self.for_head(&p.left, &t.left)
+                    && self.expr(&p.right, &t.right)
+                    && self.stmt(&p.body, &t.body)
+            }
+            (Stmt::Try(p), Stmt::Try(t)) => {
+                self.list(&p.block.stmts, &t.block.stmts, &STMTS)
+                    && self.opt(p.handler.as_ref(), t.handler.as_ref(), |m, p, t| {
+                        m.opt(p.param.as_ref(), t.param.as_ref(), Self::pat)
+                            && m.list(&p.body.stmts, &t.body.stmts, &STMTS)
+                    })
+                    && self.opt(p.finalizer.as_ref(), t.finalizer.as_ref(), |m, p, t| {
+                        m.list(&p.stmts, &t.stmts, &STMTS)
+                    })
+            }
+            (Stmt::Decl(Decl::Var(p)), Stmt::Decl(Decl::Var(t))) => self.var_decl(p, t),
+            (p, t) => p.eq_ignore_span(t),
+        }
+    }
+
+    fn for_head(&mut self, pat: &ForHead, target: &ForHead) -> bool {
+        match (pat, target) {
+            (ForHead::VarDecl(p), ForHead::VarDecl(t)) => self.var_decl(p, t),
+            (ForHead::Pat(p), ForHead::Pat(t)) => self.pat(p, t),
+            (p, t) => p.eq_ignore_span(t),
+        }
+    }
+
+    fn var_decl(&mut self, pat: &VarDecl, target: &VarDecl) -> bool {
+        pat.kind == target.kind
+            && pat.declare == target.declare
+            && self.zip(&pat.decls, &target.decls, |m, p, t| {
+                m.pat(&p.name, &t.name) && m.opt(p.init.as_deref(), t.init.as_deref(), Self::expr)
+            })
+    }
+}
+
+fn strip_parens(mut e: &Expr) -> &Expr {
+    while let Expr::Paren(p) = e {
+        e = &p.expr;
+    }
+    e
+}
+
+#[derive(Default)]
+struct VarCollector {
+    vars: FxHashSet<Atom>,
+}
+
+impl VarCollector {
+    fn add(&mut self, sym: &str) {
+        if let Some(Var::One(name) | Var::Many(name)) = metavar(sym) {
+            self.vars.insert(name.into());
+        }
+    }
+}
+
+impl Visit for VarCollector {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, n: &Ident) {
+        self.add(&n.sym);
+    }
+
+    fn visit_ident_name(&mut self, n: &IdentName) {
+        self.add(&n.sym);
+    }
+}
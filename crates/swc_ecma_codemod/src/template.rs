@@ -0,0 +1,409 @@
+use std::mem;
+
+use anyhow::{bail, Context, Error};
+use swc_atoms::Atom;
+use swc_common::{sync::Lrc, SourceFile, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_parser::Syntax;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    offset, parse_expr, parse_stmts,
+    pattern::{Capture, Captures},
+    snippet,
+    var::{many_of_arg, many_of_elem, many_of_expr, many_of_prop, many_of_stmt, metavar, Var},
+};
+
+/// A replacement for matched code, like `Object.hasOwn($obj, $key)`.
+///
+/// Rendering is done on source text, so the template and the captured nodes
+/// keep their original formatting and comments. Parentheses are added if a
+/// captured expression would be parsed differently otherwise.
+#[derive(Debug, Clone)]
+pub struct Template {
+    fm: Lrc<SourceFile>,
+    root: Root,
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone)]
+enum Root {
+    Expr(Box<Expr>),
+    Stmts(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Slot {
+    name: Atom,
+    /// Span of the metavariable.
+    span: Span,
+    kind: SlotKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SlotKind {
+    Expr(ExprCtx),
+    /// A property name or a binding.
+    Ident,
+    /// `$name;`, which is replaced as a whole if a statement is captured.
+    Stmt {
+        stmt: Span,
+    },
+    /// `$$$name`. `empty` is removed if the captured list is empty, so we
+    /// don't leave a stray comma.
+    Many {
+        empty: Span,
+    },
+}
+
+/// The kind of expressions allowed at a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExprCtx {
+    /// An operand of an operator, an object of a member expression, etc.
+    Operand,
+    /// An argument, an initializer, etc.
+    Assign,
+    /// The expression of an expression statement.
+    Stmt,
+    /// Anywhere a sequence expression is allowed.
+    Any,
+}
+
+impl ExprCtx {
+    pub(crate) fn needs_parens(self, e: &Expr) -> bool {
+        match self {
+            ExprCtx::Operand => !matches!(
+                e,
+                Expr::This(..)
+                    | Expr::Ident(..)
+                    | Expr::Lit(..)
+                    | Expr::Array(..)
+                    | Expr::Object(..)
+                    | Expr::Member(..)
+                    | Expr::SuperProp(..)
+                    | Expr::Call(..)
+                    | Expr::New(..)
+                    | Expr::Paren(..)
+                    | Expr::Tpl(..)
+                    | Expr::TaggedTpl(..)
+                    | Expr::OptChain(..)
+                    | Expr::MetaProp(..)
+                    | Expr::PrivateName(..)
+                    | Expr::JSXElement(..)
+                    | Expr::JSXFragment(..)
+                    | Expr::JSXMember(..)
+                    | Expr::JSXNamespacedName(..)
+            ),
+            ExprCtx::Assign => matches!(e, Expr::Seq(..)),
+            ExprCtx::Stmt => matches!(e, Expr::Object(..) | Expr::Fn(..) | Expr::Class(..)),
+            ExprCtx::Any => false,
+        }
+    }
+}
+
+/// The position of matched code.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Site {
+    pub ctx: ExprCtx,
+    /// `true` if the matched statement is in a statement list, so it can be
+    /// replaced with zero or multiple statements.
+    pub in_list: bool,
+}
+
+impl Template {
+    pub fn expr(src: &str, syntax: Syntax) -> Result<Self, Error> {
+        let (fm, expr) = parse_expr(src, syntax)?;
+
+        let mut v = SlotCollector::new(ExprCtx::Any);
+        expr.visit_with(&mut v);
+
+        Self::new(fm, Root::Expr(expr), v)
+    }
+
+    /// Parses a template containing zero or more statements.
+    pub fn stmts(src: &str, syntax: Syntax) -> Result<Self, Error> {
+        let (fm, stmts) = parse_stmts(src, syntax)?;
+
+        let mut v = SlotCollector::new(ExprCtx::Operand);
+        stmts.visit_with(&mut v);
+
+        Self::new(fm, Root::Stmts(stmts.len()), v)
+    }
+
+    fn new(fm: Lrc<SourceFile>, root: Root, v: SlotCollector) -> Result<Self, Error> {
+        if let Some(name) = v.misplaced {
+            bail!("`$$${}` can only be used in a list", name);
+        }
+
+        let mut slots = v.slots;
+        slots.sort_by_key(|slot| slot.span.lo);
+
+        Ok(Template { fm, root, slots })
+    }
+
+    pub fn is_expr(&self) -> bool {
+        matches!(self.root, Root::Expr(..))
+    }
+
+    pub(crate) fn vars(&self) -> impl '_ + Iterator<Item = &Atom> {
+        self.slots.iter().map(|slot| &slot.name)
+    }
+
+    /// `src` is the file captured nodes are from.
+    pub(crate) fn render(
+        &self,
+        captures: &Captures,
+        src: &SourceFile,
+        site: Site,
+    ) -> Result<String, Error> {
+        let template = &**self.fm.src;
+        let mut buf = String::with_capacity(template.len());
+        let mut pos = 0;
+
+        for slot in &self.slots {
+            let capture = captures
+                .get(&slot.name)
+                .with_context(|| format!("`${}` is not captured", slot.name))?;
+
+            let (span, text) = self.render_slot(slot, capture, src)?;
+
+            let lo = offset(&self.fm, span.lo).max(pos);
+            buf.push_str(&template[pos..lo]);
+            buf.push_str(&text);
+            pos = offset(&self.fm, span.hi);
+        }
+        buf.push_str(&template[pos..]);
+
+        Ok(match self.root {
+            Root::Expr(ref e) if site.ctx.needs_parens(e) => format!("({buf})"),
+            Root::Stmts(0) if !site.in_list => ";".into(),
+            Root::Stmts(len) if len > 1 && !site.in_list => format!("{{ {buf} }}"),
+            _ => buf,
+        })
+    }
+
+    fn render_slot(
+        &self,
+        slot: &Slot,
+        capture: &Capture,
+        src: &SourceFile,
+    ) -> Result<(Span, String), Error> {
+        let text = |span: Span| snippet(src, span).map(str::to_string);
+
+        let expr = |ctx: ExprCtx, e: &Expr| -> Result<String, Error> {
+            let text = text(e.span())?;
+
+            Ok(if ctx.needs_parens(e) {
+                format!("({text})")
+            } else {
+                text
+            })
+        };
+
+        Ok(match (slot.kind, capture) {
+            (SlotKind::Expr(ctx), Capture::Expr(e)) => (slot.span, expr(ctx, e)?),
+            (SlotKind::Expr(..) | SlotKind::Ident | SlotKind::Stmt { .. }, Capture::Ident(i)) => {
+                (slot.span, text(i.span)?)
+            }
+            (SlotKind::Ident, Capture::Expr(e)) if e.is_ident() => (slot.span, text(e.span())?),
+            (SlotKind::Stmt { .. }, Capture::Expr(e)) => (slot.span, expr(ExprCtx::Stmt, e)?),
+            (SlotKind::Stmt { stmt }, Capture::Stmt(s)) => (stmt, text(s.span())?),
+            (
+                SlotKind::Many { empty },
+                Capture::Exprs(..) | Capture::Props(..) | Capture::Stmts(..),
+            ) => match capture.span() {
+                Some(span) => (slot.span, text(span)?),
+                None => (empty, String::new()),
+            },
+            _ => bail!(
+                "`${}` captured {} and can't be used here",
+                slot.name,
+                match capture {
+                    Capture::Expr(..) => "an expression",
+                    Capture::Ident(..) => "an identifier",
+                    Capture::Stmt(..) => "a statement",
+                    Capture::Exprs(..) | Capture::Props(..) | Capture::Stmts(..) => "a list",
+                }
+            ),
+        })
+    }
+}
+
+struct SlotCollector {
+    slots: Vec<Slot>,
+    ctx: ExprCtx,
+    /// `$$$name` used outside of a list.
+    misplaced: Option<Atom>,
+}
+
+impl SlotCollector {
+    fn new(ctx: ExprCtx) -> Self {
+        SlotCollector {
+            slots: Default::default(),
+            ctx,
+            misplaced: None,
+        }
+    }
+
+    fn ident(&mut self, span: Span, sym: &str) {
+        match metavar(sym) {
+            Some(Var::One(name)) => self.slots.push(Slot {
+                name: name.into(),
+                span,
+                kind: SlotKind::Ident,
+            }),
+            Some(Var::Many(name)) => {
+                self.misplaced.get_or_insert_with(|| name.into());
+            }
+            None => {}
+        }
+    }
+
+    fn list<T>(&mut self, nodes: &[T], many: fn(&T) -> Option<&str>)
+    where
+        T: Spanned + VisitWith<Self>,
+    {
+        for (i, node) in nodes.iter().enumerate() {
+            match many(node) {
+                Some(name) => {
+                    let span = node.span();
+                    let empty = match (i.checked_sub(1), nodes.get(i + 1)) {
+                        (Some(prev), _) => span.with_lo(nodes[prev].span().hi),
+                        (None, Some(next)) => span.with_hi(next.span().lo),
+                        (None, None) => span,
+                    };
+
+                    self.slots.push(Slot {
+                        name: name.into(),
+                        span,
+                        kind: SlotKind::Many { empty },
+                    });
+                }
+                None => {
+                    self.ctx = ExprCtx::Assign;
+                    node.visit_with(self);
+                }
+            }
+        }
+    }
+}
+
+impl Visit for SlotCollector {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        n.left.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.right.visit_with(self);
+    }
+
+    fn visit_cond_expr(&mut self, n: &CondExpr) {
+        n.test.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.cons.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.alt.visit_with(self);
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        let ctx = mem::replace(&mut self.ctx, ExprCtx::Operand);
+
+        if let Expr::Ident(i) = n {
+            match metavar(&i.sym) {
+                Some(Var::One(name)) => {
+                    self.slots.push(Slot {
+                        name: name.into(),
+                        span: i.span,
+                        kind: SlotKind::Expr(ctx),
+                    });
+                    return;
+                }
+                Some(Var::Many(name)) => {
+                    self.misplaced.get_or_insert_with(|| name.into());
+                    return;
+                }
+                None => {}
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_expr_or_spreads(&mut self, n: &[ExprOrSpread]) {
+        self.list(n, many_of_arg);
+    }
+
+    fn visit_expr_stmt(&mut self, n: &ExprStmt) {
+        if let Expr::Ident(i) = &*n.expr {
+            if let Some(Var::One(name)) = metavar(&i.sym) {
+                self.slots.push(Slot {
+                    name: name.into(),
+                    span: i.span,
+                    kind: SlotKind::Stmt { stmt: n.span },
+                });
+                return;
+            }
+        }
+
+        self.ctx = ExprCtx::Stmt;
+        n.expr.visit_with(self);
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        self.ident(n.span, &n.sym);
+    }
+
+    fn visit_ident_name(&mut self, n: &IdentName) {
+        self.ident(n.span, &n.sym);
+    }
+
+    fn visit_key_value_prop(&mut self, n: &KeyValueProp) {
+        n.key.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.value.visit_with(self);
+    }
+
+    fn visit_opt_vec_expr_or_spreads(&mut self, n: &[Option<ExprOrSpread>]) {
+        self.list(n, many_of_elem);
+    }
+
+    fn visit_paren_expr(&mut self, n: &ParenExpr) {
+        self.ctx = ExprCtx::Any;
+        n.expr.visit_with(self);
+    }
+
+    fn visit_prop_or_spreads(&mut self, n: &[PropOrSpread]) {
+        self.list(n, many_of_prop);
+    }
+
+    fn visit_return_stmt(&mut self, n: &ReturnStmt) {
+        self.ctx = ExprCtx::Any;
+        n.arg.visit_with(self);
+    }
+
+    fn visit_seq_expr(&mut self, n: &SeqExpr) {
+        self.list(&n.exprs, |e| many_of_expr(e));
+    }
+
+    fn visit_stmts(&mut self, n: &[Stmt]) {
+        self.list(n, many_of_stmt);
+    }
+
+    fn visit_throw_stmt(&mut self, n: &ThrowStmt) {
+        self.ctx = ExprCtx::Any;
+        n.arg.visit_with(self);
+    }
+
+    fn visit_tpl(&mut self, n: &Tpl) {
+        for e in &n.exprs {
+            self.ctx = ExprCtx::Any;
+            e.visit_with(self);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+        n.name.visit_with(self);
+        self.ctx = ExprCtx::Assign;
+        n.init.visit_with(self);
+    }
+}
@@ -0,0 +1,81 @@
+use swc_ecma_ast::{Expr, ExprOrSpread, Prop, PropOrSpread, Stmt};
+
+/// A metavariable in a pattern or a template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Var<'a> {
+    /// `$name`, which matches a single node.
+    One(&'a str),
+    /// `$$$name`, which matches zero or more nodes of a list.
+    Many(&'a str),
+}
+
+/// `$` and `$$` are not metavariables, so patterns can still match code using
+/// jQuery-like identifiers.
+pub(crate) fn metavar(sym: &str) -> Option<Var<'_>> {
+    let (name, many) = match sym.strip_prefix("$$$") {
+        Some(name) => (name, true),
+        None => (sym.strip_prefix('$')?, false),
+    };
+
+    let first = name.chars().next()?;
+    if !first.is_ascii_alphabetic() && first != '_' {
+        return None;
+    }
+
+    Some(if many {
+        Var::Many(name)
+    } else {
+        Var::One(name)
+    })
+}
+
+pub(crate) fn one_of_expr(e: &Expr) -> Option<&str> {
+    match e {
+        Expr::Ident(i) => match metavar(&i.sym)? {
+            Var::One(name) => Some(name),
+            Var::Many(..) => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn many_of_expr(e: &Expr) -> Option<&str> {
+    match e {
+        Expr::Ident(i) => match metavar(&i.sym)? {
+            Var::Many(name) => Some(name),
+            Var::One(..) => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn many_of_arg(n: &ExprOrSpread) -> Option<&str> {
+    match n {
+        ExprOrSpread { spread: None, expr } => many_of_expr(expr),
+        _ => None,
+    }
+}
+
+pub(crate) fn many_of_elem(n: &Option<ExprOrSpread>) -> Option<&str> {
+    n.as_ref().and_then(many_of_arg)
+}
+
+pub(crate) fn many_of_prop(n: &PropOrSpread) -> Option<&str> {
+    match n {
+        PropOrSpread::Prop(p) => match &**p {
+            Prop::Shorthand(i) => match metavar(&i.sym)? {
+                Var::Many(name) => Some(name),
+                Var::One(..) => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn many_of_stmt(n: &Stmt) -> Option<&str> {
+    match n {
+        Stmt::Expr(s) => many_of_expr(&s.expr),
+        _ => None,
+    }
+}
@@ -0,0 +1,180 @@
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_codemod::{Capture, Codemod};
+use swc_ecma_parser::{parse_file_as_program, Syntax};
+
+fn codemod() -> Codemod {
+    Codemod::new(Syntax::default())
+}
+
+fn rewrite(codemod: &Codemod, src: &str) -> String {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+    let program = parse_file_as_program(
+        &fm,
+        Syntax::default(),
+        Default::default(),
+        None,
+        &mut vec![],
+    )
+    .unwrap();
+
+    codemod.rewrite(&fm, &program).unwrap()
+}
+
+#[test]
+fn preserve_formatting() {
+    let c = codemod()
+        .expr("$obj.hasOwnProperty($key)", "Object.hasOwn($obj, $key)")
+        .unwrap();
+
+    assert_eq!(
+        rewrite(
+            &c,
+            "// leading comment\nif (foo /* inner */.bar.hasOwnProperty( key )) {\n    return   \
+             1; // trailing\n}\n"
+        ),
+        "// leading comment\nif (Object.hasOwn(foo /* inner */.bar, key)) {\n    return   1; // \
+         trailing\n}\n"
+    );
+}
+
+#[test]
+fn first_rule_wins() {
+    let c = codemod()
+        .expr(
+            "Object.prototype.hasOwnProperty.call($obj, $key)",
+            "Object.hasOwn($obj, $key)",
+        )
+        .unwrap()
+        .expr("$obj.hasOwnProperty($key)", "Object.hasOwn($obj, $key)")
+        .unwrap();
+
+    assert_eq!(
+        rewrite(
+            &c,
+            "Object.prototype.hasOwnProperty.call(a, b); a.hasOwnProperty(b);"
+        ),
+        "Object.hasOwn(a, b); Object.hasOwn(a, b);"
+    );
+}
+
+#[test]
+fn sequence() {
+    let c = codemod()
+        .expr("console.log($$$args)", "logger.info($$$args)")
+        .unwrap()
+        .expr("assert($cond, $$$rest)", "invariant($$$rest, $cond)")
+        .unwrap();
+
+    assert_eq!(
+        rewrite(&c, "console.log(); console.log(a, ...b, /* c */ c);"),
+        "logger.info(); logger.info(a, ...b, /* c */ c);"
+    );
+    assert_eq!(
+        rewrite(&c, "assert(x); assert(x, 'message', 1);"),
+        "invariant(x); invariant('message', 1, x);"
+    );
+}
+
+#[test]
+fn repeated() {
+    let c = codemod().expr("$a !== $a", "Number.isNaN($a)").unwrap();
+
+    assert_eq!(
+        rewrite(&c, "x.y !== x.y; x !== y;"),
+        "Number.isNaN(x.y); x !== y;"
+    );
+}
+
+#[test]
+fn parens() {
+    let c = codemod()
+        .expr("Math.pow($a, $b)", "$a ** $b")
+        .unwrap()
+        .expr("$str.startsWith($prefix)", "$str.indexOf($prefix) === 0")
+        .unwrap();
+
+    assert_eq!(
+        rewrite(&c, "const x = Math.pow(a + 1, 2) * 3;"),
+        "const x = ((a + 1) ** 2) * 3;"
+    );
+    assert_eq!(
+        rewrite(&c, "if (!(a || b).startsWith(c)) {}"),
+        "if (!((a || b).indexOf(c) === 0)) {}"
+    );
+}
+
+#[test]
+fn ident() {
+    let c = codemod()
+        .expr("$obj.$method.call($obj, $$$args)", "$obj.$method($$$args)")
+        .unwrap();
+
+    assert_eq!(
+        rewrite(&c, "a.b.call(a, 1, 2); a.b.call(c, 1);"),
+        "a.b(1, 2); a.b.call(c, 1);"
+    );
+}
+
+#[test]
+fn stmt() {
+    let c = codemod()
+        .stmt("if (process.env.DEBUG) { $$$body }", "$$$body")
+        .unwrap()
+        .stmt("console.log($$$_);", "")
+        .unwrap();
+
+    assert_eq!(
+        rewrite(
+            &c,
+            "before();\nif (process.env.DEBUG) {\n    check(1);\n    check(2);\n}\nafter();"
+        ),
+        "before();\ncheck(1);\n    check(2);\nafter();"
+    );
+    assert_eq!(
+        rewrite(&c, "if (a) console.log(a);\nconsole.log(b);\nrun();"),
+        "if (a) ;\n\nrun();"
+    );
+}
+
+#[test]
+fn find() {
+    let c = codemod().expr("require($src)", "import($src)").unwrap();
+
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(
+        FileName::Anon.into(),
+        "const a = require('a'); require(b, c);".into(),
+    );
+    let program = parse_file_as_program(
+        &fm,
+        Syntax::default(),
+        Default::default(),
+        None,
+        &mut vec![],
+    )
+    .unwrap();
+
+    let matches = c.find(&program);
+    assert_eq!(matches.len(), 1);
+    assert!(matches!(
+        matches[0].captures.get("src"),
+        Some(Capture::Expr(e)) if e.is_lit()
+    ));
+}
+
+#[test]
+fn invalid_rule() {
+    codemod()
+        .expr("foo($a)", "bar($b)")
+        .expect_err("`$b` is not bound");
+    codemod()
+        .expr("foo($a)", "bar($$$a + 1)")
+        .expect_err("`$$$a` is used outside of a list");
+    codemod()
+        .stmt("foo($a);", "bar($a)")
+        .expect("a statement template may be an expression statement");
+    codemod()
+        .stmt("foo($a); bar($a);", "baz($a);")
+        .expect_err("a statement pattern should contain a single statement");
+}
@@ -1294,6 +1294,9 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::TsInstantiation(TsInstantiation { ref expr, .. })
         | Expr::TsSatisfies(TsSatisfiesExpr { ref expr, .. }) => can_be_null(expr),
 
+        Expr::Pipeline(PipelineExpr { ref body, .. }) => can_be_null(body),
+        Expr::TopicRef(..) => true,
+
         Expr::Invalid(..) => unreachable!(),
     }
 }
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// Formatting options. The names and defaults follow prettier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Config {
+    /// The line width the printer tries to stay within.
+    pub print_width: usize,
+
+    /// The number of spaces per indentation level.
+    pub tab_width: usize,
+
+    /// Indent with tabs instead of spaces.
+    pub use_tabs: bool,
+
+    /// Print semicolons at the ends of statements.
+    pub semi: bool,
+
+    /// Prefer single quotes over double quotes for strings.
+    pub single_quote: bool,
+
+    /// Prefer single quotes over double quotes for JSX attributes.
+    pub jsx_single_quote: bool,
+
+    pub trailing_comma: TrailingComma,
+
+    /// Print spaces between braces of object literals, object patterns and
+    /// import or export specifiers.
+    pub bracket_spacing: bool,
+
+    pub arrow_parens: ArrowParens,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            print_width: 80,
+            tab_width: 2,
+            use_tabs: false,
+            semi: true,
+            single_quote: false,
+            jsx_single_quote: false,
+            trailing_comma: Default::default(),
+            bracket_spacing: true,
+            arrow_parens: Default::default(),
+        }
+    }
+}
+
+/// Where to print trailing commas in lists broken over multiple lines.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TrailingComma {
+    /// Everywhere possible, including function parameters and arguments.
+    #[default]
+    All,
+    /// Where valid in ES5, i.e. objects, arrays and import or export
+    /// specifiers.
+    Es5,
+    None,
+}
+
+/// Whether to parenthesize a sole arrow function parameter.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArrowParens {
+    /// `(x) => x`
+    #[default]
+    Always,
+    /// `x => x`
+    Avoid,
+}
@@ -0,0 +1,224 @@
+//! A document IR in the style of Wadler's "A prettier printer".
+//!
+//! Formatting happens in two steps. The AST is converted into a [Doc], which
+//! describes the possible layouts, and then [crate::layout] picks line breaks
+//! so that lines fit in the configured width.
+
+use std::borrow::Cow;
+
+#[derive(Debug, Clone)]
+pub enum Doc {
+    /// Text without line breaks.
+    Text(Cow<'static, str>),
+    Concat(Vec<Doc>),
+    /// Contents of a group are printed on a single line if they fit, and all
+    /// lines directly in the group are broken otherwise.
+    Group {
+        contents: Box<Doc>,
+        should_break: bool,
+    },
+    Indent(Box<Doc>),
+    Line(Line),
+    /// Printed differently depending on whether the enclosing group is
+    /// broken.
+    IfBreak {
+        breaks: Box<Doc>,
+        flat: Box<Doc>,
+    },
+    /// Prints the first state whose first line fits, or the last state
+    /// broken if none of them fits.
+    ConditionalGroup(Vec<Doc>),
+    /// Alternating contents and separators. A separator is broken only if
+    /// the next content does not fit on the current line.
+    Fill(Vec<Doc>),
+    /// Deferred until the next line break, which is used for line comments.
+    LineSuffix(Box<Doc>),
+    /// Forces all enclosing groups to break.
+    BreakParent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line {
+    /// A space if the group is flat.
+    Normal,
+    /// Nothing if the group is flat.
+    Soft,
+    /// Always a line break.
+    Hard,
+}
+
+impl Doc {
+    pub const fn nil() -> Doc {
+        Doc::Text(Cow::Borrowed(""))
+    }
+
+    pub fn is_nil(&self) -> bool {
+        match self {
+            Doc::Text(s) => s.is_empty(),
+            Doc::Concat(parts) => parts.iter().all(Doc::is_nil),
+            _ => false,
+        }
+    }
+
+    /// Marks a group as broken.
+    pub fn into_broken(self) -> Doc {
+        match self {
+            Doc::Group { contents, .. } => Doc::Group {
+                contents,
+                should_break: true,
+            },
+            doc => doc,
+        }
+    }
+
+    /// Returns true if the doc contains a forced line break.
+    pub fn will_break(&self) -> bool {
+        match self {
+            Doc::Line(Line::Hard) | Doc::BreakParent => true,
+            Doc::Group {
+                should_break: true, ..
+            } => true,
+            Doc::Group { contents: doc, .. } | Doc::Indent(doc) | Doc::LineSuffix(doc) => {
+                doc.will_break()
+            }
+            Doc::IfBreak { breaks, flat } => breaks.will_break() || flat.will_break(),
+            Doc::ConditionalGroup(states) => states[0].will_break(),
+            Doc::Concat(parts) | Doc::Fill(parts) => parts.iter().any(Doc::will_break),
+            Doc::Text(..) | Doc::Line(..) => false,
+        }
+    }
+}
+
+impl Default for Doc {
+    fn default() -> Self {
+        Doc::nil()
+    }
+}
+
+impl From<&'static str> for Doc {
+    fn from(s: &'static str) -> Self {
+        Doc::Text(Cow::Borrowed(s))
+    }
+}
+
+impl From<String> for Doc {
+    fn from(s: String) -> Self {
+        Doc::Text(Cow::Owned(s))
+    }
+}
+
+impl From<Vec<Doc>> for Doc {
+    fn from(parts: Vec<Doc>) -> Self {
+        Doc::Concat(parts)
+    }
+}
+
+pub fn text(s: impl Into<Cow<'static, str>>) -> Doc {
+    Doc::Text(s.into())
+}
+
+pub fn concat(parts: impl IntoIterator<Item = Doc>) -> Doc {
+    Doc::Concat(parts.into_iter().collect())
+}
+
+pub fn group(doc: impl Into<Doc>) -> Doc {
+    Doc::Group {
+        contents: Box::new(doc.into()),
+        should_break: false,
+    }
+}
+
+pub fn broken_group(doc: impl Into<Doc>) -> Doc {
+    Doc::Group {
+        contents: Box::new(doc.into()),
+        should_break: true,
+    }
+}
+
+pub fn indent(doc: impl Into<Doc>) -> Doc {
+    Doc::Indent(Box::new(doc.into()))
+}
+
+pub const fn line() -> Doc {
+    Doc::Line(Line::Normal)
+}
+
+pub const fn softline() -> Doc {
+    Doc::Line(Line::Soft)
+}
+
+pub const fn hardline() -> Doc {
+    Doc::Line(Line::Hard)
+}
+
+pub fn if_break(breaks: impl Into<Doc>, flat: impl Into<Doc>) -> Doc {
+    Doc::IfBreak {
+        breaks: Box::new(breaks.into()),
+        flat: Box::new(flat.into()),
+    }
+}
+
+pub fn conditional_group(states: Vec<Doc>) -> Doc {
+    debug_assert!(!states.is_empty());
+    Doc::ConditionalGroup(states)
+}
+
+pub fn fill(parts: Vec<Doc>) -> Doc {
+    Doc::Fill(parts)
+}
+
+pub fn line_suffix(doc: impl Into<Doc>) -> Doc {
+    Doc::LineSuffix(Box::new(doc.into()))
+}
+
+/// Joins `docs` with `sep`.
+pub fn join(docs: impl IntoIterator<Item = Doc>, sep: impl Fn() -> Doc) -> Doc {
+    let mut parts = Vec::new();
+    for (i, doc) in docs.into_iter().enumerate() {
+        if i != 0 {
+            parts.push(sep());
+        }
+        parts.push(doc);
+    }
+    Doc::Concat(parts)
+}
+
+/// Marks groups containing forced line breaks as broken, so that the layout
+/// does not need to look into them.
+pub(crate) fn propagate_breaks(doc: &mut Doc) -> bool {
+    match doc {
+        Doc::Line(Line::Hard) | Doc::BreakParent => true,
+        Doc::Group {
+            contents,
+            should_break,
+        } => {
+            if propagate_breaks(contents) {
+                *should_break = true;
+            }
+            *should_break
+        }
+        Doc::Indent(doc) | Doc::LineSuffix(doc) => propagate_breaks(doc),
+        // Only the broken contents are printed if the enclosing group breaks.
+        Doc::IfBreak { breaks, flat } => {
+            let breaks = propagate_breaks(breaks);
+            propagate_breaks(flat);
+            breaks
+        }
+        // Each state is chosen by its own first line, so breaks are not
+        // propagated out of a conditional group.
+        Doc::ConditionalGroup(states) => {
+            for state in states {
+                propagate_breaks(state);
+            }
+            false
+        }
+        Doc::Concat(parts) | Doc::Fill(parts) => {
+            let mut res = false;
+            for part in parts {
+                res |= propagate_breaks(part);
+            }
+            res
+        }
+        Doc::Text(..) | Doc::Line(..) => false,
+    }
+}
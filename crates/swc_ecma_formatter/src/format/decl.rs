@@ -0,0 +1,488 @@
+use swc_common::Spanned;
+use swc_ecma_ast::*;
+
+use super::{CommaKind, Formatter};
+use crate::doc::*;
+
+impl Formatter<'_> {
+    pub fn decl(&self, decl: &Decl) -> Doc {
+        match decl {
+            Decl::Class(c) => concat([
+                if c.declare {
+                    text("declare ")
+                } else {
+                    Doc::nil()
+                },
+                self.class(Some(&c.ident), &c.class),
+            ]),
+            Decl::Fn(f) => concat([
+                if f.declare {
+                    text("declare ")
+                } else {
+                    Doc::nil()
+                },
+                self.function(Some(&f.ident), &f.function),
+            ]),
+            Decl::Var(v) => self.var_decl(v, true),
+            Decl::Using(u) => self.using_decl(u, true),
+            Decl::TsInterface(..)
+            | Decl::TsTypeAlias(..)
+            | Decl::TsEnum(..)
+            | Decl::TsModule(..) => self.emit(decl),
+        }
+    }
+
+    pub fn var_decl(&self, v: &VarDecl, semi: bool) -> Doc {
+        let kind = match v.kind {
+            VarDeclKind::Var => "var",
+            VarDeclKind::Let => "let",
+            VarDeclKind::Const => "const",
+        };
+
+        concat([
+            if v.declare {
+                text("declare ")
+            } else {
+                Doc::nil()
+            },
+            self.declarators(kind, &v.decls),
+            if semi { self.semi() } else { Doc::nil() },
+        ])
+    }
+
+    pub fn using_decl(&self, u: &UsingDecl, semi: bool) -> Doc {
+        concat([
+            self.declarators(if u.is_await { "await using" } else { "using" }, &u.decls),
+            if semi { self.semi() } else { Doc::nil() },
+        ])
+    }
+
+    fn declarators(&self, kind: &'static str, decls: &[VarDeclarator]) -> Doc {
+        let has_init = decls.iter().any(|d| d.init.is_some());
+
+        let mut printed = decls
+            .iter()
+            .map(|d| self.list_item(d, || self.declarator(d)));
+        let first = printed.next().unwrap_or_else(Doc::nil);
+        let rest = printed
+            .map(|d| concat([text(","), if has_init { hardline() } else { line() }, d]))
+            .collect::<Vec<_>>();
+
+        group(concat([
+            text(kind),
+            text(" "),
+            first,
+            indent(Doc::Concat(rest)),
+        ]))
+    }
+
+    fn declarator(&self, d: &VarDeclarator) -> Doc {
+        let name = match &d.name {
+            // `const { a: { b } } = c` is printed one property per line.
+            Pat::Object(o)
+                if o.props.iter().any(|p| match p {
+                    ObjectPatProp::KeyValue(p) => {
+                        matches!(&*p.value, Pat::Object(o) if !o.props.is_empty())
+                    }
+                    _ => false,
+                }) =>
+            {
+                self.object_pat(o, true)
+            }
+            // `let a!: number`
+            Pat::Ident(i) if d.definite => concat([
+                self.ident(&i.id.sym),
+                text("!"),
+                self.type_ann(i.type_ann.as_deref()),
+            ]),
+            name => self.pat(name),
+        };
+
+        match &d.init {
+            Some(init) => self.assignment(name, " =", init),
+            None => name,
+        }
+    }
+
+    pub fn function(&self, ident: Option<&Ident>, f: &Function) -> Doc {
+        concat([
+            self.decorators(&f.decorators, false),
+            if f.is_async {
+                text("async ")
+            } else {
+                Doc::nil()
+            },
+            text("function"),
+            if f.is_generator {
+                text("*")
+            } else {
+                Doc::nil()
+            },
+            match ident {
+                Some(ident) => concat([text(" "), self.ident(&ident.sym)]),
+                None => text(" "),
+            },
+            self.signature(f),
+            self.function_body(f.body.as_ref()),
+        ])
+    }
+
+    /// Type parameters, parameters and the return type of a function.
+    pub fn signature(&self, f: &Function) -> Doc {
+        let params = f
+            .params
+            .iter()
+            .map(|p| {
+                self.list_item(p, || {
+                    concat([self.decorators(&p.decorators, true), self.pat(&p.pat)])
+                })
+            })
+            .collect();
+
+        concat([
+            self.type_params(f.type_params.as_deref()),
+            self.params(
+                params,
+                f.params.last().is_some_and(|p| p.pat.is_rest()),
+                f.params.len() == 1 && should_hug(&f.params[0].pat),
+            ),
+            self.return_type(f.return_type.as_deref()),
+        ])
+    }
+
+    pub fn function_body(&self, body: Option<&BlockStmt>) -> Doc {
+        match body {
+            Some(body) => concat([text(" "), self.block(body)]),
+            None => self.semi(),
+        }
+    }
+
+    pub fn params(&self, params: Vec<Doc>, ends_with_rest: bool, hug: bool) -> Doc {
+        if hug {
+            return concat([text("("), Doc::Concat(params), text(")")]);
+        }
+
+        self.bracketed(
+            "(",
+            params,
+            if ends_with_rest {
+                None
+            } else {
+                Some(CommaKind::All)
+            },
+            ")",
+        )
+    }
+
+    pub fn type_params(&self, type_params: Option<&TsTypeParamDecl>) -> Doc {
+        type_params.map_or(Doc::nil(), |t| self.emit(t))
+    }
+
+    pub fn type_args(&self, type_args: Option<&TsTypeParamInstantiation>) -> Doc {
+        type_args.map_or(Doc::nil(), |t| self.emit(t))
+    }
+
+    pub fn return_type(&self, ty: Option<&TsTypeAnn>) -> Doc {
+        ty.map_or(Doc::nil(), |ty| {
+            concat([text(": "), self.emit(&*ty.type_ann)])
+        })
+    }
+
+    pub fn type_ann(&self, ty: Option<&TsTypeAnn>) -> Doc {
+        self.return_type(ty)
+    }
+
+    pub fn class(&self, ident: Option<&Ident>, c: &Class) -> Doc {
+        concat([
+            self.decorators(&c.decorators, false),
+            self.class_without_decorators(ident, c),
+        ])
+    }
+
+    pub fn class_without_decorators(&self, ident: Option<&Ident>, c: &Class) -> Doc {
+        let mut head = Vec::new();
+        if c.is_abstract {
+            head.push(text("abstract "));
+        }
+        head.push(text("class"));
+        if let Some(ident) = ident {
+            head.push(text(" "));
+            head.push(self.ident(&ident.sym));
+        }
+        head.push(self.type_params(c.type_params.as_deref()));
+
+        let mut heritage = Vec::new();
+        if let Some(super_class) = &c.super_class {
+            heritage.push(concat([
+                line(),
+                text("extends "),
+                self.expr(super_class),
+                self.type_args(c.super_type_params.as_deref()),
+            ]));
+        }
+        if !c.implements.is_empty() {
+            heritage.push(concat([
+                line(),
+                text("implements "),
+                join(c.implements.iter().map(|i| self.emit(i)), || text(", ")),
+            ]));
+        }
+
+        let body = self.braced(&c.body, c.span, |f, member| f.class_member(member));
+
+        concat([
+            Doc::Concat(head),
+            group(indent(Doc::Concat(heritage))),
+            text(" "),
+            body,
+        ])
+    }
+
+    fn class_member(&self, member: &ClassMember) -> Doc {
+        if let ClassMember::Empty(..) = member {
+            return Doc::nil();
+        }
+
+        self.with_comments(member.span(), || match member {
+            ClassMember::Constructor(c) => {
+                let params = c
+                    .params
+                    .iter()
+                    .map(|p| match p {
+                        ParamOrTsParamProp::Param(p) => self.list_item(p, || {
+                            concat([self.decorators(&p.decorators, true), self.pat(&p.pat)])
+                        }),
+                        ParamOrTsParamProp::TsParamProp(p) => self.emit(p),
+                    })
+                    .collect();
+                let has_param_props = c
+                    .params
+                    .iter()
+                    .any(|p| matches!(p, ParamOrTsParamProp::TsParamProp(..)));
+
+                let params = self.params(params, false, false);
+                // Parameter properties are always printed on their own lines.
+                let params = if has_param_props {
+                    params.into_broken()
+                } else {
+                    params
+                };
+
+                concat([
+                    self.accessibility(c.accessibility),
+                    text("constructor"),
+                    params,
+                    self.function_body(c.body.as_ref()),
+                ])
+            }
+            ClassMember::Method(m) => self.method(
+                MethodModifiers {
+                    is_static: m.is_static,
+                    accessibility: m.accessibility,
+                    is_abstract: m.is_abstract,
+                    is_override: m.is_override,
+                    is_optional: m.is_optional,
+                },
+                m.kind,
+                self.prop_name(&m.key),
+                &m.function,
+            ),
+            ClassMember::PrivateMethod(m) => self.method(
+                MethodModifiers {
+                    is_static: m.is_static,
+                    accessibility: m.accessibility,
+                    is_abstract: m.is_abstract,
+                    is_override: m.is_override,
+                    is_optional: m.is_optional,
+                },
+                m.kind,
+                text(format!("#{}", m.key.name)),
+                &m.function,
+            ),
+            ClassMember::ClassProp(p) => {
+                let mut parts = vec![self.decorators(&p.decorators, false)];
+                if p.declare {
+                    parts.push(text("declare "));
+                }
+                parts.push(self.accessibility(p.accessibility));
+                if p.is_static {
+                    parts.push(text("static "));
+                }
+                if p.is_abstract {
+                    parts.push(text("abstract "));
+                }
+                if p.is_override {
+                    parts.push(text("override "));
+                }
+                if p.readonly {
+                    parts.push(text("readonly "));
+                }
+                parts.push(self.prop_name(&p.key));
+                parts.push(self.class_prop_rest(
+                    p.is_optional,
+                    p.definite,
+                    p.type_ann.as_deref(),
+                    p.value.as_deref(),
+                ));
+                Doc::Concat(parts)
+            }
+            ClassMember::PrivateProp(p) => {
+                let mut parts = vec![self.decorators(&p.decorators, false)];
+                parts.push(self.accessibility(p.accessibility));
+                if p.is_static {
+                    parts.push(text("static "));
+                }
+                if p.is_override {
+                    parts.push(text("override "));
+                }
+                if p.readonly {
+                    parts.push(text("readonly "));
+                }
+                parts.push(text(format!("#{}", p.key.name)));
+                parts.push(self.class_prop_rest(
+                    p.is_optional,
+                    p.definite,
+                    p.type_ann.as_deref(),
+                    p.value.as_deref(),
+                ));
+                Doc::Concat(parts)
+            }
+            ClassMember::StaticBlock(b) => concat([text("static "), self.block(&b.body)]),
+            ClassMember::Empty(..) => Doc::nil(),
+            ClassMember::AutoAccessor(a) => {
+                let mut parts = vec![self.decorators(&a.decorators, false)];
+                parts.push(self.accessibility(a.accessibility));
+                if a.is_static {
+                    parts.push(text("static "));
+                }
+                if a.is_abstract {
+                    parts.push(text("abstract "));
+                }
+                if a.is_override {
+                    parts.push(text("override "));
+                }
+                parts.push(text("accessor "));
+                parts.push(match &a.key {
+                    Key::Private(p) => text(format!("#{}", p.name)),
+                    Key::Public(key) => self.prop_name(key),
+                });
+                parts.push(self.class_prop_rest(
+                    false,
+                    a.definite,
+                    a.type_ann.as_deref(),
+                    a.value.as_deref(),
+                ));
+                Doc::Concat(parts)
+            }
+            ClassMember::TsIndexSignature(..) => self.emit(member),
+        })
+    }
+
+    fn class_prop_rest(
+        &self,
+        is_optional: bool,
+        definite: bool,
+        type_ann: Option<&TsTypeAnn>,
+        value: Option<&Expr>,
+    ) -> Doc {
+        let name = concat([
+            if is_optional { text("?") } else { Doc::nil() },
+            if definite { text("!") } else { Doc::nil() },
+            self.type_ann(type_ann),
+        ]);
+
+        concat([
+            match value {
+                Some(value) => self.assignment(name, " =", value),
+                None => name,
+            },
+            self.semi(),
+        ])
+    }
+
+    fn accessibility(&self, accessibility: Option<Accessibility>) -> Doc {
+        match accessibility {
+            Some(Accessibility::Public) => text("public "),
+            Some(Accessibility::Protected) => text("protected "),
+            Some(Accessibility::Private) => text("private "),
+            None => Doc::nil(),
+        }
+    }
+
+    fn method(
+        &self,
+        modifiers: MethodModifiers,
+        kind: MethodKind,
+        key: Doc,
+        function: &Function,
+    ) -> Doc {
+        let mut parts = vec![self.decorators(&function.decorators, false)];
+        parts.push(self.accessibility(modifiers.accessibility));
+        if modifiers.is_static {
+            parts.push(text("static "));
+        }
+        if modifiers.is_abstract {
+            parts.push(text("abstract "));
+        }
+        if modifiers.is_override {
+            parts.push(text("override "));
+        }
+        parts.push(self.method_head(kind, function, key));
+        if modifiers.is_optional {
+            parts.push(text("?"));
+        }
+        parts.push(self.signature(function));
+        parts.push(self.function_body(function.body.as_ref()));
+
+        Doc::Concat(parts)
+    }
+
+    /// `async *key`, `get key` or `set key`.
+    pub fn method_head(&self, kind: MethodKind, function: &Function, key: Doc) -> Doc {
+        concat([
+            match kind {
+                MethodKind::Method => Doc::nil(),
+                MethodKind::Getter => text("get "),
+                MethodKind::Setter => text("set "),
+            },
+            if function.is_async {
+                text("async ")
+            } else {
+                Doc::nil()
+            },
+            if function.is_generator {
+                text("*")
+            } else {
+                Doc::nil()
+            },
+            key,
+        ])
+    }
+
+    pub fn prop_name(&self, key: &PropName) -> Doc {
+        match key {
+            PropName::Ident(i) => self.ident(&i.sym),
+            PropName::Str(s) => self.str(s),
+            PropName::Num(n) => self.lit(&Lit::Num(n.clone())),
+            PropName::BigInt(n) => self.lit(&Lit::BigInt(n.clone())),
+            PropName::Computed(c) => concat([text("["), self.expr(&c.expr), text("]")]),
+        }
+    }
+}
+
+struct MethodModifiers {
+    is_static: bool,
+    accessibility: Option<Accessibility>,
+    is_abstract: bool,
+    is_override: bool,
+    is_optional: bool,
+}
+
+/// `function f({ a, b }) {}` is printed without breaking the parentheses.
+pub(super) fn should_hug(param: &Pat) -> bool {
+    match param {
+        Pat::Object(..) => true,
+        Pat::Assign(a) => matches!(&*a.left, Pat::Object(..)) && a.right.is_object(),
+        _ => false,
+    }
+}
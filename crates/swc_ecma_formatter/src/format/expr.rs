@@ -0,0 +1,924 @@
+use swc_common::Spanned;
+use swc_ecma_ast::*;
+
+use super::{decl::should_hug, CommaKind, Formatter};
+use crate::{doc::*, layout::Layout, ArrowParens};
+
+/// A part of a member chain like `.foo`, `[0]` or `(args)`.
+enum Link<'e> {
+    Prop {
+        optional: bool,
+        prop: &'e MemberProp,
+    },
+    Call(
+        &'e [ExprOrSpread],
+        Option<&'e TsTypeParamInstantiation>,
+        bool,
+    ),
+    NonNull,
+}
+
+impl Link<'_> {
+    fn is_call(&self) -> bool {
+        matches!(self, Link::Call(..))
+    }
+}
+
+impl Formatter<'_> {
+    pub fn expr(&self, e: &Expr) -> Doc {
+        self.list_item(e, || self.expr_inner(e))
+    }
+
+    /// Prints an expression in parentheses of `if`, `while` or `return`,
+    /// where operands of a binary expression are not indented.
+    pub fn expr_in_parens(&self, e: &Expr) -> Doc {
+        match e {
+            Expr::Bin(b) => self.list_item(e, || {
+                let (first, rest) = self.binary_parts(b);
+                concat([first, Doc::Concat(rest)])
+            }),
+            _ => self.expr(e),
+        }
+    }
+
+    fn expr_inner(&self, e: &Expr) -> Doc {
+        match e {
+            Expr::This(..) => text("this"),
+            Expr::Array(a) => self.array(a),
+            Expr::Object(o) => self.object(o),
+            Expr::Fn(f) => self.function(f.ident.as_ref(), &f.function),
+            Expr::Unary(u) => self.unary(u),
+            Expr::Update(u) => {
+                let op = text(u.op.as_str());
+                if u.prefix {
+                    concat([op, self.expr(&u.arg)])
+                } else {
+                    concat([self.expr(&u.arg), op])
+                }
+            }
+            Expr::Bin(b) => {
+                let (first, rest) = self.binary_parts(b);
+                group(concat([first, indent(Doc::Concat(rest))]))
+            }
+            Expr::Assign(a) => {
+                let left = self.assign_target(&a.left);
+                self.assignment(left, format!(" {}", a.op.as_str()), &a.right)
+            }
+            Expr::Member(..) | Expr::Call(..) | Expr::OptChain(..) | Expr::TsNonNull(..) => {
+                let mut links = Vec::new();
+                let head = flatten(e, &mut links);
+                self.chain(head, links)
+            }
+            Expr::SuperProp(s) => self.super_prop(s),
+            Expr::Cond(c) => group(concat([
+                self.expr(&c.test),
+                indent(concat([
+                    line(),
+                    text("? "),
+                    self.expr(&c.cons),
+                    line(),
+                    text(": "),
+                    self.expr(&c.alt),
+                ])),
+            ])),
+            Expr::New(n) => concat([
+                text("new "),
+                self.expr(&n.callee),
+                self.type_args(n.type_args.as_deref()),
+                match &n.args {
+                    Some(args) => self.args(args),
+                    None => text("()"),
+                },
+            ]),
+            Expr::Seq(s) => {
+                let mut exprs = s.exprs.iter().map(|e| self.expr(e));
+                let first = exprs.next().unwrap_or_default();
+                let rest = exprs
+                    .map(|e| concat([text(","), line(), e]))
+                    .collect::<Vec<_>>();
+                group(concat([first, indent(Doc::Concat(rest))]))
+            }
+            Expr::Ident(i) => self.ident(&i.sym),
+            Expr::Lit(l) => self.lit(l),
+            Expr::Tpl(t) => self.tpl(t),
+            Expr::TaggedTpl(t) => concat([
+                self.expr(&t.tag),
+                self.type_args(t.type_params.as_deref()),
+                self.tpl(&t.tpl),
+            ]),
+            Expr::Arrow(a) => self.arrow(a),
+            Expr::Class(c) => self.class(c.ident.as_ref(), &c.class),
+            Expr::Yield(y) => concat([
+                text(if y.delegate { "yield*" } else { "yield" }),
+                match &y.arg {
+                    Some(arg) => concat([text(" "), self.expr(arg)]),
+                    None => Doc::nil(),
+                },
+            ]),
+            Expr::MetaProp(m) => text(match m.kind {
+                MetaPropKind::NewTarget => "new.target",
+                MetaPropKind::ImportMeta => "import.meta",
+            }),
+            Expr::Await(a) => concat([text("await "), self.expr(&a.arg)]),
+            Expr::Paren(p) => concat([text("("), self.expr(&p.expr), text(")")]),
+            Expr::JSXElement(el) => self.jsx_element(el),
+            Expr::JSXFragment(f) => self.jsx_fragment(f),
+            Expr::TsTypeAssertion(t) => concat([
+                text("<"),
+                self.emit(&*t.type_ann),
+                text(">"),
+                self.expr(&t.expr),
+            ]),
+            Expr::TsConstAssertion(t) => concat([self.expr(&t.expr), text(" as const")]),
+            Expr::TsAs(t) => concat([self.expr(&t.expr), text(" as "), self.emit(&*t.type_ann)]),
+            Expr::TsSatisfies(t) => concat([
+                self.expr(&t.expr),
+                text(" satisfies "),
+                self.emit(&*t.type_ann),
+            ]),
+            Expr::TsInstantiation(t) => {
+                concat([self.expr(&t.expr), self.type_args(Some(&t.type_args))])
+            }
+            Expr::PrivateName(p) => text(format!("#{}", p.name)),
+            Expr::Pipeline(p) => {
+                let mut rest = Vec::new();
+                let mut body = &*p.body;
+                while let Expr::Pipeline(p) = body {
+                    rest.push(concat([line(), text("|> "), self.expr(&p.head)]));
+                    body = &p.body;
+                }
+                rest.push(concat([line(), text("|> "), self.expr(body)]));
+
+                group(concat([self.expr(&p.head), indent(Doc::Concat(rest))]))
+            }
+            Expr::TopicRef(..) => text("%"),
+            Expr::JSXMember(..)
+            | Expr::JSXNamespacedName(..)
+            | Expr::JSXEmpty(..)
+            | Expr::Invalid(..) => self.emit(e),
+        }
+    }
+
+    /// Prints `left op right`, breaking after the operator if the right hand
+    /// side does not fit.
+    pub fn assignment(&self, left: Doc, op: impl Into<Doc>, right: &Expr) -> Doc {
+        if !breaks_after_operator(right) {
+            return concat([left, op.into(), text(" "), self.jsx_or_expr(right)]);
+        }
+
+        let right = match right {
+            Expr::Bin(b) => self.list_item(right, || {
+                let (first, rest) = self.binary_parts(b);
+                group(concat([first, Doc::Concat(rest)]))
+            }),
+            _ => self.expr(right),
+        };
+
+        group(concat([
+            left,
+            op.into(),
+            group(indent(concat([line(), right]))),
+        ]))
+    }
+
+    /// Flattens operands of the same precedence, so that `a && b && c` is
+    /// broken at each operator.
+    fn binary_parts(&self, b: &BinExpr) -> (Doc, Vec<Doc>) {
+        let (first, mut rest) = match &*b.left {
+            Expr::Bin(left)
+                if left.op.precedence() == b.op.precedence()
+                    && b.op != op!("**")
+                    && !needs_parens(b.op, left.op) =>
+            {
+                let leading = self.leading_comments(left.span.lo);
+                let (first, mut rest) = self.binary_parts(left);
+                rest.push(self.trailing_comments(left.span.hi));
+                (concat([leading, first]), rest)
+            }
+            left => (self.operand(left, b.op), Vec::new()),
+        };
+
+        rest.push(text(format!(" {}", b.op.as_str())));
+        rest.push(line());
+        rest.push(self.operand(&b.right, b.op));
+
+        (first, rest)
+    }
+
+    /// Adds clarifying parentheses, like `(a && b) || c`.
+    fn operand(&self, e: &Expr, parent: BinaryOp) -> Doc {
+        match e {
+            Expr::Bin(child) if needs_parens(parent, child.op) => {
+                concat([text("("), self.expr(e), text(")")])
+            }
+            _ => self.expr(e),
+        }
+    }
+
+    fn assign_target(&self, target: &AssignTarget) -> Doc {
+        match target {
+            AssignTarget::Simple(s) => match s {
+                SimpleAssignTarget::Ident(i) => self.binding_ident(i),
+                SimpleAssignTarget::Member(m) => {
+                    let mut links = vec![Link::Prop {
+                        optional: false,
+                        prop: &m.prop,
+                    }];
+                    let head = flatten(&m.obj, &mut links);
+                    self.chain(head, links)
+                }
+                SimpleAssignTarget::SuperProp(s) => self.super_prop(s),
+                SimpleAssignTarget::Paren(p) => concat([text("("), self.expr(&p.expr), text(")")]),
+                SimpleAssignTarget::OptChain(o) => {
+                    let mut links = Vec::new();
+                    let next = push_opt_chain(o, &mut links);
+                    let head = flatten(next, &mut links);
+                    self.chain(head, links)
+                }
+                SimpleAssignTarget::TsAs(..)
+                | SimpleAssignTarget::TsSatisfies(..)
+                | SimpleAssignTarget::TsNonNull(..)
+                | SimpleAssignTarget::TsTypeAssertion(..)
+                | SimpleAssignTarget::TsInstantiation(..)
+                | SimpleAssignTarget::Invalid(..) => self.emit(s),
+            },
+            AssignTarget::Pat(p) => match p {
+                AssignTargetPat::Array(a) => self.array_pat(a),
+                AssignTargetPat::Object(o) => self.object_pat(o, false),
+                AssignTargetPat::Invalid(..) => self.emit(p),
+            },
+        }
+    }
+
+    fn super_prop(&self, s: &SuperPropExpr) -> Doc {
+        match &s.prop {
+            SuperProp::Ident(i) => concat([text("super."), self.ident(&i.sym)]),
+            SuperProp::Computed(c) => concat([text("super["), self.expr(&c.expr), text("]")]),
+        }
+    }
+
+    fn unary(&self, u: &UnaryExpr) -> Doc {
+        let op = u.op.as_str();
+        let needs_space = match u.op {
+            op!("typeof") | op!("void") | op!("delete") => true,
+            // `- -x` and `+ +x`.
+            op!(unary, "-") | op!(unary, "+") => match &*u.arg {
+                Expr::Unary(arg) => arg.op == u.op,
+                Expr::Update(arg) => arg.prefix && arg.op.as_str().starts_with(op),
+                _ => false,
+            },
+            _ => false,
+        };
+
+        concat([
+            text(op),
+            if needs_space { text(" ") } else { Doc::nil() },
+            self.expr(&u.arg),
+        ])
+    }
+
+    /// Prints member chains like `a.b().c()`. Long chains with many calls
+    /// are printed with one call per line.
+    fn chain(&self, head: &Expr, links: Vec<Link>) -> Doc {
+        let head_doc = match head {
+            Expr::Call(CallExpr {
+                callee: Callee::Super(..),
+                args,
+                ..
+            }) => concat([text("super"), self.args(args)]),
+            Expr::Call(CallExpr {
+                callee: Callee::Import(import),
+                args,
+                ..
+            }) => concat([
+                text(match import.phase {
+                    ImportPhase::Evaluation => "import",
+                    ImportPhase::Source => "import.source",
+                    ImportPhase::Defer => "import.defer",
+                }),
+                self.args(args),
+            ]),
+            // `1..toString()` is printed as `(1).toString()`.
+            Expr::Lit(Lit::Num(n))
+                if !links.is_empty()
+                    && n.raw.as_deref().is_some_and(|raw| {
+                        super::lit::number(raw).bytes().all(|c| c.is_ascii_digit())
+                    }) =>
+            {
+                concat([text("("), self.expr(head), text(")")])
+            }
+            _ => self.expr(head),
+        };
+
+        let n_calls = links.iter().filter(|l| l.is_call()).count();
+        let has_complex_args = links.iter().any(|l| match l {
+            Link::Call(args, ..) => !args.iter().all(|a| is_simple_arg(&a.expr, 0)),
+            _ => false,
+        });
+
+        // The head and the computed accesses or calls right after it, like
+        // `a[0]()`, or the properties before the first call, like `a.b.c`
+        // in `a.b.c.d()`.
+        let mut links = links.into_iter().peekable();
+        let mut first = vec![head_doc];
+        while let Some(link) = links.next_if(|l| {
+            !matches!(
+                l,
+                Link::Prop {
+                    prop: MemberProp::Ident(..) | MemberProp::PrivateName(..),
+                    ..
+                }
+            )
+        }) {
+            first.push(self.link(link));
+        }
+        let rest = links.collect::<Vec<_>>();
+        let mut i = 0;
+        while i + 1 < rest.len() && !rest[i].is_call() && !rest[i + 1].is_call() {
+            i += 1;
+        }
+        let mut rest = rest.into_iter();
+        for link in rest.by_ref().take(i) {
+            first.push(self.link(link));
+        }
+
+        let mut groups: Vec<Vec<Doc>> = Vec::new();
+        let mut seen_call = false;
+        for link in rest {
+            if groups.is_empty() || (seen_call && !link.is_call()) {
+                groups.push(Vec::new());
+                seen_call = false;
+            }
+            seen_call |= link.is_call();
+            groups.last_mut().unwrap().push(self.link(link));
+        }
+        let mut groups = groups.into_iter().map(Doc::Concat).collect::<Vec<_>>();
+
+        if n_calls == 0 || groups.is_empty() {
+            first.extend(groups);
+            return Doc::Concat(first);
+        }
+
+        // `this.foo()` or `$.foo()` is kept on the first line.
+        let should_merge = first.len() == 1
+            && match head {
+                Expr::This(..) => true,
+                Expr::Ident(i) => {
+                    i.sym.starts_with(|c: char| c.is_ascii_uppercase())
+                        || i.sym.chars().all(|c| c == '$' || c == '_')
+                }
+                _ => false,
+            };
+
+        let one_line = concat(first.iter().cloned().chain(groups.iter().cloned()));
+        let cutoff = if should_merge { 3 } else { 2 };
+        if groups.len() < cutoff {
+            return group(one_line);
+        }
+
+        if should_merge {
+            first.push(groups.remove(0));
+        }
+        let should_break = (n_calls > 2 && has_complex_args)
+            || groups[..groups.len() - 1].iter().any(Doc::will_break);
+        let expanded = concat([
+            Doc::Concat(first),
+            indent(Doc::Concat(
+                groups.into_iter().flat_map(|g| [hardline(), g]).collect(),
+            )),
+        ]);
+
+        if should_break {
+            return group(expanded);
+        }
+
+        concat([
+            if one_line.will_break() {
+                Doc::BreakParent
+            } else {
+                Doc::nil()
+            },
+            conditional_group(vec![one_line, expanded]),
+        ])
+    }
+
+    fn link(&self, link: Link) -> Doc {
+        match link {
+            Link::Prop { optional, prop } => {
+                let dot = if optional { "?." } else { "." };
+                match prop {
+                    MemberProp::Ident(i) => concat([text(dot), self.ident(&i.sym)]),
+                    MemberProp::PrivateName(p) => text(format!("{dot}#{}", p.name)),
+                    MemberProp::Computed(c) => concat([
+                        text(if optional { "?.[" } else { "[" }),
+                        self.expr(&c.expr),
+                        text("]"),
+                    ]),
+                }
+            }
+            Link::Call(args, type_args, optional) => concat([
+                if optional { text("?.") } else { Doc::nil() },
+                self.type_args(type_args),
+                self.args(args),
+            ]),
+            Link::NonNull => text("!"),
+        }
+    }
+
+    /// Prints call arguments. The last argument is "hugged" if it is a
+    /// function or an object, so that `foo(a, () => {` stays on one line.
+    fn args(&self, args: &[ExprOrSpread]) -> Doc {
+        let printed = args
+            .iter()
+            .map(|a| {
+                self.list_item(a, || {
+                    concat([
+                        if a.spread.is_some() {
+                            text("...")
+                        } else {
+                            Doc::nil()
+                        },
+                        self.expr(&a.expr),
+                    ])
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let hugged = if should_hug_last_arg(args) {
+            printed.len() - 1
+        } else if should_hug_first_arg(args) {
+            0
+        } else {
+            return self.bracketed("(", printed, Some(CommaKind::All), ")");
+        };
+
+        let all_broken_out = || {
+            self.bracketed("(", printed.clone(), Some(CommaKind::All), ")")
+                .into_broken()
+        };
+        let others_break = printed
+            .iter()
+            .enumerate()
+            .any(|(i, arg)| i != hugged && arg.will_break());
+        if others_break {
+            return all_broken_out();
+        }
+
+        let hug = |expanded: bool| {
+            let args = printed.iter().enumerate().map(|(i, arg)| {
+                if i == hugged && expanded {
+                    broken_group(arg.clone())
+                } else {
+                    arg.clone()
+                }
+            });
+            concat([text("("), join(args, || text(", ")), text(")")])
+        };
+
+        concat([
+            if printed[hugged].will_break() {
+                Doc::BreakParent
+            } else {
+                Doc::nil()
+            },
+            conditional_group(vec![hug(false), hug(true), all_broken_out()]),
+        ])
+    }
+
+    fn arrow(&self, a: &ArrowExpr) -> Doc {
+        let params = match &*a.params {
+            [Pat::Ident(i)]
+                if self.cfg.arrow_parens == ArrowParens::Avoid
+                    && i.type_ann.is_none()
+                    && !i.id.optional
+                    && a.type_params.is_none()
+                    && a.return_type.is_none() =>
+            {
+                self.binding_ident(i)
+            }
+            params => self.params(
+                params
+                    .iter()
+                    .map(|p| self.list_item(p, || self.pat(p)))
+                    .collect(),
+                params.last().is_some_and(Pat::is_rest),
+                params.len() == 1 && should_hug(&params[0]),
+            ),
+        };
+
+        let head = concat([
+            if a.is_async {
+                text("async ")
+            } else {
+                Doc::nil()
+            },
+            self.type_params(a.type_params.as_deref()),
+            params,
+            self.return_type(a.return_type.as_deref()),
+            text(" =>"),
+        ]);
+
+        let body = match &*a.body {
+            BlockStmtOrExpr::BlockStmt(b) => concat([text(" "), self.block(b)]),
+            BlockStmtOrExpr::Expr(e) => {
+                let same_line = match &**e {
+                    Expr::Object(..)
+                    | Expr::Array(..)
+                    | Expr::Tpl(..)
+                    | Expr::TaggedTpl(..)
+                    | Expr::Arrow(..)
+                    | Expr::JSXElement(..)
+                    | Expr::JSXFragment(..) => true,
+                    Expr::Paren(p) => matches!(
+                        &*p.expr,
+                        Expr::Object(..) | Expr::JSXElement(..) | Expr::JSXFragment(..)
+                    ),
+                    _ => false,
+                };
+                if same_line {
+                    concat([text(" "), self.jsx_or_expr(e)])
+                } else {
+                    let body = self.expr(e);
+                    // Calls with functions, like `() => foo(() => {})`, break
+                    // in the arguments instead.
+                    if body.will_break()
+                        && matches!(&**e, Expr::Call(..) | Expr::New(..) | Expr::OptChain(..))
+                    {
+                        concat([text(" "), body])
+                    } else {
+                        group(indent(concat([line(), body])))
+                    }
+                }
+            }
+        };
+
+        concat([head, body])
+    }
+
+    pub fn object(&self, o: &ObjectLit) -> Doc {
+        if o.props.is_empty() {
+            return match self.dangling(o.span) {
+                Some(rest) => concat([
+                    text("{"),
+                    indent(concat([hardline(), rest])),
+                    hardline(),
+                    text("}"),
+                ]),
+                None => text("{}"),
+            };
+        }
+
+        // Objects are kept expanded if there is a line break after `{`.
+        let should_break = self.text(o.span.lo, o.props[0].span().lo).contains('\n');
+
+        let mut prev_hi = None;
+        let props = o
+            .props
+            .iter()
+            .map(|p| {
+                let doc = self.list_item(p, || self.prop(p));
+                let span = p.span();
+                let blank = prev_hi.is_some_and(|hi| self.has_blank_line(hi, span.lo));
+                prev_hi = Some(span.hi);
+                if blank {
+                    concat([softline(), doc])
+                } else {
+                    doc
+                }
+            })
+            .collect();
+
+        self.object_like(props, false, should_break)
+    }
+
+    fn prop(&self, p: &PropOrSpread) -> Doc {
+        let p = match p {
+            PropOrSpread::Spread(s) => return concat([text("..."), self.expr(&s.expr)]),
+            PropOrSpread::Prop(p) => p,
+        };
+
+        match &**p {
+            Prop::Shorthand(i) => self.ident(&i.sym),
+            Prop::KeyValue(kv) => self.assignment(self.prop_name(&kv.key), ":", &kv.value),
+            Prop::Assign(a) => concat([self.ident(&a.key.sym), text(" = "), self.expr(&a.value)]),
+            Prop::Getter(g) => concat([
+                text("get "),
+                self.prop_name(&g.key),
+                text("()"),
+                self.return_type(g.type_ann.as_deref()),
+                self.function_body(g.body.as_ref()),
+            ]),
+            Prop::Setter(s) => concat([
+                text("set "),
+                self.prop_name(&s.key),
+                text("("),
+                match &s.this_param {
+                    Some(this) => concat([self.pat(this), text(", ")]),
+                    None => Doc::nil(),
+                },
+                self.pat(&s.param),
+                text(")"),
+                self.function_body(s.body.as_ref()),
+            ]),
+            Prop::Method(m) => concat([
+                self.method_head(MethodKind::Method, &m.function, self.prop_name(&m.key)),
+                self.signature(&m.function),
+                self.function_body(m.function.body.as_ref()),
+            ]),
+        }
+    }
+
+    fn array(&self, a: &ArrayLit) -> Doc {
+        if a.elems.is_empty() {
+            return match self.dangling(a.span) {
+                Some(rest) => concat([
+                    text("["),
+                    indent(concat([hardline(), rest])),
+                    hardline(),
+                    text("]"),
+                ]),
+                None => text("[]"),
+            };
+        }
+
+        let elems = a
+            .elems
+            .iter()
+            .map(|e| {
+                e.as_ref().map(|e| {
+                    self.list_item(e, || {
+                        concat([
+                            if e.spread.is_some() {
+                                text("...")
+                            } else {
+                                Doc::nil()
+                            },
+                            self.expr(&e.expr),
+                        ])
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Arrays of numbers are filled, like text.
+        let is_concise = a.elems.len() > 1
+            && a.elems.iter().all(|e| {
+                e.as_ref().is_some_and(|e| {
+                    e.spread.is_none()
+                        && match &*e.expr {
+                            Expr::Lit(Lit::Num(..)) => true,
+                            Expr::Unary(UnaryExpr {
+                                op: op!(unary, "-") | op!(unary, "+"),
+                                arg,
+                                ..
+                            }) => matches!(&**arg, Expr::Lit(Lit::Num(..))),
+                            _ => false,
+                        }
+                })
+            });
+        if is_concise {
+            let mut parts = Vec::new();
+            for (i, elem) in elems.into_iter().flatten().enumerate() {
+                if i != 0 {
+                    parts.push(concat([text(","), line()]));
+                }
+                parts.push(elem);
+            }
+            return group(concat([
+                text("["),
+                indent(concat([softline(), fill(parts)])),
+                self.trailing_comma(CommaKind::Es5),
+                softline(),
+                text("]"),
+            ]));
+        }
+
+        // A list of objects or arrays is printed one per line.
+        let should_break = a.elems.len() > 1
+            && a.elems.iter().all(|e| {
+                e.as_ref().is_some_and(|e| {
+                    e.spread.is_none()
+                        && match (&*e.expr, a.elems[0].as_ref().map(|e| &*e.expr)) {
+                            (Expr::Object(o), Some(Expr::Object(..))) => o.props.len() > 1,
+                            (Expr::Array(a), Some(Expr::Array(..))) => a.elems.len() > 1,
+                            _ => false,
+                        }
+                })
+            });
+
+        self.array_like(elems, false, should_break)
+    }
+
+    /// Template literals are printed as is, with the embedded expressions
+    /// on a single line.
+    fn tpl(&self, t: &Tpl) -> Doc {
+        let mut buf = String::from("`");
+        for (i, quasi) in t.quasis.iter().enumerate() {
+            buf.push_str(&quasi.raw);
+            if let Some(e) = t.exprs.get(i) {
+                buf.push_str("${");
+                buf.push_str(&self.flat(self.expr(e)));
+                buf.push('}');
+            }
+        }
+        buf.push('`');
+
+        text(buf)
+    }
+
+    fn flat(&self, mut doc: Doc) -> String {
+        propagate_breaks(&mut doc);
+
+        Layout {
+            width: usize::MAX / 2,
+            indent: self.indent_str,
+        }
+        .print(&doc)
+    }
+}
+
+/// Collects the links of a member chain in reverse order and returns the
+/// head.
+fn flatten<'e>(mut e: &'e Expr, links: &mut Vec<Link<'e>>) -> &'e Expr {
+    loop {
+        e = match e {
+            Expr::Member(m) => {
+                links.push(Link::Prop {
+                    optional: false,
+                    prop: &m.prop,
+                });
+                &m.obj
+            }
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                type_args,
+                ..
+            }) => {
+                links.push(Link::Call(args, type_args.as_deref(), false));
+                callee
+            }
+            Expr::OptChain(o) => push_opt_chain(o, links),
+            Expr::TsNonNull(n) => {
+                links.push(Link::NonNull);
+                &n.expr
+            }
+            _ => break,
+        };
+    }
+
+    links.reverse();
+    e
+}
+
+fn push_opt_chain<'e>(o: &'e OptChainExpr, links: &mut Vec<Link<'e>>) -> &'e Expr {
+    match &*o.base {
+        OptChainBase::Member(m) => {
+            links.push(Link::Prop {
+                optional: o.optional,
+                prop: &m.prop,
+            });
+            &m.obj
+        }
+        OptChainBase::Call(c) => {
+            links.push(Link::Call(&c.args, c.type_args.as_deref(), o.optional));
+            &c.callee
+        }
+    }
+}
+
+fn needs_parens(parent: BinaryOp, child: BinaryOp) -> bool {
+    let is_logical = |op| matches!(op, op!("&&") | op!("||"));
+    let is_bitwise = |op| {
+        matches!(
+            op,
+            op!("|") | op!("^") | op!("&") | op!("<<") | op!(">>") | op!(">>>")
+        )
+    };
+    let is_multiplicative = |op| matches!(op, op!("*") | op!("/") | op!("%"));
+
+    if is_logical(parent) && is_logical(child) {
+        return parent != child;
+    }
+    if is_bitwise(parent) {
+        return parent != child;
+    }
+    // `a % b * c` and `a * b / c`.
+    if is_multiplicative(parent) && is_multiplicative(child) {
+        return parent != child;
+    }
+
+    child == op!("%") && matches!(parent, op!(bin, "+") | op!(bin, "-"))
+}
+
+/// Returns true if a line break after `=` is preferred to breaking the right
+/// hand side.
+fn breaks_after_operator(right: &Expr) -> bool {
+    match right {
+        // `a = b || {}` breaks in the object.
+        Expr::Bin(b) => {
+            !(b.op.may_short_circuit()
+                && match &*b.right {
+                    Expr::Object(o) => !o.props.is_empty(),
+                    Expr::Array(a) => !a.elems.is_empty(),
+                    _ => false,
+                })
+        }
+        Expr::Cond(c) => matches!(&*c.test, Expr::Bin(..)),
+        Expr::Lit(Lit::Str(..)) | Expr::Ident(..) => true,
+        Expr::Member(m) => is_member_chain(&m.obj),
+        _ => false,
+    }
+}
+
+fn is_member_chain(e: &Expr) -> bool {
+    match e {
+        Expr::Member(m) => is_member_chain(&m.obj),
+        Expr::Ident(..) | Expr::This(..) => true,
+        _ => false,
+    }
+}
+
+fn is_simple_arg(e: &Expr, depth: usize) -> bool {
+    match e {
+        Expr::Lit(..) | Expr::Ident(..) | Expr::This(..) | Expr::PrivateName(..) => true,
+        Expr::MetaProp(..) => true,
+        Expr::Tpl(t) => t.exprs.iter().all(|e| is_simple_arg(e, depth)),
+        Expr::Object(o) => o.props.iter().all(|p| match p {
+            PropOrSpread::Spread(s) => is_simple_arg(&s.expr, depth),
+            PropOrSpread::Prop(p) => match &**p {
+                Prop::Shorthand(..) => true,
+                Prop::KeyValue(kv) => !kv.key.is_computed() && is_simple_arg(&kv.value, depth),
+                _ => false,
+            },
+        }),
+        Expr::Array(a) => a
+            .elems
+            .iter()
+            .all(|e| e.as_ref().map_or(true, |e| is_simple_arg(&e.expr, depth))),
+        Expr::Unary(u) => {
+            matches!(u.op, op!("!") | op!(unary, "-")) && is_simple_arg(&u.arg, depth)
+        }
+        Expr::Member(m) => is_simple_arg(&m.obj, depth),
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => {
+            depth < 2
+                && is_simple_arg(callee, depth)
+                && args.iter().all(|a| is_simple_arg(&a.expr, depth + 1))
+        }
+        _ => false,
+    }
+}
+
+/// Returns true for arguments which can be broken inside, like functions
+/// or non-empty objects.
+fn is_huggable(e: &Expr) -> bool {
+    match e {
+        Expr::Fn(..) | Expr::Arrow(..) => true,
+        Expr::Object(o) => !o.props.is_empty(),
+        Expr::Array(a) => !a.elems.is_empty(),
+        _ => false,
+    }
+}
+
+fn should_hug_last_arg(args: &[ExprOrSpread]) -> bool {
+    let Some((last, rest)) = args.split_last() else {
+        return false;
+    };
+
+    last.spread.is_none() && is_huggable(&last.expr) && rest.iter().all(|a| !is_huggable(&a.expr))
+}
+
+/// `setTimeout(function () {}, 500)`.
+fn should_hug_first_arg(args: &[ExprOrSpread]) -> bool {
+    let [first, second] = args else {
+        return false;
+    };
+
+    // `useEffect(() => {}, [deps])`
+    if let (Expr::Arrow(arrow), Expr::Array(deps)) = (&*first.expr, &*second.expr) {
+        if arrow.params.is_empty()
+            && arrow.body.is_block_stmt()
+            && deps
+                .elems
+                .iter()
+                .flatten()
+                .all(|e| is_simple_arg(&e.expr, 0))
+        {
+            return true;
+        }
+    }
+
+    first.spread.is_none()
+        && second.spread.is_none()
+        && match &*first.expr {
+            Expr::Fn(..) => true,
+            Expr::Arrow(a) => a.body.is_block_stmt(),
+            _ => false,
+        }
+        && !is_huggable(&second.expr)
+        && is_simple_arg(&second.expr, 0)
+}
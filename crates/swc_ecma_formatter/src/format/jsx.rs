@@ -0,0 +1,322 @@
+use swc_common::comments::CommentKind;
+use swc_ecma_ast::*;
+
+use super::Formatter;
+use crate::doc::*;
+
+/// Whitespace between children of a JSX element.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Sep {
+    /// No whitespace, like `a<b />`, or whitespace with a line break, which
+    /// is removed by JSX transforms.
+    None,
+    /// Whitespace without line breaks, which is significant.
+    Space,
+}
+
+/// Builds the parts of a [Doc::Fill] for children of an element.
+struct Children {
+    parts: Vec<Doc>,
+    pending: Option<Sep>,
+    /// Whether the last child is an element or an expression.
+    last_is_tag: bool,
+}
+
+impl Children {
+    fn push(&mut self, doc: Doc, is_tag: bool) {
+        let pending = self.pending.take();
+
+        if self.parts.is_empty() {
+            if pending == Some(Sep::Space) {
+                self.parts.push(jsx_whitespace());
+                self.parts.push(softline());
+            }
+        } else {
+            let both_tags = self.last_is_tag && is_tag;
+            self.parts.push(match pending.unwrap_or(Sep::None) {
+                // Elements are printed on their own lines.
+                Sep::None if both_tags => hardline(),
+                Sep::None => softline(),
+                Sep::Space if both_tags => concat([text("{\" \"}"), hardline()]),
+                Sep::Space => if_break(concat([jsx_whitespace(), softline()]), " "),
+            });
+        }
+
+        self.parts.push(doc);
+        self.last_is_tag = is_tag;
+    }
+
+    fn whitespace(&mut self, ws: &str) {
+        if ws.is_empty() {
+            return;
+        }
+
+        if !ws.contains('\n') {
+            self.pending = Some(Sep::Space);
+        } else if self.pending.is_none() {
+            self.pending = Some(Sep::None);
+        }
+    }
+
+    fn finish(mut self) -> Vec<Doc> {
+        if self.pending == Some(Sep::Space) && !self.parts.is_empty() {
+            self.parts.push(softline());
+            self.parts.push(jsx_whitespace());
+        }
+        self.parts
+    }
+}
+
+/// A space which is printed as `{" "}` at the end of a line.
+fn jsx_whitespace() -> Doc {
+    if_break("{\" \"}", " ")
+}
+
+fn is_jsx_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+impl Formatter<'_> {
+    /// Prints an expression, wrapping multiline JSX in parentheses.
+    pub fn jsx_or_expr(&self, e: &Expr) -> Doc {
+        let mut inner = e;
+        while let Expr::Paren(p) = inner {
+            inner = &p.expr;
+        }
+        if !matches!(inner, Expr::JSXElement(..) | Expr::JSXFragment(..)) {
+            return self.expr(e);
+        }
+
+        group(concat([
+            if_break("(", ""),
+            indent(concat([softline(), self.expr(inner)])),
+            softline(),
+            if_break(")", ""),
+        ]))
+    }
+
+    pub fn jsx_element(&self, el: &JSXElement) -> Doc {
+        let opening = self.jsx_opening(&el.opening);
+        if el.opening.self_closing {
+            return opening;
+        }
+
+        let closing = concat([
+            text("</"),
+            self.jsx_element_name(&el.opening.name),
+            text(">"),
+        ]);
+        let force_break = el.opening.attrs.len() > 1;
+
+        self.jsx_with_children(opening, &el.children, closing, force_break)
+    }
+
+    pub fn jsx_fragment(&self, f: &JSXFragment) -> Doc {
+        self.jsx_with_children(text("<>"), &f.children, text("</>"), false)
+    }
+
+    fn jsx_with_children(
+        &self,
+        opening: Doc,
+        children: &[JSXElementChild],
+        closing: Doc,
+        force_break: bool,
+    ) -> Doc {
+        let mut builder = Children {
+            parts: Vec::new(),
+            pending: None,
+            last_is_tag: false,
+        };
+        let mut n_exprs = 0;
+        let mut has_tag = false;
+
+        for child in children {
+            match child {
+                JSXElementChild::JSXText(t) => {
+                    // `//` and `/*` in JSX text are not comments.
+                    self.take_comments_in(t.span);
+
+                    let raw = &*t.raw;
+                    let trimmed = raw.trim_start_matches(is_jsx_whitespace);
+                    builder.whitespace(&raw[..raw.len() - trimmed.len()]);
+
+                    let mut words = trimmed.split(is_jsx_whitespace).filter(|w| !w.is_empty());
+                    if let Some(word) = words.next() {
+                        builder.push(text(word.to_string()), false);
+                        for word in words {
+                            builder.parts.push(line());
+                            builder.parts.push(text(word.to_string()));
+                        }
+
+                        let rest = trimmed.trim_end_matches(is_jsx_whitespace);
+                        builder.pending = Some(Sep::None);
+                        builder.whitespace(&trimmed[rest.len()..]);
+                    }
+                }
+                JSXElementChild::JSXExprContainer(c) => {
+                    n_exprs += 1;
+                    builder.push(self.jsx_expr_container(c), true);
+                    builder.pending = Some(Sep::None);
+                }
+                JSXElementChild::JSXSpreadChild(s) => {
+                    builder.push(concat([text("{..."), self.expr(&s.expr), text("}")]), true);
+                    builder.pending = Some(Sep::None);
+                }
+                JSXElementChild::JSXElement(el) => {
+                    has_tag = true;
+                    builder.push(self.jsx_element(el), true);
+                    builder.pending = Some(Sep::None);
+                }
+                JSXElementChild::JSXFragment(f) => {
+                    has_tag = true;
+                    builder.push(self.jsx_fragment(f), true);
+                    builder.pending = Some(Sep::None);
+                }
+            }
+        }
+
+        let parts = builder.finish();
+        if parts.is_empty() {
+            return concat([opening, closing]);
+        }
+
+        let force_break = force_break || has_tag || n_exprs > 1 || opening.will_break();
+        let (sep, wrap): (fn() -> Doc, fn(Doc) -> Doc) = if force_break {
+            (hardline, broken_group)
+        } else {
+            (softline, group)
+        };
+
+        wrap(concat([
+            opening,
+            indent(concat([sep(), fill(parts)])),
+            sep(),
+            closing,
+        ]))
+    }
+
+    fn jsx_opening(&self, o: &JSXOpeningElement) -> Doc {
+        let head = concat([
+            text("<"),
+            self.jsx_element_name(&o.name),
+            self.type_args(o.type_args.as_deref()),
+        ]);
+        let end = if o.self_closing { " />" } else { ">" };
+
+        let attrs = o
+            .attrs
+            .iter()
+            .map(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(attr) => self.list_item(attr, || self.jsx_attr(attr)),
+                JSXAttrOrSpread::SpreadElement(s) => {
+                    concat([text("{..."), self.expr(&s.expr), text("}")])
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // `<div className="foo">` is never broken.
+        let is_single_str = match &*o.attrs {
+            [JSXAttrOrSpread::JSXAttr(JSXAttr {
+                value: Some(JSXAttrValue::Lit(Lit::Str(s))),
+                ..
+            })] => !s.value.contains('\n'),
+            _ => false,
+        };
+
+        if attrs.is_empty() {
+            return concat([head, text(end)]);
+        }
+        if is_single_str {
+            return group(concat([head, text(" "), Doc::Concat(attrs), text(end)]));
+        }
+
+        group(concat([
+            head,
+            indent(concat([line(), join(attrs, line)])),
+            if o.self_closing {
+                concat([line(), text("/>")])
+            } else {
+                concat([softline(), text(">")])
+            },
+        ]))
+    }
+
+    fn jsx_attr(&self, attr: &JSXAttr) -> Doc {
+        let name = match &attr.name {
+            JSXAttrName::Ident(i) => self.ident(&i.sym),
+            JSXAttrName::JSXNamespacedName(n) => text(format!("{}:{}", n.ns.sym, n.name.sym)),
+        };
+
+        let value = match &attr.value {
+            None => return name,
+            Some(JSXAttrValue::Lit(Lit::Str(s))) => match &s.raw {
+                Some(raw) => text(jsx_quote(raw, self.cfg.jsx_single_quote)),
+                None => self.str(s),
+            },
+            Some(JSXAttrValue::Lit(l)) => self.lit(l),
+            Some(JSXAttrValue::JSXExprContainer(c)) => self.jsx_expr_container(c),
+            Some(JSXAttrValue::JSXElement(el)) => self.jsx_element(el),
+            Some(JSXAttrValue::JSXFragment(f)) => self.jsx_fragment(f),
+        };
+
+        concat([name, text("="), value])
+    }
+
+    fn jsx_expr_container(&self, c: &JSXExprContainer) -> Doc {
+        match &c.expr {
+            JSXExpr::JSXEmptyExpr(..) => {
+                let comments = self.take_comments_in(c.span);
+                if comments.iter().any(|c| c.kind == CommentKind::Line) {
+                    return concat([
+                        text("{"),
+                        indent(concat([
+                            hardline(),
+                            join(comments.iter().map(|c| self.comment(c)), hardline),
+                        ])),
+                        hardline(),
+                        text("}"),
+                    ]);
+                }
+
+                concat([
+                    text("{"),
+                    join(comments.iter().map(|c| self.comment(c)), || text(" ")),
+                    text("}"),
+                ])
+            }
+            JSXExpr::Expr(e) => group(concat([text("{"), self.expr(e), text("}")])),
+        }
+    }
+
+    fn jsx_element_name(&self, name: &JSXElementName) -> Doc {
+        match name {
+            JSXElementName::Ident(i) => self.ident(&i.sym),
+            JSXElementName::JSXMemberExpr(m) => self.jsx_member_expr(m),
+            JSXElementName::JSXNamespacedName(n) => text(format!("{}:{}", n.ns.sym, n.name.sym)),
+        }
+    }
+
+    fn jsx_member_expr(&self, m: &JSXMemberExpr) -> Doc {
+        concat([
+            match &m.obj {
+                JSXObject::Ident(i) => self.ident(&i.sym),
+                JSXObject::JSXMemberExpr(m) => self.jsx_member_expr(m),
+            },
+            text("."),
+            self.ident(&m.prop.sym),
+        ])
+    }
+}
+
+/// Attribute strings cannot contain escapes, so the quote is changed only
+/// if the preferred one is not used in the value.
+fn jsx_quote(raw: &str, prefer_single: bool) -> String {
+    let content = &raw[1..raw.len() - 1];
+    let quote = if prefer_single { '\'' } else { '"' };
+
+    if content.contains(quote) {
+        raw.to_string()
+    } else {
+        format!("{quote}{content}{quote}")
+    }
+}
@@ -0,0 +1,153 @@
+use swc_ecma_ast::*;
+
+use super::Formatter;
+use crate::doc::*;
+
+impl Formatter<'_> {
+    pub fn lit(&self, lit: &Lit) -> Doc {
+        match lit {
+            Lit::Str(s) => self.str(s),
+            Lit::Bool(b) => text(if b.value { "true" } else { "false" }),
+            Lit::Null(..) => text("null"),
+            Lit::Num(n) => match &n.raw {
+                Some(raw) => text(number(raw)),
+                None => self.emit(n),
+            },
+            Lit::BigInt(n) => match &n.raw {
+                Some(raw) => text(raw.to_lowercase()),
+                None => self.emit(n),
+            },
+            Lit::Regex(r) => {
+                let mut flags = r.flags.chars().collect::<Vec<_>>();
+                flags.sort_unstable();
+                text(format!(
+                    "/{}/{}",
+                    r.exp,
+                    flags.into_iter().collect::<String>()
+                ))
+            }
+            Lit::JSXText(t) => text(t.raw.to_string()),
+        }
+    }
+
+    pub fn str(&self, s: &Str) -> Doc {
+        let Some(raw) = &s.raw else {
+            return self.emit(s);
+        };
+
+        text(quote(raw, self.cfg.single_quote))
+    }
+}
+
+/// Re-quotes a string literal with the preferred quote, unless that needs
+/// more escapes than the other one.
+pub(super) fn quote(raw: &str, prefer_single: bool) -> String {
+    let content = &raw[1..raw.len() - 1];
+
+    let (preferred, alternate) = if prefer_single {
+        ('\'', '"')
+    } else {
+        ('"', '\'')
+    };
+
+    let mut doubles = 0;
+    let mut singles = 0;
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' => doubles += 1,
+            '\'' => singles += 1,
+            _ => {}
+        }
+    }
+    // Escaped quotes count as well.
+    doubles += content.matches("\\\"").count();
+    singles += content.matches("\\'").count();
+
+    let (preferred_count, alternate_count) = if preferred == '"' {
+        (doubles, singles)
+    } else {
+        (singles, doubles)
+    };
+    let enclosing = if preferred_count > alternate_count {
+        alternate
+    } else {
+        preferred
+    };
+
+    let mut buf = String::with_capacity(raw.len());
+    buf.push(enclosing);
+
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                // Unnecessary escapes of the other quote.
+                Some(q @ ('"' | '\'')) if q != enclosing => buf.push(q),
+                Some(c) => {
+                    buf.push('\\');
+                    buf.push(c);
+                }
+                None => buf.push('\\'),
+            },
+            c if c == enclosing => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            c => buf.push(c),
+        }
+    }
+
+    buf.push(enclosing);
+    buf
+}
+
+/// Normalizes a number literal, following prettier.
+pub(super) fn number(raw: &str) -> String {
+    let raw = raw.to_ascii_lowercase();
+
+    if raw.starts_with("0x") || raw.starts_with("0o") || raw.starts_with("0b") {
+        return raw;
+    }
+    // Legacy octal literals.
+    if raw.len() > 1 && raw.starts_with('0') && raw.as_bytes()[1].is_ascii_digit() {
+        return raw;
+    }
+
+    let (mantissa, exponent) = match raw.split_once('e') {
+        Some((m, e)) => (m.to_string(), Some(e.to_string())),
+        None => (raw, None),
+    };
+
+    // Remove the unnecessary plus sign and zeroes of exponents.
+    let exponent = exponent.and_then(|e| {
+        let (sign, digits) = match e.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", e.strip_prefix('+').unwrap_or(&e)),
+        };
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            None
+        } else {
+            Some(format!("{sign}{digits}"))
+        }
+    });
+
+    let mut mantissa = mantissa;
+    if mantissa.starts_with('.') {
+        mantissa.insert(0, '0');
+    }
+    if mantissa.contains('.') {
+        // Remove trailing zeroes and the trailing dot.
+        let trimmed = mantissa.trim_end_matches('0');
+        mantissa = trimmed.strip_suffix('.').unwrap_or(trimmed).to_string();
+    }
+
+    match exponent {
+        Some(e) => format!("{mantissa}e{e}"),
+        None => mantissa,
+    }
+}
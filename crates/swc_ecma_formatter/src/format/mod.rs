@@ -0,0 +1,473 @@
+//! Conversion of the AST into a [Doc].
+
+use std::collections::BTreeSet;
+
+use swc_common::{
+    comments::{Comment, CommentKind, Comments, SingleThreadedComments},
+    sync::Lrc,
+    BytePos, SourceFile, SourceMap, Span, Spanned,
+};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter, Node};
+
+use self::writer::{LiteralWriter, SharedBuf};
+use crate::{doc::*, Config, TrailingComma};
+
+mod decl;
+mod expr;
+mod jsx;
+mod lit;
+mod module;
+mod pat;
+mod stmt;
+mod writer;
+
+pub(crate) struct Formatter<'a> {
+    pub cfg: &'a Config,
+    pub cm: Lrc<SourceMap>,
+    pub fm: &'a SourceFile,
+    pub comments: &'a SingleThreadedComments,
+    /// Positions of all comments, used to find comments which are not
+    /// attached to printed nodes.
+    comment_positions: BTreeSet<BytePos>,
+    /// Used for nodes printed by [Emitter].
+    indent_str: &'static str,
+}
+
+/// Where a trailing comma is allowed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CommaKind {
+    /// Objects, arrays and import or export specifiers.
+    Es5,
+    /// Function parameters and arguments.
+    All,
+}
+
+impl<'a> Formatter<'a> {
+    pub fn new(
+        cfg: &'a Config,
+        cm: Lrc<SourceMap>,
+        fm: &'a SourceFile,
+        comments: &'a SingleThreadedComments,
+        indent_str: &'static str,
+    ) -> Self {
+        let comment_positions = {
+            let (leading, trailing) = comments.borrow_all();
+            leading.keys().chain(trailing.keys()).copied().collect()
+        };
+
+        Formatter {
+            cfg,
+            cm,
+            fm,
+            comments,
+            comment_positions,
+            indent_str,
+        }
+    }
+
+    pub fn program(&self, program: &Program) -> Doc {
+        let body = match program {
+            Program::Module(m) => self.list(&m.body, |f, item| f.module_item(item)),
+            Program::Script(s) => self.list(&s.body, |f, stmt| f.stmt(stmt)),
+        };
+
+        let shebang = match program {
+            Program::Module(Module {
+                shebang: Some(shebang),
+                ..
+            })
+            | Program::Script(Script {
+                shebang: Some(shebang),
+                ..
+            }) => concat([text(format!("#!{shebang}")), hardline()]),
+            _ => Doc::nil(),
+        };
+
+        // Comments which are not attached to any node, like ones in an empty
+        // file.
+        let rest = self.dangling(Span::new(self.fm.start_pos, self.fm.end_pos));
+        let rest = match (body.is_nil(), rest) {
+            (_, None) => Doc::nil(),
+            (true, Some(rest)) => rest,
+            (false, Some(rest)) => concat([hardline(), rest]),
+        };
+
+        if body.is_nil() && rest.is_nil() {
+            return shebang;
+        }
+
+        concat([shebang, body, rest, hardline()])
+    }
+
+    /// Prints a list of statements, preserving blank lines between them.
+    pub fn list<T: Spanned>(&self, items: &[T], op: impl Fn(&Self, &T) -> Doc) -> Doc {
+        let mut parts = Vec::new();
+        let mut prev: Option<Span> = None;
+
+        for item in items {
+            let doc = op(self, item);
+            if doc.is_nil() {
+                continue;
+            }
+
+            if let Some(prev) = prev {
+                parts.push(hardline());
+                if self.has_blank_line(prev.hi, item.span().lo) {
+                    parts.push(hardline());
+                }
+            }
+            parts.push(doc);
+            prev = Some(item.span());
+        }
+
+        Doc::Concat(parts)
+    }
+
+    /// Returns true if there is an empty line between `hi`, the end of a
+    /// node, and the node starting at `lo`, including its leading comments.
+    pub fn has_blank_line(&self, hi: BytePos, lo: BytePos) -> bool {
+        let lo = self
+            .comments
+            .with_leading(lo, |comments| comments.first().map(|c| c.span.lo))
+            .unwrap_or(lo);
+
+        has_blank_line(self.text(hi, lo))
+    }
+
+    pub fn text(&self, lo: BytePos, hi: BytePos) -> &str {
+        if lo < self.fm.start_pos || hi > self.fm.end_pos || lo >= hi {
+            return "";
+        }
+        let start = self.fm.start_pos.0;
+        &self.fm.src[(lo.0 - start) as usize..(hi.0 - start) as usize]
+    }
+
+    /// Wraps a statement-like node with its comments.
+    pub fn with_comments(&self, span: Span, op: impl FnOnce() -> Doc) -> Doc {
+        let leading = self.leading_comments(span.lo);
+        // Comments at the end of a statement are taken before the last
+        // expression in it, which may end at the same position.
+        let trailing = self.comments.take_trailing(span.hi);
+        let doc = op();
+        let trailing = self.format_trailing(span.hi, trailing.unwrap_or_default());
+        let rest = self.stray_comments(span);
+
+        concat([leading, doc, trailing, rest])
+    }
+
+    /// Comments before a node. Comments on their own lines stay on their own
+    /// lines.
+    pub fn leading_comments(&self, pos: BytePos) -> Doc {
+        let Some(comments) = self.comments.take_leading(pos) else {
+            return Doc::nil();
+        };
+
+        let mut parts = Vec::new();
+        for (i, c) in comments.iter().enumerate() {
+            parts.push(self.comment(c));
+
+            let next = comments.get(i + 1).map_or(pos, |c| c.span.lo);
+            let between = self.text(c.span.hi, next);
+            if c.kind == CommentKind::Line || between.contains('\n') {
+                parts.push(hardline());
+                if has_blank_line(between) {
+                    parts.push(hardline());
+                }
+            } else {
+                parts.push(text(" "));
+            }
+        }
+
+        Doc::Concat(parts)
+    }
+
+    /// Comments after a node. Comments on the following lines, which are
+    /// attached to the last token of a file or a block, are printed on
+    /// their own lines.
+    pub fn trailing_comments(&self, pos: BytePos) -> Doc {
+        match self.comments.take_trailing(pos) {
+            Some(comments) => self.format_trailing(pos, comments),
+            None => Doc::nil(),
+        }
+    }
+
+    fn format_trailing(&self, pos: BytePos, comments: Vec<Comment>) -> Doc {
+        if comments.is_empty() {
+            return Doc::nil();
+        }
+
+        let (same_line, own_line): (Vec<_>, Vec<_>) = comments
+            .into_iter()
+            .partition(|c| !self.text(pos, c.span.lo).contains('\n'));
+
+        let mut parts = vec![self.inline_comments(same_line)];
+        let mut prev = pos;
+        for c in own_line {
+            let between = self.text(prev, c.span.lo);
+            let mut suffix = vec![hardline()];
+            if has_blank_line(between) {
+                suffix.push(hardline());
+            }
+            suffix.push(self.comment(&c));
+            parts.push(line_suffix(Doc::Concat(suffix)));
+            parts.push(Doc::BreakParent);
+            prev = c.span.hi;
+        }
+
+        Doc::Concat(parts)
+    }
+
+    /// Comments in `span` which are not printed yet, because they are not
+    /// attached to a node we print.
+    fn stray_comments(&self, span: Span) -> Doc {
+        let comments = self.take_comments_in(span);
+        if comments.is_empty() {
+            return Doc::nil();
+        }
+        self.inline_comments(comments)
+    }
+
+    fn inline_comments(&self, comments: Vec<Comment>) -> Doc {
+        let mut parts = Vec::new();
+        for c in comments {
+            match c.kind {
+                CommentKind::Line => {
+                    parts.push(line_suffix(concat([text(" "), self.comment(&c)])));
+                    parts.push(Doc::BreakParent);
+                }
+                CommentKind::Block => {
+                    parts.push(text(" "));
+                    parts.push(self.comment(&c));
+                }
+            }
+        }
+        Doc::Concat(parts)
+    }
+
+    /// Comments in `span` which are not printed yet, each on its own line.
+    /// This is used for comments in empty blocks and after the last
+    /// statement.
+    pub fn dangling(&self, span: Span) -> Option<Doc> {
+        let comments = self.take_comments_in(span);
+        if comments.is_empty() {
+            return None;
+        }
+
+        Some(join(comments.iter().map(|c| self.comment(c)), hardline))
+    }
+
+    pub fn take_comments_in(&self, span: Span) -> Vec<Comment> {
+        let mut comments = Vec::new();
+        for &pos in self.comment_positions.range(span.lo..=span.hi) {
+            comments.extend(self.comments.take_leading(pos).into_iter().flatten());
+            comments.extend(self.comments.take_trailing(pos).into_iter().flatten());
+        }
+        comments.sort_by_key(|c| c.span.lo);
+        comments
+    }
+
+    pub fn comment(&self, c: &Comment) -> Doc {
+        match c.kind {
+            CommentKind::Line => text(format!("//{}", c.text.trim_end())),
+            CommentKind::Block => {
+                let lines = c.text.lines().collect::<Vec<_>>();
+
+                // Re-indent JSDoc-style comments.
+                let (last, middle) = lines.split_last().unwrap_or((&"", &[]));
+                let is_jsdoc = lines.len() > 1
+                    && middle[1..].iter().all(|l| l.trim_start().starts_with('*'))
+                    && (last.trim().is_empty() || last.trim_start().starts_with('*'));
+                if is_jsdoc {
+                    let mut parts = vec![text(format!("/*{}", lines[0].trim_end()))];
+                    for l in &lines[1..] {
+                        parts.push(hardline());
+                        parts.push(text(format!(" {}", l.trim())));
+                    }
+                    parts.push(text(if last.trim().is_empty() { "*/" } else { " */" }));
+                    return Doc::Concat(parts);
+                }
+
+                text(format!("/*{}*/", c.text))
+            }
+        }
+    }
+
+    /// Prints a node using [Emitter], which is used for nodes without
+    /// dedicated formatting like most typescript types.
+    ///
+    /// The output is re-indented line by line, except for the lines inside
+    /// string and template literals, which are printed verbatim.
+    pub fn emit<N: Node>(&self, node: &N) -> Doc {
+        let buf = SharedBuf::default();
+        let literal_breaks = {
+            let mut wr = JsWriter::new(self.cm.clone(), "\n", buf.clone(), None);
+            wr.set_indent_str(self.indent_str);
+
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: self.cm.clone(),
+                comments: Some(self.comments as &dyn Comments),
+                wr: LiteralWriter {
+                    wr,
+                    buf: buf.clone(),
+                    literal_breaks: Vec::new(),
+                },
+            };
+            node.emit_with(&mut emitter)
+                .expect("failed to emit a node to a buffer");
+
+            emitter.wr.literal_breaks
+        };
+
+        let code = String::from_utf8(buf.take()).expect("codegen generated non-utf8 output");
+
+        let mut lines = Vec::new();
+        let mut current = String::new();
+        let mut pos = code.len() - code.trim_start().len();
+        for (i, l) in code.trim().split('\n').enumerate() {
+            if i > 0 {
+                if literal_breaks.binary_search(&(pos - 1)).is_ok() {
+                    current.push('\n');
+                } else {
+                    lines.push(text(current.trim_end().to_string()));
+                    current.clear();
+                }
+            }
+            current.push_str(l);
+            pos += l.len() + 1;
+        }
+        lines.push(text(current.trim_end().to_string()));
+
+        join(lines, hardline)
+    }
+
+    pub fn semi(&self) -> Doc {
+        if self.cfg.semi {
+            text(";")
+        } else {
+            Doc::nil()
+        }
+    }
+
+    pub fn trailing_comma(&self, kind: CommaKind) -> Doc {
+        match (self.cfg.trailing_comma, kind) {
+            (TrailingComma::All, _) | (TrailingComma::Es5, CommaKind::Es5) => if_break(",", ""),
+            _ => Doc::nil(),
+        }
+    }
+
+    /// `{ a }` or `{a}`.
+    pub fn bracket_line(&self) -> Doc {
+        if self.cfg.bracket_spacing {
+            line()
+        } else {
+            softline()
+        }
+    }
+
+    /// Prints a comma-separated list in brackets, on a single line if it
+    /// fits and one item per line otherwise.
+    pub fn bracketed(
+        &self,
+        open: &'static str,
+        items: Vec<Doc>,
+        comma: Option<CommaKind>,
+        close: &'static str,
+    ) -> Doc {
+        if items.is_empty() {
+            return concat([text(open), text(close)]);
+        }
+
+        group(concat([
+            text(open),
+            indent(concat([
+                softline(),
+                join(items, || concat([text(","), line()])),
+            ])),
+            comma.map_or(Doc::nil(), |kind| self.trailing_comma(kind)),
+            softline(),
+            text(close),
+        ]))
+    }
+
+    /// Prints items of a comma-separated list with their comments.
+    pub fn list_item<T: Spanned>(&self, item: &T, op: impl FnOnce() -> Doc) -> Doc {
+        let span = item.span();
+        let leading = self.leading_comments(span.lo);
+        let trailing = self.comments.take_trailing(span.hi);
+        let doc = op();
+        let trailing = self.format_trailing(span.hi, trailing.unwrap_or_default());
+
+        // Comments after the following comma, like `a, // comment`.
+        let rest = self.text(span.hi, self.fm.end_pos);
+        let after_comma = match rest.trim_start_matches([' ', '\t']).strip_prefix(',') {
+            Some(after) => {
+                let comma_hi = span.hi + BytePos((rest.len() - after.len()) as u32);
+                self.trailing_comments(comma_hi)
+            }
+            None => Doc::nil(),
+        };
+
+        concat([leading, doc, trailing, after_comma])
+    }
+
+    pub fn ident(&self, sym: &str) -> Doc {
+        text(sym.to_string())
+    }
+
+    pub fn decorators(&self, decorators: &[Decorator], same_line: bool) -> Doc {
+        if decorators.is_empty() {
+            return Doc::nil();
+        }
+
+        let sep = if same_line { line } else { hardline };
+        concat([
+            join(
+                decorators.iter().map(|d| {
+                    if is_simple_decorator(&d.expr) {
+                        concat([text("@"), self.expr(&d.expr)])
+                    } else {
+                        concat([text("@("), self.expr(&d.expr), text(")")])
+                    }
+                }),
+                sep,
+            ),
+            sep(),
+        ])
+    }
+}
+
+/// Returns true if a decorator can be printed without parentheses, like
+/// `@a.b` or `@a.b(c)`.
+fn is_simple_decorator(e: &Expr) -> bool {
+    fn is_member_chain(e: &Expr) -> bool {
+        match e {
+            Expr::Ident(..) => true,
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(..) | MemberProp::PrivateName(..),
+                ..
+            }) => is_member_chain(obj),
+            _ => false,
+        }
+    }
+
+    match e {
+        Expr::Paren(..) => true,
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => is_member_chain(callee),
+        _ => is_member_chain(e),
+    }
+}
+
+fn has_blank_line(s: &str) -> bool {
+    let mut lines = s.split('\n');
+    lines.next();
+    // The last segment is followed by the node.
+    let mut lines = lines.collect::<Vec<_>>();
+    lines.pop();
+    lines.iter().any(|l| l.trim().is_empty())
+}
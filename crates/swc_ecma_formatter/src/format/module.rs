@@ -0,0 +1,189 @@
+use swc_common::Spanned;
+use swc_ecma_ast::*;
+
+use super::Formatter;
+use crate::doc::*;
+
+impl Formatter<'_> {
+    pub fn module_item(&self, item: &ModuleItem) -> Doc {
+        match item {
+            ModuleItem::Stmt(stmt) => self.stmt(stmt),
+            ModuleItem::ModuleDecl(decl) => {
+                self.with_comments(decl.span(), || self.module_decl(decl))
+            }
+        }
+    }
+
+    fn module_decl(&self, decl: &ModuleDecl) -> Doc {
+        match decl {
+            ModuleDecl::Import(i) => self.import(i),
+            ModuleDecl::ExportDecl(e) => match &e.decl {
+                // `@dec export class Foo {}`
+                Decl::Class(c)
+                    if c.class
+                        .decorators
+                        .first()
+                        .is_some_and(|d| d.span.lo < e.span.lo) =>
+                {
+                    concat([
+                        self.decorators(&c.class.decorators, false),
+                        text("export "),
+                        if c.declare {
+                            text("declare ")
+                        } else {
+                            Doc::nil()
+                        },
+                        self.class_without_decorators(Some(&c.ident), &c.class),
+                    ])
+                }
+                decl => concat([text("export "), self.decl(decl)]),
+            },
+            ModuleDecl::ExportNamed(e) => self.named_export(e),
+            ModuleDecl::ExportDefaultDecl(e) => concat([
+                text("export default "),
+                match &e.decl {
+                    DefaultDecl::Class(c) => self.class(c.ident.as_ref(), &c.class),
+                    DefaultDecl::Fn(f) => self.function(f.ident.as_ref(), &f.function),
+                    DefaultDecl::TsInterfaceDecl(i) => self.emit(&**i),
+                },
+            ]),
+            ModuleDecl::ExportDefaultExpr(e) => concat([
+                text("export default "),
+                self.jsx_or_expr(&e.expr),
+                self.semi(),
+            ]),
+            ModuleDecl::ExportAll(e) => concat([
+                text(if e.type_only {
+                    "export type * from "
+                } else {
+                    "export * from "
+                }),
+                self.str(&e.src),
+                self.import_attributes(e.with.as_deref()),
+                self.semi(),
+            ]),
+            ModuleDecl::TsImportEquals(..)
+            | ModuleDecl::TsExportAssignment(..)
+            | ModuleDecl::TsNamespaceExport(..) => self.emit(decl),
+        }
+    }
+
+    fn import(&self, i: &ImportDecl) -> Doc {
+        let mut parts = vec![text("import")];
+        if i.type_only {
+            parts.push(text(" type"));
+        }
+        match i.phase {
+            ImportPhase::Evaluation => {}
+            ImportPhase::Source => parts.push(text(" source")),
+            ImportPhase::Defer => parts.push(text(" defer")),
+        }
+
+        let mut clauses = Vec::new();
+        let mut named = Vec::new();
+        for s in &i.specifiers {
+            match s {
+                ImportSpecifier::Default(d) => clauses.push(self.ident(&d.local.sym)),
+                ImportSpecifier::Namespace(n) => {
+                    clauses.push(concat([text("* as "), self.ident(&n.local.sym)]))
+                }
+                ImportSpecifier::Named(n) => named.push(self.list_item(n, || {
+                    concat([
+                        if n.is_type_only {
+                            text("type ")
+                        } else {
+                            Doc::nil()
+                        },
+                        match &n.imported {
+                            Some(imported) => {
+                                concat([self.module_export_name(imported), text(" as ")])
+                            }
+                            None => Doc::nil(),
+                        },
+                        self.ident(&n.local.sym),
+                    ])
+                })),
+            }
+        }
+
+        // `import {} from "a"` is kept, because it is not the same as
+        // `import "a"` in typescript.
+        let has_braces = self.text(i.span.lo, i.src.span.lo).contains('{');
+        if !named.is_empty() || (clauses.is_empty() && has_braces) {
+            clauses.push(self.object_like(named, false, false));
+        }
+
+        if !clauses.is_empty() {
+            parts.push(text(" "));
+            parts.push(join(clauses, || text(", ")));
+            parts.push(text(" from"));
+        }
+
+        parts.push(text(" "));
+        parts.push(self.str(&i.src));
+        parts.push(self.import_attributes(i.with.as_deref()));
+        parts.push(self.semi());
+
+        Doc::Concat(parts)
+    }
+
+    fn named_export(&self, e: &NamedExport) -> Doc {
+        let mut parts = vec![text(if e.type_only { "export type" } else { "export" })];
+
+        let mut clauses = Vec::new();
+        let mut named = Vec::new();
+        for s in &e.specifiers {
+            match s {
+                ExportSpecifier::Namespace(n) => {
+                    clauses.push(concat([text("* as "), self.module_export_name(&n.name)]))
+                }
+                ExportSpecifier::Default(d) => clauses.push(self.ident(&d.exported.sym)),
+                ExportSpecifier::Named(n) => named.push(self.list_item(n, || {
+                    concat([
+                        if n.is_type_only {
+                            text("type ")
+                        } else {
+                            Doc::nil()
+                        },
+                        self.module_export_name(&n.orig),
+                        match &n.exported {
+                            Some(exported) => {
+                                concat([text(" as "), self.module_export_name(exported)])
+                            }
+                            None => Doc::nil(),
+                        },
+                    ])
+                })),
+            }
+        }
+        if !named.is_empty() || clauses.is_empty() {
+            clauses.push(self.object_like(named, false, false));
+        }
+
+        parts.push(text(" "));
+        parts.push(join(clauses, || text(", ")));
+
+        if let Some(src) = &e.src {
+            parts.push(text(" from "));
+            parts.push(self.str(src));
+            parts.push(self.import_attributes(e.with.as_deref()));
+        }
+        parts.push(self.semi());
+
+        Doc::Concat(parts)
+    }
+
+    fn module_export_name(&self, name: &ModuleExportName) -> Doc {
+        match name {
+            ModuleExportName::Ident(i) => self.ident(&i.sym),
+            ModuleExportName::Str(s) => self.str(s),
+        }
+    }
+
+    fn import_attributes(&self, with: Option<&ObjectLit>) -> Doc {
+        match with {
+            Some(with) => concat([text(" with "), self.object(with)]),
+            None => Doc::nil(),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use swc_ecma_ast::*;
+
+use super::{CommaKind, Formatter};
+use crate::doc::*;
+
+impl Formatter<'_> {
+    pub fn pat(&self, pat: &Pat) -> Doc {
+        match pat {
+            Pat::Ident(i) => self.binding_ident(i),
+            Pat::Array(a) => self.array_pat(a),
+            Pat::Rest(r) => concat([
+                text("..."),
+                self.pat(&r.arg),
+                self.type_ann(r.type_ann.as_deref()),
+            ]),
+            Pat::Object(o) => self.object_pat(o, false),
+            Pat::Assign(a) => concat([self.pat(&a.left), text(" = "), self.expr(&a.right)]),
+            Pat::Expr(e) => self.expr(e),
+            Pat::Invalid(..) => self.emit(pat),
+        }
+    }
+
+    pub fn array_pat(&self, a: &ArrayPat) -> Doc {
+        concat([
+            self.array_like(
+                a.elems
+                    .iter()
+                    .map(|e| e.as_ref().map(|p| self.list_item(p, || self.pat(p))))
+                    .collect(),
+                a.elems
+                    .last()
+                    .is_some_and(|e| e.as_ref().is_some_and(Pat::is_rest)),
+                false,
+            ),
+            if a.optional { text("?") } else { Doc::nil() },
+            self.type_ann(a.type_ann.as_deref()),
+        ])
+    }
+
+    pub fn object_pat(&self, o: &ObjectPat, should_break: bool) -> Doc {
+        concat([
+            self.object_like(
+                o.props
+                    .iter()
+                    .map(|p| self.list_item(p, || self.object_pat_prop(p)))
+                    .collect(),
+                o.props.last().is_some_and(|p| p.is_rest()),
+                should_break,
+            ),
+            if o.optional { text("?") } else { Doc::nil() },
+            self.type_ann(o.type_ann.as_deref()),
+        ])
+    }
+
+    pub fn binding_ident(&self, i: &BindingIdent) -> Doc {
+        concat([
+            self.ident(&i.id.sym),
+            if i.id.optional { text("?") } else { Doc::nil() },
+            self.type_ann(i.type_ann.as_deref()),
+        ])
+    }
+
+    fn object_pat_prop(&self, p: &ObjectPatProp) -> Doc {
+        match p {
+            ObjectPatProp::KeyValue(p) => {
+                concat([self.prop_name(&p.key), text(": "), self.pat(&p.value)])
+            }
+            ObjectPatProp::Assign(p) => concat([
+                self.binding_ident(&p.key),
+                match &p.value {
+                    Some(value) => concat([text(" = "), self.expr(value)]),
+                    None => Doc::nil(),
+                },
+            ]),
+            ObjectPatProp::Rest(r) => concat([text("..."), self.pat(&r.arg)]),
+        }
+    }
+
+    /// Prints array literals and patterns. `None` is a hole.
+    pub fn array_like(
+        &self,
+        elems: Vec<Option<Doc>>,
+        ends_with_rest: bool,
+        should_break: bool,
+    ) -> Doc {
+        if elems.is_empty() {
+            return text("[]");
+        }
+
+        let ends_with_hole = elems.last().is_some_and(Option::is_none);
+        let elems = join(elems.into_iter().map(Option::unwrap_or_default), || {
+            concat([text(","), line()])
+        });
+
+        let doc = concat([
+            text("["),
+            indent(concat([softline(), elems])),
+            if ends_with_hole {
+                text(",")
+            } else if ends_with_rest {
+                Doc::nil()
+            } else {
+                self.trailing_comma(CommaKind::Es5)
+            },
+            softline(),
+            text("]"),
+        ]);
+
+        if should_break {
+            broken_group(doc)
+        } else {
+            group(doc)
+        }
+    }
+
+    /// Prints object literals and patterns.
+    pub fn object_like(&self, props: Vec<Doc>, ends_with_rest: bool, should_break: bool) -> Doc {
+        if props.is_empty() {
+            return text("{}");
+        }
+
+        let doc = concat([
+            text("{"),
+            indent(concat([
+                self.bracket_line(),
+                join(props, || concat([text(","), line()])),
+            ])),
+            if ends_with_rest {
+                Doc::nil()
+            } else {
+                self.trailing_comma(CommaKind::Es5)
+            },
+            self.bracket_line(),
+            text("}"),
+        ]);
+
+        if should_break {
+            broken_group(doc)
+        } else {
+            group(doc)
+        }
+    }
+}
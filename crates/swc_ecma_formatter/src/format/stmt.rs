@@ -0,0 +1,346 @@
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::*;
+
+use super::Formatter;
+use crate::doc::*;
+
+impl Formatter<'_> {
+    pub fn stmt(&self, stmt: &Stmt) -> Doc {
+        if let Stmt::Empty(..) = stmt {
+            return Doc::nil();
+        }
+
+        self.with_comments(stmt.span(), || self.stmt_inner(stmt))
+    }
+
+    fn stmt_inner(&self, stmt: &Stmt) -> Doc {
+        match stmt {
+            Stmt::Block(b) => self.block(b),
+            Stmt::Empty(..) => text(";"),
+            Stmt::Debugger(..) => concat([text("debugger"), self.semi()]),
+            Stmt::With(s) => concat([
+                text("with ("),
+                self.expr(&s.obj),
+                text(")"),
+                self.clause(&s.body, false),
+            ]),
+            Stmt::Return(s) => self.return_like("return", s.arg.as_deref()),
+            Stmt::Throw(s) => self.return_like("throw", Some(&s.arg)),
+            Stmt::Labeled(s) => concat([
+                self.ident(&s.label.sym),
+                text(":"),
+                if s.body.is_empty() {
+                    text(";")
+                } else {
+                    concat([text(" "), self.stmt(&s.body)])
+                },
+            ]),
+            Stmt::Break(s) => self.jump("break", s.label.as_ref()),
+            Stmt::Continue(s) => self.jump("continue", s.label.as_ref()),
+            Stmt::If(s) => self.if_stmt(s),
+            Stmt::Switch(s) => self.switch_stmt(s),
+            Stmt::Try(s) => self.try_stmt(s),
+            Stmt::While(s) => group(concat([
+                text("while ("),
+                self.test(&s.test),
+                text(")"),
+                self.clause(&s.body, false),
+            ])),
+            Stmt::DoWhile(s) => {
+                let body = group(self.clause(&s.body, false));
+                let sep = if s.body.is_block() {
+                    text(" ")
+                } else {
+                    hardline()
+                };
+
+                concat([
+                    text("do"),
+                    body,
+                    sep,
+                    text("while ("),
+                    self.test(&s.test),
+                    text(")"),
+                    self.semi(),
+                ])
+            }
+            Stmt::For(s) => self.for_stmt(s),
+            Stmt::ForIn(s) => group(concat([
+                text("for ("),
+                self.for_head(&s.left),
+                text(" in "),
+                self.expr(&s.right),
+                text(")"),
+                self.clause(&s.body, false),
+            ])),
+            Stmt::ForOf(s) => group(concat([
+                text(if s.is_await { "for await (" } else { "for (" }),
+                self.for_head(&s.left),
+                text(" of "),
+                self.expr(&s.right),
+                text(")"),
+                self.clause(&s.body, false),
+            ])),
+            Stmt::Decl(decl) => self.decl(decl),
+            Stmt::Expr(s) => self.expr_stmt(s),
+        }
+    }
+
+    pub fn block(&self, b: &BlockStmt) -> Doc {
+        self.braced(&b.stmts, b.span, |f, stmt| f.stmt(stmt))
+    }
+
+    /// Prints items in braces, one per line.
+    pub fn braced<T: Spanned>(
+        &self,
+        items: &[T],
+        span: Span,
+        op: impl Fn(&Self, &T) -> Doc,
+    ) -> Doc {
+        let body = self.list(items, op);
+        let rest = self.dangling(span.with_lo(items.last().map_or(span.lo, |item| item.span().hi)));
+
+        let body = match (body.is_nil(), rest) {
+            (true, None) => return text("{}"),
+            (true, Some(rest)) => rest,
+            (false, None) => body,
+            (false, Some(rest)) => concat([body, hardline(), rest]),
+        };
+
+        concat([
+            text("{"),
+            indent(concat([hardline(), body])),
+            hardline(),
+            text("}"),
+        ])
+    }
+
+    /// The body of `if`, `for` and `while` statements.
+    fn clause(&self, body: &Stmt, force_space: bool) -> Doc {
+        match body {
+            Stmt::Empty(..) => text(";"),
+            Stmt::Block(..) => concat([text(" "), self.stmt(body)]),
+            _ if force_space => concat([text(" "), self.stmt(body)]),
+            _ => indent(concat([line(), self.stmt(body)])),
+        }
+    }
+
+    /// The test of `if` and `while` statements.
+    fn test(&self, test: &Expr) -> Doc {
+        group(concat([
+            indent(concat([softline(), self.expr_in_parens(test)])),
+            softline(),
+        ]))
+    }
+
+    fn return_like(&self, keyword: &'static str, arg: Option<&Expr>) -> Doc {
+        let Some(arg) = arg else {
+            return concat([text(keyword), self.semi()]);
+        };
+
+        // Parentheses are added back if the argument is broken.
+        let mut arg = arg;
+        while let Expr::Paren(ParenExpr { expr, .. }) = arg {
+            if !matches!(&**expr, Expr::Bin(..) | Expr::Seq(..) | Expr::Paren(..)) {
+                break;
+            }
+            arg = expr;
+        }
+
+        let arg = match arg {
+            Expr::Bin(..) | Expr::Seq(..) => group(concat([
+                if_break("(", ""),
+                indent(concat([softline(), self.expr_in_parens(arg)])),
+                softline(),
+                if_break(")", ""),
+            ])),
+            _ => self.jsx_or_expr(arg),
+        };
+
+        concat([text(keyword), text(" "), arg, self.semi()])
+    }
+
+    fn jump(&self, keyword: &'static str, label: Option<&Ident>) -> Doc {
+        concat([
+            text(keyword),
+            label.map_or(Doc::nil(), |label| {
+                concat([text(" "), self.ident(&label.sym)])
+            }),
+            self.semi(),
+        ])
+    }
+
+    fn if_stmt(&self, s: &IfStmt) -> Doc {
+        let mut parts = vec![group(concat([
+            text("if ("),
+            self.test(&s.test),
+            text(")"),
+            group(self.clause(&s.cons, false)),
+        ]))];
+
+        if let Some(alt) = &s.alt {
+            parts.push(if s.cons.is_block() {
+                text(" ")
+            } else {
+                hardline()
+            });
+            parts.push(text("else"));
+            parts.push(group(self.clause(alt, alt.is_if_stmt())));
+        }
+
+        group(Doc::Concat(parts))
+    }
+
+    fn switch_stmt(&self, s: &SwitchStmt) -> Doc {
+        let cases = self.list(&s.cases, |f, case| {
+            f.with_comments(case.span, || {
+                let head = match &case.test {
+                    Some(test) => concat([text("case "), f.expr(test), text(":")]),
+                    None => text("default:"),
+                };
+
+                let body = match &*case.cons {
+                    [] => Doc::nil(),
+                    [stmt @ Stmt::Block(..)] => concat([text(" "), f.stmt(stmt)]),
+                    cons => indent(concat([hardline(), f.list(cons, |f, stmt| f.stmt(stmt))])),
+                };
+
+                concat([head, body])
+            })
+        });
+
+        let cases = if s.cases.is_empty() {
+            match self.dangling(s.span) {
+                Some(rest) => concat([indent(concat([hardline(), rest])), hardline()]),
+                None => Doc::nil(),
+            }
+        } else {
+            concat([indent(concat([hardline(), cases])), hardline()])
+        };
+
+        concat([
+            group(concat([
+                text("switch ("),
+                indent(concat([softline(), self.expr(&s.discriminant)])),
+                softline(),
+                text(")"),
+            ])),
+            text(" {"),
+            cases,
+            text("}"),
+        ])
+    }
+
+    fn try_stmt(&self, s: &TryStmt) -> Doc {
+        let mut parts = vec![text("try "), self.block(&s.block)];
+
+        if let Some(handler) = &s.handler {
+            parts.push(text(" catch "));
+            if let Some(param) = &handler.param {
+                parts.push(text("("));
+                parts.push(self.list_item(param, || self.pat(param)));
+                parts.push(text(") "));
+            }
+            parts.push(self.block(&handler.body));
+        }
+
+        if let Some(finalizer) = &s.finalizer {
+            parts.push(text(" finally "));
+            parts.push(self.block(finalizer));
+        }
+
+        Doc::Concat(parts)
+    }
+
+    fn for_stmt(&self, s: &ForStmt) -> Doc {
+        let body = self.clause(&s.body, false);
+
+        if s.init.is_none() && s.test.is_none() && s.update.is_none() {
+            return group(concat([text("for (;;)"), body]));
+        }
+
+        let init = match &s.init {
+            Some(VarDeclOrExpr::VarDecl(v)) => self.var_decl(v, false),
+            Some(VarDeclOrExpr::Expr(e)) => self.expr(e),
+            None => Doc::nil(),
+        };
+        let test = s.test.as_ref().map_or(Doc::nil(), |e| self.expr(e));
+        let update = s.update.as_ref().map_or(Doc::nil(), |e| self.expr(e));
+
+        group(concat([
+            text("for ("),
+            group(concat([
+                indent(concat([
+                    softline(),
+                    init,
+                    text(";"),
+                    line(),
+                    test,
+                    text(";"),
+                    line(),
+                    update,
+                ])),
+                softline(),
+            ])),
+            text(")"),
+            body,
+        ]))
+    }
+
+    fn for_head(&self, head: &ForHead) -> Doc {
+        match head {
+            ForHead::VarDecl(v) => self.var_decl(v, false),
+            ForHead::UsingDecl(u) => self.using_decl(u, false),
+            ForHead::Pat(p) => self.pat(p),
+        }
+    }
+
+    fn expr_stmt(&self, s: &ExprStmt) -> Doc {
+        let expr = self.expr(&s.expr);
+
+        // Without semicolons, a statement starting with these tokens would
+        // continue the previous statement.
+        if !self.cfg.semi && starts_with_asi_hazard(&s.expr) {
+            return concat([text(";"), expr]);
+        }
+
+        concat([expr, self.semi()])
+    }
+}
+
+fn starts_with_asi_hazard(e: &Expr) -> bool {
+    match e {
+        Expr::Paren(..) | Expr::Array(..) | Expr::Tpl(..) | Expr::Arrow(..) => true,
+        Expr::Lit(Lit::Regex(..)) => true,
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "+") | op!(unary, "-"),
+            ..
+        }) => true,
+        Expr::Update(u) => u.prefix || starts_with_asi_hazard(&u.arg),
+        Expr::Bin(b) => starts_with_asi_hazard(&b.left),
+        Expr::Member(m) => starts_with_asi_hazard(&m.obj),
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => starts_with_asi_hazard(callee),
+        Expr::TaggedTpl(t) => starts_with_asi_hazard(&t.tag),
+        Expr::Cond(c) => starts_with_asi_hazard(&c.test),
+        Expr::Seq(s) => starts_with_asi_hazard(&s.exprs[0]),
+        Expr::Assign(a) => match &a.left {
+            AssignTarget::Pat(AssignTargetPat::Array(..)) => true,
+            AssignTarget::Simple(SimpleAssignTarget::Member(m)) => starts_with_asi_hazard(&m.obj),
+            AssignTarget::Simple(SimpleAssignTarget::Paren(..)) => true,
+            _ => false,
+        },
+        Expr::OptChain(o) => match &*o.base {
+            OptChainBase::Member(m) => starts_with_asi_hazard(&m.obj),
+            OptChainBase::Call(c) => starts_with_asi_hazard(&c.callee),
+        },
+        Expr::TsAs(e) => starts_with_asi_hazard(&e.expr),
+        Expr::TsSatisfies(e) => starts_with_asi_hazard(&e.expr),
+        Expr::TsNonNull(e) => starts_with_asi_hazard(&e.expr),
+        Expr::TsConstAssertion(e) => starts_with_asi_hazard(&e.expr),
+        Expr::TsTypeAssertion(..) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,132 @@
+//! A writer for [Emitter](swc_ecma_codegen::Emitter) which records line
+//! breaks inside string and template literals.
+
+use std::{cell::RefCell, io, rc::Rc};
+
+use swc_common::{BytePos, Span};
+use swc_ecma_codegen::text_writer::{JsWriter, WriteJs};
+
+/// An output buffer shared with [LiteralWriter].
+#[derive(Clone, Default)]
+pub(super) struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuf {
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn take(&self) -> Vec<u8> {
+        self.0.take()
+    }
+}
+
+impl io::Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Wraps a [JsWriter] and records the offsets of the line breaks written as
+/// a part of a literal, which must be printed verbatim.
+pub(super) struct LiteralWriter<'a> {
+    pub wr: JsWriter<'a, SharedBuf>,
+    pub buf: SharedBuf,
+    /// Offsets of `\n` in the output.
+    pub literal_breaks: Vec<usize>,
+}
+
+impl WriteJs for LiteralWriter<'_> {
+    fn increase_indent(&mut self) -> io::Result<()> {
+        self.wr.increase_indent()
+    }
+
+    fn decrease_indent(&mut self) -> io::Result<()> {
+        self.wr.decrease_indent()
+    }
+
+    fn write_semi(&mut self, span: Option<Span>) -> io::Result<()> {
+        self.wr.write_semi(span)
+    }
+
+    fn write_space(&mut self) -> io::Result<()> {
+        self.wr.write_space()
+    }
+
+    fn write_keyword(&mut self, span: Option<Span>, s: &'static str) -> io::Result<()> {
+        self.wr.write_keyword(span, s)
+    }
+
+    fn write_operator(&mut self, span: Option<Span>, s: &str) -> io::Result<()> {
+        self.wr.write_operator(span, s)
+    }
+
+    fn write_param(&mut self, s: &str) -> io::Result<()> {
+        self.wr.write_param(s)
+    }
+
+    fn write_property(&mut self, s: &str) -> io::Result<()> {
+        self.wr.write_property(s)
+    }
+
+    fn write_line(&mut self) -> io::Result<()> {
+        self.wr.write_line()
+    }
+
+    fn write_lit(&mut self, span: Span, s: &str) -> io::Result<()> {
+        self.wr.write_lit(span, s)
+    }
+
+    fn write_comment(&mut self, s: &str) -> io::Result<()> {
+        self.wr.write_comment(s)
+    }
+
+    fn write_str_lit(&mut self, span: Span, s: &str) -> io::Result<()> {
+        let start = self.buf.len();
+        self.wr.write_str_lit(span, s)?;
+
+        // The indentation written before the literal doesn't contain line
+        // breaks, so all of them are a part of the literal.
+        let buf = self.buf.0.borrow();
+        self.literal_breaks.extend(
+            buf[start..]
+                .iter()
+                .enumerate()
+                .filter(|(_, &b)| b == b'\n')
+                .map(|(i, _)| start + i),
+        );
+
+        Ok(())
+    }
+
+    fn write_str(&mut self, s: &str) -> io::Result<()> {
+        self.wr.write_str(s)
+    }
+
+    fn write_symbol(&mut self, span: Span, s: &str) -> io::Result<()> {
+        self.wr.write_symbol(span, s)
+    }
+
+    fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> io::Result<()> {
+        self.wr.write_punct(span, s)
+    }
+
+    fn care_about_srcmap(&self) -> bool {
+        self.wr.care_about_srcmap()
+    }
+
+    fn add_srcmap(&mut self, pos: BytePos) -> io::Result<()> {
+        self.wr.add_srcmap(pos)
+    }
+
+    fn commit_pending_semi(&mut self) -> io::Result<()> {
+        self.wr.commit_pending_semi()
+    }
+
+    fn can_ignore_invalid_unicodes(&mut self) -> bool {
+        self.wr.can_ignore_invalid_unicodes()
+    }
+}
@@ -0,0 +1,309 @@
+//! Prints a [Doc] to a string, choosing line breaks.
+
+use crate::doc::{Doc, Line};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Break,
+    Flat,
+}
+
+#[derive(Clone, Copy)]
+enum Item<'a> {
+    Doc(&'a Doc),
+    /// The remaining parts of a [Doc::Fill].
+    Fill(&'a [Doc]),
+}
+
+#[derive(Clone, Copy)]
+struct Cmd<'a> {
+    indent: usize,
+    mode: Mode,
+    item: Item<'a>,
+}
+
+impl<'a> Cmd<'a> {
+    fn new(indent: usize, mode: Mode, doc: &'a Doc) -> Self {
+        Cmd {
+            indent,
+            mode,
+            item: Item::Doc(doc),
+        }
+    }
+}
+
+pub(crate) struct Layout<'a> {
+    pub width: usize,
+    /// The string for a single level of indentation.
+    pub indent: &'a str,
+}
+
+impl Layout<'_> {
+    pub fn print(&self, doc: &Doc) -> String {
+        let mut out = String::new();
+        let mut pos = 0;
+        let mut cmds = vec![Cmd::new(0, Mode::Break, doc)];
+        let mut line_suffix = Vec::new();
+        let mut should_remeasure = false;
+
+        loop {
+            let Some(cmd) = cmds.pop() else {
+                if line_suffix.is_empty() {
+                    break;
+                }
+                cmds.extend(line_suffix.drain(..).rev());
+                continue;
+            };
+            let Cmd { indent, mode, item } = cmd;
+
+            let doc = match item {
+                Item::Doc(doc) => doc,
+                Item::Fill(parts) => {
+                    self.fill(&mut cmds, indent, parts, pos);
+                    continue;
+                }
+            };
+
+            match doc {
+                Doc::Text(s) => {
+                    out.push_str(s);
+                    // Template literals may contain line breaks.
+                    match s.rfind('\n') {
+                        Some(i) => pos = width(&s[i + 1..]),
+                        None => pos += width(s),
+                    }
+                }
+                Doc::Concat(parts) => {
+                    cmds.extend(parts.iter().rev().map(|doc| Cmd::new(indent, mode, doc)));
+                }
+                Doc::Indent(doc) => cmds.push(Cmd::new(indent + 1, mode, doc)),
+                Doc::Group {
+                    contents,
+                    should_break,
+                } => {
+                    if mode == Mode::Flat && !should_remeasure {
+                        cmds.push(Cmd::new(
+                            indent,
+                            if *should_break {
+                                Mode::Break
+                            } else {
+                                Mode::Flat
+                            },
+                            contents,
+                        ));
+                    } else {
+                        should_remeasure = false;
+
+                        let flat = Cmd::new(indent, Mode::Flat, contents);
+                        let rem = self.width as isize - pos as isize;
+                        if !should_break && fits(&[flat], &cmds, rem, false) {
+                            cmds.push(flat);
+                        } else {
+                            cmds.push(Cmd::new(indent, Mode::Break, contents));
+                        }
+                    }
+                }
+                Doc::ConditionalGroup(states) => {
+                    if mode == Mode::Flat && !should_remeasure {
+                        cmds.push(Cmd::new(indent, Mode::Flat, &states[0]));
+                        continue;
+                    }
+                    should_remeasure = false;
+
+                    let rem = self.width as isize - pos as isize;
+                    let fitting = states
+                        .iter()
+                        .map(|state| Cmd::new(indent, Mode::Flat, state))
+                        .find(|cmd| fits(&[*cmd], &cmds, rem, false));
+                    cmds.push(
+                        fitting.unwrap_or_else(|| {
+                            Cmd::new(indent, Mode::Break, states.last().unwrap())
+                        }),
+                    );
+                }
+                Doc::Fill(parts) => {
+                    self.fill(&mut cmds, indent, parts, pos);
+                }
+                Doc::IfBreak { breaks, flat } => {
+                    cmds.push(Cmd::new(
+                        indent,
+                        mode,
+                        if mode == Mode::Break { breaks } else { flat },
+                    ));
+                }
+                Doc::LineSuffix(doc) => line_suffix.push(Cmd::new(indent, mode, doc)),
+                Doc::BreakParent => {}
+                Doc::Line(kind) => {
+                    if mode == Mode::Flat && *kind != Line::Hard {
+                        if *kind == Line::Normal {
+                            out.push(' ');
+                            pos += 1;
+                        }
+                        continue;
+                    }
+                    if mode == Mode::Flat {
+                        should_remeasure = true;
+                    }
+
+                    if !line_suffix.is_empty() {
+                        cmds.push(cmd);
+                        cmds.extend(line_suffix.drain(..).rev());
+                        continue;
+                    }
+
+                    out.truncate(out.trim_end_matches([' ', '\t']).len());
+                    out.push('\n');
+                    for _ in 0..indent {
+                        out.push_str(self.indent);
+                    }
+                    pos = indent * width(self.indent);
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Pushes the commands for the first content and separator of a fill.
+    fn fill<'a>(&self, cmds: &mut Vec<Cmd<'a>>, indent: usize, parts: &'a [Doc], pos: usize) {
+        let rem = self.width as isize - pos as isize;
+
+        let (content, rest) = match parts {
+            [] => return,
+            [content, rest @ ..] => (content, rest),
+        };
+        let content_flat = Cmd::new(indent, Mode::Flat, content);
+        let content_break = Cmd::new(indent, Mode::Break, content);
+        let content_fits = fits(&[content_flat], &[], rem, true);
+
+        let (sep, rest) = match rest {
+            [] => {
+                cmds.push(if content_fits {
+                    content_flat
+                } else {
+                    content_break
+                });
+                return;
+            }
+            [sep, rest @ ..] => (sep, rest),
+        };
+        let sep_flat = Cmd::new(indent, Mode::Flat, sep);
+        let sep_break = Cmd::new(indent, Mode::Break, sep);
+
+        let Some(second) = rest.first() else {
+            if content_fits {
+                cmds.push(sep_flat);
+                cmds.push(content_flat);
+            } else {
+                cmds.push(sep_break);
+                cmds.push(content_break);
+            }
+            return;
+        };
+
+        cmds.push(Cmd {
+            indent,
+            mode: Mode::Break,
+            item: Item::Fill(rest),
+        });
+
+        let pair_fits = fits(
+            &[content_flat, sep_flat, Cmd::new(indent, Mode::Flat, second)],
+            &[],
+            rem,
+            true,
+        );
+
+        if pair_fits {
+            cmds.push(sep_flat);
+            cmds.push(content_flat);
+        } else if content_fits {
+            cmds.push(sep_break);
+            cmds.push(content_flat);
+        } else {
+            cmds.push(sep_break);
+            cmds.push(content_break);
+        }
+    }
+}
+
+/// Returns true if `next` fits in `rem` columns before the next line break.
+/// Commands in `rest` are checked after `next`, in their own modes.
+fn fits(next: &[Cmd], rest: &[Cmd], mut rem: isize, must_be_flat: bool) -> bool {
+    let mut stack = next.iter().rev().copied().collect::<Vec<_>>();
+    let mut rest_idx = rest.len();
+
+    while rem >= 0 {
+        let Some(Cmd { indent, mode, item }) = stack.pop() else {
+            if rest_idx == 0 {
+                return true;
+            }
+            rest_idx -= 1;
+            stack.push(rest[rest_idx]);
+            continue;
+        };
+
+        let doc = match item {
+            Item::Doc(doc) => doc,
+            Item::Fill(parts) => {
+                stack.extend(parts.iter().rev().map(|doc| Cmd::new(indent, mode, doc)));
+                continue;
+            }
+        };
+
+        match doc {
+            Doc::Text(s) => match s.split_once('\n') {
+                Some((first, _)) => return rem >= width(first) as isize,
+                None => rem -= width(s) as isize,
+            },
+            Doc::Concat(parts) | Doc::Fill(parts) => {
+                stack.extend(parts.iter().rev().map(|doc| Cmd::new(indent, mode, doc)));
+            }
+            Doc::Indent(doc) => stack.push(Cmd::new(indent, mode, doc)),
+            Doc::Group {
+                contents,
+                should_break,
+            } => {
+                if must_be_flat && *should_break {
+                    return false;
+                }
+                let mode = if *should_break { Mode::Break } else { mode };
+                stack.push(Cmd::new(indent, mode, contents));
+            }
+            Doc::ConditionalGroup(states) => {
+                let state = if mode == Mode::Break {
+                    states.last().unwrap()
+                } else {
+                    &states[0]
+                };
+                stack.push(Cmd::new(indent, mode, state));
+            }
+            Doc::IfBreak { breaks, flat } => {
+                stack.push(Cmd::new(
+                    indent,
+                    mode,
+                    if mode == Mode::Break { breaks } else { flat },
+                ));
+            }
+            Doc::Line(kind) => {
+                if mode == Mode::Break || *kind == Line::Hard {
+                    return true;
+                }
+                if *kind == Line::Normal {
+                    rem -= 1;
+                }
+            }
+            Doc::LineSuffix(..) | Doc::BreakParent => {}
+        }
+    }
+
+    false
+}
+
+fn width(s: &str) -> usize {
+    if s.is_ascii() {
+        s.len()
+    } else {
+        s.chars().count()
+    }
+}
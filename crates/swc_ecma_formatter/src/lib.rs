@@ -0,0 +1,64 @@
+//! An opinionated code formatter for ecmascript and typescript, which
+//! produces output compatible with [prettier](https://prettier.io).
+//!
+//! Unlike `swc_ecma_codegen`, which prints an AST as compact as possible,
+//! this crate chooses line breaks so that lines fit in
+//! [Config::print_width], and keeps comments and blank lines of the
+//! original source.
+//!
+//! Typescript types are printed by `swc_ecma_codegen` for now, so they are
+//! normalized but not broken over multiple lines.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let output = swc_ecma_formatter::format_program(
+//!     cm.clone(),
+//!     &fm,
+//!     &program,
+//!     &comments,
+//!     &Default::default(),
+//! );
+//! ```
+#![deny(clippy::all)]
+#![deny(unused)]
+
+use swc_common::{comments::SingleThreadedComments, sync::Lrc, SourceFile, SourceMap};
+use swc_ecma_ast::Program;
+
+pub use self::config::{ArrowParens, Config, TrailingComma};
+use self::{doc::propagate_breaks, format::Formatter, layout::Layout};
+
+mod config;
+mod doc;
+mod format;
+mod layout;
+
+const SPACES: &str = "                ";
+
+/// Formats `program`, which is parsed from `fm` with `comments`.
+///
+/// Comments are taken from `comments` while printing.
+pub fn format_program(
+    cm: Lrc<SourceMap>,
+    fm: &SourceFile,
+    program: &Program,
+    comments: &SingleThreadedComments,
+    config: &Config,
+) -> String {
+    let indent: &'static str = if config.use_tabs {
+        "\t"
+    } else {
+        &SPACES[..config.tab_width.min(SPACES.len())]
+    };
+
+    let formatter = Formatter::new(config, cm, fm, comments, indent);
+    let mut doc = formatter.program(program);
+    propagate_breaks(&mut doc);
+
+    Layout {
+        width: config.print_width,
+        indent,
+    }
+    .print(&doc)
+}
@@ -0,0 +1,64 @@
+use std::{fs::read_to_string, path::PathBuf};
+
+use swc_common::{comments::SingleThreadedComments, FileName, SourceFile};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_formatter::{format_program, Config};
+use swc_ecma_parser::{parse_file_as_program, EsSyntax, Syntax, TsSyntax};
+use testing::{run_test2, NormalizedOutput};
+
+#[testing::fixture("tests/fixture/**/input.*")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let ext = input.extension().unwrap().to_string_lossy();
+    let output = dir.join(format!("output.{ext}"));
+
+    let config: Config = match read_to_string(dir.join("config.json")) {
+        Ok(config) => serde_json::from_str(&config).expect("invalid config.json"),
+        Err(..) => Default::default(),
+    };
+    let syntax = match &*ext {
+        "ts" => Syntax::Typescript(TsSyntax {
+            decorators: true,
+            ..Default::default()
+        }),
+        "tsx" => Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(EsSyntax {
+            jsx: true,
+            decorators: true,
+            import_attributes: true,
+            ..Default::default()
+        }),
+    };
+
+    run_test2(false, |cm, _| {
+        let format = |fm: &SourceFile| {
+            let comments = SingleThreadedComments::default();
+            let program = parse_file_as_program(
+                fm,
+                syntax,
+                EsVersion::latest(),
+                Some(&comments),
+                &mut Vec::new(),
+            )
+            .expect("failed to parse input");
+
+            format_program(cm.clone(), fm, &program, &comments, &config)
+        };
+
+        let code = format(&cm.load_file(&input).unwrap());
+
+        // Formatting should be idempotent.
+        let fm = cm.new_source_file(FileName::Anon.into(), code.clone());
+        assert_eq!(format(&fm), code, "formatting the output changed it");
+
+        NormalizedOutput::from(code)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,24 @@
+#!/usr/bin/env node
+'use strict'
+const a=1,b='it\'s',c="plain"
+let x
+var big = 0XFF + 1.50 + .5 + 5. + 1E+10 + 10n
+function foo(a,b,...rest){return a+b}
+async function* gen(){yield* other(); await x}
+if(a){b()}else if(c){d()}else{e()}
+for(let i=0;i<10;i++)continue
+for (const k in obj) {}
+for await (const v of stream) console.log(v)
+while(true){break}
+do{x++}while(x<10)
+switch(x){case 1:case 2:foo();break;default:bar()}
+try{risky()}catch(e){handle(e)}finally{cleanup()}
+label:for(;;){break label}
+const re=/ab+c/gi
+const t=`hello ${ name }, you are ${age+1}`
+const neg = - -x, pos = +(+y), typ = typeof x, v = void 0, del = delete o.k
+x ??= y; a ||= b; c &&= d
+const cond = isEnabled ? "yes" : "no"
+new Foo
+const arrow = x => x * 2
+const arrow2 = async (a, b) => { return a + b }
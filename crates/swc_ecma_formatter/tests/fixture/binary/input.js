@@ -0,0 +1,11 @@
+const isValid = someCondition && anotherCondition || yetAnotherCondition && theFinalCondition;
+if (aaaaaaaaaaaaaaaaaaaaaaa && bbbbbbbbbbbbbbbbbbbbbbbbbbbbb && cccccccccccccccccccccccccccccc) {
+  run();
+}
+const total = firstValue + secondValue + thirdValue + fourthValue + fifthValue + sixth;
+const mixed = a * b + c * d;
+function check() {
+  return someVeryLongConditionName && anotherVeryLongConditionName && yetAnotherOne;
+}
+const message = "a very long string literal that is not going to fit on a single line";
+const fallback = options || {};
@@ -0,0 +1,11 @@
+foo(reallyLongArgumentNumberOne, reallyLongArgumentNumberTwo, reallyLongArgumentNumberThree);
+useEffect(() => { doSomething(); }, [dependency]);
+setTimeout(function () { tick() }, 500);
+promise.then(result => console.log(result));
+const value = object.property.another.deeply.nested.property.access.that.is.long.enough;
+fetchData().then(res => res.json()).then(data => setState(data)).catch(err => handleError(err));
+this.server = http.createServer(this.app).listen(port, () => console.log("listening"));
+describe("suite", () => { it("works", () => { expect(add(1, 2)).toBe(3); }); });
+const result = someFunction(argumentNumberOne, { option: true, anotherOption: false, third: 3 });
+wrapper.find('SomethingLong').prop('children')(0).props.onChange({ target: { value: 'x' } });
+a?.b?.[c]?.(d);
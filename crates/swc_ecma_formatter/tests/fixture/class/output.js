@@ -0,0 +1,23 @@
+class Animal extends Base {
+  static count = 0;
+  #secret = 1;
+  name;
+  constructor(name) {
+    super(name);
+    this.name = name;
+  }
+  get label() {
+    return this.name;
+  }
+  static create() {
+    return new Animal();
+  }
+  async *stream() {}
+  #hidden() {}
+  static {
+    init();
+  }
+}
+@decorator
+class Decorated {}
+const Anon = class {};
@@ -0,0 +1,28 @@
+// A leading comment
+
+/**
+ * Adds numbers.
+ * @param {number} a
+ */
+function add(a, b) {
+  // inside
+  return a + b; // trailing
+}
+
+const x = /* inline */ 1;
+
+function empty() {
+  // only a comment
+}
+
+const obj = {
+  // comment in object
+  a: 1, // after a
+  b: 2,
+};
+
+// the end
+call(
+  a, // first
+  b,
+);
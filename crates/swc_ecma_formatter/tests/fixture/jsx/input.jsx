@@ -0,0 +1,8 @@
+const a = <div className="app">Hello</div>;
+const b = <Foo bar="1" baz={2} />;
+function App() {
+  return <div className="container"><Header title="Welcome" /><main>{children}</main></div>;
+}
+const text = <p>Some long text that goes on and on and on, and does not fit within the width of a line.</p>;
+const frag = <><A /><B /></>;
+const spaced = <span>a {b} c</span>;
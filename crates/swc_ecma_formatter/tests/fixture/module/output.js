@@ -0,0 +1,17 @@
+import React, { useState, useEffect } from "react";
+import * as path from "path";
+import def from "./def";
+import {} from "./side";
+import "./polyfill";
+import data from "./data.json" with { type: "json" };
+import {
+  aVeryLongImportName,
+  anotherVeryLongImportName,
+  yetAnotherLongName,
+} from "./module";
+export { a, b as c };
+export * from "./all";
+export * as ns from "./ns";
+export default function App() {}
+export const x = 1;
+export { default } from "./other";
@@ -0,0 +1,11 @@
+namespace N {
+    export const s = `a  
+  b`;
+    export const t = "c\
+  d";
+    export function f() {
+        return `
+    ${s}  
+`;
+    }
+}
@@ -0,0 +1,46 @@
+const short = { a: 1, b: 2 };
+const expanded = {
+  a: 1,
+  b: 2,
+};
+const long = {
+  alpha: "aaaaaaaaaaaaaa",
+  beta: "bbbbbbbbbbbbbbbb",
+  gamma: "cccccccccccccccc",
+};
+const withBlank = {
+  a: 1,
+
+  b: 2,
+};
+const methods = {
+  get x() {
+    return 1;
+  },
+  set x(v) {},
+  async *gen() {},
+  [computed]: 1,
+  "quoted-key": 2,
+  ...spread,
+};
+const {
+  a,
+  b: { c },
+  ...rest
+} = obj;
+const [first, , third = 3, ...others] = arr;
+const numbers = [
+  1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+  23, 24, 25,
+];
+const matrix = [
+  [1, 0],
+  [0, 1],
+];
+const people = [{ name: "a" }, { name: "b" }];
+const empty = {},
+  emptyArr = [];
+const users = [
+  { name: "a", age: 1 },
+  { name: "b", age: 2 },
+];
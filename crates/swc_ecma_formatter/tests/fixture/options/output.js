@@ -0,0 +1,9 @@
+const s = 'double' + 'single'
+const f = x => {
+    return [x, {y: 1}]
+}
+function longParams(
+    parameterNumberOne,
+    parameterNumberTwo,
+    parameterNumberThree
+) {}
@@ -0,0 +1,15 @@
+interface Props { name: string; age?: number }
+type Union = "a" | "b"
+enum Color { Red, Green }
+function typed<T>(value: T, other?: string): T { return value as T }
+const n = value!
+const s = obj satisfies Shape
+const c = [1, 2] as const
+class Service {
+  constructor(private readonly http: HttpClient, public name: string) {}
+  private cache: Map<string, number> = new Map()
+  abstract method(): void
+}
+declare module "foo" {
+  export const x: number
+}
@@ -51,6 +51,10 @@ pub enum SyntaxError {
     InvalidNameInUsingDecl,
     InitRequiredForUsingDecl,
 
+    TopicRefOutsideOfPipeline,
+    PipelineTopicUnused,
+    PipelineBodyRequiresParens,
+
     PrivateNameInInterface,
 
     InvalidSuperCall,
@@ -546,6 +550,16 @@ impl SyntaxError {
             SyntaxError::InitRequiredForUsingDecl => {
                 "Using declaration requires initializer".into()
             }
+            SyntaxError::TopicRefOutsideOfPipeline => {
+                "Topic reference `%` is only allowed in the body of a pipeline".into()
+            }
+            SyntaxError::PipelineTopicUnused => {
+                "The body of a pipeline must use the topic reference `%`".into()
+            }
+            SyntaxError::PipelineBodyRequiresParens => "Arrow functions, assignments, conditional \
+                                                        and yield expressions must be \
+                                                        parenthesized in the body of a pipeline"
+                .into(),
             SyntaxError::InvalidSuperCall => "Invalid `super()`".into(),
             SyntaxError::InvalidSuper => "Invalid access to super".into(),
             SyntaxError::InvalidSuperPrivateName => {
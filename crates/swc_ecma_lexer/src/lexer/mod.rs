@@ -373,6 +373,16 @@ impl<'a> Lexer<'a> {
             }));
         }
 
+        // '|>'
+        if C == b'|' && self.syntax.pipeline_operator() && self.input.cur() == Some('>') {
+            unsafe {
+                // Safety: cur() is Some('>')
+                self.input.bump();
+            }
+
+            return Ok(Token::Pipeline);
+        }
+
         // '||', '&&'
         if self.input.cur() == Some(C as char) {
             unsafe {
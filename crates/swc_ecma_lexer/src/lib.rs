@@ -78,6 +78,16 @@ impl Syntax {
         matches!(self, Syntax::Es(EsSyntax { fn_bind: true, .. }))
     }
 
+    pub fn pipeline_operator(self) -> bool {
+        matches!(
+            self,
+            Syntax::Es(EsSyntax {
+                pipeline_operator: true,
+                ..
+            })
+        )
+    }
+
     pub fn decorators(self) -> bool {
         match self {
             Syntax::Es(EsSyntax {
@@ -248,6 +258,12 @@ pub struct EsSyntax {
 
     #[serde(default)]
     pub explicit_resource_management: bool,
+
+    /// Hack-style pipeline operator, with `%` as the topic token.
+    ///
+    /// babel: `pipelineOperator` with `{ proposal: "hack", topicToken: "%" }`
+    #[serde(default)]
+    pub pipeline_operator: bool,
 }
 
 bitflags::bitflags! {
@@ -415,6 +431,9 @@ macro_rules! tok {
     ("=>") => {
         $crate::token::Token::Arrow
     };
+    ("|>") => {
+        $crate::token::Token::Pipeline
+    };
     ("...") => {
         $crate::token::Token::DotDotDot
     };
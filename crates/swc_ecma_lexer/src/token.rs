@@ -172,6 +172,7 @@ pub enum IdentKind {
 pub enum TokenKind {
     Word(WordKind),
     Arrow,
+    Pipeline,
     Hash,
     At,
     Dot,
@@ -220,6 +221,9 @@ pub enum Token {
     /// '=>'
     Arrow,
 
+    /// '|>'
+    Pipeline,
+
     /// '#'
     Hash,
 
@@ -314,6 +318,7 @@ impl Token {
     pub fn kind(&self) -> TokenKind {
         match self {
             Self::Arrow => TokenKind::Arrow,
+            Self::Pipeline => TokenKind::Pipeline,
             Self::Hash => TokenKind::Hash,
             Self::At => TokenKind::At,
             Self::Dot => TokenKind::Dot,
@@ -358,6 +363,7 @@ impl TokenKind {
             Self::Word(w) => w.before_expr(),
             Self::BinOp(w) => w.before_expr(),
             Self::Arrow
+            | Self::Pipeline
             | Self::DotDotDot
             | Self::Bang
             | Self::LParen
@@ -954,6 +960,7 @@ impl Debug for Token {
         match self {
             Token::Word(w) => write!(f, "{w:?}")?,
             Arrow => write!(f, "=>")?,
+            Pipeline => write!(f, "|>")?,
             Hash => write!(f, "#")?,
             At => write!(f, "@")?,
             Dot => write!(f, ".")?,
@@ -1,13 +1,15 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, mem::take, path::Path};
 
 use serde::{Deserialize, Serialize};
-use swc_config::merge::Merge;
+use swc_config::{glob::CachedGlob, merge::Merge};
 
 #[cfg(feature = "non_critical_lints")]
 use crate::rules::non_critical_lints::{
     dot_notation::DotNotationConfig, eqeqeq::EqeqeqConfig, no_bitwise::NoBitwiseConfig,
     no_console::NoConsoleConfig, no_empty_function::NoEmptyFunctionConfig,
-    no_param_reassign::NoParamReassignConfig, no_restricted_syntax::NoRestrictedSyntaxConfig,
+    no_param_reassign::NoParamReassignConfig, no_redeclare::NoRedeclareConfig,
+    no_restricted_syntax::NoRestrictedSyntaxConfig, no_shadow::NoShadowConfig,
+    no_undef::NoUndefConfig, no_unused_vars::NoUnusedVarsConfig,
     no_use_before_define::NoUseBeforeDefineConfig, prefer_const::PreferConstConfig,
     prefer_regex_literals::PreferRegexLiteralsConfig, quotes::QuotesConfig, radix::RadixConfig,
     symbol_description::SymbolDescriptionConfig, use_is_nan::UseIsNanConfig,
@@ -225,4 +227,98 @@ pub struct LintConfig {
     #[cfg(feature = "non_critical_lints")]
     #[serde(default, alias = "preferObjectSpread")]
     pub prefer_object_spread: RuleConfig<()>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noUnusedVars")]
+    pub no_unused_vars: RuleConfig<NoUnusedVarsConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noUndef")]
+    pub no_undef: RuleConfig<NoUndefConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noShadow")]
+    pub no_shadow: RuleConfig<NoShadowConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noRedeclare")]
+    pub no_redeclare: RuleConfig<NoRedeclareConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noUnusedPrivateClassMembers")]
+    pub no_unused_private_class_members: RuleConfig<()>,
+
+    /// Reports `swc-lint-disable` comments which don't suppress anything. See
+    /// [crate::directives].
+    #[serde(default, alias = "reportUnusedDisableDirectives")]
+    pub report_unused_disable_directives: RuleConfig<()>,
+
+    /// Rule configs for specific files. See [LintConfig::apply_overrides].
+    #[serde(default)]
+    pub overrides: Vec<LintOverride>,
+}
+
+impl LintConfig {
+    /// Applies the [LintConfig::overrides] matching `file`, in order, so the
+    /// last matching override takes precedence.
+    ///
+    /// Globs are matched against the path of `file` and against the path
+    /// relative to the current directory.
+    pub fn apply_overrides(&mut self, file: &Path) {
+        let overrides = take(&mut self.overrides);
+        if overrides.is_empty() {
+            return;
+        }
+
+        let relative = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| file.strip_prefix(cwd).ok().map(Path::to_path_buf));
+        let paths = [Some(file), relative.as_deref()];
+        let paths = paths
+            .iter()
+            .flatten()
+            .map(|path| path.to_string_lossy())
+            .collect::<Vec<_>>();
+
+        for o in overrides {
+            if !o.files.is_match(&paths) {
+                continue;
+            }
+
+            let mut rules = o.rules;
+            rules.overrides.clear();
+            rules.merge(take(self));
+            *self = rules;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintOverride {
+    /// Glob patterns of the files this override applies to.
+    pub files: LintOverrideFiles,
+
+    /// Rules configured for matching files. Rules which are not specified
+    /// here keep their original config.
+    pub rules: LintConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LintOverrideFiles {
+    Single(CachedGlob),
+    Multiple(Vec<CachedGlob>),
+}
+
+impl LintOverrideFiles {
+    fn is_match<S: AsRef<str>>(&self, paths: &[S]) -> bool {
+        let globs = match self {
+            LintOverrideFiles::Single(glob) => std::slice::from_ref(glob),
+            LintOverrideFiles::Multiple(globs) => globs,
+        };
+
+        globs
+            .iter()
+            .any(|glob| paths.iter().any(|path| glob.is_match(path.as_ref())))
+    }
 }
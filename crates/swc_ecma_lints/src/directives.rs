@@ -0,0 +1,96 @@
+//! Suppression of lint diagnostics using comments.
+//!
+//! - `// swc-lint-disable-next-line` suppresses diagnostics on the next line.
+//! - `// swc-lint-disable-line` suppresses diagnostics on the line of the
+//!   comment.
+//! - `/* swc-lint-disable */` and `/* swc-lint-enable */` enclose a range in
+//!   which diagnostics are suppressed. Without a following enable comment, the
+//!   range extends to the end of the file, so a disable comment at the top of a
+//!   file disables rules for the whole file.
+//!
+//! Each directive may be followed by a comma-separated list of rule names, in
+//! which case only those rules are affected. Text after `--` is treated as a
+//! description.
+//!
+//! ```js
+//! // swc-lint-disable-next-line no-console, no-debugger -- for debugging
+//! console.log(foo); debugger;
+//! ```
+
+//!
+//! See [swc_common::errors::directives] for the shared implementation.
+
+use swc_common::{comments::SingleThreadedComments, errors::Level, SourceMap};
+use swc_ecma_ast::{Module, Script};
+
+use crate::{
+    config::{LintConfig, LintRuleReaction},
+    rule::Rule,
+};
+
+const PREFIX: &str = "swc-lint-";
+
+/// Lint directives of a file, parsed from its comments.
+#[derive(Debug, Default)]
+pub struct Directives(swc_common::errors::directives::Directives);
+
+impl Directives {
+    pub fn parse(cm: &SourceMap, comments: &SingleThreadedComments) -> Self {
+        Self(swc_common::errors::directives::Directives::parse(
+            cm,
+            comments,
+            PREFIX,
+            |comment| Some((&*comment.text, comment.span)),
+        ))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Filters the diagnostics of `rule` using `directives`, and reports unused
+/// directives if [LintConfig::report_unused_disable_directives] is enabled.
+pub fn with_directives<R>(rule: R, directives: Directives, config: &LintConfig) -> impl Rule
+where
+    R: Rule,
+{
+    WithDirectives {
+        rule,
+        directives,
+        report_unused: match config.report_unused_disable_directives.get_rule_reaction() {
+            LintRuleReaction::Off => None,
+            LintRuleReaction::Warning => Some(Level::Warning),
+            LintRuleReaction::Error => Some(Level::Error),
+        },
+    }
+}
+
+#[derive(Debug)]
+struct WithDirectives<R>
+where
+    R: Rule,
+{
+    rule: R,
+    directives: Directives,
+    report_unused: Option<Level>,
+}
+
+impl<R> Rule for WithDirectives<R>
+where
+    R: Rule,
+{
+    fn lint_module(&mut self, program: &Module) {
+        let rule = &mut self.rule;
+        self.directives
+            .0
+            .run(self.report_unused, || rule.lint_module(program));
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        let rule = &mut self.rule;
+        self.directives
+            .0
+            .run(self.report_unused, || rule.lint_script(program));
+    }
+}
@@ -0,0 +1,5 @@
+//! Autofix support.
+//!
+//! See [swc_common::errors::fix].
+
+pub use swc_common::errors::fix::*;
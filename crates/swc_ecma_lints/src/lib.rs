@@ -4,5 +4,7 @@
 #![allow(dead_code)]
 
 pub mod config;
+pub mod directives;
+pub mod fix;
 pub mod rule;
 pub mod rules;
@@ -4,12 +4,14 @@ use auto_impl::auto_impl;
 use par_core::join;
 use parking_lot::Mutex;
 use swc_common::{
-    errors::{Diagnostic, DiagnosticBuilder, Emitter, Handler, HANDLER},
+    errors::{Diagnostic, DiagnosticBuilder, DiagnosticId, Emitter, Handler, HANDLER},
     GLOBALS,
 };
 use swc_ecma_ast::{Module, Script};
 use swc_ecma_visit::{Visit, VisitWith};
 
+use crate::fix::capture_diagnostics;
+
 /// A lint rule.
 ///
 /// # Implementation notes
@@ -150,3 +152,45 @@ where
         program.visit_with(&mut self.0);
     }
 }
+
+/// Sets the code of diagnostics reported by `rule` to `name`.
+pub(crate) fn named_rule(name: &'static str, rule: Box<dyn Rule>) -> Box<dyn Rule> {
+    Box::new(NamedRule { name, rule })
+}
+
+/// Returns the name of the rule which reported `diagnostic`.
+pub fn rule_name(diagnostic: &Diagnostic) -> Option<&str> {
+    match &diagnostic.code {
+        Some(DiagnosticId::Lint(name)) => Some(name),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+struct NamedRule {
+    name: &'static str,
+    rule: Box<dyn Rule>,
+}
+
+impl NamedRule {
+    fn emit(&self, diagnostics: Vec<Diagnostic>) {
+        HANDLER.with(|handler| {
+            for mut diagnostic in diagnostics {
+                diagnostic.code = Some(DiagnosticId::Lint(self.name.into()));
+                DiagnosticBuilder::new_diagnostic(handler, diagnostic).emit();
+            }
+        });
+    }
+}
+
+impl Rule for NamedRule {
+    fn lint_module(&mut self, program: &Module) {
+        let diagnostics = capture_diagnostics(|| self.rule.lint_module(program));
+        self.emit(diagnostics);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        let diagnostics = capture_diagnostics(|| self.rule.lint_script(program));
+        self.emit(diagnostics);
+    }
+}
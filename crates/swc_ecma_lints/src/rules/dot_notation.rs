@@ -2,7 +2,11 @@ use dashmap::DashMap;
 use regex::Regex;
 use rustc_hash::FxBuildHasher;
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, sync::Lazy, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    sync::Lazy,
+    Span,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
@@ -37,6 +41,8 @@ struct DotNotation {
     allow_keywords: bool,
     pattern: Option<String>,
     is_module: bool,
+    /// The member expression accessed with `?.`, if it's the one being visited.
+    optional_member: Option<Span>,
 }
 
 impl DotNotation {
@@ -48,28 +54,38 @@ impl DotNotation {
             allow_keywords: dot_notation_config.allow_keywords.unwrap_or(true),
             is_module,
             pattern: dot_notation_config.allow_pattern.clone(),
+            optional_member: None,
         }
     }
 
-    fn emit_report(&self, span: Span, quote_type: QuotesType, prop: &str) {
+    fn emit_report(&self, span: Span, quote_type: QuotesType, prop: &str, fix: Option<String>) {
         let message = format!(
             "[{quote}{prop}{quote}] is better written in dot notation",
             prop = prop,
             quote = quote_type.get_char()
         );
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, &message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, &message).emit();
+        HANDLER.with(|handler| {
+            let mut diagnostic = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, &message),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, &message),
+                _ => return,
+            };
+
+            if let Some(fix) = fix {
+                diagnostic.span_suggestion_with_applicability(
+                    span,
+                    "use dot notation",
+                    fix,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            diagnostic.emit();
         });
     }
 
-    fn check(&self, span: Span, quote_type: QuotesType, prop_name: &str) {
+    fn check(&self, member: &MemberExpr, span: Span, quote_type: QuotesType, prop_name: &str) {
         if self.allow_keywords
             && (prop_name.is_reserved() || prop_name.is_reserved_in_strict_mode(self.is_module))
         {
@@ -92,28 +108,56 @@ impl DotNotation {
             }
         }
 
-        self.emit_report(span, quote_type, prop_name);
+        let is_ident_name = prop_name.chars().next().is_some_and(Ident::is_valid_start)
+            && prop_name.chars().all(Ident::is_valid_continue);
+
+        // `1.toString` is a syntax error.
+        let fix = if is_ident_name && !matches!(&*member.obj, Expr::Lit(Lit::Num(..))) {
+            if self.optional_member == Some(member.span) {
+                Some(prop_name.to_string())
+            } else {
+                Some(format!(".{prop_name}"))
+            }
+        } else {
+            None
+        };
+
+        self.emit_report(span, quote_type, prop_name, fix);
     }
 }
 
 impl Visit for DotNotation {
     noop_visit_type!();
 
-    fn visit_member_prop(&mut self, member: &MemberProp) {
-        if let MemberProp::Computed(prop) = member {
-            match &*prop.expr {
+    fn visit_opt_chain_expr(&mut self, opt_chain: &OptChainExpr) {
+        if let OptChainBase::Member(member) = &*opt_chain.base {
+            if opt_chain.optional {
+                self.optional_member = Some(member.span);
+            }
+        }
+
+        opt_chain.visit_children_with(self);
+    }
+
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        let optional_member = self.optional_member.take();
+
+        member.obj.visit_with(self);
+
+        match &member.prop {
+            MemberProp::Computed(prop) => match &*prop.expr {
                 Expr::Lit(Lit::Str(lit_str)) => {
                     let quote_type = resolve_string_quote_type(lit_str).unwrap();
 
-                    self.check(prop.span, quote_type, &lit_str.value);
-                }
-                Expr::Member(member) => {
-                    member.visit_children_with(self);
+                    self.optional_member = optional_member;
+                    self.check(member, prop.span, quote_type, &lit_str.value);
+                    self.optional_member = None;
                 }
                 _ => {
                     prop.visit_with(self);
                 }
-            }
+            },
+            prop => prop.visit_with(self),
         }
     }
 }
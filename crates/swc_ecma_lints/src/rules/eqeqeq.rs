@@ -1,5 +1,14 @@
+use std::{
+    fmt::{self, Debug},
+    mem::discriminant,
+    sync::Arc,
+};
+
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    SourceMap, SourceMapper, Span, Spanned,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
@@ -27,61 +36,122 @@ pub struct EqeqeqConfig {
     mode: EqEqEqMode,
 }
 
-pub fn eqeqeq(config: &RuleConfig<EqeqeqConfig>) -> Option<Box<dyn Rule>> {
+pub fn eqeqeq(
+    source_map: &Arc<SourceMap>,
+    config: &RuleConfig<EqeqeqConfig>,
+) -> Option<Box<dyn Rule>> {
     match config.get_rule_reaction() {
         LintRuleReaction::Off => None,
-        _ => Some(visitor_rule(Eqeqeq::new(config))),
+        _ => Some(visitor_rule(Eqeqeq::new(source_map.clone(), config))),
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 struct Eqeqeq {
+    source_map: Arc<SourceMap>,
     expected_reaction: LintRuleReaction,
     mode: EqEqEqMode,
 }
 
+impl Debug for Eqeqeq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Eqeqeq")
+            .field("expected_reaction", &self.expected_reaction)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
 impl Eqeqeq {
-    fn new(config: &RuleConfig<EqeqeqConfig>) -> Self {
+    fn new(source_map: Arc<SourceMap>, config: &RuleConfig<EqeqeqConfig>) -> Self {
         Self {
+            source_map,
             expected_reaction: config.get_rule_reaction(),
             mode: config.get_rule_config().mode,
         }
     }
 
-    fn emit_report(&self, span: Span, actual: &str, expected: &str) {
+    fn emit_report(&self, bin_expr: &BinExpr, actual: &str, expected: &str) {
         let message = format!("Use '{expected}' instead of '{actual}'");
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, &message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, &message).emit();
+        HANDLER.with(|handler| {
+            let mut diagnostic = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(bin_expr.span, &message),
+                LintRuleReaction::Warning => handler.struct_span_warn(bin_expr.span, &message),
+                _ => return,
+            };
+
+            if let Some((span, replacement)) = self.fix(bin_expr, actual, expected) {
+                diagnostic.span_suggestion_with_applicability(
+                    span,
+                    &format!("use '{expected}'"),
+                    replacement,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            diagnostic.emit();
         });
     }
 
-    fn check(&self, span: Span, bin_op: &BinaryOp) {
-        match bin_op {
+    /// The operators behave the same only if one of the operands is a `typeof`
+    /// expression or if both are literals of the same type.
+    fn fix(&self, bin_expr: &BinExpr, actual: &str, expected: &str) -> Option<(Span, String)> {
+        let left = bin_expr.left.unwrap_parens();
+        let right = bin_expr.right.unwrap_parens();
+
+        let is_typeof = |e: &Expr| {
+            matches!(
+                e,
+                Expr::Unary(UnaryExpr {
+                    op: op!("typeof"),
+                    ..
+                })
+            )
+        };
+        let same_type_literals = match (left, right) {
+            (Expr::Lit(l), Expr::Lit(r)) => {
+                discriminant(l) == discriminant(r)
+                    && !matches!(l, Lit::Regex(..) | Lit::JSXText(..))
+            }
+            _ => false,
+        };
+
+        if !is_typeof(left) && !is_typeof(right) && !same_type_literals {
+            return None;
+        }
+
+        // Only whitespace is allowed around the operator, so we don't break
+        // comments.
+        let span = Span::new(bin_expr.left.span_hi(), bin_expr.right.span_lo());
+        let snippet = self.source_map.span_to_snippet(span).ok()?;
+        if snippet.trim() != actual {
+            return None;
+        }
+
+        Some((span, snippet.replacen(actual, expected, 1)))
+    }
+
+    fn check(&self, bin_expr: &BinExpr) {
+        match bin_expr.op {
             op!("==") => {
                 if let EqEqEqMode::Always = self.mode {
-                    self.emit_report(span, "==", "===");
+                    self.emit_report(bin_expr, "==", "===");
                 }
             }
             op!("!=") => {
                 if let EqEqEqMode::Always = self.mode {
-                    self.emit_report(span, "!=", "!==");
+                    self.emit_report(bin_expr, "!=", "!==");
                 }
             }
             op!("===") => {
                 if let EqEqEqMode::Never = self.mode {
-                    self.emit_report(span, "===", "==");
+                    self.emit_report(bin_expr, "===", "==");
                 }
             }
             op!("!==") => {
                 if let EqEqEqMode::Never = self.mode {
-                    self.emit_report(span, "!==", "!=");
+                    self.emit_report(bin_expr, "!==", "!=");
                 }
             }
             _ => {}
@@ -93,7 +163,7 @@ impl Visit for Eqeqeq {
     noop_visit_type!();
 
     fn visit_bin_expr(&mut self, bin_expr: &BinExpr) {
-        self.check(bin_expr.span, &bin_expr.op);
+        self.check(bin_expr);
 
         bin_expr.visit_children_with(self);
     }
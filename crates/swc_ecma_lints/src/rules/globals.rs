@@ -0,0 +1,206 @@
+//! Names of global variables defined by JavaScript environments.
+
+use serde::{Deserialize, Serialize};
+
+/// Globals of the ECMAScript standard library.
+pub const BUILTIN: &[&str] = &[
+    "AggregateError",
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "DataView",
+    "Date",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "Error",
+    "escape",
+    "eval",
+    "EvalError",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "globalThis",
+    "Infinity",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Intl",
+    "isFinite",
+    "isNaN",
+    "Iterator",
+    "JSON",
+    "Map",
+    "Math",
+    "NaN",
+    "Number",
+    "Object",
+    "parseFloat",
+    "parseInt",
+    "Promise",
+    "Proxy",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TypeError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "undefined",
+    "unescape",
+    "URIError",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+];
+
+/// Globals shared by browsers, web workers and Node.js.
+const WEB_COMMON: &[&str] = &[
+    "AbortController",
+    "AbortSignal",
+    "atob",
+    "Blob",
+    "btoa",
+    "BroadcastChannel",
+    "clearInterval",
+    "clearTimeout",
+    "console",
+    "crypto",
+    "CustomEvent",
+    "DOMException",
+    "Event",
+    "EventTarget",
+    "fetch",
+    "FormData",
+    "Headers",
+    "MessageChannel",
+    "MessageEvent",
+    "MessagePort",
+    "performance",
+    "queueMicrotask",
+    "ReadableStream",
+    "Request",
+    "Response",
+    "setInterval",
+    "setTimeout",
+    "structuredClone",
+    "TextDecoder",
+    "TextEncoder",
+    "TransformStream",
+    "URL",
+    "URLSearchParams",
+    "WebAssembly",
+    "WritableStream",
+];
+
+const BROWSER: &[&str] = &[
+    "alert",
+    "cancelAnimationFrame",
+    "cancelIdleCallback",
+    "confirm",
+    "customElements",
+    "document",
+    "Document",
+    "Element",
+    "File",
+    "FileReader",
+    "getComputedStyle",
+    "history",
+    "HTMLElement",
+    "Image",
+    "indexedDB",
+    "IntersectionObserver",
+    "localStorage",
+    "location",
+    "matchMedia",
+    "MutationObserver",
+    "navigator",
+    "Node",
+    "open",
+    "prompt",
+    "requestAnimationFrame",
+    "requestIdleCallback",
+    "ResizeObserver",
+    "screen",
+    "self",
+    "sessionStorage",
+    "WebSocket",
+    "window",
+    "Worker",
+    "XMLHttpRequest",
+];
+
+const WORKER: &[&str] = &[
+    "close",
+    "importScripts",
+    "indexedDB",
+    "postMessage",
+    "self",
+    "WebSocket",
+    "XMLHttpRequest",
+];
+
+const NODE: &[&str] = &[
+    "Buffer",
+    "clearImmediate",
+    "global",
+    "process",
+    "setImmediate",
+];
+
+const COMMONJS: &[&str] = &["__dirname", "__filename", "exports", "module", "require"];
+
+const JEST: &[&str] = &[
+    "afterAll",
+    "afterEach",
+    "beforeAll",
+    "beforeEach",
+    "describe",
+    "expect",
+    "fit",
+    "it",
+    "jest",
+    "test",
+    "xdescribe",
+    "xit",
+    "xtest",
+];
+
+/// A set of globals to be considered as defined, in addition to [BUILTIN].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Env {
+    Browser,
+    Worker,
+    /// Node.js, including the globals of CommonJS modules.
+    Node,
+    Commonjs,
+    Jest,
+}
+
+impl Env {
+    pub fn globals(self) -> impl Iterator<Item = &'static str> {
+        let sets: &[&[&str]] = match self {
+            Env::Browser => &[WEB_COMMON, BROWSER],
+            Env::Worker => &[WEB_COMMON, WORKER],
+            Env::Node => &[WEB_COMMON, NODE, COMMONJS],
+            Env::Commonjs => &[COMMONJS],
+            Env::Jest => &[JEST],
+        };
+
+        sets.iter().flat_map(|set| set.iter().copied())
+    }
+}
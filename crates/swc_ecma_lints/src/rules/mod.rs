@@ -3,7 +3,10 @@ use std::sync::Arc;
 use swc_common::{SourceMap, SyntaxContext};
 use swc_ecma_ast::*;
 
-use crate::{config::LintConfig, rule::Rule};
+use crate::{
+    config::LintConfig,
+    rule::{named_rule, Rule},
+};
 
 mod const_assign;
 mod duplicate_bindings;
@@ -19,6 +22,7 @@ pub(crate) mod non_critical_lints {
     pub mod default_param_last;
     pub mod dot_notation;
     pub mod eqeqeq;
+    pub(crate) mod globals;
     pub mod no_alert;
     pub mod no_await_in_loop;
     pub mod no_bitwise;
@@ -35,9 +39,14 @@ pub(crate) mod non_critical_lints {
     pub mod no_obj_calls;
     pub mod no_param_reassign;
     pub mod no_prototype_builtins;
+    pub mod no_redeclare;
     pub mod no_restricted_syntax;
+    pub mod no_shadow;
     pub mod no_sparse_arrays;
     pub mod no_throw_literal;
+    pub mod no_undef;
+    pub mod no_unused_private_class_members;
+    pub mod no_unused_vars;
     pub mod no_use_before_define;
     pub mod no_var;
     pub mod prefer_const;
@@ -45,6 +54,7 @@ pub(crate) mod non_critical_lints {
     pub mod prefer_regex_literals;
     pub mod quotes;
     pub mod radix;
+    pub(crate) mod scope;
     pub mod symbol_description;
     pub mod use_is_nan;
     pub mod valid_typeof;
@@ -65,10 +75,13 @@ pub struct LintParams<'a> {
 
 pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
     let mut rules = vec![
-        const_assign::const_assign(),
-        duplicate_bindings::duplicate_bindings(),
-        duplicate_exports::duplicate_exports(),
-        no_dupe_args::no_dupe_args(),
+        named_rule("const-assign", const_assign::const_assign()),
+        named_rule(
+            "duplicate-bindings",
+            duplicate_bindings::duplicate_bindings(),
+        ),
+        named_rule("duplicate-exports", duplicate_exports::duplicate_exports()),
+        named_rule("no-dupe-args", no_dupe_args::no_dupe_args()),
     ];
 
     #[cfg(feature = "non_critical_lints")]
@@ -77,140 +90,209 @@ pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
             program,
             lint_config,
             unresolved_ctxt,
-            top_level_ctxt: _,
+            top_level_ctxt,
             es_version,
             source_map,
         } = lint_params;
 
-        rules.extend(no_use_before_define::no_use_before_define(
-            &lint_params.lint_config.no_use_before_define,
-        ));
-
-        rules.extend(no_console::no_console(
-            &lint_config.no_console,
-            unresolved_ctxt,
-        ));
-
-        rules.extend(no_alert::no_alert(
-            &lint_config.no_alert,
-            unresolved_ctxt,
-            es_version,
-        ));
-
-        rules.extend(no_debugger::no_debugger(&lint_config.no_debugger));
-
-        rules.extend(quotes::quotes(&lint_config.quotes));
-
-        rules.extend(prefer_regex_literals::prefer_regex_literals(
-            &lint_config.prefer_regex_literals,
-            unresolved_ctxt,
-            es_version,
-        ));
-
-        rules.extend(dot_notation::dot_notation(
-            program,
-            &lint_config.dot_notation,
-        ));
-
-        rules.extend(no_empty_function::no_empty_function(
-            &source_map,
-            &lint_config.no_empty_function,
-        ));
-
-        rules.extend(no_empty_pattern::no_empty_pattern(
-            &lint_config.no_empty_pattern,
-        ));
-
-        rules.extend(eqeqeq::eqeqeq(&lint_config.eqeqeq));
-
-        rules.extend(no_loop_func::no_loop_func(&lint_config.no_loop_func));
-
-        rules.extend(no_new::no_new(&lint_config.no_new));
-
-        rules.extend(no_restricted_syntax::no_restricted_syntax(
-            &lint_config.no_restricted_syntax,
-        ));
-
-        rules.extend(radix::radix(unresolved_ctxt, &lint_config.radix));
-
-        rules.extend(no_bitwise::no_bitwise(&lint_config.no_bitwise));
-
-        rules.extend(default_param_last::default_param_last(
-            &lint_config.default_param_last,
-        ));
-
-        rules.extend(yoda::yoda(&lint_config.yoda));
-
-        rules.extend(no_new_symbol::no_new_symbol(
-            unresolved_ctxt,
-            &lint_config.no_new_symbol,
-        ));
-
-        rules.extend(use_is_nan::use_is_nan(
-            unresolved_ctxt,
-            &lint_config.use_isnan,
-        ));
-
-        rules.extend(valid_typeof::valid_typeof(&lint_config.valid_typeof));
-
-        rules.extend(no_param_reassign::no_param_reassign(
-            &lint_config.no_param_reassign,
-        ));
-
-        rules.extend(symbol_description::symbol_description(
-            unresolved_ctxt,
-            &lint_config.symbol_description,
-        ));
-
-        rules.extend(no_obj_calls::no_obj_calls(
-            unresolved_ctxt,
-            &lint_config.no_obj_calls,
-        ));
-
-        rules.extend(no_throw_literal::no_throw_literal(
-            &lint_config.no_throw_literal,
-        ));
-
-        rules.extend(no_var::no_var(&lint_config.no_var));
-
-        rules.extend(prefer_const::prefer_const(&lint_config.prefer_const));
-
-        rules.extend(no_compare_neg_zero::no_compare_neg_zero(
-            &lint_config.no_compare_neg_zero,
-        ));
-
-        rules.extend(constructor_super::constructor_super(
-            &lint_config.constructor_super,
-        ));
-
-        rules.extend(no_sparse_arrays::no_sparse_arrays(
-            &lint_config.no_sparse_arrays,
-        ));
-
-        rules.extend(default_case_last::default_case_last(
-            &lint_config.default_case_last,
-        ));
-
-        rules.extend(no_await_in_loop::no_await_in_loop(
-            &lint_config.no_await_in_loop,
-        ));
-
-        rules.extend(no_cond_assign::no_cond_assign(&lint_config.no_cond_assign));
-
-        rules.extend(no_prototype_builtins::no_prototype_builtins(
-            &lint_config.no_prototype_builtins,
-        ));
-
-        rules.extend(no_new_object::no_new_object(
-            unresolved_ctxt,
-            &lint_config.no_new_object,
-        ));
-
-        rules.extend(prefer_object_spread::prefer_object_spread(
-            &lint_config.prefer_object_spread,
-            unresolved_ctxt,
-            es_version,
-        ));
+        let mut add = |name, rule: Option<Box<dyn Rule>>| {
+            rules.extend(rule.map(|rule| named_rule(name, rule)));
+        };
+
+        add(
+            "no-use-before-define",
+            no_use_before_define::no_use_before_define(
+                &lint_params.lint_config.no_use_before_define,
+            ),
+        );
+
+        add(
+            "no-console",
+            no_console::no_console(&lint_config.no_console, unresolved_ctxt),
+        );
+
+        add(
+            "no-alert",
+            no_alert::no_alert(&lint_config.no_alert, unresolved_ctxt, es_version),
+        );
+
+        add(
+            "no-debugger",
+            no_debugger::no_debugger(&lint_config.no_debugger),
+        );
+
+        add("quotes", quotes::quotes(&lint_config.quotes));
+
+        add(
+            "prefer-regex-literals",
+            prefer_regex_literals::prefer_regex_literals(
+                &lint_config.prefer_regex_literals,
+                unresolved_ctxt,
+                es_version,
+            ),
+        );
+
+        add(
+            "dot-notation",
+            dot_notation::dot_notation(program, &lint_config.dot_notation),
+        );
+
+        add(
+            "no-empty-function",
+            no_empty_function::no_empty_function(&source_map, &lint_config.no_empty_function),
+        );
+
+        add(
+            "no-empty-pattern",
+            no_empty_pattern::no_empty_pattern(&lint_config.no_empty_pattern),
+        );
+
+        add("eqeqeq", eqeqeq::eqeqeq(&source_map, &lint_config.eqeqeq));
+
+        add(
+            "no-loop-func",
+            no_loop_func::no_loop_func(&lint_config.no_loop_func),
+        );
+
+        add("no-new", no_new::no_new(&lint_config.no_new));
+
+        add(
+            "no-restricted-syntax",
+            no_restricted_syntax::no_restricted_syntax(&lint_config.no_restricted_syntax),
+        );
+
+        add("radix", radix::radix(unresolved_ctxt, &lint_config.radix));
+
+        add(
+            "no-bitwise",
+            no_bitwise::no_bitwise(&lint_config.no_bitwise),
+        );
+
+        add(
+            "default-param-last",
+            default_param_last::default_param_last(&lint_config.default_param_last),
+        );
+
+        add("yoda", yoda::yoda(&lint_config.yoda));
+
+        add(
+            "no-new-symbol",
+            no_new_symbol::no_new_symbol(unresolved_ctxt, &lint_config.no_new_symbol),
+        );
+
+        add(
+            "use-isnan",
+            use_is_nan::use_is_nan(unresolved_ctxt, &lint_config.use_isnan),
+        );
+
+        add(
+            "valid-typeof",
+            valid_typeof::valid_typeof(&lint_config.valid_typeof),
+        );
+
+        add(
+            "no-param-reassign",
+            no_param_reassign::no_param_reassign(&lint_config.no_param_reassign),
+        );
+
+        add(
+            "symbol-description",
+            symbol_description::symbol_description(
+                unresolved_ctxt,
+                &lint_config.symbol_description,
+            ),
+        );
+
+        add(
+            "no-obj-calls",
+            no_obj_calls::no_obj_calls(unresolved_ctxt, &lint_config.no_obj_calls),
+        );
+
+        add(
+            "no-throw-literal",
+            no_throw_literal::no_throw_literal(&lint_config.no_throw_literal),
+        );
+
+        add("no-var", no_var::no_var(&lint_config.no_var));
+
+        add(
+            "prefer-const",
+            prefer_const::prefer_const(&lint_config.prefer_const),
+        );
+
+        add(
+            "no-compare-neg-zero",
+            no_compare_neg_zero::no_compare_neg_zero(&lint_config.no_compare_neg_zero),
+        );
+
+        add(
+            "constructor-super",
+            constructor_super::constructor_super(&lint_config.constructor_super),
+        );
+
+        add(
+            "no-sparse-arrays",
+            no_sparse_arrays::no_sparse_arrays(&lint_config.no_sparse_arrays),
+        );
+
+        add(
+            "default-case-last",
+            default_case_last::default_case_last(&lint_config.default_case_last),
+        );
+
+        add(
+            "no-await-in-loop",
+            no_await_in_loop::no_await_in_loop(&lint_config.no_await_in_loop),
+        );
+
+        add(
+            "no-cond-assign",
+            no_cond_assign::no_cond_assign(&lint_config.no_cond_assign),
+        );
+
+        add(
+            "no-prototype-builtins",
+            no_prototype_builtins::no_prototype_builtins(&lint_config.no_prototype_builtins),
+        );
+
+        add(
+            "no-new-object",
+            no_new_object::no_new_object(unresolved_ctxt, &lint_config.no_new_object),
+        );
+
+        add(
+            "prefer-object-spread",
+            prefer_object_spread::prefer_object_spread(
+                &source_map,
+                &lint_config.prefer_object_spread,
+                unresolved_ctxt,
+                es_version,
+            ),
+        );
+
+        add(
+            "no-unused-vars",
+            no_unused_vars::no_unused_vars(&lint_config.no_unused_vars, top_level_ctxt),
+        );
+
+        add(
+            "no-undef",
+            no_undef::no_undef(&lint_config.no_undef, unresolved_ctxt),
+        );
+
+        add("no-shadow", no_shadow::no_shadow(&lint_config.no_shadow));
+
+        add(
+            "no-redeclare",
+            no_redeclare::no_redeclare(&lint_config.no_redeclare),
+        );
+
+        add(
+            "no-unused-private-class-members",
+            no_unused_private_class_members::no_unused_private_class_members(
+                &lint_config.no_unused_private_class_members,
+            ),
+        );
     }
 
     rules
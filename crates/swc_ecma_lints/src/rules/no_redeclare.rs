@@ -0,0 +1,190 @@
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, Span};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    rule::{visitor_rule, Rule},
+    rules::{
+        globals::BUILTIN,
+        scope::{DeclKind, Declarations},
+    },
+};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoRedeclareConfig {
+    builtin_globals: Option<bool>,
+}
+
+pub fn no_redeclare(config: &RuleConfig<NoRedeclareConfig>) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoRedeclare::new(config))),
+    }
+}
+
+#[derive(Debug, Default)]
+struct NoRedeclare {
+    expected_reaction: LintRuleReaction,
+    builtin_globals: bool,
+}
+
+impl NoRedeclare {
+    fn new(config: &RuleConfig<NoRedeclareConfig>) -> Self {
+        let rule_config = config.get_rule_config();
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            builtin_globals: rule_config.builtin_globals.unwrap_or(true),
+        }
+    }
+
+    fn emit_report(&self, span: Span, message: &str) {
+        HANDLER.with(|handler| match self.expected_reaction {
+            LintRuleReaction::Error => {
+                handler.struct_span_err(span, message).emit();
+            }
+            LintRuleReaction::Warning => {
+                handler.struct_span_warn(span, message).emit();
+            }
+            _ => {}
+        });
+    }
+
+    /// Checks the `var`-like declarations of a function or a program.
+    ///
+    /// Block-scoped declarations can't be redeclared without a syntax error,
+    /// which is reported by the parser.
+    fn check(&self, mut decls: Declarations, is_script: bool) {
+        decls.0.sort_by_key(|decl| decl.ident.span.lo);
+
+        let mut seen = FxHashSet::<Atom>::default();
+
+        for decl in decls.0 {
+            let ident = &decl.ident;
+
+            if is_script && self.builtin_globals && BUILTIN.contains(&&*ident.sym) {
+                self.emit_report(
+                    ident.span,
+                    &format!(
+                        "'{}' is already defined as a built-in global variable.",
+                        ident.sym
+                    ),
+                );
+                continue;
+            }
+
+            if !seen.insert(ident.sym.clone()) {
+                self.emit_report(ident.span, &format!("'{}' is already defined.", ident.sym));
+            }
+        }
+    }
+}
+
+fn hoisted_functions(stmts: &[Stmt]) -> impl Iterator<Item = &Ident> {
+    stmts.iter().filter_map(|stmt| match stmt {
+        Stmt::Decl(Decl::Fn(f)) if !f.declare && f.function.body.is_some() => Some(&f.ident),
+        _ => None,
+    })
+}
+
+impl Visit for NoRedeclare {
+    noop_visit_type!();
+
+    fn visit_module(&mut self, module: &Module) {
+        let mut decls = Declarations::default();
+        decls.add_hoisted_vars(&module.body);
+
+        self.check(decls, false);
+
+        module.visit_children_with(self);
+    }
+
+    fn visit_script(&mut self, script: &Script) {
+        let mut decls = Declarations::default();
+        decls.add_hoisted_vars(&script.body);
+        for ident in hoisted_functions(&script.body) {
+            decls.add_ident(ident, DeclKind::Function);
+        }
+
+        self.check(decls, true);
+
+        script.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        let mut decls = Declarations::default();
+        if let Some(body) = &function.body {
+            decls.add_hoisted_vars(&body.stmts);
+            for ident in hoisted_functions(&body.stmts) {
+                decls.add_ident(ident, DeclKind::Function);
+            }
+        }
+
+        self.check(decls, false);
+
+        function.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        let mut decls = Declarations::default();
+        if let BlockStmtOrExpr::BlockStmt(body) = &*arrow.body {
+            decls.add_hoisted_vars(&body.stmts);
+            for ident in hoisted_functions(&body.stmts) {
+                decls.add_ident(ident, DeclKind::Function);
+            }
+        }
+
+        self.check(decls, false);
+
+        arrow.visit_children_with(self);
+    }
+
+    fn visit_constructor(&mut self, constructor: &Constructor) {
+        let mut decls = Declarations::default();
+        if let Some(body) = &constructor.body {
+            decls.add_hoisted_vars(&body.stmts);
+        }
+
+        self.check(decls, false);
+
+        constructor.visit_children_with(self);
+    }
+
+    fn visit_getter_prop(&mut self, getter: &GetterProp) {
+        let mut decls = Declarations::default();
+        if let Some(body) = &getter.body {
+            decls.add_hoisted_vars(&body.stmts);
+        }
+
+        self.check(decls, false);
+
+        getter.visit_children_with(self);
+    }
+
+    fn visit_setter_prop(&mut self, setter: &SetterProp) {
+        let mut decls = Declarations::default();
+        if let Some(body) = &setter.body {
+            decls.add_hoisted_vars(&body.stmts);
+        }
+
+        self.check(decls, false);
+
+        setter.visit_children_with(self);
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock) {
+        let mut decls = Declarations::default();
+        decls.add_hoisted_vars(&block.body.stmts);
+
+        self.check(decls, false);
+
+        block.visit_children_with(self);
+    }
+
+    fn visit_ts_module_decl(&mut self, _: &TsModuleDecl) {}
+}
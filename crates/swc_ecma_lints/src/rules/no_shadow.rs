@@ -0,0 +1,321 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, Span};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    rule::{visitor_rule, Rule},
+    rules::{
+        globals::BUILTIN,
+        scope::{DeclKind, Declarations},
+    },
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HoistMode {
+    /// Report shadowing of functions declared later in the upper scope.
+    #[default]
+    Functions,
+    /// Report shadowing of all variables declared later in the upper scope.
+    All,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoShadowConfig {
+    builtin_globals: Option<bool>,
+    hoist: Option<HoistMode>,
+    allow: Option<FxHashSet<String>>,
+}
+
+pub fn no_shadow(config: &RuleConfig<NoShadowConfig>) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoShadow::new(config))),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Binding {
+    span: Span,
+    kind: DeclKind,
+}
+
+#[derive(Debug, Default)]
+struct NoShadow {
+    expected_reaction: LintRuleReaction,
+    builtin_globals: bool,
+    hoist: HoistMode,
+    allow: FxHashSet<String>,
+
+    scopes: Vec<FxHashMap<Atom, Binding>>,
+    /// `true` if the outermost scope is the global scope of a script.
+    is_script: bool,
+}
+
+impl NoShadow {
+    fn new(config: &RuleConfig<NoShadowConfig>) -> Self {
+        let rule_config = config.get_rule_config();
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            builtin_globals: rule_config.builtin_globals.unwrap_or(false),
+            hoist: rule_config.hoist.unwrap_or_default(),
+            allow: rule_config.allow.clone().unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    fn emit_report(&self, span: Span, message: &str) {
+        HANDLER.with(|handler| match self.expected_reaction {
+            LintRuleReaction::Error => {
+                handler.struct_span_err(span, message).emit();
+            }
+            LintRuleReaction::Warning => {
+                handler.struct_span_warn(span, message).emit();
+            }
+            _ => {}
+        });
+    }
+
+    fn is_shadowing(&self, ident: &Ident, outer: &Binding) -> bool {
+        if outer.span.lo <= ident.span.lo {
+            return true;
+        }
+
+        // The shadowed variable is declared after the shadowing one.
+        match self.hoist {
+            HoistMode::All => true,
+            HoistMode::Functions => outer.kind == DeclKind::Function,
+            HoistMode::Never => false,
+        }
+    }
+
+    fn with_scope(&mut self, decls: Declarations, op: impl FnOnce(&mut Self)) {
+        let mut scope = FxHashMap::default();
+
+        for decl in decls.0 {
+            let ident = &decl.ident;
+
+            if scope.contains_key(&ident.sym) {
+                continue;
+            }
+            scope.insert(
+                ident.sym.clone(),
+                Binding {
+                    span: ident.span,
+                    kind: decl.kind,
+                },
+            );
+
+            if self.allow.contains(&*ident.sym) {
+                continue;
+            }
+
+            let outer = self
+                .scopes
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(&ident.sym));
+
+            match outer {
+                Some(outer) => {
+                    if self.is_shadowing(ident, outer) {
+                        self.emit_report(
+                            ident.span,
+                            &format!("'{}' is already declared in the upper scope.", ident.sym),
+                        );
+                    }
+                }
+                None => {
+                    let is_global_scope = self.scopes.is_empty() && self.is_script;
+
+                    if self.builtin_globals && !is_global_scope && BUILTIN.contains(&&*ident.sym) {
+                        self.emit_report(
+                            ident.span,
+                            &format!("'{}' is already a global variable.", ident.sym),
+                        );
+                    }
+                }
+            }
+        }
+
+        self.scopes.push(scope);
+        op(self);
+        self.scopes.pop();
+    }
+
+    fn visit_fn_like<'a>(
+        &mut self,
+        params: impl IntoIterator<Item = &'a Pat>,
+        body: Option<&BlockStmt>,
+    ) {
+        let params = params.into_iter().collect::<Vec<_>>();
+
+        let mut decls = Declarations::default();
+        decls.add_params(params.iter().copied());
+        if let Some(body) = body {
+            decls.add_lexical(&body.stmts);
+            decls.add_hoisted_vars(&body.stmts);
+        }
+
+        self.with_scope(decls, |v| {
+            params.iter().for_each(|param| param.visit_with(v));
+            if let Some(body) = body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+}
+
+impl Visit for NoShadow {
+    noop_visit_type!();
+
+    fn visit_module(&mut self, module: &Module) {
+        self.is_script = false;
+
+        let mut decls = Declarations::default();
+        decls.add_lexical_module_items(&module.body);
+        decls.add_hoisted_vars(&module.body);
+
+        self.with_scope(decls, |v| module.body.visit_with(v));
+    }
+
+    fn visit_script(&mut self, script: &Script) {
+        self.is_script = true;
+
+        let mut decls = Declarations::default();
+        decls.add_lexical(&script.body);
+        decls.add_hoisted_vars(&script.body);
+
+        self.with_scope(decls, |v| script.body.visit_with(v));
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        function.decorators.visit_with(self);
+
+        self.visit_fn_like(
+            function.params.iter().map(|param| &param.pat),
+            function.body.as_ref(),
+        );
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        match &*arrow.body {
+            BlockStmtOrExpr::BlockStmt(body) => self.visit_fn_like(&arrow.params, Some(body)),
+            BlockStmtOrExpr::Expr(expr) => {
+                let mut decls = Declarations::default();
+                decls.add_params(&arrow.params);
+
+                self.with_scope(decls, |v| {
+                    arrow.params.visit_with(v);
+                    expr.visit_with(v);
+                });
+            }
+        }
+    }
+
+    fn visit_constructor(&mut self, constructor: &Constructor) {
+        let mut decls = Declarations::default();
+        decls.add_constructor_params(&constructor.params);
+        if let Some(body) = &constructor.body {
+            decls.add_lexical(&body.stmts);
+            decls.add_hoisted_vars(&body.stmts);
+        }
+
+        self.with_scope(decls, |v| {
+            constructor.params.visit_with(v);
+            if let Some(body) = &constructor.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn visit_getter_prop(&mut self, getter: &GetterProp) {
+        getter.key.visit_with(self);
+        self.visit_fn_like([], getter.body.as_ref());
+    }
+
+    fn visit_setter_prop(&mut self, setter: &SetterProp) {
+        setter.key.visit_with(self);
+        self.visit_fn_like([&*setter.param], setter.body.as_ref());
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock) {
+        self.visit_fn_like([], Some(&block.body));
+    }
+
+    fn visit_block_stmt(&mut self, block: &BlockStmt) {
+        let mut decls = Declarations::default();
+        decls.add_lexical(&block.stmts);
+
+        self.with_scope(decls, |v| block.stmts.visit_with(v));
+    }
+
+    fn visit_switch_stmt(&mut self, stmt: &SwitchStmt) {
+        stmt.discriminant.visit_with(self);
+
+        let mut decls = Declarations::default();
+        for case in &stmt.cases {
+            decls.add_lexical(&case.cons);
+        }
+
+        self.with_scope(decls, |v| stmt.cases.visit_with(v));
+    }
+
+    fn visit_for_stmt(&mut self, stmt: &ForStmt) {
+        let decls = match &stmt.init {
+            Some(VarDeclOrExpr::VarDecl(var)) => lexical_var_decls(var),
+            _ => Default::default(),
+        };
+
+        self.with_scope(decls, |v| stmt.visit_children_with(v));
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &ForInStmt) {
+        let decls = for_head_decls(&stmt.left);
+
+        self.with_scope(decls, |v| stmt.visit_children_with(v));
+    }
+
+    fn visit_for_of_stmt(&mut self, stmt: &ForOfStmt) {
+        let decls = for_head_decls(&stmt.left);
+
+        self.with_scope(decls, |v| stmt.visit_children_with(v));
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause) {
+        let mut decls = Declarations::default();
+        if let Some(param) = &clause.param {
+            decls.add_pat(param, DeclKind::CatchParam);
+        }
+
+        self.with_scope(decls, |v| clause.visit_children_with(v));
+    }
+
+    fn visit_ts_module_decl(&mut self, _: &TsModuleDecl) {}
+}
+
+fn for_head_decls(head: &ForHead) -> Declarations {
+    match head {
+        ForHead::VarDecl(var) => lexical_var_decls(var),
+        _ => Default::default(),
+    }
+}
+
+fn lexical_var_decls(var: &VarDecl) -> Declarations {
+    let mut decls = Declarations::default();
+
+    if var.kind != VarDeclKind::Var {
+        for decl in &var.decls {
+            decls.add_pat(&decl.name, DeclKind::Lexical);
+        }
+    }
+
+    decls
+}
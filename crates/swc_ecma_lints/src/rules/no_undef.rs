@@ -0,0 +1,132 @@
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    rule::{visitor_rule, Rule},
+    rules::globals::{Env, BUILTIN},
+};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoUndefConfig {
+    /// Presets of globals defined by the environment the code runs in.
+    env: Option<Vec<Env>>,
+    /// Additional globals.
+    globals: Option<Vec<String>>,
+    /// Whether to report undefined variables used as the operand of `typeof`.
+    #[serde(rename = "typeof")]
+    check_typeof: Option<bool>,
+}
+
+pub fn no_undef(
+    config: &RuleConfig<NoUndefConfig>,
+    unresolved_ctxt: SyntaxContext,
+) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoUndef::new(config, unresolved_ctxt))),
+    }
+}
+
+#[derive(Debug, Default)]
+struct NoUndef {
+    expected_reaction: LintRuleReaction,
+    unresolved_ctxt: SyntaxContext,
+    globals: FxHashSet<Atom>,
+    check_typeof: bool,
+}
+
+impl NoUndef {
+    fn new(config: &RuleConfig<NoUndefConfig>, unresolved_ctxt: SyntaxContext) -> Self {
+        let rule_config = config.get_rule_config();
+
+        let mut globals: FxHashSet<Atom> = BUILTIN.iter().map(|&name| name.into()).collect();
+        globals.insert("arguments".into());
+        for env in rule_config.env.iter().flatten() {
+            globals.extend(env.globals().map(Atom::from));
+        }
+        globals.extend(
+            rule_config
+                .globals
+                .iter()
+                .flatten()
+                .map(|name| Atom::from(&**name)),
+        );
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            unresolved_ctxt,
+            globals,
+            check_typeof: rule_config.check_typeof.unwrap_or(false),
+        }
+    }
+
+    fn emit_report(&self, ident: &Ident) {
+        let message = format!("'{}' is not defined.", ident.sym);
+
+        HANDLER.with(|handler| match self.expected_reaction {
+            LintRuleReaction::Error => {
+                handler.struct_span_err(ident.span, &message).emit();
+            }
+            LintRuleReaction::Warning => {
+                handler.struct_span_warn(ident.span, &message).emit();
+            }
+            _ => {}
+        });
+    }
+}
+
+impl Visit for NoUndef {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if ident.ctxt == self.unresolved_ctxt && !self.globals.contains(&ident.sym) {
+            self.emit_report(ident);
+        }
+    }
+
+    fn visit_unary_expr(&mut self, expr: &UnaryExpr) {
+        if expr.op == op!("typeof") && !self.check_typeof && expr.arg.is_ident() {
+            return;
+        }
+
+        expr.visit_children_with(self);
+    }
+
+    fn visit_named_export(&mut self, export: &NamedExport) {
+        // Re-exports don't refer to local bindings.
+        if export.src.is_some() {
+            return;
+        }
+
+        for specifier in &export.specifiers {
+            if let ExportSpecifier::Named(ExportNamedSpecifier { orig, .. }) = specifier {
+                orig.visit_with(self);
+            }
+        }
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &JSXElementName) {
+        match name {
+            // Lowercase names are intrinsic elements.
+            JSXElementName::Ident(ident)
+                if ident.sym.starts_with(|c: char| c.is_ascii_lowercase()) => {}
+            _ => name.visit_children_with(self),
+        }
+    }
+
+    fn visit_labeled_stmt(&mut self, stmt: &LabeledStmt) {
+        stmt.body.visit_with(self);
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt) {}
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt) {}
+
+    fn visit_ts_module_decl(&mut self, _: &TsModuleDecl) {}
+}
@@ -0,0 +1,162 @@
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, Span};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    rule::{visitor_rule, Rule},
+};
+
+pub fn no_unused_private_class_members(config: &RuleConfig<()>) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoUnusedPrivateClassMembers::new(config))),
+    }
+}
+
+#[derive(Debug)]
+struct PrivateMember {
+    name: Atom,
+    span: Span,
+    used: bool,
+}
+
+#[derive(Debug, Default)]
+struct NoUnusedPrivateClassMembers {
+    expected_reaction: LintRuleReaction,
+    /// Private members declared by each of the enclosing classes.
+    classes: Vec<Vec<PrivateMember>>,
+}
+
+impl NoUnusedPrivateClassMembers {
+    fn new(config: &RuleConfig<()>) -> Self {
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            classes: Vec::new(),
+        }
+    }
+
+    fn emit_report(&self, member: &PrivateMember) {
+        let message = format!("'#{}' is defined but never used.", member.name);
+
+        HANDLER.with(|handler| match self.expected_reaction {
+            LintRuleReaction::Error => {
+                handler.struct_span_err(member.span, &message).emit();
+            }
+            LintRuleReaction::Warning => {
+                handler.struct_span_warn(member.span, &message).emit();
+            }
+            _ => {}
+        });
+    }
+
+    fn mark_used(&mut self, name: &PrivateName) {
+        // Private names are resolved lexically, to the innermost class declaring them.
+        for class in self.classes.iter_mut().rev() {
+            let mut found = false;
+
+            for member in class.iter_mut().filter(|member| member.name == name.name) {
+                member.used = true;
+                found = true;
+            }
+
+            if found {
+                return;
+            }
+        }
+    }
+
+    /// Visits `expr` as the target of a write, which doesn't count as a usage.
+    fn visit_write_target(&mut self, expr: &MemberExpr) {
+        expr.obj.visit_with(self);
+    }
+}
+
+impl Visit for NoUnusedPrivateClassMembers {
+    noop_visit_type!();
+
+    fn visit_class(&mut self, class: &Class) {
+        let members = class
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                ClassMember::PrivateProp(PrivateProp { key, .. })
+                | ClassMember::PrivateMethod(PrivateMethod { key, .. }) => Some(PrivateMember {
+                    name: key.name.clone(),
+                    span: key.span,
+                    used: false,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        self.classes.push(members);
+        class.visit_children_with(self);
+
+        let members = self.classes.pop().unwrap_or_default();
+
+        let mut reported = Vec::<&Atom>::new();
+        for member in &members {
+            // Accessor pairs share a name.
+            let used = members
+                .iter()
+                .any(|other| other.name == member.name && other.used);
+
+            if !used && !reported.contains(&&member.name) {
+                self.emit_report(member);
+                reported.push(&member.name);
+            }
+        }
+    }
+
+    fn visit_member_expr(&mut self, expr: &MemberExpr) {
+        if let MemberProp::PrivateName(name) = &expr.prop {
+            self.mark_used(name);
+        }
+
+        expr.visit_children_with(self);
+    }
+
+    fn visit_bin_expr(&mut self, expr: &BinExpr) {
+        // `#x in obj`
+        if let Expr::PrivateName(name) = &*expr.left {
+            self.mark_used(name);
+        }
+
+        expr.visit_children_with(self);
+    }
+
+    fn visit_assign_expr(&mut self, expr: &AssignExpr) {
+        match &expr.left {
+            AssignTarget::Simple(SimpleAssignTarget::Member(
+                member @ MemberExpr {
+                    prop: MemberProp::PrivateName(..),
+                    ..
+                },
+            )) if expr.op == op!("=") => {
+                self.visit_write_target(member);
+                expr.right.visit_with(self);
+            }
+            _ => expr.visit_children_with(self),
+        }
+    }
+
+    fn visit_expr_stmt(&mut self, stmt: &ExprStmt) {
+        // The result of `this.#x++` is discarded.
+        if let Expr::Update(UpdateExpr { arg, .. }) = &*stmt.expr {
+            if let Expr::Member(
+                member @ MemberExpr {
+                    prop: MemberProp::PrivateName(..),
+                    ..
+                },
+            ) = &**arg
+            {
+                self.visit_write_target(member);
+                return;
+            }
+        }
+
+        stmt.visit_children_with(self);
+    }
+}
@@ -0,0 +1,638 @@
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_usage_analyzer::{
+    alias::Access,
+    analyzer::{
+        analyze_with_storage,
+        storage::{ScopeDataLike, Storage, VarDataLike},
+        Ctx, ScopeKind,
+    },
+};
+use swc_ecma_utils::{find_pat_ids, Type, Value};
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    rule::{visitor_rule, Rule},
+};
+
+const INVALID_REGEX_MESSAGE: &str = "no-unused-vars: invalid regex pattern in ignore pattern. Check syntax documentation https://docs.rs/regex/latest/regex/#syntax";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VarsMode {
+    #[default]
+    All,
+    /// Top-level variables of scripts are not checked.
+    Local,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArgsMode {
+    /// Only arguments after the last used argument are checked.
+    #[default]
+    AfterUsed,
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaughtErrorsMode {
+    #[default]
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoUnusedVarsConfig {
+    vars: Option<VarsMode>,
+    vars_ignore_pattern: Option<String>,
+    args: Option<ArgsMode>,
+    args_ignore_pattern: Option<String>,
+    caught_errors: Option<CaughtErrorsMode>,
+    caught_errors_ignore_pattern: Option<String>,
+    ignore_rest_siblings: Option<bool>,
+}
+
+pub fn no_unused_vars(
+    config: &RuleConfig<NoUnusedVarsConfig>,
+    top_level_ctxt: SyntaxContext,
+) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoUnusedVars::new(config, top_level_ctxt))),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BindingKind {
+    Var,
+    /// Function, class and import declarations, which are checked like
+    /// variables but are never considered as assigned.
+    Decl,
+    Arg,
+    CaughtError,
+}
+
+#[derive(Debug, Default)]
+struct NoUnusedVars {
+    expected_reaction: LintRuleReaction,
+    top_level_ctxt: SyntaxContext,
+    vars: VarsMode,
+    vars_ignore_pattern: Option<Regex>,
+    args: ArgsMode,
+    args_ignore_pattern: Option<Regex>,
+    caught_errors: CaughtErrorsMode,
+    caught_errors_ignore_pattern: Option<Regex>,
+    ignore_rest_siblings: bool,
+
+    is_script: bool,
+    usage: UsageData,
+    /// Bindings referenced from type annotations.
+    type_refs: FxHashSet<Id>,
+    exported: FxHashSet<Id>,
+    has_jsx: bool,
+    reported: FxHashSet<Id>,
+}
+
+impl NoUnusedVars {
+    fn new(config: &RuleConfig<NoUnusedVarsConfig>, top_level_ctxt: SyntaxContext) -> Self {
+        let rule_config = config.get_rule_config();
+        let regex = |pattern: &Option<String>| {
+            pattern
+                .as_deref()
+                .map(|pattern| Regex::new(pattern).expect(INVALID_REGEX_MESSAGE))
+        };
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            top_level_ctxt,
+            vars: rule_config.vars.unwrap_or_default(),
+            vars_ignore_pattern: regex(&rule_config.vars_ignore_pattern),
+            args: rule_config.args.unwrap_or_default(),
+            args_ignore_pattern: regex(&rule_config.args_ignore_pattern),
+            caught_errors: rule_config.caught_errors.unwrap_or_default(),
+            caught_errors_ignore_pattern: regex(&rule_config.caught_errors_ignore_pattern),
+            ignore_rest_siblings: rule_config.ignore_rest_siblings.unwrap_or(false),
+            ..Default::default()
+        }
+    }
+
+    fn emit_report(&self, ident: &Ident, assigned: bool) {
+        let message = if assigned {
+            format!("'{}' is assigned a value but never used.", ident.sym)
+        } else {
+            format!("'{}' is defined but never used.", ident.sym)
+        };
+
+        HANDLER.with(|handler| match self.expected_reaction {
+            LintRuleReaction::Error => {
+                handler.struct_span_err(ident.span, &message).emit();
+            }
+            LintRuleReaction::Warning => {
+                handler.struct_span_warn(ident.span, &message).emit();
+            }
+            _ => {}
+        });
+    }
+
+    fn is_used(&self, id: &Id) -> bool {
+        self.usage
+            .vars
+            .get(id)
+            .is_some_and(|var| var.usage_count > 0 || var.exported)
+            || self.exported.contains(id)
+            || self.type_refs.contains(id)
+            || (self.has_jsx && id.0 == "React")
+    }
+
+    fn is_ignored(&self, ident: &Ident, kind: BindingKind) -> bool {
+        let pattern = match kind {
+            BindingKind::Var | BindingKind::Decl => &self.vars_ignore_pattern,
+            BindingKind::Arg => &self.args_ignore_pattern,
+            BindingKind::CaughtError => &self.caught_errors_ignore_pattern,
+        };
+
+        pattern
+            .as_ref()
+            .is_some_and(|pattern| pattern.is_match(&ident.sym))
+    }
+
+    fn check(&mut self, ident: &Ident, kind: BindingKind) {
+        let id = ident.to_id();
+
+        if self.is_used(&id) || self.is_ignored(ident, kind) {
+            return;
+        }
+
+        if matches!(kind, BindingKind::Var | BindingKind::Decl)
+            && self.vars == VarsMode::Local
+            && self.is_script
+            && ident.ctxt == self.top_level_ctxt
+        {
+            return;
+        }
+
+        if !self.reported.insert(id.clone()) {
+            return;
+        }
+
+        let assigned =
+            kind == BindingKind::Var && self.usage.vars.get(&id).is_some_and(|var| var.assigned);
+
+        self.emit_report(ident, assigned);
+    }
+
+    /// Returns the bindings of `pat` which should be checked.
+    fn collect_bindings<'a>(&self, pat: &'a Pat, bindings: &mut Vec<&'a Ident>) {
+        match pat {
+            Pat::Ident(ident) => {
+                if ident.sym != "this" {
+                    bindings.push(&ident.id)
+                }
+            }
+            Pat::Array(array) => {
+                for elem in array.elems.iter().flatten() {
+                    self.collect_bindings(elem, bindings);
+                }
+            }
+            Pat::Rest(rest) => self.collect_bindings(&rest.arg, bindings),
+            Pat::Object(object) => {
+                let skip_siblings = self.ignore_rest_siblings
+                    && object
+                        .props
+                        .iter()
+                        .any(|prop| matches!(prop, ObjectPatProp::Rest(..)));
+
+                for prop in &object.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => {
+                            if !skip_siblings {
+                                self.collect_bindings(value, bindings)
+                            }
+                        }
+                        ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
+                            if !skip_siblings {
+                                bindings.push(&key.id)
+                            }
+                        }
+                        ObjectPatProp::Rest(rest) => self.collect_bindings(&rest.arg, bindings),
+                    }
+                }
+            }
+            Pat::Assign(assign) => self.collect_bindings(&assign.left, bindings),
+            Pat::Invalid(..) | Pat::Expr(..) => {}
+        }
+    }
+
+    fn check_pat(&mut self, pat: &Pat, kind: BindingKind) {
+        let mut bindings = Vec::new();
+        self.collect_bindings(pat, &mut bindings);
+
+        for ident in bindings {
+            self.check(ident, kind);
+        }
+    }
+
+    fn check_params<'a>(&mut self, params: impl IntoIterator<Item = &'a Pat>) {
+        let params = params
+            .into_iter()
+            .map(|pat| {
+                let mut bindings = Vec::new();
+                self.collect_bindings(pat, &mut bindings);
+                (is_positional(pat), bindings)
+            })
+            .collect::<Vec<_>>();
+
+        let last_used = match self.args {
+            ArgsMode::None => return,
+            ArgsMode::All => None,
+            ArgsMode::AfterUsed => params.iter().rposition(|(_, bindings)| {
+                bindings.iter().any(|ident| self.is_used(&ident.to_id()))
+            }),
+        };
+
+        for (idx, (positional, bindings)) in params.into_iter().enumerate() {
+            // Unused positional parameters before the last used one are
+            // required to access the used one.
+            if positional && last_used.is_some_and(|last_used| idx < last_used) {
+                continue;
+            }
+
+            for ident in bindings {
+                self.check(ident, BindingKind::Arg);
+            }
+        }
+    }
+
+    fn analyze<N>(&mut self, program: &N)
+    where
+        N: VisitWith<UsageAnalyzer> + VisitWith<TypeRefCollector> + VisitWith<ExportCollector>,
+    {
+        self.usage = analyze_with_storage(program, None);
+
+        let mut type_refs = TypeRefCollector::default();
+        program.visit_with(&mut type_refs);
+        self.type_refs = type_refs.refs;
+        self.has_jsx = type_refs.has_jsx;
+
+        let mut exports = ExportCollector::default();
+        program.visit_with(&mut exports);
+        self.exported = exports.exported;
+    }
+}
+
+/// Returns `true` if `pat` binds the parameter as a whole, instead of
+/// destructuring it.
+fn is_positional(pat: &Pat) -> bool {
+    match pat {
+        Pat::Ident(..) => true,
+        Pat::Assign(AssignPat { left, .. }) => is_positional(left),
+        Pat::Rest(RestPat { arg, .. }) => is_positional(arg),
+        _ => false,
+    }
+}
+
+type UsageAnalyzer = swc_ecma_usage_analyzer::analyzer::UsageAnalyzer<UsageData>;
+
+impl Visit for NoUnusedVars {
+    noop_visit_type!();
+
+    fn visit_module(&mut self, module: &Module) {
+        self.is_script = false;
+        self.analyze(module);
+
+        module.visit_children_with(self);
+    }
+
+    fn visit_script(&mut self, script: &Script) {
+        self.is_script = true;
+        self.analyze(script);
+
+        script.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+        if var_decl.declare {
+            return;
+        }
+
+        for decl in &var_decl.decls {
+            self.check_pat(&decl.name, BindingKind::Var);
+        }
+
+        var_decl.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        if fn_decl.declare {
+            return;
+        }
+
+        self.check(&fn_decl.ident, BindingKind::Decl);
+
+        fn_decl.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        if class_decl.declare {
+            return;
+        }
+
+        self.check(&class_decl.ident, BindingKind::Decl);
+
+        class_decl.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        // Overloads and ambient declarations
+        if function.body.is_some() {
+            self.check_params(function.params.iter().map(|param| &param.pat));
+        }
+
+        function.visit_children_with(self);
+    }
+
+    fn visit_class_method(&mut self, method: &ClassMethod) {
+        // The parameter of a setter is required.
+        if method.kind == MethodKind::Setter {
+            method.function.decorators.visit_with(self);
+            method.function.body.visit_with(self);
+            method.key.visit_with(self);
+            return;
+        }
+
+        method.visit_children_with(self);
+    }
+
+    fn visit_private_method(&mut self, method: &PrivateMethod) {
+        if method.kind == MethodKind::Setter {
+            method.function.decorators.visit_with(self);
+            method.function.body.visit_with(self);
+            return;
+        }
+
+        method.visit_children_with(self);
+    }
+
+    fn visit_setter_prop(&mut self, setter: &SetterProp) {
+        setter.key.visit_with(self);
+        setter.body.visit_with(self);
+    }
+
+    fn visit_constructor(&mut self, constructor: &Constructor) {
+        if constructor.body.is_some() {
+            self.check_params(constructor.params.iter().filter_map(|param| match param {
+                ParamOrTsParamProp::Param(param) => Some(&param.pat),
+                // Parameter properties are class members.
+                ParamOrTsParamProp::TsParamProp(..) => None,
+            }));
+        }
+
+        constructor.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        self.check_params(&arrow.params);
+
+        arrow.visit_children_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause) {
+        if self.caught_errors == CaughtErrorsMode::All {
+            if let Some(param) = &clause.param {
+                self.check_pat(param, BindingKind::CaughtError);
+            }
+        }
+
+        clause.visit_children_with(self);
+    }
+
+    fn visit_import_decl(&mut self, import: &ImportDecl) {
+        for specifier in &import.specifiers {
+            let local = match specifier {
+                ImportSpecifier::Named(s) => &s.local,
+                ImportSpecifier::Default(s) => &s.local,
+                ImportSpecifier::Namespace(s) => &s.local,
+            };
+
+            self.check(local, BindingKind::Decl);
+        }
+    }
+
+    fn visit_ts_module_decl(&mut self, _: &TsModuleDecl) {}
+}
+
+/// Collects bindings referenced from types and whether the program contains
+/// JSX, which needs `React` in scope with the classic runtime.
+#[derive(Default)]
+struct TypeRefCollector {
+    refs: FxHashSet<Id>,
+    has_jsx: bool,
+}
+
+impl Visit for TypeRefCollector {
+    fn visit_ts_entity_name(&mut self, name: &TsEntityName) {
+        match name {
+            TsEntityName::Ident(ident) => {
+                self.refs.insert(ident.to_id());
+            }
+            TsEntityName::TsQualifiedName(name) => name.left.visit_with(self),
+        }
+    }
+
+    fn visit_ts_expr_with_type_args(&mut self, n: &TsExprWithTypeArgs) {
+        let mut expr = &*n.expr;
+        while let Expr::Member(member) = expr {
+            expr = &member.obj;
+        }
+        if let Expr::Ident(ident) = expr {
+            self.refs.insert(ident.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_element(&mut self, n: &JSXElement) {
+        self.has_jsx = true;
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_fragment(&mut self, n: &JSXFragment) {
+        self.has_jsx = true;
+
+        n.visit_children_with(self);
+    }
+}
+
+/// Collects bindings declared by exported declarations.
+#[derive(Default)]
+struct ExportCollector {
+    exported: FxHashSet<Id>,
+}
+
+impl Visit for ExportCollector {
+    noop_visit_type!();
+
+    fn visit_export_decl(&mut self, n: &ExportDecl) {
+        match &n.decl {
+            Decl::Class(c) => {
+                self.exported.insert(c.ident.to_id());
+            }
+            Decl::Fn(f) => {
+                self.exported.insert(f.ident.to_id());
+            }
+            Decl::Var(v) => {
+                self.exported.extend(find_pat_ids::<_, Id>(&v.decls));
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+/// [Storage] for the usage analyzer, which only tracks what's required to
+/// find unused variables.
+#[derive(Debug, Default)]
+struct UsageData {
+    vars: FxHashMap<Id, VarUsage>,
+    scope: ScopeData,
+    initialized_cnt: usize,
+}
+
+#[derive(Debug, Default)]
+struct VarUsage {
+    /// The number of reads.
+    usage_count: u32,
+    assigned: bool,
+    exported: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+struct ScopeData;
+
+impl Storage for UsageData {
+    type ScopeData = ScopeData;
+    type VarData = VarUsage;
+
+    fn scope(&mut self, _: SyntaxContext) -> &mut Self::ScopeData {
+        &mut self.scope
+    }
+
+    fn top_scope(&mut self) -> &mut Self::ScopeData {
+        &mut self.scope
+    }
+
+    fn var_or_default(&mut self, id: Id) -> &mut Self::VarData {
+        self.vars.entry(id).or_default()
+    }
+
+    fn merge(&mut self, _: ScopeKind, child: Self) {
+        for (id, var) in child.vars {
+            let to = self.vars.entry(id).or_default();
+
+            to.usage_count += var.usage_count;
+            to.assigned |= var.assigned;
+            to.exported |= var.exported;
+        }
+    }
+
+    fn report_usage(&mut self, _: Ctx, i: Id) {
+        self.vars.entry(i).or_default().usage_count += 1;
+    }
+
+    fn report_assign(&mut self, _: Ctx, i: Id, is_op: bool, _: Value<Type>) {
+        let var = self.vars.entry(i).or_default();
+
+        var.assigned = true;
+        // Targets of assignments are reported as usages.
+        if !is_op {
+            var.usage_count = var.usage_count.saturating_sub(1);
+        }
+    }
+
+    fn declare_decl(
+        &mut self,
+        _: Ctx,
+        i: &Ident,
+        init_type: Option<Value<Type>>,
+        _: Option<VarDeclKind>,
+    ) -> &mut Self::VarData {
+        let var = self.vars.entry(i.to_id()).or_default();
+        var.assigned |= init_type.is_some();
+        var
+    }
+
+    fn get_initialized_cnt(&self) -> usize {
+        self.initialized_cnt
+    }
+
+    fn truncate_initialized_cnt(&mut self, len: usize) {
+        self.initialized_cnt = len;
+    }
+
+    fn mark_property_mutation(&mut self, _: Id) {}
+}
+
+impl ScopeDataLike for ScopeData {
+    fn add_declared_symbol(&mut self, _: &Ident) {}
+
+    fn merge(&mut self, _: Self, _: bool) {}
+
+    fn mark_used_arguments(&mut self) {}
+
+    fn mark_eval_called(&mut self) {}
+
+    fn mark_with_stmt(&mut self) {}
+}
+
+impl VarDataLike for VarUsage {
+    fn mark_declared_as_fn_param(&mut self) {}
+
+    fn mark_declared_as_fn_decl(&mut self) {}
+
+    fn mark_declared_as_fn_expr(&mut self) {}
+
+    fn mark_declared_as_for_init(&mut self) {}
+
+    fn mark_has_property_access(&mut self) {}
+
+    fn mark_used_as_callee(&mut self) {}
+
+    fn mark_used_as_arg(&mut self) {}
+
+    fn mark_indexed_with_dynamic_key(&mut self) {}
+
+    fn add_accessed_property(&mut self, _: Atom) {}
+
+    fn mark_used_as_ref(&mut self) {}
+
+    fn add_infects_to(&mut self, _: Access) {}
+
+    fn prevent_inline(&mut self) {}
+
+    fn mark_as_exported(&mut self) {
+        self.exported = true;
+    }
+
+    fn mark_initialized_with_safe_value(&mut self) {}
+
+    fn mark_as_pure_fn(&mut self) {}
+
+    fn mark_used_above_decl(&mut self) {}
+
+    fn mark_used_recursively(&mut self) {}
+}
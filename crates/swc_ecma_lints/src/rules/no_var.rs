@@ -1,5 +1,10 @@
-use swc_common::{errors::HANDLER, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    BytePos, Span,
+};
 use swc_ecma_ast::*;
+use swc_ecma_utils::{find_pat_ids, DestructuringFinder};
 use swc_ecma_visit::{Visit, VisitWith};
 
 use crate::{
@@ -21,32 +26,214 @@ pub fn no_var(config: &RuleConfig<()>) -> Option<Box<dyn Rule>> {
 #[derive(Debug, Default)]
 struct NoVar {
     expected_reaction: LintRuleReaction,
+    /// Declarations which can be changed to `let` without changing the
+    /// semantics of the program.
+    fixable: FxHashSet<Span>,
 }
 
 impl NoVar {
     fn new(expected_reaction: LintRuleReaction) -> Self {
-        Self { expected_reaction }
+        Self {
+            expected_reaction,
+            fixable: Default::default(),
+        }
     }
 
-    fn emit_error(&self, span: Span) {
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, MESSAGE).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, MESSAGE).emit();
+    fn emit_error(&self, span: Span, fixable: bool) {
+        HANDLER.with(|handler| {
+            let mut diagnostic = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, MESSAGE),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, MESSAGE),
+                _ => return,
+            };
+
+            if fixable {
+                diagnostic.span_suggestion_with_applicability(
+                    span.with_hi(span.lo + BytePos(3)),
+                    "use `let` instead",
+                    "let".into(),
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            diagnostic.emit();
         });
     }
 }
 
 impl Visit for NoVar {
+    fn visit_module(&mut self, module: &Module) {
+        let mut analyzer = Analyzer::default();
+        analyzer.add_candidates(module.body.iter().filter_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::Var(var),
+                ..
+            })) => Some(&**var),
+            _ => None,
+        }));
+        module.visit_with(&mut analyzer);
+        self.fixable = analyzer.into_fixable();
+
+        module.visit_children_with(self);
+    }
+
+    fn visit_script(&mut self, script: &Script) {
+        // Top-level `var`s of a script are properties of the global object, so
+        // those are never fixed.
+        let mut analyzer = Analyzer::default();
+        script.visit_with(&mut analyzer);
+        self.fixable = analyzer.into_fixable();
+
+        script.visit_children_with(self);
+    }
+
     fn visit_var_decl(&mut self, var_decl: &VarDecl) {
         if let VarDeclKind::Var = var_decl.kind {
-            self.emit_error(var_decl.span);
+            self.emit_error(var_decl.span, self.fixable.contains(&var_decl.span));
         }
 
         var_decl.visit_children_with(self);
     }
 }
+
+/// Finds `var` declarations which behave the same as `let`.
+///
+/// To keep it simple, only declarations placed directly in a function body or
+/// at the top level of a module are considered, so the scope of the bindings
+/// does not change. A declaration is fixable if its bindings are declared only
+/// once and not referenced before the declaration.
+#[derive(Default)]
+struct Analyzer {
+    candidates: Vec<(Span, Vec<Ident>)>,
+    decl_counts: FxHashMap<Id, usize>,
+    references: FxHashMap<Id, Vec<BytePos>>,
+}
+
+impl Analyzer {
+    fn add_candidates<'a>(&mut self, vars: impl IntoIterator<Item = &'a VarDecl>) {
+        for var in vars {
+            if var.kind == VarDeclKind::Var && !var.declare {
+                self.candidates.push((var.span, find_pat_ids(&var.decls)));
+            }
+        }
+    }
+
+    fn add_decls<T>(&mut self, node: &T)
+    where
+        T: VisitWith<DestructuringFinder<Id>>,
+    {
+        for id in find_pat_ids::<_, Id>(node) {
+            *self.decl_counts.entry(id).or_default() += 1;
+        }
+    }
+
+    fn add_fn_body(&mut self, body: Option<&BlockStmt>) {
+        if let Some(body) = body {
+            self.add_candidates(body.stmts.iter().filter_map(|stmt| match stmt {
+                Stmt::Decl(Decl::Var(var)) => Some(&**var),
+                _ => None,
+            }));
+        }
+    }
+
+    fn into_fixable(self) -> FxHashSet<Span> {
+        self.candidates
+            .into_iter()
+            .filter(|(span, bindings)| {
+                bindings.iter().all(|binding| {
+                    let id = binding.to_id();
+
+                    if binding.sym == "let" {
+                        return false;
+                    }
+
+                    self.decl_counts.get(&id).copied().unwrap_or_default() == 1
+                        && self.references.get(&id).map_or(true, |refs| {
+                            refs.iter()
+                                .all(|&pos| pos == binding.span.lo || pos >= span.hi)
+                        })
+                })
+            })
+            .map(|(span, _)| span)
+            .collect()
+    }
+}
+
+impl Visit for Analyzer {
+    fn visit_function(&mut self, function: &Function) {
+        self.add_fn_body(function.body.as_ref());
+        self.add_decls(&function.params);
+
+        function.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+        if let BlockStmtOrExpr::BlockStmt(body) = &*arrow.body {
+            self.add_fn_body(Some(body));
+        }
+        self.add_decls(&arrow.params);
+
+        arrow.visit_children_with(self);
+    }
+
+    fn visit_constructor(&mut self, constructor: &Constructor) {
+        self.add_fn_body(constructor.body.as_ref());
+        self.add_decls(&constructor.params);
+
+        constructor.visit_children_with(self);
+    }
+
+    fn visit_getter_prop(&mut self, getter: &GetterProp) {
+        self.add_fn_body(getter.body.as_ref());
+
+        getter.visit_children_with(self);
+    }
+
+    fn visit_setter_prop(&mut self, setter: &SetterProp) {
+        self.add_fn_body(setter.body.as_ref());
+        self.add_decls(&setter.param);
+
+        setter.visit_children_with(self);
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock) {
+        self.add_fn_body(Some(&block.body));
+
+        block.visit_children_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause) {
+        self.add_decls(&clause.param);
+
+        clause.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        self.add_decls(&declarator.name);
+
+        declarator.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        *self.decl_counts.entry(fn_decl.ident.to_id()).or_default() += 1;
+
+        fn_decl.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        *self
+            .decl_counts
+            .entry(class_decl.ident.to_id())
+            .or_default() += 1;
+
+        class_decl.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.references
+            .entry(ident.to_id())
+            .or_default()
+            .push(ident.span.lo);
+    }
+}
@@ -1,6 +1,9 @@
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    BytePos, Span,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{Visit, VisitWith};
 
@@ -35,6 +38,8 @@ pub fn prefer_const(config: &RuleConfig<PreferConstConfig>) -> Option<Box<dyn Ru
 struct VariableMeta {
     span: Span,
     order: usize,
+    // span of the `let` declaration
+    decl: Span,
     initialized: bool,
     block_depth: usize,
     // for cases like
@@ -56,6 +61,9 @@ struct VariableMeta {
 struct PreferConst {
     expected_reaction: LintRuleReaction,
     vars_meta: FxHashMap<Id, VariableMeta>,
+    // number of bindings of each `let` declaration
+    decl_bindings: FxHashMap<Span, usize>,
+    current_decl: Span,
     scope_vars_idx: usize,
     block_depth: usize,
     cycle_head_depth: usize,
@@ -68,6 +76,8 @@ impl PreferConst {
         Self {
             expected_reaction,
             vars_meta: Default::default(),
+            decl_bindings: Default::default(),
+            current_decl: Default::default(),
             scope_vars_idx: 0,
             block_depth: 0,
             cycle_head_depth: 0,
@@ -76,28 +86,39 @@ impl PreferConst {
         }
     }
 
-    fn emit_report(&self, span: Span, var_name: &str) {
+    fn emit_report(&self, span: Span, var_name: &str, fix: Option<Span>) {
         let message = format!("'{var_name}' is never reassigned. Use 'const' insted");
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, &message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, &message).emit();
+        HANDLER.with(|handler| {
+            let mut diagnostic = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, &message),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, &message),
+                _ => return,
+            };
+
+            if let Some(keyword) = fix {
+                diagnostic.span_suggestion_with_applicability(
+                    keyword,
+                    "use `const` instead",
+                    "const".into(),
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            diagnostic.emit();
         });
     }
 
     fn add_var_meta(&mut self, ident: &Ident, initialized: bool) {
         self.scope_vars_idx += 1;
+        *self.decl_bindings.entry(self.current_decl).or_default() += 1;
 
         self.vars_meta.insert(
             ident.to_id(),
             VariableMeta {
                 span: ident.span,
                 order: self.scope_vars_idx,
+                decl: self.current_decl,
                 initialized,
                 block_depth: self.block_depth,
                 destructuring_assign: false,
@@ -192,6 +213,19 @@ impl PreferConst {
 
         vars.sort_by(|(_, a), (_, b)| a.order.cmp(&b.order));
 
+        // A declaration can be changed to `const` only if all of its bindings are
+        // initialized by it and never reassigned.
+        let mut const_bindings: FxHashMap<Span, usize> = Default::default();
+        for (_, var_meta) in &vars {
+            if (var_meta.initialized || var_meta.declared_into_cycle_head)
+                && !var_meta.decl.is_dummy()
+                && !var_meta.postinitialized
+                && !var_meta.destructuring_assign
+            {
+                *const_bindings.entry(var_meta.decl).or_default() += 1;
+            }
+        }
+
         vars.into_iter().for_each(|(id, var_meta)| {
             let postinitialized = if self.ignore_read_before_assign {
                 var_meta.postinitialized && !var_meta.used_before_initialize
@@ -204,7 +238,17 @@ impl PreferConst {
                 || var_meta.destructuring_assign
                 || var_meta.declared_into_cycle_head
             {
-                self.emit_report(var_meta.span, &id.0);
+                // Attached to the first binding of the declaration only.
+                let fix = if const_bindings
+                    .remove(&var_meta.decl)
+                    .is_some_and(|cnt| self.decl_bindings.get(&var_meta.decl) == Some(&cnt))
+                {
+                    Some(var_meta.decl.with_hi(var_meta.decl.lo + BytePos(3)))
+                } else {
+                    None
+                };
+
+                self.emit_report(var_meta.span, &id.0, fix);
             }
         });
     }
@@ -225,6 +269,12 @@ impl Visit for PreferConst {
 
     fn visit_var_decl(&mut self, var_decl: &VarDecl) {
         if let VarDeclKind::Let = var_decl.kind {
+            self.current_decl = if var_decl.declare {
+                Default::default()
+            } else {
+                var_decl.span
+            };
+
             var_decl.decls.iter().for_each(|var_decl| {
                 self.collect_decl_pat(var_decl.init.is_some(), &var_decl.name);
             })
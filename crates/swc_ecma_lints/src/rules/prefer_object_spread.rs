@@ -1,4 +1,13 @@
-use swc_common::{errors::HANDLER, Span, SyntaxContext};
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use rustc_hash::FxHashSet;
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    BytePos, SourceMap, SourceMapper, Spanned, SyntaxContext,
+};
 use swc_ecma_ast::*;
 use swc_ecma_utils::ExprExt;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
@@ -15,6 +24,7 @@ const USE_LITERAL_MESSAGE: &str =
     r#""Use an object literal instead of `Object.assign`. eg: `{ foo: bar }`""#;
 
 pub fn prefer_object_spread(
+    source_map: &Arc<SourceMap>,
     config: &RuleConfig<()>,
     unresolved_ctxt: SyntaxContext,
     es_version: EsVersion,
@@ -28,16 +38,29 @@ pub fn prefer_object_spread(
     match rule_reaction {
         LintRuleReaction::Off => None,
         _ => Some(visitor_rule(PreferObjectSpread::new(
+            source_map.clone(),
             rule_reaction,
             unresolved_ctxt,
         ))),
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 struct PreferObjectSpread {
+    source_map: Arc<SourceMap>,
     expected_reaction: LintRuleReaction,
     unresolved_ctxt: SyntaxContext,
+    /// Positions where an object literal would be parsed as a block.
+    needs_paren: FxHashSet<BytePos>,
+}
+
+impl Debug for PreferObjectSpread {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreferObjectSpread")
+            .field("expected_reaction", &self.expected_reaction)
+            .field("unresolved_ctxt", &self.unresolved_ctxt)
+            .finish()
+    }
 }
 
 #[derive(Debug)]
@@ -51,25 +74,76 @@ enum ArgType {
 }
 
 impl PreferObjectSpread {
-    fn new(expected_reaction: LintRuleReaction, unresolved_ctxt: SyntaxContext) -> Self {
+    fn new(
+        source_map: Arc<SourceMap>,
+        expected_reaction: LintRuleReaction,
+        unresolved_ctxt: SyntaxContext,
+    ) -> Self {
         Self {
+            source_map,
             expected_reaction,
             unresolved_ctxt,
+            needs_paren: Default::default(),
         }
     }
 
-    fn emit_report(&self, span: Span, message: &str) {
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, message).emit();
+    fn emit_report(&self, call_expr: &CallExpr, message: &str) {
+        HANDLER.with(|handler| {
+            let mut diagnostic = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(call_expr.span, message),
+                LintRuleReaction::Warning => handler.struct_span_warn(call_expr.span, message),
+                _ => return,
+            };
+
+            if let Some(fix) = self.fix(call_expr) {
+                diagnostic.span_suggestion_with_applicability(
+                    call_expr.span,
+                    "use an object literal",
+                    fix,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            diagnostic.emit();
         });
     }
 
+    /// Builds an object literal from the source text of the arguments.
+    fn fix(&self, call_expr: &CallExpr) -> Option<String> {
+        let mut props = Vec::with_capacity(call_expr.args.len());
+
+        for (idx, arg) in call_expr.args.iter().enumerate() {
+            let code = self.source_map.span_to_snippet(arg.expr.span()).ok()?;
+
+            if idx == 0 {
+                let Expr::Object(..) = arg.expr.unwrap_parens() else {
+                    return None;
+                };
+                let code = code.trim_start_matches('(').trim_end_matches(')').trim();
+                let inner = code.strip_prefix('{')?.strip_suffix('}')?.trim();
+                let inner = inner.strip_suffix(',').unwrap_or(inner).trim_end();
+
+                if !inner.is_empty() {
+                    props.push(inner.to_string());
+                }
+            } else {
+                props.push(format!("...{code}"));
+            }
+        }
+
+        let object = if props.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{ {} }}", props.join(", "))
+        };
+
+        if self.needs_paren.contains(&call_expr.span.lo) {
+            Some(format!("({object})"))
+        } else {
+            Some(object)
+        }
+    }
+
     fn recognize_expr_arg(expr: &Expr) -> ArgType {
         match expr {
             Expr::Object(obj) => {
@@ -157,7 +231,7 @@ impl PreferObjectSpread {
             (1, ArgType::EmptyLiteralObject)
             | (1, ArgType::LiteralObjectWithFields)
             | (1, ArgType::LiteralObjectWithGetterOrSetter) => {
-                self.emit_report(call_expr.span, USE_LITERAL_MESSAGE);
+                self.emit_report(call_expr, USE_LITERAL_MESSAGE);
             }
             (_, ArgType::EmptyLiteralObject) | (_, ArgType::LiteralObjectWithFields) => {
                 let has_spread_or_getter_setter = call_expr.args[1..].iter().any(|prop| {
@@ -171,7 +245,7 @@ impl PreferObjectSpread {
                     return;
                 }
 
-                self.emit_report(call_expr.span, USE_SPREAD_MESSAGE);
+                self.emit_report(call_expr, USE_SPREAD_MESSAGE);
             }
             _ => {}
         }
@@ -181,6 +255,20 @@ impl PreferObjectSpread {
 impl Visit for PreferObjectSpread {
     noop_visit_type!();
 
+    fn visit_expr_stmt(&mut self, expr_stmt: &ExprStmt) {
+        self.needs_paren.insert(expr_stmt.span.lo);
+
+        expr_stmt.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, arrow_expr: &ArrowExpr) {
+        if let BlockStmtOrExpr::Expr(body) = &*arrow_expr.body {
+            self.needs_paren.insert(body.span_lo());
+        }
+
+        arrow_expr.visit_children_with(self);
+    }
+
     fn visit_call_expr(&mut self, call_expr: &CallExpr) {
         self.check(call_expr);
 
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use swc_common::{errors::HANDLER, Span};
+use swc_common::{
+    errors::{Applicability, HANDLER},
+    Span,
+};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
@@ -50,24 +53,69 @@ impl Quotes {
         }
     }
 
-    fn emit_report(&self, span: Span) {
+    fn emit_report(&self, span: Span, fix: Option<String>) {
         let message = match &self.prefer {
             QuotesType::Backtick => MUST_USE_BACKTICK_QUOTES_MESSAGE,
             QuotesType::Single => MUST_USE_SINGLE_QUOTES_MESSAGE,
             QuotesType::Double => MUST_USE_DOUBLE_QUOTES_MESSAGE,
         };
 
-        HANDLER.with(|handler| match self.expected_reaction {
-            LintRuleReaction::Error => {
-                handler.struct_span_err(span, message).emit();
-            }
-            LintRuleReaction::Warning => {
-                handler.struct_span_warn(span, message).emit();
+        HANDLER.with(|handler| {
+            let mut diagnostic = match self.expected_reaction {
+                LintRuleReaction::Error => handler.struct_span_err(span, message),
+                LintRuleReaction::Warning => handler.struct_span_warn(span, message),
+                _ => return,
+            };
+
+            if let Some(fix) = fix {
+                diagnostic.span_suggestion_with_applicability(
+                    span,
+                    "change the quotes",
+                    fix,
+                    Applicability::MachineApplicable,
+                );
             }
-            _ => {}
+
+            diagnostic.emit();
         });
     }
 
+    /// Quotes `raw`, which is the text between the quotes of a literal quoted
+    /// with `from`, with the preferred quotes.
+    fn requote(&self, raw: &str, from: char) -> Option<String> {
+        let to = self.prefer.get_char();
+
+        let mut quoted = String::with_capacity(raw.len() + 2);
+        quoted.push(to);
+
+        let mut chars = raw.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    let escaped = chars.next()?;
+
+                    if escaped != from || escaped == to {
+                        quoted.push('\\');
+                    }
+                    quoted.push(escaped);
+                }
+                // Line breaks are only allowed in templates.
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' if to != '`' => return None,
+                '$' if to == '`' && chars.peek() == Some(&'{') => {
+                    quoted.push_str("\\$");
+                }
+                c if c == to => {
+                    quoted.push('\\');
+                    quoted.push(c);
+                }
+                c => quoted.push(c),
+            }
+        }
+
+        quoted.push(to);
+        Some(quoted)
+    }
+
     fn is_mirroring_escape(&self, value: &str) -> bool {
         let quote = self.prefer.get_char();
 
@@ -83,7 +131,15 @@ impl Quotes {
     fn check_str(&self, is_method_key_check: bool, lit_str: &Str) {
         let found_quote_type = resolve_string_quote_type(lit_str).unwrap();
 
-        let Str { span, value, .. } = lit_str;
+        let Str {
+            span, value, raw, ..
+        } = lit_str;
+
+        let fix = || {
+            let raw = raw.as_deref()?;
+
+            self.requote(&raw[1..raw.len() - 1], found_quote_type.get_char())
+        };
 
         match (&self.prefer, &found_quote_type) {
             (QuotesType::Double, QuotesType::Single) => {
@@ -91,14 +147,14 @@ impl Quotes {
                     return;
                 }
 
-                self.emit_report(*span);
+                self.emit_report(*span, fix());
             }
             (QuotesType::Single, QuotesType::Double) => {
                 if self.avoid_escape && self.is_mirroring_escape(value) {
                     return;
                 }
 
-                self.emit_report(*span);
+                self.emit_report(*span, fix());
             }
             (QuotesType::Backtick, _) => {
                 if is_method_key_check {
@@ -109,14 +165,19 @@ impl Quotes {
                     return;
                 }
 
-                self.emit_report(*span);
+                self.emit_report(*span, fix());
             }
             _ => {}
         }
     }
 
     fn check_tpl_str(&self, tpl_str: &Tpl) {
-        let Tpl { span, exprs, .. } = tpl_str;
+        let Tpl {
+            span,
+            exprs,
+            quasis,
+            ..
+        } = tpl_str;
 
         if self.allow_template_literals {
             return;
@@ -130,7 +191,11 @@ impl Quotes {
             return;
         }
 
-        self.emit_report(*span);
+        let fix = quasis
+            .first()
+            .and_then(|quasi| self.requote(&quasi.raw, '`'));
+
+        self.emit_report(*span, fix);
     }
 }
 
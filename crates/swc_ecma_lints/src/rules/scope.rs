@@ -0,0 +1,195 @@
+//! Helpers to collect the bindings declared in a scope.
+
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DeclKind {
+    Var,
+    /// `let`, `const` and `using` declarations.
+    Lexical,
+    Function,
+    Class,
+    Param,
+    CatchParam,
+    Import,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Declaration {
+    pub ident: Ident,
+    pub kind: DeclKind,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Declarations(pub Vec<Declaration>);
+
+impl Declarations {
+    pub fn add_pat(&mut self, pat: &Pat, kind: DeclKind) {
+        let mut bindings = Vec::new();
+        pat_bindings(pat, &mut bindings);
+
+        self.0.extend(bindings.into_iter().map(|ident| Declaration {
+            ident: ident.clone(),
+            kind,
+        }));
+    }
+
+    pub fn add_ident(&mut self, ident: &Ident, kind: DeclKind) {
+        self.0.push(Declaration {
+            ident: ident.clone(),
+            kind,
+        });
+    }
+
+    pub fn add_params<'a>(&mut self, params: impl IntoIterator<Item = &'a Pat>) {
+        for pat in params {
+            self.add_pat(pat, DeclKind::Param);
+        }
+    }
+
+    pub fn add_constructor_params(&mut self, params: &[ParamOrTsParamProp]) {
+        for param in params {
+            match param {
+                ParamOrTsParamProp::Param(param) => self.add_pat(&param.pat, DeclKind::Param),
+                ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+                    TsParamPropParam::Ident(ident) => self.add_ident(&ident.id, DeclKind::Param),
+                    TsParamPropParam::Assign(assign) => self.add_pat(&assign.left, DeclKind::Param),
+                },
+            }
+        }
+    }
+
+    /// Adds `var` declarations in `node`, excluding the ones in nested
+    /// functions.
+    pub fn add_hoisted_vars<N>(&mut self, node: &N)
+    where
+        N: VisitWith<VarFinder>,
+    {
+        let mut finder = VarFinder::default();
+        node.visit_with(&mut finder);
+        self.0.extend(finder.decls.0);
+    }
+
+    /// Adds block-scoped declarations of `stmts`.
+    pub fn add_lexical(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            if let Stmt::Decl(decl) = stmt {
+                self.add_lexical_decl(decl);
+            }
+        }
+    }
+
+    /// Adds block-scoped declarations and imports of `items`.
+    pub fn add_lexical_module_items(&mut self, items: &[ModuleItem]) {
+        for item in items {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(decl))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                    self.add_lexical_decl(decl)
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl,
+                    ..
+                })) => match decl {
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(ident), ..
+                    }) => self.add_ident(ident, DeclKind::Class),
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident), ..
+                    }) => self.add_ident(ident, DeclKind::Function),
+                    _ => {}
+                },
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    for specifier in &import.specifiers {
+                        let local = match specifier {
+                            ImportSpecifier::Named(s) => &s.local,
+                            ImportSpecifier::Default(s) => &s.local,
+                            ImportSpecifier::Namespace(s) => &s.local,
+                        };
+
+                        self.add_ident(local, DeclKind::Import);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn add_lexical_decl(&mut self, decl: &Decl) {
+        match decl {
+            Decl::Var(var) if var.kind != VarDeclKind::Var => {
+                for decl in &var.decls {
+                    self.add_pat(&decl.name, DeclKind::Lexical);
+                }
+            }
+            Decl::Using(using) => {
+                for decl in &using.decls {
+                    self.add_pat(&decl.name, DeclKind::Lexical);
+                }
+            }
+            Decl::Fn(f) if !f.declare => self.add_ident(&f.ident, DeclKind::Function),
+            Decl::Class(c) if !c.declare => self.add_ident(&c.ident, DeclKind::Class),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the bindings declared by `pat`, in order.
+fn pat_bindings<'a>(pat: &'a Pat, bindings: &mut Vec<&'a Ident>) {
+    match pat {
+        Pat::Ident(ident) => {
+            if ident.sym != "this" {
+                bindings.push(&ident.id)
+            }
+        }
+        Pat::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                pat_bindings(elem, bindings);
+            }
+        }
+        Pat::Rest(rest) => pat_bindings(&rest.arg, bindings),
+        Pat::Object(object) => {
+            for prop in &object.props {
+                match prop {
+                    ObjectPatProp::KeyValue(prop) => pat_bindings(&prop.value, bindings),
+                    ObjectPatProp::Assign(prop) => bindings.push(&prop.key.id),
+                    ObjectPatProp::Rest(rest) => pat_bindings(&rest.arg, bindings),
+                }
+            }
+        }
+        Pat::Assign(assign) => pat_bindings(&assign.left, bindings),
+        Pat::Invalid(..) | Pat::Expr(..) => {}
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct VarFinder {
+    decls: Declarations,
+}
+
+impl Visit for VarFinder {
+    noop_visit_type!();
+
+    fn visit_var_decl(&mut self, var: &VarDecl) {
+        if var.kind == VarDeclKind::Var {
+            for decl in &var.decls {
+                self.decls.add_pat(&decl.name, DeclKind::Var);
+            }
+        }
+
+        var.decls.iter().for_each(|decl| decl.init.visit_with(self));
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_getter_prop(&mut self, _: &GetterProp) {}
+
+    fn visit_setter_prop(&mut self, _: &SetterProp) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+
+    fn visit_ts_module_decl(&mut self, _: &TsModuleDecl) {}
+}
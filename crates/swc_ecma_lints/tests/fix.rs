@@ -0,0 +1,69 @@
+#![cfg(feature = "non_critical_lints")]
+
+use std::{fs, path::PathBuf};
+
+use swc_common::{input::SourceFileInput, FileName, Mark, SyntaxContext};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_lints::{
+    config::LintConfig,
+    fix::{capture_diagnostics, fix_until_stable},
+    rule::Rule,
+    rules::{all, LintParams},
+};
+use swc_ecma_parser::{lexer::Lexer, Parser, Syntax};
+use swc_ecma_transforms_base::resolver;
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fix/**/input.js")]
+fn fix(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let config: LintConfig =
+        serde_json::from_str(&fs::read_to_string(dir.join("config.json")).unwrap()).unwrap();
+
+    testing::run_test(false, |cm, _| {
+        let src = fs::read_to_string(&input).unwrap();
+        let es_version = EsVersion::latest();
+
+        let output = fix_until_stable(
+            &cm,
+            FileName::Real(input.clone()).into(),
+            src,
+            10,
+            |fm| -> Result<_, ()> {
+                let lexer = Lexer::new(
+                    Syntax::Es(Default::default()),
+                    es_version,
+                    SourceFileInput::from(&**fm),
+                    None,
+                );
+                let mut program = Parser::new_from(lexer).parse_program().unwrap();
+
+                let unresolved_mark = Mark::new();
+                let top_level_mark = Mark::new();
+                program.mutate(resolver(unresolved_mark, top_level_mark, false));
+
+                let mut rules = all(LintParams {
+                    program: &program,
+                    lint_config: &config,
+                    unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+                    top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+                    es_version,
+                    source_map: cm.clone(),
+                });
+
+                Ok(capture_diagnostics(|| match &program {
+                    Program::Module(m) => rules.lint_module(m),
+                    Program::Script(s) => rules.lint_script(s),
+                }))
+            },
+        )
+        .unwrap();
+
+        NormalizedOutput::from(output.code)
+            .compare_to_file(dir.join("output.js"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
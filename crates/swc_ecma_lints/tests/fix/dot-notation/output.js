@@ -0,0 +1,6 @@
+a.b;
+a.b.c;
+a?.b;
+a["b-c"];
+a["class"];
+a[`b`];
@@ -0,0 +1,12 @@
+if (typeof a == "string") {
+}
+if ("a" != "b") {
+}
+if (1 == 1) {
+}
+if (a == b) {
+}
+if (a == null) {
+}
+if ((typeof a) /* cmp */ == "number") {
+}
@@ -0,0 +1,17 @@
+const a = 1;
+let b = 2;
+b++;
+console.log(c);
+var c = 3;
+var d = 4, d = 5;
+
+export function foo() {
+    const x = [];
+    for (var i = 0; i < 10; i++) {
+        x.push(i);
+    }
+    if (x.length) {
+        var y = x;
+    }
+    return y;
+}
@@ -0,0 +1,10 @@
+let a = 1;
+let b = 2, c = 3;
+let d = 4, e = 5;
+e = 6;
+let f;
+f = 7;
+for (let item of [a, b, c, d, e, f]) {
+    console.log(item);
+}
+export let { g, h } = foo();
@@ -0,0 +1,10 @@
+const a = 1;
+const b = 2, c = 3;
+let d = 4, e = 5;
+e = 6;
+let f;
+f = 7;
+for (const item of [a, b, c, d, e, f]) {
+    console.log(item);
+}
+export const { g, h } = foo();
@@ -0,0 +1,6 @@
+const a = Object.assign({}, foo);
+const b = Object.assign({ x: 1, }, foo, bar);
+const c = Object.assign({ x: 1 });
+const d = () => Object.assign({}, foo);
+Object.assign({}, foo).x;
+Object.assign(foo, bar);
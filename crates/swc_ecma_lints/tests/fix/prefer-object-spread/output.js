@@ -0,0 +1,6 @@
+const a = { ...foo };
+const b = { x: 1, ...foo, ...bar };
+const c = { x: 1 };
+const d = () => ({ ...foo });
+({ ...foo }).x;
+Object.assign(foo, bar);
@@ -0,0 +1,5 @@
+"use strict";
+const a = "foo";
+const b = "it's";
+const c = "say \"hi\"";
+const d = 'bar';
@@ -0,0 +1,337 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Error};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use swc_common::FileName;
+use tracing::{debug, warn, Level};
+use url::Url;
+
+use crate::resolve::{Resolution, Resolve};
+
+/// The content of an import map, as written in
+/// `<script type="importmap">` or in a `.json` file.
+///
+/// See https://html.spec.whatwg.org/multipage/webappapis.html#import-maps
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportMap {
+    #[serde(default)]
+    pub imports: FxHashMap<String, String>,
+
+    #[serde(default)]
+    pub scopes: FxHashMap<String, FxHashMap<String, String>>,
+}
+
+impl ImportMap {
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read import map at `{}`", path.display()))?;
+
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse import map at `{}`", path.display()))
+    }
+}
+
+/// A specifier map, normalized and sorted so that longer keys come first.
+///
+/// Addresses are [None] if they are invalid, which blocks the specifier.
+type SpecifierMap = Vec<(String, Option<Url>)>;
+
+/// Support for import maps.
+///
+/// Specifiers mapped to `file:` URLs are resolved using the inner resolver,
+/// and specifiers mapped to other URLs (e.g. `https:`) are resolved as
+/// [FileName::Url]. Specifiers not matched by the import map are passed to the
+/// inner resolver as-is.
+///
+/// See https://html.spec.whatwg.org/multipage/webappapis.html#resolving-a-module-specifier
+#[derive(Debug)]
+pub struct ImportMapResolver<R>
+where
+    R: Resolve,
+{
+    inner: R,
+    base_url: Url,
+    imports: SpecifierMap,
+    scopes: Vec<(String, SpecifierMap)>,
+}
+
+impl<R> ImportMapResolver<R>
+where
+    R: Resolve,
+{
+    ///
+    /// # Parameters
+    ///
+    /// ## `base_dir`
+    ///
+    /// The directory used as the base URL of the import map. Addresses and
+    /// scope prefixes are resolved against it, so this should be the directory
+    /// of the import map file, or of the html page embedding it.
+    pub fn new(inner: R, import_map: ImportMap, base_dir: &Path) -> Self {
+        let base_url = dir_to_url(base_dir);
+
+        let imports = sort_and_normalize_specifier_map(import_map.imports, &base_url);
+
+        let mut scopes: Vec<_> = import_map
+            .scopes
+            .into_iter()
+            .filter_map(|(prefix, map)| match base_url.join(&prefix) {
+                Ok(prefix) => Some((
+                    prefix.to_string(),
+                    sort_and_normalize_specifier_map(map, &base_url),
+                )),
+                Err(err) => {
+                    warn!("Ignoring invalid scope prefix `{}`: {}", prefix, err);
+                    None
+                }
+            })
+            .collect();
+        scopes.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        Self {
+            inner,
+            base_url,
+            imports,
+            scopes,
+        }
+    }
+
+    fn referrer_url(&self, base: &FileName) -> Url {
+        match base {
+            FileName::Real(path) => file_to_url(path).unwrap_or_else(|| self.base_url.clone()),
+            FileName::Url(url) => url.clone(),
+            _ => self.base_url.clone(),
+        }
+    }
+
+    fn resolve_with_map(
+        &self,
+        referrer: &Url,
+        normalized: &str,
+        as_url: Option<&Url>,
+    ) -> Result<Option<Url>, Error> {
+        for (prefix, map) in &self.scopes {
+            if prefix == referrer.as_str()
+                || (prefix.ends_with('/') && referrer.as_str().starts_with(prefix.as_str()))
+            {
+                debug!("Checking scope `{}`", prefix);
+
+                if let Some(url) = resolve_imports_match(normalized, as_url, map)? {
+                    return Ok(Some(url));
+                }
+            }
+        }
+
+        resolve_imports_match(normalized, as_url, &self.imports)
+    }
+}
+
+impl<R> Resolve for ImportMapResolver<R>
+where
+    R: Resolve,
+{
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error> {
+        if self.imports.is_empty() && self.scopes.is_empty() {
+            return self.inner.resolve(base, module_specifier);
+        }
+
+        let _tracing = if cfg!(debug_assertions) {
+            Some(
+                tracing::span!(
+                    Level::ERROR,
+                    "ImportMapResolver::resolve",
+                    base = tracing::field::display(base),
+                    src = tracing::field::display(module_specifier),
+                )
+                .entered(),
+            )
+        } else {
+            None
+        };
+
+        let referrer = self.referrer_url(base);
+        let as_url = parse_url_like_specifier(module_specifier, &referrer);
+        let normalized = as_url.as_ref().map_or(module_specifier, |url| url.as_str());
+
+        let Some(url) = self
+            .resolve_with_map(&referrer, normalized, as_url.as_ref())
+            .with_context(|| format!("failed to resolve `{module_specifier}` from `{base}`"))?
+        else {
+            return self.inner.resolve(base, module_specifier);
+        };
+
+        debug!("Mapped `{}` to `{}`", module_specifier, url);
+
+        if url.scheme() != "file" {
+            return Ok(Resolution {
+                filename: FileName::Url(url),
+                slug: None,
+            });
+        }
+
+        let path = url_to_file(&url);
+        self.inner
+            .resolve(base, &path.to_string_lossy())
+            .with_context(|| {
+                format!(
+                    "failed to resolve `{}`, which is mapped to `{}` by the import map",
+                    module_specifier,
+                    path.display()
+                )
+            })
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/webappapis.html#sorting-and-normalizing-a-module-specifier-map
+fn sort_and_normalize_specifier_map(
+    map: FxHashMap<String, String>,
+    base_url: &Url,
+) -> SpecifierMap {
+    let mut normalized: SpecifierMap = map
+        .into_iter()
+        .filter_map(|(key, value)| {
+            if key.is_empty() {
+                warn!("Ignoring an empty specifier key in the import map");
+                return None;
+            }
+
+            let key = match parse_url_like_specifier(&key, base_url) {
+                Some(url) => url.to_string(),
+                None => key,
+            };
+
+            let address = match parse_url_like_specifier(&value, base_url) {
+                Some(address) if key.ends_with('/') && !address.as_str().ends_with('/') => {
+                    warn!(
+                        "Address `{}` of `{}` should end with `/` because the key ends with `/`",
+                        value, key
+                    );
+                    None
+                }
+                Some(address) => Some(address),
+                None => {
+                    warn!(
+                        "Invalid address `{}` for `{}` in the import map",
+                        value, key
+                    );
+                    None
+                }
+            };
+
+            Some((key, address))
+        })
+        .collect();
+
+    normalized.sort_by(|(a, _), (b, _)| b.cmp(a));
+    normalized
+}
+
+/// https://html.spec.whatwg.org/multipage/webappapis.html#resolving-an-imports-match
+fn resolve_imports_match(
+    normalized: &str,
+    as_url: Option<&Url>,
+    map: &SpecifierMap,
+) -> Result<Option<Url>, Error> {
+    for (key, address) in map {
+        if key == normalized {
+            let Some(address) = address else {
+                bail!("`{normalized}` is blocked by the import map");
+            };
+
+            return Ok(Some(address.clone()));
+        }
+
+        if key.ends_with('/')
+            && normalized.starts_with(key.as_str())
+            && as_url.map_or(true, Url::is_special)
+        {
+            let Some(address) = address else {
+                bail!("`{normalized}` is blocked by the import map entry `{key}`");
+            };
+
+            let after_prefix = &normalized[key.len()..];
+            let url = address.join(after_prefix).with_context(|| {
+                format!("failed to resolve `{after_prefix}` against `{address}`")
+            })?;
+
+            if !url.as_str().starts_with(address.as_str()) {
+                bail!("`{normalized}` backtracks above its prefix `{key}` in the import map");
+            }
+
+            return Ok(Some(url));
+        }
+    }
+
+    Ok(None)
+}
+
+/// https://html.spec.whatwg.org/multipage/webappapis.html#resolving-a-url-like-module-specifier
+fn parse_url_like_specifier(specifier: &str, base_url: &Url) -> Option<Url> {
+    if specifier.starts_with('/') || specifier.starts_with("./") || specifier.starts_with("../") {
+        return base_url.join(specifier).ok();
+    }
+
+    Url::parse(specifier).ok()
+}
+
+/// We don't use [Url::from_file_path] because it's not available for
+/// `wasm32-unknown-unknown`.
+fn file_to_url(path: &Path) -> Option<Url> {
+    let path = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        env::current_dir().ok()?.join(path)
+    };
+    let path = path.to_string_lossy().replace('\\', "/");
+
+    if path.starts_with('/') {
+        Url::parse(&format!("file://{path}")).ok()
+    } else {
+        Url::parse(&format!("file:///{path}")).ok()
+    }
+}
+
+fn dir_to_url(dir: &Path) -> Url {
+    let mut url = file_to_url(dir).unwrap_or_else(|| Url::parse("file:///").unwrap());
+    if !url.path().ends_with('/') {
+        url.set_path(&format!("{}/", url.path()));
+    }
+    url
+}
+
+fn url_to_file(url: &Url) -> PathBuf {
+    let path = percent_decode(url.path());
+
+    // `/C:/foo` on windows
+    let path = match path.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => &path[1..],
+        _ => &*path,
+    };
+
+    PathBuf::from(path)
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(Ok(byte)) = s.get(i + 1..i + 3).map(|hex| u8::from_str_radix(hex, 16)) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
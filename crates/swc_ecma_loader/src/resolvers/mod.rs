@@ -1,3 +1,6 @@
+#[cfg(feature = "import_map")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import_map")))]
+pub mod import_map;
 #[cfg(feature = "lru")]
 #[cfg_attr(docsrs, doc(cfg(feature = "lru")))]
 pub mod lru;
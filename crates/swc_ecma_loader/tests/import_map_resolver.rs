@@ -0,0 +1,178 @@
+#![cfg(feature = "import_map")]
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Error;
+use swc_common::FileName;
+use swc_ecma_loader::{
+    resolve::{Resolution, Resolve},
+    resolvers::import_map::{ImportMap, ImportMapResolver},
+};
+
+fn resolver(import_map: &str) -> ImportMapResolver<TestResolver> {
+    let import_map: ImportMap = serde_json::from_str(import_map).unwrap();
+
+    ImportMapResolver::new(TestResolver, import_map, Path::new("/app"))
+}
+
+fn resolve(r: &ImportMapResolver<TestResolver>, base: &str, src: &str) -> FileName {
+    r.resolve(&FileName::Real(PathBuf::from(base)), src)
+        .unwrap_or_else(|err| panic!("failed to resolve `{src}` from `{base}`: {err:?}"))
+        .filename
+}
+
+#[test]
+fn exact() {
+    let r = resolver(
+        r#"{
+            "imports": {
+                "react": "https://esm.sh/react@18",
+                "utils": "./src/utils/index.js"
+            }
+        }"#,
+    );
+
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "react"),
+        FileName::Url("https://esm.sh/react@18".parse().unwrap())
+    );
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "utils"),
+        FileName::Custom("/app/src/utils/index.js".into())
+    );
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "react/jsx-runtime"),
+        FileName::Custom("react/jsx-runtime".into()),
+        "should not use an exact match as a prefix"
+    );
+}
+
+#[test]
+fn trailing_slash() {
+    let r = resolver(
+        r#"{
+            "imports": {
+                "lodash/": "./vendor/lodash/",
+                "lodash/fp/": "https://cdn.example.com/lodash-fp/",
+                "broken/": "./vendor/broken.js"
+            }
+        }"#,
+    );
+
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "lodash/map.js"),
+        FileName::Custom("/app/vendor/lodash/map.js".into())
+    );
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "lodash/fp/map.js"),
+        FileName::Url("https://cdn.example.com/lodash-fp/map.js".parse().unwrap()),
+        "longer prefix should win"
+    );
+
+    r.resolve(
+        &FileName::Real("/app/src/main.js".into()),
+        "lodash/../secret.js",
+    )
+    .expect_err("should not allow backtracking above the prefix");
+    r.resolve(&FileName::Real("/app/src/main.js".into()), "broken/a.js")
+        .expect_err("address without a trailing slash should block the prefix");
+}
+
+#[test]
+fn url_like_keys() {
+    let r = resolver(
+        r#"{
+            "imports": {
+                "./src/config.js": "./src/config.prod.js",
+                "https://cdn.example.com/": "./vendor/cdn/"
+            }
+        }"#,
+    );
+
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "./config.js"),
+        FileName::Custom("/app/src/config.prod.js".into())
+    );
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "https://cdn.example.com/a.js"),
+        FileName::Custom("/app/vendor/cdn/a.js".into())
+    );
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "./other.js"),
+        FileName::Custom("./other.js".into()),
+        "unmapped specifiers should be passed to the inner resolver as-is"
+    );
+}
+
+#[test]
+fn scopes() {
+    let r = resolver(
+        r#"{
+            "imports": {
+                "react": "https://esm.sh/react@18"
+            },
+            "scopes": {
+                "./legacy/": {
+                    "react": "https://esm.sh/react@16"
+                },
+                "./legacy/next/": {
+                    "react": "https://esm.sh/react@19"
+                },
+                "./legacy/entry.js": {
+                    "react": "https://esm.sh/react@17"
+                }
+            }
+        }"#,
+    );
+
+    assert_eq!(
+        resolve(&r, "/app/src/main.js", "react"),
+        FileName::Url("https://esm.sh/react@18".parse().unwrap())
+    );
+    assert_eq!(
+        resolve(&r, "/app/legacy/main.js", "react"),
+        FileName::Url("https://esm.sh/react@16".parse().unwrap())
+    );
+    assert_eq!(
+        resolve(&r, "/app/legacy/next/main.js", "react"),
+        FileName::Url("https://esm.sh/react@19".parse().unwrap())
+    );
+    assert_eq!(
+        resolve(&r, "/app/legacy/entry.js", "react"),
+        FileName::Url("https://esm.sh/react@17".parse().unwrap())
+    );
+}
+
+#[test]
+fn scope_fallback() {
+    let r = resolver(
+        r#"{
+            "imports": {
+                "lodash": "./vendor/lodash.js"
+            },
+            "scopes": {
+                "./legacy/": {
+                    "react": "https://esm.sh/react@16"
+                }
+            }
+        }"#,
+    );
+
+    assert_eq!(
+        resolve(&r, "/app/legacy/main.js", "lodash"),
+        FileName::Custom("/app/vendor/lodash.js".into()),
+        "should fall back to top-level imports"
+    );
+}
+
+/// Returns the specifier as-is.
+struct TestResolver;
+
+impl Resolve for TestResolver {
+    fn resolve(&self, _: &FileName, src: &str) -> Result<Resolution, Error> {
+        Ok(Resolution {
+            filename: FileName::Custom(src.replace('\\', "/")),
+            slug: None,
+        })
+    }
+}
@@ -1415,7 +1415,9 @@ impl Optimizer<'_> {
             | Expr::TsNonNull(_)
             | Expr::TsAs(_)
             | Expr::TsInstantiation(_)
-            | Expr::TsSatisfies(_) => false,
+            | Expr::TsSatisfies(_)
+            | Expr::Pipeline(_)
+            | Expr::TopicRef(_) => false,
         }
     }
 
@@ -73,6 +73,7 @@ pub mod eval;
 pub mod js;
 mod metadata;
 mod mode;
+pub mod observer;
 pub mod option;
 mod pass;
 mod program_data;
@@ -121,6 +122,7 @@ pub fn optimize(
                 extra.unresolved_mark,
                 extra.top_level_mark,
             ));
+            observer::after_phase("global_defs", &n);
         }
     }
 
@@ -134,6 +136,7 @@ pub fn optimize(
             remaining_depth: 6,
         }));
         debug_assert_valid(&n);
+        observer::after_phase("precompress", &n);
     }
 
     if options.compress.is_some() {
@@ -162,6 +165,7 @@ pub fn optimize(
         if options.unused {
             perform_dce(&mut n, options, extra);
             debug_assert_valid(&n);
+            observer::after_phase("dce", &n);
         }
     }
 
@@ -193,6 +197,7 @@ pub fn optimize(
                 &Minification,
             ))
         }
+        observer::after_phase("compress", &n);
 
         // Again, we don't need to validate ast
 
@@ -208,6 +213,7 @@ pub fn optimize(
                 enable_join_vars: true,
             },
         ));
+        observer::after_phase("postcompress", &n);
     }
 
     if let Some(ref mut _t) = timings {
@@ -250,6 +256,7 @@ pub fn optimize(
         if let Some(property_mangle_options) = &mangle.props {
             mangle_properties(&mut n, property_mangle_options.clone(), chars);
         }
+        observer::after_phase("mangle", &n);
     }
 
     n.visit_mut_with(&mut merge_exports());
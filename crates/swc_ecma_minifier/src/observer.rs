@@ -0,0 +1,26 @@
+//! Hooks to inspect the program between the phases of [crate::optimize].
+//!
+//! This is intended for debugging tools which want to know which phase of the
+//! minifier changed a piece of code.
+
+use better_scoped_tls::scoped_tls;
+use swc_ecma_ast::Program;
+
+/// Receives the program after each phase of [crate::optimize].
+pub trait PhaseObserver {
+    fn after_phase(&self, phase: &'static str, program: &Program);
+}
+
+scoped_tls!(static OBSERVER: Box<dyn PhaseObserver>);
+
+/// Runs `op` with `observer` notified about the phases of [crate::optimize]
+/// called from `op`.
+pub fn observe_phases<Ret>(observer: Box<dyn PhaseObserver>, op: impl FnOnce() -> Ret) -> Ret {
+    OBSERVER.set(&observer, op)
+}
+
+pub(crate) fn after_phase(phase: &'static str, program: &Program) {
+    if OBSERVER.is_set() {
+        OBSERVER.with(|observer| observer.after_phase(phase, program));
+    }
+}
@@ -186,6 +186,8 @@ impl SizeWithCtxt for Expr {
             },
 
             Expr::Paren(p) => 2 + p.expr.size(unresolved),
+            Expr::Pipeline(p) => p.head.size(unresolved) + 2 + p.body.size(unresolved),
+            Expr::TopicRef(_) => 1,
             Expr::Invalid(_) => 0,
 
             Expr::JSXMember(_) => TODO,
@@ -1394,9 +1394,28 @@ impl<I: Tokens> Parser<I> {
             },
         );
 
-        self.with_ctx(ctx)
-            .with_state(State::default())
-            .parse_fn_body_inner(is_simple_parameter_list)
+        // Arrow functions don't shadow the topic reference of a pipeline
+        let topic_used = if is_arrow_function {
+            self.state.topic_used
+        } else {
+            None
+        };
+        let (body, topic_used) = {
+            let mut p = self.with_ctx(ctx);
+            let mut p = p.with_state(State {
+                topic_used,
+                ..Default::default()
+            });
+            let body = p.parse_fn_body_inner(is_simple_parameter_list);
+
+            (body, p.state.topic_used)
+        };
+
+        if is_arrow_function {
+            self.state.topic_used = topic_used;
+        }
+
+        body
     }
 }
 
@@ -225,6 +225,10 @@ impl<I: Tokens> Parser<I> {
         let test = self.parse_bin_expr()?;
         return_if_arrow!(self, test);
 
+        if is!(self, "|>") {
+            return self.parse_pipeline_body(start, test);
+        }
+
         if eat!(self, '?') {
             let ctx = self.ctx()
                 | Context::InCondExpr
@@ -247,6 +251,38 @@ impl<I: Tokens> Parser<I> {
         }
     }
 
+    /// Parses the body of a Hack-style pipeline, e.g. `f(%)` in `x |> f(%)`.
+    ///
+    /// The body is an assignment expression, so `x |> f(%) |> g(%)` is parsed
+    /// as `x |> (f(%) |> g(%))`.
+    fn parse_pipeline_body(&mut self, start: BytePos, head: Box<Expr>) -> PResult<Box<Expr>> {
+        trace_cur!(self, parse_pipeline_body);
+
+        assert_and_bump!(self, "|>");
+
+        let outer = self.state.topic_used.replace(false);
+        let body = self.parse_assignment_expr();
+        let is_topic_used = std::mem::replace(&mut self.state.topic_used, outer) == Some(true);
+        let body = body?;
+
+        if matches!(
+            *body,
+            Expr::Arrow(..) | Expr::Assign(..) | Expr::Cond(..) | Expr::Yield(..)
+        ) {
+            self.emit_err(body.span(), SyntaxError::PipelineBodyRequiresParens);
+        }
+        if !is_topic_used {
+            self.emit_err(body.span(), SyntaxError::PipelineTopicUnused);
+        }
+
+        Ok(PipelineExpr {
+            span: span!(self, start),
+            head,
+            body,
+        }
+        .into())
+    }
+
     /// Parse a primary expression or arrow function
     #[cfg_attr(
         feature = "tracing-spans",
@@ -263,6 +299,7 @@ impl<I: Tokens> Parser<I> {
             .potential_arrow_start
             .map(|s| s == start)
             .unwrap_or(false);
+        let has_pipeline = self.syntax().pipeline_operator();
 
         if let Some(tok) = self.input.cur() {
             match tok {
@@ -381,6 +418,20 @@ impl<I: Tokens> Parser<I> {
                     return self.parse_paren_expr_or_arrow_fn(can_be_arrow, None);
                 }
 
+                tok!('%') if has_pipeline => {
+                    bump!(self);
+                    // The topic reference is an operand, so `/` after it is a division
+                    self.input.set_expr_allowed(false);
+
+                    let span = span!(self, start);
+                    match &mut self.state.topic_used {
+                        Some(is_used) => *is_used = true,
+                        None => self.emit_err(span, SyntaxError::TopicRefOutsideOfPipeline),
+                    }
+
+                    return Ok(TopicRef { span }.into());
+                }
+
                 _ => {}
             }
         }
@@ -2015,6 +2066,7 @@ impl<I: Tokens> Parser<I> {
                 && !is!(self, '*')
                 && !is!(self, '/')
                 && !is!(self, "/=")
+                && !(is!(self, '%') && self.syntax().pipeline_operator())
                 && !cur!(self, false)
                     .map(|t| t.kind().starts_expr())
                     .unwrap_or(true))
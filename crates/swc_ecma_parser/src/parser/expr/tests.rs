@@ -527,6 +527,111 @@ fn issue_6781() {
     assert!(errors.is_empty());
 }
 
+fn pipeline(s: &'static str) -> Box<Expr> {
+    test_parser(
+        s,
+        Syntax::Es(EsSyntax {
+            pipeline_operator: true,
+            ..Default::default()
+        }),
+        |p| p.parse_expr(),
+    )
+}
+
+#[test]
+fn pipeline_topic_division() {
+    assert_eq_ignore_span!(
+        pipeline("x |> % / 2"),
+        Box::new(Expr::Pipeline(PipelineExpr {
+            span,
+            head: Ident::new_no_ctxt("x".into(), span).into(),
+            body: BinExpr {
+                span,
+                op: op!("/"),
+                left: TopicRef { span }.into(),
+                right: Box::new(Expr::Lit(Lit::Num(Number {
+                    span,
+                    value: 2.0,
+                    raw: Some("2".into()),
+                }))),
+            }
+            .into(),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_topic_modulo() {
+    assert_eq_ignore_span!(
+        pipeline("x |> % % 2"),
+        Box::new(Expr::Pipeline(PipelineExpr {
+            span,
+            head: Ident::new_no_ctxt("x".into(), span).into(),
+            body: BinExpr {
+                span,
+                op: op!("%"),
+                left: TopicRef { span }.into(),
+                right: Box::new(Expr::Lit(Lit::Num(Number {
+                    span,
+                    value: 2.0,
+                    raw: Some("2".into()),
+                }))),
+            }
+            .into(),
+        }))
+    );
+}
+
+#[test]
+fn pipeline_body_is_nested() {
+    let expr = pipeline("x |> f(%) |> g(%)");
+    let body = &expr.as_pipeline().unwrap().body;
+
+    assert!(body.is_pipeline());
+}
+
+#[test]
+fn pipeline_yield_topic() {
+    test_parser(
+        "function* f() { x |> (yield %) }",
+        Syntax::Es(EsSyntax {
+            pipeline_operator: true,
+            ..Default::default()
+        }),
+        |p| p.parse_script(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Topic reference `%` is only allowed in the body of a pipeline")]
+fn pipeline_topic_outside_of_body() {
+    pipeline("f(%)");
+}
+
+#[test]
+#[should_panic(expected = "Topic reference `%` is only allowed in the body of a pipeline")]
+fn pipeline_topic_in_function() {
+    pipeline("x |> % + function () { return %; }");
+}
+
+#[test]
+#[should_panic(expected = "The body of a pipeline must use the topic reference `%`")]
+fn pipeline_unused_topic() {
+    pipeline("x |> f(%) |> g");
+}
+
+#[test]
+#[should_panic(expected = "must be parenthesized in the body of a pipeline")]
+fn pipeline_unparenthesized_arrow() {
+    pipeline("x |> () => %");
+}
+
+#[test]
+#[should_panic(expected = "must be parenthesized in the body of a pipeline")]
+fn pipeline_unparenthesized_cond() {
+    pipeline("x |> % ? 1 : 2");
+}
+
 #[bench]
 fn bench_new_expr_ts(b: &mut Bencher) {
     bench_parser(
@@ -58,6 +58,9 @@ struct State {
     potential_arrow_start: Option<BytePos>,
     /// Start position of an AST node and the span of its trailing comma.
     trailing_commas: FxHashMap<BytePos, Span>,
+    /// `Some` while parsing the body of a pipeline, with whether the topic
+    /// reference (`%`) was used.
+    topic_used: Option<bool>,
 }
 
 impl<'a> Parser<Lexer<'a>> {
@@ -160,6 +160,8 @@ pub(super) trait ExprExt {
 
             Expr::OptChain(..) => false,
 
+            Expr::Pipeline(..) | Expr::TopicRef(..) => false,
+
             // MemberExpression is valid assignment target
             Expr::PrivateName(..) => false,
 
@@ -0,0 +1 @@
+const result = value |> double(%) |> % + 1;
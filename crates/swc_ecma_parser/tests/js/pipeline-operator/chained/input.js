@@ -0,0 +1,2 @@
+x |> (y |> f(%)) + %;
+x |> (% ? 1 : 2);
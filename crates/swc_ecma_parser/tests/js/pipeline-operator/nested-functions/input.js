@@ -0,0 +1 @@
+list |> %.map((x) => x * 2) |> (() => %.length)();
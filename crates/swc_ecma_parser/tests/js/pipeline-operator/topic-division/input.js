@@ -0,0 +1 @@
+a |> % / 2 |> %[0] % 3;
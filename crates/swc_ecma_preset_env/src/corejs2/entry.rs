@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use preset_env_base::{version::should_enable, Versions};
 use rustc_hash::FxBuildHasher;
 use swc_atoms::atom;
-use swc_common::DUMMY_SP;
+use swc_common::{Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
@@ -14,7 +14,8 @@ use super::builtin::BUILTINS;
 pub struct Entry {
     is_any_target: bool,
     target: Arc<Versions>,
-    pub imports: IndexSet<&'static str, FxBuildHasher>,
+    /// Imported modules, with the span of the entry import.
+    pub imports: IndexMap<&'static str, Span, FxBuildHasher>,
 }
 
 impl Entry {
@@ -34,13 +35,13 @@ impl Entry {
             imports: Default::default(),
         };
         if is_any_target || is_web_target {
-            v.imports.insert("web.timers");
-            v.imports.insert("web.immediate");
-            v.imports.insert("web.dom.iterable");
+            v.imports.insert("web.timers", DUMMY_SP);
+            v.imports.insert("web.immediate", DUMMY_SP);
+            v.imports.insert("web.dom.iterable", DUMMY_SP);
         }
 
         if regenerator {
-            v.imports.insert("regenerator-runtime/runtime.js");
+            v.imports.insert("regenerator-runtime/runtime.js", DUMMY_SP);
         }
 
         v
@@ -48,21 +49,21 @@ impl Entry {
 
     /// Add imports.
     /// Returns true if it's replaced.
-    fn add_all(&mut self, src: &str) -> bool {
+    fn add_all(&mut self, src: &str, span: Span) -> bool {
         if src != "@babel/polyfill" && src != "@swc/polyfill" && src != "core-js" {
             return false;
         }
 
         for (feature, version) in BUILTINS.iter() {
-            self.add_inner(feature, version);
+            self.add_inner(feature, version, span);
         }
 
         true
     }
 
-    fn add_inner(&mut self, feature: &'static str, version: &Versions) {
+    fn add_inner(&mut self, feature: &'static str, version: &Versions, span: Span) {
         if self.is_any_target || should_enable(&self.target, version, true) {
-            self.imports.insert(feature);
+            self.imports.entry(feature).or_insert(span);
         }
     }
 }
@@ -71,7 +72,7 @@ impl VisitMut for Entry {
     noop_visit_mut_type!(fail);
 
     fn visit_mut_import_decl(&mut self, i: &mut ImportDecl) {
-        let remove = i.specifiers.is_empty() && self.add_all(&i.src.value);
+        let remove = i.specifiers.is_empty() && self.add_all(&i.src.value, i.span);
 
         if remove {
             i.src.value = atom!("");
@@ -102,7 +103,7 @@ impl VisitMut for Entry {
                         } else {
                             false
                         }
-                        && self.add_all("@swc/polyfill")
+                        && self.add_all("@swc/polyfill", expr.span())
                     {
                         return false;
                     }
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use preset_env_base::{version::should_enable, Versions};
 use rustc_hash::FxBuildHasher;
 use swc_atoms::Atom;
+use swc_common::{Span, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
@@ -21,7 +22,10 @@ mod entry;
 pub(crate) struct UsageVisitor {
     is_any_target: bool,
     target: Arc<Versions>,
-    pub required: IndexSet<&'static str, FxBuildHasher>,
+    /// Required modules, with the span of the first usage.
+    pub required: IndexMap<&'static str, Span, FxBuildHasher>,
+    /// Span of the node being visited.
+    span: Span,
 }
 
 impl UsageVisitor {
@@ -53,6 +57,7 @@ impl UsageVisitor {
             is_any_target: target.is_any_target(),
             target,
             required: Default::default(),
+            span: DUMMY_SP,
         }
     }
 
@@ -61,10 +66,11 @@ impl UsageVisitor {
         let UsageVisitor {
             is_any_target,
             target,
-            ..
+            required,
+            span,
         } = self;
 
-        self.required.extend(features.iter().filter(|f| {
+        let features = features.iter().filter(|f| {
             if !*is_any_target {
                 if let Some(v) = BUILTINS.get(&***f) {
                     // Skip
@@ -75,7 +81,11 @@ impl UsageVisitor {
             }
 
             true
-        }));
+        });
+
+        for f in features {
+            required.entry(f).or_insert(*span);
+        }
     }
 
     fn add_property_deps_inner(&mut self, obj: Option<&Atom>, prop: &Atom) {
@@ -130,6 +140,7 @@ impl Visit for UsageVisitor {
 
     fn visit_ident(&mut self, node: &Ident) {
         node.visit_children_with(self);
+        self.span = node.span;
 
         for (name, builtin) in BUILTIN_TYPES {
             if node.sym == **name {
@@ -140,6 +151,7 @@ impl Visit for UsageVisitor {
 
     fn visit_var_declarator(&mut self, d: &VarDeclarator) {
         d.visit_children_with(self);
+        self.span = d.span;
 
         if let Some(ref init) = d.init {
             if let Pat::Object(ref o) = d.name {
@@ -152,6 +164,7 @@ impl Visit for UsageVisitor {
 
     fn visit_assign_expr(&mut self, e: &AssignExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         if let AssignTarget::Pat(AssignTargetPat::Object(o)) = &e.left {
             self.visit_object_pat_props(&e.right, &o.props)
@@ -166,6 +179,7 @@ impl Visit for UsageVisitor {
         if let MemberProp::Computed(c) = &node.prop {
             c.visit_with(self);
         }
+        self.span = node.span;
         //enter(path: NodePath) {
         //    const { node } = path;
         //    const { object, property } = node;
@@ -289,6 +303,7 @@ impl Visit for UsageVisitor {
     /// - `arr[Symbol.iterator]()`
     fn visit_call_expr(&mut self, e: &CallExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         if match &e.callee {
             Callee::Expr(callee) => matches!(&**callee, Expr::Member(MemberExpr {
@@ -305,6 +320,7 @@ impl Visit for UsageVisitor {
     /// - `Symbol.iterator in arr`
     fn visit_bin_expr(&mut self, e: &BinExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         match e.op {
             op!("in") if is_symbol_iterator(&e.left) => self.add(&["web.dom.iterable"]),
@@ -316,6 +332,7 @@ impl Visit for UsageVisitor {
     /// - `yield*`
     fn visit_yield_expr(&mut self, e: &YieldExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         if e.delegate {
             self.add(&["web.dom.iterable"])
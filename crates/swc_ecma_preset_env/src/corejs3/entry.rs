@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use preset_env_base::{
     version::{should_enable, Version},
@@ -8,7 +8,7 @@ use preset_env_base::{
 };
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use swc_atoms::atom;
-use swc_common::DUMMY_SP;
+use swc_common::{Span, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::VisitMut;
 
@@ -36,7 +36,8 @@ pub struct Entry {
     is_any_target: bool,
     target: Arc<Versions>,
     corejs_version: Version,
-    pub imports: IndexSet<&'static str, FxBuildHasher>,
+    /// Imported modules, with the span of the entry import.
+    pub imports: IndexMap<&'static str, Span, FxBuildHasher>,
     remove_regenerator: bool,
 }
 
@@ -55,13 +56,13 @@ impl Entry {
 
     /// Add imports.
     /// Returns true if it's replaced.
-    fn add(&mut self, src: &str) -> bool {
+    fn add(&mut self, src: &str, span: Span) -> bool {
         let Entry {
             is_any_target,
             target,
             corejs_version,
             remove_regenerator,
-            ..
+            imports,
         } = self;
 
         if *remove_regenerator && src == "regenerator-runtime/runtime.js" {
@@ -69,7 +70,7 @@ impl Entry {
         }
 
         if let Some(features) = ENTRIES.get(src) {
-            self.imports.extend(features.iter().filter(|f| {
+            let features = features.iter().filter(|f| {
                 let feature = CORE_JS_COMPAT_DATA.get(&***f);
 
                 if !*is_any_target {
@@ -85,7 +86,11 @@ impl Entry {
                 }
 
                 true
-            }));
+            });
+
+            for f in features {
+                imports.entry(f).or_insert(span);
+            }
 
             true
         } else {
@@ -96,7 +101,7 @@ impl Entry {
 
 impl VisitMut for Entry {
     fn visit_mut_import_decl(&mut self, i: &mut ImportDecl) {
-        let remove = i.specifiers.is_empty() && self.add(&i.src.value);
+        let remove = i.specifiers.is_empty() && self.add(&i.src.value, i.span);
 
         if remove {
             i.src.span = DUMMY_SP;
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use indexmap::IndexSet;
+use indexmap::IndexMap;
 use preset_env_base::version::{should_enable, Version};
 use rustc_hash::FxBuildHasher;
 use swc_atoms::Atom;
+use swc_common::{Span, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
@@ -22,7 +23,10 @@ pub(crate) struct UsageVisitor {
     is_any_target: bool,
     target: Arc<Versions>,
     corejs_version: Version,
-    pub required: IndexSet<&'static str, FxBuildHasher>,
+    /// Required modules, with the span of the first usage.
+    pub required: IndexMap<&'static str, Span, FxBuildHasher>,
+    /// Span of the node being visited.
+    span: Span,
 }
 
 impl UsageVisitor {
@@ -52,6 +56,7 @@ impl UsageVisitor {
             target,
             corejs_version,
             required: Default::default(),
+            span: DUMMY_SP,
         }
     }
 
@@ -70,10 +75,11 @@ impl UsageVisitor {
             is_any_target,
             target,
             corejs_version,
-            ..
+            required,
+            span,
         } = self;
 
-        self.required.extend(features.iter().filter(|f| {
+        let features = features.iter().filter(|f| {
             if !*shipped_proposals && f.starts_with("esnext.") {
                 return false;
             }
@@ -93,7 +99,11 @@ impl UsageVisitor {
             }
 
             true
-        }));
+        });
+
+        for f in features {
+            required.entry(f).or_insert(*span);
+        }
     }
 
     fn add_builtin(&mut self, built_in: &str) {
@@ -161,12 +171,14 @@ impl Visit for UsageVisitor {
     /// `[a, b] = c`
     fn visit_array_pat(&mut self, p: &ArrayPat) {
         p.visit_children_with(self);
+        self.span = p.span;
 
         self.may_inject_global(COMMON_ITERATORS)
     }
 
     fn visit_assign_expr(&mut self, e: &AssignExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         if let AssignTarget::Pat(AssignTargetPat::Object(o)) = &e.left {
             self.visit_object_pat_props(&e.right, &o.props)
@@ -175,6 +187,7 @@ impl Visit for UsageVisitor {
 
     fn visit_bin_expr(&mut self, e: &BinExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         if e.op == op!("in") {
             // 'entries' in Object
@@ -189,6 +202,7 @@ impl Visit for UsageVisitor {
     /// RegExp(".", "us")
     fn visit_call_expr(&mut self, e: &CallExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         if let Callee::Import(_) = &e.callee {
             self.may_inject_global(PROMISE_DEPENDENCIES)
@@ -199,6 +213,7 @@ impl Visit for UsageVisitor {
         e.visit_children_with(self);
 
         if let Expr::Ident(i) = e {
+            self.span = i.span;
             self.add_builtin(&i.sym)
         }
     }
@@ -206,7 +221,8 @@ impl Visit for UsageVisitor {
     /// `[...spread]`
     fn visit_expr_or_spread(&mut self, e: &ExprOrSpread) {
         e.visit_children_with(self);
-        if e.spread.is_some() {
+        if let Some(spread) = e.spread {
+            self.span = spread;
             self.may_inject_global(COMMON_ITERATORS)
         }
     }
@@ -214,12 +230,14 @@ impl Visit for UsageVisitor {
     /// for-of
     fn visit_for_of_stmt(&mut self, s: &ForOfStmt) {
         s.visit_children_with(self);
+        self.span = s.span;
 
         self.may_inject_global(COMMON_ITERATORS)
     }
 
     fn visit_function(&mut self, f: &Function) {
         f.visit_children_with(self);
+        self.span = f.span;
 
         if f.is_async {
             self.may_inject_global(PROMISE_DEPENDENCIES)
@@ -229,11 +247,13 @@ impl Visit for UsageVisitor {
     fn visit_member_expr(&mut self, e: &MemberExpr) {
         e.obj.visit_with(self);
         if let MemberProp::Computed(c) = &e.prop {
+            c.visit_with(self);
             if let Expr::Lit(Lit::Str(s)) = &*c.expr {
+                self.span = e.span;
                 self.add_property_deps(&e.obj, &s.value);
             }
-            c.visit_with(self);
         }
+        self.span = e.span;
 
         // Object.entries
         // [1, 2, 3].entries
@@ -250,6 +270,7 @@ impl Visit for UsageVisitor {
 
     fn visit_var_declarator(&mut self, d: &VarDeclarator) {
         d.visit_children_with(self);
+        self.span = d.span;
 
         if let Some(ref init) = d.init {
             if let Pat::Object(ref o) = d.name {
@@ -263,6 +284,7 @@ impl Visit for UsageVisitor {
     /// `yield*`
     fn visit_yield_expr(&mut self, e: &YieldExpr) {
         e.visit_children_with(self);
+        self.span = e.span;
 
         if e.delegate {
             self.may_inject_global(COMMON_ITERATORS)
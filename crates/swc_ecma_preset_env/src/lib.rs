@@ -7,9 +7,11 @@ use std::{path::PathBuf, sync::Arc};
 use preset_env_base::query::targets_to_versions;
 pub use preset_env_base::{query::Targets, version::Version, BrowserData, Versions};
 use rustc_hash::FxHashSet;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use swc_atoms::{atom, Atom};
-use swc_common::{comments::Comments, pass::Optional, FromVariant, Mark, SyntaxContext, DUMMY_SP};
+use swc_common::{
+    comments::Comments, pass::Optional, FromVariant, Mark, Span, SyntaxContext, DUMMY_SP,
+};
 use swc_ecma_ast::*;
 use swc_ecma_transforms::{
     compat::{
@@ -24,13 +26,17 @@ use swc_ecma_transforms::{
 use swc_ecma_utils::{prepend_stmts, ExprFactory};
 use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith, VisitWith};
 
-pub use self::transform_data::Feature;
+pub use self::{
+    report::{EnvReport, EnvReporter, PolyfillReport, TransformReason, TransformReport},
+    transform_data::Feature,
+};
 
 #[macro_use]
 mod util;
 mod corejs2;
 mod corejs3;
 mod regenerator;
+mod report;
 mod transform_data;
 
 pub trait Caniuse {
@@ -320,6 +326,13 @@ pub fn transform_from_env<C>(
 where
     C: Comments + Clone,
 {
+    let reporter = env_config.reporter.clone();
+    if let Some(reporter) = &reporter {
+        reporter.set_targets(&env_config.core_js_config.targets);
+    }
+
+    let feature_config = env_config.feature_config.clone();
+    let transform_reporter = reporter.clone();
     let pass = transform_internal(
         unresolved_mark,
         comments,
@@ -327,7 +340,15 @@ where
         env_config.config.loose,
         env_config.config.dynamic_import,
         env_config.config.debug,
-        move |f| env_config.feature_config.caniuse(f),
+        move |f| match feature_config.transform_reason(f) {
+            Some(reason) => {
+                if let Some(reporter) = &transform_reporter {
+                    reporter.add_transform(f, reason);
+                }
+                false
+            }
+            None => true,
+        },
     );
 
     if env_config.config.debug {
@@ -349,6 +370,7 @@ where
             includes: env_config.core_js_config.included_modules,
             excludes: env_config.core_js_config.excluded_modules,
             unresolved_mark,
+            reporter,
         }),
     )
 }
@@ -398,9 +420,11 @@ struct Polyfills {
     includes: FxHashSet<String>,
     excludes: FxHashSet<String>,
     unresolved_mark: Mark,
+    reporter: Option<EnvReporter>,
 }
 impl Polyfills {
-    fn collect<T>(&mut self, m: &mut T) -> Vec<Atom>
+    /// Returns the modules to import, with the spans which require them.
+    fn collect<T>(&mut self, m: &mut T) -> Vec<(Atom, Span)>
     where
         T: VisitWith<corejs2::UsageVisitor>
             + VisitWith<corejs3::UsageVisitor>
@@ -431,7 +455,7 @@ impl Polyfills {
                 };
 
                 if regenerator::is_required(m) {
-                    r.insert("regenerator-runtime/runtime.js");
+                    r.insert("regenerator-runtime/runtime.js", DUMMY_SP);
                 }
 
                 r
@@ -455,74 +479,71 @@ impl Polyfills {
         };
         required
             .iter()
-            .filter(|s| {
-                !s.starts_with("esnext") || !required.contains(&s.replace("esnext", "es").as_str())
+            .filter(|(s, _)| {
+                !s.starts_with("esnext")
+                    || !required.contains_key(&s.replace("esnext", "es").as_str())
             })
-            .filter(|s| !self.excludes.contains(&***s))
-            .map(|s| -> Atom {
+            .filter(|(s, _)| !self.excludes.contains(&***s))
+            .map(|(s, span)| -> (Atom, Span) {
                 if *s != "regenerator-runtime/runtime.js" {
-                    format!("core-js/modules/{s}.js").into()
+                    (format!("core-js/modules/{s}.js").into(), *span)
                 } else {
-                    "regenerator-runtime/runtime.js".to_string().into()
+                    ("regenerator-runtime/runtime.js".to_string().into(), *span)
                 }
             })
             .chain(self.includes.iter().map(|s| {
                 if s != "regenerator-runtime/runtime.js" {
-                    format!("core-js/modules/{s}.js").into()
+                    (format!("core-js/modules/{s}.js").into(), DUMMY_SP)
                 } else {
-                    "regenerator-runtime/runtime.js".to_string().into()
+                    (
+                        "regenerator-runtime/runtime.js".to_string().into(),
+                        DUMMY_SP,
+                    )
                 }
             }))
             .collect::<Vec<_>>()
     }
+
+    /// Sorts `required` in debug builds for deterministic output, and records
+    /// it to the report.
+    fn finish(&self, mut required: Vec<(Atom, Span)>) -> Vec<Atom> {
+        if cfg!(debug_assertions) {
+            required.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+
+        if let Some(reporter) = &self.reporter {
+            for (src, span) in &required {
+                reporter.add_polyfill(src.clone(), *span);
+            }
+        }
+
+        required.into_iter().map(|(src, _)| src).collect()
+    }
 }
 impl VisitMut for Polyfills {
     fn visit_mut_module(&mut self, m: &mut Module) {
         let span = m.span;
         let required = self.collect(m);
-        if cfg!(debug_assertions) {
-            let mut v = required.into_iter().collect::<Vec<_>>();
-            v.sort();
-            prepend_stmts(
-                &mut m.body,
-                v.into_iter().map(|src| {
-                    ImportDecl {
-                        span,
-                        specifiers: Vec::new(),
-                        src: Str {
-                            span: DUMMY_SP,
-                            raw: None,
-                            value: src,
-                        }
-                        .into(),
-                        type_only: false,
-                        with: None,
-                        phase: Default::default(),
-                    }
-                    .into()
-                }),
-            );
-        } else {
-            prepend_stmts(
-                &mut m.body,
-                required.into_iter().map(|src| {
-                    ImportDecl {
-                        span,
-                        specifiers: Vec::new(),
-                        src: Str {
-                            span: DUMMY_SP,
-                            raw: None,
-                            value: src,
-                        }
-                        .into(),
-                        type_only: false,
-                        with: None,
-                        phase: Default::default(),
+        let required = self.finish(required);
+        prepend_stmts(
+            &mut m.body,
+            required.into_iter().map(|src| {
+                ImportDecl {
+                    span,
+                    specifiers: Vec::new(),
+                    src: Str {
+                        span: DUMMY_SP,
+                        raw: None,
+                        value: src,
                     }
-                    .into()
-                }),
-            );
-        }
+                    .into(),
+                    type_only: false,
+                    with: None,
+                    phase: Default::default(),
+                }
+                .into()
+            }),
+        );
 
         m.body.retain(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { src, .. })) if src.span == DUMMY_SP && src.value == atom!("")));
     }
@@ -530,68 +551,37 @@ impl VisitMut for Polyfills {
     fn visit_mut_script(&mut self, m: &mut Script) {
         let span = m.span;
         let required = self.collect(m);
-        if cfg!(debug_assertions) {
-            let mut v = required.into_iter().collect::<Vec<_>>();
-            v.sort();
-            prepend_stmts(
-                &mut m.body,
-                v.into_iter().map(|src| {
-                    ExprStmt {
-                        span: DUMMY_SP,
-                        expr: CallExpr {
-                            span,
-                            callee: Ident {
-                                ctxt: SyntaxContext::empty().apply_mark(self.unresolved_mark),
-                                sym: "require".into(),
-                                ..Default::default()
-                            }
-                            .as_callee(),
-                            args: vec![Str {
-                                span: DUMMY_SP,
-                                value: src,
-                                raw: None,
-                            }
-                            .as_arg()],
-                            type_args: None,
+        let required = self.finish(required);
+        prepend_stmts(
+            &mut m.body,
+            required.into_iter().map(|src| {
+                ExprStmt {
+                    span: DUMMY_SP,
+                    expr: CallExpr {
+                        span,
+                        callee: Ident {
+                            ctxt: SyntaxContext::empty().apply_mark(self.unresolved_mark),
+                            sym: "require".into(),
                             ..Default::default()
                         }
-                        .into(),
-                    }
-                    .into()
-                }),
-            );
-        } else {
-            prepend_stmts(
-                &mut m.body,
-                required.into_iter().map(|src| {
-                    ExprStmt {
-                        span: DUMMY_SP,
-                        expr: CallExpr {
-                            span,
-                            callee: Ident {
-                                ctxt: SyntaxContext::empty().apply_mark(self.unresolved_mark),
-                                sym: "require".into(),
-                                ..Default::default()
-                            }
-                            .as_callee(),
-                            args: vec![Str {
-                                span: DUMMY_SP,
-                                value: src,
-                                raw: None,
-                            }
-                            .as_arg()],
-                            ..Default::default()
+                        .as_callee(),
+                        args: vec![Str {
+                            span: DUMMY_SP,
+                            value: src,
+                            raw: None,
                         }
-                        .into(),
+                        .as_arg()],
+                        ..Default::default()
                     }
-                    .into()
-                }),
-            );
-        }
+                    .into(),
+                }
+                .into()
+            }),
+        );
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Mode {
     #[serde(rename = "usage")]
     Usage,
@@ -599,7 +589,7 @@ pub enum Mode {
     Entry,
 }
 
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     #[serde(default)]
@@ -645,6 +635,11 @@ pub struct Config {
 
     #[serde(default)]
     pub bugfixes: bool,
+
+    /// Collect an [EnvReport] of the transforms and polyfills applied to the
+    /// file.
+    #[serde(default)]
+    pub report: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -667,6 +662,7 @@ pub struct EnvConfig {
     config: Config,
     feature_config: Arc<FeatureConfig>,
     core_js_config: CoreJSConfig,
+    reporter: Option<EnvReporter>,
 }
 
 impl From<Config> for EnvConfig {
@@ -691,10 +687,12 @@ impl From<Config> for EnvConfig {
             included_modules,
             excluded_modules,
         };
+        let reporter = config.report.then(EnvReporter::default);
         Self {
             config,
             feature_config: Arc::new(feature_config),
             core_js_config,
+            reporter,
         }
     }
 }
@@ -703,27 +701,57 @@ impl EnvConfig {
     pub fn get_feature_config(&self) -> Arc<FeatureConfig> {
         Arc::clone(&self.feature_config)
     }
+
+    /// Returns the reporter if [Config::report] is enabled.
+    ///
+    /// The report is filled while the pass created by [transform_from_env] is
+    /// applied.
+    pub fn reporter(&self) -> Option<EnvReporter> {
+        self.reporter.clone()
+    }
 }
 
-impl Caniuse for FeatureConfig {
-    fn caniuse(&self, feature: Feature) -> bool {
+impl FeatureConfig {
+    /// Returns why `feature` should be transformed, or [None] if the targets
+    /// support it.
+    pub fn transform_reason(&self, feature: Feature) -> Option<TransformReason> {
         if self.exclude.contains(&feature) {
-            return true;
+            return None;
+        }
+
+        if self.force_all_transforms {
+            return Some(TransformReason::ForceAllTransforms);
         }
 
-        if self.force_all_transforms || self.is_any_target {
-            return false;
+        if self.is_any_target {
+            return Some(TransformReason::AnyTarget);
         }
 
         if self.include.contains(&feature) {
-            return false;
+            return Some(TransformReason::Included);
         }
 
-        !feature.should_enable(&self.targets, self.bugfixes, false)
+        let browsers = feature.unsupported_targets(&self.targets, self.bugfixes);
+        if browsers.is_empty() {
+            return None;
+        }
+
+        Some(TransformReason::Targets {
+            browsers: browsers
+                .into_iter()
+                .map(|(name, version)| (name.to_string(), version.to_string()))
+                .collect(),
+        })
+    }
+}
+
+impl Caniuse for FeatureConfig {
+    fn caniuse(&self, feature: Feature) -> bool {
+        self.transform_reason(feature).is_none()
     }
 }
 
-#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromVariant)]
 #[serde(untagged)]
 pub enum FeatureOrModule {
     Feature(Feature),
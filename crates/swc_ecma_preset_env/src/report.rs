@@ -0,0 +1,90 @@
+//! Structured report of the decisions made by preset-env for a file.
+//!
+//! Enabled by [Config::report](crate::Config::report). Unlike
+//! [Config::debug](crate::Config::debug), which prints to stdout, the report
+//! can be inspected by the caller with
+//! [EnvConfig::reporter](crate::EnvConfig::reporter).
+
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use serde::Serialize;
+use swc_atoms::Atom;
+use swc_common::Span;
+
+use crate::{Feature, Versions};
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvReport {
+    /// Resolved versions of the targets, by browser.
+    pub targets: BTreeMap<String, String>,
+    /// Transforms which are enabled, in the order they are applied.
+    pub transforms: Vec<TransformReport>,
+    /// Modules which are imported, in the order they are injected.
+    pub polyfills: Vec<PolyfillReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformReport {
+    pub feature: Feature,
+    pub reason: TransformReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TransformReason {
+    /// `forceAllTransforms` is enabled.
+    ForceAllTransforms,
+    /// No target is specified.
+    AnyTarget,
+    /// The feature is listed in `include`.
+    Included,
+    /// These targets do not support the feature.
+    Targets { browsers: BTreeMap<String, String> },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolyfillReport {
+    /// e.g. `core-js/modules/es.array.from.js`
+    pub module: Atom,
+    /// The usage of the builtin for `usage` mode, or the entry import for
+    /// `entry` mode. Dummy if the module is listed in `include`.
+    pub span: Span,
+}
+
+/// Shared between the passes of preset-env for a single file.
+#[derive(Debug, Clone, Default)]
+pub struct EnvReporter {
+    report: Rc<RefCell<EnvReport>>,
+}
+
+impl EnvReporter {
+    pub(crate) fn set_targets(&self, targets: &Versions) {
+        self.report.borrow_mut().targets = targets
+            .iter()
+            .filter_map(|(name, version)| Some((name.to_string(), version.as_ref()?.to_string())))
+            .collect();
+    }
+
+    pub(crate) fn add_transform(&self, feature: Feature, reason: TransformReason) {
+        let mut report = self.report.borrow_mut();
+
+        // Some features are checked more than once.
+        if report.transforms.iter().all(|t| t.feature != feature) {
+            report.transforms.push(TransformReport { feature, reason });
+        }
+    }
+
+    pub(crate) fn add_polyfill(&self, module: Atom, span: Span) {
+        self.report
+            .borrow_mut()
+            .polyfills
+            .push(PolyfillReport { module, span });
+    }
+
+    pub fn report(&self) -> EnvReport {
+        self.report.borrow().clone()
+    }
+}
@@ -1,6 +1,6 @@
 use once_cell::sync::Lazy;
 use preset_env_base::{
-    version::{should_enable, Version},
+    version::{should_enable, unsupported_targets, Version},
     BrowserData, Versions,
 };
 use rustc_hash::FxHashMap;
@@ -19,6 +19,24 @@ impl Feature {
 
         should_enable(target, f, default)
     }
+
+    /// Returns the targets which require the transform of this feature.
+    pub fn unsupported_targets(
+        self,
+        target: &Versions,
+        bugfixes: bool,
+    ) -> Vec<(&'static str, Version)> {
+        let f = if bugfixes {
+            &BUGFIX_FEATURES[&self]
+        } else {
+            match FEATURES.get(&self) {
+                Some(f) => f,
+                None => return Vec::new(),
+            }
+        };
+
+        unsupported_targets(target, f).collect()
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, StringEnum, Hash)]
@@ -0,0 +1,84 @@
+use swc_common::{comments::SingleThreadedComments, FileName, Mark, SourceMapper};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_module, Syntax};
+use swc_ecma_preset_env::{transform_from_env, Config, EnvConfig, Feature, Mode, TransformReason};
+use swc_ecma_transforms::{helpers, resolver};
+
+#[test]
+fn report_transforms_and_polyfills() {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "const a = b ?? 1;\nPromise.allSettled([a]);\n".into(),
+        );
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::default(),
+            EsVersion::latest(),
+            None,
+            &mut vec![],
+        )
+        .unwrap();
+
+        let env: EnvConfig = Config {
+            mode: Some(Mode::Usage),
+            core_js: Some("3.30".parse().unwrap()),
+            targets: Some(serde_json::from_str(r#"{ "chrome": "70" }"#).unwrap()),
+            report: true,
+            ..Default::default()
+        }
+        .into();
+        let reporter = env.reporter().unwrap();
+
+        let unresolved_mark = Mark::new();
+        helpers::HELPERS.set(&Default::default(), || {
+            Program::Module(module).apply((
+                resolver(unresolved_mark, Mark::new(), false),
+                transform_from_env(
+                    unresolved_mark,
+                    None::<SingleThreadedComments>,
+                    env,
+                    Default::default(),
+                ),
+            ))
+        });
+
+        let report = reporter.report();
+
+        assert_eq!(report.targets.len(), 1);
+        assert_eq!(report.targets["chrome"], "70.0.0");
+
+        let nullish = report
+            .transforms
+            .iter()
+            .find(|t| t.feature == Feature::NullishCoalescing)
+            .expect("nullish coalescing should be transformed");
+        match &nullish.reason {
+            TransformReason::Targets { browsers } => {
+                assert_eq!(browsers.len(), 1);
+                assert_eq!(browsers["chrome"], "70.0.0");
+            }
+            reason => panic!("unexpected reason: {reason:?}"),
+        }
+        assert!(report
+            .transforms
+            .iter()
+            .all(|t| t.feature != Feature::ArrowFunctions));
+
+        let all_settled = report
+            .polyfills
+            .iter()
+            .find(|p| &*p.module == "core-js/modules/es.promise.all-settled.js")
+            .expect("Promise.allSettled should be polyfilled");
+        assert_eq!(
+            cm.span_to_snippet(all_settled.span).unwrap(),
+            "Promise.allSettled"
+        );
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["transforms"][0]["reason"]["type"], "targets");
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -148,6 +148,7 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                         shipped_proposals: c.shipped_proposals,
                         targets: c.targets,
                         path: std::env::current_dir().ok(),
+                        report: false,
                     }
                     .into(),
                     Default::default(),
@@ -58,7 +58,9 @@ impl_enum!(
         TsSatisfies,
         PrivateName,
         OptChain,
-        Invalid
+        Invalid,
+        Pipeline,
+        TopicRef
     ],
     true
 );
@@ -107,6 +109,8 @@ impl_struct!(JSXEmptyExpr, [span]);
 impl_struct!(JSXElement, [span, opening, closing, children]);
 impl_struct!(JSXFragment, [span, opening, closing, children]);
 impl_struct!(OptChainExpr, [span, optional, base]);
+impl_struct!(PipelineExpr, [span, head, body]);
+impl_struct!(TopicRef, [span]);
 
 impl_struct!(ParenExpr, [span, expr]);
 impl_struct!(
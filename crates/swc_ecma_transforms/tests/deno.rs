@@ -42,6 +42,8 @@ fn run_test(input: PathBuf) {
                         import_export_assign_config:
                             typescript::TsImportExportAssignConfig::Preserve,
                         ts_enum_is_mutable: true,
+                        rewrite_relative_import_extensions: false,
+                        imported_enums: Default::default(),
                     },
                     unresolved_mark,
                     top_level_mark,
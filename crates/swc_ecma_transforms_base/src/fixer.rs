@@ -81,7 +81,8 @@ impl Fixer<'_> {
             | Expr::Unary(..)
             | Expr::Object(..)
             | Expr::Await(..)
-            | Expr::Yield(..) => self.wrap(e),
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(e),
             _ => (),
         }
     }
@@ -213,9 +214,11 @@ impl VisitMut for Fixer<'_> {
         self.ctx = old;
 
         match &*expr.arg {
-            Expr::Cond(..) | Expr::Assign(..) | Expr::Bin(..) | Expr::Yield(..) => {
-                self.wrap(&mut expr.arg)
-            }
+            Expr::Cond(..)
+            | Expr::Assign(..)
+            | Expr::Bin(..)
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(&mut expr.arg),
             _ => {}
         }
     }
@@ -267,7 +270,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Seq(..)
             | Expr::Yield(..)
             | Expr::Cond(..)
-            | Expr::Arrow(..) => {
+            | Expr::Arrow(..)
+            | Expr::Pipeline(..) => {
                 self.wrap(&mut expr.right);
             }
             Expr::Bin(BinExpr { op: op_of_rhs, .. }) => {
@@ -318,7 +322,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Yield(..)
             | Expr::Cond(..)
             | Expr::Assign(..)
-            | Expr::Arrow(..) => {
+            | Expr::Arrow(..)
+            | Expr::Pipeline(..) => {
                 self.wrap(&mut expr.left);
             }
             Expr::Object(..)
@@ -449,6 +454,7 @@ impl VisitMut for Fixer<'_> {
                 | Expr::Assign(_)
                 | Expr::Seq(_)
                 | Expr::Cond(_)
+                | Expr::Pipeline(_)
                 | Expr::TaggedTpl(_)
                 | Expr::Update(UpdateExpr { prefix: false, .. }) => {}
                 _ => self.ctx = Context::FreeExpr,
@@ -580,6 +586,7 @@ impl VisitMut for Fixer<'_> {
             | Expr::Class(..)
             | Expr::Yield(..)
             | Expr::Await(..)
+            | Expr::Pipeline(..)
             | Expr::New(NewExpr { args: None, .. }) => {
                 self.wrap(&mut n.obj);
             }
@@ -621,6 +628,7 @@ impl VisitMut for Fixer<'_> {
             | Expr::Assign(..)
             | Expr::Seq(..)
             | Expr::Unary(..)
+            | Expr::Pipeline(..)
             | Expr::Lit(..) => self.wrap(&mut node.callee),
             _ => {}
         }
@@ -660,6 +668,34 @@ impl VisitMut for Fixer<'_> {
         self.ctx = old;
     }
 
+    fn visit_mut_pipeline_expr(&mut self, expr: &mut PipelineExpr) {
+        expr.head.visit_mut_with(self);
+
+        let ctx = self.ctx;
+        self.ctx = Context::FreeExpr;
+        expr.body.visit_mut_with(self);
+        self.ctx = ctx;
+
+        match &*expr.head {
+            Expr::Seq(..)
+            | Expr::Assign(..)
+            | Expr::Cond(..)
+            | Expr::Arrow(..)
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(&mut expr.head),
+            _ => {}
+        }
+
+        match &*expr.body {
+            Expr::Seq(..)
+            | Expr::Assign(..)
+            | Expr::Cond(..)
+            | Expr::Arrow(..)
+            | Expr::Yield(..) => self.wrap(&mut expr.body),
+            _ => {}
+        }
+    }
+
     fn visit_mut_prop_name(&mut self, name: &mut PropName) {
         name.visit_mut_children_with(self);
 
@@ -729,7 +765,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Seq(..)
             | Expr::Fn(..)
             | Expr::Assign(..)
-            | Expr::Unary(..) => {
+            | Expr::Unary(..)
+            | Expr::Pipeline(..) => {
                 self.wrap(&mut e.tag);
             }
             _ => {}
@@ -761,7 +798,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Seq(..)
             | Expr::Cond(..)
             | Expr::Arrow(..)
-            | Expr::Yield(..) => self.wrap(&mut n.arg),
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(&mut n.arg),
 
             _ => {}
         }
@@ -944,7 +982,8 @@ impl Fixer<'_> {
                     | Expr::Assign(..)
                     | Expr::Cond(..)
                     | Expr::Arrow(..)
-                    | Expr::Yield(..) => self.wrap(&mut expr.test),
+                    | Expr::Yield(..)
+                    | Expr::Pipeline(..) => self.wrap(&mut expr.test),
 
                     Expr::Object(..) | Expr::Fn(..) | Expr::Class(..) => {
                         if self.ctx == Context::Default {
@@ -1805,4 +1844,36 @@ var store = global[SHARED] || (global[SHARED] = {});
     identical!(issue_5417, "console.log(a ?? b ?? c)");
 
     identical!(bin_and_unary, "console.log(a++ && b--)");
+
+    fn run_pipeline_test(src: &str) {
+        crate::tests::test_transform(
+            swc_ecma_parser::Syntax::Es(swc_ecma_parser::EsSyntax {
+                pipeline_operator: true,
+                ..Default::default()
+            }),
+            |_| noop_pass(),
+            src,
+            src,
+            true,
+            Default::default,
+        );
+    }
+
+    #[test]
+    fn pipeline_in_bin() {
+        run_pipeline_test("(a |> f(%)) + 1;");
+    }
+
+    #[test]
+    fn pipeline_head() {
+        run_pipeline_test("(a |> f(%)) |> g(%);");
+        run_pipeline_test("(a ? b : c) |> f(%);");
+    }
+
+    #[test]
+    fn pipeline_body() {
+        run_pipeline_test("a |> (%, b);");
+        run_pipeline_test("a |> (() => %);");
+        run_pipeline_test("a |> f(%) |> g(%);");
+    }
 }
@@ -313,6 +313,7 @@ define_helpers!(Helpers {
     extends: (),
     get: (super_prop_base),
     get_prototype_of: (),
+    import_defer_proxy: (),
     inherits: (set_prototype_of),
     inherits_loose: (),
     initializer_define_property: (),
@@ -410,6 +411,7 @@ define_helpers!(Helpers {
     ts_generator: (),
     ts_metadata: (),
     ts_param: (),
+    ts_rewrite_relative_import_extension: (),
     ts_values: (),
     ts_add_disposable_resource: (),
     ts_dispose_resources: (),
@@ -17,13 +17,15 @@ use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith
 
 pub use super::util::Config as InnerConfig;
 use crate::{
-    module_decl_strip::{Export, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip},
+    module_decl_strip::{
+        DeferredImport, Export, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip,
+    },
     module_ref_rewriter::{rewrite_import_bindings, ImportMap},
     path::Resolver,
     top_level_this::top_level_this,
     util::{
-        define_es_module, emit_export_stmts, local_name_for_src, use_strict, ImportInterop,
-        VecStmtLike,
+        define_es_module, emit_export_stmts, local_name_for_src, use_strict,
+        warn_dynamic_import_phase, ImportInterop, VecStmtLike,
     },
     SpanCtx,
 };
@@ -141,6 +143,7 @@ where
             link,
             export,
             export_assign,
+            deferred,
             has_module_decl,
             ..
         } = strip;
@@ -155,6 +158,17 @@ where
 
         stmts.extend(self.handle_import_export(&mut import_map, link, export, is_export_assign));
 
+        // `import defer * as ns from "mod"` -> `const ns = _import_defer_proxy(() =>
+        // _interop_require_wildcard(_mod))`
+        //
+        // AMD loaders evaluate the dependencies before the factory, so only the
+        // namespace object is created lazily.
+        stmts.extend(
+            deferred
+                .into_iter()
+                .map(|import| self.handle_deferred_import(import)),
+        );
+
         stmts.extend(n.body.take().into_iter().filter_map(|item| match item {
             ModuleItem::Stmt(stmt) if !stmt.is_empty() => Some(stmt),
             _ => None,
@@ -284,6 +298,14 @@ where
                     self.support_arrow,
                 );
             }
+            Expr::Call(CallExpr {
+                callee: Callee::Import(Import { span, phase }),
+                ..
+            }) if !self.config.ignore_dynamic => {
+                warn_dynamic_import_phase(*span, *phase, "AMD");
+
+                n.visit_mut_children_with(self);
+            }
             Expr::Member(MemberExpr { span, obj, prop })
                 if !self.config.preserve_import_meta
                     && obj
@@ -466,6 +488,40 @@ where
         export_stmts.into_iter().chain(stmts)
     }
 
+    fn handle_deferred_import(&mut self, import: DeferredImport) -> Stmt {
+        let DeferredImport {
+            src,
+            src_span,
+            local,
+        } = import;
+
+        let mod_ident = private_ident!(local_name_for_src(&src));
+
+        self.dep_list
+            .push((mod_ident.clone(), src, (src_span, SyntaxContext::empty())));
+
+        let import_expr: Expr = mod_ident.into();
+
+        let import_expr = match self.config.import_interop() {
+            ImportInterop::None => import_expr,
+            ImportInterop::Swc => {
+                helper_expr!(interop_require_wildcard).as_call(PURE_SP, vec![import_expr.as_arg()])
+            }
+            ImportInterop::Node => helper_expr!(interop_require_wildcard)
+                .as_call(PURE_SP, vec![import_expr.as_arg(), true.as_arg()]),
+        };
+
+        helper_expr!(import_defer_proxy)
+            .as_call(
+                PURE_SP,
+                vec![import_expr
+                    .into_lazy_auto(Default::default(), self.support_arrow)
+                    .as_arg()],
+            )
+            .into_var_decl(self.const_var_kind, local.into())
+            .into()
+    }
+
     fn module(&mut self) -> Ident {
         self.module
             .get_or_insert_with(|| private_ident!("module"))
@@ -12,14 +12,15 @@ use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith
 pub use super::util::Config;
 use crate::{
     module_decl_strip::{
-        Export, ExportKV, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip,
+        DeferredImport, Export, ExportKV, Link, LinkFlag, LinkItem, LinkSpecifierReducer,
+        ModuleDeclStrip,
     },
     module_ref_rewriter::{rewrite_import_bindings, ImportMap},
     path::Resolver,
     top_level_this::top_level_this,
     util::{
         define_es_module, emit_export_stmts, local_name_for_src, prop_name, use_strict,
-        ImportInterop, VecStmtLike,
+        warn_dynamic_import_phase, ImportInterop, VecStmtLike,
     },
 };
 
@@ -104,6 +105,7 @@ impl VisitMut for Cjs {
             link,
             export,
             export_assign,
+            deferred,
             has_module_decl,
             ..
         } = strip;
@@ -131,6 +133,14 @@ impl VisitMut for Cjs {
             .map(From::from),
         );
 
+        // `import defer * as ns from "mod"` -> `const ns = _import_defer_proxy(() =>
+        // require("mod"))`
+        stmts.extend(
+            deferred
+                .into_iter()
+                .map(|import| self.handle_deferred_import(import).into()),
+        );
+
         stmts.extend(n.body.take().into_iter().filter(|item| match item {
             ModuleItem::Stmt(stmt) => !stmt.is_empty(),
             _ => false,
@@ -205,6 +215,14 @@ impl VisitMut for Cjs {
                     is_lit_path,
                 );
             }
+            Expr::Call(CallExpr {
+                callee: Callee::Import(Import { span, phase }),
+                ..
+            }) if !self.config.ignore_dynamic => {
+                warn_dynamic_import_phase(*span, *phase, "CommonJS");
+
+                n.visit_mut_children_with(self);
+            }
             Expr::Member(MemberExpr { span, obj, prop })
                 if !self.config.preserve_import_meta
                     && obj
@@ -380,6 +398,37 @@ impl Cjs {
         export_stmts.into_iter().chain(stmts)
     }
 
+    fn handle_deferred_import(&self, import: DeferredImport) -> Stmt {
+        let DeferredImport {
+            src,
+            src_span,
+            local,
+        } = import;
+
+        let import_expr = self
+            .resolver
+            .make_require_call(self.unresolved_mark, src, src_span);
+
+        let import_expr = match self.config.import_interop() {
+            ImportInterop::None => import_expr,
+            ImportInterop::Swc => {
+                helper_expr!(interop_require_wildcard).as_call(PURE_SP, vec![import_expr.as_arg()])
+            }
+            ImportInterop::Node => helper_expr!(interop_require_wildcard)
+                .as_call(PURE_SP, vec![import_expr.as_arg(), true.as_arg()]),
+        };
+
+        helper_expr!(import_defer_proxy)
+            .as_call(
+                PURE_SP,
+                vec![import_expr
+                    .into_lazy_auto(Default::default(), self.support_arrow)
+                    .as_arg()],
+            )
+            .into_var_decl(self.const_var_kind, local.into())
+            .into()
+    }
+
     fn handle_ts_import_equals(
         &self,
         module_decl: ModuleDecl,
@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHashSet};
 use swc_atoms::Atom;
-use swc_common::{util::take::Take, Mark, Span, SyntaxContext};
+use swc_common::{errors::HANDLER, util::take::Take, Mark, Span, SyntaxContext};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{find_pat_ids, private_ident, quote_ident, ExprFactory};
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
@@ -27,6 +27,9 @@ pub struct ModuleDeclStrip {
     /// `export = ` detected
     pub export_assign: Option<Box<Expr>>,
 
+    /// `import defer * as ns from "mod"` in source text order
+    pub deferred: Vec<DeferredImport>,
+
     pub has_module_decl: bool,
 
     /// `export default expr`
@@ -41,6 +44,7 @@ impl ModuleDeclStrip {
             link: Default::default(),
             export: Default::default(),
             export_assign: Default::default(),
+            deferred: Default::default(),
             has_module_decl: Default::default(),
             export_default: Default::default(),
             const_var_kind,
@@ -102,9 +106,50 @@ impl VisitMut for ModuleDeclStrip {
         }
 
         let ImportDecl {
-            specifiers, src, ..
+            span,
+            specifiers,
+            src,
+            phase,
+            ..
         } = n.take();
 
+        match phase {
+            ImportPhase::Evaluation => {}
+            ImportPhase::Defer => {
+                if let [ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })] =
+                    &*specifiers
+                {
+                    self.deferred.push(DeferredImport {
+                        src: src.value,
+                        src_span: src.span,
+                        local: local.clone(),
+                    });
+                    return;
+                }
+
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            span,
+                            "`import defer` can only be used with a namespace import, like \
+                             `import defer * as ns from \"mod\"`",
+                        )
+                        .emit()
+                });
+            }
+            ImportPhase::Source => {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            span,
+                            "`import source` can't be transformed to CommonJS, AMD or UMD modules",
+                        )
+                        .emit()
+                });
+                return;
+            }
+        }
+
         self.link
             .entry(src.value)
             .or_default()
@@ -336,6 +381,16 @@ impl VisitMut for ModuleDeclStrip {
     }
 }
 
+/// ```javascript
+/// import defer * as ns from "mod";
+/// ```
+#[derive(Debug)]
+pub struct DeferredImport {
+    pub src: Atom,
+    pub src_span: Span,
+    pub local: Ident,
+}
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum LinkSpecifier {
     ///```javascript
@@ -84,9 +84,10 @@ impl ImportResolver for NoopImportResolver {
 
 /// [ImportResolver] implementation for node.js
 ///
-/// Supports [FileName::Real] and [FileName::Anon] for `base`, [FileName::Real]
-/// and [FileName::Custom] for `target`. ([FileName::Custom] is used for core
-/// modules)
+/// Supports [FileName::Real] and [FileName::Anon] for `base`, [FileName::Real],
+/// [FileName::Custom] and [FileName::Url] for `target`. ([FileName::Custom] is
+/// used for core modules, and [FileName::Url] is used for modules mapped to a
+/// URL by an import map)
 #[derive(Debug, Clone, Default)]
 pub struct NodeImportResolver<R>
 where
@@ -274,6 +275,7 @@ where
         let mut target = match target {
             FileName::Real(v) => v,
             FileName::Custom(s) => return Ok(self.to_specifier(s.into(), slug)),
+            FileName::Url(url) => return Ok(url.as_str().into()),
             _ => {
                 unreachable!(
                     "Node path provider does not support using `{:?}` as a target file name",
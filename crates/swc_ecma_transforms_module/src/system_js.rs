@@ -2,7 +2,7 @@ use anyhow::Context;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use swc_atoms::Atom;
-use swc_common::{Mark, Span, SyntaxContext, DUMMY_SP};
+use swc_common::{errors::HANDLER, Mark, Span, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{
     member_expr, private_ident, quote_ident, quote_str, var::VarCollector, ExprFactory,
@@ -637,6 +637,34 @@ impl Fold for SystemJs {
             match item {
                 ModuleItem::ModuleDecl(decl) => match decl {
                     ModuleDecl::Import(import) => {
+                        match import.phase {
+                            ImportPhase::Evaluation => {}
+                            ImportPhase::Defer => {
+                                HANDLER.with(|handler| {
+                                    handler
+                                        .struct_span_err(
+                                            import.src.span,
+                                            "`import defer` can't be transformed to SystemJS \
+                                             modules",
+                                        )
+                                        .emit()
+                                });
+                                continue;
+                            }
+                            ImportPhase::Source => {
+                                HANDLER.with(|handler| {
+                                    handler
+                                        .struct_span_err(
+                                            import.span,
+                                            "`import source` can't be transformed to SystemJS \
+                                             modules",
+                                        )
+                                        .emit()
+                                });
+                                continue;
+                            }
+                        }
+
                         let src = match &self.resolver {
                             Resolver::Real { resolver, base } => resolver
                                 .resolve_import(base, &import.src.value)
@@ -7,14 +7,17 @@ use swc_common::{
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper_expr;
 use swc_ecma_utils::{
-    is_valid_prop_ident, private_ident, quote_ident, quote_str, ExprFactory, IsDirective,
+    is_valid_prop_ident, private_ident, quote_ident, quote_str, ExprFactory, FunctionFactory,
+    IsDirective,
 };
 use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
 
 use self::config::BuiltConfig;
 pub use self::config::Config;
 use crate::{
-    module_decl_strip::{Export, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip},
+    module_decl_strip::{
+        DeferredImport, Export, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip,
+    },
     module_ref_rewriter::{rewrite_import_bindings, ImportMap},
     path::Resolver,
     top_level_this::top_level_this,
@@ -105,6 +108,7 @@ impl VisitMut for Umd {
             link,
             export,
             export_assign,
+            deferred,
             has_module_decl,
             ..
         } = strip;
@@ -119,6 +123,17 @@ impl VisitMut for Umd {
 
         stmts.extend(self.handle_import_export(&mut import_map, link, export, is_export_assign));
 
+        // `import defer * as ns from "mod"` -> `const ns = _import_defer_proxy(() =>
+        // _interop_require_wildcard(_mod))`
+        //
+        // UMD loaders evaluate the dependencies before the factory, so only the
+        // namespace object is created lazily.
+        stmts.extend(
+            deferred
+                .into_iter()
+                .map(|import| self.handle_deferred_import(import)),
+        );
+
         stmts.extend(module_items.take().into_iter().filter_map(|i| match i {
             ModuleItem::Stmt(stmt) if !stmt.is_empty() => Some(stmt),
             _ => None,
@@ -271,6 +286,41 @@ impl Umd {
         export_stmts.into_iter().chain(stmts)
     }
 
+    fn handle_deferred_import(&mut self, import: DeferredImport) -> Stmt {
+        let DeferredImport {
+            src,
+            src_span,
+            local,
+        } = import;
+
+        let mod_ident = private_ident!(local_name_for_src(&src));
+
+        self.dep_list
+            .push((mod_ident.clone(), src, (src_span, SyntaxContext::empty())));
+
+        let import_expr: Expr = mod_ident.into();
+
+        let import_expr = match self.config.config.import_interop() {
+            ImportInterop::None => import_expr,
+            ImportInterop::Swc => {
+                helper_expr!(interop_require_wildcard).as_call(PURE_SP, vec![import_expr.as_arg()])
+            }
+            ImportInterop::Node => helper_expr!(interop_require_wildcard)
+                .as_call(PURE_SP, vec![import_expr.as_arg(), true.as_arg()]),
+        };
+
+        helper_expr!(import_defer_proxy)
+            .as_call(
+                PURE_SP,
+                vec![import_expr
+                    .into_lazy_fn(Default::default())
+                    .into_fn_expr(None)
+                    .as_arg()],
+            )
+            .into_var_decl(self.const_var_kind, local.into())
+            .into()
+    }
+
     fn exports(&mut self) -> Ident {
         self.exports
             .get_or_insert_with(|| private_ident!("exports"))
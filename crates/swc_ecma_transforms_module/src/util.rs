@@ -1,7 +1,7 @@
 use is_macro::Is;
 use serde::{Deserialize, Serialize};
 use swc_atoms::Atom;
-use swc_common::DUMMY_SP;
+use swc_common::{errors::HANDLER, Span, DUMMY_SP};
 use swc_config::file_pattern::FilePattern;
 use swc_ecma_ast::*;
 use swc_ecma_utils::{
@@ -168,6 +168,26 @@ pub(super) fn local_name_for_src(src: &Atom) -> Atom {
     }
 }
 
+/// Warns about `import.defer()` and `import.source()`, which are left as is.
+pub(super) fn warn_dynamic_import_phase(span: Span, phase: ImportPhase, module_format: &str) {
+    let callee = match phase {
+        ImportPhase::Evaluation => return,
+        ImportPhase::Source => "import.source()",
+        ImportPhase::Defer => "import.defer()",
+    };
+
+    HANDLER.with(|handler| {
+        handler
+            .struct_span_warn(
+                span,
+                &format!(
+                    "`{callee}` is not supported by {module_format} modules and is left as is"
+                ),
+            )
+            .emit()
+    });
+}
+
 /// Creates
 ///
 ///```js
@@ -0,0 +1,6 @@
+import defer * as ns from "./dep.js";
+import { eager } from "./eager.js";
+
+export function run() {
+    return eager(ns.value);
+}
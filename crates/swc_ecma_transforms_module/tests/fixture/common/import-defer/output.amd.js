@@ -0,0 +1,21 @@
+define([
+    "require",
+    "exports",
+    "./eager.js",
+    "./dep.js"
+], function(require, exports, _eager, _dep) {
+    "use strict";
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+    Object.defineProperty(exports, "run", {
+        enumerable: true,
+        get: function() {
+            return run;
+        }
+    });
+    const ns = /*#__PURE__*/ _import_defer_proxy(()=>/*#__PURE__*/ _interop_require_wildcard(_dep));
+    function run() {
+        return (0, _eager.eager)(ns.value);
+    }
+});
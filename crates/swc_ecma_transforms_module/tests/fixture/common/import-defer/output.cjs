@@ -0,0 +1,15 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "run", {
+    enumerable: true,
+    get: function() {
+        return run;
+    }
+});
+const _eager = require("./eager.js");
+const ns = /*#__PURE__*/ _import_defer_proxy(()=>/*#__PURE__*/ _interop_require_wildcard(require("./dep.js")));
+function run() {
+    return (0, _eager.eager)(ns.value);
+}
@@ -0,0 +1,26 @@
+(function(global, factory) {
+    if (typeof module === "object" && typeof module.exports === "object") factory(exports, require("./eager.js"), require("./dep.js"));
+    else if (typeof define === "function" && define.amd) define([
+        "exports",
+        "./eager.js",
+        "./dep.js"
+    ], factory);
+    else if (global = typeof globalThis !== "undefined" ? globalThis : global || self) factory(global.input = {}, global.eagerJs, global.depJs);
+})(this, function(exports, _eager, _dep) {
+    "use strict";
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+    Object.defineProperty(exports, "run", {
+        enumerable: true,
+        get: function() {
+            return run;
+        }
+    });
+    const ns = /*#__PURE__*/ _import_defer_proxy(function() {
+        return /*#__PURE__*/ _interop_require_wildcard(_dep);
+    });
+    function run() {
+        return (0, _eager.eager)(ns.value);
+    }
+});
@@ -0,0 +1,17 @@
+use swc_ecma_ast::Pass;
+
+use crate::{decorator_impl::decorator_impl, DecoratorVersion};
+
+/// Decorators of the `2023-11` proposal, including decorator metadata.
+///
+/// Each decorated class gets a metadata object, which is passed to decorators
+/// as `context.metadata` and assigned to `Class[Symbol.metadata]`. The
+/// prototype of the metadata object is the metadata of the parent class.
+/// `Symbol.for("Symbol.metadata")` is used if `Symbol.metadata` is not
+/// available.
+///
+/// Decorators are applied by the runtime helper of `2022-03`, so the other
+/// additions of `2023-11`, like `context.access.has`, aren't supported yet.
+pub fn decorator_2023_11() -> impl Pass {
+    decorator_impl(DecoratorVersion::V202311)
+}
@@ -37,7 +37,6 @@ struct DecoratorPass {
 
     extra_exports: Vec<ExportSpecifier>,
 
-    #[allow(unused)]
     version: DecoratorVersion,
 }
 
@@ -858,8 +857,23 @@ impl VisitMut for DecoratorPass {
             }
         }
 
+        // In `2023-11`, metadata of the class inherits from the metadata of the parent
+        // class, so the parent class is required even if only members are decorated.
+        let has_member_decorators =
+            !self.state.init_proto_args.is_empty() || !self.state.init_static_args.is_empty();
+        let is_super_class_captured = matches!(self.version, DecoratorVersion::V202311)
+            && has_member_decorators
+            && self.state.super_class.is_none();
+        if is_super_class_captured {
+            self.handle_super_class(n);
+        }
+
         self.consume_inits();
 
+        if is_super_class_captured {
+            self.state.super_class = None;
+        }
+
         if !self.state.extra_stmts.is_empty() {
             n.body.insert(
                 0,
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 pub use self::{
     decorators::decorators, export_default_from::export_default_from,
-    import_attributes::import_attributes,
+    import_attributes::import_attributes, pipeline_operator::pipeline_operator,
 };
 
 #[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
@@ -23,8 +23,10 @@ pub enum DecoratorVersion {
 }
 
 pub mod decorator_2022_03;
+pub mod decorator_2023_11;
 mod decorator_impl;
 pub mod decorators;
 pub mod explicit_resource_management;
 mod export_default_from;
 mod import_attributes;
+mod pipeline_operator;
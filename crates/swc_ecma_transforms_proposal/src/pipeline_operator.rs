@@ -0,0 +1,239 @@
+use std::mem;
+
+use swc_common::{util::take::Take, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{
+    prepend_stmt, private_ident, stack_size::maybe_grow_default, ExprFactory, StmtLike,
+};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, visit_mut_pass, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// `@babel/plugin-proposal-pipeline-operator` with `{ proposal: "hack",
+/// topicToken: "%" }`
+///
+/// `value |> f(%)` is compiled to `(_ref = value, f(_ref))`, so `value` is
+/// evaluated exactly once and before the body.
+///
+/// If the topic reference is captured by an arrow function, each evaluation
+/// of the pipeline needs its own binding, so the body is wrapped in an arrow
+/// function instead, e.g. `value |> (() => %)` is compiled to `(_ref => () =>
+/// _ref)(value)`.
+pub fn pipeline_operator() -> impl Pass {
+    visit_mut_pass(PipelineOperator::default())
+}
+
+#[derive(Default)]
+struct PipelineOperator {
+    vars: Vec<VarDeclarator>,
+}
+
+impl PipelineOperator {
+    fn visit_mut_stmt_likes<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: StmtLike,
+        Vec<T>: VisitMutWith<Self>,
+    {
+        let old_vars = self.vars.take();
+
+        stmts.visit_mut_children_with(self);
+
+        let vars = mem::replace(&mut self.vars, old_vars);
+        if !vars.is_empty() {
+            prepend_stmt(stmts, T::from(var_decl(vars)));
+        }
+    }
+}
+
+impl VisitMut for PipelineOperator {
+    noop_visit_mut_type!();
+
+    /// Temporary variables of an expression body are declared in the arrow
+    /// function itself, so recursive calls don't share them.
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        n.params.visit_mut_with(self);
+
+        let BlockStmtOrExpr::Expr(expr) = &mut *n.body else {
+            n.body.visit_mut_with(self);
+            return;
+        };
+
+        let old_vars = self.vars.take();
+        expr.visit_mut_with(self);
+        let vars = mem::replace(&mut self.vars, old_vars);
+
+        if !vars.is_empty() {
+            *n.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                stmts: vec![
+                    var_decl(vars),
+                    ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(expr.take()),
+                    }
+                    .into(),
+                ],
+                ..Default::default()
+            });
+        }
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if !e.is_pipeline() {
+            maybe_grow_default(|| e.visit_mut_children_with(self));
+            return;
+        }
+
+        // Declares the variable of the outer pipeline first
+        let index = self.vars.len();
+        maybe_grow_default(|| e.visit_mut_children_with(self));
+
+        let Expr::Pipeline(PipelineExpr {
+            span,
+            head,
+            mut body,
+        }) = e.take()
+        else {
+            unreachable!()
+        };
+
+        // Nested pipelines are already lowered, so every remaining `%` in the
+        // body belongs to this pipeline.
+        let topic = private_ident!("_ref");
+        let mut replacer = TopicReplacer {
+            topic: &topic,
+            in_closure: false,
+            is_captured: false,
+        };
+        body.visit_mut_with(&mut replacer);
+
+        if replacer.is_captured && !contains_yield_or_await(&body) {
+            *e = CallExpr {
+                span,
+                callee: ArrowExpr {
+                    params: vec![topic.into()],
+                    body: Box::new(BlockStmtOrExpr::Expr(body)),
+                    ..Default::default()
+                }
+                .as_callee(),
+                args: vec![head.as_arg()],
+                ..Default::default()
+            }
+            .into();
+            return;
+        }
+
+        self.vars.insert(
+            index,
+            VarDeclarator {
+                span: DUMMY_SP,
+                name: topic.clone().into(),
+                init: None,
+                definite: false,
+            },
+        );
+
+        let mut exprs = vec![AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: topic.into(),
+            right: head,
+        }
+        .into()];
+        match *body {
+            Expr::Seq(seq) => exprs.extend(seq.exprs),
+            body => exprs.push(Box::new(body)),
+        }
+
+        *e = SeqExpr { span, exprs }.into();
+    }
+
+    fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_likes(n);
+    }
+
+    fn visit_mut_stmts(&mut self, n: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_likes(n);
+    }
+}
+
+fn var_decl(decls: Vec<VarDeclarator>) -> Stmt {
+    VarDecl {
+        kind: VarDeclKind::Var,
+        decls,
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Replaces `%` with the temporary variable of a pipeline.
+struct TopicReplacer<'a> {
+    topic: &'a Ident,
+    in_closure: bool,
+    /// Whether `%` is used inside an arrow function or a class.
+    is_captured: bool,
+}
+
+impl TopicReplacer<'_> {
+    fn visit_mut_closure<N>(&mut self, n: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        let old = mem::replace(&mut self.in_closure, true);
+        n.visit_mut_children_with(self);
+        self.in_closure = old;
+    }
+}
+
+impl VisitMut for TopicReplacer<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        self.visit_mut_closure(n);
+    }
+
+    fn visit_mut_class(&mut self, n: &mut Class) {
+        self.visit_mut_closure(n);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Expr::TopicRef(TopicRef { span }) = e {
+            *e = Ident {
+                span: *span,
+                ..self.topic.clone()
+            }
+            .into();
+            self.is_captured |= self.in_closure;
+            return;
+        }
+
+        maybe_grow_default(|| e.visit_mut_children_with(self));
+    }
+}
+
+/// Returns true if `yield` or `await` of the current function is used in
+/// `expr`, which can't be moved into an arrow function.
+fn contains_yield_or_await(expr: &Expr) -> bool {
+    let mut finder = YieldOrAwaitFinder { found: false };
+    expr.visit_with(&mut finder);
+    finder.found
+}
+
+struct YieldOrAwaitFinder {
+    found: bool,
+}
+
+impl Visit for YieldOrAwaitFinder {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_yield_expr(&mut self, _: &YieldExpr) {
+        self.found = true;
+    }
+}
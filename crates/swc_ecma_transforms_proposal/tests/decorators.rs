@@ -10,7 +10,9 @@ use swc_common::{comments::SingleThreadedComments, Mark};
 use swc_ecma_ast::Pass;
 use swc_ecma_parser::{EsSyntax, Syntax, TsSyntax};
 use swc_ecma_transforms_base::{assumptions::Assumptions, resolver};
-use swc_ecma_transforms_proposal::{decorator_2022_03::decorator_2022_03, DecoratorVersion};
+use swc_ecma_transforms_proposal::{
+    decorator_2022_03::decorator_2022_03, decorator_2023_11::decorator_2023_11, DecoratorVersion,
+};
 use swc_ecma_transforms_testing::{test_fixture, FixtureTestConfig};
 use swc_ecma_visit::Fold;
 
@@ -159,7 +161,7 @@ fn create_pass(comments: Rc<SingleThreadedComments>, input: &Path) -> Box<dyn Pa
                 "proposal-decorators" => match config {
                     BabelPluginOption::Decorator { version } => match version {
                         DecoratorVersion::V202311 => {
-                            todo!()
+                            add!(decorator_2023_11());
                         }
                         DecoratorVersion::V202112 => todo!(),
                         DecoratorVersion::V202203 => {
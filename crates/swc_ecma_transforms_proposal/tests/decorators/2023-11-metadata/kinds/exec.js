@@ -0,0 +1,34 @@
+const contexts = [];
+
+function dec(_, ctx) {
+  contexts.push(ctx);
+  ctx.metadata[ctx.name] = ctx.kind;
+}
+
+Symbol.metadata = Symbol();
+
+@dec
+class A {
+  @dec field;
+  @dec accessor acc;
+  @dec method() {}
+  @dec get getter() {}
+  @dec set setter(v) {}
+  @dec #privateMethod() {}
+  @dec static staticField;
+}
+
+const metadata = A[Symbol.metadata];
+
+expect(contexts.length).toBe(8);
+expect(contexts.every((ctx) => ctx.metadata === metadata)).toBe(true);
+expect(metadata).toEqual({
+  A: "class",
+  field: "field",
+  acc: "accessor",
+  method: "method",
+  getter: "getter",
+  setter: "setter",
+  "#privateMethod": "method",
+  staticField: "field",
+});
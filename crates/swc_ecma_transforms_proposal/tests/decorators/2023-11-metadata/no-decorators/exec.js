@@ -0,0 +1,5 @@
+Symbol.metadata = Symbol();
+
+class A {}
+
+expect(A.hasOwnProperty(Symbol.metadata)).toBe(false);
@@ -0,0 +1,10 @@
+function dec(_, ctx) {
+  ctx.metadata.foo = 1;
+}
+
+delete Symbol.metadata;
+
+@dec
+class A {}
+
+expect(A[Symbol.for("Symbol.metadata")]).toEqual({ foo: 1 });
@@ -0,0 +1,4 @@
+class A extends B {
+  @dec
+  foo() {}
+}
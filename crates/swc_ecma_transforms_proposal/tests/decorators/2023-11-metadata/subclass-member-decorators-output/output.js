@@ -0,0 +1,14 @@
+var _B, _initProto;
+class A extends (_B = B) {
+    foo() {}
+    constructor(...args){
+        super(...args), _initProto(this);
+    }
+}
+({ e: [_initProto] } = _apply_decs_2203_r(A, [
+    [
+        dec,
+        2,
+        "foo"
+    ]
+], [], _B));
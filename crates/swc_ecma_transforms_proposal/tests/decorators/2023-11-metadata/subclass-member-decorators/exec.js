@@ -0,0 +1,22 @@
+function dec(v) {
+  return (_, ctx) => {
+    ctx.metadata[ctx.name] = v;
+  };
+}
+
+Symbol.metadata = Symbol();
+
+class B {
+  @dec(1)
+  foo;
+}
+
+class A extends B {
+  @dec(2)
+  bar() {}
+}
+
+expect(A[Symbol.metadata]).toEqual({ bar: 2 });
+expect(Object.getPrototypeOf(A[Symbol.metadata])).toBe(B[Symbol.metadata]);
+expect(A[Symbol.metadata].foo).toBe(1);
+expect(B[Symbol.metadata]).toEqual({ foo: 1 });
@@ -0,0 +1,10 @@
+const fns = [];
+for (const x of xs) {
+    fns.push(x |> (() => %));
+}
+
+class A {
+    static b = value |> class {
+        c = %;
+    };
+}
@@ -0,0 +1,9 @@
+const fns = [];
+for (const x of xs){
+    fns.push(((_ref)=>()=>_ref)(x));
+}
+class A {
+    static b = ((_ref)=>class {
+            c = _ref;
+        })(value);
+}
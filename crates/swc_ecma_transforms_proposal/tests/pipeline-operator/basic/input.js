@@ -0,0 +1,5 @@
+const result = value |> double(%) |> % + 1;
+
+function f(x) {
+    return x |> %.map((y) => y * 2) |> % / 2;
+}
@@ -0,0 +1,6 @@
+var _ref, _ref1;
+const result = (_ref = value, _ref1 = double(_ref), _ref1 + 1);
+function f(x) {
+    var _ref, _ref1;
+    return _ref = x, _ref1 = _ref.map((y)=>y * 2), _ref1 / 2;
+}
@@ -0,0 +1,6 @@
+const fns = [];
+for (const x of [1, 2, 3]) {
+    fns.push(x |> (() => % * 10));
+}
+
+expect(fns.map((f) => f())).toEqual([10, 20, 30]);
@@ -0,0 +1,6 @@
+const log = [];
+const head = () => (log.push("head"), 2);
+const f = (x) => (log.push("body"), x * 3);
+
+expect(head() |> f(%) |> % + %).toBe(12);
+expect(log).toEqual(["head", "body"]);
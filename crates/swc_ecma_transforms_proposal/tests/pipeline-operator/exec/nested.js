@@ -0,0 +1,4 @@
+const add = (x, y) => x + y;
+
+expect(1 |> (2 |> add(%, %)) + %).toBe(5);
+expect([1, 2, 3] |> %.map((x) => x |> % * %) |> %.join(",")).toBe("1,4,9");
@@ -0,0 +1,3 @@
+const fib = (n) => n < 2 ? n : n |> fib(% - 1) + fib(% - 2);
+
+expect(fib(10)).toBe(55);
@@ -0,0 +1,7 @@
+function* f() {
+    yield value |> ((yield %), () => %);
+}
+
+async function g() {
+    return value |> await fetch(%) |> (() => %.json());
+}
@@ -0,0 +1,8 @@
+function* f() {
+    var _ref;
+    yield (_ref = value, yield _ref, ()=>_ref);
+}
+async function g() {
+    var _ref;
+    return _ref = value, ((_ref)=>()=>_ref.json())(await fetch(_ref));
+}
@@ -0,0 +1,5 @@
+a |> (b |> f(%, %)) + %;
+
+x |> (%, y);
+
+const g = (x = a |> f(%)) => x |> g(%);
@@ -0,0 +1,7 @@
+var _ref, _ref1, _ref2, _ref3;
+_ref = a, (_ref1 = b, f(_ref1, _ref1)) + _ref;
+_ref2 = x, _ref2, y;
+const g = (x1 = (_ref3 = a, f(_ref3)))=>{
+    var _ref;
+    return _ref = x1, g(_ref);
+};
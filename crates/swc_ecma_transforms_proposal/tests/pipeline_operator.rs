@@ -0,0 +1,55 @@
+use std::{fs::read_to_string, path::PathBuf};
+
+use swc_common::Mark;
+use swc_ecma_parser::{EsSyntax, Syntax};
+use swc_ecma_transforms_base::resolver;
+use swc_ecma_transforms_proposal::pipeline_operator;
+use swc_ecma_transforms_testing::{exec_tr, test_fixture, FixtureTestConfig};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsSyntax {
+        pipeline_operator: true,
+        ..Default::default()
+    })
+}
+
+#[testing::fixture("tests/pipeline-operator/exec/*.js")]
+fn exec(input: PathBuf) {
+    exec_tr(
+        "pipeline-operator",
+        syntax(),
+        |_| {
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+
+            (
+                resolver(unresolved_mark, top_level_mark, false),
+                pipeline_operator(),
+            )
+        },
+        &read_to_string(input).unwrap(),
+    );
+}
+
+#[testing::fixture("tests/pipeline-operator/**/input.js")]
+fn fixture(input: PathBuf) {
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        syntax(),
+        &|_| {
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+
+            (
+                resolver(unresolved_mark, top_level_mark, false),
+                pipeline_operator(),
+            )
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            ..Default::default()
+        },
+    );
+}
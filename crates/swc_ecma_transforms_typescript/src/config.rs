@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use swc_common::sync::Lrc;
 
+use crate::ImportedEnums;
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
@@ -34,6 +36,17 @@ pub struct Config {
     /// Defaults to false.
     #[serde(default)]
     pub ts_enum_is_mutable: bool,
+
+    /// https://www.typescriptlang.org/tsconfig/#rewriteRelativeImportExtensions
+    #[serde(default)]
+    pub rewrite_relative_import_extensions: bool,
+
+    /// Enums exported by the dependencies of the module, whose members are
+    /// inlined like the ones of enums declared in the module.
+    ///
+    /// See [EnumRegistry](crate::EnumRegistry).
+    #[serde(skip)]
+    pub imported_enums: ImportedEnums,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -0,0 +1,510 @@
+use std::sync::Arc;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::{atom, Atom};
+use swc_common::{FileName, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_loader::resolve::Resolve;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+use crate::ts_enum::{EnumValueComputer, TsEnumRecord, TsEnumRecordKey, TsEnumRecordValue};
+
+/// Enums exported by the modules of a project.
+///
+/// Modules are compiled one at a time, so [typescript](crate::typescript())
+/// can only inline the members of enums declared in the same module. Adding
+/// every module of a project to a registry allows inlining the members of
+/// imported enums, like `tsc` does without `isolatedModules`.
+///
+/// ```ignore
+/// let mut registry = EnumRegistry::default();
+/// for (file, module) in &modules {
+///     registry.add_module(&resolver, file.clone(), module);
+/// }
+///
+/// let config = typescript::Config {
+///     imported_enums: registry.imported_enums(&file),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct EnumRegistry {
+    modules: FxHashMap<FileName, ModuleEnums>,
+}
+
+#[derive(Debug, Default)]
+struct ModuleEnums {
+    /// Enums declared in the module, by exported name.
+    enums: FxHashMap<Atom, Arc<EnumInfo>>,
+
+    /// Exported name to the module and the name it is exported from.
+    reexports: FxHashMap<Atom, (FileName, Atom)>,
+
+    /// `export * from "..."`
+    star_reexports: Vec<FileName>,
+
+    /// Resolved module specifiers of imports and re-exports.
+    deps: FxHashMap<Atom, FileName>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct EnumInfo {
+    pub is_const: bool,
+
+    /// Members with a constant value.
+    pub members: FxHashMap<Atom, TsEnumRecordValue>,
+}
+
+impl EnumRegistry {
+    /// Collects the enums exported by `module`, which may be a declaration
+    /// file, e.g. one generated by `swc_typescript`.
+    ///
+    /// The specifiers of imports and re-exports are resolved relative to
+    /// `file` using `resolver`. Specifiers which can't be resolved are
+    /// ignored.
+    pub fn add_module(&mut self, resolver: &dyn Resolve, file: FileName, module: &Module) {
+        let mut deps = FxHashMap::default();
+        for item in &module.body {
+            let src = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { src, .. }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll { src, .. }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    src: Some(src),
+                    ..
+                })) => &src.value,
+                _ => continue,
+            };
+
+            if let Ok(resolution) = resolver.resolve(&file, src) {
+                deps.insert(src.clone(), resolution.filename);
+            }
+        }
+
+        let mut record = TsEnumRecord::default();
+        let mut local_enums = FxHashMap::<Atom, EnumInfo>::default();
+        let mut imports = FxHashMap::<Atom, (&FileName, Atom)>::default();
+
+        for item in &module.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.type_only => {
+                    let Some(dep) = deps.get(&import.src.value) else {
+                        continue;
+                    };
+
+                    for specifier in &import.specifiers {
+                        let (local, imported) = match specifier {
+                            ImportSpecifier::Named(named) if !named.is_type_only => (
+                                &named.local,
+                                named
+                                    .imported
+                                    .as_ref()
+                                    .map(|imported| imported.atom().clone())
+                                    .unwrap_or_else(|| named.local.sym.clone()),
+                            ),
+                            ImportSpecifier::Default(default) => (&default.local, atom!("default")),
+                            _ => continue,
+                        };
+
+                        imports.insert(local.sym.clone(), (dep, imported));
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsEnum(decl),
+                    ..
+                }))
+                | ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(decl))) => {
+                    collect_enum(decl, &mut record, &mut local_enums);
+                }
+                _ => {}
+            }
+        }
+
+        let local_enums = local_enums
+            .into_iter()
+            .map(|(name, info)| (name, Arc::new(info)))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut exported = ModuleEnums::default();
+        let export_local = |exported: &mut ModuleEnums, local: &Atom, name: Atom| {
+            if let Some(info) = local_enums.get(local) {
+                exported.enums.insert(name, info.clone());
+            } else if let Some((dep, imported)) = imports.get(local) {
+                exported
+                    .reexports
+                    .insert(name, ((*dep).clone(), imported.clone()));
+            }
+        };
+
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(decl) = item else {
+                continue;
+            };
+
+            match decl {
+                ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsEnum(decl),
+                    ..
+                }) => {
+                    export_local(&mut exported, &decl.id.sym, decl.id.sym.clone());
+                }
+                ModuleDecl::ExportNamed(export) if !export.type_only => {
+                    let src = match &export.src {
+                        Some(src) => match deps.get(&src.value) {
+                            Some(dep) => Some(dep),
+                            None => continue,
+                        },
+                        None => None,
+                    };
+
+                    for specifier in &export.specifiers {
+                        let ExportSpecifier::Named(named) = specifier else {
+                            continue;
+                        };
+                        if named.is_type_only {
+                            continue;
+                        }
+
+                        let orig = named.orig.atom().clone();
+                        let name = named
+                            .exported
+                            .as_ref()
+                            .map(|exported| exported.atom().clone())
+                            .unwrap_or_else(|| orig.clone());
+
+                        match &src {
+                            Some(src) => {
+                                exported
+                                    .reexports
+                                    .insert(name, (FileName::clone(src), orig));
+                            }
+                            None => export_local(&mut exported, &orig, name),
+                        }
+                    }
+                }
+                ModuleDecl::ExportAll(export) if !export.type_only => {
+                    if let Some(dep) = deps.get(&export.src.value) {
+                        exported.star_reexports.push(dep.clone());
+                    }
+                }
+                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) => {
+                    if let Expr::Ident(ident) = &**expr {
+                        export_local(&mut exported, &ident.sym, atom!("default"));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        exported.deps = deps;
+        self.modules.insert(file, exported);
+    }
+
+    /// Returns the enums which `file` can import, to be passed to
+    /// [Config::imported_enums](crate::Config::imported_enums).
+    ///
+    /// `file` should have been added to the registry, as the specifiers of
+    /// its imports are resolved by [EnumRegistry::add_module].
+    pub fn imported_enums(&self, file: &FileName) -> ImportedEnums {
+        let Some(module) = self.modules.get(file) else {
+            return Default::default();
+        };
+
+        let modules = module
+            .deps
+            .iter()
+            .map(|(src, dep)| {
+                let mut enums = FxHashMap::default();
+                self.collect_exports(dep, &mut enums, &mut Default::default());
+
+                (src.clone(), enums)
+            })
+            .filter(|(_, enums)| !enums.is_empty())
+            .collect();
+
+        ImportedEnums { modules }
+    }
+
+    fn collect_exports<'a>(
+        &'a self,
+        file: &'a FileName,
+        enums: &mut FxHashMap<Atom, Arc<EnumInfo>>,
+        visited: &mut FxHashSet<&'a FileName>,
+    ) {
+        if !visited.insert(file) {
+            return;
+        }
+
+        let Some(module) = self.modules.get(file) else {
+            return;
+        };
+
+        for (name, info) in &module.enums {
+            enums.insert(name.clone(), info.clone());
+        }
+        for name in module.reexports.keys() {
+            if let Some(info) = self.find_export(file, name, &mut Default::default()) {
+                enums.insert(name.clone(), info);
+            }
+        }
+
+        for dep in &module.star_reexports {
+            let mut star = FxHashMap::default();
+            self.collect_exports(dep, &mut star, visited);
+
+            for (name, info) in star {
+                if &*name != "default" {
+                    enums.entry(name).or_insert(info);
+                }
+            }
+        }
+    }
+
+    fn find_export<'a>(
+        &'a self,
+        file: &'a FileName,
+        name: &Atom,
+        visited: &mut FxHashSet<(&'a FileName, Atom)>,
+    ) -> Option<Arc<EnumInfo>> {
+        if !visited.insert((file, name.clone())) {
+            return None;
+        }
+
+        let module = self.modules.get(file)?;
+
+        if let Some(info) = module.enums.get(name) {
+            return Some(info.clone());
+        }
+        if let Some((dep, orig)) = module.reexports.get(name) {
+            return self.find_export(dep, orig, visited);
+        }
+        if &**name == "default" {
+            return None;
+        }
+
+        module
+            .star_reexports
+            .iter()
+            .find_map(|dep| self.find_export(dep, name, visited))
+    }
+}
+
+fn collect_enum(
+    decl: &TsEnumDecl,
+    record: &mut TsEnumRecord,
+    local_enums: &mut FxHashMap<Atom, EnumInfo>,
+) {
+    let enum_id = decl.id.to_id();
+    let mut default_init: TsEnumRecordValue = 0.0.into();
+
+    let info = local_enums.entry(decl.id.sym.clone()).or_default();
+    info.is_const |= decl.is_const;
+
+    for member in &decl.members {
+        let value = member
+            .init
+            .clone()
+            .map(|init| {
+                EnumValueComputer {
+                    enum_id: &enum_id,
+                    unresolved_ctxt: SyntaxContext::empty(),
+                    record,
+                }
+                .compute(init)
+            })
+            .filter(TsEnumRecordValue::has_value)
+            .unwrap_or_else(|| default_init.clone());
+
+        default_init = value.inc();
+
+        let member_name = member.id.as_ref().clone();
+        if value.is_const() {
+            info.members.insert(member_name.clone(), value.clone());
+        }
+        record.insert(
+            TsEnumRecordKey {
+                enum_id: enum_id.clone(),
+                member_name,
+            },
+            value,
+        );
+    }
+}
+
+/// The enums exported by the dependencies of a module, by module specifier.
+/// Created by [EnumRegistry::imported_enums].
+#[derive(Debug, Default)]
+pub struct ImportedEnums {
+    modules: FxHashMap<Atom, FxHashMap<Atom, Arc<EnumInfo>>>,
+}
+
+impl ImportedEnums {
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    fn get(&self, src: &Atom, name: &Atom) -> Option<&Arc<EnumInfo>> {
+        self.modules.get(src)?.get(name)
+    }
+}
+
+/// Inlines the members of imported enums and removes the re-exports of
+/// imported const enums.
+///
+/// This runs before the unused imports are removed, so the imports of const
+/// enums are removed if all of their usages are inlined.
+pub(crate) struct InlineImportedEnums<'a> {
+    pub imported_enums: &'a ImportedEnums,
+    pub ts_enum_is_mutable: bool,
+
+    enums: FxHashMap<Id, Arc<EnumInfo>>,
+    namespaces: FxHashMap<Id, &'a FxHashMap<Atom, Arc<EnumInfo>>>,
+}
+
+impl<'a> InlineImportedEnums<'a> {
+    pub fn new(imported_enums: &'a ImportedEnums, ts_enum_is_mutable: bool) -> Self {
+        Self {
+            imported_enums,
+            ts_enum_is_mutable,
+            enums: Default::default(),
+            namespaces: Default::default(),
+        }
+    }
+
+    fn can_inline(&self, info: &EnumInfo) -> bool {
+        info.is_const || !self.ts_enum_is_mutable
+    }
+
+    fn find_enum(&self, obj: &Expr) -> Option<&EnumInfo> {
+        match obj {
+            Expr::Ident(ident) => self.enums.get(&ident.to_id()).map(|info| &**info),
+            Expr::Member(MemberExpr { obj, prop, .. }) => {
+                let Expr::Ident(ns) = &**obj else {
+                    return None;
+                };
+
+                self.namespaces
+                    .get(&ns.to_id())?
+                    .get(&member_key(prop)?)
+                    .map(|info| &**info)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_const_enum(&self, src: Option<&Atom>, orig: &ModuleExportName) -> bool {
+        let info = match (src, orig) {
+            (Some(src), _) => self.imported_enums.get(src, orig.atom()),
+            (None, ModuleExportName::Ident(ident)) => self.enums.get(&ident.to_id()),
+            _ => None,
+        };
+
+        info.is_some_and(|info| info.is_const)
+    }
+}
+
+impl VisitMut for InlineImportedEnums<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        let imported_enums = self.imported_enums;
+
+        for item in &n.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+            if import.type_only {
+                continue;
+            }
+            let Some(enums) = imported_enums.modules.get(&import.src.value) else {
+                continue;
+            };
+
+            for specifier in &import.specifiers {
+                match specifier {
+                    ImportSpecifier::Named(named) if !named.is_type_only => {
+                        let imported = named
+                            .imported
+                            .as_ref()
+                            .map(|imported| imported.atom().clone())
+                            .unwrap_or_else(|| named.local.sym.clone());
+
+                        if let Some(info) = enums.get(&imported) {
+                            self.enums.insert(named.local.to_id(), info.clone());
+                        }
+                    }
+                    ImportSpecifier::Default(default) => {
+                        if let Some(info) = enums.get(&atom!("default")) {
+                            self.enums.insert(default.local.to_id(), info.clone());
+                        }
+                    }
+                    ImportSpecifier::Namespace(namespace) => {
+                        self.namespaces.insert(namespace.local.to_id(), enums);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if self.enums.is_empty() && self.namespaces.is_empty() {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
+        n.retain_mut(|item| {
+            if let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) = item {
+                if export.type_only || export.specifiers.is_empty() {
+                    return true;
+                }
+
+                let src = export.src.as_ref().map(|src| &src.value);
+                export.specifiers.retain(|specifier| match specifier {
+                    ExportSpecifier::Named(named) => !self.is_const_enum(src, &named.orig),
+                    _ => true,
+                });
+
+                return !export.specifiers.is_empty();
+            }
+
+            item.visit_mut_with(self);
+            true
+        });
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        if let Expr::Member(MemberExpr { obj, prop, .. }) = n {
+            let value = self.find_enum(obj).and_then(|info| {
+                if !self.can_inline(info) {
+                    return None;
+                }
+
+                info.members.get(&member_key(prop)?)
+            });
+
+            if let Some(value) = value {
+                *n = value.clone().into();
+                return;
+            }
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_update_expr(&mut self, n: &mut UpdateExpr) {
+        // `E.A++` is left as is.
+        n.arg.visit_mut_children_with(self);
+    }
+}
+
+fn member_key(prop: &MemberProp) -> Option<Atom> {
+    match prop {
+        MemberProp::Ident(ident) => Some(ident.sym.clone()),
+        MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(s)) => Some(s.value.clone()),
+            _ => None,
+        },
+        MemberProp::PrivateName(..) => None,
+    }
+}
@@ -2,9 +2,15 @@
 #![allow(clippy::vec_box)]
 #![allow(clippy::mutable_key_type)]
 
-pub use self::{strip_type::*, typescript::*};
+pub use self::{
+    enum_registry::{EnumRegistry, ImportedEnums},
+    strip_type::*,
+    typescript::*,
+};
 mod config;
+mod enum_registry;
 mod macros;
+mod rewrite_import_extensions;
 mod strip_import_export;
 mod strip_type;
 mod transform;
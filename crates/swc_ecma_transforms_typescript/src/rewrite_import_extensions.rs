@@ -0,0 +1,118 @@
+use swc_common::{util::take::Take, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::helper;
+use swc_ecma_utils::ExprFactory;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// https://www.typescriptlang.org/tsconfig/#rewriteRelativeImportExtensions
+///
+/// Rewrites `.ts`, `.tsx`, `.mts` and `.cts` extensions of relative module
+/// specifiers to the extensions of the emitted files. Non-literal specifiers
+/// of `import()` and `require()` are rewritten at runtime.
+pub(crate) struct RewriteImportExtensions {
+    pub unresolved_ctxt: SyntaxContext,
+}
+
+impl RewriteImportExtensions {
+    fn rewrite_str(src: &mut Str) {
+        if let Some(value) = rewrite_extension(&src.value) {
+            src.value = value.into();
+            src.raw = None;
+        }
+    }
+
+    fn is_require(&self, callee: &Callee) -> bool {
+        matches!(
+            callee,
+            Callee::Expr(expr)
+                if matches!(&**expr, Expr::Ident(Ident { sym, ctxt, .. })
+                    if sym == "require" && *ctxt == self.unresolved_ctxt)
+        )
+    }
+}
+
+impl VisitMut for RewriteImportExtensions {
+    noop_visit_mut_type!();
+
+    fn visit_mut_import_decl(&mut self, n: &mut ImportDecl) {
+        Self::rewrite_str(&mut n.src);
+    }
+
+    fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+        if let Some(src) = &mut n.src {
+            Self::rewrite_str(src);
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, n: &mut ExportAll) {
+        Self::rewrite_str(&mut n.src);
+    }
+
+    fn visit_mut_ts_import_equals_decl(&mut self, n: &mut TsImportEqualsDecl) {
+        if let TsModuleRef::TsExternalModuleRef(module_ref) = &mut n.module_ref {
+            Self::rewrite_str(&mut module_ref.expr);
+        }
+    }
+
+    fn visit_mut_call_expr(&mut self, n: &mut CallExpr) {
+        n.visit_mut_children_with(self);
+
+        if !matches!(n.callee, Callee::Import(..)) && !self.is_require(&n.callee) {
+            return;
+        }
+
+        let Some(ExprOrSpread { spread: None, expr }) = n.args.first_mut() else {
+            return;
+        };
+
+        match &mut **expr {
+            Expr::Lit(Lit::Str(src)) => Self::rewrite_str(src),
+            _ => {
+                *expr = CallExpr {
+                    callee: helper!(ts, ts_rewrite_relative_import_extension),
+                    args: vec![expr.take().as_arg()],
+                    ..Default::default()
+                }
+                .into();
+            }
+        }
+    }
+}
+
+/// Returns the specifier with the extension of the emitted file, or [None] if
+/// `src` is not a relative path to a TypeScript file.
+///
+/// Declaration files are not rewritten.
+fn rewrite_extension(src: &str) -> Option<String> {
+    if !src.starts_with("./") && !src.starts_with("../") {
+        return None;
+    }
+
+    let lower = src.to_ascii_lowercase();
+
+    if lower.ends_with(".tsx") {
+        return Some(format!("{}.js", &src[..src.len() - 4]));
+    }
+
+    let (stem, ext) = if lower.ends_with(".mts") {
+        (&src[..src.len() - 4], ".mjs")
+    } else if lower.ends_with(".cts") {
+        (&src[..src.len() - 4], ".cjs")
+    } else if lower.ends_with(".ts") {
+        (&src[..src.len() - 3], ".js")
+    } else {
+        return None;
+    };
+
+    // `foo.d.ts` and `foo.d.json.ts`, but not `foo.d.json.mts`
+    let file_name = stem.rsplit('/').next().unwrap_or(stem);
+    let parts = file_name.split('.').collect::<Vec<_>>();
+    let is_d = |idx: usize| {
+        parts.len() > idx + 1 && parts[parts.len() - 1 - idx].eq_ignore_ascii_case("d")
+    };
+    if is_d(0) || (ext == ".js" && is_d(1)) {
+        return None;
+    }
+
+    Some(format!("{stem}{ext}"))
+}
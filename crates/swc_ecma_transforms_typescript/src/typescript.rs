@@ -1,13 +1,18 @@
 use std::mem;
 
 use rustc_hash::FxHashSet;
-use swc_common::{comments::Comments, sync::Lrc, util::take::Take, Mark, SourceMap, Span, Spanned};
+use swc_common::{
+    comments::Comments, sync::Lrc, util::take::Take, Mark, SourceMap, Span, Spanned, SyntaxContext,
+};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_react::{parse_expr_for_jsx, JsxDirectives};
 use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith};
 
 pub use crate::config::*;
-use crate::{strip_import_export::StripImportExport, strip_type::StripType, transform::transform};
+use crate::{
+    enum_registry::InlineImportedEnums, rewrite_import_extensions::RewriteImportExtensions,
+    strip_import_export::StripImportExport, strip_type::StripType, transform::transform,
+};
 
 #[cfg(feature = "concurrent")]
 macro_rules! static_str {
@@ -52,6 +57,13 @@ impl VisitMut for TypeScript {
     fn visit_mut_program(&mut self, n: &mut Program) {
         let was_module = n.as_module().and_then(|m| self.get_last_module_span(m));
 
+        if !self.config.imported_enums.is_empty() {
+            n.visit_mut_with(&mut InlineImportedEnums::new(
+                &self.config.imported_enums,
+                self.config.ts_enum_is_mutable,
+            ));
+        }
+
         if !self.config.verbatim_module_syntax {
             n.visit_mut_with(&mut StripImportExport {
                 import_not_used_as_values: self.config.import_not_used_as_values,
@@ -71,6 +83,12 @@ impl VisitMut for TypeScript {
             self.config.native_class_properties,
         ));
 
+        if self.config.rewrite_relative_import_extensions {
+            n.visit_mut_with(&mut RewriteImportExtensions {
+                unresolved_ctxt: SyntaxContext::empty().apply_mark(self.unresolved_mark),
+            });
+        }
+
         if let Some(span) = was_module {
             let module = n.as_mut_module().unwrap();
             Self::restore_esm_ctx(module, span);
@@ -0,0 +1,10 @@
+import { Opaque } from "./enums";
+console.log(1, 2, "LEFT!", "LEFT");
+console.log(1, 1, Opaque.B, 4);
+function f(Direction) {
+    return Direction.Up;
+}
+var Own = /*#__PURE__*/ function(Own) {
+    Own[Own["A"] = 2] = "A";
+    return Own;
+}(Own || {});
@@ -0,0 +1,16 @@
+import a from "./a.js";
+import b from "../b.js";
+import "./c.mjs";
+import "./d.cjs";
+const f = require("./f.js");
+import g from "./g.d.ts";
+import h from "./h.d.json.ts";
+import i from "pkg/i.ts";
+import j from "./j.js";
+export { k } from "./k.js";
+export * from "./l.js";
+import("./m.js");
+import(_ts_rewrite_relative_import_extension(name));
+require("./n.js");
+require(_ts_rewrite_relative_import_extension(name));
+console.log(a, b, f, g, h, i, j);
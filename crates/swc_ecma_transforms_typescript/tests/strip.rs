@@ -1,7 +1,8 @@
 use std::path::PathBuf;
 
-use swc_common::{comments::NoopComments, pass::Optional, Mark};
+use swc_common::{comments::NoopComments, pass::Optional, FileName, Mark};
 use swc_ecma_ast::Pass;
+use swc_ecma_loader::resolve::{Resolution, Resolve};
 use swc_ecma_parser::{Syntax, TsSyntax};
 use swc_ecma_transforms_base::resolver;
 use swc_ecma_transforms_compat::{
@@ -15,7 +16,7 @@ use swc_ecma_transforms_proposal::decorators;
 use swc_ecma_transforms_react::jsx;
 use swc_ecma_transforms_testing::{test, test_exec, test_fixture, Tester};
 use swc_ecma_transforms_typescript::{
-    tsx, typescript, ImportsNotUsedAsValues, TsImportExportAssignConfig, TsxConfig,
+    tsx, typescript, EnumRegistry, ImportsNotUsedAsValues, TsImportExportAssignConfig, TsxConfig,
 };
 
 fn tr(t: &mut Tester) -> impl Pass {
@@ -2833,3 +2834,146 @@ test!(
     ts_jsx_bad_pragma,
     r#"/** @jsx bad-pragma */"#
 );
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_config(
+        t,
+        Some(typescript::Config {
+            rewrite_relative_import_extensions: true,
+            no_empty_export: true,
+            ..Default::default()
+        }),
+        None,
+        false,
+    ),
+    rewrite_relative_import_extensions,
+    r#"
+    import a from "./a.ts";
+    import b from "../b.tsx";
+    import "./c.mts";
+    import "./d.cts";
+    import type { E } from "./e.ts";
+    import f = require("./f.ts");
+    import g from "./g.d.ts";
+    import h from "./h.d.json.ts";
+    import i from "pkg/i.ts";
+    import j from "./j.js";
+    export { k } from "./k.ts";
+    export * from "./l.ts";
+
+    import("./m.ts");
+    import(name);
+    require("./n.ts");
+    require(name);
+
+    console.log(a, b, f, g, h, i, j);
+    "#
+);
+
+/// Resolves `./foo` to `foo.ts`.
+struct RelativeResolver;
+
+impl Resolve for RelativeResolver {
+    fn resolve(&self, _: &FileName, src: &str) -> Result<Resolution, anyhow::Error> {
+        Ok(Resolution {
+            filename: FileName::Custom(format!("{}.ts", src.trim_start_matches("./"))),
+            slug: None,
+        })
+    }
+}
+
+/// Strips `input` as `main.ts`, with the enums of `files` in the registry.
+fn tr_enum_registry(t: &mut Tester, files: &[(&str, &str)], input: &str) -> impl Pass {
+    let mut registry = EnumRegistry::default();
+
+    for &(name, src) in files.iter().chain([("main.ts", input)].iter()) {
+        let syntax = Syntax::Typescript(TsSyntax {
+            dts: name.ends_with(".d.ts"),
+            ..Default::default()
+        });
+        let module = t
+            .with_parser(name, syntax, src, |p| p.parse_module())
+            .unwrap();
+
+        let file = FileName::Custom(name.replace(".d.ts", ".ts"));
+        registry.add_module(&RelativeResolver, file, &module);
+    }
+
+    tr_config(
+        t,
+        Some(typescript::Config {
+            imported_enums: registry.imported_enums(&FileName::Custom("main.ts".into())),
+            no_empty_export: true,
+            ..Default::default()
+        }),
+        None,
+        false,
+    )
+}
+
+const ENUM_REGISTRY_INPUT: &str = r#"
+import { Direction, Mutable, Opaque } from "./enums";
+import { Dir } from "./index";
+import * as ns from "./enums";
+import { Local } from "./local";
+export { Direction } from "./enums";
+export { Local };
+
+console.log(Direction.Up, Direction["Down"], Dir.Right, ns.Direction.Left);
+console.log(Mutable.A, Opaque.A, Opaque.B, Local.A);
+
+function f(Direction: any) {
+    return Direction.Up;
+}
+
+enum Own {
+    A = Direction.Down,
+}
+"#;
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_enum_registry(
+        t,
+        &[
+            (
+                "enums.d.ts",
+                r#"
+                export declare const enum Direction {
+                    Up = 1,
+                    Down,
+                    Left = "LEFT",
+                    Right = Left + "!",
+                }
+                export declare enum Mutable {
+                    A = 1,
+                }
+                export declare enum Opaque {
+                    A = 1,
+                    B = Math.random(),
+                }
+                "#
+            ),
+            (
+                "index.ts",
+                r#"
+                export * from "./enums";
+                export { Direction as Dir } from "./enums";
+                "#
+            ),
+            (
+                "local.ts",
+                r#"
+                const enum Local {
+                    A = 1 << 2,
+                }
+                export { Local };
+                "#
+            ),
+        ],
+        ENUM_REGISTRY_INPUT,
+    ),
+    imported_enums,
+    ENUM_REGISTRY_INPUT
+);
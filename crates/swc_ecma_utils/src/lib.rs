@@ -1951,6 +1951,9 @@ impl ExprCtx {
             }
             Expr::OptChain(..) => to.push(Box::new(expr)),
 
+            Expr::Pipeline(..) => to.push(Box::new(expr)),
+            Expr::TopicRef(..) => {}
+
             Expr::Invalid(..) => unreachable!(),
         }
     }
@@ -3529,6 +3532,11 @@ fn may_have_side_effects(expr: &Expr, ctx: ExprCtx) -> bool {
         | Expr::TsInstantiation(TsInstantiation { ref expr, .. })
         | Expr::TsSatisfies(TsSatisfiesExpr { ref expr, .. }) => expr.may_have_side_effects(ctx),
 
+        Expr::Pipeline(PipelineExpr { head, body, .. }) => {
+            head.may_have_side_effects(ctx) || body.may_have_side_effects(ctx)
+        }
+        Expr::TopicRef(..) => false,
+
         Expr::Invalid(..) => true,
     }
 }
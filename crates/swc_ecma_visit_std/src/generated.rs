@@ -1061,6 +1061,13 @@ pub trait Visit {
     fn visit_pats(&mut self, node: &[Pat]) {
         <[Pat] as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <PipelineExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -1271,6 +1278,13 @@ pub trait Visit {
     fn visit_throw_stmt(&mut self, node: &ThrowStmt) {
         <ThrowStmt as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicRef`.\n\nBy default, this method calls \
+             [`TopicRef::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_topic_ref(&mut self, node: &TopicRef) {
+        <TopicRef as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -2171,6 +2185,11 @@ where
         <V as Visit>::visit_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <V as Visit>::visit_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         <V as Visit>::visit_private_method(&mut **self, node)
@@ -2326,6 +2345,11 @@ where
         <V as Visit>::visit_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_topic_ref(&mut self, node: &TopicRef) {
+        <V as Visit>::visit_topic_ref(&mut **self, node)
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         <V as Visit>::visit_tpl(&mut **self, node)
@@ -3190,6 +3214,11 @@ where
         <V as Visit>::visit_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <V as Visit>::visit_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         <V as Visit>::visit_private_method(&mut **self, node)
@@ -3345,6 +3374,11 @@ where
         <V as Visit>::visit_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_topic_ref(&mut self, node: &TopicRef) {
+        <V as Visit>::visit_topic_ref(&mut **self, node)
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         <V as Visit>::visit_tpl(&mut **self, node)
@@ -4694,6 +4728,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         match self {
@@ -4942,6 +4984,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_ref(&mut self, node: &TopicRef) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_topic_ref(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_topic_ref(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         match self {
@@ -6322,6 +6372,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        if self.enabled {
+            <V as Visit>::visit_pipeline_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         if self.enabled {
@@ -6570,6 +6628,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_ref(&mut self, node: &TopicRef) {
+        if self.enabled {
+            <V as Visit>::visit_topic_ref(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         if self.enabled {
@@ -7952,6 +8018,12 @@ impl<V: ?Sized + Visit> VisitWith<V> for Expr {
             Expr::Invalid { 0: _field_0 } => {
                 <Invalid as VisitWith<V>>::visit_with(_field_0, visitor);
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                <PipelineExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            Expr::TopicRef { 0: _field_0 } => {
+                <TopicRef as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
         }
     }
 }
@@ -9547,6 +9619,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for PipelineExpr {
+    #[doc = "Calls [Visit`::visit_pipeline_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_pipeline_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            PipelineExpr { span, head, body } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(head, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(body, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for PrivateMethod {
     #[doc = "Calls [Visit`::visit_private_method`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -10209,6 +10303,22 @@ impl<V: ?Sized + Visit> VisitWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for TopicRef {
+    #[doc = "Calls [Visit`::visit_topic_ref`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_topic_ref(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            TopicRef { span } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Tpl {
     #[doc = "Calls [Visit`::visit_tpl`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -12970,6 +13080,19 @@ pub trait VisitAstPath {
     fn visit_pats<'ast: 'r, 'r>(&mut self, node: &'ast [Pat], __ast_path: &mut AstNodePath<'r>) {
         <[Pat] as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <PipelineExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -13307,6 +13430,17 @@ pub trait VisitAstPath {
     ) {
         <ThrowStmt as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `TopicRef`.\n\nBy default, this method calls \
+             [`TopicRef::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_topic_ref<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicRef,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <TopicRef as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -14846,6 +14980,15 @@ where
         <V as VisitAstPath>::visit_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -15097,6 +15240,15 @@ where
         <V as VisitAstPath>::visit_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_topic_ref<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicRef,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_ref(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_tpl(&mut **self, node, __ast_path)
@@ -16587,6 +16739,15 @@ where
         <V as VisitAstPath>::visit_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -16838,6 +16999,15 @@ where
         <V as VisitAstPath>::visit_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_topic_ref<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicRef,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_ref(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_tpl(&mut **self, node, __ast_path)
@@ -19383,6 +19553,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -19841,6 +20027,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_ref<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicRef,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_topic_ref(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_topic_ref(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         match self {
@@ -21919,6 +22121,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -22263,6 +22477,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_ref<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicRef,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_topic_ref(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         if self.enabled {
@@ -25499,6 +25725,28 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Expr {
                     &mut *__ast_path,
                 );
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Expr(
+                    self,
+                    self::fields::ExprField::Pipeline,
+                ));
+                <PipelineExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::TopicRef { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Expr(
+                    self,
+                    self::fields::ExprField::TopicRef,
+                ));
+                <TopicRef as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
         }
     }
 }
@@ -29359,6 +29607,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [VisitAstPath`::visit_pipeline_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            PipelineExpr { span, head, body } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Head,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        head,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Body,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [VisitAstPath`::visit_private_method`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -31032,6 +31336,40 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for TopicRef {
+    #[doc = "Calls [VisitAstPath`::visit_topic_ref`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_ref(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            TopicRef { span } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::TopicRef(
+                        self,
+                        self::fields::TopicRefField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Tpl {
     #[doc = "Calls [VisitAstPath`::visit_tpl`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -34067,6 +34405,13 @@ pub trait VisitMut {
     fn visit_mut_pats(&mut self, node: &mut Vec<Pat>) {
         <Vec<Pat> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <PipelineExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_mut_children_with`]. If you want to recurse, you need to call \
              it manually."]
@@ -34283,6 +34628,13 @@ pub trait VisitMut {
     fn visit_mut_throw_stmt(&mut self, node: &mut ThrowStmt) {
         <ThrowStmt as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicRef`.\n\nBy default, this method calls \
+             [`TopicRef::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef) {
+        <TopicRef as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -35184,6 +35536,11 @@ where
         <V as VisitMut>::visit_mut_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <V as VisitMut>::visit_mut_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         <V as VisitMut>::visit_mut_private_method(&mut **self, node)
@@ -35339,6 +35696,11 @@ where
         <V as VisitMut>::visit_mut_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef) {
+        <V as VisitMut>::visit_mut_topic_ref(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         <V as VisitMut>::visit_mut_tpl(&mut **self, node)
@@ -36203,6 +36565,11 @@ where
         <V as VisitMut>::visit_mut_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <V as VisitMut>::visit_mut_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         <V as VisitMut>::visit_mut_private_method(&mut **self, node)
@@ -36358,6 +36725,11 @@ where
         <V as VisitMut>::visit_mut_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef) {
+        <V as VisitMut>::visit_mut_topic_ref(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         <V as VisitMut>::visit_mut_tpl(&mut **self, node)
@@ -37839,6 +38211,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         match self {
@@ -38091,6 +38471,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_topic_ref(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_topic_ref(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         match self {
@@ -39473,6 +39861,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_pipeline_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         if self.enabled {
@@ -39721,6 +40117,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_topic_ref(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         if self.enabled {
@@ -41103,6 +41507,12 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Expr {
             Expr::Invalid { 0: _field_0 } => {
                 <Invalid as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                <PipelineExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            Expr::TopicRef { 0: _field_0 } => {
+                <TopicRef as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
         }
     }
 }
@@ -42698,6 +43108,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for PipelineExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_pipeline_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_pipeline_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            PipelineExpr { span, head, body } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(head, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(body, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for PrivateMethod {
     #[doc = "Calls [VisitMut`::visit_mut_private_method`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -43360,6 +43792,22 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for TopicRef {
+    #[doc = "Calls [VisitMut`::visit_mut_topic_ref`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_topic_ref(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            TopicRef { span } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Tpl {
     #[doc = "Calls [VisitMut`::visit_mut_tpl`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -45887,6 +46335,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <PipelineExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -46180,6 +46637,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `TopicRef`.\n\nBy default, this method calls \
+             [`TopicRef::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef, __ast_path: &mut AstKindPath) {
+        <TopicRef as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_mut_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -47337,6 +47803,11 @@ where
         <V as VisitMutAstPath>::visit_mut_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_private_method(&mut **self, node, __ast_path)
@@ -47508,6 +47979,11 @@ where
         <V as VisitMutAstPath>::visit_mut_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_ref(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_tpl(&mut **self, node, __ast_path)
@@ -48594,6 +49070,11 @@ where
         <V as VisitMutAstPath>::visit_mut_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_private_method(&mut **self, node, __ast_path)
@@ -48765,6 +49246,11 @@ where
         <V as VisitMutAstPath>::visit_mut_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_ref(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_tpl(&mut **self, node, __ast_path)
@@ -50930,6 +51416,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         match self {
@@ -51318,6 +51816,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_topic_ref(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_topic_ref(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         match self {
@@ -53112,6 +53622,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -53380,6 +53898,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_ref(&mut self, node: &mut TopicRef, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_topic_ref(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -55955,6 +56481,24 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Expr {
                     &mut *__ast_path,
                 );
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Pipeline));
+                <PipelineExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::TopicRef { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::TopicRef));
+                <TopicRef as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
         }
     }
 }
@@ -58996,6 +59540,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_pipeline_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            PipelineExpr { span, head, body } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Head,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        head,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Body,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_private_method`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -60345,6 +60934,30 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for TopicRef {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_topic_ref`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_ref(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            TopicRef { span } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::TopicRef(self::fields::TopicRefField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Tpl {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_tpl`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -62860,6 +63473,13 @@ pub trait Fold {
     fn fold_pats(&mut self, node: Vec<Pat>) -> Vec<Pat> {
         <Vec<Pat> as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <PipelineExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -63070,6 +63690,13 @@ pub trait Fold {
     fn fold_throw_stmt(&mut self, node: ThrowStmt) -> ThrowStmt {
         <ThrowStmt as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicRef`.\n\nBy default, this method calls \
+             [`TopicRef::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef) -> TopicRef {
+        <TopicRef as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -63994,6 +64621,11 @@ where
         <V as Fold>::fold_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <V as Fold>::fold_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         <V as Fold>::fold_private_method(&mut **self, node)
@@ -64152,6 +64784,11 @@ where
         <V as Fold>::fold_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef) -> TopicRef {
+        <V as Fold>::fold_topic_ref(&mut **self, node)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         <V as Fold>::fold_tpl(&mut **self, node)
@@ -65043,6 +65680,11 @@ where
         <V as Fold>::fold_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <V as Fold>::fold_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         <V as Fold>::fold_private_method(&mut **self, node)
@@ -65201,6 +65843,11 @@ where
         <V as Fold>::fold_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef) -> TopicRef {
+        <V as Fold>::fold_topic_ref(&mut **self, node)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         <V as Fold>::fold_tpl(&mut **self, node)
@@ -66559,6 +67206,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         match self {
@@ -66810,6 +67465,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef) -> TopicRef {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_topic_ref(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_topic_ref(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         match self {
@@ -68371,6 +69034,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        if self.enabled {
+            <V as Fold>::fold_pipeline_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         if self.enabled {
@@ -68653,6 +69325,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef) -> TopicRef {
+        if self.enabled {
+            <V as Fold>::fold_topic_ref(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         if self.enabled {
@@ -69686,10 +70367,18 @@ impl<V: ?Sized + Fold> FoldWith<V> for Decl {
                 let _field_0 = <Box<UsingDecl> as FoldWith<V>>::fold_with(_field_0, visitor);
                 Decl::Using { 0: _field_0 }
             }
-            Decl::TsInterface { 0: _field_0 } => Decl::TsInterface { 0: _field_0 },
-            Decl::TsTypeAlias { 0: _field_0 } => Decl::TsTypeAlias { 0: _field_0 },
-            Decl::TsEnum { 0: _field_0 } => Decl::TsEnum { 0: _field_0 },
-            Decl::TsModule { 0: _field_0 } => Decl::TsModule { 0: _field_0 },
+            Decl::TsInterface { 0: _field_0 } => {
+                Decl::TsInterface { 0: _field_0 }
+            }
+            Decl::TsTypeAlias { 0: _field_0 } => {
+                Decl::TsTypeAlias { 0: _field_0 }
+            }
+            Decl::TsEnum { 0: _field_0 } => {
+                Decl::TsEnum { 0: _field_0 }
+            }
+            Decl::TsModule { 0: _field_0 } => {
+                Decl::TsModule { 0: _field_0 }
+            }
         }
     }
 }
@@ -70046,12 +70735,24 @@ impl<V: ?Sized + Fold> FoldWith<V> for Expr {
                 let _field_0 = <JSXFragment as FoldWith<V>>::fold_with(_field_0, visitor);
                 Expr::JSXFragment { 0: _field_0 }
             }
-            Expr::TsTypeAssertion { 0: _field_0 } => Expr::TsTypeAssertion { 0: _field_0 },
-            Expr::TsConstAssertion { 0: _field_0 } => Expr::TsConstAssertion { 0: _field_0 },
-            Expr::TsNonNull { 0: _field_0 } => Expr::TsNonNull { 0: _field_0 },
-            Expr::TsAs { 0: _field_0 } => Expr::TsAs { 0: _field_0 },
-            Expr::TsInstantiation { 0: _field_0 } => Expr::TsInstantiation { 0: _field_0 },
-            Expr::TsSatisfies { 0: _field_0 } => Expr::TsSatisfies { 0: _field_0 },
+            Expr::TsTypeAssertion { 0: _field_0 } => {
+                Expr::TsTypeAssertion { 0: _field_0 }
+            }
+            Expr::TsConstAssertion { 0: _field_0 } => {
+                Expr::TsConstAssertion { 0: _field_0 }
+            }
+            Expr::TsNonNull { 0: _field_0 } => {
+                Expr::TsNonNull { 0: _field_0 }
+            }
+            Expr::TsAs { 0: _field_0 } => {
+                Expr::TsAs { 0: _field_0 }
+            }
+            Expr::TsInstantiation { 0: _field_0 } => {
+                Expr::TsInstantiation { 0: _field_0 }
+            }
+            Expr::TsSatisfies { 0: _field_0 } => {
+                Expr::TsSatisfies { 0: _field_0 }
+            }
             Expr::PrivateName { 0: _field_0 } => {
                 let _field_0 = <PrivateName as FoldWith<V>>::fold_with(_field_0, visitor);
                 Expr::PrivateName { 0: _field_0 }
@@ -70064,6 +70765,14 @@ impl<V: ?Sized + Fold> FoldWith<V> for Expr {
                 let _field_0 = <Invalid as FoldWith<V>>::fold_with(_field_0, visitor);
                 Expr::Invalid { 0: _field_0 }
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let _field_0 = <PipelineExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Pipeline { 0: _field_0 }
+            }
+            Expr::TopicRef { 0: _field_0 } => {
+                let _field_0 = <TopicRef as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::TopicRef { 0: _field_0 }
+            }
         }
     }
 }
@@ -71634,6 +72343,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for PipelineExpr {
+    #[doc = "Calls [Fold`::fold_pipeline_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_pipeline_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            PipelineExpr { span, head, body } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let head = { <Box<Expr> as FoldWith<V>>::fold_with(head, visitor) };
+                let body = { <Box<Expr> as FoldWith<V>>::fold_with(body, visitor) };
+                PipelineExpr { span, head, body }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for PrivateMethod {
     #[doc = "Calls [Fold`::fold_private_method`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -72000,7 +72726,9 @@ impl<V: ?Sized + Fold> FoldWith<V> for SimpleAssignTarget {
                 let _field_0 = <OptChainExpr as FoldWith<V>>::fold_with(_field_0, visitor);
                 SimpleAssignTarget::OptChain { 0: _field_0 }
             }
-            SimpleAssignTarget::TsAs { 0: _field_0 } => SimpleAssignTarget::TsAs { 0: _field_0 },
+            SimpleAssignTarget::TsAs { 0: _field_0 } => {
+                SimpleAssignTarget::TsAs { 0: _field_0 }
+            }
             SimpleAssignTarget::TsSatisfies { 0: _field_0 } => {
                 SimpleAssignTarget::TsSatisfies { 0: _field_0 }
             }
@@ -72311,6 +73039,21 @@ impl<V: ?Sized + Fold> FoldWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for TopicRef {
+    #[doc = "Calls [Fold`::fold_topic_ref`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_topic_ref(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            TopicRef { span } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                TopicRef { span }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Tpl {
     #[doc = "Calls [Fold`::fold_tpl`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -74739,6 +75482,17 @@ pub trait FoldAstPath {
     fn fold_pats(&mut self, node: Vec<Pat>, __ast_path: &mut AstKindPath) -> Vec<Pat> {
         <Vec<Pat> as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <PipelineExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::fold_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -75020,6 +75774,13 @@ pub trait FoldAstPath {
     fn fold_throw_stmt(&mut self, node: ThrowStmt, __ast_path: &mut AstKindPath) -> ThrowStmt {
         <ThrowStmt as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `TopicRef`.\n\nBy default, this method calls \
+             [`TopicRef::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef, __ast_path: &mut AstKindPath) -> TopicRef {
+        <TopicRef as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -76289,6 +77050,15 @@ where
         <V as FoldAstPath>::fold_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <V as FoldAstPath>::fold_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -76492,6 +77262,11 @@ where
         <V as FoldAstPath>::fold_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef, __ast_path: &mut AstKindPath) -> TopicRef {
+        <V as FoldAstPath>::fold_topic_ref(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         <V as FoldAstPath>::fold_tpl(&mut **self, node, __ast_path)
@@ -77714,6 +78489,15 @@ where
         <V as FoldAstPath>::fold_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <V as FoldAstPath>::fold_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -77917,6 +78701,11 @@ where
         <V as FoldAstPath>::fold_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef, __ast_path: &mut AstKindPath) -> TopicRef {
+        <V as FoldAstPath>::fold_topic_ref(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         <V as FoldAstPath>::fold_tpl(&mut **self, node, __ast_path)
@@ -80156,6 +80945,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -80546,6 +81351,18 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef, __ast_path: &mut AstKindPath) -> TopicRef {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_topic_ref(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_topic_ref(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         match self {
@@ -82506,6 +83323,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -82833,6 +83663,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_ref(&mut self, node: TopicRef, __ast_path: &mut AstKindPath) -> TopicRef {
+        if self.enabled {
+            <V as FoldAstPath>::fold_topic_ref(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         if self.enabled {
@@ -85640,6 +86479,26 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Expr {
                 );
                 Expr::Invalid { 0: _field_0 }
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Pipeline));
+                let _field_0 = <PipelineExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Pipeline { 0: _field_0 }
+            }
+            Expr::TopicRef { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::TopicRef));
+                let _field_0 = <TopicRef as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::TopicRef { 0: _field_0 }
+            }
         }
     }
 }
@@ -88901,6 +89760,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [FoldAstPath`::fold_pipeline_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            PipelineExpr { span, head, body } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let head = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Head,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        head,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let body = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Body,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                PipelineExpr { span, head, body }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [FoldAstPath`::fold_private_method`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -90364,6 +91269,31 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for TopicRef {
+    #[doc = "Calls [FoldAstPath`::fold_topic_ref`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_topic_ref(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            TopicRef { span } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::TopicRef(self::fields::TopicRefField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                TopicRef { span }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Tpl {
     #[doc = "Calls [FoldAstPath`::fold_tpl`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -92927,6 +93857,10 @@ pub mod fields {
         OptChain,
         #[doc = "Represents [`Expr::Invalid`]"]
         Invalid,
+        #[doc = "Represents [`Expr::Pipeline`]"]
+        Pipeline,
+        #[doc = "Represents [`Expr::TopicRef`]"]
+        TopicRef,
     }
     impl ExprOrSpreadField {
         pub(crate) fn set_index(&mut self, index: usize) {
@@ -94202,6 +95136,23 @@ pub mod fields {
         #[doc = "Represents [`Pat::Expr`]"]
         Expr,
     }
+    impl PipelineExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum PipelineExprField {
+        #[doc = "Represents [`PipelineExpr::span`]"]
+        Span,
+        #[doc = "Represents [`PipelineExpr::head`]"]
+        Head,
+        #[doc = "Represents [`PipelineExpr::body`]"]
+        Body,
+    }
     impl PrivateMethodField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -94729,6 +95680,19 @@ pub mod fields {
         #[doc = "Represents [`ThrowStmt::arg`]"]
         Arg,
     }
+    impl TopicRefField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum TopicRefField {
+        #[doc = "Represents [`TopicRef::span`]"]
+        Span,
+    }
     impl TplField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -95151,6 +96115,7 @@ pub mod fields {
         ParamOrTsParamProp(ParamOrTsParamPropField),
         ParenExpr(ParenExprField),
         Pat(PatField),
+        PipelineExpr(PipelineExprField),
         PrivateMethod(PrivateMethodField),
         PrivateName(PrivateNameField),
         PrivateProp(PrivatePropField),
@@ -95177,6 +96142,7 @@ pub mod fields {
         TaggedTpl(TaggedTplField),
         ThisExpr(ThisExprField),
         ThrowStmt(ThrowStmtField),
+        TopicRef(TopicRefField),
         Tpl(TplField),
         TplElement(TplElementField),
         TruePlusMinus(TruePlusMinusField),
@@ -95318,6 +96284,7 @@ pub mod fields {
                 Self::ParamOrTsParamProp(v) => v.set_index(index),
                 Self::ParenExpr(v) => v.set_index(index),
                 Self::Pat(v) => v.set_index(index),
+                Self::PipelineExpr(v) => v.set_index(index),
                 Self::PrivateMethod(v) => v.set_index(index),
                 Self::PrivateName(v) => v.set_index(index),
                 Self::PrivateProp(v) => v.set_index(index),
@@ -95344,6 +96311,7 @@ pub mod fields {
                 Self::TaggedTpl(v) => v.set_index(index),
                 Self::ThisExpr(v) => v.set_index(index),
                 Self::ThrowStmt(v) => v.set_index(index),
+                Self::TopicRef(v) => v.set_index(index),
                 Self::Tpl(v) => v.set_index(index),
                 Self::TplElement(v) => v.set_index(index),
                 Self::TruePlusMinus(v) => v.set_index(index),
@@ -95488,6 +96456,7 @@ pub mod fields {
         ParamOrTsParamProp(&'ast ParamOrTsParamProp, ParamOrTsParamPropField),
         ParenExpr(&'ast ParenExpr, ParenExprField),
         Pat(&'ast Pat, PatField),
+        PipelineExpr(&'ast PipelineExpr, PipelineExprField),
         PrivateMethod(&'ast PrivateMethod, PrivateMethodField),
         PrivateName(&'ast PrivateName, PrivateNameField),
         PrivateProp(&'ast PrivateProp, PrivatePropField),
@@ -95514,6 +96483,7 @@ pub mod fields {
         TaggedTpl(&'ast TaggedTpl, TaggedTplField),
         ThisExpr(&'ast ThisExpr, ThisExprField),
         ThrowStmt(&'ast ThrowStmt, ThrowStmtField),
+        TopicRef(&'ast TopicRef, TopicRefField),
         Tpl(&'ast Tpl, TplField),
         TplElement(&'ast TplElement, TplElementField),
         TruePlusMinus(&'ast TruePlusMinus, TruePlusMinusField),
@@ -95661,6 +96631,7 @@ pub mod fields {
                 Self::ParamOrTsParamProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::ParenExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Pat(_, __field_kind) => __field_kind.set_index(index),
+                Self::PipelineExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateMethod(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateName(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateProp(_, __field_kind) => __field_kind.set_index(index),
@@ -95687,6 +96658,7 @@ pub mod fields {
                 Self::TaggedTpl(_, __field_kind) => __field_kind.set_index(index),
                 Self::ThisExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::ThrowStmt(_, __field_kind) => __field_kind.set_index(index),
+                Self::TopicRef(_, __field_kind) => __field_kind.set_index(index),
                 Self::Tpl(_, __field_kind) => __field_kind.set_index(index),
                 Self::TplElement(_, __field_kind) => __field_kind.set_index(index),
                 Self::TruePlusMinus(_, __field_kind) => __field_kind.set_index(index),
@@ -95885,6 +96857,7 @@ pub mod fields {
                 }
                 Self::ParenExpr(_, __field_kind) => AstParentKind::ParenExpr(*__field_kind),
                 Self::Pat(_, __field_kind) => AstParentKind::Pat(*__field_kind),
+                Self::PipelineExpr(_, __field_kind) => AstParentKind::PipelineExpr(*__field_kind),
                 Self::PrivateMethod(_, __field_kind) => AstParentKind::PrivateMethod(*__field_kind),
                 Self::PrivateName(_, __field_kind) => AstParentKind::PrivateName(*__field_kind),
                 Self::PrivateProp(_, __field_kind) => AstParentKind::PrivateProp(*__field_kind),
@@ -95913,6 +96886,7 @@ pub mod fields {
                 Self::TaggedTpl(_, __field_kind) => AstParentKind::TaggedTpl(*__field_kind),
                 Self::ThisExpr(_, __field_kind) => AstParentKind::ThisExpr(*__field_kind),
                 Self::ThrowStmt(_, __field_kind) => AstParentKind::ThrowStmt(*__field_kind),
+                Self::TopicRef(_, __field_kind) => AstParentKind::TopicRef(*__field_kind),
                 Self::Tpl(_, __field_kind) => AstParentKind::Tpl(*__field_kind),
                 Self::TplElement(_, __field_kind) => AstParentKind::TplElement(*__field_kind),
                 Self::TruePlusMinus(_, __field_kind) => AstParentKind::TruePlusMinus(*__field_kind),
@@ -96533,6 +97507,11 @@ impl<'ast> From<&'ast Pat> for NodeRef<'ast> {
         NodeRef::Pat(node)
     }
 }
+impl<'ast> From<&'ast PipelineExpr> for NodeRef<'ast> {
+    fn from(node: &'ast PipelineExpr) -> Self {
+        NodeRef::PipelineExpr(node)
+    }
+}
 impl<'ast> From<&'ast PrivateMethod> for NodeRef<'ast> {
     fn from(node: &'ast PrivateMethod) -> Self {
         NodeRef::PrivateMethod(node)
@@ -96663,6 +97642,11 @@ impl<'ast> From<&'ast ThrowStmt> for NodeRef<'ast> {
         NodeRef::ThrowStmt(node)
     }
 }
+impl<'ast> From<&'ast TopicRef> for NodeRef<'ast> {
+    fn from(node: &'ast TopicRef) -> Self {
+        NodeRef::TopicRef(node)
+    }
+}
 impl<'ast> From<&'ast Tpl> for NodeRef<'ast> {
     fn from(node: &'ast Tpl) -> Self {
         NodeRef::Tpl(node)
@@ -96865,6 +97849,7 @@ pub enum NodeRef<'ast> {
     ParamOrTsParamProp(&'ast ParamOrTsParamProp),
     ParenExpr(&'ast ParenExpr),
     Pat(&'ast Pat),
+    PipelineExpr(&'ast PipelineExpr),
     PrivateMethod(&'ast PrivateMethod),
     PrivateName(&'ast PrivateName),
     PrivateProp(&'ast PrivateProp),
@@ -96891,6 +97876,7 @@ pub enum NodeRef<'ast> {
     TaggedTpl(&'ast TaggedTpl),
     ThisExpr(&'ast ThisExpr),
     ThrowStmt(&'ast ThrowStmt),
+    TopicRef(&'ast TopicRef),
     Tpl(&'ast Tpl),
     TplElement(&'ast TplElement),
     TruePlusMinus(&'ast TruePlusMinus),
@@ -97365,6 +98351,8 @@ impl<'ast> NodeRef<'ast> {
                 Expr::PrivateName(v0) => Box::new(::std::iter::once(NodeRef::PrivateName(v0))),
                 Expr::OptChain(v0) => Box::new(::std::iter::once(NodeRef::OptChainExpr(v0))),
                 Expr::Invalid(v0) => Box::new(::std::iter::once(NodeRef::Invalid(v0))),
+                Expr::Pipeline(v0) => Box::new(::std::iter::once(NodeRef::PipelineExpr(v0))),
+                Expr::TopicRef(v0) => Box::new(::std::iter::once(NodeRef::TopicRef(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
             NodeRef::ExprOrSpread(node) => {
@@ -97978,6 +98966,18 @@ impl<'ast> NodeRef<'ast> {
                 Pat::Expr(v0) => Box::new(::std::iter::once(NodeRef::Expr(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
+            NodeRef::PipelineExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain({
+                        let item = &*node.head;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    })
+                    .chain({
+                        let item = &*node.body;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    });
+                Box::new(iterator)
+            }
             NodeRef::PrivateMethod(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(::std::iter::once(NodeRef::PrivateName(&node.key)))
@@ -98215,6 +99215,10 @@ impl<'ast> NodeRef<'ast> {
                 });
                 Box::new(iterator)
             }
+            NodeRef::TopicRef(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>();
+                Box::new(iterator)
+            }
             NodeRef::Tpl(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(node.exprs.iter().flat_map(|item| {
@@ -36,9 +36,12 @@ impl miette::Diagnostic for PrettyDiagnostic<'_> {
         self.d
             .code
             .as_ref()
-            .map(|v| match v {
-                DiagnosticId::Error(v) => v,
-                DiagnosticId::Lint(v) => v,
+            .and_then(|v| match v {
+                DiagnosticId::Error(v) => Some(v),
+                // Lint ids are names of lint rules, which are attached to match
+                // suppression comments. They are not rendered to keep the output
+                // of the compiler stable.
+                DiagnosticId::Lint(_) => None,
             })
             .map(|code| Box::new(code) as Box<dyn fmt::Display>)
     }
@@ -96,6 +96,8 @@ pub enum Expression {
     Bind(BindExpression),
     #[tag("PipelinePrimaryTopicReference")]
     PipelinePrimaryTopicRef(PipelinePrimaryTopicReference),
+    #[tag("TopicReference")]
+    TopicRef(TopicReference),
     #[tag("DoExpression")]
     Do(DoExpression),
     #[tag("RecordExpression")]
@@ -223,6 +225,8 @@ pub enum BinaryExprOp {
     GreaterThanOrEqual,
     #[serde(rename = "<=")]
     LessThanOrEqual,
+    #[serde(rename = "|>")]
+    Pipeline,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -633,6 +637,14 @@ pub struct PipelinePrimaryTopicReference {
     pub base: BaseNode,
 }
 
+/// `%` of a Hack-style pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[ast_serde("TopicReference")]
+pub struct TopicReference {
+    #[serde(flatten)]
+    pub base: BaseNode,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[ast_serde("DoExpression")]
 pub struct DoExpression {
@@ -5,19 +5,20 @@ use swc_ecma_ast::{
     ArrayLit, ArrowExpr, AssignExpr, AssignTarget, AssignTargetPat, AwaitExpr, BinExpr, BinaryOp,
     BlockStmtOrExpr, CallExpr, Callee, ClassExpr, CondExpr, Expr, ExprOrSpread, FnExpr, Ident,
     Import, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, NewExpr, ObjectLit, ParenExpr,
-    PropOrSpread, SeqExpr, SimpleAssignTarget, SpreadElement, Super, SuperProp, SuperPropExpr,
-    TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+    PipelineExpr, PropOrSpread, SeqExpr, SimpleAssignTarget, SpreadElement, Super, SuperProp,
+    SuperPropExpr, TaggedTpl, ThisExpr, TopicRef, Tpl, TplElement, UnaryExpr, UpdateExpr,
+    YieldExpr,
 };
 use swc_estree_ast::{
     flavor::Flavor, ArrayExprEl, ArrayExpression, ArrowFuncExprBody, ArrowFunctionExpression,
-    AssignmentExpression, AwaitExpression, BinaryExprLeft, BinaryExpression, CallExpression,
-    Callee as BabelCallee, ClassExpression, ConditionalExpression, Expression, FunctionExpression,
-    Import as BabelImport, LVal, Literal, LogicalExpression, MemberExprProp, MemberExpression,
-    MetaProperty, NewExpression, ObjectExprProp, ObjectExpression, ObjectKey, ObjectMember,
-    ParenthesizedExpression, PrivateName, SequenceExpression, SpreadElement as BabelSpreadElement,
-    Super as BabelSuper, TaggedTemplateExprTypeParams, TaggedTemplateExpression, TemplateElVal,
-    TemplateElement, TemplateLiteral, TemplateLiteralExpr, ThisExpression, UnaryExpression,
-    UpdateExpression, YieldExpression,
+    AssignmentExpression, AwaitExpression, BinaryExprLeft, BinaryExprOp, BinaryExpression,
+    CallExpression, Callee as BabelCallee, ClassExpression, ConditionalExpression, Expression,
+    FunctionExpression, Import as BabelImport, LVal, Literal, LogicalExpression, MemberExprProp,
+    MemberExpression, MetaProperty, NewExpression, ObjectExprProp, ObjectExpression, ObjectKey,
+    ObjectMember, ParenthesizedExpression, PrivateName, SequenceExpression,
+    SpreadElement as BabelSpreadElement, Super as BabelSuper, TaggedTemplateExprTypeParams,
+    TaggedTemplateExpression, TemplateElVal, TemplateElement, TemplateLiteral, TemplateLiteralExpr,
+    ThisExpression, TopicReference, UnaryExpression, UpdateExpression, YieldExpression,
 };
 
 use crate::babelify::{Babelify, Context};
@@ -138,6 +139,12 @@ impl Babelify for Expr {
             Expr::TsAs(a) => ExprOutput::Expr(Box::alloc().init(Expression::TSAs(a.babelify(ctx)))),
             Expr::TsInstantiation(..) => unimplemented!("Babel doesn't support this right now."),
             Expr::PrivateName(p) => ExprOutput::Private(p.babelify(ctx)),
+            Expr::Pipeline(p) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::Binary(p.babelify(ctx))))
+            }
+            Expr::TopicRef(t) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::TopicRef(t.babelify(ctx))))
+            }
 
             // TODO(dwoznicki): how does babel handle these?
             Expr::JSXMember(_) => panic!(
@@ -344,6 +351,29 @@ impl Babelify for BinExpr {
     }
 }
 
+impl Babelify for PipelineExpr {
+    type Output = BinaryExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        BinaryExpression {
+            base: ctx.base(self.span),
+            operator: BinaryExprOp::Pipeline,
+            left: Box::alloc().init(self.head.babelify(ctx).into()),
+            right: Box::alloc().init(self.body.babelify(ctx).into()),
+        }
+    }
+}
+
+impl Babelify for TopicRef {
+    type Output = TopicReference;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TopicReference {
+            base: ctx.base(self.span),
+        }
+    }
+}
+
 impl Babelify for FnExpr {
     type Output = FunctionExpression;
 
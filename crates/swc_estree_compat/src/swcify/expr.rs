@@ -5,9 +5,9 @@ use swc_ecma_ast::{
     FnExpr, Function, Ident, Import, JSXAttr, JSXAttrOrSpread, JSXAttrValue, JSXEmptyExpr, JSXExpr,
     JSXExprContainer, JSXMemberExpr, JSXObject, KeyValueProp, Lit, MemberExpr, MemberProp,
     MetaPropExpr, MetaPropKind, MethodProp, NewExpr, ObjectLit, OptCall, OptChainBase,
-    OptChainExpr, ParenExpr, Prop, PropName, PropOrSpread, SeqExpr, SpreadElement, SuperProp,
-    SuperPropExpr, TaggedTpl, ThisExpr, TsAsExpr, TsNonNullExpr, TsTypeAssertion,
-    TsTypeParamInstantiation, UnaryExpr, UnaryOp, UpdateExpr, YieldExpr,
+    OptChainExpr, ParenExpr, PipelineExpr, Prop, PropName, PropOrSpread, SeqExpr, SpreadElement,
+    SuperProp, SuperPropExpr, TaggedTpl, ThisExpr, TopicRef, TsAsExpr, TsNonNullExpr,
+    TsTypeAssertion, TsTypeParamInstantiation, UnaryExpr, UnaryOp, UpdateExpr, YieldExpr,
 };
 use swc_estree_ast::{
     Arg, ArrayExprEl, ArrayExpression, ArrowFuncExprBody, ArrowFunctionExpression,
@@ -21,9 +21,9 @@ use swc_estree_ast::{
     ObjectProperty, OptionalCallExpression, OptionalMemberExprProp, OptionalMemberExpression,
     ParenthesizedExpression, PatternLike, PipelinePrimaryTopicReference, RecordExpression,
     SequenceExpression, TSAsExpression, TSNonNullExpression, TSTypeAssertion,
-    TaggedTemplateExprTypeParams, TaggedTemplateExpression, ThisExpression, TupleExpression,
-    TypeCastExpression, UnaryExprOp, UnaryExpression, UpdateExprOp, UpdateExpression,
-    YieldExpression,
+    TaggedTemplateExprTypeParams, TaggedTemplateExpression, ThisExpression, TopicReference,
+    TupleExpression, TypeCastExpression, UnaryExprOp, UnaryExpression, UpdateExprOp,
+    UpdateExpression, YieldExpression,
 };
 
 use super::Context;
@@ -36,6 +36,17 @@ impl Swcify for Expression {
         Box::new(match self {
             Expression::Array(e) => e.swcify(ctx).into(),
             Expression::Assignment(e) => e.swcify(ctx).into(),
+            Expression::Binary(BinaryExpression {
+                base,
+                operator: BinaryExprOp::Pipeline,
+                left,
+                right,
+            }) => PipelineExpr {
+                span: ctx.span(&base),
+                head: left.swcify(ctx),
+                body: right.swcify(ctx),
+            }
+            .into(),
             Expression::Binary(e) => e.swcify(ctx).into(),
             Expression::Call(e) => e.swcify(ctx).into(),
             Expression::Conditional(e) => e.swcify(ctx).into(),
@@ -72,6 +83,7 @@ impl Swcify for Expression {
             Expression::TSAs(e) => e.swcify(ctx).into(),
             Expression::TSTypeAssertion(e) => e.swcify(ctx).into(),
             Expression::TSNonNull(e) => e.swcify(ctx).into(),
+            Expression::TopicRef(e) => e.swcify(ctx).into(),
             _ => {
                 unimplemented!("swcify: {:?}", self)
             }
@@ -215,6 +227,9 @@ impl Swcify for BinaryExprOp {
             BinaryExprOp::LessThanOrEqual => {
                 op!("<=")
             }
+            BinaryExprOp::Pipeline => {
+                unreachable!("pipelines are converted to `PipelineExpr`")
+            }
         }
     }
 }
@@ -1106,6 +1121,16 @@ impl Swcify for PipelinePrimaryTopicReference {
     }
 }
 
+impl Swcify for TopicReference {
+    type Output = TopicRef;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TopicRef {
+            span: ctx.span(&self.base),
+        }
+    }
+}
+
 impl Swcify for RecordExpression {
     type Output = Never;
 
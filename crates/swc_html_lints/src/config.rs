@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules::aria_valid_role::AriaValidRoleConfig;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintRuleReaction {
+    Off,
+    Warning,
+    Error,
+}
+
+impl Default for LintRuleReaction {
+    fn default() -> Self {
+        Self::Off
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(untagged)]
+enum LintRuleLevel {
+    Str(LintRuleReaction),
+    Number(u8),
+}
+
+impl Default for LintRuleLevel {
+    fn default() -> Self {
+        Self::Str(LintRuleReaction::Off)
+    }
+}
+
+impl From<LintRuleLevel> for LintRuleReaction {
+    fn from(level: LintRuleLevel) -> Self {
+        match level {
+            LintRuleLevel::Str(level) => level,
+            LintRuleLevel::Number(level) => match level {
+                1 => LintRuleReaction::Warning,
+                2 => LintRuleReaction::Error,
+                _ => LintRuleReaction::Off,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleConfig<T: Debug + Clone + Serialize + Default>(
+    #[serde(default)] LintRuleLevel,
+    #[serde(default)] T,
+);
+
+impl<T: Debug + Clone + Serialize + Default> RuleConfig<T> {
+    #[inline]
+    pub(crate) fn get_rule_reaction(&self) -> LintRuleReaction {
+        self.0.into()
+    }
+
+    #[inline]
+    pub(crate) fn get_rule_config(&self) -> &T {
+        &self.1
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case")]
+pub struct RulesConfig {
+    #[serde(default, alias = "ariaValidAttr")]
+    pub aria_valid_attr: RuleConfig<()>,
+
+    #[serde(default, alias = "ariaValidRole")]
+    pub aria_valid_role: RuleConfig<AriaValidRoleConfig>,
+
+    #[serde(default, alias = "buttonType")]
+    pub button_type: RuleConfig<()>,
+
+    #[serde(default, alias = "headingOrder")]
+    pub heading_order: RuleConfig<()>,
+
+    #[serde(default, alias = "imgAlt")]
+    pub img_alt: RuleConfig<()>,
+
+    #[serde(default, alias = "labelHasControl")]
+    pub label_has_control: RuleConfig<()>,
+
+    #[serde(default, alias = "noDuplicateId")]
+    pub no_duplicate_id: RuleConfig<()>,
+
+    #[serde(default, alias = "noInlineEventHandlers")]
+    pub no_inline_event_handlers: RuleConfig<()>,
+
+    #[serde(default, alias = "noInvalidNesting")]
+    pub no_invalid_nesting: RuleConfig<()>,
+
+    #[serde(default, alias = "noObsoleteAttributes")]
+    pub no_obsolete_attributes: RuleConfig<()>,
+
+    #[serde(default, alias = "noObsoleteElements")]
+    pub no_obsolete_elements: RuleConfig<()>,
+
+    #[serde(default, alias = "noTargetBlank")]
+    pub no_target_blank: RuleConfig<()>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LintConfig {
+    #[serde(default)]
+    pub rules: RulesConfig,
+}
@@ -0,0 +1,318 @@
+/// Returns `true` if `role` is a non-abstract role of WAI-ARIA, DPUB-ARIA or
+/// Graphics ARIA. `role` must be lowercase.
+pub(crate) fn is_known_aria_role(role: &str) -> bool {
+    KNOWN_ARIA_ROLES.binary_search(&role).is_ok()
+}
+
+/// Returns `true` if `name` is a state or property of WAI-ARIA. `name` must be
+/// lowercase.
+pub(crate) fn is_known_aria_attribute(name: &str) -> bool {
+    KNOWN_ARIA_ATTRIBUTES.binary_search(&name).is_ok()
+}
+
+/// See https://html.spec.whatwg.org/multipage/obsolete.html#non-conforming-features
+pub(crate) fn is_obsolete_element(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "acronym"
+            | "applet"
+            | "basefont"
+            | "bgsound"
+            | "big"
+            | "blink"
+            | "center"
+            | "dir"
+            | "font"
+            | "frame"
+            | "frameset"
+            | "image"
+            | "isindex"
+            | "keygen"
+            | "listing"
+            | "marquee"
+            | "menuitem"
+            | "multicol"
+            | "nextid"
+            | "nobr"
+            | "noembed"
+            | "noframes"
+            | "plaintext"
+            | "rb"
+            | "rtc"
+            | "spacer"
+            | "strike"
+            | "tt"
+            | "xmp"
+    )
+}
+
+/// Returns `true` if `name` is an obsolete attribute of `tag_name`. Attributes
+/// of obsolete elements are not included.
+///
+/// See https://html.spec.whatwg.org/multipage/obsolete.html#non-conforming-features
+pub(crate) fn is_obsolete_attribute(tag_name: &str, name: &str) -> bool {
+    match name {
+        "align" => matches!(
+            tag_name,
+            "caption"
+                | "col"
+                | "colgroup"
+                | "div"
+                | "embed"
+                | "h1"
+                | "h2"
+                | "h3"
+                | "h4"
+                | "h5"
+                | "h6"
+                | "hr"
+                | "iframe"
+                | "img"
+                | "input"
+                | "legend"
+                | "object"
+                | "p"
+                | "table"
+                | "tbody"
+                | "td"
+                | "tfoot"
+                | "th"
+                | "thead"
+                | "tr"
+        ),
+        "alink" | "bottommargin" | "leftmargin" | "link" | "rightmargin" | "text" | "topmargin"
+        | "vlink" => tag_name == "body",
+        "archive" | "classid" | "code" | "codebase" | "codetype" | "declare" | "standby" => {
+            tag_name == "object"
+        }
+        "abbr" => tag_name == "td",
+        "axis" => matches!(tag_name, "td" | "th"),
+        "background" => matches!(
+            tag_name,
+            "body" | "table" | "td" | "th" | "thead" | "tbody" | "tfoot" | "tr"
+        ),
+        "bgcolor" => matches!(tag_name, "body" | "table" | "td" | "th" | "tr"),
+        "border" => matches!(tag_name, "img" | "object"),
+        "cellpadding" | "cellspacing" | "frame" | "rules" | "summary" => tag_name == "table",
+        "char" | "charoff" | "valign" => matches!(
+            tag_name,
+            "col" | "colgroup" | "tbody" | "td" | "tfoot" | "th" | "thead" | "tr"
+        ),
+        "charset" => matches!(tag_name, "a" | "link" | "script"),
+        "clear" => tag_name == "br",
+        "compact" => matches!(tag_name, "dl" | "menu" | "ol" | "ul"),
+        "coords" | "shape" => tag_name == "a",
+        "datafld" | "dataformatas" | "datapagesize" | "datasrc" => true,
+        "event" | "for" => tag_name == "script",
+        "frameborder" | "scrolling" => tag_name == "iframe",
+        "height" => matches!(tag_name, "td" | "th"),
+        "hspace" | "vspace" => matches!(tag_name, "embed" | "iframe" | "img" | "object"),
+        "language" => tag_name == "script",
+        "longdesc" => matches!(tag_name, "iframe" | "img"),
+        "lowsrc" => tag_name == "img",
+        "marginheight" | "marginwidth" => matches!(tag_name, "body" | "iframe"),
+        "methods" | "rev" | "urn" => matches!(tag_name, "a" | "link"),
+        "name" => matches!(tag_name, "a" | "embed" | "img" | "option"),
+        "nohref" => tag_name == "area",
+        "noshade" => tag_name == "hr",
+        "nowrap" => matches!(tag_name, "td" | "th"),
+        "profile" => tag_name == "head",
+        "scheme" => tag_name == "meta",
+        "size" => tag_name == "hr",
+        "type" => matches!(tag_name, "li" | "ul"),
+        "version" => tag_name == "html",
+        "width" => matches!(
+            tag_name,
+            "col" | "colgroup" | "hr" | "pre" | "table" | "td" | "th"
+        ),
+        _ => false,
+    }
+}
+
+const KNOWN_ARIA_ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "comment",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "doc-abstract",
+    "doc-acknowledgments",
+    "doc-afterword",
+    "doc-appendix",
+    "doc-backlink",
+    "doc-biblioentry",
+    "doc-bibliography",
+    "doc-biblioref",
+    "doc-chapter",
+    "doc-colophon",
+    "doc-conclusion",
+    "doc-cover",
+    "doc-credit",
+    "doc-credits",
+    "doc-dedication",
+    "doc-endnote",
+    "doc-endnotes",
+    "doc-epigraph",
+    "doc-epilogue",
+    "doc-errata",
+    "doc-example",
+    "doc-footnote",
+    "doc-foreword",
+    "doc-glossary",
+    "doc-glossref",
+    "doc-index",
+    "doc-introduction",
+    "doc-noteref",
+    "doc-notice",
+    "doc-pagebreak",
+    "doc-pagefooter",
+    "doc-pageheader",
+    "doc-pagelist",
+    "doc-part",
+    "doc-preface",
+    "doc-prologue",
+    "doc-pullquote",
+    "doc-qna",
+    "doc-subtitle",
+    "doc-tip",
+    "doc-toc",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "graphics-document",
+    "graphics-object",
+    "graphics-symbol",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "mark",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "suggestion",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+const KNOWN_ARIA_ATTRIBUTES: &[&str] = &[
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+];
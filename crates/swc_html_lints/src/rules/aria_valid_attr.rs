@@ -0,0 +1,31 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::is_known_aria_attribute,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+};
+
+pub fn aria_valid_attr(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), AriaValidAttr { ctx })
+}
+
+#[derive(Debug, Default)]
+struct AriaValidAttr {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for AriaValidAttr {
+    fn visit_attribute(&mut self, attribute: &Attribute) {
+        if attribute.namespace.is_none()
+            && attribute.name.starts_with("aria-")
+            && !is_known_aria_attribute(&attribute.name.to_ascii_lowercase())
+        {
+            let message = format!("Unexpected unknown ARIA attribute \"{}\".", attribute.name);
+
+            self.ctx.report(attribute, message);
+        }
+
+        attribute.visit_children_with(self);
+    }
+}
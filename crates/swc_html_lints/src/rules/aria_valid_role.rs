@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::is_known_aria_role,
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::get_attribute,
+    ConfigError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AriaValidRoleConfig {
+    ignore_roles: Option<Vec<String>>,
+}
+
+pub fn aria_valid_role(
+    ctx: LintRuleContext<AriaValidRoleConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored_roles = ctx
+        .config()
+        .ignore_roles
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+
+    Ok(visitor_rule(
+        ctx.reaction(),
+        AriaValidRole { ctx, ignored_roles },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct AriaValidRole {
+    ctx: LintRuleContext<AriaValidRoleConfig>,
+    ignored_roles: Vec<NamePattern>,
+}
+
+impl Visit for AriaValidRole {
+    fn visit_element(&mut self, element: &Element) {
+        if let Some(attribute) = get_attribute(element, "role") {
+            // Roles after the first one are fallbacks
+            for role in attribute
+                .value
+                .as_deref()
+                .unwrap_or_default()
+                .split_ascii_whitespace()
+            {
+                if is_known_aria_role(&role.to_ascii_lowercase())
+                    || self
+                        .ignored_roles
+                        .iter()
+                        .any(|pattern| pattern.is_match(role))
+                {
+                    continue;
+                }
+
+                let message = format!("Unexpected invalid ARIA role \"{role}\".");
+
+                self.ctx.report(attribute, message);
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
@@ -0,0 +1,46 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::{get_attribute, is_html_element, start_tag_span},
+};
+
+pub fn button_type(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), ButtonType { ctx })
+}
+
+#[derive(Debug, Default)]
+struct ButtonType {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for ButtonType {
+    fn visit_element(&mut self, element: &Element) {
+        if is_html_element(element, "button") {
+            // A button without `type` submits its form, which is rarely intended
+            match get_attribute(element, "type") {
+                Some(attribute) => {
+                    let value = attribute.value.as_deref().unwrap_or_default();
+
+                    if !["submit", "reset", "button"]
+                        .iter()
+                        .any(|ty| value.eq_ignore_ascii_case(ty))
+                    {
+                        let message = format!("Unexpected invalid button type \"{value}\".");
+
+                        self.ctx.report(attribute, message);
+                    }
+                }
+                None => {
+                    self.ctx.report(
+                        start_tag_span(element),
+                        "Expected <button> to have an explicit \"type\" attribute.",
+                    );
+                }
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
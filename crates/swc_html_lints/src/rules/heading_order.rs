@@ -0,0 +1,61 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::start_tag_span,
+};
+
+pub fn heading_order(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        HeadingOrder {
+            ctx,
+            last_level: None,
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+struct HeadingOrder {
+    ctx: LintRuleContext<()>,
+    last_level: Option<u8>,
+}
+
+impl Visit for HeadingOrder {
+    fn visit_element(&mut self, element: &Element) {
+        if let Some(level) = heading_level(element) {
+            match self.last_level {
+                Some(last_level) if level > last_level + 1 => {
+                    let message = format!(
+                        "Unexpected <h{level}> after <h{last_level}>, heading levels should only \
+                         increase by one."
+                    );
+
+                    self.ctx.report(start_tag_span(element), message);
+                }
+                _ => {}
+            }
+
+            self.last_level = Some(level);
+        }
+
+        element.visit_children_with(self);
+    }
+}
+
+fn heading_level(element: &Element) -> Option<u8> {
+    if element.namespace != Namespace::HTML {
+        return None;
+    }
+
+    match &*element.tag_name {
+        "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
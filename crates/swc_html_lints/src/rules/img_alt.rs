@@ -0,0 +1,44 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::{get_attribute, get_attribute_value, is_html_element, start_tag_span},
+};
+
+pub fn img_alt(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), ImgAlt { ctx })
+}
+
+#[derive(Debug, Default)]
+struct ImgAlt {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for ImgAlt {
+    fn visit_element(&mut self, element: &Element) {
+        // An empty `alt` is allowed for decorative images
+        let description = if is_html_element(element, "img") {
+            Some("<img>")
+        } else if is_html_element(element, "area") && get_attribute(element, "href").is_some() {
+            Some("<area>")
+        } else if is_html_element(element, "input")
+            && get_attribute_value(element, "type")
+                .is_some_and(|value| value.eq_ignore_ascii_case("image"))
+        {
+            Some("<input type=\"image\">")
+        } else {
+            None
+        };
+
+        if let Some(description) = description {
+            if get_attribute(element, "alt").is_none() {
+                let message = format!("Expected {description} to have an \"alt\" attribute.");
+
+                self.ctx.report(start_tag_span(element), message);
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
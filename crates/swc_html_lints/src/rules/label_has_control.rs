@@ -0,0 +1,57 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::{get_attribute, get_attribute_value, is_html_element, start_tag_span},
+};
+
+pub fn label_has_control(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), LabelHasControl { ctx })
+}
+
+#[derive(Debug, Default)]
+struct LabelHasControl {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for LabelHasControl {
+    fn visit_element(&mut self, element: &Element) {
+        if is_html_element(element, "label")
+            && get_attribute(element, "for").is_none()
+            && !element.children.iter().any(contains_labelable_element)
+        {
+            self.ctx.report(
+                start_tag_span(element),
+                "Expected <label> to have a \"for\" attribute or to contain a form control.",
+            );
+        }
+
+        element.visit_children_with(self);
+    }
+}
+
+/// See https://html.spec.whatwg.org/multipage/forms.html#category-label
+fn contains_labelable_element(child: &Child) -> bool {
+    let Child::Element(element) = child else {
+        return false;
+    };
+
+    if element.namespace == Namespace::HTML {
+        match &*element.tag_name {
+            "button" | "meter" | "output" | "progress" | "select" | "textarea" => return true,
+            "input" => {
+                if !get_attribute_value(element, "type")
+                    .is_some_and(|value| value.eq_ignore_ascii_case("hidden"))
+                {
+                    return true;
+                }
+            }
+            // Custom elements may be form-associated
+            tag_name if tag_name.contains('-') => return true,
+            _ => {}
+        }
+    }
+
+    element.children.iter().any(contains_labelable_element)
+}
@@ -0,0 +1,56 @@
+use crate::{
+    config::LintConfig,
+    error::ConfigError,
+    rule::LintRule,
+    rules::{
+        aria_valid_attr::aria_valid_attr, aria_valid_role::aria_valid_role,
+        button_type::button_type, heading_order::heading_order, img_alt::img_alt,
+        label_has_control::label_has_control, no_duplicate_id::no_duplicate_id,
+        no_inline_event_handlers::no_inline_event_handlers, no_invalid_nesting::no_invalid_nesting,
+        no_obsolete_attributes::no_obsolete_attributes, no_obsolete_elements::no_obsolete_elements,
+        no_target_blank::no_target_blank,
+    },
+};
+
+pub mod aria_valid_attr;
+pub mod aria_valid_role;
+pub mod button_type;
+pub mod heading_order;
+pub mod img_alt;
+pub mod label_has_control;
+pub mod no_duplicate_id;
+pub mod no_inline_event_handlers;
+pub mod no_invalid_nesting;
+pub mod no_obsolete_attributes;
+pub mod no_obsolete_elements;
+pub mod no_target_blank;
+
+pub struct LintParams<'a> {
+    pub lint_config: &'a LintConfig,
+}
+
+pub fn get_rules(
+    LintParams { lint_config }: &LintParams,
+) -> Result<Vec<Box<dyn LintRule>>, ConfigError> {
+    let rules_config = &lint_config.rules;
+
+    let rules = vec![
+        // Accessibility
+        aria_valid_attr((&rules_config.aria_valid_attr).into()),
+        aria_valid_role((&rules_config.aria_valid_role).into())?,
+        button_type((&rules_config.button_type).into()),
+        heading_order((&rules_config.heading_order).into()),
+        img_alt((&rules_config.img_alt).into()),
+        label_has_control((&rules_config.label_has_control).into()),
+        // Validity
+        no_duplicate_id((&rules_config.no_duplicate_id).into()),
+        no_invalid_nesting((&rules_config.no_invalid_nesting).into()),
+        no_obsolete_attributes((&rules_config.no_obsolete_attributes).into()),
+        no_obsolete_elements((&rules_config.no_obsolete_elements).into()),
+        // Security
+        no_inline_event_handlers((&rules_config.no_inline_event_handlers).into()),
+        no_target_blank((&rules_config.no_target_blank).into()),
+    ];
+
+    Ok(rules)
+}
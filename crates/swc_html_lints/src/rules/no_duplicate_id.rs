@@ -0,0 +1,50 @@
+use rustc_hash::FxHashSet;
+use swc_atoms::Atom;
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::get_attribute,
+};
+
+pub fn no_duplicate_id(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        NoDuplicateId {
+            ctx,
+            ids: Default::default(),
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+struct NoDuplicateId {
+    ctx: LintRuleContext<()>,
+    ids: FxHashSet<Atom>,
+}
+
+impl Visit for NoDuplicateId {
+    fn visit_document_fragment(&mut self, document_fragment: &DocumentFragment) {
+        // The content of `<template>` is a separate tree
+        let old_ids = std::mem::take(&mut self.ids);
+
+        document_fragment.visit_children_with(self);
+
+        self.ids = old_ids;
+    }
+
+    fn visit_element(&mut self, element: &Element) {
+        if let Some(attribute) = get_attribute(element, "id") {
+            if let Some(id) = &attribute.value {
+                if !self.ids.insert(id.clone()) {
+                    let message = format!("Unexpected duplicate id \"{id}\".");
+
+                    self.ctx.report(attribute, message);
+                }
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
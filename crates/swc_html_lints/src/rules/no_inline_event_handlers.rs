@@ -0,0 +1,30 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::rule::{visitor_rule, LintRule, LintRuleContext};
+
+pub fn no_inline_event_handlers(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), NoInlineEventHandlers { ctx })
+}
+
+#[derive(Debug, Default)]
+struct NoInlineEventHandlers {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for NoInlineEventHandlers {
+    fn visit_attribute(&mut self, attribute: &Attribute) {
+        // Inline event handlers require `unsafe-inline` in the Content Security
+        // Policy
+        if attribute.namespace.is_none()
+            && attribute.name.len() > 2
+            && attribute.name.starts_with("on")
+        {
+            let message = format!("Unexpected inline event handler \"{}\".", attribute.name);
+
+            self.ctx.report(attribute, message);
+        }
+
+        attribute.visit_children_with(self);
+    }
+}
@@ -0,0 +1,182 @@
+use swc_atoms::Atom;
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::{get_attribute, is_interactive_element, start_tag_span},
+};
+
+pub fn no_invalid_nesting(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        NoInvalidNesting {
+            ctx,
+            scope: Default::default(),
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+struct NoInvalidNesting {
+    ctx: LintRuleContext<()>,
+    scope: Scope,
+}
+
+/// The nearest ancestors which restrict their descendants.
+///
+/// Invalid nesting which is fixed by the parser, e.g. `<p><div></div></p>`, is
+/// reported as a parse error instead.
+#[derive(Debug, Default, Clone)]
+struct Scope {
+    /// `<a>` or `<button>`, which can't contain interactive content.
+    interactive: Option<Atom>,
+    /// Elements which only allow phrasing content.
+    phrasing: Option<Atom>,
+    in_label: bool,
+}
+
+impl Visit for NoInvalidNesting {
+    fn visit_document_fragment(&mut self, document_fragment: &DocumentFragment) {
+        // The content of `<template>` is a separate tree
+        let old_scope = std::mem::take(&mut self.scope);
+
+        document_fragment.visit_children_with(self);
+
+        self.scope = old_scope;
+    }
+
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace != Namespace::HTML {
+            element.visit_children_with(self);
+
+            return;
+        }
+
+        let tag_name = &element.tag_name;
+
+        if let Some(parent) = &self.scope.interactive {
+            if is_interactive_element(element) || get_attribute(element, "tabindex").is_some() {
+                let message =
+                    format!("Unexpected interactive element <{tag_name}> inside <{parent}>.");
+
+                self.ctx.report(start_tag_span(element), message);
+            }
+        }
+
+        if *tag_name == *"label" && self.scope.in_label {
+            self.ctx.report(
+                start_tag_span(element),
+                "Unexpected <label> inside <label>.",
+            );
+        }
+
+        if let Some(parent) = &self.scope.phrasing {
+            if is_flow_only_element(tag_name) {
+                let message = format!(
+                    "Unexpected <{tag_name}> inside <{parent}>, which only allows phrasing \
+                     content."
+                );
+
+                self.ctx.report(start_tag_span(element), message);
+            }
+        }
+
+        let old_scope = self.scope.clone();
+
+        if matches!(&**tag_name, "a" | "button") {
+            self.scope.interactive = Some(tag_name.clone());
+        }
+        if is_phrasing_only_element(tag_name) {
+            self.scope.phrasing = Some(tag_name.clone());
+        }
+        if *tag_name == *"label" {
+            self.scope.in_label = true;
+        }
+
+        element.visit_children_with(self);
+
+        self.scope = old_scope;
+    }
+}
+
+/// Elements whose content model is phrasing content.
+fn is_phrasing_only_element(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "abbr"
+            | "b"
+            | "bdi"
+            | "bdo"
+            | "button"
+            | "cite"
+            | "code"
+            | "data"
+            | "dfn"
+            | "em"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "i"
+            | "kbd"
+            | "label"
+            | "mark"
+            | "meter"
+            | "output"
+            | "p"
+            | "pre"
+            | "progress"
+            | "q"
+            | "s"
+            | "samp"
+            | "small"
+            | "span"
+            | "strong"
+            | "sub"
+            | "sup"
+            | "time"
+            | "u"
+            | "var"
+    )
+}
+
+/// Elements which are flow content but not phrasing content.
+fn is_flow_only_element(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "address"
+            | "article"
+            | "aside"
+            | "blockquote"
+            | "details"
+            | "dialog"
+            | "div"
+            | "dl"
+            | "fieldset"
+            | "figure"
+            | "footer"
+            | "form"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "header"
+            | "hgroup"
+            | "hr"
+            | "main"
+            | "menu"
+            | "nav"
+            | "ol"
+            | "p"
+            | "pre"
+            | "search"
+            | "section"
+            | "table"
+            | "ul"
+    )
+}
@@ -0,0 +1,37 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::is_obsolete_attribute,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+};
+
+pub fn no_obsolete_attributes(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), NoObsoleteAttributes { ctx })
+}
+
+#[derive(Debug, Default)]
+struct NoObsoleteAttributes {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for NoObsoleteAttributes {
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace == Namespace::HTML {
+            for attribute in &element.attributes {
+                if attribute.namespace.is_none()
+                    && is_obsolete_attribute(&element.tag_name, &attribute.name)
+                {
+                    let message = format!(
+                        "Unexpected obsolete attribute \"{}\" on <{}>.",
+                        attribute.name, element.tag_name
+                    );
+
+                    self.ctx.report(attribute, message);
+                }
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
@@ -0,0 +1,29 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::is_obsolete_element,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::start_tag_span,
+};
+
+pub fn no_obsolete_elements(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), NoObsoleteElements { ctx })
+}
+
+#[derive(Debug, Default)]
+struct NoObsoleteElements {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for NoObsoleteElements {
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace == Namespace::HTML && is_obsolete_element(&element.tag_name) {
+            let message = format!("Unexpected obsolete element <{}>.", element.tag_name);
+
+            self.ctx.report(start_tag_span(element), message);
+        }
+
+        element.visit_children_with(self);
+    }
+}
@@ -0,0 +1,51 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    util::{get_attribute, get_attribute_value, is_html_element},
+};
+
+pub fn no_target_blank(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), NoTargetBlank { ctx })
+}
+
+#[derive(Debug, Default)]
+struct NoTargetBlank {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for NoTargetBlank {
+    fn visit_element(&mut self, element: &Element) {
+        let is_navigating = ((is_html_element(element, "a") || is_html_element(element, "area"))
+            && get_attribute(element, "href").is_some())
+            || is_html_element(element, "form");
+
+        if is_navigating {
+            if let Some(target) = get_attribute(element, "target") {
+                let is_blank = target
+                    .value
+                    .as_deref()
+                    .is_some_and(|value| value.eq_ignore_ascii_case("_blank"));
+                // The opened page can access `window.opener` without them
+                let has_noopener = get_attribute_value(element, "rel").is_some_and(|rel| {
+                    rel.split_ascii_whitespace().any(|keyword| {
+                        keyword.eq_ignore_ascii_case("noopener")
+                            || keyword.eq_ignore_ascii_case("noreferrer")
+                    })
+                });
+
+                if is_blank && !has_noopener {
+                    let message = format!(
+                        "Expected rel=\"noopener\" on <{}> with target=\"_blank\".",
+                        element.tag_name
+                    );
+
+                    self.ctx.report(target, message);
+                }
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
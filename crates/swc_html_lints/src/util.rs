@@ -0,0 +1,50 @@
+use swc_common::{BytePos, Span};
+use swc_html_ast::{Attribute, Element, Namespace};
+
+/// Returns the span of `<tag` of the start tag of `element`, because the span
+/// of an element includes its children.
+pub(crate) fn start_tag_span(element: &Element) -> Span {
+    let span = element.span;
+    let hi = span.lo + BytePos(element.tag_name.len() as u32 + 1);
+
+    // Elements inserted by the parser, e.g. `<tbody>`, may have no start tag
+    if span.is_dummy() || hi > span.hi {
+        return span;
+    }
+
+    Span::new(span.lo, hi)
+}
+
+pub(crate) fn is_html_element(element: &Element, tag_name: &str) -> bool {
+    element.namespace == Namespace::HTML && element.tag_name == *tag_name
+}
+
+pub(crate) fn get_attribute<'a>(element: &'a Element, name: &str) -> Option<&'a Attribute> {
+    element
+        .attributes
+        .iter()
+        .find(|attribute| attribute.namespace.is_none() && attribute.name == *name)
+}
+
+/// Returns the value of the attribute `name`, or an empty string if the
+/// attribute has no value.
+pub(crate) fn get_attribute_value<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+    get_attribute(element, name).map(|attribute| attribute.value.as_deref().unwrap_or(""))
+}
+
+/// See https://html.spec.whatwg.org/multipage/dom.html#interactive-content
+pub(crate) fn is_interactive_element(element: &Element) -> bool {
+    if element.namespace != Namespace::HTML {
+        return false;
+    }
+
+    match &*element.tag_name {
+        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        "a" => get_attribute(element, "href").is_some(),
+        "audio" | "video" => get_attribute(element, "controls").is_some(),
+        "img" => get_attribute(element, "usemap").is_some(),
+        "input" => !get_attribute_value(element, "type")
+            .is_some_and(|value| value.eq_ignore_ascii_case("hidden")),
+        _ => false,
+    }
+}
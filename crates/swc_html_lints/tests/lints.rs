@@ -0,0 +1,80 @@
+use std::{fs, path::PathBuf};
+
+use swc_common::errors::HANDLER;
+use swc_html_lints::{get_rules, LintConfig, LintParams, LintRule};
+use swc_html_parser::parse_file_as_document;
+
+#[testing::fixture("tests/rules/pass/**/input.html")]
+fn pass(input: PathBuf) {
+    let config_path = input.parent().unwrap().join("config.json");
+    let lint_config =
+        serde_json::from_str::<LintConfig>(&fs::read_to_string(config_path).unwrap()).unwrap();
+
+    testing::run_test2(false, |cm, handler| -> Result<(), _> {
+        let fm = cm.load_file(&input).unwrap();
+        let mut errors = Vec::new();
+
+        let document = match parse_file_as_document(&fm, Default::default(), &mut errors) {
+            Ok(document) => document,
+            Err(err) => {
+                err.to_diagnostics(&handler).emit();
+                panic!();
+            }
+        };
+
+        let mut rules = get_rules(&LintParams {
+            lint_config: &lint_config,
+        })
+        .unwrap();
+
+        HANDLER.set(&handler, || {
+            rules.lint_document(&document);
+        });
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/rules/fail/**/input.html")]
+fn fail(input: PathBuf) {
+    let stderr_path = input.parent().unwrap().join("output.swc-stderr");
+    let config_path = input.parent().unwrap().join("config.json");
+    let lint_config =
+        serde_json::from_str::<LintConfig>(&fs::read_to_string(config_path).unwrap()).unwrap();
+
+    let stderr = testing::run_test2(false, |cm, handler| -> Result<(), _> {
+        let fm = cm.load_file(&input).unwrap();
+        let mut errors = Vec::new();
+
+        let document = match parse_file_as_document(&fm, Default::default(), &mut errors) {
+            Ok(document) => document,
+            Err(err) => {
+                err.to_diagnostics(&handler).emit();
+                panic!();
+            }
+        };
+
+        let mut rules = get_rules(&LintParams {
+            lint_config: &lint_config,
+        })
+        .unwrap();
+
+        HANDLER.set(&handler, || {
+            rules.lint_document(&document);
+        });
+
+        if !handler.has_errors() {
+            panic!("should error");
+        }
+
+        Err(())
+    })
+    .unwrap_err();
+
+    stderr.compare_to_file(stderr_path).unwrap();
+}
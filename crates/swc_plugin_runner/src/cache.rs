@@ -19,7 +19,7 @@ use wasmer_cache::{Cache as WasmerCache, FileSystemCache, Hash};
 
 use crate::{
     plugin_module_bytes::{CompiledPluginModuleBytes, PluginModuleBytes, RawPluginModuleBytes},
-    wasix_runtime::new_store,
+    wasix_runtime::{new_compiler_store, new_store},
 };
 
 /// Version for bytecode cache stored in local filesystem.
@@ -30,7 +30,7 @@ use crate::{
 /// however it is not gauranteed to be compatible across wasmer's
 /// internal changes.
 /// https://github.com/wasmerio/wasmer/issues/2781
-const MODULE_SERIALIZATION_VERSION: &str = "v7";
+const MODULE_SERIALIZATION_VERSION: &str = "v8";
 
 #[derive(Default)]
 pub struct PluginModuleCacheInner {
@@ -113,7 +113,7 @@ impl PluginModuleCacheInner {
             // If FilesystemCache is available, store serialized bytes into fs.
             if let Some(fs_cache_store) = &mut self.fs_cache_store {
                 let module_bytes_hash = Hash::generate(&raw_module_bytes);
-                let store = new_store();
+                let store = new_compiler_store();
 
                 let module =
                     if let Ok(module) = unsafe { fs_cache_store.load(&store, module_bytes_hash) } {
@@ -12,6 +12,9 @@ mod imported_fn;
 #[cfg(feature = "__rkyv")]
 mod memory_interop;
 pub mod plugin_module_bytes;
+pub mod sandbox;
+#[cfg(feature = "__rkyv")]
+pub mod schema;
 mod transform_executor;
 pub mod wasix_runtime;
 
@@ -1,7 +1,10 @@
+use anyhow::Error;
 use swc_common::plugin::serialized::{PluginSerializedBytes, VersionedSerializable};
 use swc_plugin_proxy::AllocatedBytesPtr;
 use wasmer::{Memory, MemoryView, StoreMut, TypedFunction, WasmPtr};
 
+use crate::schema::{self, SchemaAdapterError, HOST_SCHEMA_VERSION};
+
 #[tracing::instrument(level = "info", skip_all)]
 pub fn copy_bytes_into_host(memory: &MemoryView, bytes_ptr: i32, bytes_ptr_len: i32) -> Vec<u8> {
     let ptr: WasmPtr<u8> = WasmPtr::new(bytes_ptr as _);
@@ -45,6 +48,52 @@ where
     )
 }
 
+/// Write a serialized `Program` of the host into the guest, after converting
+/// it into the AST schema version of the plugin.
+#[tracing::instrument(level = "info", skip_all)]
+pub fn write_program_into_memory_view<F>(
+    memory: &Memory,
+    store: &mut StoreMut,
+    program: &PluginSerializedBytes,
+    plugin_schema_version: u32,
+    alloc_guest_memory: F,
+) -> Result<(u32, u32), Error>
+where
+    F: FnOnce(&mut StoreMut, usize) -> Result<u32, Error>,
+{
+    let adapted;
+    let program = match HOST_SCHEMA_VERSION {
+        Some(host_schema_version) if host_schema_version != plugin_schema_version => {
+            adapted = schema::adapt(program, host_schema_version, plugin_schema_version)?;
+            &adapted
+        }
+        _ => program,
+    };
+
+    let guest_ptr = alloc_guest_memory(store, program.as_ptr().1)?;
+
+    Ok(write_into_memory_view(memory, store, program, |_, _| {
+        guest_ptr
+    }))
+}
+
+/// Construct a serialized `Program` from the bytes returned by the guest,
+/// converting it into the AST schema version of the host.
+#[tracing::instrument(level = "info", skip_all)]
+pub fn read_program_from_guest(
+    bytes: &[u8],
+    plugin_schema_version: u32,
+) -> Result<PluginSerializedBytes, SchemaAdapterError> {
+    let program = PluginSerializedBytes::from_slice(bytes);
+
+    match HOST_SCHEMA_VERSION {
+        Some(host_schema_version) if host_schema_version != plugin_schema_version => {
+            schema::adapt(&program, plugin_schema_version, host_schema_version)
+        }
+        _ => Ok(program),
+    }
+}
+
 /// Set `return` value to pass into guest from functions returning values with
 /// non-deterministic size like `Vec<Comment>`. Guest pre-allocates a struct to
 /// contain ptr to the value, host in here allocates guest memory for the actual
@@ -2,7 +2,7 @@ use anyhow::Error;
 use serde::{Deserialize, Serialize};
 use wasmer::{Module, Store};
 
-use crate::wasix_runtime::new_store;
+use crate::wasix_runtime::new_compiler_store;
 
 // A trait abstracts plugin's wasm compilation and instantiation.
 // Depends on the caller, this could be a simple clone from existing module, or
@@ -28,7 +28,7 @@ impl PluginModuleBytes for RawPluginModuleBytes {
     }
 
     fn compile_module(&self) -> Result<(Store, Module), Error> {
-        let store = new_store();
+        let store = new_compiler_store();
         let module = Module::new(&store, &self.bytes)?;
         Ok((store, module))
     }
@@ -0,0 +1,572 @@
+//! Resource limits and capabilities of a plugin.
+//!
+//! A plugin can't access the filesystem or the environment variables of the
+//! host unless they are granted by [PluginSandbox]. Fuel and memory limits
+//! stop a plugin that doesn't terminate or allocates too much, and
+//! [PluginSandbox::deterministic] makes the output of a plugin reproducible.
+
+use std::{
+    fmt,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use parking_lot::Mutex;
+use wasmer::{AsStoreMut, Function, Imports, Instance, Module, RuntimeError, Store, Value};
+
+/// The name of the exported global holding the fuel a plugin has left.
+const REMAINING_FUEL_EXPORT: &str = "__swc_remaining_fuel";
+/// The name of the exported global set to 1 when a plugin runs out of fuel.
+const FUEL_EXHAUSTED_EXPORT: &str = "__swc_fuel_exhausted";
+
+/// WASI functions returning a different value on each call.
+const NONDETERMINISTIC_IMPORTS: &[&str] = &["clock_res_get", "clock_time_get", "random_get"];
+
+/// Limits and capabilities of a plugin.
+///
+/// The default configuration doesn't limit fuel or memory, and denies access
+/// to the filesystem and the environment variables.
+#[derive(Debug, Clone, Default)]
+pub struct PluginSandbox {
+    /// The number of Wasm instructions a plugin can execute, including the
+    /// handshake with the host.
+    pub fuel: Option<u64>,
+    /// The maximum size of the linear memory of a plugin, in bytes.
+    pub max_memory: Option<u64>,
+    /// Host directories a WASI plugin can access, as `(guest path, host
+    /// path)` pairs.
+    pub mapped_dirs: Vec<(String, PathBuf)>,
+    /// Environment variables of the host a WASI plugin can read.
+    pub env_vars: Vec<swc_atoms::Atom>,
+    /// If true, calls to clocks or random number generators fail with
+    /// [PluginLimitError::Nondeterministic].
+    pub deterministic: bool,
+}
+
+/// An error returned when a plugin exceeds the limits of its
+/// [PluginSandbox].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginLimitError {
+    /// The plugin executed more instructions than
+    /// [PluginSandbox::fuel].
+    FuelExhausted { plugin: String, fuel: u64 },
+    /// The plugin tried to grow its memory over
+    /// [PluginSandbox::max_memory].
+    MemoryExceeded { plugin: String, max_memory: u64 },
+    /// The plugin called a clock or a random number generator in
+    /// deterministic mode.
+    Nondeterministic { plugin: String, import: String },
+}
+
+impl fmt::Display for PluginLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginLimitError::FuelExhausted { plugin, fuel } => {
+                write!(f, "plugin `{plugin}` exceeded its fuel limit of {fuel}")
+            }
+            PluginLimitError::MemoryExceeded { plugin, max_memory } => write!(
+                f,
+                "plugin `{plugin}` exceeded its memory limit of {max_memory} bytes"
+            ),
+            PluginLimitError::Nondeterministic { plugin, import } => write!(
+                f,
+                "plugin `{plugin}` called `{import}`, which is not allowed in deterministic mode"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PluginLimitError {}
+
+/// Tracks whether a running plugin violated its [PluginSandbox].
+#[derive(Debug)]
+pub(crate) struct SandboxGuard {
+    plugin_name: String,
+    sandbox: PluginSandbox,
+    memory_exceeded: Arc<AtomicBool>,
+    nondeterministic_import: Arc<Mutex<Option<String>>>,
+}
+
+impl SandboxGuard {
+    pub fn new(plugin_name: &str, sandbox: &PluginSandbox) -> Self {
+        Self {
+            plugin_name: plugin_name.to_string(),
+            sandbox: sandbox.clone(),
+            memory_exceeded: Default::default(),
+            nondeterministic_import: Default::default(),
+        }
+    }
+
+    pub fn sandbox(&self) -> &PluginSandbox {
+        &self.sandbox
+    }
+
+    /// Returns a store for `store` whose memories are limited to
+    /// [PluginSandbox::max_memory].
+    ///
+    /// `store` must not contain any objects yet.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn limit_memory(&self, store: Store) -> Store {
+        use wasmer::{sys::NativeEngineExt, Pages, WASM_MAX_PAGES, WASM_PAGE_SIZE};
+
+        let Some(max_memory) = self.sandbox.max_memory else {
+            return store;
+        };
+
+        let mut engine = store.engine().clone();
+        let tunables = LimitingTunables {
+            base: wasmer::sys::BaseTunables::for_target(engine.target()),
+            max_pages: Pages(
+                (max_memory / WASM_PAGE_SIZE as u64).min(WASM_MAX_PAGES as u64) as u32,
+            ),
+            exceeded: self.memory_exceeded.clone(),
+        };
+        engine.set_tunables(tunables);
+
+        Store::new(engine)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn limit_memory(&self, store: Store) -> Store {
+        store
+    }
+
+    /// Replaces the imports of `module` returning clocks or random numbers
+    /// with functions failing with [PluginLimitError::Nondeterministic] in
+    /// deterministic mode.
+    pub fn deny_nondeterministic_imports(
+        &self,
+        store: &mut Store,
+        module: &Module,
+        import_object: &mut Imports,
+    ) {
+        if !self.sandbox.deterministic {
+            return;
+        }
+
+        for import in module.imports().functions() {
+            if !import.module().starts_with("wasi")
+                || !NONDETERMINISTIC_IMPORTS.contains(&import.name())
+            {
+                continue;
+            }
+
+            let import_name = import.name().to_string();
+            let nondeterministic_import = self.nondeterministic_import.clone();
+            let function = Function::new(store, import.ty().clone(), move |_| {
+                *nondeterministic_import.lock() = Some(import_name.clone());
+                Err(RuntimeError::new(format!(
+                    "`{import_name}` is not allowed in deterministic mode"
+                )))
+            });
+
+            import_object.define(import.module(), import.name(), function);
+        }
+    }
+
+    /// Gives the plugin [PluginSandbox::fuel] to run.
+    ///
+    /// Modules compiled by this crate are always metered, but modules
+    /// compiled elsewhere can't be limited.
+    pub fn refuel(&self, store: &mut impl AsStoreMut, instance: &Instance) -> anyhow::Result<()> {
+        match (
+            instance.exports.get_global(REMAINING_FUEL_EXPORT),
+            self.sandbox.fuel,
+        ) {
+            (Ok(remaining_fuel), fuel) => {
+                remaining_fuel.set(store, Value::I64(fuel.unwrap_or(u64::MAX) as i64))?;
+                Ok(())
+            }
+            (Err(_), None) => Ok(()),
+            (Err(_), Some(_)) => anyhow::bail!(
+                "plugin `{}` is not compiled with fuel metering, so its fuel can't be limited",
+                self.plugin_name
+            ),
+        }
+    }
+
+    /// Replaces `err` of a failed call into the plugin with the limit the
+    /// plugin exceeded, if any.
+    pub fn error(
+        &self,
+        store: &mut impl AsStoreMut,
+        instance: Option<&Instance>,
+        err: anyhow::Error,
+    ) -> anyhow::Error {
+        match self.violation(store, instance) {
+            Some(violation) => violation.into(),
+            None => err,
+        }
+    }
+
+    /// Returns the limit the plugin exceeded, if any.
+    fn violation(
+        &self,
+        store: &mut impl AsStoreMut,
+        instance: Option<&Instance>,
+    ) -> Option<PluginLimitError> {
+        let plugin = self.plugin_name.clone();
+
+        if let Some(import) = self.nondeterministic_import.lock().clone() {
+            return Some(PluginLimitError::Nondeterministic { plugin, import });
+        }
+
+        if self.memory_exceeded.load(Ordering::Relaxed) {
+            return Some(PluginLimitError::MemoryExceeded {
+                plugin,
+                max_memory: self.sandbox.max_memory.unwrap_or_default(),
+            });
+        }
+
+        let fuel_exhausted = instance
+            .and_then(|instance| instance.exports.get_global(FUEL_EXHAUSTED_EXPORT).ok())
+            .is_some_and(|exhausted| matches!(exhausted.get(store), Value::I32(1)));
+        if fuel_exhausted {
+            return Some(PluginLimitError::FuelExhausted {
+                plugin,
+                fuel: self.sandbox.fuel.unwrap_or(u64::MAX),
+            });
+        }
+
+        None
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+use self::sys::LimitingTunables;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use self::sys::Metering;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod sys {
+    use std::{
+        ptr::NonNull,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use parking_lot::Mutex;
+    use wasmer::{
+        sys::{
+            vm::{
+                LinearMemory, MemoryError, NotifyLocation, ThreadConditions, Trap, VMMemory,
+                VMMemoryDefinition, VMTable, VMTableDefinition, WaiterError,
+            },
+            wasmparser::{BlockType, Operator},
+            BaseTunables, FunctionMiddleware, MiddlewareReaderState, ModuleMiddleware, Tunables,
+        },
+        ExportIndex, GlobalInit, GlobalType, LocalFunctionIndex, MemoryStyle, MemoryType,
+        Mutability, Pages, TableStyle, TableType, Type,
+    };
+    use wasmer_types::{GlobalIndex, MiddlewareError, ModuleInfo};
+
+    use super::{FUEL_EXHAUSTED_EXPORT, REMAINING_FUEL_EXPORT};
+
+    /// Tunables limiting the maximum size of memories.
+    pub(crate) struct LimitingTunables {
+        pub base: BaseTunables,
+        pub max_pages: Pages,
+        pub exceeded: Arc<AtomicBool>,
+    }
+
+    impl LimitingTunables {
+        fn limit(&self, ty: &MemoryType) -> Result<MemoryType, MemoryError> {
+            if ty.minimum > self.max_pages {
+                self.exceeded.store(true, Ordering::Relaxed);
+                return Err(MemoryError::MinimumMemoryTooLarge {
+                    min_requested: ty.minimum,
+                    max_allowed: self.max_pages,
+                });
+            }
+
+            let mut ty = *ty;
+            ty.maximum = Some(
+                ty.maximum
+                    .map_or(self.max_pages, |max| max.min(self.max_pages)),
+            );
+            Ok(ty)
+        }
+
+        fn wrap(&self, memory: VMMemory) -> VMMemory {
+            VMMemory(Box::new(LimitedMemory {
+                inner: memory.0,
+                max_pages: self.max_pages,
+                exceeded: self.exceeded.clone(),
+            }))
+        }
+    }
+
+    impl Tunables for LimitingTunables {
+        fn memory_style(&self, memory: &MemoryType) -> MemoryStyle {
+            self.base.memory_style(memory)
+        }
+
+        fn table_style(&self, table: &TableType) -> TableStyle {
+            self.base.table_style(table)
+        }
+
+        fn create_host_memory(
+            &self,
+            ty: &MemoryType,
+            style: &MemoryStyle,
+        ) -> Result<VMMemory, MemoryError> {
+            let ty = self.limit(ty)?;
+            Ok(self.wrap(self.base.create_host_memory(&ty, style)?))
+        }
+
+        unsafe fn create_vm_memory(
+            &self,
+            ty: &MemoryType,
+            style: &MemoryStyle,
+            vm_definition_location: NonNull<VMMemoryDefinition>,
+        ) -> Result<VMMemory, MemoryError> {
+            let ty = self.limit(ty)?;
+            Ok(self.wrap(
+                self.base
+                    .create_vm_memory(&ty, style, vm_definition_location)?,
+            ))
+        }
+
+        fn create_host_table(&self, ty: &TableType, style: &TableStyle) -> Result<VMTable, String> {
+            self.base.create_host_table(ty, style)
+        }
+
+        unsafe fn create_vm_table(
+            &self,
+            ty: &TableType,
+            style: &TableStyle,
+            vm_definition_location: NonNull<VMTableDefinition>,
+        ) -> Result<VMTable, String> {
+            self.base.create_vm_table(ty, style, vm_definition_location)
+        }
+    }
+
+    /// A memory recording attempts to grow over its limit.
+    #[derive(Debug)]
+    struct LimitedMemory {
+        inner: Box<dyn LinearMemory + 'static>,
+        max_pages: Pages,
+        exceeded: Arc<AtomicBool>,
+    }
+
+    impl LinearMemory for LimitedMemory {
+        fn ty(&self) -> MemoryType {
+            self.inner.ty()
+        }
+
+        fn size(&self) -> Pages {
+            self.inner.size()
+        }
+
+        fn style(&self) -> MemoryStyle {
+            self.inner.style()
+        }
+
+        fn grow(&mut self, delta: Pages) -> Result<Pages, MemoryError> {
+            let current = self.inner.size();
+            if current.0.saturating_add(delta.0) > self.max_pages.0 {
+                self.exceeded.store(true, Ordering::Relaxed);
+                return Err(MemoryError::CouldNotGrow {
+                    current,
+                    attempted_delta: delta,
+                });
+            }
+
+            self.inner.grow(delta)
+        }
+
+        fn grow_at_least(&mut self, min_size: u64) -> Result<(), MemoryError> {
+            self.inner.grow_at_least(min_size)
+        }
+
+        fn reset(&mut self) -> Result<(), MemoryError> {
+            self.inner.reset()
+        }
+
+        fn vmmemory(&self) -> NonNull<VMMemoryDefinition> {
+            self.inner.vmmemory()
+        }
+
+        fn try_clone(&self) -> Result<Box<dyn LinearMemory + 'static>, MemoryError> {
+            self.inner.try_clone()
+        }
+
+        unsafe fn initialize_with_data(&self, start: usize, data: &[u8]) -> Result<(), Trap> {
+            self.inner.initialize_with_data(start, data)
+        }
+
+        fn copy(&mut self) -> Result<Box<dyn LinearMemory + 'static>, MemoryError> {
+            self.inner.copy()
+        }
+
+        fn do_wait(
+            &mut self,
+            dst: NotifyLocation,
+            timeout: Option<Duration>,
+        ) -> Result<u32, WaiterError> {
+            self.inner.do_wait(dst, timeout)
+        }
+
+        fn do_notify(&mut self, dst: NotifyLocation, count: u32) -> u32 {
+            self.inner.do_notify(dst, count)
+        }
+
+        fn thread_conditions(&self) -> Option<&ThreadConditions> {
+            self.inner.thread_conditions()
+        }
+    }
+
+    /// A middleware charging one unit of fuel per instruction.
+    ///
+    /// Every module gets a global with its remaining fuel, which is checked
+    /// and decremented at the end of each basic block. The module traps if
+    /// it's not enough for the block.
+    ///
+    /// An instance keeps the globals of the module it compiles, so it must
+    /// not be used for more than one module. See
+    /// [new_compiler_store](crate::wasix_runtime::new_compiler_store).
+    #[derive(Debug, Default)]
+    pub(crate) struct Metering {
+        /// The globals of the module being compiled.
+        globals: Mutex<Option<MeteringGlobals>>,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct MeteringGlobals {
+        remaining_fuel: GlobalIndex,
+        fuel_exhausted: GlobalIndex,
+    }
+
+    impl ModuleMiddleware for Metering {
+        fn generate_function_middleware(
+            &self,
+            _: LocalFunctionIndex,
+        ) -> Box<dyn FunctionMiddleware> {
+            Box::new(FunctionMetering {
+                globals: (*self.globals.lock())
+                    .expect("module info should be transformed before functions"),
+                cost: 0,
+            })
+        }
+
+        fn transform_module_info(
+            &self,
+            module_info: &mut ModuleInfo,
+        ) -> Result<(), MiddlewareError> {
+            let mut globals = self.globals.lock();
+            if globals.is_some() {
+                return Err(MiddlewareError::new(
+                    "Metering",
+                    "a `Metering` middleware can't be used for multiple modules",
+                ));
+            }
+
+            let remaining_fuel = module_info
+                .globals
+                .push(GlobalType::new(Type::I64, Mutability::Var));
+            module_info
+                .global_initializers
+                .push(GlobalInit::I64Const(u64::MAX as i64));
+            module_info.exports.insert(
+                REMAINING_FUEL_EXPORT.to_string(),
+                ExportIndex::Global(remaining_fuel),
+            );
+
+            let fuel_exhausted = module_info
+                .globals
+                .push(GlobalType::new(Type::I32, Mutability::Var));
+            module_info
+                .global_initializers
+                .push(GlobalInit::I32Const(0));
+            module_info.exports.insert(
+                FUEL_EXHAUSTED_EXPORT.to_string(),
+                ExportIndex::Global(fuel_exhausted),
+            );
+
+            *globals = Some(MeteringGlobals {
+                remaining_fuel,
+                fuel_exhausted,
+            });
+
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct FunctionMetering {
+        globals: MeteringGlobals,
+        /// The cost of the current basic block.
+        cost: u64,
+    }
+
+    impl FunctionMiddleware for FunctionMetering {
+        fn feed<'a>(
+            &mut self,
+            operator: Operator<'a>,
+            state: &mut MiddlewareReaderState<'a>,
+        ) -> Result<(), MiddlewareError> {
+            self.cost += 1;
+
+            let ends_block = matches!(
+                operator,
+                Operator::Loop { .. }
+                    | Operator::End
+                    | Operator::If { .. }
+                    | Operator::Else
+                    | Operator::Br { .. }
+                    | Operator::BrIf { .. }
+                    | Operator::BrTable { .. }
+                    | Operator::Unreachable
+                    | Operator::Return
+                    | Operator::Call { .. }
+                    | Operator::CallIndirect { .. }
+                    | Operator::ReturnCall { .. }
+                    | Operator::ReturnCallIndirect { .. }
+            );
+
+            if ends_block {
+                let remaining_fuel = self.globals.remaining_fuel.as_u32();
+                let cost = self.cost as i64;
+
+                state.extend([
+                    // if remaining_fuel < cost { fuel_exhausted = 1; unreachable }
+                    Operator::GlobalGet {
+                        global_index: remaining_fuel,
+                    },
+                    Operator::I64Const { value: cost },
+                    Operator::I64LtU,
+                    Operator::If {
+                        blockty: BlockType::Empty,
+                    },
+                    Operator::I32Const { value: 1 },
+                    Operator::GlobalSet {
+                        global_index: self.globals.fuel_exhausted.as_u32(),
+                    },
+                    Operator::Unreachable,
+                    Operator::End,
+                    // remaining_fuel -= cost
+                    Operator::GlobalGet {
+                        global_index: remaining_fuel,
+                    },
+                    Operator::I64Const { value: cost },
+                    Operator::I64Sub,
+                    Operator::GlobalSet {
+                        global_index: remaining_fuel,
+                    },
+                ]);
+
+                self.cost = 0;
+            }
+
+            state.push_operator(operator);
+
+            Ok(())
+        }
+    }
+}
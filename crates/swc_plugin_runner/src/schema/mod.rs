@@ -0,0 +1,135 @@
+//! Adapters between AST schema versions.
+//!
+//! A plugin reports the AST schema version of the `swc_core` it's built
+//! against. If it differs from the version of the host, the serialized
+//! `Program` passed to the plugin is downgraded to the version of the plugin,
+//! and the program returned by the plugin is upgraded back to the version of
+//! the host, one version at a time.
+
+use std::fmt;
+
+use swc_common::{plugin::serialized::PluginSerializedBytes, Span};
+
+#[cfg(feature = "ecma")]
+pub use self::v2::SchemaV2Adapter;
+
+#[cfg(feature = "ecma")]
+mod v2;
+
+/// The AST schema version of the host, if it's compiled with one.
+#[cfg(any(
+    feature = "plugin_transform_schema_v1",
+    feature = "plugin_transform_schema_v2",
+    feature = "plugin_transform_schema_vtest"
+))]
+pub(crate) const HOST_SCHEMA_VERSION: Option<u32> =
+    Some(swc_common::plugin::PLUGIN_TRANSFORM_AST_SCHEMA_VERSION);
+#[cfg(not(any(
+    feature = "plugin_transform_schema_v1",
+    feature = "plugin_transform_schema_v2",
+    feature = "plugin_transform_schema_vtest"
+)))]
+pub(crate) const HOST_SCHEMA_VERSION: Option<u32> = None;
+
+/// Adapters of the runner, ordered by version.
+#[cfg(feature = "ecma")]
+const ADAPTERS: &[&dyn SchemaAdapter] = &[&SchemaV2Adapter];
+#[cfg(not(feature = "ecma"))]
+const ADAPTERS: &[&dyn SchemaAdapter] = &[];
+
+/// Converts serialized programs between two adjacent AST schema versions.
+pub trait SchemaAdapter: Send + Sync {
+    /// The version this adapter upgrades to. Programs are downgraded from
+    /// this version to `version() - 1`.
+    fn version(&self) -> u32;
+
+    /// Converts a program of `version() - 1` into `version()`.
+    fn upgrade(
+        &self,
+        program: &PluginSerializedBytes,
+    ) -> Result<PluginSerializedBytes, SchemaAdapterError>;
+
+    /// Converts a program of `version()` into `version() - 1`.
+    fn downgrade(
+        &self,
+        program: &PluginSerializedBytes,
+    ) -> Result<PluginSerializedBytes, SchemaAdapterError>;
+}
+
+/// An error returned when a program can't be converted between AST schema
+/// versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaAdapterError {
+    /// There are no adapters between the versions.
+    Unsupported { from: u32, to: u32 },
+    /// The program contains a node which doesn't exist in `version`.
+    Unrepresentable {
+        node: &'static str,
+        span: Span,
+        version: u32,
+    },
+    /// The program couldn't be deserialized or serialized.
+    Serialization(String),
+}
+
+impl fmt::Display for SchemaAdapterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaAdapterError::Unsupported { from, to } => write!(
+                f,
+                "cannot convert a program of AST schema version {from} into version {to}"
+            ),
+            SchemaAdapterError::Unrepresentable { node, version, .. } => write!(
+                f,
+                "`{node}` cannot be represented in AST schema version {version}"
+            ),
+            SchemaAdapterError::Serialization(msg) => {
+                write!(f, "failed to convert a program between AST schemas: {msg}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SchemaAdapterError {}
+
+fn find_adapter(version: u32) -> Option<&'static dyn SchemaAdapter> {
+    ADAPTERS
+        .iter()
+        .copied()
+        .find(|adapter| adapter.version() == version)
+}
+
+/// Returns true if programs of the AST schema version `from` can be
+/// converted into `to`.
+pub fn is_supported(from: u32, to: u32) -> bool {
+    let (min, max) = (from.min(to), from.max(to));
+
+    // Avoids iterating over the versions reserved for testing.
+    max - min <= ADAPTERS.len() as u32 && (min + 1..=max).all(|v| find_adapter(v).is_some())
+}
+
+/// Converts a serialized program of the AST schema version `from` into `to`.
+pub fn adapt(
+    program: &PluginSerializedBytes,
+    from: u32,
+    to: u32,
+) -> Result<PluginSerializedBytes, SchemaAdapterError> {
+    if !is_supported(from, to) {
+        return Err(SchemaAdapterError::Unsupported { from, to });
+    }
+
+    let mut program = PluginSerializedBytes::from_slice(program.as_slice());
+    if from < to {
+        for version in from + 1..=to {
+            let adapter = find_adapter(version).expect("should be checked by is_supported");
+            program = adapter.upgrade(&program)?;
+        }
+    } else {
+        for version in (to + 1..=from).rev() {
+            let adapter = find_adapter(version).expect("should be checked by is_supported");
+            program = adapter.downgrade(&program)?;
+        }
+    }
+
+    Ok(program)
+}
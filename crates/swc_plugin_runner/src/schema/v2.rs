@@ -0,0 +1,78 @@
+use swc_common::{plugin::serialized::PluginSerializedBytes, Span};
+use swc_ecma_ast::{Expr, PipelineExpr, Program, TopicRef};
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use super::{SchemaAdapter, SchemaAdapterError};
+
+/// Converts programs between AST schema version 1 and 2.
+///
+/// Version 2 appends [Expr::Pipeline] and [Expr::TopicRef] to [Expr], so a
+/// program of version 1 is a valid program of version 2 as-is. A program of
+/// version 2 can only be converted into version 1 if it contains neither of
+/// them.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SchemaV2Adapter;
+
+impl SchemaAdapter for SchemaV2Adapter {
+    fn version(&self) -> u32 {
+        2
+    }
+
+    fn upgrade(
+        &self,
+        program: &PluginSerializedBytes,
+    ) -> Result<PluginSerializedBytes, SchemaAdapterError> {
+        Ok(PluginSerializedBytes::from_slice(program.as_slice()))
+    }
+
+    fn downgrade(
+        &self,
+        program: &PluginSerializedBytes,
+    ) -> Result<PluginSerializedBytes, SchemaAdapterError> {
+        let deserialized: Program = program
+            .deserialize()
+            .map_err(|err| SchemaAdapterError::Serialization(format!("{err:#}")))?
+            .into_inner();
+
+        if let Some((node, span)) = find_v2_node(&deserialized) {
+            return Err(SchemaAdapterError::Unrepresentable {
+                node,
+                span,
+                version: 1,
+            });
+        }
+
+        Ok(PluginSerializedBytes::from_slice(program.as_slice()))
+    }
+}
+
+/// Returns the name and the span of the first node added in version 2.
+fn find_v2_node(program: &Program) -> Option<(&'static str, Span)> {
+    let mut finder = V2NodeFinder { found: None };
+    program.visit_with(&mut finder);
+    finder.found
+}
+
+struct V2NodeFinder {
+    found: Option<(&'static str, Span)>,
+}
+
+impl Visit for V2NodeFinder {
+    noop_visit_type!();
+
+    fn visit_expr(&mut self, e: &Expr) {
+        if self.found.is_some() {
+            return;
+        }
+
+        match e {
+            Expr::Pipeline(PipelineExpr { span, .. }) => {
+                self.found = Some(("PipelineExpression", *span));
+            }
+            Expr::TopicRef(TopicRef { span }) => {
+                self.found = Some(("TopicReference", *span));
+            }
+            _ => e.visit_children_with(self),
+        }
+    }
+}
@@ -4,11 +4,6 @@ use anyhow::{anyhow, Context, Error};
 use parking_lot::Mutex;
 #[cfg(feature = "__rkyv")]
 use swc_common::plugin::serialized::{PluginError, PluginSerializedBytes};
-#[cfg(any(
-    feature = "plugin_transform_schema_v1",
-    feature = "plugin_transform_schema_vtest"
-))]
-use swc_common::plugin::PLUGIN_TRANSFORM_AST_SCHEMA_VERSION;
 use swc_common::{
     plugin::{diagnostics::PluginCorePkgDiagnostics, metadata::TransformPluginMetadataContext},
     SourceMap,
@@ -25,9 +20,14 @@ use crate::{
         metadata_context::MetadataContextHostEnvironment,
         set_transform_result::TransformResultHostEnvironment, source_map::SourceMapHostEnvironment,
     },
-    memory_interop::write_into_memory_view,
+    memory_interop::{read_program_from_guest, write_program_into_memory_view},
+    schema::{self, SchemaAdapterError, HOST_SCHEMA_VERSION},
+};
+use crate::{
+    plugin_module_bytes::PluginModuleBytes,
+    sandbox::{PluginLimitError, PluginSandbox, SandboxGuard},
+    wasix_runtime::build_wasi_runtime,
 };
-use crate::{plugin_module_bytes::PluginModuleBytes, wasix_runtime::build_wasi_runtime};
 
 /// An internal state to the plugin transform.
 struct PluginTransformState {
@@ -43,7 +43,7 @@ struct PluginTransformState {
     instance: Instance,
     store: Store,
     transform_result: Arc<Mutex<Vec<u8>>>,
-    #[allow(unused)]
+    sandbox_guard: SandboxGuard,
     plugin_core_diag: PluginCorePkgDiagnostics,
 }
 
@@ -55,47 +55,51 @@ impl PluginTransformState {
         unresolved_mark: swc_common::Mark,
         should_enable_comments_proxy: Option<bool>,
     ) -> Result<PluginSerializedBytes, Error> {
-        let memory = self.instance.exports.get_memory("memory")?;
-
         let should_enable_comments_proxy =
             u32::from(should_enable_comments_proxy.unwrap_or_default());
 
         // Copy host's serialized bytes into guest (plugin)'s allocated memory.
-        let guest_program_ptr = write_into_memory_view(
-            memory,
+        // Allocation can fail if the plugin is out of fuel or memory.
+        let memory = self.instance.exports.get_memory("memory")?.clone();
+        let alloc = self.exported_plugin_alloc.clone();
+        let written = write_program_into_memory_view(
+            &memory,
             &mut self.store.as_store_mut(),
             program,
-            |s, serialized_len| {
-                self.exported_plugin_alloc
-                    .call(
-                        s,
-                        serialized_len
-                            .try_into()
-                            .expect("Should able to convert size"),
-                    )
-                    .unwrap_or_else(|_| {
-                        panic!("Should able to allocate memory for the size of {serialized_len}")
-                    })
+            self.plugin_core_diag.ast_schema_version,
+            |store, serialized_len| {
+                Ok(alloc.call(
+                    store,
+                    serialized_len
+                        .try_into()
+                        .expect("Should able to convert size"),
+                )?)
             },
         );
-
-        let returned_ptr_result = self.exported_plugin_transform.call(
-            &mut self.store,
-            guest_program_ptr.0,
-            guest_program_ptr.1,
-            unresolved_mark.as_u32(),
-            should_enable_comments_proxy,
-        )?;
+        let guest_program_ptr = written.map_err(|err| self.sandbox_error(err))?;
+
+        let returned_ptr_result = self
+            .exported_plugin_transform
+            .call(
+                &mut self.store,
+                guest_program_ptr.0,
+                guest_program_ptr.1,
+                unresolved_mark.as_u32(),
+                should_enable_comments_proxy,
+            )
+            .map_err(|err| self.sandbox_error(err.into()))?;
 
         // Copy guest's memory into host, construct serialized struct from raw
         // bytes.
         let transformed_result = &(*self.transform_result.lock());
-        let ret = PluginSerializedBytes::from_slice(&transformed_result[..]);
 
         let ret = if returned_ptr_result == 0 {
-            Ok(ret)
+            read_program_from_guest(transformed_result, self.plugin_core_diag.ast_schema_version)
+                .map_err(Error::from)
         } else {
-            let err: PluginError = ret.deserialize()?.into_inner();
+            let err: PluginError = PluginSerializedBytes::from_slice(&transformed_result[..])
+                .deserialize()?
+                .into_inner();
             match err {
                 PluginError::SizeInteropFailure(msg) => Err(anyhow!(
                     "Failed to convert pointer size to calculate: {}",
@@ -128,43 +132,39 @@ impl PluginTransformState {
         ret
     }
 
+    fn sandbox_error(&mut self, err: Error) -> Error {
+        self.sandbox_guard
+            .error(&mut self.store, Some(&self.instance), err)
+    }
+
     /**
      * Check compile-time version of AST schema between the plugin and
-     * the host. Returns true if it's compatible, false otherwise.
+     * the host. The versions are compatible if they're same, or if
+     * [schema] can convert programs between them.
      *
      * Host should appropriately handle if plugin is not compatible to the
      * current runtime.
      */
-    #[allow(unreachable_code)]
     pub fn is_transform_schema_compatible(&mut self) -> Result<(), Error> {
-        #[cfg(any(
-            feature = "plugin_transform_schema_v1",
-            feature = "plugin_transform_schema_vtest"
-        ))]
-        return {
-            let host_schema_version = PLUGIN_TRANSFORM_AST_SCHEMA_VERSION;
-
-            // TODO: this is incomplete
-            if host_schema_version >= self.plugin_core_diag.ast_schema_version {
-                Ok(())
-            } else {
-                anyhow::bail!(
-                    "Plugin's AST schema version is not compatible with host's. Host: {}, Plugin: \
-                     {}",
-                    host_schema_version,
-                    self.plugin_core_diag.ast_schema_version
-                )
-            }
+        let Some(host_schema_version) = HOST_SCHEMA_VERSION else {
+            anyhow::bail!(
+                "Plugin runner cannot detect plugin's schema version. Ensure host is compiled \
+                 with proper versions"
+            )
         };
 
-        #[cfg(not(all(
-            feature = "plugin_transform_schema_v1",
-            feature = "plugin_transform_schema_vtest"
-        )))]
-        anyhow::bail!(
-            "Plugin runner cannot detect plugin's schema version. Ensure host is compiled with \
-             proper versions"
-        )
+        if schema::is_supported(
+            self.plugin_core_diag.ast_schema_version,
+            host_schema_version,
+        ) {
+            Ok(())
+        } else {
+            anyhow::bail!(
+                "Plugin's AST schema version is not compatible with host's. Host: {}, Plugin: {}",
+                host_schema_version,
+                self.plugin_core_diag.ast_schema_version
+            )
+        }
     }
 }
 
@@ -177,6 +177,7 @@ pub struct TransformExecutor {
     plugin_config: Option<serde_json::Value>,
     module_bytes: Box<dyn PluginModuleBytes>,
     runtime: Option<Arc<dyn Runtime + Send + Sync>>,
+    sandbox: PluginSandbox,
 }
 
 #[cfg(feature = "__rkyv")]
@@ -211,15 +212,28 @@ impl TransformExecutor {
             plugin_config,
             module_bytes,
             runtime,
+            sandbox: Default::default(),
         }
     }
 
+    /// Sets the limits and capabilities of the plugin.
+    ///
+    /// By default, the plugin can't access the filesystem or environment
+    /// variables other than `plugin_env_vars`, and has no resource limits.
+    pub fn with_sandbox(mut self, sandbox: PluginSandbox) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
     // Import, export, and create memory for the plugin to communicate between host
     // and guest then acquire necessary exports from the plugin.
     fn setup_plugin_env_exports(&mut self) -> Result<PluginTransformState, Error> {
         // First, compile plugin module bytes into wasmer::Module and get the
         // corresponding store
-        let (mut store, module) = self.module_bytes.compile_module()?;
+        let (store, module) = self.module_bytes.compile_module()?;
+
+        let sandbox_guard = SandboxGuard::new(self.module_bytes.get_module_name(), &self.sandbox);
+        let mut store = sandbox_guard.limit_memory(store);
 
         let context_key_buffer = Arc::new(Mutex::new(Vec::new()));
         let metadata_env = FunctionEnv::new(
@@ -280,28 +294,27 @@ impl TransformExecutor {
                 builder
             };
 
-            // Implicitly enable filesystem access for the wasi plugin to cwd.
+            // Enable filesystem access only to the directories granted to the plugin.
             //
             // This allows wasi plugin can read arbitary data (i.e node_modules) or produce
             // output for post process (i.e .lcov coverage data) directly.
-            //
-            // TODO: this is not finalized decision
-            // - should we support this?
-            // - can we limit to allowlisted input / output only?
-            // - should there be a top-level config from .swcrc to manually override this?
-            let mut wasi_env_builder = if let Ok(cwd) = env::current_dir() {
+            let mapped_dirs = &sandbox_guard.sandbox().mapped_dirs;
+            let mut wasi_env_builder = if mapped_dirs.is_empty() {
                 builder
-                    .fs(default_fs_backing())
-                    .map_dirs(vec![("/cwd".to_string(), cwd)].drain(..))?
             } else {
                 builder
+                    .fs(default_fs_backing())
+                    .map_dirs(mapped_dirs.iter().cloned())?
             };
 
-            if let Some(env_vars) = self.plugin_env_vars.as_ref() {
-                for env in env_vars.iter() {
-                    if let Ok(value) = env::var(env.as_str()) {
-                        wasi_env_builder.add_env(env.as_str(), value);
-                    }
+            let env_vars = self
+                .plugin_env_vars
+                .iter()
+                .flat_map(|env_vars| env_vars.iter())
+                .chain(&sandbox_guard.sandbox().env_vars);
+            for env in env_vars {
+                if let Ok(value) = env::var(env.as_str()) {
+                    wasi_env_builder.add_env(env.as_str(), value);
                 }
             }
 
@@ -313,16 +326,23 @@ impl TransformExecutor {
             // and attach it to the Wasm instance.
             let wasi_env_import_object = wasi_env.import_object(&mut store, &module)?;
             import_object.extend(&wasi_env_import_object);
+            sandbox_guard.deny_nondeterministic_imports(&mut store, &module, &mut import_object);
 
-            let instance = Instance::new(&mut store, &module, &import_object)?;
+            let instance = Instance::new(&mut store, &module, &import_object)
+                .map_err(|err| sandbox_guard.error(&mut store, None, err.into()))?;
 
             wasi_env.initialize(&mut store, instance.clone())?;
 
             (instance, Some(wasi_env))
         } else {
-            (Instance::new(&mut store, &module, &import_object)?, None)
+            let instance = Instance::new(&mut store, &module, &import_object)
+                .map_err(|err| sandbox_guard.error(&mut store, None, err.into()))?;
+
+            (instance, None)
         };
 
+        sandbox_guard.refuel(&mut store, &instance)?;
+
         // Attach the memory export
         let memory = instance.exports.get_memory("memory")?;
         import_object.define("env", "memory", memory.clone());
@@ -357,7 +377,8 @@ impl TransformExecutor {
         instance
             .exports
             .get_typed_function::<(), u32>(&store, "__get_transform_plugin_core_pkg_diag")?
-            .call(&mut store)?;
+            .call(&mut store)
+            .map_err(|err| sandbox_guard.error(&mut store, Some(&instance), err.into()))?;
 
         let diag_result: PluginCorePkgDiagnostics =
             PluginSerializedBytes::from_slice(&(&(*diagnostics_buffer.lock()))[..])
@@ -385,6 +406,7 @@ impl TransformExecutor {
             store,
             wasi_env,
             transform_result,
+            sandbox_guard,
             plugin_core_diag: diag_result,
         })
     }
@@ -397,17 +419,36 @@ impl TransformExecutor {
     ) -> Result<PluginSerializedBytes, Error> {
         let mut transform_state = self.setup_plugin_env_exports()?;
         transform_state.is_transform_schema_compatible()?;
-        transform_state
-            .run(program, self.unresolved_mark, should_enable_comments_proxy)
-            .with_context(|| {
-                format!(
-                    "failed to run Wasm plugin transform. Please ensure the version of `swc_core` \
-                     used by the plugin is compatible with the host runtime. See the \
-                     documentation for compatibility information. If you are an author of the \
-                     plugin, please update `swc_core` to the compatible version.
+        let result =
+            transform_state.run(program, self.unresolved_mark, should_enable_comments_proxy);
+
+        // Exceeding a limit is not a compatibility issue.
+        if matches!(&result, Err(err) if err.is::<PluginLimitError>()) {
+            return result;
+        }
+
+        // Neither is a program using syntax the AST schema of the plugin lacks.
+        if let Err(err) = &result {
+            if let Some(SchemaAdapterError::Unrepresentable { span, .. }) = err.downcast_ref() {
+                let loc = self.source_map.span_to_string(*span);
+                return result.with_context(|| {
+                    format!(
+                        "plugin `{}` cannot transform the program at {loc}",
+                        self.module_bytes.get_module_name()
+                    )
+                });
+            }
+        }
+
+        result.with_context(|| {
+            format!(
+                "failed to run Wasm plugin transform. Please ensure the version of `swc_core` \
+                 used by the plugin is compatible with the host runtime. See the \
+                 documentation for compatibility information. If you are an author of the \
+                 plugin, please update `swc_core` to the compatible version.
 
                 Note that if you want to use the os features like filesystem, you need to use \
-                     `wasi`. Wasm itself does not have concept of filesystem.
+                 `wasi`. Wasm itself does not have concept of filesystem.
 
                 https://swc.rs/docs/plugin/selecting-swc-core
 
@@ -421,8 +462,8 @@ impl TransformExecutor {
                     swc_plugin_runner: {PKG_VERSION}
                     Dependencies: {PKG_DEPS}
                 "
-                )
-            })
+            )
+        })
     }
 }
 
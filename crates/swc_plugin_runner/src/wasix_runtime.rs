@@ -9,7 +9,11 @@ use wasmer_wasix::Runtime;
 
 /// A shared instance to plugin runtime engine.
 /// ref: https://github.com/wasmerio/wasmer/issues/3793#issuecomment-1607117480
-static ENGINE: Lazy<Mutex<wasmer::Engine>> = Lazy::new(|| {
+///
+/// Plugins are compiled by [new_compiler_store] instead.
+static ENGINE: Lazy<Mutex<wasmer::Engine>> = Lazy::new(|| Mutex::new(new_engine()));
+
+fn new_engine() -> wasmer::Engine {
     // Use empty enumset to disable simd.
     use enumset::EnumSet;
     use wasmer::sys::{BaseTunables, CompilerConfig, EngineBuilder, Target, Triple};
@@ -20,14 +24,17 @@ static ENGINE: Lazy<Mutex<wasmer::Engine>> = Lazy::new(|| {
     set.insert(wasmer::sys::CpuFeature::SSE2);
     let target = Target::new(Triple::host(), set);
 
-    let config = wasmer_compiler_cranelift::Cranelift::default();
+    let mut config = wasmer_compiler_cranelift::Cranelift::default();
+    // Every module is metered, so fuel can be limited per plugin without
+    // recompiling it.
+    config.push_middleware(Arc::new(crate::sandbox::Metering::default()));
     let mut engine = EngineBuilder::new(Box::new(config) as Box<dyn CompilerConfig>)
         .set_target(Some(target))
         .engine();
     let tunables = BaseTunables::for_target(engine.target());
     engine.set_tunables(tunables);
-    parking_lot::Mutex::new(wasmer::Engine::from(engine))
-});
+    wasmer::Engine::from(engine)
+}
 
 /// Dummy http client for wasix runtime to avoid instantiation failure for the
 /// default pluggable runtime. We don't support network in the host runtime
@@ -98,3 +105,17 @@ pub(crate) fn new_store() -> Store {
 pub(crate) fn new_store() -> Store {
     Store::default()
 }
+
+/// Creates an instance of [Store] with its own engine to compile a plugin.
+///
+/// The metering middleware of an engine can only compile a single module, so
+/// each module must be compiled by a store of its own.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn new_compiler_store() -> Store {
+    Store::new(new_engine())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn new_compiler_store() -> Store {
+    Store::default()
+}
@@ -0,0 +1,197 @@
+#![cfg_attr(not(feature = "__rkyv"), allow(warnings))]
+use std::sync::Arc;
+
+use swc_common::{plugin::metadata::TransformPluginMetadataContext, sync::Lrc, Mark, SourceMap};
+#[cfg(feature = "__rkyv")]
+use swc_common::{
+    plugin::serialized::{PluginSerializedBytes, VersionedSerializable},
+    GLOBALS,
+};
+use swc_plugin_runner::{
+    plugin_module_bytes::RawPluginModuleBytes,
+    sandbox::{PluginLimitError, PluginSandbox},
+};
+
+/// Returns the error of running a plugin whose handshake with the host
+/// executes `handshake`.
+#[cfg(feature = "__rkyv")]
+fn run_plugin(
+    imports: &str,
+    memory_pages: u32,
+    handshake: &str,
+    sandbox: PluginSandbox,
+) -> PluginLimitError {
+    let wat = format!(
+        r#"
+        (module
+            {imports}
+            (memory (export "memory") {memory_pages})
+            (func (export "__alloc") (param i32) (result i32) (i32.const 0))
+            (func (export "__free") (param i32 i32) (result i32) (i32.const 0))
+            (func (export "__get_transform_plugin_core_pkg_diag") (result i32)
+                {handshake}
+                (i32.const 0))
+            (func (export "__transform_plugin_process_impl")
+                (param i32 i32 i32 i32) (result i32)
+                (i32.const 0)))
+        "#
+    );
+
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        GLOBALS.set(&Default::default(), || {
+            let cm: Lrc<SourceMap> = Default::default();
+            let program = PluginSerializedBytes::try_serialize(&VersionedSerializable::new(
+                swc_ecma_ast::Program::Module(Default::default()),
+            ))
+            .unwrap();
+
+            let mut executor = swc_plugin_runner::create_plugin_transform_executor(
+                &cm,
+                &Mark::new(),
+                &Arc::new(TransformPluginMetadataContext::new(
+                    None,
+                    "development".to_string(),
+                    None,
+                )),
+                None,
+                Box::new(RawPluginModuleBytes::new(
+                    "test_plugin".to_string(),
+                    wat.into_bytes(),
+                )),
+                None,
+                None,
+            )
+            .with_sandbox(sandbox);
+
+            let Err(err) = executor.transform(&program, Some(false)) else {
+                panic!("plugin should exceed its limits");
+            };
+
+            err.downcast()
+                .unwrap_or_else(|err| panic!("error should be a limit error: {err:?}"))
+        })
+    })
+}
+
+#[cfg(feature = "__rkyv")]
+#[test]
+fn fuel_exhausted() {
+    let err = run_plugin(
+        "",
+        1,
+        "(loop $loop (br $loop))",
+        PluginSandbox {
+            fuel: Some(1_000_000),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        err,
+        PluginLimitError::FuelExhausted {
+            plugin: "test_plugin".to_string(),
+            fuel: 1_000_000
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "plugin `test_plugin` exceeded its fuel limit of 1000000"
+    );
+}
+
+/// Modules compiled at the same time must be metered with their own globals.
+#[cfg(feature = "__rkyv")]
+#[test]
+fn fuel_exhausted_in_parallel() {
+    let handles = (0..8)
+        .map(|i| {
+            std::thread::spawn(move || {
+                run_plugin(
+                    &"(global i32 (i32.const 0))".repeat(i),
+                    1,
+                    "(loop $loop (br $loop))",
+                    PluginSandbox {
+                        fuel: Some(1_000_000),
+                        ..Default::default()
+                    },
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert_eq!(
+            handle.join().unwrap(),
+            PluginLimitError::FuelExhausted {
+                plugin: "test_plugin".to_string(),
+                fuel: 1_000_000
+            }
+        );
+    }
+}
+
+#[cfg(feature = "__rkyv")]
+#[test]
+fn memory_grow_exceeded() {
+    let err = run_plugin(
+        "",
+        1,
+        "(if (i32.eq (memory.grow (i32.const 16)) (i32.const -1)) (then unreachable))",
+        PluginSandbox {
+            max_memory: Some(4 * 65536),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        err,
+        PluginLimitError::MemoryExceeded {
+            plugin: "test_plugin".to_string(),
+            max_memory: 4 * 65536
+        }
+    );
+}
+
+#[cfg(feature = "__rkyv")]
+#[test]
+fn memory_minimum_exceeded() {
+    let err = run_plugin(
+        "",
+        16,
+        "",
+        PluginSandbox {
+            max_memory: Some(4 * 65536),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        err,
+        PluginLimitError::MemoryExceeded {
+            plugin: "test_plugin".to_string(),
+            max_memory: 4 * 65536
+        }
+    );
+}
+
+#[cfg(feature = "__rkyv")]
+#[test]
+fn deterministic_random() {
+    let err = run_plugin(
+        r#"(import "wasi_snapshot_preview1" "random_get" (func $random_get (param i32 i32) (result i32)))"#,
+        1,
+        "(drop (call $random_get (i32.const 0) (i32.const 8)))",
+        PluginSandbox {
+            deterministic: true,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        err,
+        PluginLimitError::Nondeterministic {
+            plugin: "test_plugin".to_string(),
+            import: "random_get".to_string()
+        }
+    );
+}
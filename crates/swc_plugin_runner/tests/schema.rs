@@ -0,0 +1,117 @@
+#![cfg_attr(not(feature = "__rkyv"), allow(warnings))]
+
+#[cfg(feature = "__rkyv")]
+use swc_common::plugin::serialized::{PluginSerializedBytes, VersionedSerializable};
+use swc_common::{sync::Lrc, BytePos, FileName, SourceMap, Span, GLOBALS};
+use swc_ecma_ast::{EsVersion, Expr, ExprStmt, Module, Program, TopicRef};
+use swc_ecma_parser::{parse_file_as_program, EsSyntax, Syntax};
+#[cfg(feature = "__rkyv")]
+use swc_plugin_runner::schema::{adapt, is_supported, SchemaAdapterError};
+
+fn parse(src: &str) -> Program {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+
+    parse_file_as_program(
+        &fm,
+        Syntax::Es(EsSyntax {
+            pipeline_operator: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .expect("should parse")
+}
+
+#[cfg(feature = "__rkyv")]
+fn serialize(program: Program) -> PluginSerializedBytes {
+    PluginSerializedBytes::try_serialize(&VersionedSerializable::new(program))
+        .expect("should serialize")
+}
+
+#[cfg(all(feature = "__rkyv", feature = "ecma"))]
+#[test]
+fn downgrade_unrepresentable_pipeline() {
+    GLOBALS.set(&Default::default(), || {
+        let program = serialize(parse("const x = a |> f(%);"));
+
+        let Err(err) = adapt(&program, 2, 1) else {
+            panic!("`|>` should not be representable");
+        };
+
+        assert_eq!(
+            err,
+            SchemaAdapterError::Unrepresentable {
+                node: "PipelineExpression",
+                span: Span::new(BytePos(11), BytePos(20)),
+                version: 1
+            }
+        );
+    });
+}
+
+#[cfg(all(feature = "__rkyv", feature = "ecma"))]
+#[test]
+fn downgrade_unrepresentable_topic() {
+    GLOBALS.set(&Default::default(), || {
+        let span = Span::new(BytePos(1), BytePos(2));
+        let program = serialize(Program::Module(Module {
+            body: vec![ExprStmt {
+                expr: Box::new(Expr::TopicRef(TopicRef { span })),
+                ..Default::default()
+            }
+            .into()],
+            ..Default::default()
+        }));
+
+        let Err(err) = adapt(&program, 2, 1) else {
+            panic!("`%` should not be representable");
+        };
+
+        assert_eq!(
+            err,
+            SchemaAdapterError::Unrepresentable {
+                node: "TopicReference",
+                span,
+                version: 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "`TopicReference` cannot be represented in AST schema version 1"
+        );
+    });
+}
+
+#[cfg(all(feature = "__rkyv", feature = "ecma"))]
+#[test]
+fn unchanged_program() {
+    GLOBALS.set(&Default::default(), || {
+        let program = serialize(parse("const x = f(a); x;"));
+
+        assert_eq!(
+            adapt(&program, 2, 1).unwrap().as_slice(),
+            program.as_slice()
+        );
+        assert_eq!(
+            adapt(&program, 1, 2).unwrap().as_slice(),
+            program.as_slice()
+        );
+    });
+}
+
+#[cfg(all(feature = "__rkyv", feature = "ecma"))]
+#[test]
+fn unsupported_versions() {
+    assert!(is_supported(1, 2));
+    assert!(is_supported(2, 1));
+    assert!(!is_supported(1, u32::MAX - 1));
+
+    let program = serialize(Program::Module(Default::default()));
+    assert_eq!(
+        adapt(&program, 3, 1).map(|_| ()),
+        Err(SchemaAdapterError::Unsupported { from: 3, to: 1 })
+    );
+}
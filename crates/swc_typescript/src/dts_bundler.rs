@@ -0,0 +1,1034 @@
+//! Bundling of declaration files.
+//!
+//! [DtsBundler] rolls up the declaration files reachable from an entry into a
+//! single module, like `api-extractor` or `rollup-plugin-dts` do.
+//!
+//! Only the declarations used by the exports of the entry are kept, and
+//! declarations with conflicting names are renamed. Imports of external
+//! packages are kept, unless the package is listed in
+//! [DtsBundlerConfig::inline_packages].
+//!
+//! `import("...")` types are kept as is.
+
+use anyhow::{anyhow, bail, Context, Error};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::{atom, Atom};
+use swc_common::{comments::Comments, BytePos, FileName, Mark, Spanned, SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_loader::resolve::Resolve;
+use swc_ecma_transforms_base::resolver;
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::fast_dts::visitors::internal_annotation::InternalAnnotationTransformer;
+
+/// A loaded declaration file.
+#[derive(Debug, Default)]
+pub struct DtsModule {
+    pub module: Module,
+
+    /// Positions of the comments containing `@internal`, as returned by
+    /// [FastDts::get_internal_annotations](crate::fast_dts::FastDts::get_internal_annotations).
+    pub internal_annotations: FxHashSet<BytePos>,
+}
+
+/// Loads the declaration file of a resolved module.
+///
+/// The loader is responsible for mapping source files to their declaration
+/// files, e.g. by running [FastDts](crate::fast_dts::FastDts) on `foo.ts` or
+/// by loading `foo.d.ts` for `foo.js`.
+pub trait Load {
+    fn load(&self, file: &FileName) -> Result<DtsModule, Error>;
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DtsBundlerConfig {
+    /// Packages whose declarations are inlined into the bundle instead of
+    /// being imported. Subpaths of the packages are inlined too.
+    pub inline_packages: Vec<String>,
+
+    /// Removes the declarations and members annotated with `@internal`.
+    pub strip_internal: bool,
+}
+
+pub struct DtsBundler<'a> {
+    loader: &'a dyn Load,
+    resolver: &'a dyn Resolve,
+    comments: Option<&'a dyn Comments>,
+    config: DtsBundlerConfig,
+}
+
+impl<'a> DtsBundler<'a> {
+    /// `comments` should contain the comments of the loaded modules, so the
+    /// comments of exported declarations are kept.
+    pub fn new(
+        loader: &'a dyn Load,
+        resolver: &'a dyn Resolve,
+        comments: Option<&'a dyn Comments>,
+        config: DtsBundlerConfig,
+    ) -> Self {
+        Self {
+            loader,
+            resolver,
+            comments,
+            config,
+        }
+    }
+
+    /// Bundles `entry` and the declaration files it depends on.
+    pub fn bundle(&self, entry: &FileName) -> Result<Module, Error> {
+        let mut graph = Graph {
+            bundler: self,
+            unresolved_mark: Mark::new(),
+            modules: Vec::new(),
+            ids: FxHashMap::default(),
+            order: Vec::new(),
+        };
+
+        let entry = graph.load(entry)?;
+
+        Linker::new(&graph).link(entry)
+    }
+
+    fn is_external(&self, src: &str) -> bool {
+        if src.starts_with('.') || src.starts_with('/') {
+            return false;
+        }
+
+        !self.config.inline_packages.iter().any(|package| {
+            src.strip_prefix(&**package)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ModuleRef {
+    Internal(usize),
+    External(Atom),
+}
+
+#[derive(Debug, Clone)]
+enum Import {
+    Named(ModuleRef, Atom),
+    Namespace(ModuleRef),
+}
+
+#[derive(Debug, Clone)]
+enum Export {
+    /// A declaration or an import of the module.
+    Local(Id),
+    Reexport(ModuleRef, Atom),
+    Namespace(ModuleRef),
+}
+
+/// A binding of the bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Binding {
+    Decl(usize, Id),
+    /// A named, default (`default`) or namespace (`*`) import of an external
+    /// module.
+    External(Atom, Atom),
+    /// The exports of an internal module, as a namespace.
+    Namespace(usize),
+}
+
+#[derive(Debug)]
+struct ModuleInfo {
+    file: FileName,
+
+    /// Declarations and other statements, without `export`.
+    items: Vec<ModuleItem>,
+    decls: FxHashMap<Id, Vec<usize>>,
+    /// Items which are kept even if they are not used, like `declare global`.
+    side_effects: Vec<usize>,
+
+    imports: FxHashMap<Id, Import>,
+    exports: Vec<(Atom, Export)>,
+    star_exports: Vec<ModuleRef>,
+
+    /// `export as namespace Foo`
+    namespace_export: Option<TsNamespaceExportDecl>,
+    /// Globals referenced by the module.
+    unresolved: FxHashSet<Atom>,
+}
+
+impl ModuleInfo {
+    fn new(file: FileName) -> Self {
+        Self {
+            file,
+            items: Default::default(),
+            decls: Default::default(),
+            side_effects: Default::default(),
+            imports: Default::default(),
+            exports: Default::default(),
+            star_exports: Default::default(),
+            namespace_export: Default::default(),
+            unresolved: Default::default(),
+        }
+    }
+
+    fn export(&self, name: &Atom) -> Option<&Export> {
+        self.exports
+            .iter()
+            .find(|(exported, _)| exported == name)
+            .map(|(_, export)| export)
+    }
+
+    fn add_decl(&mut self, id: Id, item: usize) {
+        let items = self.decls.entry(id).or_default();
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+}
+
+struct Graph<'a, 'b> {
+    bundler: &'b DtsBundler<'a>,
+    unresolved_mark: Mark,
+
+    modules: Vec<ModuleInfo>,
+    ids: FxHashMap<FileName, usize>,
+    /// Modules in the order they should be emitted, dependencies first.
+    order: Vec<usize>,
+}
+
+impl Graph<'_, '_> {
+    fn load(&mut self, file: &FileName) -> Result<usize, Error> {
+        if let Some(&id) = self.ids.get(file) {
+            return Ok(id);
+        }
+
+        let id = self.modules.len();
+        self.ids.insert(file.clone(), id);
+        self.modules.push(ModuleInfo::new(file.clone()));
+
+        let DtsModule {
+            mut module,
+            internal_annotations,
+        } = self
+            .bundler
+            .loader
+            .load(file)
+            .with_context(|| format!("failed to load {file}"))?;
+
+        if self.bundler.config.strip_internal {
+            module
+                .body
+                .retain(|item| !internal_annotations.contains(&item.span_lo()));
+            module.visit_mut_with(&mut InternalAnnotationTransformer::new(
+                &internal_annotations,
+            ));
+        }
+
+        module.visit_mut_with(&mut ResetCtxt);
+        module.visit_mut_with(&mut resolver(self.unresolved_mark, Mark::new(), true));
+
+        let info = self
+            .analyze(id, module)
+            .with_context(|| format!("failed to bundle {file}"))?;
+        self.modules[id] = info;
+        self.order.push(id);
+
+        Ok(id)
+    }
+
+    fn module_ref(&mut self, base: &FileName, src: &Atom) -> Result<ModuleRef, Error> {
+        if self.bundler.is_external(src) {
+            return Ok(ModuleRef::External(src.clone()));
+        }
+
+        let resolved = self
+            .bundler
+            .resolver
+            .resolve(base, src)
+            .with_context(|| format!("failed to resolve {src}"))?;
+
+        Ok(ModuleRef::Internal(self.load(&resolved.filename)?))
+    }
+
+    fn move_comments(&self, from: BytePos, to: BytePos) {
+        if let Some(comments) = self.bundler.comments {
+            comments.move_leading(from, to);
+        }
+    }
+
+    fn analyze(&mut self, id: usize, module: Module) -> Result<ModuleInfo, Error> {
+        let file = self.modules[id].file.clone();
+        let mut info = ModuleInfo::new(file.clone());
+
+        let mut unresolved = CollectUnresolved {
+            unresolved_ctxt: SyntaxContext::empty().apply_mark(self.unresolved_mark),
+            names: Default::default(),
+        };
+        module.visit_with(&mut unresolved);
+        info.unresolved = unresolved.names;
+
+        for item in module.body {
+            let decl = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    let module = self.module_ref(&file, &import.src.value)?;
+
+                    for specifier in import.specifiers {
+                        let (local, import) = match specifier {
+                            ImportSpecifier::Named(named) => {
+                                let imported = named
+                                    .imported
+                                    .map(|imported| imported.atom().clone())
+                                    .unwrap_or_else(|| named.local.sym.clone());
+
+                                (named.local, Import::Named(module.clone(), imported))
+                            }
+                            ImportSpecifier::Default(default) => (
+                                default.local,
+                                Import::Named(module.clone(), atom!("default")),
+                            ),
+                            ImportSpecifier::Namespace(namespace) => {
+                                (namespace.local, Import::Namespace(module.clone()))
+                            }
+                        };
+
+                        info.imports.insert(local.to_id(), import);
+                    }
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                    self.move_comments(export.span_lo(), export.decl.span_lo());
+                    for id in decl_ids(&export.decl) {
+                        info.exports.push((id.0.clone(), Export::Local(id)));
+                    }
+                    export.decl
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+                    self.move_comments(export.span_lo(), export.decl.span_lo());
+                    let decl = match export.decl {
+                        DefaultDecl::Class(class) => Decl::Class(ClassDecl {
+                            ident: class.ident.unwrap_or_else(default_ident),
+                            declare: true,
+                            class: class.class,
+                        }),
+                        DefaultDecl::Fn(function) => Decl::Fn(FnDecl {
+                            ident: function.ident.unwrap_or_else(default_ident),
+                            declare: true,
+                            function: function.function,
+                        }),
+                        DefaultDecl::TsInterfaceDecl(interface) => Decl::TsInterface(interface),
+                    };
+
+                    for id in decl_ids(&decl) {
+                        info.exports.push((atom!("default"), Export::Local(id)));
+                    }
+                    decl
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+                    let Expr::Ident(ident) = *export.expr else {
+                        bail!("`export default` of an expression is not supported");
+                    };
+
+                    info.exports
+                        .push((atom!("default"), Export::Local(ident.to_id())));
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+                    let module = match &export.src {
+                        Some(src) => Some(self.module_ref(&file, &src.value)?),
+                        None => None,
+                    };
+
+                    for specifier in export.specifiers {
+                        match specifier {
+                            ExportSpecifier::Named(named) => {
+                                let name = named
+                                    .exported
+                                    .as_ref()
+                                    .unwrap_or(&named.orig)
+                                    .atom()
+                                    .clone();
+
+                                let export = match (&module, named.orig) {
+                                    (Some(module), orig) => {
+                                        Export::Reexport(module.clone(), orig.atom().clone())
+                                    }
+                                    (None, ModuleExportName::Ident(orig)) => {
+                                        Export::Local(orig.to_id())
+                                    }
+                                    (None, ModuleExportName::Str(..)) => continue,
+                                };
+
+                                info.exports.push((name, export));
+                            }
+                            ExportSpecifier::Namespace(namespace) => {
+                                let Some(module) = &module else {
+                                    continue;
+                                };
+
+                                info.exports.push((
+                                    namespace.name.atom().clone(),
+                                    Export::Namespace(module.clone()),
+                                ));
+                            }
+                            ExportSpecifier::Default(..) => {}
+                        }
+                    }
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
+                    let module = self.module_ref(&file, &export.src.value)?;
+                    if !info.star_exports.contains(&module) {
+                        info.star_exports.push(module);
+                    }
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
+                    if let TsModuleRef::TsExternalModuleRef(module_ref) = &import.module_ref {
+                        let module = self.module_ref(&file, &module_ref.expr.value)?;
+
+                        if let ModuleRef::Internal(..) = module {
+                            let local = import.id.to_id();
+                            if import.is_export {
+                                info.exports
+                                    .push((local.0.clone(), Export::Local(local.clone())));
+                            }
+                            info.imports.insert(local, Import::Namespace(module));
+                            continue;
+                        }
+                    }
+
+                    let id = import.id.to_id();
+                    if import.is_export {
+                        info.exports.push((id.0.clone(), Export::Local(id.clone())));
+                    }
+                    info.items.push(
+                        TsImportEqualsDecl {
+                            is_export: false,
+                            ..*import
+                        }
+                        .into(),
+                    );
+                    info.add_decl(id, info.items.len() - 1);
+                    continue;
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(..)) => {
+                    bail!("`export =` is not supported");
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(export)) => {
+                    info.namespace_export = Some(export);
+                    continue;
+                }
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                ModuleItem::Stmt(stmt) => {
+                    info.items.push(stmt.into());
+                    info.side_effects.push(info.items.len() - 1);
+                    continue;
+                }
+            };
+
+            let decl = declare(decl);
+            let ids = decl_ids(&decl);
+            info.items.push(decl.into());
+
+            if ids.is_empty() {
+                info.side_effects.push(info.items.len() - 1);
+            }
+            for id in ids {
+                info.add_decl(id, info.items.len() - 1);
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+struct Linker<'a> {
+    modules: &'a [ModuleInfo],
+    order: &'a [usize],
+
+    /// Bindings in the order they are found.
+    bindings: Vec<Binding>,
+    used: FxHashSet<Binding>,
+    included: Vec<FxHashSet<usize>>,
+
+    /// The preferred names of bindings without a declaration.
+    hints: FxHashMap<Binding, Atom>,
+}
+
+impl<'a> Linker<'a> {
+    fn new(graph: &'a Graph) -> Self {
+        Self {
+            modules: &graph.modules,
+            order: &graph.order,
+            bindings: Default::default(),
+            used: Default::default(),
+            included: vec![Default::default(); graph.modules.len()],
+            hints: Default::default(),
+        }
+    }
+
+    fn link(mut self, entry: usize) -> Result<Module, Error> {
+        // Exports of the bundle.
+        let mut exports = Vec::new();
+        let mut external_star_exports = Vec::new();
+        self.collect_export_names(entry, &mut exports, &mut external_star_exports);
+
+        let mut exported = Vec::new();
+        for name in exports {
+            let binding = self.resolve_export(entry, &name, &mut Default::default())?;
+            self.use_binding(binding.clone(), Some(&name));
+            exported.push((name, binding));
+        }
+
+        for &module in self.order {
+            for &item in &self.modules[module].side_effects {
+                self.include(module, item)?;
+            }
+        }
+
+        // Walk the dependencies of the used bindings.
+        let mut index = 0;
+        while let Some(binding) = self.bindings.get(index).cloned() {
+            index += 1;
+
+            match binding {
+                Binding::Decl(module, id) => {
+                    for &item in &self.modules[module].decls[&id] {
+                        self.include(module, item)?;
+                    }
+                }
+                Binding::Namespace(module) => {
+                    let mut names = Vec::new();
+                    self.collect_export_names(module, &mut names, &mut Vec::new());
+
+                    for name in names {
+                        let binding =
+                            self.resolve_export(module, &name, &mut Default::default())?;
+                        self.use_binding(binding, Some(&name));
+                    }
+                }
+                Binding::External(..) => {}
+            }
+        }
+
+        let names = self.assign_names(entry);
+
+        Ok(self.emit(entry, &names, exported, external_star_exports))
+    }
+
+    /// Names exported by `module`, including the ones of `export *`.
+    fn collect_export_names(
+        &self,
+        module: usize,
+        names: &mut Vec<Atom>,
+        external_star_exports: &mut Vec<Atom>,
+    ) {
+        let info = &self.modules[module];
+
+        for (name, _) in &info.exports {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+
+        for star in &info.star_exports {
+            match star {
+                ModuleRef::Internal(dep) => {
+                    let mut dep_names = Vec::new();
+                    let mut visited = FxHashSet::default();
+                    self.collect_star_export_names(*dep, &mut dep_names, &mut visited);
+
+                    for name in dep_names {
+                        if &*name != "default" && !names.contains(&name) {
+                            names.push(name);
+                        }
+                    }
+
+                    self.collect_external_star_exports(*dep, external_star_exports, &mut visited);
+                }
+                ModuleRef::External(src) => {
+                    if !external_star_exports.contains(src) {
+                        external_star_exports.push(src.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_star_export_names(
+        &self,
+        module: usize,
+        names: &mut Vec<Atom>,
+        visited: &mut FxHashSet<usize>,
+    ) {
+        if !visited.insert(module) {
+            return;
+        }
+
+        let info = &self.modules[module];
+        for (name, _) in &info.exports {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        for star in &info.star_exports {
+            if let ModuleRef::Internal(dep) = star {
+                self.collect_star_export_names(*dep, names, visited);
+            }
+        }
+    }
+
+    fn collect_external_star_exports(
+        &self,
+        module: usize,
+        external_star_exports: &mut Vec<Atom>,
+        visited: &mut FxHashSet<usize>,
+    ) {
+        for star in &self.modules[module].star_exports {
+            match star {
+                ModuleRef::Internal(dep) => {
+                    if visited.insert(*dep) {
+                        self.collect_external_star_exports(*dep, external_star_exports, visited);
+                    }
+                }
+                ModuleRef::External(src) => {
+                    if !external_star_exports.contains(src) {
+                        external_star_exports.push(src.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve_export(
+        &self,
+        module: usize,
+        name: &Atom,
+        visited: &mut FxHashSet<(usize, Atom)>,
+    ) -> Result<Binding, Error> {
+        if !visited.insert((module, name.clone())) {
+            bail!("circular re-export of {name}");
+        }
+
+        let info = &self.modules[module];
+
+        if let Some(export) = info.export(name) {
+            return match export {
+                Export::Local(id) => self.resolve_local(module, id, visited),
+                Export::Reexport(ModuleRef::Internal(dep), orig) => {
+                    self.resolve_export(*dep, orig, visited)
+                }
+                Export::Reexport(ModuleRef::External(src), orig) => {
+                    Ok(Binding::External(src.clone(), orig.clone()))
+                }
+                Export::Namespace(ModuleRef::Internal(dep)) => Ok(Binding::Namespace(*dep)),
+                Export::Namespace(ModuleRef::External(src)) => {
+                    Ok(Binding::External(src.clone(), atom!("*")))
+                }
+            };
+        }
+
+        if &**name != "default" {
+            for star in &info.star_exports {
+                if let ModuleRef::Internal(dep) = star {
+                    if let Ok(binding) = self.resolve_export(*dep, name, visited) {
+                        return Ok(binding);
+                    }
+                }
+            }
+
+            // The name may come from an external module.
+            let mut external = info.star_exports.iter().filter_map(|star| match star {
+                ModuleRef::External(src) => Some(src),
+                ModuleRef::Internal(..) => None,
+            });
+            if let Some(src) = external.next() {
+                return Ok(Binding::External(src.clone(), name.clone()));
+            }
+        }
+
+        Err(anyhow!("{} does not export {name}", info.file))
+    }
+
+    fn resolve_local(
+        &self,
+        module: usize,
+        id: &Id,
+        visited: &mut FxHashSet<(usize, Atom)>,
+    ) -> Result<Binding, Error> {
+        let info = &self.modules[module];
+
+        match info.imports.get(id) {
+            Some(Import::Named(ModuleRef::Internal(dep), imported)) => {
+                self.resolve_export(*dep, imported, visited)
+            }
+            Some(Import::Named(ModuleRef::External(src), imported)) => {
+                Ok(Binding::External(src.clone(), imported.clone()))
+            }
+            Some(Import::Namespace(ModuleRef::Internal(dep))) => Ok(Binding::Namespace(*dep)),
+            Some(Import::Namespace(ModuleRef::External(src))) => {
+                Ok(Binding::External(src.clone(), atom!("*")))
+            }
+            None if info.decls.contains_key(id) => Ok(Binding::Decl(module, id.clone())),
+            None => bail!("{} is not declared in {}", id.0, info.file),
+        }
+    }
+
+    /// Marks `binding` as used, with `hint` as the preferred name if it has no
+    /// declaration.
+    fn use_binding(&mut self, binding: Binding, hint: Option<&Atom>) {
+        if let Some(hint) = hint {
+            if !matches!(binding, Binding::Decl(..)) {
+                self.hints
+                    .entry(binding.clone())
+                    .or_insert_with(|| hint.clone());
+            }
+        }
+
+        if self.used.insert(binding.clone()) {
+            self.bindings.push(binding);
+        }
+    }
+
+    fn include(&mut self, module: usize, item: usize) -> Result<(), Error> {
+        if !self.included[module].insert(item) {
+            return Ok(());
+        }
+
+        let info = &self.modules[module];
+        let mut refs = CollectRefs {
+            info,
+            refs: Default::default(),
+        };
+        info.items[item].visit_with(&mut refs);
+
+        for id in refs.refs {
+            let binding = self
+                .resolve_local(module, &id, &mut Default::default())
+                .with_context(|| format!("failed to bundle {}", info.file))?;
+            self.use_binding(binding, Some(&id.0));
+        }
+
+        Ok(())
+    }
+
+    fn assign_names(&self, entry: usize) -> FxHashMap<Binding, Atom> {
+        let mut used_names = self
+            .modules
+            .iter()
+            .flat_map(|info| info.unresolved.iter().cloned())
+            .collect::<FxHashSet<_>>();
+        let mut names = FxHashMap::default();
+
+        let mut assign = |binding: &Binding, name: &Atom| {
+            let mut unique = name.clone();
+            let mut n = 0;
+            while !used_names.insert(unique.clone()) {
+                n += 1;
+                unique = format!("{name}_{n}").into();
+            }
+            names.insert(binding.clone(), unique);
+        };
+
+        // Declarations of the entry keep their names.
+        let entry_first = self
+            .bindings
+            .iter()
+            .filter(|b| matches!(b, Binding::Decl(module, _) if *module == entry));
+        let rest = self
+            .bindings
+            .iter()
+            .filter(|b| !matches!(b, Binding::Decl(module, _) if *module == entry));
+
+        let default = atom!("_default");
+        for binding in entry_first.chain(rest) {
+            let name = match binding {
+                Binding::Decl(_, id) => &id.0,
+                Binding::External(_, name) if &**name != "default" && &**name != "*" => name,
+                _ => self.hints.get(binding).unwrap_or(&default),
+            };
+            assign(binding, name);
+        }
+
+        names
+    }
+
+    fn emit(
+        &self,
+        entry: usize,
+        names: &FxHashMap<Binding, Atom>,
+        exported: Vec<(Atom, Binding)>,
+        external_star_exports: Vec<Atom>,
+    ) -> Module {
+        let mut body = Vec::new();
+
+        // Imports of external modules, grouped by module.
+        let mut imports = Vec::<ImportDecl>::new();
+        for binding in &self.bindings {
+            let Binding::External(src, imported) = binding else {
+                continue;
+            };
+            let local = Ident::new_no_ctxt(names[binding].clone(), DUMMY_SP);
+
+            let specifier = match &**imported {
+                "*" => {
+                    imports.push(import_decl(
+                        src,
+                        ImportStarAsSpecifier {
+                            span: DUMMY_SP,
+                            local,
+                        }
+                        .into(),
+                    ));
+                    continue;
+                }
+                "default" => ImportDefaultSpecifier {
+                    span: DUMMY_SP,
+                    local,
+                }
+                .into(),
+                _ => ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    imported: (local.sym != *imported)
+                        .then(|| Ident::new_no_ctxt(imported.clone(), DUMMY_SP).into()),
+                    local,
+                    is_type_only: false,
+                }
+                .into(),
+            };
+
+            match imports.iter_mut().find(|import| {
+                import.src.value == *src
+                    && !import
+                        .specifiers
+                        .iter()
+                        .any(|specifier| specifier.is_namespace())
+            }) {
+                Some(import) => import.specifiers.push(specifier),
+                None => imports.push(import_decl(src, specifier)),
+            }
+        }
+        for import in &mut imports {
+            // A default import should be the first specifier.
+            import
+                .specifiers
+                .sort_by_key(|specifier| !specifier.is_default());
+        }
+        body.extend(imports.into_iter().map(ModuleItem::from));
+
+        // Declarations, renamed.
+        for &module in self.order {
+            let info = &self.modules[module];
+            let mut renamer = Renamer {
+                names: Default::default(),
+            };
+            for id in info.decls.keys() {
+                if let Some(name) = names.get(&Binding::Decl(module, id.clone())) {
+                    renamer.names.insert(id.clone(), name.clone());
+                }
+            }
+            for id in info.imports.keys() {
+                if let Ok(binding) = self.resolve_local(module, id, &mut Default::default()) {
+                    if let Some(name) = names.get(&binding) {
+                        renamer.names.insert(id.clone(), name.clone());
+                    }
+                }
+            }
+
+            let mut items = self.included[module].iter().copied().collect::<Vec<_>>();
+            items.sort_unstable();
+            for item in items {
+                let mut item = info.items[item].clone();
+                item.visit_mut_with(&mut renamer);
+                body.push(item);
+            }
+        }
+
+        // Namespaces of internal modules.
+        for binding in &self.bindings {
+            let Binding::Namespace(module) = binding else {
+                continue;
+            };
+
+            let mut export_names = Vec::new();
+            self.collect_export_names(*module, &mut export_names, &mut Vec::new());
+
+            let specifiers = export_names
+                .into_iter()
+                .filter_map(|name| {
+                    let binding = self
+                        .resolve_export(*module, &name, &mut Default::default())
+                        .ok()?;
+                    Some(export_specifier(&names[&binding], &name))
+                })
+                .collect();
+
+            body.push(
+                Decl::TsModule(Box::new(TsModuleDecl {
+                    span: DUMMY_SP,
+                    declare: true,
+                    global: false,
+                    namespace: true,
+                    id: Ident::new_no_ctxt(names[binding].clone(), DUMMY_SP).into(),
+                    body: Some(TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                        span: DUMMY_SP,
+                        body: vec![named_export(specifiers).into()],
+                    })),
+                }))
+                .into(),
+            );
+        }
+
+        // Exports of the entry.
+        let specifiers = exported
+            .iter()
+            .map(|(name, binding)| export_specifier(&names[binding], name))
+            .collect::<Vec<_>>();
+        let has_exports = !specifiers.is_empty() || !external_star_exports.is_empty();
+        if !specifiers.is_empty() || !has_exports {
+            body.push(named_export(specifiers).into());
+        }
+        for src in external_star_exports {
+            body.push(
+                ExportAll {
+                    span: DUMMY_SP,
+                    src: Box::new(src.into()),
+                    type_only: false,
+                    with: None,
+                }
+                .into(),
+            );
+        }
+
+        if let Some(mut export) = self.modules[entry].namespace_export.clone() {
+            export.id.ctxt = SyntaxContext::empty();
+            body.push(ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(
+                export,
+            )));
+        }
+
+        Module {
+            span: DUMMY_SP,
+            body,
+            shebang: None,
+        }
+    }
+}
+
+fn default_ident() -> Ident {
+    Ident::new(
+        atom!("_default"),
+        DUMMY_SP,
+        SyntaxContext::empty().apply_mark(Mark::new()),
+    )
+}
+
+/// Adds `declare` to declarations which were exported.
+fn declare(mut decl: Decl) -> Decl {
+    match &mut decl {
+        Decl::Class(ClassDecl { declare, .. }) | Decl::Fn(FnDecl { declare, .. }) => {
+            *declare = true
+        }
+        Decl::TsEnum(e) => e.declare = true,
+        Decl::Var(var) => var.declare = true,
+        Decl::TsModule(module) if !module.global => module.declare = true,
+        _ => {}
+    }
+    decl
+}
+
+fn decl_ids(decl: &Decl) -> Vec<Id> {
+    match decl {
+        Decl::Class(c) => vec![c.ident.to_id()],
+        Decl::Fn(f) => vec![f.ident.to_id()],
+        Decl::Var(v) => swc_ecma_utils::find_pat_ids(&v.decls),
+        Decl::Using(u) => swc_ecma_utils::find_pat_ids(&u.decls),
+        Decl::TsInterface(i) => vec![i.id.to_id()],
+        Decl::TsTypeAlias(a) => vec![a.id.to_id()],
+        Decl::TsEnum(e) => vec![e.id.to_id()],
+        Decl::TsModule(m) => match &m.id {
+            TsModuleName::Ident(ident) if !m.global => vec![ident.to_id()],
+            _ => Vec::new(),
+        },
+    }
+}
+
+fn import_decl(src: &Atom, specifier: ImportSpecifier) -> ImportDecl {
+    ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![specifier],
+        src: Box::new(src.clone().into()),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    }
+}
+
+fn named_export(specifiers: Vec<ExportSpecifier>) -> NamedExport {
+    NamedExport {
+        span: DUMMY_SP,
+        specifiers,
+        src: None,
+        type_only: false,
+        with: None,
+    }
+}
+
+fn export_specifier(local: &Atom, exported: &Atom) -> ExportSpecifier {
+    ExportNamedSpecifier {
+        span: DUMMY_SP,
+        orig: Ident::new_no_ctxt(local.clone(), DUMMY_SP).into(),
+        exported: (local != exported)
+            .then(|| Ident::new_no_ctxt(exported.clone(), DUMMY_SP).into()),
+        is_type_only: false,
+    }
+    .into()
+}
+
+/// Resets the syntax contexts, as loaded modules may have been resolved.
+struct ResetCtxt;
+
+impl VisitMut for ResetCtxt {
+    fn visit_mut_ident(&mut self, n: &mut Ident) {
+        n.ctxt = SyntaxContext::empty();
+    }
+}
+
+struct CollectUnresolved {
+    unresolved_ctxt: SyntaxContext,
+    names: FxHashSet<Atom>,
+}
+
+impl Visit for CollectUnresolved {
+    fn visit_ident(&mut self, n: &Ident) {
+        if n.ctxt == self.unresolved_ctxt {
+            self.names.insert(n.sym.clone());
+        }
+    }
+}
+
+/// Collects the references to top-level bindings of a module.
+struct CollectRefs<'a> {
+    info: &'a ModuleInfo,
+    refs: Vec<Id>,
+}
+
+impl Visit for CollectRefs<'_> {
+    fn visit_ident(&mut self, n: &Ident) {
+        let id = n.to_id();
+
+        if (self.info.decls.contains_key(&id) || self.info.imports.contains_key(&id))
+            && !self.refs.contains(&id)
+        {
+            self.refs.push(id);
+        }
+    }
+}
+
+struct Renamer {
+    names: FxHashMap<Id, Atom>,
+}
+
+impl VisitMut for Renamer {
+    fn visit_mut_ident(&mut self, n: &mut Ident) {
+        if let Some(name) = self.names.get(&n.to_id()) {
+            n.sym = name.clone();
+        }
+    }
+}
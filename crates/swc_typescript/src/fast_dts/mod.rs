@@ -26,7 +26,7 @@ mod function;
 mod inferrer;
 mod types;
 mod util;
-mod visitors;
+pub(crate) mod visitors;
 
 /// TypeScript Isolated Declaration support.
 ///
@@ -1,6 +1,6 @@
 use rustc_hash::FxHashSet;
 use swc_common::{BytePos, Spanned};
-use swc_ecma_ast::TsTypeElement;
+use swc_ecma_ast::{ClassMember, TsTypeElement};
 use swc_ecma_visit::VisitMut;
 
 pub struct InternalAnnotationTransformer<'a> {
@@ -19,4 +19,8 @@ impl VisitMut for InternalAnnotationTransformer<'_> {
     fn visit_mut_ts_type_elements(&mut self, node: &mut Vec<TsTypeElement>) {
         node.retain(|elem| !self.internal_annotations.contains(&elem.span_lo()));
     }
+
+    fn visit_mut_class_members(&mut self, node: &mut Vec<ClassMember>) {
+        node.retain(|member| !self.internal_annotations.contains(&member.span_lo()));
+    }
 }
@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Error};
+use rustc_hash::FxHashMap;
+use swc_common::{comments::SingleThreadedComments, sync::Lrc, FileName, SourceMap};
+use swc_ecma_codegen::to_code_with_comments;
+use swc_ecma_loader::resolve::{Resolution, Resolve};
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
+use swc_typescript::{
+    dts_bundler::{DtsBundler, DtsBundlerConfig, DtsModule, Load},
+    fast_dts::FastDts,
+};
+use testing::NormalizedOutput;
+
+struct MemoryLoader {
+    cm: Lrc<SourceMap>,
+    comments: SingleThreadedComments,
+    files: FxHashMap<String, &'static str>,
+}
+
+impl Load for MemoryLoader {
+    fn load(&self, file: &FileName) -> Result<DtsModule, Error> {
+        let FileName::Custom(name) = file else {
+            unreachable!()
+        };
+        let src = self
+            .files
+            .get(name)
+            .ok_or_else(|| anyhow!("{name} does not exist"))?;
+
+        let fm = self
+            .cm
+            .new_source_file(file.clone().into(), src.to_string());
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::Typescript(TsSyntax {
+                dts: true,
+                ..Default::default()
+            }),
+            Default::default(),
+            Some(&self.comments),
+            &mut Vec::new(),
+        )
+        .map_err(|err| anyhow!("failed to parse {name}: {err:?}"))?;
+
+        Ok(DtsModule {
+            module,
+            internal_annotations: FastDts::get_internal_annotations(&self.comments),
+        })
+    }
+}
+
+/// Resolves `./foo` and `pkg` to `foo.d.ts` and `pkg.d.ts`.
+struct MemoryResolver;
+
+impl Resolve for MemoryResolver {
+    fn resolve(&self, _: &FileName, src: &str) -> Result<Resolution, Error> {
+        Ok(Resolution {
+            filename: FileName::Custom(format!("{}.d.ts", src.trim_start_matches("./"))),
+            slug: None,
+        })
+    }
+}
+
+fn bundle(name: &str, files: &[(&str, &'static str)], config: DtsBundlerConfig) {
+    let cm = Lrc::<SourceMap>::default();
+    let loader = MemoryLoader {
+        cm,
+        comments: Default::default(),
+        files: files
+            .iter()
+            .map(|(name, src)| (name.to_string(), *src))
+            .collect(),
+    };
+
+    let output = testing::run_test2(false, |_, _| {
+        let bundler = DtsBundler::new(&loader, &MemoryResolver, Some(&loader.comments), config);
+
+        match bundler.bundle(&FileName::Custom("index.d.ts".into())) {
+            Ok(module) => Ok(to_code_with_comments(Some(&loader.comments), &module)),
+            Err(err) => Ok(format!("{err:#}")),
+        }
+    })
+    .unwrap();
+
+    NormalizedOutput::from(output)
+        .compare_to_file(Path::new("tests/dts_bundler").join(format!("{name}.snap")))
+        .unwrap();
+}
+
+#[test]
+fn reexports() {
+    bundle(
+        "reexports",
+        &[
+            (
+                "index.d.ts",
+                r#"
+                export * from "./shapes";
+                export { Color as Colour, type Palette } from "./colors";
+                export * as utils from "./utils";
+                import type { Options } from "./options";
+                /** Creates a canvas. */
+                export declare function createCanvas(options: Options): Canvas;
+                export interface Canvas {
+                    shapes: Shape[];
+                    meta: Meta;
+                }
+                interface Meta {
+                    width: number;
+                }
+                import { Shape } from "./shapes";
+                export default Canvas;
+                "#,
+            ),
+            (
+                "shapes.d.ts",
+                r#"
+                import { Color } from "./colors";
+                export interface Shape {
+                    color: Color;
+                    meta: Meta;
+                }
+                interface Meta {
+                    id: string;
+                }
+                export declare class Circle implements Shape {
+                    color: Color;
+                    radius: number;
+                }
+                "#,
+            ),
+            (
+                "colors.d.ts",
+                r#"
+                export type Color = "red" | "green";
+                export type Palette = Color[];
+                export type Unused = number;
+                "#,
+            ),
+            (
+                "options.d.ts",
+                r#"
+                import type { Color as Canvas } from "./colors";
+                import type { Readable } from "node:stream";
+                export interface Options {
+                    background: Canvas;
+                    stream: Readable;
+                }
+                export * from "./colors";
+                "#,
+            ),
+            (
+                "utils.d.ts",
+                r#"
+                import type { Readable } from "node:stream";
+                export declare function toStream(): Readable;
+                export declare const version: string;
+                "#,
+            ),
+        ],
+        Default::default(),
+    );
+}
+
+#[test]
+fn strip_internal() {
+    bundle(
+        "strip_internal",
+        &[
+            (
+                "index.d.ts",
+                r#"
+                export { Client } from "./client";
+                /** @internal */
+                export declare function debug(): void;
+                "#,
+            ),
+            (
+                "client.d.ts",
+                r#"
+                import { Config } from "./config";
+                export declare class Client {
+                    constructor(config: Config);
+                    /** @internal */
+                    _socket: unknown;
+                    send(data: string): void;
+                }
+                "#,
+            ),
+            (
+                "config.d.ts",
+                r#"
+                export interface Config {
+                    url: string;
+                    /** @internal */
+                    retries?: number;
+                }
+                "#,
+            ),
+        ],
+        DtsBundlerConfig {
+            strip_internal: true,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn inline_packages() {
+    bundle(
+        "inline_packages",
+        &[
+            (
+                "index.d.ts",
+                r#"
+                import type { Event } from "@scope/events";
+                import type { Stream } from "stream-lib";
+                export * from "external";
+                export interface Listener {
+                    on(event: Event, stream: Stream): void;
+                }
+                "#,
+            ),
+            (
+                "@scope/events.d.ts",
+                r#"
+                export interface Event {
+                    name: string;
+                }
+                "#,
+            ),
+        ],
+        DtsBundlerConfig {
+            inline_packages: vec!["@scope/events".into()],
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn export_assignment() {
+    bundle(
+        "export_assignment",
+        &[
+            ("index.d.ts", r#"export * from "./legacy";"#),
+            (
+                "legacy.d.ts",
+                r#"
+                declare function legacy(): void;
+                export = legacy;
+                "#,
+            ),
+        ],
+        Default::default(),
+    );
+}
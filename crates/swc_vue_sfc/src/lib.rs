@@ -0,0 +1,267 @@
+//! Compiler for Vue single-file components.
+//!
+//! [parse] splits a `.vue` file into its blocks, and [compile] compiles them
+//! to a JavaScript module which exports the component, and a stylesheet for
+//! each `<style>` block.
+//!
+//! - `<script setup>` is compiled to the `setup()` function of the component.
+//!   `defineProps()` and `defineEmits()` accept both runtime declarations and
+//!   TypeScript types, which are resolved in the same file.
+//! - `<template>` is compiled to a render function. Static subtrees are hoisted
+//!   out of the render function.
+//! - `<style scoped>` and `<style module>` are scoped to the component.
+//!
+//! Every block is parsed in place, so the spans of the module and the
+//! stylesheets point to the original file and can be used for source maps.
+//!
+//! TypeScript is stripped with marks, so [compile] must be called within
+//! [swc_common::GLOBALS].
+#![deny(clippy::all)]
+
+use std::hash::{Hash, Hasher};
+
+use anyhow::{bail, Error};
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+use swc_common::{FileName, Mark, SourceFile, DUMMY_SP};
+use swc_css_ast::Stylesheet;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::resolver;
+use swc_ecma_transforms_typescript::strip;
+use swc_ecma_utils::{quote_ident, quote_str};
+
+pub use self::parse::{parse, SfcBlock, SfcDescriptor};
+use self::{
+    script::compile_script,
+    style::compile_style,
+    template::{compile_template, TemplateOptions},
+};
+
+mod parse;
+mod script;
+mod style;
+mod template;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompileOptions {
+    /// The id of the component used by scoped styles and CSS modules.
+    ///
+    /// Defaults to a hash of the file name.
+    #[serde(default)]
+    pub id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct CompiledComponent {
+    /// A module which exports the component as the default export.
+    pub module: Module,
+    /// Compiled `<style>` blocks, in order.
+    pub styles: Vec<Stylesheet>,
+}
+
+/// Compiles the blocks of a single-file component parsed by [parse].
+pub fn compile(
+    fm: &SourceFile,
+    descriptor: &SfcDescriptor,
+    options: &CompileOptions,
+) -> Result<CompiledComponent, Error> {
+    let id = options.id.clone().unwrap_or_else(|| {
+        let mut hasher = FxHasher::default();
+        fm.name.to_string().hash(&mut hasher);
+
+        format!("{:08x}", hasher.finish() as u32)
+    });
+
+    let is_ts = match (&descriptor.script, &descriptor.script_setup) {
+        (Some(script), Some(setup)) if script.lang() != setup.lang() => {
+            bail!(
+                "<script> and <script setup> of `{}` must have the same lang",
+                fm.name
+            )
+        }
+        (Some(block), _) | (None, Some(block)) => match block.lang() {
+            None | Some("js") => false,
+            Some("ts") => true,
+            Some(lang) => bail!("unsupported script language `{lang}` in `{}`", fm.name),
+        },
+        (None, None) => false,
+    };
+
+    let mut styles = Vec::new();
+    let mut css_modules = Vec::new();
+    let mut is_scoped = false;
+
+    for block in &descriptor.styles {
+        let style = compile_style(fm, block, &id)?;
+
+        styles.push(style.stylesheet);
+        css_modules.extend(style.module);
+        is_scoped |= style.is_scoped;
+    }
+
+    let script = compile_script(fm, descriptor, is_ts)?;
+    let bindings = script.bindings();
+
+    let template = match &descriptor.template {
+        Some(block) => {
+            match block.lang() {
+                None | Some("html") => {}
+                Some(lang) => bail!("unsupported template language `{lang}` in `{}`", fm.name),
+            }
+
+            Some(compile_template(
+                fm,
+                block,
+                &TemplateOptions {
+                    is_ts,
+                    bindings: &bindings,
+                    // Hoisted vnodes are created outside of the component, so they
+                    // wouldn't get the scope id
+                    hoist_static: !is_scoped,
+                },
+            )?)
+        }
+        None => None,
+    };
+
+    let used = template
+        .as_ref()
+        .map(|template| template.used.clone())
+        .unwrap_or_default();
+    let mut body = script.finish(&component_name(&fm.name), &used);
+
+    if let Some(template) = template {
+        // Helpers of the render function are imported after the imports of the
+        // script
+        let (imports, items): (Vec<_>, Vec<_>) = template
+            .items
+            .into_iter()
+            .partition(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..))));
+        let index = body
+            .iter()
+            .position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..))))
+            .unwrap_or(body.len());
+
+        body.splice(index..index, imports);
+        body.extend(items);
+        body.push(set_option("render", ident("_sfc_render")));
+    }
+    if is_scoped {
+        body.push(set_option(
+            "__scopeId",
+            quote_str!(format!("data-v-{id}")).into(),
+        ));
+    }
+    if !css_modules.is_empty() {
+        let modules = css_modules
+            .into_iter()
+            .map(|(name, classes)| {
+                let classes = classes
+                    .into_iter()
+                    .map(|(local, names)| key_value(&local, quote_str!(names).into()))
+                    .collect();
+
+                key_value(&name, object(classes))
+            })
+            .collect();
+
+        body.push(set_option("__cssModules", object(modules)));
+    }
+
+    body.push(
+        ExportDefaultExpr {
+            span: DUMMY_SP,
+            expr: Box::new(ident("_sfc_main")),
+        }
+        .into(),
+    );
+
+    let mut module = Module {
+        span: DUMMY_SP,
+        body,
+        shebang: None,
+    };
+
+    if is_ts {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+
+        module = Program::Module(module)
+            .apply(resolver(unresolved_mark, top_level_mark, true))
+            .apply(strip(unresolved_mark, top_level_mark))
+            .expect_module();
+    }
+
+    Ok(CompiledComponent { module, styles })
+}
+
+/// The name of the component for devtools and warnings, e.g. `App` for
+/// `App.vue`.
+fn component_name(name: &FileName) -> String {
+    match name {
+        FileName::Real(path) => path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        name => {
+            let name = name.to_string();
+
+            name.rsplit(['/', '\\'])
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches(".vue")
+                .to_string()
+        }
+    }
+}
+
+/// `_sfc_main.name = value;`
+fn set_option(name: &str, value: Expr) -> ModuleItem {
+    let target = MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(ident("_sfc_main")),
+        prop: MemberProp::Ident(quote_ident!(name)),
+    };
+
+    ModuleItem::Stmt(
+        ExprStmt {
+            span: DUMMY_SP,
+            expr: Box::new(
+                AssignExpr {
+                    span: DUMMY_SP,
+                    op: AssignOp::Assign,
+                    left: target.into(),
+                    right: Box::new(value),
+                }
+                .into(),
+            ),
+        }
+        .into(),
+    )
+}
+
+fn ident(name: &str) -> Expr {
+    Ident::new_no_ctxt(name.into(), DUMMY_SP).into()
+}
+
+fn object(props: Vec<PropOrSpread>) -> Expr {
+    ObjectLit {
+        span: DUMMY_SP,
+        props,
+    }
+    .into()
+}
+
+fn key_value(key: &str, value: Expr) -> PropOrSpread {
+    let key = if swc_ecma_utils::is_valid_prop_ident(key) {
+        PropName::Ident(quote_ident!(key))
+    } else {
+        PropName::Str(quote_str!(key))
+    };
+
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key,
+        value: Box::new(value),
+    })))
+}
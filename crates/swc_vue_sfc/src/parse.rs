@@ -0,0 +1,164 @@
+use anyhow::{bail, Error};
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, BytePos, SourceFile, Span, DUMMY_SP};
+use swc_html_ast::{Attribute, Child, DocumentMode, Element, Namespace};
+use swc_html_parser::{parse_file_as_document_fragment, parser::ParserConfig};
+
+/// Blocks of a single-file component.
+#[derive(Debug, Clone, Default)]
+pub struct SfcDescriptor {
+    pub template: Option<SfcBlock>,
+    pub script: Option<SfcBlock>,
+    pub script_setup: Option<SfcBlock>,
+    pub styles: Vec<SfcBlock>,
+    /// Blocks other than `<template>`, `<script>` and `<style>`, e.g. `<i18n>`.
+    pub custom_blocks: Vec<SfcBlock>,
+}
+
+/// A top-level block of a single-file component.
+#[derive(Debug, Clone)]
+pub struct SfcBlock {
+    /// The block element. The children of `<template>` are in
+    /// [Element::content].
+    pub element: Element,
+    /// The span of the content between the start tag and the end tag.
+    pub content_span: Span,
+}
+
+impl SfcBlock {
+    pub fn tag_name(&self) -> &Atom {
+        &self.element.tag_name
+    }
+
+    /// Returns the value of the attribute `name`, or an empty string if the
+    /// attribute has no value.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.element
+            .attributes
+            .iter()
+            .find(|attr| attr.name == *name)
+            .map(|attr| attr.value.as_deref().unwrap_or_default())
+    }
+
+    pub fn lang(&self) -> Option<&str> {
+        self.attr("lang")
+    }
+
+    /// Returns the content of the block.
+    pub fn content<'a>(&self, fm: &'a SourceFile) -> &'a str {
+        slice(fm, self.content_span)
+    }
+}
+
+/// Splits a single-file component into its blocks.
+///
+/// The content of each block keeps its position in `fm`, so the spans of
+/// compiled blocks point to the original file.
+pub fn parse(fm: &SourceFile) -> Result<SfcDescriptor, Error> {
+    let context_element = Element {
+        span: DUMMY_SP,
+        tag_name: "template".into(),
+        namespace: Namespace::HTML,
+        attributes: Vec::new(),
+        children: Vec::new(),
+        content: None,
+        is_self_closing: false,
+    };
+    let mut errors = Vec::new();
+    let fragment = parse_file_as_document_fragment(
+        fm,
+        &context_element,
+        DocumentMode::NoQuirks,
+        None,
+        ParserConfig {
+            // For components like `<MyComponent />`
+            allow_self_closing: true,
+            ..Default::default()
+        },
+        &mut errors,
+    );
+    let fragment = match fragment {
+        Ok(fragment) => fragment,
+        Err(err) => {
+            HANDLER.with(|handler| err.to_diagnostics(handler).emit());
+            bail!("failed to parse `{}`", fm.name)
+        }
+    };
+
+    let mut descriptor = SfcDescriptor::default();
+
+    for child in fragment.children {
+        let Child::Element(element) = child else {
+            continue;
+        };
+        let content_span = content_span(fm, &element);
+        let block = SfcBlock {
+            element,
+            content_span,
+        };
+
+        match &**block.tag_name() {
+            "template" => {
+                if descriptor.template.is_some() {
+                    bail!("`{}` has multiple <template> blocks", fm.name)
+                }
+
+                descriptor.template = Some(block);
+            }
+            "script" => {
+                let slot = if block.attr("setup").is_some() {
+                    &mut descriptor.script_setup
+                } else {
+                    &mut descriptor.script
+                };
+
+                if slot.is_some() {
+                    bail!(
+                        "`{}` has multiple <script> blocks of the same kind",
+                        fm.name
+                    )
+                }
+
+                *slot = Some(block);
+            }
+            "style" => descriptor.styles.push(block),
+            _ => descriptor.custom_blocks.push(block),
+        }
+    }
+
+    Ok(descriptor)
+}
+
+/// Returns the source text of `span`.
+pub(crate) fn slice(fm: &SourceFile, span: Span) -> &str {
+    let lo = (span.lo - fm.start_pos).0 as usize;
+    let hi = (span.hi - fm.start_pos).0 as usize;
+
+    &fm.src[lo..hi]
+}
+
+/// The span of an element includes its tags, so we find the end of the start
+/// tag and the start of the end tag in the source.
+fn content_span(fm: &SourceFile, element: &Element) -> Span {
+    let source = slice(fm, element.span);
+    let start_tag_end = element
+        .attributes
+        .last()
+        .map_or(element.tag_name.len() + 1, |attr: &Attribute| {
+            (attr.span.hi - element.span.lo).0 as usize
+        });
+    let lo = source[start_tag_end..]
+        .find('>')
+        .map_or(source.len(), |pos| start_tag_end + pos + 1);
+    let end_tag = format!("</{}", element.tag_name);
+    let hi = source
+        .to_ascii_lowercase()
+        .rfind(&end_tag)
+        .filter(|&pos| pos >= lo)
+        .unwrap_or(source.len());
+
+    Span::new(
+        element.span.lo + BytePos(lo as u32),
+        element.span.lo + BytePos(hi as u32),
+    )
+}
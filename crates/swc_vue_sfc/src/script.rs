@@ -0,0 +1,1047 @@
+//! Compiles `<script>` and `<script setup>`.
+//!
+//! `<script setup>` is compiled to the `setup()` function of the component,
+//! which returns the top-level bindings to the render function. Compiler
+//! macros like `defineProps` are replaced with the parameters of `setup()` and
+//! the options of the component.
+
+use anyhow::{bail, Error};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, SourceFile, Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{EsSyntax, Parser, StringInput, Syntax, TsSyntax};
+use swc_ecma_utils::{find_pat_ids, quote_ident, quote_str, ExprFactory};
+
+use crate::parse::{SfcBlock, SfcDescriptor};
+
+/// The maximum depth of type references resolved for type-based props.
+const MAX_TYPE_DEPTH: usize = 32;
+
+pub(crate) struct CompiledScript {
+    /// Statements of `<script>` and imports of both scripts.
+    items: Vec<ModuleItem>,
+    /// The default export of `<script>`.
+    default: Option<Expr>,
+    setup: Option<Setup>,
+}
+
+struct Setup {
+    /// The argument of `defineOptions()`.
+    options: Option<Expr>,
+    props: Option<Props>,
+    emits: Option<Expr>,
+    stmts: Vec<Stmt>,
+    /// Top-level bindings. Imports of TypeScript are returned only if they are
+    /// used by the template, as they may be types.
+    bindings: Vec<(Atom, bool)>,
+    has_expose: bool,
+}
+
+enum Props {
+    Runtime(Expr),
+    Typed {
+        props: Vec<TypedProp>,
+        /// The second argument of `withDefaults()`.
+        defaults: Option<Expr>,
+    },
+}
+
+struct TypedProp {
+    name: Atom,
+    /// Constructors of the runtime type check, or empty for any type.
+    types: Vec<&'static str>,
+    required: bool,
+}
+
+impl CompiledScript {
+    /// Returns the names which can be used by the template.
+    pub fn bindings(&self) -> FxHashSet<Atom> {
+        self.setup
+            .iter()
+            .flat_map(|setup| setup.bindings.iter().map(|(name, _)| name.clone()))
+            .collect()
+    }
+
+    /// Returns the module items declaring `_sfc_main`. `used` are the names
+    /// used by the template.
+    pub fn finish(self, name: &str, used: &FxHashSet<Atom>) -> Vec<ModuleItem> {
+        let mut items = self.items;
+
+        let Some(setup) = self.setup else {
+            let component = self.default.unwrap_or_else(|| object(Vec::new()));
+
+            items.push(const_decl("_sfc_main", component).into());
+            return items;
+        };
+
+        let mut options = Vec::new();
+
+        if let Some(default) = self.default {
+            items.push(const_decl("_sfc_default", default).into());
+            options.push(spread(ident("_sfc_default")));
+        }
+        if let Some(extra) = setup.options {
+            options.push(spread(extra));
+        }
+
+        options.push(key_value("__name", quote_str!(name).into()));
+
+        if let Some(props) = setup.props {
+            let props = match props {
+                Props::Runtime(props) => props,
+                Props::Typed { props, defaults } => {
+                    let (props, merge) = typed_props_object(props, defaults);
+
+                    match merge {
+                        Some(defaults) => {
+                            items.insert(0, vue_import("mergeDefaults"));
+
+                            ident("_mergeDefaults")
+                                .as_call(DUMMY_SP, vec![props.as_arg(), defaults.as_arg()])
+                        }
+                        None => props,
+                    }
+                }
+            };
+
+            options.push(key_value("props", props));
+        }
+        if let Some(emits) = setup.emits {
+            options.push(key_value("emits", emits));
+        }
+
+        let mut stmts = Vec::new();
+
+        // Closes the instance unless `defineExpose` is used
+        if !setup.has_expose {
+            stmts.push(ident("__expose").as_call(DUMMY_SP, Vec::new()).into_stmt());
+        }
+        stmts.extend(setup.stmts);
+
+        let mut returned = FxHashSet::default();
+        let returned = setup
+            .bindings
+            .into_iter()
+            .filter(|(name, is_ts_import)| !is_ts_import || used.contains(name))
+            .filter(|(name, _)| returned.insert(name.clone()))
+            .map(|(name, _)| {
+                PropOrSpread::Prop(Box::new(Prop::Shorthand(Ident::new_no_ctxt(
+                    name, DUMMY_SP,
+                ))))
+            })
+            .collect();
+        stmts.push(
+            ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(object(returned))),
+            }
+            .into(),
+        );
+
+        let context = ObjectPat {
+            span: DUMMY_SP,
+            props: [("expose", "__expose"), ("emit", "__emit")]
+                .into_iter()
+                .map(|(key, value)| {
+                    ObjectPatProp::KeyValue(KeyValuePatProp {
+                        key: PropName::Ident(quote_ident!(key)),
+                        value: Box::new(Ident::new_no_ctxt(value.into(), DUMMY_SP).into()),
+                    })
+                })
+                .collect(),
+            optional: false,
+            type_ann: None,
+        };
+
+        options.push(PropOrSpread::Prop(Box::new(Prop::Method(MethodProp {
+            key: PropName::Ident(quote_ident!("setup")),
+            function: Box::new(Function {
+                params: vec![
+                    Pat::from(Ident::new_no_ctxt("__props".into(), DUMMY_SP)).into(),
+                    Pat::from(context).into(),
+                ],
+                body: Some(BlockStmt {
+                    stmts,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        }))));
+
+        items.push(const_decl("_sfc_main", object(options)).into());
+        items
+    }
+}
+
+pub(crate) fn compile_script(
+    fm: &SourceFile,
+    descriptor: &SfcDescriptor,
+    is_ts: bool,
+) -> Result<CompiledScript, Error> {
+    let mut items = Vec::new();
+    let mut default = None;
+    let mut types = TypeDecls::default();
+    let mut bindings = Vec::new();
+
+    if let Some(block) = &descriptor.script {
+        let module = parse_block(fm, block, is_ts)?;
+
+        for item in module.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+                    default = Some(*export.expr);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Class(class),
+                    ..
+                })) => default = Some(class.into()),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Fn(function),
+                    ..
+                })) => default = Some(function.into()),
+                item => {
+                    collect_types(&item, &mut types);
+                    collect_bindings(&item, is_ts, &mut bindings);
+                    items.push(item);
+                }
+            }
+        }
+    }
+
+    let Some(block) = &descriptor.script_setup else {
+        return Ok(CompiledScript {
+            items,
+            default,
+            setup: None,
+        });
+    };
+
+    let module = parse_block(fm, block, is_ts)?;
+
+    for item in &module.body {
+        collect_types(item, &mut types);
+    }
+
+    let mut compiler = SetupCompiler {
+        types: &types,
+        options: None,
+        props: None,
+        emits: None,
+        has_expose: false,
+        uses_slots: false,
+        has_error: false,
+    };
+    let mut stmts = Vec::new();
+
+    for item in module.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(..)) => {
+                collect_bindings(&item, is_ts, &mut bindings);
+                items.push(item);
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::TsInterface(..) | Decl::TsTypeAlias(..),
+                ..
+            })) => items.push(item),
+            ModuleItem::ModuleDecl(decl) => {
+                compiler.error(
+                    decl.span(),
+                    "<script setup> cannot contain ES module exports",
+                );
+            }
+            ModuleItem::Stmt(stmt) => {
+                if let Some(stmt) = compiler.transform_stmt(stmt) {
+                    let item = ModuleItem::Stmt(stmt);
+
+                    collect_bindings(&item, is_ts, &mut bindings);
+                    stmts.push(item.expect_stmt());
+                }
+            }
+        }
+    }
+
+    if compiler.has_error {
+        bail!("failed to compile <script setup> of `{}`", fm.name)
+    }
+
+    if compiler.uses_slots {
+        items.insert(0, vue_import("useSlots"));
+    }
+
+    Ok(CompiledScript {
+        items,
+        default,
+        setup: Some(Setup {
+            options: compiler.options,
+            props: compiler.props,
+            emits: compiler.emits,
+            stmts,
+            bindings,
+            has_expose: compiler.has_expose,
+        }),
+    })
+}
+
+fn parse_block(fm: &SourceFile, block: &SfcBlock, is_ts: bool) -> Result<Module, Error> {
+    let syntax = if is_ts {
+        Syntax::Typescript(TsSyntax::default())
+    } else {
+        Syntax::Es(EsSyntax::default())
+    };
+    let input = StringInput::new(
+        block.content(fm),
+        block.content_span.lo,
+        block.content_span.hi,
+    );
+    let mut parser = Parser::new(syntax, input, None);
+
+    let result = parser.parse_module();
+    let errors = parser.take_errors();
+
+    HANDLER.with(|handler| {
+        let has_error = !errors.is_empty();
+
+        for err in errors {
+            err.into_diagnostic(handler).emit();
+        }
+
+        match result {
+            Ok(module) if !has_error => Ok(module),
+            Ok(..) => bail!("failed to parse <script> of `{}`", fm.name),
+            Err(err) => {
+                err.into_diagnostic(handler).emit();
+                bail!("failed to parse <script> of `{}`", fm.name)
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct TypeDecls {
+    interfaces: FxHashMap<Atom, TsInterfaceDecl>,
+    aliases: FxHashMap<Atom, TsTypeAliasDecl>,
+}
+
+fn collect_types(item: &ModuleItem, types: &mut TypeDecls) {
+    let decl = match item {
+        ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+        _ => return,
+    };
+
+    match decl {
+        Decl::TsInterface(interface) => {
+            types
+                .interfaces
+                .insert(interface.id.sym.clone(), (**interface).clone());
+        }
+        Decl::TsTypeAlias(alias) => {
+            types
+                .aliases
+                .insert(alias.id.sym.clone(), (**alias).clone());
+        }
+        _ => {}
+    }
+}
+
+fn collect_bindings(item: &ModuleItem, is_ts: bool, bindings: &mut Vec<(Atom, bool)>) {
+    let decl = match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+            if import.type_only {
+                return;
+            }
+
+            for specifier in &import.specifiers {
+                let (local, is_type_only) = match specifier {
+                    ImportSpecifier::Named(named) => (&named.local, named.is_type_only),
+                    ImportSpecifier::Default(default) => (&default.local, false),
+                    ImportSpecifier::Namespace(namespace) => (&namespace.local, false),
+                };
+
+                if !is_type_only {
+                    bindings.push((local.sym.clone(), is_ts));
+                }
+            }
+            return;
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+        ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+        _ => return,
+    };
+
+    match decl {
+        Decl::Class(class) => bindings.push((class.ident.sym.clone(), false)),
+        Decl::Fn(function) => bindings.push((function.ident.sym.clone(), false)),
+        Decl::Var(var) => {
+            let names: Vec<Atom> = find_pat_ids(&var.decls);
+
+            bindings.extend(names.into_iter().map(|name| (name, false)));
+        }
+        Decl::TsEnum(enum_decl) => bindings.push((enum_decl.id.sym.clone(), false)),
+        _ => {}
+    }
+}
+
+struct SetupCompiler<'a> {
+    types: &'a TypeDecls,
+    options: Option<Expr>,
+    props: Option<Props>,
+    emits: Option<Expr>,
+    has_expose: bool,
+    uses_slots: bool,
+    has_error: bool,
+}
+
+impl SetupCompiler<'_> {
+    fn error(&mut self, span: Span, msg: &str) {
+        HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+        self.has_error = true;
+    }
+
+    /// Returns `None` if the statement is removed.
+    fn transform_stmt(&mut self, stmt: Stmt) -> Option<Stmt> {
+        match stmt {
+            Stmt::Expr(ExprStmt { span, expr }) => {
+                let expr = self.transform_macro(*expr, true)?;
+
+                Some(
+                    ExprStmt {
+                        span,
+                        expr: Box::new(expr),
+                    }
+                    .into(),
+                )
+            }
+            Stmt::Decl(Decl::Var(mut var)) => {
+                for decl in &mut var.decls {
+                    let Some(init) = decl.init.take() else {
+                        continue;
+                    };
+
+                    let is_props =
+                        is_macro_call(&init, "defineProps") || is_macro_call(&init, "withDefaults");
+                    if is_props && !matches!(decl.name, Pat::Ident(..)) {
+                        self.error(
+                            decl.name.span(),
+                            "destructuring the result of defineProps() is not supported",
+                        );
+                    }
+
+                    let init = self
+                        .transform_macro(*init, false)
+                        .unwrap_or_else(|| *Expr::undefined(DUMMY_SP));
+                    decl.init = Some(Box::new(init));
+                }
+
+                Some(var.into())
+            }
+            stmt => Some(stmt),
+        }
+    }
+
+    /// Replaces a macro call. Returns `None` if the call is removed, which is
+    /// only allowed for expression statements.
+    fn transform_macro(&mut self, expr: Expr, is_stmt: bool) -> Option<Expr> {
+        let Expr::Call(call) = &expr else {
+            return Some(expr);
+        };
+        let Callee::Expr(callee) = &call.callee else {
+            return Some(expr);
+        };
+        let Expr::Ident(callee) = &**callee else {
+            return Some(expr);
+        };
+
+        let replacement = match &*callee.sym {
+            "defineProps" => {
+                self.define_props(call, None);
+                "__props"
+            }
+            "withDefaults" => {
+                match call.args.first().map(|arg| &*arg.expr) {
+                    Some(first @ Expr::Call(define_props))
+                        if is_macro_call(first, "defineProps") =>
+                    {
+                        let defaults = call.args.get(1).map(|arg| *arg.expr.clone());
+
+                        self.define_props(define_props, defaults);
+                    }
+                    _ => self.error(
+                        call.span,
+                        "the first argument of withDefaults() must be defineProps()",
+                    ),
+                }
+                "__props"
+            }
+            "defineEmits" => {
+                self.define_emits(call);
+                "__emit"
+            }
+            "defineExpose" => {
+                self.has_expose = true;
+
+                return Some(ident("__expose").as_call(call.span, call.args.clone()));
+            }
+            "defineOptions" => {
+                if self.options.is_some() {
+                    self.error(call.span, "duplicate defineOptions() call");
+                }
+                self.options = call.args.first().map(|arg| *arg.expr.clone());
+
+                return (!is_stmt).then(|| *Expr::undefined(DUMMY_SP));
+            }
+            "defineSlots" => {
+                self.uses_slots = true;
+
+                return (!is_stmt).then(|| ident("_useSlots").as_call(call.span, Vec::new()));
+            }
+            "defineModel" => {
+                self.error(call.span, "defineModel() is not supported");
+                return Some(expr);
+            }
+            _ => return Some(expr),
+        };
+
+        (!is_stmt).then(|| ident(replacement))
+    }
+
+    fn define_props(&mut self, call: &CallExpr, defaults: Option<Expr>) {
+        if self.props.is_some() {
+            self.error(call.span, "duplicate defineProps() call");
+            return;
+        }
+
+        let props = match (&call.type_args, call.args.first()) {
+            (Some(type_args), None) => {
+                let props = self.resolve_props(&type_args.params[0]);
+
+                Props::Typed { props, defaults }
+            }
+            (None, Some(arg)) => {
+                if defaults.is_some() {
+                    self.error(
+                        call.span,
+                        "withDefaults() can only be used with type-based defineProps()",
+                    );
+                }
+
+                Props::Runtime(*arg.expr.clone())
+            }
+            (None, None) => Props::Runtime(object(Vec::new())),
+            (Some(..), Some(..)) => {
+                self.error(
+                    call.span,
+                    "defineProps() cannot accept both type and non-type arguments",
+                );
+                return;
+            }
+        };
+
+        self.props = Some(props);
+    }
+
+    fn resolve_props(&mut self, ty: &TsType) -> Vec<TypedProp> {
+        let Some(members) = resolve_members(self.types, ty, 0) else {
+            self.error(ty.span(), "unresolvable type of defineProps()");
+            return Vec::new();
+        };
+
+        let mut props = Vec::new();
+
+        for member in members {
+            let (key, computed, types, required) = match member {
+                TsTypeElement::TsPropertySignature(prop) => {
+                    let mut types = Vec::new();
+                    let is_known = prop.type_ann.as_ref().is_some_and(|type_ann| {
+                        runtime_types(self.types, &type_ann.type_ann, 0, &mut types)
+                    });
+
+                    (
+                        &prop.key,
+                        prop.computed,
+                        if is_known { types } else { Vec::new() },
+                        !prop.optional,
+                    )
+                }
+                TsTypeElement::TsMethodSignature(method) => (
+                    &method.key,
+                    method.computed,
+                    vec!["Function"],
+                    !method.optional,
+                ),
+                _ => continue,
+            };
+
+            match prop_name(key, computed) {
+                Some(name) => props.push(TypedProp {
+                    name,
+                    types,
+                    required,
+                }),
+                None => self.error(key.span(), "unsupported key of a prop"),
+            }
+        }
+
+        props
+    }
+
+    fn define_emits(&mut self, call: &CallExpr) {
+        if self.emits.is_some() {
+            self.error(call.span, "duplicate defineEmits() call");
+            return;
+        }
+
+        let emits = match (&call.type_args, call.args.first()) {
+            (Some(type_args), None) => {
+                let ty = &type_args.params[0];
+
+                match resolve_emits(self.types, ty) {
+                    Some(events) => array(
+                        events
+                            .into_iter()
+                            .map(|event| quote_str!(event).into())
+                            .collect(),
+                    ),
+                    None => {
+                        self.error(ty.span(), "unresolvable type of defineEmits()");
+                        return;
+                    }
+                }
+            }
+            (None, Some(arg)) => *arg.expr.clone(),
+            (None, None) => array(Vec::new()),
+            (Some(..), Some(..)) => {
+                self.error(
+                    call.span,
+                    "defineEmits() cannot accept both type and non-type arguments",
+                );
+                return;
+            }
+        };
+
+        self.emits = Some(emits);
+    }
+}
+
+fn is_macro_call(expr: &Expr, name: &str) -> bool {
+    match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => matches!(&**callee, Expr::Ident(callee) if callee.sym == *name),
+        _ => false,
+    }
+}
+
+/// Returns the members of an object type, including the members of extended
+/// interfaces.
+fn resolve_members<'a>(
+    types: &'a TypeDecls,
+    ty: &'a TsType,
+    depth: usize,
+) -> Option<Vec<&'a TsTypeElement>> {
+    if depth > MAX_TYPE_DEPTH {
+        return None;
+    }
+
+    match ty {
+        TsType::TsTypeLit(lit) => Some(lit.members.iter().collect()),
+        TsType::TsParenthesizedType(paren) => resolve_members(types, &paren.type_ann, depth + 1),
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+            intersection,
+        )) => {
+            let mut members = Vec::new();
+
+            for ty in &intersection.types {
+                members.extend(resolve_members(types, ty, depth + 1)?);
+            }
+
+            Some(members)
+        }
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(name),
+            type_params: None,
+            ..
+        }) => {
+            if let Some(interface) = types.interfaces.get(&name.sym) {
+                return resolve_interface_members(types, interface, depth + 1);
+            }
+
+            let alias = types.aliases.get(&name.sym)?;
+
+            resolve_members(types, &alias.type_ann, depth + 1)
+        }
+        _ => None,
+    }
+}
+
+fn resolve_interface_members<'a>(
+    types: &'a TypeDecls,
+    interface: &'a TsInterfaceDecl,
+    depth: usize,
+) -> Option<Vec<&'a TsTypeElement>> {
+    if depth > MAX_TYPE_DEPTH {
+        return None;
+    }
+
+    let mut members = Vec::new();
+
+    for parent in &interface.extends {
+        let Expr::Ident(parent) = &*parent.expr else {
+            return None;
+        };
+        let parent = types.interfaces.get(&parent.sym)?;
+
+        members.extend(resolve_interface_members(types, parent, depth + 1)?);
+    }
+    members.extend(interface.body.body.iter());
+
+    Some(members)
+}
+
+/// Adds the constructors of the runtime type check of `ty` to `out`. Returns
+/// `false` if the type can't be checked at runtime.
+fn runtime_types(
+    types: &TypeDecls,
+    ty: &TsType,
+    depth: usize,
+    out: &mut Vec<&'static str>,
+) -> bool {
+    if depth > MAX_TYPE_DEPTH {
+        return false;
+    }
+
+    let constructor = match ty {
+        TsType::TsKeywordType(keyword) => match keyword.kind {
+            TsKeywordTypeKind::TsStringKeyword => "String",
+            TsKeywordTypeKind::TsNumberKeyword => "Number",
+            TsKeywordTypeKind::TsBooleanKeyword => "Boolean",
+            TsKeywordTypeKind::TsObjectKeyword => "Object",
+            TsKeywordTypeKind::TsBigIntKeyword => "BigInt",
+            TsKeywordTypeKind::TsSymbolKeyword => "Symbol",
+            TsKeywordTypeKind::TsNullKeyword
+            | TsKeywordTypeKind::TsUndefinedKeyword
+            | TsKeywordTypeKind::TsVoidKeyword
+            | TsKeywordTypeKind::TsNeverKeyword => return true,
+            _ => return false,
+        },
+        TsType::TsLitType(lit) => match lit.lit {
+            TsLit::Str(..) | TsLit::Tpl(..) => "String",
+            TsLit::Number(..) => "Number",
+            TsLit::Bool(..) => "Boolean",
+            TsLit::BigInt(..) => "BigInt",
+        },
+        TsType::TsTypeLit(lit) => {
+            if is_callable(&lit.members) {
+                "Function"
+            } else {
+                "Object"
+            }
+        }
+        TsType::TsFnOrConstructorType(..) => "Function",
+        TsType::TsArrayType(..) | TsType::TsTupleType(..) => "Array",
+        TsType::TsParenthesizedType(paren) => {
+            return runtime_types(types, &paren.type_ann, depth + 1, out)
+        }
+        TsType::TsOptionalType(optional) => {
+            return runtime_types(types, &optional.type_ann, depth + 1, out)
+        }
+        TsType::TsTypeOperator(operator) if operator.op == TsTypeOperatorOp::ReadOnly => {
+            return runtime_types(types, &operator.type_ann, depth + 1, out)
+        }
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union)) => {
+            return union
+                .types
+                .iter()
+                .all(|ty| runtime_types(types, ty, depth + 1, out));
+        }
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(..)) => {
+            "Object"
+        }
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(name),
+            ..
+        }) => match &*name.sym {
+            "Array" | "ReadonlyArray" => "Array",
+            "Function" => "Function",
+            "Date" => "Date",
+            "Promise" => "Promise",
+            "Map" | "ReadonlyMap" => "Map",
+            "Set" | "ReadonlySet" => "Set",
+            "WeakMap" => "WeakMap",
+            "WeakSet" => "WeakSet",
+            "RegExp" => "RegExp",
+            "Error" => "Error",
+            "Record" | "Partial" | "Required" | "Readonly" | "Pick" | "Omit" | "Object" => "Object",
+            _ => {
+                if let Some(interface) = types.interfaces.get(&name.sym) {
+                    if is_callable(&interface.body.body) {
+                        "Function"
+                    } else {
+                        "Object"
+                    }
+                } else if let Some(alias) = types.aliases.get(&name.sym) {
+                    return runtime_types(types, &alias.type_ann, depth + 1, out);
+                } else {
+                    return false;
+                }
+            }
+        },
+        _ => return false,
+    };
+
+    if !out.contains(&constructor) {
+        out.push(constructor);
+    }
+
+    true
+}
+
+/// Returns `true` for object types with only call signatures.
+fn is_callable(members: &[TsTypeElement]) -> bool {
+    !members.is_empty()
+        && members
+            .iter()
+            .all(|member| matches!(member, TsTypeElement::TsCallSignatureDecl(..)))
+}
+
+/// Returns the event names of a type-based `defineEmits()`, which is either
+/// call signatures like `(e: 'change', id: number): void` or properties like
+/// `change: [id: number]`.
+fn resolve_emits(types: &TypeDecls, ty: &TsType) -> Option<Vec<Atom>> {
+    let mut events = Vec::new();
+
+    if let TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(function)) = ty {
+        collect_event_names(types, function.params.first()?, &mut events)?;
+
+        return Some(events);
+    }
+
+    for member in resolve_members(types, ty, 0)? {
+        match member {
+            TsTypeElement::TsCallSignatureDecl(signature) => {
+                collect_event_names(types, signature.params.first()?, &mut events)?;
+            }
+            TsTypeElement::TsPropertySignature(prop) => {
+                events.push(prop_name(&prop.key, prop.computed)?);
+            }
+            _ => return None,
+        }
+    }
+
+    Some(events)
+}
+
+fn collect_event_names(types: &TypeDecls, param: &TsFnParam, events: &mut Vec<Atom>) -> Option<()> {
+    let TsFnParam::Ident(BindingIdent {
+        type_ann: Some(type_ann),
+        ..
+    }) = param
+    else {
+        return None;
+    };
+
+    string_literals(types, &type_ann.type_ann, 0, events)
+}
+
+fn string_literals(
+    types: &TypeDecls,
+    ty: &TsType,
+    depth: usize,
+    out: &mut Vec<Atom>,
+) -> Option<()> {
+    if depth > MAX_TYPE_DEPTH {
+        return None;
+    }
+
+    match ty {
+        TsType::TsLitType(TsLitType {
+            lit: TsLit::Str(value),
+            ..
+        }) => {
+            if !out.contains(&value.value) {
+                out.push(value.value.clone());
+            }
+
+            Some(())
+        }
+        TsType::TsParenthesizedType(paren) => {
+            string_literals(types, &paren.type_ann, depth + 1, out)
+        }
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union)) => {
+            for ty in &union.types {
+                string_literals(types, ty, depth + 1, out)?;
+            }
+
+            Some(())
+        }
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(name),
+            type_params: None,
+            ..
+        }) => {
+            let alias = types.aliases.get(&name.sym)?;
+
+            string_literals(types, &alias.type_ann, depth + 1, out)
+        }
+        _ => None,
+    }
+}
+
+fn prop_name(key: &Expr, computed: bool) -> Option<Atom> {
+    match key {
+        Expr::Ident(ident) if !computed => Some(ident.sym.clone()),
+        Expr::Lit(Lit::Str(s)) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+/// Returns the props object, and the defaults if they can't be inlined, which
+/// are merged at runtime.
+fn typed_props_object(props: Vec<TypedProp>, defaults: Option<Expr>) -> (Expr, Option<Expr>) {
+    let mut inlined = FxHashMap::default();
+    let mut merged = None;
+
+    match defaults {
+        Some(Expr::Object(defaults)) if defaults.props.iter().all(is_static_prop) => {
+            for prop in defaults.props {
+                let PropOrSpread::Prop(prop) = prop else {
+                    unreachable!()
+                };
+
+                match *prop {
+                    Prop::KeyValue(KeyValueProp { key, value }) => {
+                        inlined.insert(static_prop_name(&key).unwrap(), *value);
+                    }
+                    Prop::Shorthand(ident) => {
+                        inlined.insert(ident.sym.clone(), ident.into());
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+        defaults => merged = defaults,
+    }
+
+    let props = props
+        .into_iter()
+        .map(|prop| {
+            let ty = match prop.types.len() {
+                0 => Lit::Null(Null { span: DUMMY_SP }).into(),
+                1 => ident(prop.types[0]),
+                _ => array(prop.types.iter().map(|ty| ident(ty)).collect()),
+            };
+            let mut options = vec![
+                key_value("type", ty),
+                key_value("required", prop.required.into()),
+            ];
+
+            if let Some(default) = inlined.remove(&prop.name) {
+                options.push(key_value("default", default));
+            }
+
+            let key = if swc_ecma_utils::is_valid_prop_ident(&prop.name) {
+                PropName::Ident(IdentName::new(prop.name, DUMMY_SP))
+            } else {
+                PropName::Str(quote_str!(prop.name))
+            };
+
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key,
+                value: Box::new(object(options)),
+            })))
+        })
+        .collect();
+
+    (object(props), merged)
+}
+
+fn is_static_prop(prop: &PropOrSpread) -> bool {
+    match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(prop) => static_prop_name(&prop.key).is_some(),
+            Prop::Shorthand(..) => true,
+            _ => false,
+        },
+        PropOrSpread::Spread(..) => false,
+    }
+}
+
+fn static_prop_name(key: &PropName) -> Option<Atom> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+/// `import { name as _name } from "vue"`
+fn vue_import(name: &str) -> ModuleItem {
+    ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![ImportNamedSpecifier {
+            span: DUMMY_SP,
+            local: Ident::new_no_ctxt(format!("_{name}").into(), DUMMY_SP),
+            imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+                name.into(),
+                DUMMY_SP,
+            ))),
+            is_type_only: false,
+        }
+        .into()],
+        src: Box::new(quote_str!("vue")),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    }
+    .into()
+}
+
+fn ident(name: &str) -> Expr {
+    Ident::new_no_ctxt(name.into(), DUMMY_SP).into()
+}
+
+fn const_decl(name: &str, init: Expr) -> Stmt {
+    VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Ident::new_no_ctxt(name.into(), DUMMY_SP).into(),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+        ..Default::default()
+    }
+    .into()
+}
+
+fn object(props: Vec<PropOrSpread>) -> Expr {
+    ObjectLit {
+        span: DUMMY_SP,
+        props,
+    }
+    .into()
+}
+
+fn array(elems: Vec<Expr>) -> Expr {
+    ArrayLit {
+        span: DUMMY_SP,
+        elems: elems.into_iter().map(|elem| Some(elem.as_arg())).collect(),
+    }
+    .into()
+}
+
+fn key_value(key: &str, value: Expr) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(quote_ident!(key)),
+        value: Box::new(value),
+    })))
+}
+
+fn spread(expr: Expr) -> PropOrSpread {
+    PropOrSpread::Spread(SpreadElement {
+        dot3_token: DUMMY_SP,
+        expr: Box::new(expr),
+    })
+}
@@ -0,0 +1,345 @@
+//! Compiles `<style>`.
+//!
+//! Selectors of `<style scoped>` get the attribute of the component, e.g.
+//! `.a .b` is compiled to `.a .b[data-v-1b2c3d4e]`. `:deep()`, `:slotted()`
+//! and `:global()` control where the attribute is added.
+//!
+//! Class names of `<style module>` are renamed with [swc_css_modules], and
+//! exposed to the template as `$style`.
+
+use std::mem::take;
+
+use anyhow::{bail, Error};
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, input::StringInput, BytePos, SourceFile, Span, DUMMY_SP};
+use swc_css_ast::*;
+use swc_css_modules::{CssClassName, TransformConfig};
+use swc_css_parser::{lexer::Lexer, parse_string_input, parser::ParserConfig};
+use swc_css_visit::{VisitMut, VisitMutWith};
+
+use crate::parse::{slice, SfcBlock};
+
+pub(crate) struct CompiledStyle {
+    pub stylesheet: Stylesheet,
+    /// The name of the CSS module, e.g. `$style`, and the class names of each
+    /// local name.
+    pub module: Option<(String, Vec<(Atom, String)>)>,
+    pub is_scoped: bool,
+}
+
+pub(crate) fn compile_style(
+    fm: &SourceFile,
+    block: &SfcBlock,
+    id: &str,
+) -> Result<CompiledStyle, Error> {
+    match block.lang() {
+        None | Some("css") => {}
+        Some(lang) => bail!("unsupported style language `{lang}` in `{}`", fm.name),
+    }
+
+    let module_name = block.attr("module").map(|name| match name {
+        "" => "$style".to_string(),
+        name => name.to_string(),
+    });
+    let is_scoped = block.attr("scoped").is_some();
+
+    let mut stylesheet: Stylesheet = parse(
+        fm,
+        block.content_span,
+        ParserConfig {
+            css_modules: module_name.is_some(),
+            ..Default::default()
+        },
+    )
+    .map_err(|()| Error::msg(format!("failed to parse <style> of `{}`", fm.name)))?;
+
+    let module = module_name.map(|name| {
+        let result = swc_css_modules::compile(&mut stylesheet, ModuleConfig { id });
+        let mut classes = result
+            .renamed
+            .into_iter()
+            .map(|(local, names)| {
+                let names = names
+                    .iter()
+                    .filter_map(|name| match name {
+                        CssClassName::Local { name } | CssClassName::Global { name } => {
+                            Some(&*name.value)
+                        }
+                        CssClassName::Import { .. } => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                (local, names)
+            })
+            .collect::<Vec<_>>();
+        classes.sort();
+
+        (name, classes)
+    });
+
+    if is_scoped {
+        let mut scoper = Scoper {
+            fm,
+            id,
+            has_error: false,
+        };
+
+        stylesheet.visit_mut_with(&mut scoper);
+
+        if scoper.has_error {
+            bail!("failed to compile <style scoped> of `{}`", fm.name)
+        }
+    }
+
+    Ok(CompiledStyle {
+        stylesheet,
+        module,
+        is_scoped,
+    })
+}
+
+/// Parses the source of `span` in `fm`. Errors are emitted to [HANDLER].
+fn parse<T>(fm: &SourceFile, span: Span, config: ParserConfig) -> Result<T, ()>
+where
+    for<'a, 'b> swc_css_parser::parser::Parser<Lexer<'b, StringInput<'a>>>:
+        swc_css_parser::Parse<T>,
+{
+    let input = StringInput::new(slice(fm, span), span.lo, span.hi);
+    let mut errors = Vec::new();
+    let result = parse_string_input(input, None, config, &mut errors);
+
+    HANDLER.with(|handler| {
+        let has_error = !errors.is_empty();
+
+        for err in errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        match result {
+            Ok(node) if !has_error => Ok(node),
+            Ok(..) => Err(()),
+            Err(err) => {
+                err.to_diagnostics(handler).emit();
+                Err(())
+            }
+        }
+    })
+}
+
+struct ModuleConfig<'a> {
+    id: &'a str,
+}
+
+impl TransformConfig for ModuleConfig<'_> {
+    fn new_name_for(&self, local: &Atom) -> Atom {
+        format!("{local}_{}", self.id).into()
+    }
+}
+
+struct Scoper<'a> {
+    fm: &'a SourceFile,
+    id: &'a str,
+    has_error: bool,
+}
+
+impl Scoper<'_> {
+    fn attribute(&self, suffix: &str) -> SubclassSelector {
+        let name = Atom::from(format!("data-v-{}{suffix}", self.id));
+
+        SubclassSelector::Attribute(Box::new(AttributeSelector {
+            span: DUMMY_SP,
+            name: WqName {
+                span: DUMMY_SP,
+                prefix: None,
+                value: Ident {
+                    span: DUMMY_SP,
+                    value: name,
+                    raw: None,
+                },
+            },
+            matcher: None,
+            value: None,
+            modifier: None,
+        }))
+    }
+
+    /// Adds `[data-v-id]` after the last selector which is not a pseudo-class
+    /// or a pseudo-element, e.g. `.a[data-v-id]:hover`.
+    fn inject(&self, compound: &mut CompoundSelector, suffix: &str) {
+        let index = compound
+            .subclass_selectors
+            .iter()
+            .rposition(|selector| {
+                !matches!(
+                    selector,
+                    SubclassSelector::PseudoClass(..) | SubclassSelector::PseudoElement(..)
+                )
+            })
+            .map_or(0, |index| index + 1);
+
+        compound
+            .subclass_selectors
+            .insert(index, self.attribute(suffix));
+    }
+
+    /// Returns the argument of `:deep()`, `:slotted()` or `:global()`, which
+    /// is kept as tokens by the parser.
+    fn argument(&mut self, pseudo: &PseudoClassSelector) -> Option<ComplexSelector> {
+        if let Some([PseudoClassSelectorChildren::ComplexSelector(selector)]) =
+            pseudo.children.as_deref()
+        {
+            return Some(selector.clone());
+        }
+
+        let source = slice(self.fm, pseudo.span);
+        let (Some(start), Some(end)) = (source.find('('), source.rfind(')')) else {
+            self.error(pseudo.span, "expected a selector argument");
+            return None;
+        };
+        let span = Span::new(
+            pseudo.span.lo + BytePos(start as u32 + 1),
+            pseudo.span.lo + BytePos(end as u32),
+        );
+
+        let selector = parse::<ComplexSelector>(self.fm, span, Default::default());
+        if selector.is_err() {
+            self.has_error = true;
+        }
+
+        selector.ok()
+    }
+
+    fn error(&mut self, span: Span, msg: &str) {
+        HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+        self.has_error = true;
+    }
+
+    fn scope(&mut self, selector: &mut ComplexSelector) {
+        let mut children = Vec::with_capacity(selector.children.len() + 2);
+        let mut is_injected = false;
+        let mut rest = take(&mut selector.children).into_iter();
+
+        while let Some(child) = rest.next() {
+            let ComplexSelectorChildren::CompoundSelector(mut compound) = child else {
+                children.push(child);
+                continue;
+            };
+
+            let special = compound
+                .subclass_selectors
+                .iter()
+                .position(|selector| match selector {
+                    SubclassSelector::PseudoClass(pseudo) => matches!(
+                        &*pseudo.name.value.to_ascii_lowercase(),
+                        "deep" | "v-deep" | "slotted" | "v-slotted" | "global" | "v-global"
+                    ),
+                    _ => false,
+                });
+            let Some(index) = special else {
+                children.push(ComplexSelectorChildren::CompoundSelector(compound));
+                continue;
+            };
+            let SubclassSelector::PseudoClass(pseudo) = compound.subclass_selectors.remove(index)
+            else {
+                unreachable!()
+            };
+            let Some(argument) = self.argument(&pseudo) else {
+                return;
+            };
+
+            match pseudo.name.value.trim_start_matches("v-") {
+                "deep" => {
+                    let is_empty = compound.nesting_selector.is_none()
+                        && compound.type_selector.is_none()
+                        && compound.subclass_selectors.is_empty();
+
+                    if !is_empty {
+                        self.inject(&mut compound, "");
+                        children.push(ComplexSelectorChildren::CompoundSelector(compound));
+                    } else {
+                        // `.a :deep(.b)` is compiled to `.a[data-v-id] .b`
+                        if let Some(ComplexSelectorChildren::Combinator(Combinator {
+                            value: CombinatorValue::Descendant,
+                            ..
+                        })) = children.last()
+                        {
+                            children.pop();
+                        }
+
+                        match children.last_mut() {
+                            Some(ComplexSelectorChildren::CompoundSelector(prev)) => {
+                                self.inject(prev, "");
+                            }
+                            _ => children.push(ComplexSelectorChildren::CompoundSelector(
+                                CompoundSelector {
+                                    span: DUMMY_SP,
+                                    nesting_selector: None,
+                                    type_selector: None,
+                                    subclass_selectors: vec![self.attribute("")],
+                                },
+                            )),
+                        }
+                    }
+
+                    children.push(ComplexSelectorChildren::Combinator(Combinator {
+                        span: DUMMY_SP,
+                        value: CombinatorValue::Descendant,
+                    }));
+                    children.extend(argument.children);
+                    children.extend(rest);
+
+                    selector.children = children;
+                    return;
+                }
+                "slotted" => {
+                    let mut argument = argument.children;
+
+                    if let Some(ComplexSelectorChildren::CompoundSelector(last)) =
+                        argument.last_mut()
+                    {
+                        self.inject(last, "-s");
+                    }
+                    children.extend(argument);
+                    is_injected = true;
+                }
+                _ => {
+                    // `:global()` is not scoped
+                    selector.children = argument.children;
+                    return;
+                }
+            }
+        }
+
+        if !is_injected {
+            if let Some(ComplexSelectorChildren::CompoundSelector(last)) = children.last_mut() {
+                self.inject(last, "");
+            }
+        }
+
+        selector.children = children;
+    }
+}
+
+impl VisitMut for Scoper<'_> {
+    fn visit_mut_at_rule(&mut self, at_rule: &mut AtRule) {
+        let is_keyframes = match &at_rule.name {
+            AtRuleName::Ident(name) => name.value.to_ascii_lowercase().ends_with("keyframes"),
+            AtRuleName::DashedIdent(..) => false,
+        };
+
+        if !is_keyframes {
+            at_rule.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_qualified_rule(&mut self, rule: &mut QualifiedRule) {
+        if let QualifiedRulePrelude::SelectorList(list) = &mut rule.prelude {
+            for selector in &mut list.children {
+                self.scope(selector);
+            }
+        }
+
+        rule.block.visit_mut_with(self);
+    }
+}
@@ -0,0 +1,262 @@
+use rustc_hash::FxHashSet;
+use swc_atoms::Atom;
+use swc_common::{BytePos, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{
+    error::Error, lexer::Lexer, EsSyntax, Parser, StringInput, Syntax, TsSyntax,
+};
+use swc_ecma_utils::{find_pat_ids, quote_ident};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+/// Parses a JavaScript expression of a template. `lo` is the position of
+/// `src` in the file.
+pub(super) fn parse_expr(src: &str, lo: BytePos, is_ts: bool) -> Result<Box<Expr>, Error> {
+    let mut parser = parser(src, lo, is_ts);
+
+    let expr = parser.parse_expr()?;
+
+    if let Some(err) = parser.take_errors().pop() {
+        return Err(err);
+    }
+
+    Ok(expr)
+}
+
+/// Parses the statements of an inline event handler, e.g. `a++; b()`.
+pub(super) fn parse_stmts(src: &str, lo: BytePos, is_ts: bool) -> Result<Vec<Stmt>, Error> {
+    let mut parser = parser(src, lo, is_ts);
+
+    let script = parser.parse_script()?;
+
+    if let Some(err) = parser.take_errors().pop() {
+        return Err(err);
+    }
+
+    Ok(script.body)
+}
+
+/// Parses the parameters of a scoped slot or the aliases of `v-for`, e.g.
+/// `{ item }` or `(item, index)`.
+pub(super) fn parse_params(src: &str, lo: BytePos, is_ts: bool) -> Result<Vec<Pat>, Error> {
+    let src = src.trim();
+    let src = match src.strip_prefix('(').and_then(|src| src.strip_suffix(')')) {
+        Some(src) => src,
+        None => src,
+    };
+    // The position of the wrapper is not in the file, but it's fine because it
+    // has no identifier.
+    let wrapped = format!("({src}) => 0");
+
+    match *parse_expr(&wrapped, lo - BytePos(1).min(lo), is_ts)? {
+        Expr::Arrow(arrow) => Ok(arrow.params),
+        _ => unreachable!("should be an arrow function"),
+    }
+}
+
+fn parser(src: &str, lo: BytePos, is_ts: bool) -> Parser<Lexer<'_>> {
+    let syntax = if is_ts {
+        Syntax::Typescript(TsSyntax::default())
+    } else {
+        Syntax::Es(EsSyntax::default())
+    };
+    let hi = lo + BytePos(src.len() as u32);
+
+    Parser::new(syntax, StringInput::new(src, lo, hi), None)
+}
+
+/// Returns `true` for expressions which can be used as an event handler as-is,
+/// e.g. `onClick` or `handlers.click`.
+pub(super) fn is_member_expression(expr: &Expr) -> bool {
+    match expr {
+        Expr::Ident(..) => true,
+        Expr::Member(member) => is_member_expression(&member.obj),
+        Expr::Paren(paren) => is_member_expression(&paren.expr),
+        _ => false,
+    }
+}
+
+pub(super) fn is_function_expression(expr: &Expr) -> bool {
+    match expr {
+        Expr::Arrow(..) | Expr::Fn(..) => true,
+        Expr::Paren(paren) => is_function_expression(&paren.expr),
+        _ => false,
+    }
+}
+
+/// Rewrites references to the component instance, e.g. `count` to
+/// `_ctx.count`. `locals` are the variables of `v-for` and scoped slots.
+///
+/// Returns the names of the rewritten identifiers.
+pub(super) fn prefix_identifiers<N>(node: &mut N, locals: &[Atom], used: &mut FxHashSet<Atom>)
+where
+    N: for<'a> VisitMutWith<Prefixer<'a>>,
+{
+    let mut prefixer = Prefixer {
+        locals,
+        scopes: Vec::new(),
+        used,
+    };
+
+    node.visit_mut_with(&mut prefixer);
+}
+
+pub(super) struct Prefixer<'a> {
+    locals: &'a [Atom],
+    /// Parameters of functions in the expression.
+    scopes: Vec<FxHashSet<Atom>>,
+    used: &'a mut FxHashSet<Atom>,
+}
+
+impl Prefixer<'_> {
+    fn should_prefix(&self, sym: &Atom) -> bool {
+        !self.locals.contains(sym)
+            && !self.scopes.iter().any(|scope| scope.contains(sym))
+            && !is_globally_allowed(sym)
+    }
+
+    fn ctx_member(&mut self, ident: &Ident) -> Expr {
+        self.used.insert(ident.sym.clone());
+
+        ctx_member(ident.sym.clone(), ident.span)
+    }
+
+    fn with_scope<N>(&mut self, params: &N, op: impl FnOnce(&mut Self))
+    where
+        N: swc_ecma_visit::VisitWith<swc_ecma_utils::DestructuringFinder<Atom>>,
+    {
+        self.scopes.push(find_pat_ids(params).into_iter().collect());
+        op(self);
+        self.scopes.pop();
+    }
+}
+
+impl VisitMut for Prefixer<'_> {
+    fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+        let params = arrow.params.clone();
+
+        self.with_scope(&params, |v| {
+            arrow.params.visit_mut_with(v);
+            arrow.body.visit_mut_with(v);
+        });
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Ident(ident) = expr {
+            if self.should_prefix(&ident.sym) {
+                *expr = self.ctx_member(ident);
+            }
+
+            return;
+        }
+
+        expr.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        let params = function.params.clone();
+
+        self.with_scope(&params, |v| {
+            function.params.visit_mut_with(v);
+            function.body.visit_mut_with(v);
+        });
+    }
+
+    fn visit_mut_pat(&mut self, pat: &mut Pat) {
+        // Only default values and computed keys are references
+        match pat {
+            Pat::Ident(..) => {}
+            Pat::Expr(expr) => expr.visit_mut_with(self),
+            _ => pat.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop(&mut self, prop: &mut Prop) {
+        if let Prop::Shorthand(ident) = prop {
+            if self.should_prefix(&ident.sym) {
+                *prop = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(ident.clone().into()),
+                    value: Box::new(self.ctx_member(ident)),
+                });
+            }
+
+            return;
+        }
+
+        prop.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+        match prop {
+            ObjectPatProp::KeyValue(prop) => {
+                prop.key.visit_mut_with(self);
+                prop.value.visit_mut_with(self);
+            }
+            ObjectPatProp::Assign(prop) => prop.value.visit_mut_with(self),
+            ObjectPatProp::Rest(prop) => prop.arg.visit_mut_with(self),
+        }
+    }
+
+    fn visit_mut_simple_assign_target(&mut self, target: &mut SimpleAssignTarget) {
+        if let SimpleAssignTarget::Ident(ident) = target {
+            if self.should_prefix(&ident.sym) {
+                if let Expr::Member(member) = self.ctx_member(&ident.id) {
+                    *target = SimpleAssignTarget::Member(member);
+                }
+            }
+
+            return;
+        }
+
+        target.visit_mut_children_with(self);
+    }
+}
+
+/// `_ctx.name`
+pub(super) fn ctx_member(sym: Atom, span: Span) -> Expr {
+    MemberExpr {
+        span,
+        obj: Box::new(Ident::from(quote_ident!("_ctx")).into()),
+        prop: MemberProp::Ident(IdentName::new(sym, span)),
+    }
+    .into()
+}
+
+/// A `$event` parameter.
+pub(super) fn event_param() -> Pat {
+    Ident::new_no_ctxt("$event".into(), DUMMY_SP).into()
+}
+
+/// See `isGloballyAllowed` of `@vue/shared`.
+fn is_globally_allowed(sym: &str) -> bool {
+    matches!(
+        sym,
+        "Infinity"
+            | "undefined"
+            | "NaN"
+            | "isFinite"
+            | "isNaN"
+            | "parseFloat"
+            | "parseInt"
+            | "decodeURI"
+            | "decodeURIComponent"
+            | "encodeURI"
+            | "encodeURIComponent"
+            | "Math"
+            | "Number"
+            | "Date"
+            | "Array"
+            | "Object"
+            | "Boolean"
+            | "String"
+            | "RegExp"
+            | "Map"
+            | "Set"
+            | "JSON"
+            | "Intl"
+            | "BigInt"
+            | "console"
+            | "Error"
+            | "Symbol"
+            | "arguments"
+    )
+}
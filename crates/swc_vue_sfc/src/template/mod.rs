@@ -0,0 +1,2027 @@
+//! Compiles `<template>` to a render function.
+//!
+//! The output is similar to the output of `@vue/compiler-dom`, but it doesn't
+//! use blocks and patch flags. Static subtrees are hoisted out of the render
+//! function.
+
+use std::{collections::BTreeSet, mem::take};
+
+use anyhow::{bail, Error};
+use rustc_hash::FxHashSet;
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, BytePos, SourceFile, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_pat_ids, is_valid_prop_ident, quote_str, ExprFactory};
+use swc_html_ast::{Attribute, Child, Element, Namespace, Text};
+
+use self::expr::{
+    ctx_member, event_param, is_function_expression, is_member_expression, parse_expr,
+    parse_params, parse_stmts, prefix_identifiers,
+};
+use crate::parse::{slice, SfcBlock};
+
+mod expr;
+
+/// The patch flag of hoisted vnodes.
+const HOISTED: f64 = -1.0;
+
+pub(crate) struct TemplateOptions<'a> {
+    pub is_ts: bool,
+    /// Top-level bindings of `<script setup>`. Components and directives in
+    /// the bindings are used instead of resolving them by name.
+    pub bindings: &'a FxHashSet<Atom>,
+    pub hoist_static: bool,
+}
+
+pub(crate) struct CompiledTemplate {
+    /// The import of runtime helpers, hoisted vnodes and `_sfc_render`.
+    pub items: Vec<ModuleItem>,
+    /// Names which are accessed through `_ctx`.
+    pub used: FxHashSet<Atom>,
+}
+
+pub(crate) fn compile_template(
+    fm: &SourceFile,
+    block: &SfcBlock,
+    options: &TemplateOptions,
+) -> Result<CompiledTemplate, Error> {
+    let mut compiler = Compiler {
+        fm,
+        options,
+        helpers: Default::default(),
+        hoisted: Vec::new(),
+        assets: Vec::new(),
+        locals: Vec::new(),
+        used: Default::default(),
+        cache_count: 0,
+        in_pre: 0,
+        in_v_pre: 0,
+        has_error: false,
+    };
+
+    let children = match &block.element.content {
+        Some(content) => content.children.iter().collect(),
+        None => Vec::new(),
+    };
+    let mut roots = compiler.gen_children(&children, false);
+    let root = match roots.len() {
+        0 => null(),
+        1 => {
+            let root = roots.pop().unwrap();
+            compiler.vnode_of(root)
+        }
+        _ => {
+            let children = compiler.vnodes_of(roots);
+            let fragment = compiler.helper("Fragment");
+            compiler.call_helper("createVNode", vec![fragment, null(), array(children)])
+        }
+    };
+
+    if compiler.has_error {
+        bail!("failed to compile the template of `{}`", fm.name)
+    }
+
+    let mut items = Vec::new();
+
+    if !compiler.helpers.is_empty() {
+        items.push(ModuleItem::ModuleDecl(
+            ImportDecl {
+                span: DUMMY_SP,
+                specifiers: compiler
+                    .helpers
+                    .iter()
+                    .map(|name| {
+                        ImportNamedSpecifier {
+                            span: DUMMY_SP,
+                            local: helper_ident(name),
+                            imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+                                (*name).into(),
+                                DUMMY_SP,
+                            ))),
+                            is_type_only: false,
+                        }
+                        .into()
+                    })
+                    .collect(),
+                src: Box::new(quote_str!("vue")),
+                type_only: false,
+                with: None,
+                phase: Default::default(),
+            }
+            .into(),
+        ));
+    }
+
+    for (i, vnode) in compiler.hoisted.into_iter().enumerate() {
+        items.push(const_decl(hoisted_ident(i), vnode).into());
+    }
+
+    let mut stmts = compiler
+        .assets
+        .into_iter()
+        .map(|(name, init)| const_decl(name, init).into())
+        .collect::<Vec<Stmt>>();
+    stmts.push(
+        ReturnStmt {
+            span: DUMMY_SP,
+            arg: Some(Box::new(root)),
+        }
+        .into(),
+    );
+
+    items.push(
+        Decl::from(FnDecl {
+            ident: Ident::new_no_ctxt("_sfc_render".into(), DUMMY_SP),
+            declare: false,
+            function: Box::new(Function {
+                params: ["_ctx", "_cache"]
+                    .into_iter()
+                    .map(|name| Pat::from(Ident::new_no_ctxt(name.into(), DUMMY_SP)).into())
+                    .collect(),
+                body: Some(BlockStmt {
+                    stmts,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        })
+        .into(),
+    );
+
+    Ok(CompiledTemplate {
+        items,
+        used: compiler.used,
+    })
+}
+
+struct Compiler<'a> {
+    fm: &'a SourceFile,
+    options: &'a TemplateOptions<'a>,
+    /// Names of the imported runtime helpers.
+    helpers: BTreeSet<&'static str>,
+    hoisted: Vec<Expr>,
+    /// Components and directives resolved at the start of the render function.
+    assets: Vec<(Ident, Expr)>,
+    /// Variables of `v-for` and scoped slots in scope.
+    locals: Vec<Atom>,
+    used: FxHashSet<Atom>,
+    /// The number of `_cache` entries used by `v-once`.
+    cache_count: usize,
+    /// The depth of `<pre>` and `<textarea>` elements, where whitespace is
+    /// preserved.
+    in_pre: usize,
+    /// The depth of `v-pre` elements, which are not compiled.
+    in_v_pre: usize,
+    has_error: bool,
+}
+
+/// A child of an element.
+enum Node {
+    Text(Expr),
+    VNode(Expr),
+}
+
+/// A parsed attribute of an element.
+enum Attr<'a> {
+    Static {
+        name: &'a str,
+        value: Option<&'a str>,
+    },
+    Directive(Directive<'a>),
+}
+
+struct Directive<'a> {
+    /// The name without the `v-` prefix, e.g. `bind` for `:foo`.
+    name: &'a str,
+    arg: Option<DirectiveArg<'a>>,
+    modifiers: Vec<&'a str>,
+    value: Option<Source<'a>>,
+    span: Span,
+}
+
+enum DirectiveArg<'a> {
+    Static(&'a str),
+    /// e.g. `:[key]`
+    Dynamic(Source<'a>),
+}
+
+/// The source of a JavaScript expression in the template.
+#[derive(Clone, Copy)]
+struct Source<'a> {
+    text: &'a str,
+    /// The position of the source in the file.
+    lo: BytePos,
+}
+
+/// A property of a props object.
+enum Entry {
+    Prop(PropKey, Expr),
+    /// `v-bind="object"` or `v-on="object"`
+    Spread(Expr),
+}
+
+enum PropKey {
+    Static(String),
+    Computed(Expr),
+}
+
+impl Compiler<'_> {
+    fn error(&mut self, span: Span, msg: &str) {
+        HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+        self.has_error = true;
+    }
+
+    fn parse_error(&mut self, err: swc_ecma_parser::error::Error) {
+        HANDLER.with(|handler| err.into_diagnostic(handler).emit());
+        self.has_error = true;
+    }
+
+    fn helper(&mut self, name: &'static str) -> Expr {
+        self.helpers.insert(name);
+
+        helper_ident(name).into()
+    }
+
+    fn call_helper(&mut self, name: &'static str, args: Vec<Expr>) -> Expr {
+        self.helper(name)
+            .as_call(DUMMY_SP, args.into_iter().map(|arg| arg.as_arg()).collect())
+    }
+
+    /// Parses an expression and rewrites its references to the component
+    /// instance.
+    fn expr(&mut self, source: Source) -> Expr {
+        match parse_expr(source.text, source.lo, self.options.is_ts) {
+            Ok(mut expr) => {
+                self.prefix(&mut *expr);
+                *expr
+            }
+            Err(err) => {
+                self.parse_error(err);
+                *Expr::undefined(DUMMY_SP)
+            }
+        }
+    }
+
+    fn prefix<N>(&mut self, node: &mut N)
+    where
+        N: for<'p> swc_ecma_visit::VisitMutWith<expr::Prefixer<'p>>,
+    {
+        prefix_identifiers(node, &self.locals, &mut self.used);
+    }
+
+    /// Calls `op` with the variables declared by `params` in scope.
+    fn with_locals<T>(&mut self, params: &[Pat], op: impl FnOnce(&mut Self) -> T) -> T {
+        let names: Vec<Atom> = find_pat_ids(&params.to_vec());
+        let len = self.locals.len();
+
+        self.locals.extend(names);
+        let result = op(self);
+        self.locals.truncate(len);
+
+        result
+    }
+
+    fn vnode_of(&mut self, node: Node) -> Expr {
+        match node {
+            Node::Text(text) => self.call_helper("createTextVNode", vec![text]),
+            Node::VNode(vnode) => vnode,
+        }
+    }
+
+    fn vnodes_of(&mut self, nodes: Vec<Node>) -> Vec<Expr> {
+        nodes.into_iter().map(|node| self.vnode_of(node)).collect()
+    }
+
+    fn gen_children(&mut self, children: &[&Child], can_hoist: bool) -> Vec<Node> {
+        // Comments are not rendered
+        let children = children
+            .iter()
+            .copied()
+            .filter(|child| matches!(child, Child::Element(..) | Child::Text(..)))
+            .collect::<Vec<_>>();
+        let mut nodes = Vec::new();
+        let mut i = 0;
+
+        while i < children.len() {
+            match children[i] {
+                Child::Text(text) => {
+                    let prev = i.checked_sub(1).map(|i| children[i]);
+                    let next = children.get(i + 1).copied();
+
+                    if let Some(text) = self.gen_text(text, prev, next) {
+                        nodes.push(Node::Text(text));
+                    }
+                    i += 1;
+                }
+                Child::Element(element) if self.in_v_pre == 0 && has_attr(element, "v-if") => {
+                    let (vnode, len) = self.gen_if(&children[i..]);
+
+                    nodes.push(Node::VNode(vnode));
+                    i += len;
+                }
+                Child::Element(element)
+                    if self.in_v_pre == 0
+                        && (has_attr(element, "v-else-if") || has_attr(element, "v-else")) =>
+                {
+                    self.error(
+                        element.span,
+                        "v-else and v-else-if must be used after an element with v-if or v-else-if",
+                    );
+                    i += 1;
+                }
+                Child::Element(element) => {
+                    let vnode = self.gen_element(element, can_hoist, None);
+
+                    nodes.push(Node::VNode(vnode));
+                    i += 1;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        nodes
+    }
+
+    /// Generates the text of a text node, or returns `None` if the text node
+    /// is whitespace which is removed.
+    fn gen_text(
+        &mut self,
+        text: &Text,
+        prev: Option<&Child>,
+        next: Option<&Child>,
+    ) -> Option<Expr> {
+        let data = &*text.data;
+
+        if self.in_pre == 0 && data.chars().all(|c| c.is_ascii_whitespace()) {
+            let is_element = |child: Option<&Child>| matches!(child, Some(Child::Element(..)));
+
+            if prev.is_none()
+                || next.is_none()
+                || (is_element(prev) && is_element(next) && data.contains('\n'))
+            {
+                return None;
+            }
+
+            return Some(quote_str!(" ").into());
+        }
+
+        let mut parts = Vec::new();
+        let mut rest = data;
+
+        while self.in_v_pre == 0 {
+            let Some(start) = rest.find("{{") else {
+                break;
+            };
+            let Some(len) = rest[start + 2..].find("}}") else {
+                break;
+            };
+
+            self.push_static_text(&mut parts, &rest[..start]);
+
+            let source = &rest[start + 2..start + 2 + len];
+            let lo = text.span.lo + BytePos(offset_of(data, source));
+            let value = self.expr(Source { text: source, lo });
+            parts.push(self.call_helper("toDisplayString", vec![value]));
+
+            rest = &rest[start + 2 + len + 2..];
+        }
+        self.push_static_text(&mut parts, rest);
+
+        parts
+            .into_iter()
+            .reduce(|left, right| left.make_bin(BinaryOp::Add, right))
+    }
+
+    fn push_static_text(&mut self, parts: &mut Vec<Expr>, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        let text = if self.in_pre == 0 {
+            condense_whitespace(text)
+        } else {
+            text.to_string()
+        };
+
+        parts.push(quote_str!(text).into());
+    }
+
+    /// Generates a `v-if` chain starting at `children[0]`. Returns the
+    /// expression and the number of consumed children.
+    fn gen_if(&mut self, children: &[&Child]) -> (Expr, usize) {
+        let mut branches = Vec::new();
+        let mut i = 0;
+        let mut len;
+
+        loop {
+            let Child::Element(element) = children[i] else {
+                unreachable!()
+            };
+            let condition = element
+                .attributes
+                .iter()
+                .find(|attr| attr.name == "v-if" || attr.name == "v-else-if");
+            let condition = condition.map(|attr| match self.attr_value(attr) {
+                Some(value) => self.expr(value),
+                None => {
+                    self.error(attr.span, "v-if and v-else-if require an expression");
+                    *Expr::undefined(DUMMY_SP)
+                }
+            });
+            let vnode = self.gen_element(element, false, Some(branches.len()));
+
+            len = i + 1;
+
+            let is_last = condition.is_none();
+            branches.push((condition, vnode));
+
+            if is_last {
+                break;
+            }
+
+            let mut next = i + 1;
+            while let Some(Child::Text(text)) = children.get(next) {
+                if !text.data.chars().all(|c| c.is_ascii_whitespace()) {
+                    break;
+                }
+                next += 1;
+            }
+
+            match children.get(next) {
+                Some(Child::Element(element))
+                    if has_attr(element, "v-else-if") || has_attr(element, "v-else") =>
+                {
+                    i = next;
+                }
+                _ => break,
+            }
+        }
+
+        let mut alternate = match branches.last() {
+            Some((None, _)) => branches.pop().unwrap().1,
+            _ => self.call_helper(
+                "createCommentVNode",
+                vec![quote_str!("v-if").into(), true.into()],
+            ),
+        };
+
+        for (condition, vnode) in branches.into_iter().rev() {
+            alternate = CondExpr {
+                span: DUMMY_SP,
+                test: Box::new(condition.unwrap()),
+                cons: Box::new(vnode),
+                alt: Box::new(alternate),
+            }
+            .into();
+        }
+
+        (alternate, len)
+    }
+
+    /// `key` is the key of a branch of `v-if`.
+    fn gen_element(&mut self, element: &Element, can_hoist: bool, key: Option<usize>) -> Expr {
+        if self.in_v_pre == 0 {
+            if let Some(attr) = element.attributes.iter().find(|attr| attr.name == "v-for") {
+                return self.gen_for(element, attr, key);
+            }
+        }
+
+        let is_once = self.in_v_pre == 0 && has_attr(element, "v-once");
+        let enters_v_pre = self.in_v_pre == 0 && has_attr(element, "v-pre");
+
+        if enters_v_pre {
+            self.in_v_pre += 1;
+        }
+        let vnode = self.gen_element_without_for(element, can_hoist && key.is_none(), key);
+        if enters_v_pre {
+            self.in_v_pre -= 1;
+        }
+
+        if is_once {
+            let index: Expr = (self.cache_count as f64).into();
+            self.cache_count += 1;
+
+            let cache = || -> Expr {
+                MemberExpr {
+                    span: DUMMY_SP,
+                    obj: Box::new(Ident::new_no_ctxt("_cache".into(), DUMMY_SP).into()),
+                    prop: MemberProp::Computed(ComputedPropName {
+                        span: DUMMY_SP,
+                        expr: Box::new(index.clone()),
+                    }),
+                }
+                .into()
+            };
+
+            return cache().make_bin(
+                BinaryOp::LogicalOr,
+                AssignExpr {
+                    span: DUMMY_SP,
+                    op: AssignOp::Assign,
+                    left: AssignTarget::try_from(Box::new(cache())).unwrap(),
+                    right: Box::new(vnode),
+                }
+                .wrap_with_paren(),
+            );
+        }
+
+        vnode
+    }
+
+    fn gen_for(&mut self, element: &Element, attr: &Attribute, key: Option<usize>) -> Expr {
+        let Some(value) = self.attr_value(attr) else {
+            self.error(attr.span, "v-for requires an expression");
+            return null();
+        };
+        let Some((alias, source)) = split_for(value.text) else {
+            self.error(attr.span, "v-for has an invalid expression");
+            return null();
+        };
+
+        let source = self.expr(Source {
+            text: source,
+            lo: value.lo + BytePos(offset_of(value.text, source)),
+        });
+        let params = match parse_params(
+            alias,
+            value.lo + BytePos(offset_of(value.text, alias)),
+            self.options.is_ts,
+        ) {
+            Ok(params) => params,
+            Err(err) => {
+                self.parse_error(err);
+                return null();
+            }
+        };
+
+        let vnode = self.with_locals(&params, |compiler| {
+            compiler.gen_element_without_for(element, true, None)
+        });
+        let list = self.call_helper("renderList", vec![source, arrow(params, vnode)]);
+
+        let props = match key {
+            Some(key) => object_lit(vec![(PropKey::Static("key".into()), (key as f64).into())]),
+            None => null(),
+        };
+        let fragment = self.helper("Fragment");
+
+        self.call_helper("createVNode", vec![fragment, props, list])
+    }
+
+    fn gen_element_without_for(
+        &mut self,
+        element: &Element,
+        can_hoist: bool,
+        key: Option<usize>,
+    ) -> Expr {
+        let tag = self.raw_tag_name(element);
+
+        if self.in_v_pre == 0 && element.namespace == Namespace::HTML {
+            match &*element.tag_name {
+                "slot" => return self.gen_slot_outlet(element),
+                "template" if is_special_template(element) => {
+                    let attrs = self.parse_attrs(element);
+                    let props = self.gen_props(&attrs, false, key);
+                    let nodes = self.gen_children(&children_of(element), true);
+                    let children = self.vnodes_of(nodes);
+                    let fragment = self.helper("Fragment");
+
+                    return self.call_helper(
+                        "createVNode",
+                        vec![fragment, props.unwrap_or_else(null), array(children)],
+                    );
+                }
+                _ => {}
+            }
+
+            if is_component(element, &tag) {
+                return self.gen_component(element, &tag, key);
+            }
+        }
+
+        if can_hoist && self.options.hoist_static && self.is_static(element) {
+            let mut args = self.gen_native_element(element, &tag, key, false);
+
+            args.resize_with(3, null);
+            args.push(HOISTED.into());
+
+            let vnode = self.call_helper("createElementVNode", args);
+            self.hoisted.push(vnode);
+
+            return hoisted_ident(self.hoisted.len() - 1).into();
+        }
+
+        let args = self.gen_native_element(element, &tag, key, true);
+        let vnode = self.call_helper("createElementVNode", args);
+
+        self.with_directives(vnode, element)
+    }
+
+    /// Returns the arguments of `createElementVNode`.
+    fn gen_native_element(
+        &mut self,
+        element: &Element,
+        tag: &str,
+        key: Option<usize>,
+        can_hoist: bool,
+    ) -> Vec<Expr> {
+        let attrs = self.parse_attrs(element);
+
+        for attr in &attrs {
+            if let Attr::Directive(directive) = attr {
+                if directive.name == "slot" {
+                    self.error(
+                        directive.span,
+                        "v-slot can only be used on components or <template>",
+                    );
+                }
+            }
+        }
+
+        let props = self.gen_props(&attrs, false, key);
+        let has_inner_content = attrs.iter().any(|attr| {
+            matches!(
+                attr,
+                Attr::Directive(Directive {
+                    name: "html" | "text",
+                    ..
+                })
+            )
+        });
+
+        let children = if has_inner_content {
+            None
+        } else {
+            let preserves_whitespace = matches!(&*element.tag_name, "pre" | "textarea");
+
+            if preserves_whitespace {
+                self.in_pre += 1;
+            }
+            let mut nodes = self.gen_children(&children_of(element), can_hoist);
+            if preserves_whitespace {
+                self.in_pre -= 1;
+            }
+
+            match nodes.len() {
+                0 => None,
+                1 if matches!(nodes[0], Node::Text(..)) => match nodes.pop() {
+                    Some(Node::Text(text)) => Some(text),
+                    _ => unreachable!(),
+                },
+                _ => Some(array(self.vnodes_of(nodes))),
+            }
+        };
+
+        let mut args = vec![quote_str!(tag).into()];
+
+        match (props, children) {
+            (props, Some(children)) => {
+                args.push(props.unwrap_or_else(null));
+                args.push(children);
+            }
+            (Some(props), None) => args.push(props),
+            (None, None) => {}
+        }
+
+        args
+    }
+
+    fn gen_component(&mut self, element: &Element, tag: &str, key: Option<usize>) -> Expr {
+        let attrs = self.parse_attrs(element);
+        let component = match builtin_component(tag) {
+            Some(name) => self.helper(name),
+            None if element.tag_name == "component" => {
+                let mut is = None;
+
+                for attr in &attrs {
+                    match attr {
+                        Attr::Static { name: "is", value } => {
+                            is = Some(quote_str!(value.unwrap_or_default()).into());
+                        }
+                        Attr::Directive(Directive {
+                            name: "bind",
+                            arg: Some(DirectiveArg::Static("is")),
+                            value: Some(value),
+                            ..
+                        }) => is = Some(self.expr(*value)),
+                        _ => {}
+                    }
+                }
+
+                let is = is.unwrap_or_else(|| {
+                    self.error(element.span, "<component> requires the `is` attribute");
+                    null()
+                });
+
+                self.call_helper("resolveDynamicComponent", vec![is])
+            }
+            None => self.resolve_component(tag),
+        };
+
+        let attrs = attrs
+            .into_iter()
+            .filter(|attr| {
+                element.tag_name != "component"
+                    || !matches!(
+                        attr,
+                        Attr::Static { name: "is", .. }
+                            | Attr::Directive(Directive {
+                                name: "bind",
+                                arg: Some(DirectiveArg::Static("is")),
+                                ..
+                            })
+                    )
+            })
+            .collect::<Vec<_>>();
+        let props = self.gen_props(&attrs, true, key);
+
+        // The children of these components are not slots
+        let children = if matches!(builtin_component(tag), Some("Teleport" | "KeepAlive")) {
+            let nodes = self.gen_children(&children_of(element), true);
+            let children = self.vnodes_of(nodes);
+
+            (!children.is_empty()).then(|| array(children))
+        } else {
+            self.gen_slots(element, &attrs)
+        };
+
+        let mut args = vec![component];
+
+        match (props, children) {
+            (props, Some(children)) => {
+                args.push(props.unwrap_or_else(null));
+                args.push(children);
+            }
+            (Some(props), None) => args.push(props),
+            (None, None) => {}
+        }
+
+        let vnode = self.call_helper("createVNode", args);
+
+        self.with_directives(vnode, element)
+    }
+
+    /// Generates `<slot>`.
+    fn gen_slot_outlet(&mut self, element: &Element) -> Expr {
+        let attrs = self.parse_attrs(element);
+        let mut name = None;
+        let mut rest = Vec::new();
+
+        for attr in attrs {
+            match attr {
+                Attr::Static {
+                    name: "name",
+                    value,
+                } => name = Some(quote_str!(value.unwrap_or_default()).into()),
+                Attr::Directive(Directive {
+                    name: "bind",
+                    arg: Some(DirectiveArg::Static("name")),
+                    value: Some(value),
+                    ..
+                }) => name = Some(self.expr(value)),
+                attr => rest.push(attr),
+            }
+        }
+
+        let props = self.gen_props(&rest, true, None);
+        let nodes = self.gen_children(&children_of(element), true);
+        let fallback = self.vnodes_of(nodes);
+
+        let mut args = vec![
+            ctx_member("$slots".into(), DUMMY_SP),
+            name.unwrap_or_else(|| quote_str!("default").into()),
+        ];
+
+        if !fallback.is_empty() {
+            args.push(props.unwrap_or_else(|| object_lit(Vec::new())));
+            args.push(arrow(Vec::new(), array(fallback)));
+        } else if let Some(props) = props {
+            args.push(props);
+        }
+
+        self.call_helper("renderSlot", args)
+    }
+
+    /// Generates the slots object of a component.
+    fn gen_slots(&mut self, element: &Element, attrs: &[Attr]) -> Option<Expr> {
+        let children = children_of(element);
+        let mut slots = Vec::new();
+
+        let on_component = attrs.iter().find_map(|attr| match attr {
+            Attr::Directive(directive) if directive.name == "slot" => Some(directive),
+            _ => None,
+        });
+
+        if let Some(directive) = on_component {
+            let key = self.slot_key(directive);
+            let slot = self.gen_slot_fn(Some(directive), &children);
+
+            slots.push((key, slot));
+        } else {
+            let mut implicit = Vec::new();
+            let is_slot_template = |child: Option<&&Child>| {
+                matches!(
+                    child,
+                    Some(Child::Element(element))
+                        if element.tag_name == "template" && has_slot_directive(element)
+                )
+            };
+
+            for (i, child) in children.iter().copied().enumerate() {
+                let Child::Element(template) = child else {
+                    // Whitespace next to a slot template would otherwise end up in the
+                    // default slot
+                    let is_whitespace = matches!(
+                        child,
+                        Child::Text(text) if text.data.chars().all(|c| c.is_ascii_whitespace())
+                    );
+                    let is_next_to_template = is_slot_template(children.get(i + 1))
+                        || is_slot_template(i.checked_sub(1).and_then(|i| children.get(i)));
+
+                    if !(is_whitespace && is_next_to_template) {
+                        implicit.push(child);
+                    }
+                    continue;
+                };
+                if !is_slot_template(Some(&child)) {
+                    implicit.push(child);
+                    continue;
+                }
+
+                if has_attr(template, "v-if") || has_attr(template, "v-for") {
+                    self.error(
+                        template.span,
+                        "v-if and v-for on slot templates are not supported",
+                    );
+                    continue;
+                }
+
+                let attrs = self.parse_attrs(template);
+                let directive = attrs
+                    .iter()
+                    .find_map(|attr| match attr {
+                        Attr::Directive(directive) if directive.name == "slot" => Some(directive),
+                        _ => None,
+                    })
+                    .unwrap();
+                let key = self.slot_key(directive);
+
+                if let PropKey::Static(name) = &key {
+                    let is_duplicate = slots
+                        .iter()
+                        .any(|(key, _)| matches!(key, PropKey::Static(other) if other == name));
+
+                    if is_duplicate {
+                        self.error(directive.span, &format!("duplicate slot \"{name}\""));
+                        continue;
+                    }
+                }
+
+                let slot = self.gen_slot_fn(Some(directive), &children_of(template));
+                slots.push((key, slot));
+            }
+
+            let has_content = implicit.iter().any(|child| match child {
+                Child::Element(..) => true,
+                Child::Text(text) => !text.data.chars().all(|c| c.is_ascii_whitespace()),
+                _ => false,
+            });
+
+            if has_content {
+                if slots
+                    .iter()
+                    .any(|(key, _)| matches!(key, PropKey::Static(name) if name == "default"))
+                {
+                    self.error(
+                        element.span,
+                        "the default slot is used by both a template and other children",
+                    );
+                }
+
+                let slot = self.gen_slot_fn(None, &implicit);
+                slots.push((PropKey::Static("default".into()), slot));
+            }
+        }
+
+        if slots.is_empty() {
+            return None;
+        }
+
+        // `SlotFlags.STABLE`
+        slots.push((PropKey::Static("_".into()), 1.0.into()));
+
+        Some(object_lit(slots))
+    }
+
+    fn slot_key(&mut self, directive: &Directive) -> PropKey {
+        match &directive.arg {
+            None => PropKey::Static("default".into()),
+            Some(DirectiveArg::Static(name)) => PropKey::Static(name.to_string()),
+            Some(DirectiveArg::Dynamic(source)) => PropKey::Computed(self.expr(*source)),
+        }
+    }
+
+    fn gen_slot_fn(&mut self, directive: Option<&Directive>, children: &[&Child]) -> Expr {
+        let params = match directive.and_then(|directive| directive.value) {
+            Some(source) => match parse_params(source.text, source.lo, self.options.is_ts) {
+                Ok(params) => params,
+                Err(err) => {
+                    self.parse_error(err);
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        };
+
+        let children = self.with_locals(&params, |compiler| {
+            let nodes = compiler.gen_children(children, true);
+            compiler.vnodes_of(nodes)
+        });
+
+        self.call_helper("withCtx", vec![arrow(params, array(children))])
+    }
+
+    /// Wraps `vnode` with `withDirectives` if `element` has runtime
+    /// directives.
+    fn with_directives(&mut self, vnode: Expr, element: &Element) -> Expr {
+        if self.in_v_pre > 0 {
+            return vnode;
+        }
+
+        let attrs = self.parse_attrs(element);
+        let mut directives = Vec::new();
+
+        for attr in &attrs {
+            let Attr::Directive(directive) = attr else {
+                continue;
+            };
+
+            match directive.name {
+                "show" => {
+                    let v_show = self.helper("vShow");
+                    let value = self.directive_value(directive);
+
+                    directives.push(array(vec![v_show, value]));
+                }
+                "model" if !is_component(element, &self.raw_tag_name(element)) => {
+                    if let Some(directive) = self.gen_v_model_directive(element, &attrs, directive)
+                    {
+                        directives.push(directive);
+                    }
+                }
+                name if !is_builtin_directive(name) => {
+                    let directive = self.gen_custom_directive(directive);
+
+                    directives.push(directive);
+                }
+                _ => {}
+            }
+        }
+
+        if directives.is_empty() {
+            return vnode;
+        }
+
+        self.call_helper("withDirectives", vec![vnode, array(directives)])
+    }
+
+    fn directive_value(&mut self, directive: &Directive) -> Expr {
+        match directive.value {
+            Some(value) => self.expr(value),
+            None => {
+                self.error(
+                    directive.span,
+                    &format!("v-{} requires an expression", directive.name),
+                );
+                *Expr::undefined(DUMMY_SP)
+            }
+        }
+    }
+
+    fn gen_v_model_directive(
+        &mut self,
+        element: &Element,
+        attrs: &[Attr],
+        directive: &Directive,
+    ) -> Option<Expr> {
+        let name = match &*element.tag_name {
+            "select" => "vModelSelect",
+            "textarea" => "vModelText",
+            "input" => {
+                let mut name = "vModelText";
+
+                for attr in attrs {
+                    match attr {
+                        Attr::Static {
+                            name: "type",
+                            value,
+                        } => {
+                            name = match value.unwrap_or_default() {
+                                "checkbox" => "vModelCheckbox",
+                                "radio" => "vModelRadio",
+                                _ => "vModelText",
+                            };
+                        }
+                        Attr::Directive(Directive {
+                            name: "bind",
+                            arg: Some(DirectiveArg::Static("type")) | None,
+                            ..
+                        }) => name = "vModelDynamic",
+                        _ => {}
+                    }
+                }
+
+                name
+            }
+            _ => {
+                self.error(
+                    directive.span,
+                    "v-model can only be used on <input>, <textarea>, <select> and components",
+                );
+                return None;
+            }
+        };
+
+        if directive.arg.is_some() {
+            self.error(
+                directive.span,
+                "v-model on elements doesn't support an argument",
+            );
+        }
+
+        let helper = self.helper(name);
+        let value = self.directive_value(directive);
+        let mut tuple = vec![helper, value];
+
+        if !directive.modifiers.is_empty() {
+            tuple.push(*Expr::undefined(DUMMY_SP));
+            tuple.push(modifiers_object(&directive.modifiers));
+        }
+
+        Some(array(tuple))
+    }
+
+    fn gen_custom_directive(&mut self, directive: &Directive) -> Expr {
+        let resolved = self.resolve_directive(directive.name);
+        let value = directive.value.map(|value| self.expr(value));
+        let arg = directive.arg.as_ref().map(|arg| match arg {
+            DirectiveArg::Static(arg) => quote_str!(*arg).into(),
+            DirectiveArg::Dynamic(source) => self.expr(*source),
+        });
+        let modifiers =
+            (!directive.modifiers.is_empty()).then(|| modifiers_object(&directive.modifiers));
+
+        let mut tuple = vec![Some(resolved), value, arg, modifiers];
+        while let Some(None) = tuple.last() {
+            tuple.pop();
+        }
+
+        array(
+            tuple
+                .into_iter()
+                .map(|item| item.unwrap_or_else(|| *Expr::undefined(DUMMY_SP)))
+                .collect(),
+        )
+    }
+
+    /// Generates the props object of an element. Runtime directives are
+    /// generated by [Self::with_directives].
+    fn gen_props(
+        &mut self,
+        attrs: &[Attr],
+        is_component: bool,
+        key: Option<usize>,
+    ) -> Option<Expr> {
+        let mut entries = Vec::new();
+
+        let has_key = attrs.iter().any(|attr| match attr {
+            Attr::Static { name, .. } => *name == "key",
+            Attr::Directive(directive) => {
+                directive.name == "bind"
+                    && matches!(directive.arg, Some(DirectiveArg::Static("key")))
+            }
+        });
+        if let Some(key) = key.filter(|_| !has_key) {
+            entries.push(Entry::Prop(
+                PropKey::Static("key".into()),
+                (key as f64).into(),
+            ));
+        }
+
+        for attr in attrs {
+            let directive = match attr {
+                Attr::Static { name, value } => {
+                    entries.push(Entry::Prop(
+                        PropKey::Static(name.to_string()),
+                        quote_str!(value.unwrap_or_default()).into(),
+                    ));
+                    continue;
+                }
+                Attr::Directive(directive) => directive,
+            };
+
+            match directive.name {
+                "bind" => {
+                    if let Some(entry) = self.gen_v_bind(directive) {
+                        entries.push(entry);
+                    }
+                }
+                "on" => {
+                    if let Some(entry) = self.gen_v_on(directive) {
+                        entries.push(entry);
+                    }
+                }
+                "model" => self.gen_v_model(directive, is_component, &mut entries),
+                "html" => {
+                    let value = self.directive_value(directive);
+
+                    entries.push(Entry::Prop(PropKey::Static("innerHTML".into()), value));
+                }
+                "text" => {
+                    let value = self.directive_value(directive);
+                    let value = self.call_helper("toDisplayString", vec![value]);
+
+                    entries.push(Entry::Prop(PropKey::Static("textContent".into()), value));
+                }
+                "memo" => self.error(directive.span, "v-memo is not supported"),
+                _ => {}
+            }
+        }
+
+        self.build_props(entries)
+    }
+
+    fn gen_v_bind(&mut self, directive: &Directive) -> Option<Entry> {
+        let Some(arg) = &directive.arg else {
+            let value = self.directive_value(directive);
+
+            return Some(Entry::Spread(value));
+        };
+
+        let key = match arg {
+            DirectiveArg::Static(name) => {
+                let mut name = name.to_string();
+
+                if directive.modifiers.contains(&"camel") {
+                    name = camelize(&name);
+                }
+                if directive.modifiers.contains(&"prop") {
+                    name = format!(".{name}");
+                }
+                if directive.modifiers.contains(&"attr") {
+                    name = format!("^{name}");
+                }
+
+                PropKey::Static(name)
+            }
+            DirectiveArg::Dynamic(source) => PropKey::Computed(self.expr(*source)),
+        };
+
+        let value = match (directive.value, arg) {
+            (Some(value), _) => self.expr(value),
+            // `:foo` is a shorthand of `:foo="foo"`
+            (None, DirectiveArg::Static(name)) => {
+                let name = camelize(name);
+                let lo = directive.span.lo + BytePos(1);
+
+                self.expr(Source {
+                    text: &name,
+                    lo: lo.min(directive.span.hi),
+                })
+            }
+            (None, DirectiveArg::Dynamic(..)) => {
+                self.error(
+                    directive.span,
+                    "v-bind with a dynamic argument requires a value",
+                );
+                return None;
+            }
+        };
+
+        Some(Entry::Prop(key, value))
+    }
+
+    fn gen_v_on(&mut self, directive: &Directive) -> Option<Entry> {
+        let Some(arg) = &directive.arg else {
+            let value = self.directive_value(directive);
+
+            return Some(Entry::Spread(self.call_helper("toHandlers", vec![value])));
+        };
+
+        let mut handler = match directive.value {
+            Some(source) => self.gen_handler(source),
+            None => arrow(Vec::new(), BlockStmtOrExpr::BlockStmt(Default::default())),
+        };
+
+        let event = match arg {
+            DirectiveArg::Static(name) => Some(*name),
+            DirectiveArg::Dynamic(..) => None,
+        };
+        let is_keyboard_event = matches!(event, Some("keyup" | "keydown" | "keypress"));
+
+        let mut event_modifiers = Vec::new();
+        let mut key_modifiers = Vec::new();
+        let mut option_modifiers = String::new();
+        let mut event = event.map(|event| event.to_string());
+
+        for &modifier in &directive.modifiers {
+            match modifier {
+                "once" | "capture" | "passive" => option_modifiers.push_str(&capitalize(modifier)),
+                "stop" | "prevent" | "self" | "ctrl" | "shift" | "alt" | "meta" | "exact" => {
+                    event_modifiers.push(modifier)
+                }
+                "left" | "right" | "middle" if !is_keyboard_event => {
+                    event_modifiers.push(modifier);
+
+                    // Right and middle clicks don't emit `click`
+                    if event.as_deref() == Some("click") {
+                        match modifier {
+                            "right" => event = Some("contextmenu".into()),
+                            "middle" => event = Some("mouseup".into()),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => key_modifiers.push(modifier),
+            }
+        }
+
+        if !event_modifiers.is_empty() {
+            let modifiers = string_array(&event_modifiers);
+
+            handler = self.call_helper("withModifiers", vec![handler, modifiers]);
+        }
+        if !key_modifiers.is_empty() && (is_keyboard_event || event.is_none()) {
+            let modifiers = string_array(&key_modifiers);
+
+            handler = self.call_helper("withKeys", vec![handler, modifiers]);
+        }
+
+        let key = match (event, arg) {
+            (Some(event), _) => PropKey::Static(format!(
+                "on{}{}",
+                capitalize(&camelize(&event)),
+                option_modifiers
+            )),
+            (None, DirectiveArg::Dynamic(source)) => {
+                let name = self.expr(*source);
+
+                PropKey::Computed(self.call_helper("toHandlerKey", vec![name]))
+            }
+            (None, DirectiveArg::Static(..)) => unreachable!(),
+        };
+
+        Some(Entry::Prop(key, handler))
+    }
+
+    /// Generates an event handler. Inline statements are wrapped in a function
+    /// with the `$event` parameter.
+    fn gen_handler(&mut self, source: Source) -> Expr {
+        let is_ts = self.options.is_ts;
+        let mut handler = match parse_expr(source.text, source.lo, is_ts) {
+            Ok(expr) if is_member_expression(&expr) || is_function_expression(&expr) => *expr,
+            Ok(expr) => arrow(vec![event_param()], expr.wrap_with_paren()),
+            Err(..) => match parse_stmts(source.text, source.lo, is_ts) {
+                Ok(stmts) => arrow(
+                    vec![event_param()],
+                    BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        stmts,
+                        ..Default::default()
+                    }),
+                ),
+                Err(err) => {
+                    self.parse_error(err);
+                    return *Expr::undefined(DUMMY_SP);
+                }
+            },
+        };
+
+        self.prefix(&mut handler);
+
+        handler
+    }
+
+    fn gen_v_model(&mut self, directive: &Directive, is_component: bool, entries: &mut Vec<Entry>) {
+        let Some(source) = directive.value else {
+            self.error(directive.span, "v-model requires an expression");
+            return;
+        };
+        let target = match parse_expr(source.text, source.lo, self.options.is_ts) {
+            Ok(target) => target,
+            Err(err) => {
+                self.parse_error(err);
+                return;
+            }
+        };
+        let Ok(left) = AssignTarget::try_from(target.clone()) else {
+            self.error(
+                directive.span,
+                "v-model value must be a valid JavaScript member expression",
+            );
+            return;
+        };
+
+        let mut value = *target;
+        self.prefix(&mut value);
+
+        let mut update = arrow(
+            vec![event_param()],
+            AssignExpr {
+                span: DUMMY_SP,
+                op: AssignOp::Assign,
+                left,
+                right: Box::new(Ident::new_no_ctxt("$event".into(), DUMMY_SP).into()),
+            }
+            .wrap_with_paren(),
+        );
+        self.prefix(&mut update);
+
+        if !is_component {
+            // The value is passed to the runtime directive
+            entries.push(Entry::Prop(
+                PropKey::Static("onUpdate:modelValue".into()),
+                update,
+            ));
+            return;
+        }
+
+        let (prop, modifiers_prop) = match &directive.arg {
+            None => (
+                PropKey::Static("modelValue".into()),
+                Some("modelModifiers".to_string()),
+            ),
+            Some(DirectiveArg::Static(name)) => (
+                PropKey::Static(name.to_string()),
+                Some(format!("{name}Modifiers")),
+            ),
+            Some(DirectiveArg::Dynamic(source)) => (PropKey::Computed(self.expr(*source)), None),
+        };
+
+        let update_key = match &prop {
+            PropKey::Static(name) => PropKey::Static(format!("onUpdate:{name}")),
+            PropKey::Computed(name) => PropKey::Computed(
+                quote_str!("onUpdate:").make_bin(BinaryOp::Add, name.clone().wrap_with_paren()),
+            ),
+        };
+
+        entries.push(Entry::Prop(prop, value));
+        entries.push(Entry::Prop(update_key, update));
+
+        if let Some(modifiers_prop) = modifiers_prop.filter(|_| !directive.modifiers.is_empty()) {
+            entries.push(Entry::Prop(
+                PropKey::Static(modifiers_prop),
+                modifiers_object(&directive.modifiers),
+            ));
+        }
+    }
+
+    /// Merges the entries to a props object. `class` and `style` are
+    /// normalized, and multiple handlers of the same event are merged to an
+    /// array.
+    fn build_props(&mut self, entries: Vec<Entry>) -> Option<Expr> {
+        let mut segments = Vec::new();
+        let mut props: Vec<(PropKey, Vec<Expr>)> = Vec::new();
+
+        for entry in entries {
+            match entry {
+                Entry::Prop(PropKey::Static(name), value) => {
+                    let prev = props.iter_mut().find_map(|(key, values)| match key {
+                        PropKey::Static(key) if *key == name => Some(values),
+                        _ => None,
+                    });
+
+                    match prev {
+                        Some(values) if is_mergeable_prop(&name) => values.push(value),
+                        Some(values) => *values = vec![value],
+                        None => props.push((PropKey::Static(name), vec![value])),
+                    }
+                }
+                Entry::Prop(key, value) => props.push((key, vec![value])),
+                Entry::Spread(value) => {
+                    if !props.is_empty() {
+                        let object = self.props_object(take(&mut props));
+                        segments.push(object);
+                    }
+                    segments.push(value);
+                }
+            }
+        }
+
+        if segments.is_empty() {
+            if props.is_empty() {
+                return None;
+            }
+
+            return Some(self.props_object(props));
+        }
+
+        if !props.is_empty() {
+            let object = self.props_object(props);
+            segments.push(object);
+        }
+
+        if segments.len() == 1 {
+            let value = segments.pop().unwrap();
+            let value = self.call_helper("guardReactiveProps", vec![value]);
+
+            return Some(self.call_helper("normalizeProps", vec![value]));
+        }
+
+        Some(self.call_helper("mergeProps", segments))
+    }
+
+    fn props_object(&mut self, props: Vec<(PropKey, Vec<Expr>)>) -> Expr {
+        let props = props
+            .into_iter()
+            .map(|(key, mut values)| {
+                let normalizer = match &key {
+                    PropKey::Static(name) if name == "class" => Some("normalizeClass"),
+                    PropKey::Static(name) if name == "style" => Some("normalizeStyle"),
+                    _ => None,
+                };
+
+                let value = match normalizer {
+                    Some(..) if values.len() == 1 && is_str_lit(&values[0]) => {
+                        values.pop().unwrap()
+                    }
+                    Some(normalizer) => {
+                        let value = if values.len() == 1 {
+                            values.pop().unwrap()
+                        } else {
+                            array(values)
+                        };
+
+                        self.call_helper(normalizer, vec![value])
+                    }
+                    None if values.len() == 1 => values.pop().unwrap(),
+                    None => array(values),
+                };
+
+                (key, value)
+            })
+            .collect();
+
+        object_lit(props)
+    }
+
+    fn resolve_component(&mut self, tag: &str) -> Expr {
+        let camelized = camelize(tag);
+        let pascalized = capitalize(&camelized);
+
+        for name in [tag, &camelized, &pascalized] {
+            if self.options.bindings.contains(&Atom::from(name)) {
+                self.used.insert(name.into());
+
+                return ctx_member(name.into(), DUMMY_SP);
+            }
+        }
+
+        let helper = self.helper("resolveComponent");
+
+        self.resolve_asset(format!("_component_{}", to_valid_ident(tag)), || {
+            helper.as_call(DUMMY_SP, vec![quote_str!(tag).as_arg()])
+        })
+    }
+
+    fn resolve_directive(&mut self, name: &str) -> Expr {
+        // A directive `vFocus` of `<script setup>` is used as `v-focus`
+        let binding = camelize(&format!("v-{name}"));
+
+        if self.options.bindings.contains(&Atom::from(&*binding)) {
+            self.used.insert(binding.clone().into());
+
+            return ctx_member(binding.into(), DUMMY_SP);
+        }
+
+        let helper = self.helper("resolveDirective");
+
+        self.resolve_asset(format!("_directive_{}", to_valid_ident(name)), || {
+            helper.as_call(DUMMY_SP, vec![quote_str!(name).as_arg()])
+        })
+    }
+
+    fn resolve_asset(&mut self, name: String, init: impl FnOnce() -> Expr) -> Expr {
+        if !self.assets.iter().any(|(ident, _)| ident.sym == *name) {
+            self.assets
+                .push((Ident::new_no_ctxt(name.clone().into(), DUMMY_SP), init()));
+        }
+
+        Ident::new_no_ctxt(name.into(), DUMMY_SP).into()
+    }
+
+    /// Returns `true` for elements without dynamic parts, which can be
+    /// hoisted out of the render function.
+    fn is_static(&self, element: &Element) -> bool {
+        if self.in_v_pre == 0 {
+            if element.namespace == Namespace::HTML
+                && (matches!(&*element.tag_name, "slot" | "template")
+                    || is_component(element, &self.raw_tag_name(element)))
+            {
+                return false;
+            }
+
+            let has_dynamic_attr = element.attributes.iter().any(|attr| {
+                let name = raw_attr_name(attr);
+
+                is_directive_name(name) || name == "ref" || name == "key"
+            });
+            if has_dynamic_attr {
+                return false;
+            }
+        }
+
+        element.children.iter().all(|child| match child {
+            Child::Text(text) => self.in_v_pre > 0 || !text.data.contains("{{"),
+            Child::Element(element) => self.is_static(element),
+            _ => true,
+        })
+    }
+
+    /// Returns the tag name as it's written in the source, as the HTML parser
+    /// lowercases tag names.
+    fn raw_tag_name(&self, element: &Element) -> String {
+        let lo = (element.span.lo - self.fm.start_pos).0 as usize + 1;
+        let raw = self
+            .fm
+            .src
+            .get(lo..lo + element.tag_name.len())
+            .filter(|raw| raw.eq_ignore_ascii_case(&element.tag_name));
+
+        raw.unwrap_or(&element.tag_name).to_string()
+    }
+
+    fn parse_attrs<'a>(&self, element: &'a Element) -> Vec<Attr<'a>> {
+        element
+            .attributes
+            .iter()
+            .filter_map(|attr| {
+                if self.in_v_pre > 0 {
+                    return (attr.name != "v-pre").then(|| Attr::Static {
+                        name: raw_attr_name(attr),
+                        value: attr.value.as_deref(),
+                    });
+                }
+
+                Some(self.parse_attr(attr))
+            })
+            .collect()
+    }
+
+    fn parse_attr<'a>(&self, attr: &'a Attribute) -> Attr<'a> {
+        let raw = raw_attr_name(attr);
+        let mut modifiers = Vec::new();
+
+        let (name, rest) = if let Some(rest) = raw.strip_prefix("v-") {
+            let end = rest.find([':', '.']).unwrap_or(rest.len());
+            let (name, rest) = rest.split_at(end);
+
+            (name, rest.strip_prefix(':').unwrap_or(rest))
+        } else if let Some(rest) = raw.strip_prefix(':') {
+            ("bind", rest)
+        } else if let Some(rest) = raw.strip_prefix('.') {
+            modifiers.push("prop");
+            ("bind", rest)
+        } else if let Some(rest) = raw.strip_prefix('@') {
+            ("on", rest)
+        } else if let Some(rest) = raw.strip_prefix('#') {
+            ("slot", rest)
+        } else {
+            return Attr::Static {
+                name: raw,
+                value: attr.value.as_deref(),
+            };
+        };
+
+        let (arg, rest) = if rest.starts_with('[') {
+            let end = rest.find(']').map_or(rest.len(), |end| end + 1);
+            let text = &rest[1..end.saturating_sub(1).max(1)];
+
+            (
+                Some(DirectiveArg::Dynamic(Source {
+                    text,
+                    lo: attr.span.lo + BytePos(offset_of(raw, text)),
+                })),
+                &rest[end..],
+            )
+        } else if rest.starts_with('.') {
+            (None, rest)
+        } else {
+            let end = rest.find('.').unwrap_or(rest.len());
+            let arg = &rest[..end];
+
+            (
+                (!arg.is_empty()).then_some(DirectiveArg::Static(arg)),
+                &rest[end..],
+            )
+        };
+
+        modifiers.extend(rest.split('.').filter(|modifier| !modifier.is_empty()));
+
+        Attr::Directive(Directive {
+            name,
+            arg,
+            modifiers,
+            value: self.attr_value(attr),
+            span: attr.span,
+        })
+    }
+
+    fn attr_value<'a>(&self, attr: &'a Attribute) -> Option<Source<'a>> {
+        let text = attr.value.as_deref()?;
+        let raw = raw_attr_name(attr);
+        let source = slice(self.fm, attr.span);
+
+        // Skips `=` and the quote
+        let offset = source.get(raw.len()..).and_then(|rest| {
+            let eq = rest.find('=')?;
+            let value = &rest[eq + 1..];
+            let trimmed = value.trim_start();
+            let quote = usize::from(trimmed.starts_with(['"', '\'']));
+
+            Some(raw.len() + eq + 1 + (value.len() - trimmed.len()) + quote)
+        });
+
+        Some(Source {
+            text,
+            lo: attr.span.lo + BytePos(offset.unwrap_or_default() as u32),
+        })
+    }
+}
+
+fn raw_attr_name(attr: &Attribute) -> &str {
+    attr.raw_name.as_deref().unwrap_or(&attr.name)
+}
+
+fn has_attr(element: &Element, name: &str) -> bool {
+    element.attributes.iter().any(|attr| attr.name == *name)
+}
+
+fn has_slot_directive(element: &Element) -> bool {
+    element.attributes.iter().any(|attr| {
+        let name = raw_attr_name(attr);
+
+        name.starts_with('#') || name == "v-slot" || name.starts_with("v-slot:")
+    })
+}
+
+/// `<template>` with these directives is a fragment. Otherwise, it's rendered
+/// as a native `<template>` element.
+fn is_special_template(element: &Element) -> bool {
+    has_slot_directive(element)
+        || ["v-if", "v-else-if", "v-else", "v-for"]
+            .iter()
+            .any(|name| has_attr(element, name))
+}
+
+fn is_directive_name(name: &str) -> bool {
+    name.starts_with("v-") || name.starts_with([':', '.', '@', '#'])
+}
+
+/// Directives which are compiled to props or control flow, rather than to
+/// runtime directives.
+fn is_builtin_directive(name: &str) -> bool {
+    matches!(
+        name,
+        "bind"
+            | "on"
+            | "if"
+            | "else-if"
+            | "else"
+            | "for"
+            | "model"
+            | "slot"
+            | "show"
+            | "html"
+            | "text"
+            | "once"
+            | "pre"
+            | "cloak"
+            | "memo"
+            | "is"
+    )
+}
+
+fn children_of(element: &Element) -> Vec<&Child> {
+    match &element.content {
+        Some(content) => content.children.iter().collect(),
+        None => element.children.iter().collect(),
+    }
+}
+
+fn is_component(element: &Element, tag: &str) -> bool {
+    element.namespace == Namespace::HTML
+        && !matches!(&*element.tag_name, "slot" | "template")
+        && (element.tag_name == "component"
+            || builtin_component(tag).is_some()
+            || tag.chars().any(|c| c.is_ascii_uppercase())
+            || !is_html_tag(&element.tag_name))
+}
+
+fn builtin_component(tag: &str) -> Option<&'static str> {
+    match tag {
+        "Teleport" | "teleport" => Some("Teleport"),
+        "Suspense" | "suspense" => Some("Suspense"),
+        "KeepAlive" | "keep-alive" => Some("KeepAlive"),
+        "Transition" | "transition" => Some("Transition"),
+        "TransitionGroup" | "transition-group" => Some("TransitionGroup"),
+        _ => None,
+    }
+}
+
+/// Elements of the HTML namespace which are not components. See `HTML_TAGS`
+/// of `@vue/shared`.
+fn is_html_tag(tag: &str) -> bool {
+    matches!(
+        tag,
+        "html"
+            | "body"
+            | "base"
+            | "head"
+            | "link"
+            | "meta"
+            | "style"
+            | "title"
+            | "address"
+            | "article"
+            | "aside"
+            | "footer"
+            | "header"
+            | "hgroup"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "nav"
+            | "section"
+            | "div"
+            | "dd"
+            | "dl"
+            | "dt"
+            | "figcaption"
+            | "figure"
+            | "picture"
+            | "hr"
+            | "img"
+            | "li"
+            | "main"
+            | "ol"
+            | "p"
+            | "pre"
+            | "ul"
+            | "a"
+            | "b"
+            | "abbr"
+            | "bdi"
+            | "bdo"
+            | "br"
+            | "cite"
+            | "code"
+            | "data"
+            | "dfn"
+            | "em"
+            | "i"
+            | "kbd"
+            | "mark"
+            | "q"
+            | "rp"
+            | "rt"
+            | "ruby"
+            | "s"
+            | "samp"
+            | "small"
+            | "span"
+            | "strong"
+            | "sub"
+            | "sup"
+            | "time"
+            | "u"
+            | "var"
+            | "wbr"
+            | "area"
+            | "audio"
+            | "map"
+            | "track"
+            | "video"
+            | "embed"
+            | "object"
+            | "param"
+            | "source"
+            | "canvas"
+            | "script"
+            | "noscript"
+            | "del"
+            | "ins"
+            | "caption"
+            | "col"
+            | "colgroup"
+            | "table"
+            | "thead"
+            | "tbody"
+            | "td"
+            | "th"
+            | "tr"
+            | "button"
+            | "datalist"
+            | "fieldset"
+            | "form"
+            | "input"
+            | "label"
+            | "legend"
+            | "meter"
+            | "optgroup"
+            | "option"
+            | "output"
+            | "progress"
+            | "select"
+            | "textarea"
+            | "details"
+            | "dialog"
+            | "menu"
+            | "summary"
+            | "template"
+            | "blockquote"
+            | "iframe"
+            | "tfoot"
+            | "search"
+            | "svg"
+            | "math"
+    )
+}
+
+/// Returns `true` for props which are merged instead of overridden when they
+/// appear multiple times.
+fn is_mergeable_prop(name: &str) -> bool {
+    name == "class"
+        || name == "style"
+        || (name.starts_with("on") && name[2..].starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// Splits the value of `v-for` to the alias and the source, e.g. `item in
+/// items`.
+fn split_for(value: &str) -> Option<(&str, &str)> {
+    let bytes = value.as_bytes();
+
+    (1..value.len().saturating_sub(2)).find_map(|i| {
+        let keyword = value.get(i..i + 2)?;
+
+        if (keyword == "in" || keyword == "of")
+            && bytes[i - 1].is_ascii_whitespace()
+            && bytes[i + 2].is_ascii_whitespace()
+        {
+            let alias = value[..i].trim();
+            let source = value[i + 2..].trim();
+
+            return (!alias.is_empty() && !source.is_empty()).then_some((alias, source));
+        }
+
+        None
+    })
+}
+
+/// Returns the offset of `inner` in `outer`. `inner` must be a subslice of
+/// `outer`.
+fn offset_of(outer: &str, inner: &str) -> u32 {
+    (inner.as_ptr() as usize - outer.as_ptr() as usize) as u32
+}
+
+fn condense_whitespace(text: &str) -> String {
+    let mut condensed = String::with_capacity(text.len());
+    let mut prev_whitespace = false;
+
+    for c in text.chars() {
+        if c.is_ascii_whitespace() {
+            if !prev_whitespace {
+                condensed.push(' ');
+            }
+            prev_whitespace = true;
+        } else {
+            condensed.push(c);
+            prev_whitespace = false;
+        }
+    }
+
+    condensed
+}
+
+/// `foo-bar` to `fooBar`
+pub(crate) fn camelize(s: &str) -> String {
+    let mut camelized = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(next) if c == '-' && next.is_ascii_alphanumeric() => {
+                camelized.push(next.to_ascii_uppercase());
+                chars.next();
+            }
+            _ => camelized.push(c),
+        }
+    }
+
+    camelized
+}
+
+pub(crate) fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn to_valid_ident(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn helper_ident(name: &str) -> Ident {
+    Ident::new_no_ctxt(format!("_{name}").into(), DUMMY_SP)
+}
+
+fn hoisted_ident(index: usize) -> Ident {
+    Ident::new_no_ctxt(format!("_hoisted_{}", index + 1).into(), DUMMY_SP)
+}
+
+fn const_decl(name: Ident, init: Expr) -> Decl {
+    VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: name.into(),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+        ..Default::default()
+    }
+    .into()
+}
+
+fn null() -> Expr {
+    Lit::Null(Null { span: DUMMY_SP }).into()
+}
+
+fn is_str_lit(expr: &Expr) -> bool {
+    matches!(expr, Expr::Lit(Lit::Str(..)))
+}
+
+fn array(elems: Vec<Expr>) -> Expr {
+    ArrayLit {
+        span: DUMMY_SP,
+        elems: elems.into_iter().map(|elem| Some(elem.as_arg())).collect(),
+    }
+    .into()
+}
+
+fn string_array(items: &[&str]) -> Expr {
+    array(items.iter().map(|item| quote_str!(*item).into()).collect())
+}
+
+/// `{ trim: true }`
+fn modifiers_object(modifiers: &[&str]) -> Expr {
+    object_lit(
+        modifiers
+            .iter()
+            .map(|modifier| (PropKey::Static(modifier.to_string()), true.into()))
+            .collect(),
+    )
+}
+
+fn object_lit(props: Vec<(PropKey, Expr)>) -> Expr {
+    ObjectLit {
+        span: DUMMY_SP,
+        props: props
+            .into_iter()
+            .map(|(key, value)| {
+                let key = match key {
+                    PropKey::Static(name) if is_valid_prop_ident(&name) => {
+                        PropName::Ident(IdentName::new(name.into(), DUMMY_SP))
+                    }
+                    PropKey::Static(name) => PropName::Str(quote_str!(name)),
+                    PropKey::Computed(expr) => PropName::Computed(ComputedPropName {
+                        span: DUMMY_SP,
+                        expr: Box::new(expr),
+                    }),
+                };
+
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key,
+                    value: Box::new(value),
+                })))
+            })
+            .collect(),
+    }
+    .into()
+}
+
+fn arrow(params: Vec<Pat>, body: impl Into<BlockStmtOrExpr>) -> Expr {
+    ArrowExpr {
+        params,
+        body: Box::new(body.into()),
+        ..Default::default()
+    }
+    .into()
+}
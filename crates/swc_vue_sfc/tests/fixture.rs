@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use swc_common::{errors::HANDLER, sync::Lrc, SourceFile, SourceMap};
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_vue_sfc::{compile, parse, CompileOptions, CompiledComponent};
+use testing::NormalizedOutput;
+
+fn options() -> CompileOptions {
+    CompileOptions {
+        id: Some("7ba5bd90".into()),
+    }
+}
+
+fn print_js(cm: &Lrc<SourceMap>, fm: &SourceFile, compiled: &CompiledComponent) -> String {
+    let mut buf = Vec::new();
+    let mut src_map_buf = Vec::new();
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: JsWriter::new(cm.clone(), "\n", &mut buf, Some(&mut src_map_buf)),
+        };
+
+        emitter.emit_module(&compiled.module).unwrap();
+    }
+
+    // Blocks are compiled in place, so the output is mapped to the `.vue` file
+    let source_map = cm.build_source_map(&src_map_buf);
+    assert!(
+        source_map
+            .sources()
+            .all(|source| *source == fm.name.to_string()),
+        "the source map should only point to the input file"
+    );
+
+    String::from_utf8(buf).unwrap()
+}
+
+fn print_css(cm: &Lrc<SourceMap>, fm: &SourceFile, compiled: &CompiledComponent) -> String {
+    let mut css = String::new();
+
+    for stylesheet in &compiled.styles {
+        let mut buf = String::new();
+        let mut src_map_buf = Vec::new();
+        {
+            let wr = BasicCssWriter::new(
+                &mut buf,
+                Some(&mut src_map_buf),
+                BasicCssWriterConfig::default(),
+            );
+            let mut gen = CodeGenerator::new(wr, CodegenConfig { minify: false });
+
+            gen.emit(stylesheet).unwrap();
+        }
+
+        let source_map = cm.build_source_map(&src_map_buf);
+        assert!(
+            source_map
+                .sources()
+                .any(|source| *source == fm.name.to_string()),
+            "styles should be mapped to the input file"
+        );
+
+        css.push_str(&buf);
+        css.push('\n');
+    }
+
+    css
+}
+
+#[testing::fixture("tests/fixture/**/input.vue")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+
+    testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        let compiled = HANDLER.set(&handler, || {
+            let descriptor = parse(&fm)?;
+
+            compile(&fm, &descriptor, &options())
+        });
+        let compiled = match compiled {
+            Ok(compiled) => compiled,
+            Err(err) => panic!("failed to compile: {err:?}"),
+        };
+
+        NormalizedOutput::from(print_js(&cm, &fm, &compiled))
+            .compare_to_file(dir.join("output.js"))
+            .unwrap();
+
+        if !compiled.styles.is_empty() {
+            NormalizedOutput::from(print_css(&cm, &fm, &compiled))
+                .compare_to_file(dir.join("output.css"))
+                .unwrap();
+        }
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/errors/**/input.vue")]
+fn errors(input: PathBuf) {
+    let stderr_path = input.parent().unwrap().join("output.swc-stderr");
+
+    let stderr = testing::run_test2(false, |cm, handler| -> Result<(), _> {
+        let fm = cm.load_file(&input).unwrap();
+
+        let result = HANDLER.set(&handler, || {
+            let descriptor = parse(&fm)?;
+
+            compile(&fm, &descriptor, &options())
+        });
+
+        if result.is_ok() || !handler.has_errors() {
+            panic!("should error");
+        }
+
+        Err(())
+    })
+    .unwrap_err();
+
+    stderr.compare_to_file(stderr_path).unwrap();
+}
@@ -0,0 +1,57 @@
+import { Fragment as _Fragment, createElementVNode as _createElementVNode, createVNode as _createVNode, normalizeClass as _normalizeClass, renderList as _renderList, toDisplayString as _toDisplayString } from "vue";
+const _sfc_main = {
+    data () {
+        return {
+            name: 'Vue',
+            count: 0,
+            isActive: true,
+            items: []
+        };
+    },
+    methods: {
+        select (item) {
+            console.log(item);
+        },
+        onInput (event) {
+            this.name = event.target.value;
+        }
+    }
+};
+const _hoisted_1 = _createElementVNode("h1", null, "Static title", -1);
+function _sfc_render(_ctx, _cache) {
+    return _createElementVNode("div", {
+        id: "app",
+        class: _normalizeClass([
+            "container",
+            {
+                active: _ctx.isActive
+            }
+        ])
+    }, [
+        _hoisted_1,
+        _createElementVNode("p", null, "Hello, " + _toDisplayString(_ctx.name) + "! You have " + _toDisplayString(_ctx.items.length) + " items."),
+        _ctx.items.length === 0 ? _createElementVNode("p", {
+            key: 0
+        }, "No items") : _ctx.items.length === 1 ? _createElementVNode("p", {
+            key: 1
+        }, "One item") : _createElementVNode("p", {
+            key: 2
+        }, "Many items"),
+        _createElementVNode("ul", null, [
+            _createVNode(_Fragment, null, _renderList(_ctx.items, (item, index)=>_createElementVNode("li", {
+                    key: item.id,
+                    onClick: ($event)=>(_ctx.select(item))
+                }, " " + _toDisplayString(index) + ": " + _toDisplayString(item.name) + " ")))
+        ]),
+        _createElementVNode("button", {
+            type: "button",
+            onClick: ($event)=>(_ctx.count++)
+        }, "Clicked " + _toDisplayString(_ctx.count) + " times"),
+        _createElementVNode("input", {
+            value: _ctx.name,
+            onInput: _ctx.onInput
+        })
+    ]);
+}
+_sfc_main.render = _sfc_render;
+export default _sfc_main;
@@ -0,0 +1,26 @@
+import { Fragment as _Fragment, createElementVNode as _createElementVNode, createVNode as _createVNode, normalizeClass as _normalizeClass } from "vue";
+const _sfc_main = {};
+function _sfc_render(_ctx, _cache) {
+    return _createVNode(_Fragment, null, [
+        _createElementVNode("p", {
+            class: _normalizeClass(_ctx.$style.red)
+        }, "Red"),
+        _createElementVNode("p", {
+            class: _normalizeClass([
+                _ctx.classes.big,
+                _ctx.classes.bold
+            ])
+        }, "Big")
+    ]);
+}
+_sfc_main.render = _sfc_render;
+_sfc_main.__cssModules = {
+    $style: {
+        red: "red_7ba5bd90"
+    },
+    classes: {
+        big: "big_7ba5bd90",
+        bold: "bold_7ba5bd90 big_7ba5bd90"
+    }
+};
+export default _sfc_main;
@@ -0,0 +1,111 @@
+import { ref } from 'vue';
+import vFocus from './focus';
+import { createElementVNode as _createElementVNode, createTextVNode as _createTextVNode, mergeProps as _mergeProps, resolveDirective as _resolveDirective, toDisplayString as _toDisplayString, toHandlerKey as _toHandlerKey, toHandlers as _toHandlers, vModelText as _vModelText, vShow as _vShow, withDirectives as _withDirectives, withKeys as _withKeys, withModifiers as _withModifiers } from "vue";
+const _sfc_main = {
+    __name: "input",
+    setup (__props, { expose: __expose, emit: __emit }) {
+        __expose();
+        const visible = ref(true);
+        const html = ref('<b>bold</b>');
+        const value = ref('');
+        function submit() {}
+        function onKey(event) {}
+        return {
+            ref,
+            vFocus,
+            visible,
+            html,
+            value,
+            submit,
+            onKey
+        };
+    }
+};
+const _hoisted_1 = _createElementVNode("span", null, [
+    _createTextVNode("{{ raw }} "),
+    _createElementVNode("b", {
+        ":x": "y"
+    }, "raw")
+], -1);
+const _hoisted_2 = _createElementVNode("pre", null, "  keep   whitespace\n", -1);
+const _hoisted_3 = _createElementVNode("svg", {
+    viewBox: "0 0 10 10"
+}, [
+    _createElementVNode("circle", {
+        cx: "5",
+        cy: "5",
+        r: "4"
+    })
+], -1);
+function _sfc_render(_ctx, _cache) {
+    const _directive_tooltip = _resolveDirective("tooltip");
+    return _createElementVNode("form", {
+        onSubmit: _withModifiers(_ctx.submit, [
+            "prevent"
+        ])
+    }, [
+        _withDirectives(_createElementVNode("input", {
+            "onUpdate:modelValue": ($event)=>(_ctx.value = $event),
+            onKeyup: _withKeys(_ctx.submit, [
+                "enter"
+            ]),
+            onKeydown: _withKeys(_withModifiers(_ctx.onKey, [
+                "ctrl",
+                "prevent"
+            ]), [
+                "s"
+            ])
+        }), [
+            [
+                _ctx.vFocus
+            ],
+            [
+                _vModelText,
+                _ctx.value,
+                void 0,
+                {
+                    trim: true
+                }
+            ]
+        ]),
+        _withDirectives(_createElementVNode("p", null, "Shown"), [
+            [
+                _vShow,
+                _ctx.visible
+            ],
+            [
+                _directive_tooltip,
+                'Hint',
+                "top",
+                {
+                    delay: true
+                }
+            ]
+        ]),
+        _createElementVNode("div", {
+            innerHTML: _ctx.html
+        }),
+        _createElementVNode("span", {
+            textContent: _toDisplayString(_ctx.value)
+        }),
+        _cache[0] || (_cache[0] = _createElementVNode("span", null, _toDisplayString(_ctx.value))),
+        _hoisted_1,
+        _createElementVNode("a", _mergeProps({
+            onContextmenu: _withModifiers(_ctx.submit, [
+                "right"
+            ]),
+            onClickOnceCapture: _ctx.submit
+        }, _ctx.$attrs, _toHandlers({
+            mouseenter: _ctx.submit
+        })), "Link"),
+        _hoisted_2,
+        _hoisted_3,
+        _createElementVNode("button", {
+            [_ctx.attr]: _ctx.value,
+            [_toHandlerKey(_ctx.event)]: _ctx.submit,
+            disabled: _ctx.disabled
+        }, "Dynamic")
+    ]);
+}
+_sfc_main.render = _sfc_render;
+export default _sfc_main;
@@ -0,0 +1,15 @@
+import { createElementVNode as _createElementVNode, renderSlot as _renderSlot } from "vue";
+const _sfc_main = {};
+function _sfc_render(_ctx, _cache) {
+    return _createElementVNode("div", {
+        class: "card"
+    }, [
+        _createElementVNode("h2", {
+            class: "title"
+        }, "Title"),
+        _renderSlot(_ctx.$slots, "default")
+    ]);
+}
+_sfc_main.render = _sfc_render;
+_sfc_main.__scopeId = "data-v-7ba5bd90";
+export default _sfc_main;
@@ -0,0 +1,79 @@
+import { ref } from 'vue';
+import { format } from './item';
+import { Fragment as _Fragment, createElementVNode as _createElementVNode, createVNode as _createVNode, normalizeClass as _normalizeClass, renderList as _renderList, toDisplayString as _toDisplayString } from "vue";
+const _sfc_main = {
+    __name: "input",
+    props: {
+        id: {
+            type: String,
+            required: true
+        },
+        items: {
+            type: Array,
+            required: true
+        },
+        size: {
+            type: String,
+            required: false,
+            default: 'small'
+        },
+        disabled: {
+            type: Boolean,
+            required: false
+        },
+        count: {
+            type: [
+                Number,
+                String
+            ],
+            required: false,
+            default: 0
+        },
+        onSelect: {
+            type: Function,
+            required: false
+        },
+        meta: {
+            type: Object,
+            required: true
+        },
+        data: {
+            type: null,
+            required: false
+        }
+    },
+    emits: [
+        "select",
+        "close",
+        "open"
+    ],
+    setup (__props, { expose: __expose, emit: __emit }) {
+        __expose();
+        const props = __props;
+        const emit = __emit;
+        const selected = ref(null);
+        const select = (item)=>{
+            selected.value = item;
+            emit('select', item);
+        };
+        return {
+            format,
+            props,
+            emit,
+            selected,
+            select
+        };
+    }
+};
+function _sfc_render(_ctx, _cache) {
+    return _createElementVNode("ul", {
+        class: _normalizeClass(_ctx.size)
+    }, [
+        _createVNode(_Fragment, null, _renderList(_ctx.items, (item)=>_createElementVNode("li", {
+                key: item.id,
+                onClick: ($event)=>(_ctx.select(item))
+            }, " " + _toDisplayString(_ctx.format(item)) + " ")))
+    ]);
+}
+_sfc_main.render = _sfc_render;
+export default _sfc_main;
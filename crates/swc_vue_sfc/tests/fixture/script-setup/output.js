@@ -0,0 +1,93 @@
+import { ref, computed } from 'vue';
+import TodoItem from './TodoItem.vue';
+import { createElementVNode as _createElementVNode, createVNode as _createVNode, toDisplayString as _toDisplayString, vModelCheckbox as _vModelCheckbox, vModelSelect as _vModelSelect, vModelText as _vModelText, withDirectives as _withDirectives } from "vue";
+const _sfc_main = {
+    __name: "input",
+    props: {
+        title: String,
+        initial: {
+            type: Number,
+            default: 0
+        }
+    },
+    emits: [
+        'change'
+    ],
+    setup (__props, { expose: __expose, emit: __emit }) {
+        const props = __props;
+        const emit = __emit;
+        const count = ref(props.initial);
+        const double = computed(()=>count.value * 2);
+        const text = ref('');
+        function increment() {
+            count.value++;
+            emit('change', count.value);
+        }
+        __expose({
+            increment
+        });
+        return {
+            ref,
+            computed,
+            TodoItem,
+            props,
+            emit,
+            count,
+            double,
+            text,
+            increment
+        };
+    }
+};
+const _hoisted_1 = _createElementVNode("option", null, "A", -1);
+function _sfc_render(_ctx, _cache) {
+    return _createElementVNode("section", null, [
+        _createElementVNode("h2", null, _toDisplayString(_ctx.title)),
+        _createVNode(_ctx.TodoItem, {
+            modelValue: _ctx.text,
+            "onUpdate:modelValue": ($event)=>(_ctx.text = $event),
+            count: _ctx.double,
+            onRemove: _ctx.increment
+        }),
+        _createVNode(_ctx.TodoItem, {
+            title: _ctx.text,
+            "onUpdate:title": ($event)=>(_ctx.text = $event),
+            titleModifiers: {
+                trim: true
+            }
+        }),
+        _withDirectives(_createElementVNode("input", {
+            "onUpdate:modelValue": ($event)=>(_ctx.text = $event)
+        }), [
+            [
+                _vModelText,
+                _ctx.text,
+                void 0,
+                {
+                    lazy: true
+                }
+            ]
+        ]),
+        _withDirectives(_createElementVNode("input", {
+            type: "checkbox",
+            "onUpdate:modelValue": ($event)=>(_ctx.checked = $event)
+        }), [
+            [
+                _vModelCheckbox,
+                _ctx.checked
+            ]
+        ]),
+        _withDirectives(_createElementVNode("select", {
+            "onUpdate:modelValue": ($event)=>(_ctx.selected = $event)
+        }, [
+            _hoisted_1
+        ]), [
+            [
+                _vModelSelect,
+                _ctx.selected
+            ]
+        ])
+    ]);
+}
+_sfc_main.render = _sfc_render;
+export default _sfc_main;
@@ -0,0 +1,87 @@
+import { Fragment as _Fragment, KeepAlive as _KeepAlive, Teleport as _Teleport, Transition as _Transition, createCommentVNode as _createCommentVNode, createElementVNode as _createElementVNode, createVNode as _createVNode, renderList as _renderList, renderSlot as _renderSlot, resolveComponent as _resolveComponent, resolveDynamicComponent as _resolveDynamicComponent, toDisplayString as _toDisplayString, vShow as _vShow, withCtx as _withCtx, withDirectives as _withDirectives } from "vue";
+const _sfc_main = {
+    props: [
+        'current',
+        'ok',
+        'visible'
+    ],
+    data: ()=>({
+            dynamicSlot: 'extra',
+            message: 'hi',
+            year: 2024
+        })
+};
+const _hoisted_1 = _createElementVNode("span", null, "dynamic", -1);
+const _hoisted_2 = _createElementVNode("footer", null, "Default footer", -1);
+const _hoisted_3 = _createElementVNode("div", {
+    class: "modal"
+}, "Modal", -1);
+const _hoisted_4 = _createElementVNode("span", null, "a", -1);
+const _hoisted_5 = _createElementVNode("span", null, "b", -1);
+function _sfc_render(_ctx, _cache) {
+    const _component_Layout = _resolveComponent("Layout");
+    const _component_List = _resolveComponent("List");
+    return _createVNode(_Fragment, null, [
+        _createVNode(_component_Layout, null, {
+            header: _withCtx(({ title })=>[
+                    _createElementVNode("h1", null, _toDisplayString(title))
+                ]),
+            [_ctx.dynamicSlot]: _withCtx(()=>[
+                    _hoisted_1
+                ]),
+            default: _withCtx(()=>[
+                    _createElementVNode("p", null, "Default content " + _toDisplayString(_ctx.message))
+                ]),
+            _: 1
+        }),
+        _createVNode(_component_List, null, {
+            default: _withCtx(({ item })=>[
+                    _createElementVNode("span", null, _toDisplayString(item.name))
+                ]),
+            _: 1
+        }),
+        _renderSlot(_ctx.$slots, "footer", {
+            year: _ctx.year
+        }, ()=>[
+                _hoisted_2
+            ]),
+        _renderSlot(_ctx.$slots, "default"),
+        _createVNode(_resolveDynamicComponent(_ctx.current), {
+            class: "dynamic"
+        }),
+        _createVNode(_KeepAlive, null, [
+            _createVNode(_resolveDynamicComponent("Tab"))
+        ]),
+        _createVNode(_Teleport, {
+            to: "body"
+        }, [
+            _hoisted_3
+        ]),
+        _createVNode(_Transition, {
+            name: "fade"
+        }, {
+            default: _withCtx(()=>[
+                    _withDirectives(_createElementVNode("p", null, "Fade"), [
+                        [
+                            _vShow,
+                            _ctx.visible
+                        ]
+                    ])
+                ]),
+            _: 1
+        }),
+        _ctx.ok ? _createVNode(_Fragment, {
+            key: 0
+        }, [
+            _hoisted_4,
+            _hoisted_5
+        ]) : _createCommentVNode("v-if", true),
+        _createVNode(_Fragment, null, _renderList(3, (n)=>_createVNode(_Fragment, {
+                key: n
+            }, [
+                _createElementVNode("b", null, _toDisplayString(n))
+            ])))
+    ]);
+}
+_sfc_main.render = _sfc_render;
+export default _sfc_main;
@@ -0,0 +1,36 @@
+function _import_defer_proxy(init) {
+    var ns = null;
+    var constValue = function(v) {
+        return function() {
+            return v;
+        };
+    };
+    var proxy = function(run) {
+        return function(_target, p, receiver) {
+            if (ns === null) ns = init();
+
+            return run(ns, p, receiver);
+        };
+    };
+
+    // The target is an empty object, so the properties of the namespace must be
+    // reported as configurable to satisfy the invariants of Proxy.
+    var getOwnPropertyDescriptor = function(target, p) {
+        var desc = Reflect.getOwnPropertyDescriptor(target, p);
+        if (desc) desc.configurable = true;
+        return desc;
+    };
+
+    return new Proxy({}, {
+        defineProperty: constValue(false),
+        deleteProperty: constValue(false),
+        get: proxy(Reflect.get),
+        getOwnPropertyDescriptor: proxy(getOwnPropertyDescriptor),
+        getPrototypeOf: constValue(null),
+        has: proxy(Reflect.has),
+        ownKeys: proxy(Reflect.ownKeys),
+        set: constValue(false),
+        setPrototypeOf: constValue(false)
+    });
+}
+export { _import_defer_proxy as _ };
@@ -0,0 +1 @@
+export { __rewriteRelativeImportExtension as _ } from "tslib";
@@ -53,6 +53,7 @@ export { _ as _extends } from "./_extends.js";
 export { _ as _get } from "./_get.js";
 export { _ as _get_prototype_of } from "./_get_prototype_of.js";
 export { _ as _identity } from "./_identity.js";
+export { _ as _import_defer_proxy } from "./_import_defer_proxy.js";
 export { _ as _inherits } from "./_inherits.js";
 export { _ as _inherits_loose } from "./_inherits_loose.js";
 export { _ as _initializer_define_property } from "./_initializer_define_property.js";
@@ -96,6 +97,7 @@ export { _ as _ts_dispose_resources } from "./_ts_dispose_resources.js";
 export { _ as _ts_generator } from "./_ts_generator.js";
 export { _ as _ts_metadata } from "./_ts_metadata.js";
 export { _ as _ts_param } from "./_ts_param.js";
+export { _ as _ts_rewrite_relative_import_extension } from "./_ts_rewrite_relative_import_extension.js";
 export { _ as _ts_values } from "./_ts_values.js";
 export { _ as _type_of } from "./_type_of.js";
 export { _ as _unsupported_iterable_to_array } from "./_unsupported_iterable_to_array.js";
@@ -701,9 +701,25 @@ export interface JscConfig {
         [from: string]: string[];
     };
 
+    /**
+     * Path to an import map, relative to `.swcrc`.
+     *
+     * Bare specifiers mapped to a local file are rewritten to a relative
+     * path, and specifiers mapped to a URL are rewritten to the URL.
+     */
+    importMap?: string;
+
     minify?: JsMinifyOptions;
 
     preserveAllComments?: boolean;
+
+    /**
+     * Read compiler options from `tsconfig.json`. `true` uses the nearest
+     * `tsconfig.json` of the input file, and a string is the path of the file.
+     *
+     * Options in `.swcrc` take priority.
+     */
+    tsconfig?: boolean | string;
 }
 
 export type JscTarget =
@@ -824,6 +840,12 @@ export interface EsParserConfig {
      * Defaults to `false`
      */
     explicitResourceManagement?: boolean;
+    /**
+     * Hack-style pipeline operator with `%` as the topic reference.
+     *
+     * Defaults to `false`
+     */
+    pipelineOperator?: boolean;
 }
 
 /**
@@ -855,7 +877,7 @@ export interface TransformConfig {
     /**
      * https://swc.rs/docs/configuration/compilation#jsctransformdecoratorversion
      */
-    decoratorVersion?: "2021-12" | "2022-03";
+    decoratorVersion?: "2021-12" | "2022-03" | "2023-11";
 
     treatConstEnumAsEnum?: boolean;
 
@@ -869,6 +891,11 @@ export interface TransformConfig {
      */
     verbatimModuleSyntax?: boolean;
 
+    /**
+     * https://www.typescriptlang.org/tsconfig/#rewriteRelativeImportExtensions
+     */
+    rewriteRelativeImportExtensions?: boolean;
+
     /**
      * Native class properties support
      */
@@ -1452,6 +1479,8 @@ export type Expression =
     | TsInstantiation
     | PrivateName
     | OptionalChainingExpression
+    | PipelineExpression
+    | TopicReference
     | Invalid;
 
 interface ExpressionBase extends Node, HasSpan {}
@@ -1591,6 +1620,18 @@ export interface ConditionalExpression extends ExpressionBase {
     alternate: Expression;
 }
 
+export interface PipelineExpression extends ExpressionBase {
+    type: "PipelineExpression";
+
+    head: Expression;
+
+    body: Expression;
+}
+
+export interface TopicReference extends ExpressionBase {
+    type: "TopicReference";
+}
+
 export interface Super extends Node, HasSpan {
     type: "Super";
 }
@@ -2936,4 +2977,46 @@ export type WasmAnalysisOptions = {
     plugins: WasmPlugin[];
 };
 
-export type WasmPlugin = [wasmPackage: string, config: Record<string, any>];
+export type WasmPlugin = [
+    wasmPackage: string,
+    config: Record<string, any>,
+    sandbox?: WasmPluginSandbox,
+];
+
+/**
+ * Limits and capabilities of a Wasm plugin.
+ *
+ * A plugin can't access the filesystem or environment variables unless they
+ * are granted here, and has no resource limits by default.
+ */
+export interface WasmPluginSandbox {
+    /**
+     * The number of Wasm instructions the plugin can execute per file.
+     */
+    fuel?: number;
+
+    /**
+     * The maximum size of the memory of the plugin, in bytes.
+     */
+    maxMemory?: number;
+
+    /**
+     * Directories the plugin can access, from the path seen by the plugin to
+     * the path on the host. Relative host paths are resolved from the current
+     * directory.
+     *
+     * e.g. `{ "/cwd": "." }`
+     */
+    fs?: Record<string, string>;
+
+    /**
+     * Environment variables the plugin can read.
+     */
+    env?: string[];
+
+    /**
+     * Makes clocks and random number generators unavailable, so the output of
+     * the plugin only depends on its input.
+     */
+    deterministic?: boolean;
+}